@@ -1,4 +1,131 @@
+use std::ffi::OsString;
+use structopt::StructOpt;
+use tex::configure::{Clock, ConfigurationError, InteractionMode, TeXConfiguration};
+
+/// Options of a TeX run; like other TeX programs, the options of TeX
+/// may be spelled with a single dash, as in `-interaction=nonstopmode`.
+#[derive(StructOpt)]
+struct Opt {
+    /// be INITEX, able to load patterns and \dump a format
+    #[structopt(long)]
+    ini: bool,
+    /// format to load, instead of plain
+    #[structopt(long, value_name = "NAME", allow_hyphen_values = true)]
+    fmt: Option<String>,
+    /// name of the job, instead of the name of the first input file
+    #[structopt(long, value_name = "NAME", allow_hyphen_values = true)]
+    jobname: Option<String>,
+    /// directory to write output files to
+    #[structopt(long, value_name = "DIR", allow_hyphen_values = true)]
+    output_directory: Option<String>,
+    /// level of interaction: batchmode, nonstopmode, scrollmode or errorstopmode
+    #[structopt(long, value_name = "MODE", parse(try_from_str = parse_interaction))]
+    interaction: Option<InteractionMode>,
+    /// end the job at the first error
+    #[structopt(long)]
+    halt_on_error: bool,
+    /// greatest index in the main memory array
+    #[structopt(long)]
+    mem_max: Option<u32>,
+    /// maximum number of characters in the input buffer
+    #[structopt(long)]
+    buf_size: Option<u16>,
+    /// maximum number of simultaneous input sources
+    #[structopt(long)]
+    stack_size: Option<u16>,
+    /// maximum number of simultaneously open input files
+    #[structopt(long)]
+    max_in_open: Option<u8>,
+    /// maximum internal font number
+    #[structopt(long)]
+    font_max: Option<u8>,
+    /// number of words of font memory for all fonts
+    #[structopt(long)]
+    font_mem_size: Option<u16>,
+    /// maximum number of characters in strings
+    #[structopt(long)]
+    pool_size: Option<u32>,
+    /// space for saving values outside of current group
+    #[structopt(long)]
+    save_size: Option<u16>,
+    /// space for hyphenation patterns
+    #[structopt(long)]
+    trie_size: Option<u16>,
+    /// size of the output buffer; a multiple of 8
+    #[structopt(long)]
+    dvi_buf_size: Option<u16>,
+    /// report the date and time in UTC instead of the local time zone
+    #[structopt(long)]
+    utc: bool,
+    /// first line of input, as if typed at the `**` prompt
+    #[structopt(name = "FIRST_LINE")]
+    first_line: Vec<String>,
+}
+
+fn parse_interaction(mode: &str) -> Result<InteractionMode, String> {
+    match mode {
+        "batchmode" => Ok(InteractionMode::Batch),
+        "nonstopmode" => Ok(InteractionMode::NonStop),
+        "scrollmode" => Ok(InteractionMode::Scroll),
+        "errorstopmode" => Ok(InteractionMode::ErrorStop),
+        _ => Err(format!("unknown interaction mode `{}`", mode)),
+    }
+}
+
+/// The options TeX programs take with a single dash.
+const SINGLE_DASH_OPTIONS: &[&str] = &[
+    "ini",
+    "fmt",
+    "jobname",
+    "output-directory",
+    "interaction",
+    "halt-on-error",
+];
+
+/// The options whose value may follow as a separate argument.
+const OPTIONS_WITH_VALUES: &[&str] = &["fmt", "jobname", "output-directory", "interaction"];
+
+/// Turn `-name` into `--name` for the options in `SINGLE_DASH_OPTIONS`,
+/// leaving option values, `--` and everything after it alone.
+fn args_with_double_dashes(args: impl Iterator<Item = OsString>) -> Vec<OsString> {
+    let mut options_ended = false;
+    let mut value_follows = false;
+    args.map(|arg| {
+        if options_ended || std::mem::take(&mut value_follows) {
+            return arg;
+        }
+        let s = match arg.to_str() {
+            Some("--") => {
+                options_ended = true;
+                return arg;
+            }
+            Some(s) => s,
+            None => return arg,
+        };
+        let name = match s.strip_prefix("--").or_else(|| s.strip_prefix('-')) {
+            Some(option) => option.split('=').next().unwrap_or_default(),
+            None => return arg,
+        };
+        value_follows = !s.contains('=') && OPTIONS_WITH_VALUES.contains(&name);
+        if !s.starts_with("--") && SINGLE_DASH_OPTIONS.contains(&name) {
+            format!("-{}", s).into()
+        } else {
+            arg
+        }
+    })
+    .collect()
+}
+
+fn exit_on_error(result: Result<(), ConfigurationError>) {
+    if let Err(err) = result {
+        eprintln!("tex-cli: {}", err);
+        std::process::exit(2);
+    }
+}
+
 fn main() {
+    let opt = Opt::from_iter(args_with_double_dashes(std::env::args_os()));
+
     #[cfg(feature = "trace")]
     {
         use tracing_subscriber::{layer::SubscriberExt, Registry};
@@ -20,7 +147,63 @@ fn main() {
         }
     }
 
-    let mut globals: Box<tex::TeXGlobals> = Default::default();
-    tex::entry(&mut globals);
-    drop(globals);
+    let mut job = tex::Job::new(&opt.first_line.join(" "));
+    let globals = job.configuration();
+    if let Some(mem_max) = opt.mem_max {
+        exit_on_error(globals.set_mem_max(mem_max));
+    }
+    if let Some(buf_size) = opt.buf_size {
+        exit_on_error(globals.set_buf_size(buf_size));
+    }
+    if let Some(stack_size) = opt.stack_size {
+        exit_on_error(globals.set_stack_size(stack_size));
+    }
+    if let Some(max_in_open) = opt.max_in_open {
+        exit_on_error(globals.set_max_in_open(max_in_open));
+    }
+    if let Some(font_max) = opt.font_max {
+        exit_on_error(globals.set_font_max(font_max));
+    }
+    if let Some(font_mem_size) = opt.font_mem_size {
+        exit_on_error(globals.set_font_mem_size(font_mem_size));
+    }
+    if let Some(pool_size) = opt.pool_size {
+        exit_on_error(globals.set_pool_size(pool_size));
+    }
+    if let Some(save_size) = opt.save_size {
+        exit_on_error(globals.set_save_size(save_size));
+    }
+    if let Some(trie_size) = opt.trie_size {
+        exit_on_error(globals.set_trie_size(trie_size));
+    }
+    if let Some(dvi_buf_size) = opt.dvi_buf_size {
+        exit_on_error(globals.set_dvi_buf_size(dvi_buf_size));
+    }
+    if opt.utc {
+        exit_on_error(globals.set_clock(Clock::Utc));
+    }
+    globals.set_ini_version(opt.ini);
+    if let Some(fmt) = &opt.fmt {
+        exit_on_error(globals.set_format(fmt));
+    }
+    if let Some(jobname) = &opt.jobname {
+        globals.set_job_name(jobname);
+    }
+    if let Some(output_directory) = &opt.output_directory {
+        globals.set_output_directory(output_directory);
+    }
+    if let Some(interaction) = opt.interaction {
+        globals.set_interaction(interaction);
+    }
+    globals.set_halt_on_error(opt.halt_on_error);
+    let result = job.run();
+    if let Some(unported_section) = &result.unported_section {
+        eprintln!("tex-cli: {}", unported_section);
+    }
+    if let Some(internal_error) = &result.internal_error {
+        eprintln!("tex-cli: internal error: {}", internal_error);
+    }
+    if result.history >= tex::History::ErrorMessageIssued {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,712 @@
+//! A transcription of the parts of DVItype, Version 3.6, that the TRIP test
+//! uses: it lists a DVI file at output level 2 (mnemonics), with resolution
+//! 72.27 pixels per inch and all pages selected, as `trip.typ` records it.
+//! Only the errors that DVItype reports within its listing are kept; a file
+//! it would reject as bad makes this panic instead.
+
+use std::fmt::Write;
+
+const INFINITY: i64 = 0o17777777777;
+const INVALID_WIDTH: i64 = 0o17777777777;
+const LINE_LENGTH: usize = 79;
+const RESOLUTION: f64 = 72.27;
+
+struct Font {
+    num: i32,
+    check_sum: i32,
+    scaled_size: i32,
+    design_size: i32,
+    name: Vec<u8>,
+    space: i64,
+    bc: i32,
+    ec: i32,
+    widths: Vec<i64>,
+}
+
+struct DVItype<'a, F> {
+    dvi: &'a [u8],
+    cur_loc: usize,
+    out: String,
+    showing: bool,
+    text_buf: Vec<u8>,
+    open_tfm: F,
+    fonts: Vec<Font>,
+    conv: f64,
+    true_conv: f64,
+    numerator: i32,
+    denominator: i32,
+    mag: i32,
+    old_backpointer: i32,
+    count: [i32; 10],
+    page_count: i32,
+    max_h_so_far: i64,
+    max_v_so_far: i64,
+    max_s_so_far: usize,
+    in_postamble: bool,
+}
+
+/// Lists `dvi` the way `DVItype` does for the TRIP test; `open_tfm` supplies
+/// the `TFM` file of each font by name.
+pub(crate) fn dvitype(dvi: &[u8], open_tfm: impl Fn(&str) -> Option<Vec<u8>>) -> Vec<u8> {
+    let mut t = DVItype {
+        dvi,
+        cur_loc: 0,
+        out: String::new(),
+        showing: false,
+        text_buf: Vec::new(),
+        open_tfm,
+        fonts: Vec::new(),
+        conv: 0.0,
+        true_conv: 0.0,
+        numerator: 0,
+        denominator: 0,
+        mag: 0,
+        old_backpointer: -1,
+        count: [0; 10],
+        page_count: 0,
+        max_h_so_far: 0,
+        max_v_so_far: 0,
+        max_s_so_far: 0,
+        in_postamble: false,
+    };
+    t.run();
+    t.out.into_bytes()
+}
+
+fn xchr(c: u8) -> char {
+    if (b' '..=b'~').contains(&c) {
+        c as char
+    } else {
+        '?'
+    }
+}
+
+impl<'a, F: Fn(&str) -> Option<Vec<u8>>> DVItype<'a, F> {
+    fn get_byte(&mut self) -> i32 {
+        let b = self.dvi[self.cur_loc];
+        self.cur_loc += 1;
+        b as i32
+    }
+
+    fn signed_byte(&mut self) -> i32 {
+        self.get_byte() as u8 as i8 as i32
+    }
+
+    fn get_two_bytes(&mut self) -> i32 {
+        let a = self.get_byte();
+        a * 256 + self.get_byte()
+    }
+
+    fn signed_pair(&mut self) -> i32 {
+        let a = self.signed_byte();
+        a * 256 + self.get_byte()
+    }
+
+    fn get_three_bytes(&mut self) -> i32 {
+        let a = self.get_two_bytes();
+        a * 256 + self.get_byte()
+    }
+
+    fn signed_trio(&mut self) -> i32 {
+        let a = self.signed_pair();
+        a * 256 + self.get_byte()
+    }
+
+    fn signed_quad(&mut self) -> i32 {
+        let a = self.signed_trio();
+        a.wrapping_mul(256).wrapping_add(self.get_byte())
+    }
+
+    fn first_par(&mut self, o: i32, w: i32, x: i32, y: i32, z: i32) -> i32 {
+        match o {
+            0..=127 => o,
+            // set1, put1, fnt1, xxx1, fnt_def1
+            128 | 133 | 235 | 239 | 243 => self.get_byte(),
+            129 | 134 | 236 | 240 | 244 => self.get_two_bytes(),
+            130 | 135 | 237 | 241 | 245 => self.get_three_bytes(),
+            // right1, w1, x1, y1, z1, down1
+            143 | 148 | 153 | 157 | 162 | 167 => self.signed_byte(),
+            144 | 149 | 154 | 158 | 163 | 168 => self.signed_pair(),
+            145 | 150 | 155 | 159 | 164 | 169 => self.signed_trio(),
+            131 | 132 | 136 | 137 | 146 | 151 | 156 | 160 | 165 | 170 | 238 | 242 | 246 => {
+                self.signed_quad()
+            }
+            147 => w,
+            152 => x,
+            161 => y,
+            166 => z,
+            171..=234 => o - 171,
+            _ => 0,
+        }
+    }
+
+    fn print_ln(&mut self, s: &str) {
+        self.out.push_str(s);
+        self.out.push('\n');
+    }
+
+    fn flush_text(&mut self) {
+        if !self.text_buf.is_empty() {
+            let text: String = self.text_buf.iter().map(|&c| xchr(c)).collect();
+            writeln!(self.out, "[{}]", text).unwrap();
+            self.text_buf.clear();
+        }
+    }
+
+    fn out_text(&mut self, c: u8) {
+        if self.text_buf.len() == LINE_LENGTH - 2 {
+            self.flush_text();
+        }
+        self.text_buf.push(c);
+    }
+
+    /// `major`: a command that is always shown, after the pending text
+    fn major(&mut self, a: usize, s: &str) {
+        self.flush_text();
+        self.minor(a, s);
+    }
+
+    /// `minor`: a command that is shown without interrupting the text
+    fn minor(&mut self, a: usize, s: &str) {
+        self.showing = true;
+        write!(self.out, "{}: {}", a, s).unwrap();
+    }
+
+    fn error(&mut self, a: usize, s: &str) {
+        if !self.showing {
+            self.major(a, s);
+        } else {
+            write!(self.out, " {}", s).unwrap();
+        }
+    }
+
+    fn run(&mut self) {
+        self.print_ln("This is DVItype, Version 3.6");
+        self.print_ln("Options selected:");
+        self.print_ln("  Starting page = *.*.*.*.*.*.*.*.*.* ");
+        self.print_ln("  Maximum number of pages = 1000000");
+        self.print_ln("  Output level = 2 (mnemonics)");
+        writeln!(
+            self.out,
+            "  Resolution = {:12.8} pixels per inch",
+            RESOLUTION
+        )
+        .unwrap();
+        // Process the preamble
+        assert_eq!(self.get_byte(), 247, "First byte isn't start of preamble!");
+        let id = self.get_byte();
+        if id != 2 {
+            writeln!(self.out, "identification in byte 1 should be 2!").unwrap();
+        }
+        self.numerator = self.signed_quad();
+        self.denominator = self.signed_quad();
+        writeln!(
+            self.out,
+            "numerator/denominator={}/{}",
+            self.numerator, self.denominator
+        )
+        .unwrap();
+        self.conv = (self.numerator as f64 / 254000.0) * (RESOLUTION / self.denominator as f64);
+        self.mag = self.signed_quad();
+        self.true_conv = self.conv;
+        self.conv = self.true_conv * (self.mag as f64 / 1000.0);
+        writeln!(
+            self.out,
+            "magnification={}; {:16.8} pixels per DVI unit",
+            self.mag, self.conv
+        )
+        .unwrap();
+        let k = self.get_byte();
+        let comment: String = (0..k).map(|_| xchr(self.get_byte() as u8)).collect();
+        writeln!(self.out, "'{}'", comment).unwrap();
+        // Translate up to the given page range
+        self.scan_bop();
+        while !self.in_postamble {
+            self.print_ln(" ");
+            write!(self.out, "{}: beginning of page ", self.cur_loc - 45).unwrap();
+            let count = self.count.map(|c| c.to_string()).join(".");
+            writeln!(self.out, "{} ", count).unwrap();
+            self.do_page();
+            self.scan_bop();
+        }
+        let backpointer = self.signed_quad();
+        if backpointer != self.old_backpointer {
+            writeln!(
+                self.out,
+                "backpointer in byte {} should be {}!",
+                self.cur_loc - 4,
+                self.old_backpointer
+            )
+            .unwrap();
+        }
+        self.read_postamble();
+    }
+
+    fn scan_bop(&mut self) {
+        let mut k;
+        loop {
+            k = self.get_byte();
+            if (243..247).contains(&k) {
+                let p = self.first_par(k, 0, 0, 0, 0);
+                self.define_font(p);
+            } else if k != 138 {
+                break;
+            }
+        }
+        if k == 248 {
+            self.in_postamble = true;
+        } else {
+            assert_eq!(k, 139, "byte {} is not bop", self.cur_loc - 1);
+            let new_backpointer = self.cur_loc as i32 - 1;
+            self.page_count += 1;
+            for k in 0..10 {
+                self.count[k] = self.signed_quad();
+            }
+            if self.signed_quad() != self.old_backpointer {
+                writeln!(
+                    self.out,
+                    "backpointer in byte {} should be {}!",
+                    self.cur_loc - 4,
+                    self.old_backpointer
+                )
+                .unwrap();
+            }
+            self.old_backpointer = new_backpointer;
+        }
+    }
+
+    fn do_page(&mut self) {
+        let (mut h, mut v, mut w, mut x, mut y, mut z) = (0i64, 0i64, 0, 0, 0, 0);
+        let mut stack: Vec<(i64, i64, i32, i32, i32, i32)> = Vec::new();
+        let mut cur_font: Option<usize> = None;
+        loop {
+            let a = self.cur_loc;
+            self.showing = false;
+            let o = self.get_byte();
+            let mut p = self.first_par(o, w, x, y, z);
+            let font_space = cur_font.map_or(0, |f| self.fonts[f].space);
+            let mut move_right: Option<i64> = None;
+            let mut move_down: Option<i64> = None;
+            match o {
+                0..=127 | 128..=131 | 133..=136 => {
+                    if o <= 127 {
+                        if o > b' ' as i32 && o <= b'~' as i32 {
+                            self.out_text(p as u8);
+                            self.minor(a, &format!("setchar{}", p));
+                        } else {
+                            self.major(a, &format!("setchar{}", p));
+                        }
+                    } else if o < 133 {
+                        self.major(a, &format!("set{} {}", o - 127, p));
+                    } else {
+                        self.major(a, &format!("put{} {}", o - 132, p));
+                    }
+                    // Finish a command that either sets or puts a character
+                    if p < 0 {
+                        p = 255 - ((-1 - p) % 256);
+                    } else if p >= 256 {
+                        p %= 256;
+                    }
+                    let q = match cur_font {
+                        Some(f) if p >= self.fonts[f].bc && p <= self.fonts[f].ec => {
+                            self.fonts[f].widths[(p - self.fonts[f].bc) as usize]
+                        }
+                        _ => INVALID_WIDTH,
+                    };
+                    if q == INVALID_WIDTH {
+                        let font = self.font_name(cur_font);
+                        let bang = if cur_font.is_some() { "!" } else { "" };
+                        self.error(
+                            a,
+                            &format!("character {} invalid in font {}{}", p, font, bang),
+                        );
+                    }
+                    if o < 133 {
+                        move_right = Some(if q == INVALID_WIDTH { 0 } else { q });
+                    }
+                }
+                132 | 137 => {
+                    let name = if o == 132 { "setrule" } else { "putrule" };
+                    self.major(a, name);
+                    let q = self.signed_quad();
+                    write!(self.out, " height {}, width {}", p, q).unwrap();
+                    if o == 132 {
+                        move_right = Some(q as i64);
+                    }
+                }
+                138 => self.minor(a, "nop"),
+                139 => panic!("bop occurred before eop!"),
+                140 => {
+                    self.major(a, "eop");
+                    if !stack.is_empty() {
+                        let level = stack.len();
+                        self.error(
+                            a,
+                            &format!("stack not empty at end of page (level {})!", level),
+                        );
+                    }
+                    self.print_ln(" ");
+                    return;
+                }
+                141 => {
+                    self.major(a, "push");
+                    stack.push((h, v, w, x, y, z));
+                    self.max_s_so_far = self.max_s_so_far.max(stack.len());
+                }
+                142 => {
+                    self.major(a, "pop");
+                    match stack.pop() {
+                        Some(state) => (h, v, w, x, y, z) = state,
+                        None => self.error(a, "(illegal at level zero)!"),
+                    }
+                }
+                143..=161 | 162..=170 => {
+                    let (name, n) = match o {
+                        143..=146 => ("right", o - 142),
+                        147..=151 => ("w", o - 147),
+                        152..=156 => ("x", o - 152),
+                        157..=160 => ("down", o - 156),
+                        161..=165 => ("y", o - 161),
+                        _ => ("z", o - 166),
+                    };
+                    match name {
+                        "w" => w = p,
+                        "x" => x = p,
+                        "y" => y = p,
+                        "z" => z = p,
+                        _ => {}
+                    }
+                    if matches!(name, "right" | "w" | "x") {
+                        if p as i64 >= font_space || p as i64 <= -4 * font_space {
+                            self.out_text(b' ');
+                        }
+                        self.minor(a, &format!("{}{} {}", name, n, p));
+                        move_right = Some(p as i64);
+                    } else {
+                        self.major(a, &format!("{}{} {}", name, n, p));
+                        move_down = Some(p as i64);
+                    }
+                }
+                171..=234 | 235..=238 => {
+                    if o <= 234 {
+                        self.major(a, &format!("fntnum{}", p));
+                    } else {
+                        self.major(a, &format!("fnt{} {}", o - 234, p));
+                    }
+                    cur_font = self.fonts.iter().position(|f| f.num == p);
+                    if cur_font.is_none() {
+                        let message =
+                            format!("invalid font selection: font {} was never defined!", p);
+                        self.error(a, &message);
+                    }
+                }
+                239..=242 => {
+                    self.major(a, "xxx '");
+                    let mut bad_char = false;
+                    if p < 0 {
+                        self.error(a, "string of negative length!");
+                    }
+                    for _ in 0..p {
+                        let q = self.get_byte() as u8;
+                        if !(b' '..=b'~').contains(&q) {
+                            bad_char = true;
+                        }
+                        self.out.push(xchr(q));
+                    }
+                    self.out.push('\'');
+                    if bad_char {
+                        self.error(a, "non-ASCII character in xxx command!");
+                    }
+                }
+                243..=246 => {
+                    self.major(a, &format!("fntdef{} {}", o - 242, p));
+                    self.define_font(p);
+                }
+                _ => panic!("undefined command {}!", o),
+            }
+            if let Some(mut q) = move_right {
+                // Finish a command that sets |h:=h+q|
+                if h > 0 && q > 0 && h > INFINITY - q {
+                    let message = format!(
+                        "arithmetic overflow! parameter changed from {} to {}",
+                        q,
+                        INFINITY - h
+                    );
+                    self.error(a, &message);
+                    q = INFINITY - h;
+                }
+                if h < 0 && q < 0 && -h > q + INFINITY {
+                    let message = format!(
+                        "arithmetic overflow! parameter changed from {} to {}",
+                        q,
+                        -h - INFINITY
+                    );
+                    self.error(a, &message);
+                    q = -h - INFINITY;
+                }
+                h += q;
+                self.max_h_so_far = self.max_h_so_far.max(h.abs());
+            }
+            if let Some(mut p) = move_down {
+                // Finish a command that sets |v:=v+p|
+                if v > 0 && p > 0 && v > INFINITY - p {
+                    let message = format!(
+                        "arithmetic overflow! parameter changed from {} to {}",
+                        p,
+                        INFINITY - v
+                    );
+                    self.error(a, &message);
+                    p = INFINITY - v;
+                }
+                if v < 0 && p < 0 && -v > p + INFINITY {
+                    let message = format!(
+                        "arithmetic overflow! parameter changed from {} to {}",
+                        p,
+                        -v - INFINITY
+                    );
+                    self.error(a, &message);
+                    p = -v - INFINITY;
+                }
+                v += p;
+                self.max_v_so_far = self.max_v_so_far.max(v.abs());
+            }
+            if self.showing {
+                self.print_ln(" ");
+            }
+        }
+    }
+
+    fn font_name(&self, f: Option<usize>) -> String {
+        match f {
+            Some(f) => self.fonts[f].name.iter().map(|&c| xchr(c)).collect(),
+            None => "UNDEFINED!".to_string(),
+        }
+    }
+
+    fn define_font(&mut self, e: i32) {
+        let c = self.signed_quad();
+        let q = self.signed_quad();
+        let d = self.signed_quad();
+        let m = if q <= 0 || d <= 0 {
+            1000
+        } else {
+            ((1000.0 * self.conv * q as f64) / (self.true_conv * d as f64)).round() as i64
+        };
+        let p = self.get_byte();
+        let n = self.get_byte();
+        let name: Vec<u8> = (0..p + n).map(|_| self.get_byte() as u8).collect();
+        if self.showing {
+            self.out.push_str(": ");
+        } else {
+            write!(self.out, "Font {}: ", e).unwrap();
+        }
+        if name.is_empty() {
+            self.out.push_str("null font name!");
+        }
+        self.out.extend(name.iter().map(|&c| xchr(c)));
+        if !self.showing && m != 1000 {
+            write!(self.out, " scaled {}", m).unwrap();
+        }
+        let old = self.fonts.iter().position(|f| f.num == e);
+        if !self.in_postamble {
+            if old.is_some() {
+                self.print_ln("---this font was already defined!");
+            }
+        } else if old.is_none() {
+            self.print_ln("---this font wasn't loaded before!");
+        }
+        match old {
+            None => self.load_font(e, c, q, d, name),
+            Some(f) => {
+                // Check that the current font definition matches the old one
+                let f = &self.fonts[f];
+                let mut messages = Vec::new();
+                if f.check_sum != c {
+                    messages.push("---check sum doesn't match previous definition!");
+                }
+                if f.scaled_size != q {
+                    messages.push("---scaled size doesn't match previous definition!");
+                }
+                if f.design_size != d {
+                    messages.push("---design size doesn't match previous definition!");
+                }
+                if f.name != name {
+                    messages.push("---font name doesn't match previous definition!");
+                }
+                for message in messages {
+                    self.print_ln(message);
+                }
+            }
+        }
+    }
+
+    fn load_font(&mut self, e: i32, c: i32, q: i32, d: i32, name: Vec<u8>) {
+        let name_str: String = name.iter().map(|&c| c as char).collect();
+        let tfm = match (self.open_tfm)(&format!("{}.tfm", name_str)) {
+            Some(tfm) => tfm,
+            None => {
+                self.out
+                    .push_str("---not loaded, TFM file can't be opened!");
+                return;
+            }
+        };
+        if q <= 0 || q >= 0o1000000000 {
+            write!(self.out, "---not loaded, bad scale ({})!", q).unwrap();
+            return;
+        }
+        if d <= 0 || d >= 0o1000000000 {
+            write!(self.out, "---not loaded, bad design size ({})!", d).unwrap();
+            return;
+        }
+        let (tfm_check_sum, bc, ec, widths) = in_tfm(&tfm, q);
+        if c != 0 && tfm_check_sum != 0 && c != tfm_check_sum {
+            self.print_ln("---beware: check sums do not agree!");
+            writeln!(
+                self.out,
+                "   ({} in DVI file vs. {} in TFM file)",
+                c, tfm_check_sum
+            )
+            .unwrap();
+            self.out.push_str("   ");
+        }
+        write!(self.out, "---loaded at size {} DVI units", q).unwrap();
+        let percent = ((100.0 * self.conv * q as f64) / (self.true_conv * d as f64)).round() as i64;
+        if percent != 100 {
+            self.print_ln(" ");
+            write!(self.out, " (this font is magnified {}%)", percent).unwrap();
+        }
+        self.fonts.push(Font {
+            num: e,
+            check_sum: c,
+            scaled_size: q,
+            design_size: d,
+            name,
+            space: q as i64 / 6,
+            bc,
+            ec,
+            widths,
+        });
+    }
+
+    fn read_postamble(&mut self) {
+        let post_loc = self.cur_loc - 5;
+        writeln!(self.out, "Postamble starts at byte {}.", post_loc).unwrap();
+        if self.signed_quad() != self.numerator {
+            self.print_ln("numerator doesn't match the preamble!");
+        }
+        if self.signed_quad() != self.denominator {
+            self.print_ln("denominator doesn't match the preamble!");
+        }
+        if self.signed_quad() != self.mag {
+            self.print_ln("magnification doesn't match the preamble!");
+        }
+        let max_v = self.signed_quad() as i64;
+        let max_h = self.signed_quad() as i64;
+        write!(self.out, "maxv={}, maxh={}", max_v, max_h).unwrap();
+        let max_s = self.get_two_bytes() as usize;
+        let total_pages = self.get_two_bytes();
+        writeln!(
+            self.out,
+            ", maxstackdepth={}, totalpages={}",
+            max_s, total_pages
+        )
+        .unwrap();
+        // Compare the lust parameters with the accumulated facts
+        if max_v + 99 < self.max_v_so_far {
+            writeln!(self.out, "warning: observed maxv was {}", self.max_v_so_far).unwrap();
+        }
+        if max_h + 99 < self.max_h_so_far {
+            writeln!(self.out, "warning: observed maxh was {}", self.max_h_so_far).unwrap();
+        }
+        if max_s < self.max_s_so_far {
+            let observed = self.max_s_so_far;
+            writeln!(self.out, "warning: observed maxstackdepth was {}", observed).unwrap();
+        }
+        if self.page_count != total_pages {
+            let page_count = self.page_count;
+            writeln!(
+                self.out,
+                "there are really {} pages, not {}!",
+                page_count, total_pages
+            )
+            .unwrap();
+        }
+        // Process the font definitions of the postamble
+        self.showing = false;
+        let mut k;
+        loop {
+            k = self.get_byte();
+            if (243..247).contains(&k) {
+                let p = self.first_par(k, 0, 0, 0, 0);
+                self.define_font(p);
+                self.print_ln(" ");
+            } else if k != 138 {
+                break;
+            }
+        }
+        if k != 249 {
+            writeln!(self.out, "byte {} is not postpost!", self.cur_loc - 1).unwrap();
+        }
+        // Make sure that the end of the file is well-formed
+        if self.signed_quad() as usize != post_loc {
+            let loc = self.cur_loc - 4;
+            writeln!(self.out, "bad postamble pointer in byte {}!", loc).unwrap();
+        }
+        if self.get_byte() != 2 {
+            let loc = self.cur_loc - 1;
+            writeln!(self.out, "identification in byte {} should be 2!", loc).unwrap();
+        }
+        let k = self.cur_loc;
+        assert!(
+            self.dvi[k..].iter().all(|&m| m == 223),
+            "signature should be 223"
+        );
+        if self.dvi.len() < k + 4 {
+            let found = self.dvi.len() - k;
+            writeln!(
+                self.out,
+                "not enough signature bytes at end of file ({})",
+                found
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Reads the check sum, the character range and the scaled widths of the
+/// characters of a `TFM` file, as `in_TFM` does for font size `z`.
+fn in_tfm(tfm: &[u8], z: i32) -> (i32, i32, i32, Vec<i64>) {
+    let half = |k: usize| ((tfm[2 * k] as i32) << 8) | tfm[2 * k + 1] as i32;
+    let word = |k: usize| [tfm[4 * k], tfm[4 * k + 1], tfm[4 * k + 2], tfm[4 * k + 3]];
+    let (lh, bc, ec, nw) = (half(1) as usize, half(2), half(3), half(4) as usize);
+    let check_sum = i32::from_be_bytes(word(6));
+    let char_base = 6 + lh;
+    let width_base = char_base + (ec - bc + 1) as usize;
+    // Replace |z| by $|z|^\prime$ and compute $\alpha,\beta$
+    let mut z = z as i64;
+    let mut alpha = 16;
+    while z >= 0o40000000 {
+        z /= 2;
+        alpha += alpha;
+    }
+    let beta = 256 / alpha;
+    let alpha = alpha * z;
+    let in_width: Vec<i64> = (0..nw)
+        .map(|k| {
+            let [a, b, c, d] = word(width_base + k).map(|x| x as i64);
+            let w = (((((d * z) / 0o400) + (c * z)) / 0o400) + (b * z)) / beta;
+            match a {
+                0 => w,
+                255 => w - alpha,
+                _ => panic!("TFM file is bad"),
+            }
+        })
+        .collect();
+    let widths = (0..=(ec - bc).max(-1))
+        .map(|k| match word(char_base + k as usize)[0] {
+            0 => INVALID_WIDTH,
+            w => in_width[w as usize],
+        })
+        .collect();
+    (check_sum, bc, ec, widths)
+}
@@ -3,14 +3,16 @@
 #[path = "auxiliary/vfs.rs"]
 mod vfs;
 
+use vfs::configure_for_test;
 use vfs::prepare_pool;
 use vfs::TeXTestVFS;
 
 #[test]
 fn initex_immediate_eof() {
     let term_output = prepare_pool().install(|| {
-        TeXTestVFS::default().install_as_tex_io_handler();
+        TeXTestVFS::default().install_as_current();
         if let mut globals = tex::TeXGlobals::default() {
+            configure_for_test(&mut globals);
             tex::entry(&mut globals);
         }
         TeXTestVFS::with_current(|vfs| vfs.dump_current_term_out().unwrap())
@@ -32,8 +34,9 @@ fn initex_empty_fmt_and_minimal_term_in() {
         TeXTestVFS::default()
             .and_then_prepare_file("empty.tex", b"")
             .and_then_prepare_termin(concat!("empty\n", "\\end\n").as_bytes())
-            .install_as_tex_io_handler();
+            .install_as_current();
         if let mut globals = tex::TeXGlobals::default() {
+            configure_for_test(&mut globals);
             tex::entry(&mut globals);
         }
         TeXTestVFS::with_current(|vfs| {
@@ -63,8 +66,532 @@ fn initex_empty_fmt_and_minimal_term_in() {
     );
 }
 
+#[test]
+fn initex_first_line_and_job_name_options() {
+    let (term_output, renamed_log) = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file("empty.tex", b"")
+            .and_then_prepare_termin(b"\\end\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            globals.set_first_line("empty");
+            globals.set_job_name("renamed");
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| {
+            let term_output = vfs.dump_current_term_out().unwrap();
+            let renamed_log = vfs.dump_file("renamed.log").unwrap();
+            (term_output, renamed_log)
+        })
+    });
+    assert_eq!(
+        concat!(
+            "This is TeX-rs, Version 3.141592653 (INITEX)\n",
+            "(empty.tex)\n",
+            "*No pages of output.\n",
+            "Transcript written on renamed.log."
+        ),
+        String::from_utf8_lossy(&term_output).as_ref()
+    );
+    assert_eq!(
+        concat!(
+            "This is TeX-rs, Version 3.141592653 (INITEX)  4 JUL 1776 12:00\n",
+            "**empty\n",
+            "(empty.tex)\n",
+            "*\\end\n",
+            "No pages of output.\n",
+        ),
+        String::from_utf8_lossy(&renamed_log).as_ref()
+    );
+}
+
+#[cfg(not(feature = "wide_pointers"))]
+#[test]
+fn initex_rejects_inconsistent_capacities() {
+    let term_output = prepare_pool().install(|| {
+        TeXTestVFS::default().install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            assert!(globals.set_dvi_buf_size(801).is_err());
+            assert!(globals.set_max_in_open(128).is_err());
+            assert!(globals.set_mem_max(29999).is_err());
+            assert!(globals.set_dvi_buf_size(0).is_err());
+            assert!(globals.set_buf_size(20).is_err());
+            assert!(globals.set_stack_size(0).is_err());
+            assert!(globals.set_font_mem_size(6).is_err());
+            assert!(globals.set_save_size(5).is_err());
+            assert!(globals.set_trie_size(255).is_err());
+            // INITEX builds `mem` from scratch, so it must end at `mem_top`.
+            globals.set_mem_max(30001).unwrap();
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| vfs.dump_current_term_out().unwrap())
+    });
+    assert_eq!(
+        "Ouch---my internal constants have been clobbered!---case 10\n",
+        String::from_utf8_lossy(&term_output).as_ref()
+    );
+}
+
+#[test]
+fn initex_runs_with_the_smallest_capacities() {
+    let (term_output, small_log) = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_termin(b"\\catcode`\\{=1 \\catcode`\\}=2 {}\\dump\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            globals.set_job_name("small");
+            globals.set_stack_size(1).unwrap();
+            globals.set_font_max(0).unwrap();
+            globals.set_font_mem_size(7).unwrap();
+            globals.set_save_size(6).unwrap();
+            globals.set_trie_size(256).unwrap();
+            globals.set_dvi_buf_size(8).unwrap();
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| {
+            let term_output = vfs.dump_current_term_out().unwrap();
+            let small_log = vfs.dump_file("small.log").unwrap();
+            (term_output, small_log)
+        })
+    });
+    let small_log = String::from_utf8_lossy(&small_log);
+    assert!(small_log.contains("0 words of font info for 0 preloaded fonts"));
+    assert!(small_log.contains("Hyphenation trie of length 256 has 0 ops out of 500"));
+    assert!(String::from_utf8_lossy(&term_output).ends_with("Transcript written on small.log."));
+
+    let term_output = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_termin(b"\\end\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            globals.set_buf_size(21).unwrap();
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| vfs.dump_current_term_out().unwrap())
+    });
+    assert_eq!(
+        concat!(
+            "This is TeX-rs, Version 3.141592653 (INITEX)\n",
+            "**No pages of output.\n",
+            "Transcript written on texput.log."
+        ),
+        String::from_utf8_lossy(&term_output).as_ref()
+    );
+}
+
+#[test]
+fn initex_unicode_code_tables() {
+    let run = |source: &'static [u8]| {
+        prepare_pool().install(|| {
+            TeXTestVFS::default()
+                .and_then_prepare_file("codes.tex", source)
+                .and_then_prepare_termin(b"codes\n")
+                .install_as_current();
+            if let mut globals = tex::TeXGlobals::default() {
+                use tex::configure::TeXConfiguration;
+                configure_for_test(&mut globals);
+                globals.set_unicode_support(true);
+                tex::entry(&mut globals);
+            }
+            TeXTestVFS::with_current(|vfs| {
+                let codes_log = vfs.dump_file("codes.log").unwrap();
+                let codes_fmt = vfs.dump_file("codes.fmt").unwrap();
+                (codes_log, codes_fmt)
+            })
+        })
+    };
+    let (codes_log, _) = run(concat!(
+        "\\catcode`\\{=1 \\catcode`\\}=2 \\batchmode\n",
+        "\\message{[\\the\\catcode\"1F600,\\the\\sfcode\"1F600,\\the\\delcode\"1F600]}\n",
+        "{\\catcode\"1F600=11 \\delcode\"1F600=7 \\message{[\\the\\catcode\"1F600,\\the\\delcode\"1F600]}}\n",
+        "\\message{[\\the\\catcode\"1F600,\\the\\delcode\"1F600]}\n",
+        "{\\global\\lccode\"1F600=\"4E00 }\\message{[\\the\\lccode\"1F600]}\n",
+        "\\dump\n"
+    )
+    .as_bytes());
+    let codes_log = String::from_utf8_lossy(&codes_log);
+    assert!(codes_log.contains("[12,1000,-1]"));
+    assert!(codes_log.contains("[11,7]"));
+    assert!(codes_log.contains("[12,-1]"));
+    assert!(codes_log.contains("[19968]"));
+    // Reading codes beyond 255 leaves the tables as they were, so the format
+    // dumped afterwards is the same as one from a run that never read them.
+    let (_, read_fmt) = run(concat!(
+        "\\count1=\\catcode\"1F600 \\count2=\\mathcode\"1F601\n",
+        "\\count1=0 \\count2=0 \\dump\n"
+    )
+    .as_bytes());
+    let (_, plain_fmt) = run(b"\\count1=0 \\count2=0 \\dump\n");
+    assert!(read_fmt == plain_fmt);
+}
+
+#[test]
+fn initex_grapheme_clusters() {
+    let run = |handling| {
+        prepare_pool().install(|| {
+            TeXTestVFS::default()
+                .and_then_prepare_file(
+                    "clusters.tex",
+                    concat!(
+                        "\\catcode`\\{=1 \\catcode`\\}=2 \\batchmode\n",
+                        "\\message{[\\number`q\u{301},\\number`\\q\u{301}]}\n",
+                        "\\count1=`\\relax \\message{[\\the\\count1]}\n",
+                        "\\end\n"
+                    )
+                    .as_bytes(),
+                )
+                .and_then_prepare_termin(b"clusters\n")
+                .install_as_current();
+            if let mut globals = tex::TeXGlobals::default() {
+                use tex::configure::TeXConfiguration;
+                configure_for_test(&mut globals);
+                globals.set_unicode_support(true);
+                globals.set_grapheme_cluster_handling(handling);
+                tex::entry(&mut globals);
+            }
+            TeXTestVFS::with_current(|vfs| vfs.dump_file("clusters.log").unwrap())
+        })
+    };
+    let interned_log = run(tex::configure::GraphemeClusterHandling::Intern);
+    let interned_log = String::from_utf8_lossy(&interned_log);
+    // the first interned cluster comes right after the last scalar value
+    assert!(interned_log.contains("[1114112,1114112]"));
+    assert!(interned_log.contains("! Improper alphabetic constant."));
+    assert!(interned_log.contains("[48]"));
+    let nfc_log = run(tex::configure::GraphemeClusterHandling::NormalizeNfc);
+    let nfc_log = String::from_utf8_lossy(&nfc_log);
+    assert!(nfc_log.contains("[113\u{301},113\u{301}]"));
+    assert!(nfc_log.contains("[48]"));
+}
+
+#[test]
+fn initex_unicode_control_sequences() {
+    let (names_log, names_fmt) = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file(
+                "names.tex",
+                concat!(
+                    "\\catcode`\\{=1 \\catcode`\\}=2 \\catcode`\\#=6 \\batchmode\n",
+                    "\\catcode\"F6=11 \\catcode\"DF=11 \\catcode\"540D=11 \\catcode\"524D=11\n",
+                    "\\def\\größe{G}\\def\\名前#1{[#1]}\\def\\名{N}\n",
+                    "\\message{\\größe\\名前{x}\\名\\expandafter\\string\\csname 名前\\endcsname}\n",
+                    "\\message{\\string\\名\\meaning\\名前}\n",
+                    "\\dump\n"
+                )
+                .as_bytes(),
+            )
+            .and_then_prepare_termin(b"names\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            globals.set_unicode_support(true);
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| {
+            let names_log = vfs.dump_file("names.log").unwrap();
+            let names_fmt = vfs.dump_file("names.fmt").unwrap();
+            (names_log, names_fmt)
+        })
+    });
+    let names_log = String::from_utf8_lossy(&names_log);
+    assert!(names_log.contains("G[x]N\\名前"));
+    assert!(names_log.contains("\\名macro:#1->[#1]"));
+    let reload_log = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file("names.fmt", &names_fmt)
+            .and_then_prepare_file(
+                "reload.tex",
+                concat!(
+                    "\\message{\\名前{y}\\string\\größe\\expandafter\\meaning\\csname größe\\endcsname}\n",
+                    "\\end\n"
+                )
+                .as_bytes(),
+            )
+            .and_then_prepare_termin(b"&names reload\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            globals.set_unicode_support(true);
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| vfs.dump_file("reload.log").unwrap())
+    });
+    let reload_log = String::from_utf8_lossy(&reload_log);
+    assert!(reload_log.contains("[y]\\größemacro:->G"));
+}
+
+/// Run INITEX with `latex_support` on `name.tex` holding `source`, next to
+/// `other_files`, and return what it wrote to the terminal and to `name.log`.
+fn run_latex_support_job(
+    name: &str,
+    source: &[u8],
+    other_files: &[(&str, &[u8])],
+) -> (String, String) {
+    let (term_output, log) = prepare_pool().install(|| {
+        let mut vfs = TeXTestVFS::default().and_then_prepare_file(&format!("{}.tex", name), source);
+        for (other_name, contents) in other_files {
+            vfs = vfs.and_then_prepare_file(other_name, contents);
+        }
+        vfs.and_then_prepare_termin(format!("{}\n", name).as_bytes())
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            globals.set_latex_support(true);
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| {
+            let term_output = vfs.dump_current_term_out().unwrap();
+            let log = vfs.dump_file(&format!("{}.log", name)).unwrap();
+            (term_output, log)
+        })
+    });
+    (
+        String::from_utf8_lossy(&term_output).into_owned(),
+        String::from_utf8_lossy(&log).into_owned(),
+    )
+}
+
+#[test]
+fn initex_latex_support_conditionals() {
+    let (term_output, ifs_log) = run_latex_support_job(
+        "ifs",
+        concat!(
+            "\\catcode`\\{=1 \\catcode`\\}=2 \\def\\a{}\n",
+            "\\message{\\ifdefined\\a Y\\else N\\fi\n",
+            "\\ifdefined\\b Y\\else N\\fi\n",
+            "\\ifcsname a\\endcsname Y\\else N\\fi\n",
+            "\\ifcsname b\\endcsname Y\\else N\\fi\n",
+            "\\unless\\ifdefined\\b Y\\else N\\fi}\n",
+            "\\tracingcommands=2 \\unless\\ifx\\a\\a\\fi\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+        &[],
+    );
+    assert!(term_output.contains("YNYNY"));
+    assert!(ifs_log.contains("{vertical mode: \\unless}"));
+    assert!(ifs_log.contains("{\\unless\\ifx}"));
+    assert!(ifs_log.contains("{false}"));
+}
+
+#[test]
+fn initex_latex_support_expressions() {
+    let (_, expr_log) = run_latex_support_job(
+        "expr",
+        concat!(
+            "\\catcode`\\{=1 \\catcode`\\}=2 \\batchmode\n",
+            "\\message{\\the\\numexpr 7*(3+4)/2\\relax,\n",
+            "\\the\\dimexpr 1pt*3/4\\relax,\n",
+            "\\the\\glueexpr 1pt plus 2fil*2\\relax,\n",
+            "\\the\\muexpr 3mu-1mu\\relax}\n",
+            "\\message{\\the\\numexpr 2147483647+1\\relax}\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+        &[],
+    );
+    assert!(expr_log.contains("25, 0.75pt, 2.0pt plus 4.0fil, 2.0mu"));
+    assert!(expr_log.contains("! Arithmetic overflow."));
+}
+
+#[test]
+fn initex_latex_support_protected_and_unexpanded() {
+    let (_, prot_log) = run_latex_support_job(
+        "prot",
+        concat!(
+            "\\catcode`\\{=1 \\catcode`\\}=2 \\batchmode\n",
+            "\\protected\\def\\p{P}\\protected\\long\\def\\l{L}\\def\\q{Q}\n",
+            "\\edef\\r{\\p\\q\\unexpanded{\\q}\\detokenize{\\q}}\n",
+            "\\message{[\\meaning\\r]}\\message{[\\meaning\\p]}\n",
+            "\\message{[\\meaning\\l]}\n",
+            "\\protected\\let\\s=\\q\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+        &[],
+    );
+    assert!(prot_log.contains("[macro:->\\p Q\\q \\q ]"));
+    assert!(prot_log.contains("[\\protected macro:->P]"));
+    assert!(prot_log.contains("[\\protected\\long macro:->L]"));
+    assert!(
+        prot_log.contains("! You can't use `\\long' or `\\outer' or `\\protected' with `\\let'.")
+    );
+}
+
+#[test]
+fn initex_latex_support_scantokens_and_readline() {
+    let (_, scan_log) = run_latex_support_job(
+        "scan",
+        concat!(
+            "\\catcode`\\{=1 \\catcode`\\}=2 \\batchmode\n",
+            "\\everyeof{E\\noexpand}\\message{[\\scantokens{a\\relax b}]}\n",
+            "\\scantokens{\\undefined}\n",
+            "\\endlinechar=-1 \\openin1=rl \\readline1 to\\x \\message{[\\meaning\\x]}\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+        &[("rl.tex", b"a{b} %c\n")],
+    );
+    assert!(scan_log.contains("[a\\relax b E]"));
+    assert!(scan_log.contains("<scantokens> l.1 \\undefined"));
+    assert!(scan_log.contains("l.3 \\scantokens{\\undefined}"));
+    assert!(scan_log.contains("[macro:->a{b} %c]"));
+}
+
+#[test]
+fn initex_latex_support_sparse_registers() {
+    let (_, reg_log) = run_latex_support_job(
+        "reg",
+        concat!(
+            "\\catcode`\\{=1 \\catcode`\\}=2 \\batchmode\n",
+            "\\countdef\\c=300 \\c=5\n",
+            "{\\count300=7 \\global\\advance\\count4000 by 2 \\dimen32767=3pt}\n",
+            "\\message{[\\the\\c,\\the\\count4000,\\the\\dimen32767]}\n",
+            "\\toks4000={x}\\message{[\\the\\toks4000]}\n",
+            "\\setbox32767=\\hbox{}\\message{[\\ifvoid32767 V\\else N\\fi]}\n",
+            "\\count32768=1\n",
+            "\\setbox300=\\hbox{}\\showbox300\n",
+            "\\setbox300=\\vbox{\\hrule height 2pt\\penalty-10000\\hrule height 3pt}\n",
+            "\\setbox301=\\copy300 \\message{[\\ifvoid300 V\\else N\\fi\\ifvoid301 V\\else N\\fi]}\n",
+            "\\setbox302=\\vsplit300 to 2pt \\message{[\\the\\ht302,\\the\\ht300]}\n",
+            "\\setbox303=\\hbox{\\box301}\\message{[\\ifvoid301 V\\else N\\fi\\the\\ht303]}\n",
+            "\\countdef\\e=400 \\let\\f=\\e \\let\\e=\\relax \\f=10 \\message{[\\the\\f,\\the\\count400]}\n",
+            "{\\let\\g=\\f \\g=11}\\message{[\\the\\count400]}\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+        &[],
+    );
+    assert!(reg_log.contains("[5,2,0.0pt]"));
+    assert!(reg_log.contains("[x]"));
+    assert!(reg_log.contains("[N]"));
+    assert!(reg_log.contains("! Bad register code (32768)."));
+    assert!(reg_log.contains("A register number must be between 0 and 32767."));
+    assert!(reg_log.contains("> \\box300=\n\\hbox(0.0+0.0)x0.0\n"));
+    assert!(reg_log.contains("[NN] [2.0pt,3.0pt] [V5.0pt] [10,10] [10]"));
+}
+
+#[test]
+fn initex_latex_support_sparse_register_dump() {
+    let (sparse_log, sparse_fmt) = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file(
+                "sparse.tex",
+                concat!(
+                    "\\catcode`\\{=1 \\catcode`\\}=2 \\batchmode\n",
+                    "\\count300=42 \\toks4000={abc}\\setbox300=\\hbox to 7pt{}\n",
+                    "\\dump\n"
+                )
+                .as_bytes(),
+            )
+            .and_then_prepare_termin(b"sparse\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            globals.set_latex_support(true);
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| {
+            let sparse_log = vfs.dump_file("sparse.log").unwrap();
+            let sparse_fmt = vfs.dump_file("sparse.fmt").unwrap();
+            (sparse_log, sparse_fmt)
+        })
+    });
+    let sparse_log = String::from_utf8_lossy(&sparse_log);
+    assert!(sparse_log.contains("Beginning to dump on file sparse.fmt"));
+    let reload_log = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file("sparse.fmt", &sparse_fmt)
+            .and_then_prepare_file(
+                "reload.tex",
+                concat!(
+                    "\\message{[\\the\\count300,\\the\\toks4000,\\the\\wd300]}\n",
+                    "\\showbox300\n",
+                    "\\end\n"
+                )
+                .as_bytes(),
+            )
+            .and_then_prepare_termin(b"&sparse reload\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            use tex::configure::TeXConfiguration;
+            configure_for_test(&mut globals);
+            globals.set_latex_support(true);
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| vfs.dump_file("reload.log").unwrap())
+    });
+    let reload_log = String::from_utf8_lossy(&reload_log);
+    assert!(reload_log.contains("[42,abc,7.0pt]"));
+    assert!(reload_log.contains("> \\box300=\n\\hbox(0.0+0.0)x7.0\n"));
+}
+
+#[cfg(feature = "wide_pointers")]
+#[test]
+fn initex_wide_pointers_dump_and_reload() {
+    // The one-word region starts just below `mem_top`, far above 65535, so
+    // every token list and box here lives at a wide pointer.
+    let (wide_log, wide_fmt) = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file(
+                "wide.tex",
+                concat!(
+                    "\\catcode`\\{=1 \\catcode`\\}=2 \\catcode`\\#=6 \\batchmode\n",
+                    "\\def\\greet#1{[#1]}\\setbox1=\\hbox to 7pt{}\n",
+                    "\\dump\n"
+                )
+                .as_bytes(),
+            )
+            .and_then_prepare_termin(b"wide\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            configure_for_test(&mut globals);
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| {
+            let wide_log = vfs.dump_file("wide.log").unwrap();
+            let wide_fmt = vfs.dump_file("wide.fmt").unwrap();
+            (wide_log, wide_fmt)
+        })
+    });
+    let wide_log = String::from_utf8_lossy(&wide_log);
+    assert!(wide_log.contains("Beginning to dump on file wide.fmt"));
+    let reload_log = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file("wide.fmt", &wide_fmt)
+            .and_then_prepare_file(
+                "reload.tex",
+                concat!("\\message{\\greet{wide}}\n", "\\showbox1\n", "\\end\n").as_bytes(),
+            )
+            .and_then_prepare_termin(b"&wide reload\n")
+            .install_as_current();
+        if let mut globals = tex::TeXGlobals::default() {
+            configure_for_test(&mut globals);
+            tex::entry(&mut globals);
+        }
+        TeXTestVFS::with_current(|vfs| vfs.dump_file("reload.log").unwrap())
+    });
+    let reload_log = String::from_utf8_lossy(&reload_log);
+    assert!(reload_log.contains("[wide]"));
+    assert!(reload_log.contains("> \\box1=\n\\hbox(0.0+0.0)x7.0\n"));
+}
+
+#[cfg(not(feature = "wide_pointers"))]
 const PLAIN_DMP_RECORD: &[u8] = include_bytes!("../tests_data/plain_dmp/plain.fmt");
 
+#[cfg(not(feature = "wide_pointers"))]
 #[test]
 fn initex_plain_dump() {
     let (term_output, plain_log, plain_dmp) = prepare_pool().install(|| {
@@ -176,8 +703,9 @@ fn initex_plain_dump() {
             .and_then_prepare_file("cmtt9.tfm", include_bytes!("../tests_data/plain/cmtt9.tfm"))
             .and_then_prepare_file("cmu10.tfm", include_bytes!("../tests_data/plain/cmu10.tfm"))
             .and_then_prepare_termin(concat!("plain\n", "\\dump\n").as_bytes())
-            .install_as_tex_io_handler();
+            .install_as_current();
         if let mut globals = tex::TeXGlobals::default() {
+            configure_for_test(&mut globals);
             tex::entry(&mut globals);
         }
         TeXTestVFS::with_current(|vfs| {
@@ -196,7 +724,7 @@ fn initex_plain_dump() {
             ")\n",
             "*Beginning to dump on file plain.fmt\n",
             " (preloaded format=plain 1776.7.4)\n",
-            "1626 strings of total length 15262\n",
+            "1959 strings of total length 27452\n",
             "4990 memory locations dumped; current usage is 110&4877\n",
             "926 multiletter control sequences\n",
             "\\font\\nullfont=nullfont\n",
@@ -308,7 +836,7 @@ fn initex_plain_dump() {
             "*\\dump\n",
             "Beginning to dump on file plain.fmt\n",
             " (preloaded format=plain 1776.7.4)\n",
-            "1626 strings of total length 15262\n",
+            "1959 strings of total length 27452\n",
             "4990 memory locations dumped; current usage is 110&4877\n",
             "926 multiletter control sequences\n",
             "\\font\\n",
@@ -1,8 +1,11 @@
+// The `plain.fmt` fixture is dumped by the build with 16-bit halfwords.
+#![cfg(not(feature = "wide_pointers"))]
 #![allow(irrefutable_let_patterns)]
 
 #[path = "auxiliary/vfs.rs"]
 mod vfs;
 
+use vfs::configure_for_test;
 use vfs::prepare_pool;
 use vfs::TeXTestVFS;
 
@@ -14,8 +17,9 @@ fn plain_hello_world() {
         TeXTestVFS::default()
             .and_then_prepare_file("plain.fmt", PLAIN_DMP_RECORD)
             .and_then_prepare_termin(concat!("&plain\n", "Hello world\n", "\\end\n").as_bytes())
-            .install_as_tex_io_handler();
+            .install_as_current();
         if let mut globals = tex::TeXGlobals::default() {
+            configure_for_test(&mut globals);
             tex::entry(&mut globals);
         }
         TeXTestVFS::with_current(|vfs| {
@@ -53,3 +57,630 @@ fn plain_hello_world() {
         include_bytes!("../tests_data/dvi_result/plain_helloworld.dvi");
     assert_eq!(plain_helloworld_dvi_record, &texput_dvi[..]);
 }
+
+/// Run the job `name` on `name.tex` holding `source`, with the plain format
+/// loaded, after `setup` has had its say on the job.
+fn run_plain_job(
+    name: &str,
+    source: &[u8],
+    setup: impl FnOnce(tex::Job) -> tex::Job + Send,
+) -> tex::JobResult {
+    prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file("plain.fmt", PLAIN_DMP_RECORD)
+            .and_then_prepare_file(&format!("{}.tex", name), source)
+            .install_as_current();
+        let mut job = tex::Job::new(name).format("plain").unwrap();
+        configure_for_test(job.configuration());
+        setup(job).run()
+    })
+}
+
+/// Run the job `name` in batch mode, so that errors are only logged.
+fn run_plain_batch_job(name: &str, source: &[u8]) -> tex::JobResult {
+    run_plain_job(name, source, |job| {
+        job.interaction(tex::configure::InteractionMode::Batch)
+    })
+}
+
+#[test]
+fn plain_hello_world_job() {
+    let result = run_plain_job("hello", b"Hello world\n\\end\n", |job| job);
+    assert_eq!(tex::History::Spotless, result.history);
+    assert_eq!(1, result.total_pages);
+    assert_eq!(
+        vec!["hello.log".to_owned(), "hello.dvi".to_owned()],
+        result.files_written
+    );
+    assert!(result
+        .log
+        .unwrap()
+        .ends_with("Output written on hello.dvi (1 page, 224 bytes).\n"));
+    let plain_helloworld_dvi_record =
+        include_bytes!("../tests_data/dvi_result/plain_helloworld.dvi");
+    assert_eq!(&plain_helloworld_dvi_record[..], &result.dvi.unwrap()[..]);
+}
+
+#[cfg(feature = "pdf_support")]
+#[test]
+fn plain_pdf_output_job() {
+    use tex::configure::{OutputDriver, TeXConfiguration};
+    let source = concat!("Hello world\\hrule\n", "\\end\n").as_bytes();
+    let result = run_plain_job("hello", source, |mut job| {
+        job.configuration().set_output_driver(OutputDriver::Pdf);
+        job
+    });
+    assert_eq!(tex::History::Spotless, result.history);
+    assert_eq!(1, result.total_pages);
+    assert_eq!(
+        vec!["hello.log".to_owned(), "hello.pdf".to_owned()],
+        result.files_written
+    );
+    let pdf = result.dvi.unwrap();
+    assert!(result.log.unwrap().ends_with(&format!(
+        "Output written on hello.pdf (1 page, {} bytes).\n",
+        pdf.len()
+    )));
+    let pdf = String::from_utf8_lossy(&pdf).into_owned();
+    assert!(pdf.starts_with("%PDF-1.4\n"));
+    assert!(pdf.ends_with("%%EOF\n"));
+    // the page is as wide as `\hsize` plus one inch on either side
+    assert!(pdf.contains("/MediaBox [0 0 612.0000 808.7099]"));
+    // `H`, indented by `\parindent`
+    assert!(pdf.contains("BT /F1 9.9626 Tf 91.9253 726.7472 Td <48> Tj ET\n"));
+    assert!(pdf.contains("72.0000 726.3487 468.0000 0.3985 re f\n"));
+    assert!(pdf.contains("/Type /Font /Subtype /Type1 /BaseFont /CMR10 /FirstChar 0 /LastChar 127"));
+
+    let result = run_plain_job("hello", source, |mut job| {
+        let configuration = job.configuration();
+        configuration.set_output_driver(OutputDriver::Pdf);
+        assert!(configuration.set_pdf_page_size(0.0, 842.0).is_err());
+        configuration.set_pdf_page_size(595.0, 842.0).unwrap();
+        job
+    });
+    let pdf = String::from_utf8_lossy(&result.dvi.unwrap()).into_owned();
+    assert!(pdf.contains("/MediaBox [0 0 595.0000 842.0000]"));
+    assert!(pdf.contains("BT /F1 9.9626 Tf 91.9253 760.0374 Td <48> Tj ET\n"));
+}
+
+#[test]
+fn plain_special_job() {
+    let result = run_plain_job(
+        "special",
+        concat!(
+            "\\setbox0\\hbox{\\special{color push}}\\copy0\\box0\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+        |job| job,
+    );
+    assert_eq!(tex::History::Spotless, result.history);
+    assert_eq!(1, result.total_pages);
+    let dvi = result.dvi.unwrap();
+    // `xxx1`, the length, then the text of the special; once for the copy, once for the box
+    let special = b"\xef\x0acolor push";
+    let occurrences = dvi
+        .windows(special.len())
+        .filter(|window| window == special)
+        .count();
+    assert_eq!(2, occurrences);
+}
+
+#[test]
+fn plain_vsplit_past_whatsit_job() {
+    let result = run_plain_batch_job(
+        "whatsit",
+        concat!(
+            "\\setbox0\\vbox{\\hbox{a}\\special{s}\\penalty0\\hbox{b}\\penalty0\\hbox{c}}\n",
+            "\\setbox1\\vsplit0 to 12pt \\showbox1 \\showbox0\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+    );
+    assert_eq!(tex::History::ErrorMessageIssued, result.history);
+    let log = result.log.unwrap();
+    // the whatsit is not a breakpoint, so the split comes after it
+    assert!(log.contains("..\\tenrm a\n.\\special{s}\n\n! OK."));
+    assert!(log
+        .contains(".\\glue(\\splittopskip) 3.05556\n.\\hbox(6.94444+0.0)x5.55557\n..\\tenrm b\n"));
+}
+
+#[test]
+fn plain_vsplit_at_kern_job() {
+    let result = run_plain_batch_job(
+        "kern",
+        concat!(
+            "\\setbox0\\vbox{\\hbox{a}\\kern3pt\\nointerlineskip\\hbox{b}\\penalty-10000\\hbox{c}}\n",
+            "\\setbox1\\vsplit0 to 30pt \\showbox1 \\showbox0\n",
+            "\\setbox0\\vbox{\\hbox{a}\\kern3pt\\vskip0pt\\hbox{b}}\n",
+            "\\setbox1\\vsplit0 to 9pt \\showbox1 \\showbox0\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+    );
+    assert_eq!(tex::History::ErrorMessageIssued, result.history);
+    let log = result.log.unwrap();
+    // a kern not followed by glue is no breakpoint, and the forced break is taken
+    assert!(log.contains(concat!(
+        "> \\box1=\n\\vbox(30.0+0.0)x5.55557\n.\\hbox(4.30554+0.0)x5.00002\n..\\tenrm a\n",
+        ".\\kern 3.0\n.\\hbox(6.94444+0.0)x5.55557\n..\\tenrm b\n"
+    )));
+    assert!(log
+        .contains(".\\glue(\\splittopskip) 5.69446\n.\\hbox(4.30554+0.0)x4.44444\n..\\tenrm c\n"));
+    // a kern followed by glue is a breakpoint, and both vanish at the top of the rest
+    assert!(log.contains(
+        "> \\box1=\n\\vbox(9.0+0.0)x5.00002\n.\\hbox(4.30554+0.0)x5.00002\n..\\tenrm a\n\n! OK."
+    ));
+    assert!(log.contains(concat!(
+        "> \\box0=\n\\vbox(10.0+0.0)x5.55557\n.\\glue(\\splittopskip) 3.05556\n",
+        ".\\hbox(6.94444+0.0)x5.55557\n..\\tenrm b\n\n! OK."
+    )));
+}
+
+#[test]
+fn plain_marks_job() {
+    let result = run_plain_job(
+        "marks",
+        concat!(
+            "\\setbox0\\vbox{\\mark{a}\\hbox{}\\mark{b}}\\setbox1\\vsplit0 to 100pt\n",
+            "\\message{[\\splitfirstmark/\\splitbotmark]}\n",
+            "\\output{\\message{[\\topmark/\\firstmark/\\botmark]}\\shipout\\box255}\n",
+            "\\mark{c}x\\mark{d}\\vfill\\penalty-10000\n",
+            "y\\vfill\\penalty-10000\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+        |job| job,
+    );
+    assert_eq!(tex::History::WarningIssued, result.history);
+    assert_eq!(2, result.total_pages);
+    let log = result.log.unwrap();
+    assert!(log.contains("Underfull \\vbox (badness 10000) detected at line 1"));
+    assert!(log.contains("[a/b]"));
+    assert!(log.contains("[/c/d]"));
+    assert!(log.contains("[d/d/d]"));
+}
+
+#[test]
+fn plain_default_output_job() {
+    let result = run_plain_job(
+        "default",
+        concat!(
+            "\\output{}\\hbox{one}\\vfill\\penalty-10000\n",
+            "\\hbox{two}\\vfill\\penalty-10000\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+        |job| job,
+    );
+    assert_eq!(tex::History::Spotless, result.history);
+    assert_eq!(2, result.total_pages);
+    assert!(result.dvi.is_some());
+}
+
+#[test]
+fn plain_insertion_split_job() {
+    let result = run_plain_batch_job(
+        "insert",
+        concat!(
+            "\\tracingpages=1 \\vsize=100pt \\dimen\\footins=20pt\n",
+            "\\output{\\shipout\\box255 \\setbox0\\box\\footins}\n",
+            "\\hbox{x}\\insert\\footins{\\hbox{a}\\hbox{b}\\hbox{c}\\hbox{d}}\\hbox{y}\\showlists\n",
+            "\\vfill\\penalty-10000\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+    );
+    assert_eq!(tex::History::ErrorMessageIssued, result.history);
+    assert_eq!(3, result.total_pages);
+    let log = result.log.unwrap();
+    assert!(
+        log.contains("\\insert254, natural size 40.30554; split(10.0,16383.99998); float cost 0\n")
+    );
+    assert!(log.contains(" goal height 71.69446\n\\insert254 adds 16.30554, #1 might split\n"));
+    // the page builder traces its decisions only when TeX keeps statistics
+    if cfg!(feature = "statistics") {
+        assert!(
+            log.contains("%% goal height=100.0, max depth=4.0\n% split254 to 20.0,16.30554 p=0\n")
+        );
+        assert!(log.contains("% split254 to 20.0,10.0 p=0\n"));
+    }
+}
+
+#[test]
+fn plain_group_mismatch_job() {
+    let result = run_plain_batch_job(
+        "mismatch",
+        concat!(
+            "\\endgroup\n",
+            "\\begingroup }\\endgroup\n",
+            "$x}$\n",
+            "$\\left( x $\n",
+            "\\hbox{\\endgroup}\n",
+            "\\hbox{\\hrule}\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+    );
+    assert_eq!(tex::History::ErrorMessageIssued, result.history);
+    let log = result.log.unwrap();
+    assert!(log.contains("! Extra \\endgroup.\nl.1 \\endgroup\n"));
+    assert!(log.contains("! Extra }, or forgotten \\endgroup.\nl.2 \\begingroup }\n"));
+    assert!(log.contains("! Extra }, or forgotten $.\nl.3 $x}\n"));
+    assert!(
+        log.contains("! Missing \\right. inserted.\n<inserted text> \n                \\right .\n")
+    );
+    assert!(log.contains("! Missing } inserted.\n<inserted text> \n                }\n"));
+    assert!(log.contains("! You can't use `\\hrule' here except with leaders.\n"));
+    assert!(!log.contains("occurred inside a group"));
+}
+
+#[test]
+fn plain_math_noads_job() {
+    let result = run_plain_batch_job(
+        "noads",
+        concat!(
+            "\\setbox0\\hbox{$\\mathchoice{{a\\over b}\\left(x\\right)\\scriptstyle y}{t}{s}{ss}$}\n",
+            "\\setbox0\\hbox{$\\displaystyle{a\\over b}\\left(x\\right)\\mathchoice{a}{b}{c}{d}\\showlists$}\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+    );
+    // `\showlists` stops like an error does
+    assert_eq!(tex::History::ErrorMessageIssued, result.history);
+    let log = result.log.unwrap();
+    assert!(log.contains("\\displaystyle"));
+    assert!(log.contains("\\fraction, thickness = default"));
+    assert!(log.contains("\\left\"28300"));
+    assert!(log.contains("\\mathchoice"));
+}
+
+#[test]
+fn plain_alignment_errors_job() {
+    let result = run_plain_batch_job(
+        "align",
+        concat!(
+            "\\setbox0\\vbox{\\halign{a&#b#\\cr x&y\\cr}}\n",
+            "\\omit\\noalign{}\n",
+            "\\halign{#\\cr\\begingroup a\\cr}\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+    );
+    assert_eq!(tex::History::ErrorMessageIssued, result.history);
+    let log = result.log.unwrap();
+    assert!(log.contains("! Missing # inserted in alignment preamble."));
+    assert!(log.contains("! Only one # is allowed per tab."));
+    assert!(log.contains("! Misplaced \\omit."));
+    assert!(log.contains("! Misplaced \\noalign."));
+    assert!(log.contains("! Missing \\endgroup inserted."));
+}
+
+#[test]
+fn plain_interwoven_preambles_job() {
+    let result = run_plain_batch_job(
+        "interwoven",
+        concat!(
+            "\\halign{#\\global\\futurelet\\e\\relax\\cr a\\cr}\n",
+            "\\halign{#\\cr\\e\\cr}\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+    );
+    assert_eq!(tex::History::FatalErrorStop, result.history);
+    let log = result.log.unwrap();
+    assert!(log.contains("! Emergency stop.\n<recently read> \\endtemplate \n"));
+    assert!(log.contains("(interwoven alignment preambles are not allowed)\n"));
+}
+
+#[test]
+fn plain_runaway_job() {
+    let result = run_plain_batch_job(
+        "runaway",
+        concat!(
+            "\\def\\a#1{}\\a{x\\par}\n",
+            "\\a}\n",
+            "\\outer\\def\\b{}\\def\\c{\\b}\n",
+            "\\end\n"
+        )
+        .as_bytes(),
+    );
+    assert_eq!(tex::History::ErrorMessageIssued, result.history);
+    let log = result.log.unwrap();
+    assert!(log.contains("Runaway argument?"));
+    assert!(log.contains("! Paragraph ended before \\a was complete."));
+    assert!(log.contains("! Argument of \\a has an extra }."));
+    assert!(log.contains("Runaway definition?"));
+    assert!(log.contains("! Forbidden control sequence found while scanning definition of \\c."));
+}
+
+#[test]
+fn plain_expansion_step_limit_job() {
+    let result = run_plain_job("loop", b"\\def\\a{\\a}\\a\n", |job| {
+        job.limits(tex::limits::Limits {
+            max_expansion_steps: Some(10000),
+            ..Default::default()
+        })
+    });
+    assert_eq!(tex::History::FatalErrorStop, result.history);
+    assert_eq!(0, result.total_pages);
+    let log = result.log.unwrap();
+    assert!(log.contains("! Emergency stop.\n"));
+    assert!(log.contains("*** (job aborted, expansion step limit exceeded)\n"));
+}
+
+#[test]
+fn plain_page_limit_job() {
+    let source = b"\\def\\p{x\\vfill\\penalty-10000 }\\p\\p\\p\\end\n";
+    let result = run_plain_job("pages", source, |job| {
+        job.limits(tex::limits::Limits {
+            max_pages: Some(2),
+            ..Default::default()
+        })
+    });
+    assert_eq!(tex::History::FatalErrorStop, result.history);
+    assert_eq!(2, result.total_pages);
+    let log = result.log.unwrap();
+    assert!(log.contains("*** (job aborted, page limit exceeded)\n"));
+    assert!(log.contains("Output written on pages.dvi (2 pages, "));
+}
+
+#[test]
+fn plain_output_byte_budget_job() {
+    // a single page of more than 2000 rules, which outgrows the budget before it is shipped out
+    let source = concat!(
+        "\\def\\r{\\vrule height1pt\\kern1pt}\\def\\s{\\r\\r\\r\\r\\r\\r\\r\\r\\r\\r}\n",
+        "\\def\\t{\\s\\s\\s\\s\\s\\s\\s\\s\\s\\s}\n",
+        "\\hbox to 1pt{\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\t\\hss}\\end\n"
+    );
+    let result = run_plain_job("budget", source.as_bytes(), |job| {
+        job.limits(tex::limits::Limits {
+            output_byte_budget: Some(1000),
+            ..Default::default()
+        })
+    });
+    assert_eq!(tex::History::FatalErrorStop, result.history);
+    // the page is still closed, and the postamble written, once the job has stopped
+    assert_eq!(1, result.total_pages);
+    assert!(result.dvi.unwrap().len() < 2000);
+    let log = result.log.unwrap();
+    assert!(log.contains("*** (job aborted, output byte budget exceeded)\n"));
+}
+
+#[test]
+fn plain_time_budget_job() {
+    let result = run_plain_job("forever", b"\\def\\a{\\a}\\a\n", |job| {
+        job.limits(tex::limits::Limits {
+            time_budget: Some(std::time::Duration::from_millis(100)),
+            ..Default::default()
+        })
+    });
+    assert_eq!(tex::History::FatalErrorStop, result.history);
+    let log = result.log.unwrap();
+    assert!(log.contains("*** (job aborted, time budget exceeded)\n"));
+}
+
+#[test]
+fn plain_cancelled_job() {
+    let result = run_plain_job("cancelled", b"\\def\\a{\\a}\\a\n", |job| {
+        let handle = job.cancellation_handle();
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            handle.cancel();
+        });
+        job
+    });
+    assert_eq!(tex::History::FatalErrorStop, result.history);
+    let log = result.log.unwrap();
+    assert!(log.contains("*** (job aborted, cancelled)\n"));
+}
+
+#[test]
+fn plain_capacity_exceeded_job() {
+    let recursive_input = run_plain_job("recursive", b"\\input recursive\n", |job| job);
+    assert_eq!(tex::History::FatalErrorStop, recursive_input.history);
+    assert!(recursive_input
+        .log
+        .unwrap()
+        .contains("! TeX capacity exceeded, sorry [text input levels=6]."));
+    let nested_boxes = run_plain_job("nested", b"\\def\\a{\\hbox\\bgroup\\a}\\a\n", |job| job);
+    assert_eq!(tex::History::FatalErrorStop, nested_boxes.history);
+    assert!(nested_boxes
+        .log
+        .unwrap()
+        .contains("! TeX capacity exceeded, sorry [semantic nest size=40]."));
+    let long_source = [&[b'x'; 600][..], b"\n"].concat();
+    let long_line = run_plain_job("long", &long_source, |job| job);
+    assert_eq!(tex::History::FatalErrorStop, long_line.history);
+    assert!(long_line
+        .log
+        .unwrap()
+        .contains("! TeX capacity exceeded, sorry [buffer size=500]."));
+    let nested_groups = run_plain_job(
+        "groups",
+        b"\\def\\a{\\begingroup\\count1=1 \\a}\\a\n",
+        |job| job,
+    );
+    assert_eq!(tex::History::FatalErrorStop, nested_groups.history);
+    assert!(nested_groups
+        .log
+        .unwrap()
+        .contains("! TeX capacity exceeded, sorry [save size=600]."));
+}
+
+#[test]
+fn plain_unported_section_job() {
+    let result = run_plain_job("apology", b"\\iffalse\\or\\fi\n\\end\n", |job| job);
+    assert_eq!(tex::History::FatalErrorStop, result.history);
+    assert_eq!(Some(500), result.unported_section.map(|s| s.section));
+    assert_eq!(None, result.internal_error);
+    let log = result.log.unwrap();
+    assert!(log.contains("! This can't happen (unported section 500).\n"));
+}
+
+#[test]
+fn plain_undefined_family_job() {
+    let result = run_plain_batch_job("family", b"$\\fam9 x$\\end\n");
+    assert_eq!(tex::History::ErrorMessageIssued, result.history);
+    let log = result.log.unwrap();
+    assert!(log.contains("! \\textfont 9 is undefined (character x).\n"));
+}
+
+#[test]
+fn plain_source_date_epoch_job() {
+    fn run_clock_job(clock: tex::configure::Clock) -> String {
+        let source = b"\\message{[\\the\\year.\\the\\month.\\the\\day.\\the\\time]}\\end\n";
+        let result = run_plain_job("clock", source, |mut job| {
+            use tex::configure::TeXConfiguration;
+            job.configuration().set_clock(clock).unwrap();
+            job
+        });
+        result.log.unwrap()
+    }
+    use tex::configure::Clock;
+    // no other test reads the environment, as they all run with a fixed clock
+    std::env::set_var("SOURCE_DATE_EPOCH", "1000000000");
+    let pinned_log = run_clock_job(Clock::Utc);
+    // ten hours ahead of UTC, where the epoch above is already 11:46
+    std::env::set_var("TZ", "AEST-10");
+    let pinned_local_log = run_clock_job(Clock::Local);
+    std::env::remove_var("TZ");
+    std::env::set_var("SOURCE_DATE_EPOCH", "yesterday");
+    let fallback_log = run_clock_job(Clock::Utc);
+    std::env::remove_var("SOURCE_DATE_EPOCH");
+    assert!(pinned_log.contains("  9 SEP 2001 01:46\n"));
+    assert!(pinned_log.contains("[2001.9.9.106]"));
+    assert!(pinned_local_log.contains("  9 SEP 2001 01:46\n"));
+    assert!(pinned_local_log.contains("[2001.9.9.106]"));
+    let year = fallback_log
+        .split('[')
+        .nth(1)
+        .and_then(|date| date.split('.').next())
+        .and_then(|year| year.parse::<i32>().ok())
+        .unwrap();
+    assert!(year >= 2021);
+}
+
+#[test]
+fn plain_rejects_nonexistent_fixed_clock() {
+    use tex::configure::{Clock, FixedDateTime, TeXConfiguration};
+    let mut globals = tex::TeXGlobals::default();
+    let february_30 = FixedDateTime {
+        year: 2001,
+        month: 2,
+        day: 30,
+        hour: 0,
+        minute: 0,
+    };
+    let error = globals.set_clock(Clock::Fixed(february_30)).unwrap_err();
+    assert_eq!("clock", error.parameter());
+    let hour_twenty_four = FixedDateTime {
+        hour: 24,
+        day: 28,
+        ..february_30
+    };
+    assert!(globals.set_clock(Clock::Fixed(hour_twenty_four)).is_err());
+    assert!(globals.set_clock(Clock::Utc).is_ok());
+}
+
+#[test]
+fn plain_jobs_keep_their_own_file_systems() {
+    let (first, second) = prepare_pool().install(|| {
+        TeXTestVFS::default()
+            .and_then_prepare_file("plain.fmt", PLAIN_DMP_RECORD)
+            .and_then_prepare_file("page.tex", b"first\\end\n")
+            .install_as_current();
+        let mut first = tex::Job::new("page").format("plain").unwrap();
+        configure_for_test(first.configuration());
+        TeXTestVFS::default()
+            .and_then_prepare_file("plain.fmt", PLAIN_DMP_RECORD)
+            .and_then_prepare_file("page.tex", b"\\message{second}\\end\n")
+            .install_as_current();
+        let mut second = tex::Job::new("page").format("plain").unwrap();
+        configure_for_test(second.configuration());
+        // both jobs run on this thread, the second one before the first
+        let second = second.run();
+        (first.run(), second)
+    });
+    assert_eq!(1, first.total_pages);
+    assert!(first.dvi.is_some());
+    assert_eq!(0, second.total_pages);
+    assert!(second.log.unwrap().contains("(page.tex second )"));
+}
+
+#[test]
+fn plain_search_path_job() {
+    use tex::configure::{InteractionMode, SearchArea, TeXConfiguration};
+    use tex::file_system::DiskFileSystem;
+    let root = std::env::temp_dir().join(format!("texrs-search-path-{}", std::process::id()));
+    let prepare = |path: &str, contents: &[u8]| {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    };
+    prepare("formats/plain.fmt", PLAIN_DMP_RECORD);
+    prepare(
+        "environment/nested/environment.tex",
+        b"\\message{environment}",
+    );
+    prepare(
+        "configured/main.tex",
+        b"\\input environment \\input listed \\end\n",
+    );
+    prepare("configured/database.tex", b"\\input unlisted \\end\n");
+    prepare(
+        "listed/ls-R",
+        b"% ls-R -- filename database\n./:\nls-R\nlisted.tex\n",
+    );
+    prepare("listed/listed.tex", b"\\message{listed}");
+    prepare("listed/unlisted.tex", b"\\message{unlisted}");
+    std::fs::create_dir_all(root.join("output")).unwrap();
+    let path_of = |elements: &[&str]| {
+        let paths = elements.iter().map(|element| {
+            if element.is_empty() {
+                String::new()
+            } else if let Some(element) = element.strip_prefix("!!") {
+                format!("!!{}", root.join(element).to_str().unwrap())
+            } else {
+                root.join(element).to_str().unwrap().to_owned()
+            }
+        });
+        std::env::join_paths(paths).unwrap().into_string().unwrap()
+    };
+    let run = |first_line: &str, inputs: &str| {
+        let mut job = tex::Job::new(first_line)
+            .format("plain")
+            .unwrap()
+            .interaction(InteractionMode::Batch);
+        let configuration = job.configuration();
+        configuration.set_file_system(Box::new(DiskFileSystem));
+        configuration.set_search_path(SearchArea::Formats, &path_of(&["formats"]));
+        configuration.set_search_path(SearchArea::Inputs, inputs);
+        configuration.set_output_directory(root.join("output").to_str().unwrap());
+        job.run()
+    };
+    // no other test looks at the disk, as they all run on memory file systems
+    std::env::set_var("TEXINPUTS", path_of(&["environment//"]));
+    // the empty element at the end stands for the directories of `TEXINPUTS`
+    let with_default = run("main", &path_of(&["configured", "!!listed", ""]));
+    let without_default = run("main", &path_of(&["configured", "!!listed"]));
+    // `!!` directories are only looked up in their `ls-R`
+    let unlisted = run("database", &path_of(&["configured", "!!listed"]));
+    std::env::remove_var("TEXINPUTS");
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(tex::History::Spotless, with_default.history);
+    let log = with_default.log.unwrap();
+    assert!(log.contains(" environment)"));
+    assert!(log.contains(" listed)"));
+    assert_eq!(tex::History::FatalErrorStop, without_default.history);
+    assert!(without_default
+        .log
+        .unwrap()
+        .contains("! I can't find file `environment.tex'."));
+    assert_eq!(tex::History::FatalErrorStop, unlisted.history);
+    assert!(unlisted
+        .log
+        .unwrap()
+        .contains("! I can't find file `unlisted.tex'."));
+}
@@ -0,0 +1,125 @@
+// The `plain.fmt` fixture is dumped by the build with 16-bit halfwords.
+#![cfg(not(feature = "wide_pointers"))]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const PLAIN_DMP_RECORD: &[u8] = include_bytes!("../tests_data/plain_dmp/plain.fmt");
+
+/// A directory of its own for `test`, holding `plain.fmt` and `hello.tex`.
+fn prepare_directory(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("texrs-{}-{}", test, std::process::id()));
+    std::fs::create_dir_all(dir.join("output")).unwrap();
+    std::fs::write(dir.join("plain.fmt"), PLAIN_DMP_RECORD).unwrap();
+    std::fs::write(dir.join("hello.tex"), b"Hello world\n\\end\n").unwrap();
+    dir
+}
+
+/// Run `texrs` in `dir` with `args`, away from the search paths of the host,
+/// at noon on 4 July 1776 in UTC.
+fn texrs(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_texrs"))
+        .args(args)
+        .current_dir(dir)
+        .env("SOURCE_DATE_EPOCH", "-6106017600")
+        .env_remove("TEXINPUTS")
+        .env_remove("TEXFONTS")
+        .env_remove("TEXFORMATS")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn texrs_runs_with_options() {
+    let dir = prepare_directory("options");
+    let output = texrs(
+        &dir,
+        &[
+            "--utc",
+            "--fmt=plain",
+            "--jobname",
+            "-greeting",
+            "--interaction=batchmode",
+            "--output-directory=output",
+            "hello",
+        ],
+    );
+    let dvi = std::fs::read(dir.join("output/-greeting.dvi"));
+    let log = std::fs::read_to_string(dir.join("output/-greeting.log"));
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(
+        &include_bytes!("../tests_data/dvi_result/plain_helloworld.dvi")[..],
+        &dvi.unwrap()[..]
+    );
+    assert!(log.unwrap().contains("**&plain hello\n"));
+}
+
+#[test]
+fn texrs_takes_single_dash_options() {
+    let dir = prepare_directory("single-dash");
+    let output = texrs(
+        &dir,
+        &[
+            "-fmt=plain",
+            "-jobname",
+            "-greeting",
+            "-interaction=batchmode",
+            "-output-directory=output",
+            "-halt-on-error",
+            "hello",
+        ],
+    );
+    let dvi = std::fs::read(dir.join("output/-greeting.dvi"));
+    let log = std::fs::read_to_string(dir.join("output/-greeting.log"));
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!(
+        &include_bytes!("../tests_data/dvi_result/plain_helloworld.dvi")[..],
+        &dvi.unwrap()[..]
+    );
+    assert!(log.unwrap().contains("**&plain hello\n"));
+}
+
+#[test]
+fn texrs_virtex_is_the_default() {
+    let dir = prepare_directory("ini");
+    let default = texrs(&dir, &["--interaction=batchmode", "&plain", "hello"]);
+    let ini = texrs(&dir, &["-ini", "-interaction=batchmode", "&plain", "hello"]);
+    std::fs::remove_dir_all(&dir).unwrap();
+    let banner = |output: &Output| {
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        stdout.lines().next().unwrap_or_default().to_owned()
+    };
+    assert_eq!(
+        "This is TeX-rs, Version 3.141592653 (no format preloaded)",
+        banner(&default)
+    );
+    assert_eq!(Some(0), default.status.code());
+    assert_eq!("This is TeX-rs, Version 3.141592653 (INITEX)", banner(&ini));
+}
+
+#[test]
+fn texrs_rejects_bad_options() {
+    let dir = prepare_directory("rejects");
+    let spaced_format = texrs(&dir, &["--fmt", "pla in", "hello"]);
+    let dvi_buf_size = texrs(&dir, &["--dvi-buf-size=801", "hello"]);
+    let interaction = texrs(&dir, &["--interaction=bogus", "hello"]);
+    // only the options of TeX itself may be spelled with one dash
+    let single_dash = texrs(&dir, &["-mem-max=40000", "hello"]);
+    std::fs::remove_dir_all(&dir).unwrap();
+    let stderr = |output: &Output| String::from_utf8_lossy(&output.stderr).into_owned();
+    assert_eq!(Some(2), spaced_format.status.code());
+    assert_eq!(
+        "tex-cli: invalid format: must not contain spaces\n",
+        stderr(&spaced_format)
+    );
+    assert_eq!(Some(2), dvi_buf_size.status.code());
+    assert_eq!(
+        "tex-cli: invalid dvi_buf_size: must be a positive multiple of 8\n",
+        stderr(&dvi_buf_size)
+    );
+    assert!(!interaction.status.success());
+    assert!(stderr(&interaction).contains("unknown interaction mode `bogus`"));
+    assert!(!single_dash.status.success());
+}
@@ -0,0 +1,93 @@
+//! Options a job is started with, as a command line would give them.
+//! They are kept apart from the variables `initialize` resets, and
+//! are consulted at the points where `TeX` would otherwise fall back
+//! to its defaults.
+
+/// is this run able to initialize tables, load patterns and `\dump`?
+#[cfg(feature = "initex")]
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static ini_version: boolean = true;
+
+/// text that stands in for the first line typed at the `**` prompt
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static first_line_option: String = String::new();
+
+/// format to load, as if `&` and its name started the first line
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static format_option: String = String::new();
+
+/// name to use for the job instead of the name of the first input file
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static job_name_option: String = String::new();
+
+/// directory that output files with relative names are written to
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsFilenameView)]
+pub(crate) static output_directory: String = String::new();
+
+/// level of interaction to enter once the format is loaded
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static interaction_option: Option<u8> = None;
+
+/// does the first error end the job?
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static halt_on_error: boolean = false;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::pascal::boolean;
+
+/// The first line of input given along with the job, if any; it is handed out only once.
+pub(crate) fn take_first_line(globals: &mut TeXGlobals) -> Option<String> {
+    let first_line = core::mem::take(&mut globals.first_line_option);
+    let format = core::mem::take(&mut globals.format_option);
+    match (format.is_empty(), first_line.is_empty()) {
+        (true, true) => None,
+        (true, false) => Some(first_line),
+        (false, _) => Some(format!("&{} {}", format, first_line)),
+    }
+}
+
+/// The job name given along with the job, entered into the string pool.
+pub(crate) fn job_name_from_option(globals: &mut TeXGlobals) -> Option<str_number> {
+    if globals.job_name_option.is_empty() {
+        return None;
+    }
+    let name = globals.job_name_option.clone();
+    // `open_log_file` has no way to stop the job, so a name that does not fit in the
+    // pool is left out, as `str_room` would end the job
+    let room = name.chars().count() as integer * character_max_room;
+    if globals.pool_ptr.get() + room as u32 > globals.pool_size {
+        return None;
+    }
+    for ch in name.chars() {
+        append_char(
+            make_globals_string_view!(globals),
+            ASCII_code::from(ch as u32 as integer),
+        );
+    }
+    Some(make_string(make_globals_string_view!(globals)))
+}
+
+/// The name an output file is opened under, placing relative names in the output directory.
+pub(crate) fn output_file_name(output_directory: &str, name_of_file: String) -> String {
+    let name = name_of_file.trim_end_matches(' ');
+    if output_directory.is_empty() || Path::new(name).is_absolute() {
+        return name_of_file;
+    }
+    Path::new(output_directory)
+        .join(name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+use crate::pascal::integer;
+use crate::section_0004::make_globals_string_view;
+use crate::section_0004::TeXGlobals;
+use crate::section_0004::TeXGlobalsStringView;
+use crate::section_0018::ASCII_code;
+use crate::section_0038::str_number;
+use crate::section_0042::append_char;
+use crate::section_0042::character_max_room;
+use crate::section_0043::make_string;
+use globals_struct::{globals_struct_field, globals_struct_field_view, globals_struct_use};
+use std::path::Path;
@@ -8,14 +8,193 @@ pub trait TeXConfiguration {
     fn set_half_error_line(&mut self, half_error_line: u8);
     /// Configure `max_print_line` parameter
     fn set_max_print_line(&mut self, max_print_line: u8);
+    /// Configure `mem_max` capacity (greatest index in the `mem` array; at least `mem_top`,
+    /// and equal to it when `ini_version`)
+    fn set_mem_max(&mut self, mem_max: u32) -> Result<(), ConfigurationError>;
+    /// Configure `buf_size` capacity (size of the input line buffer; at least 21, so that
+    /// `INITEX` can put the name of every primitive in it)
+    fn set_buf_size(&mut self, buf_size: u16) -> Result<(), ConfigurationError>;
+    /// Configure `stack_size` capacity (maximum number of simultaneous input sources;
+    /// at least 1)
+    fn set_stack_size(&mut self, stack_size: u16) -> Result<(), ConfigurationError>;
+    /// Configure `max_in_open` capacity (maximum number of simultaneously open input files;
+    /// less than 128)
+    fn set_max_in_open(&mut self, max_in_open: u8) -> Result<(), ConfigurationError>;
+    /// Configure `font_max` capacity (maximum internal font number; at least `font_base`)
+    fn set_font_max(&mut self, font_max: u8) -> Result<(), ConfigurationError>;
+    /// Configure `font_mem_size` capacity (number of words of `font_info` for all fonts;
+    /// at least the 7 words of `null_font`)
+    fn set_font_mem_size(&mut self, font_mem_size: u16) -> Result<(), ConfigurationError>;
+    /// Configure `pool_size` capacity (maximum number of characters in strings)
+    fn set_pool_size(&mut self, pool_size: u32) -> Result<(), ConfigurationError>;
+    /// Configure `save_size` capacity (space for saving values outside of current group;
+    /// at least the 6 entries `check_full_save_stack` keeps in reserve)
+    fn set_save_size(&mut self, save_size: u16) -> Result<(), ConfigurationError>;
+    /// Configure `trie_size` capacity (space for hyphenation patterns; at least 256,
+    /// the room `init_trie` takes for the trie root)
+    fn set_trie_size(&mut self, trie_size: u16) -> Result<(), ConfigurationError>;
+    /// Configure `dvi_buf_size` capacity (size of the output buffer; a positive multiple of 8)
+    fn set_dvi_buf_size(&mut self, dvi_buf_size: u16) -> Result<(), ConfigurationError>;
+    /// Configure where `\time`, `\day`, `\month` and `\year` are taken from
+    /// (a fixed date and time must exist in the calendar)
+    fn set_clock(&mut self, clock: Clock) -> Result<(), ConfigurationError>;
+    /// Configure the search path of a system area, overriding its environment variable
+    fn set_search_path(&mut self, area: SearchArea, path: &str);
+    /// Configure whether `ls-R` filename databases are consulted during searches
+    fn set_use_filename_database(&mut self, use_filename_database: bool);
+    #[cfg(feature = "initex")]
+    /// Configure whether this run can initialize tables, load patterns and `\dump`
+    fn set_ini_version(&mut self, ini_version: bool);
+    /// Configure the first line of input, in place of what is typed at the `**` prompt
+    fn set_first_line(&mut self, first_line: &str);
+    /// Configure the format to load, as if `&` and its name started the first line
+    /// (so the name cannot contain spaces)
+    fn set_format(&mut self, format: &str) -> Result<(), ConfigurationError>;
+    /// Configure the job name, in place of the name of the first input file
+    fn set_job_name(&mut self, job_name: &str);
+    /// Configure the directory that output files with relative names are written to
+    fn set_output_directory(&mut self, output_directory: &str);
+    /// Configure the level of interaction, in place of the one in the format
+    fn set_interaction(&mut self, interaction: InteractionMode);
+    /// Configure whether the first error ends the job
+    fn set_halt_on_error(&mut self, halt_on_error: bool);
+    /// Configure the file system that files and the terminal are opened through
+    fn set_file_system(&mut self, file_system: Box<dyn crate::file_system::FileSystem>);
+    /// Configure the resources the job may use before it is stopped
+    fn set_limits(&mut self, limits: crate::limits::Limits);
+    /// A handle that stops the job from another thread while it runs
+    fn cancellation_handle(&self) -> crate::limits::CancellationHandle;
 
     #[cfg(feature = "unicode_support")]
     /// Configure `unicode_support` parameter
     fn set_unicode_support(&mut self, unicode_support_enabled: bool);
 
+    #[cfg(feature = "unicode_support")]
+    /// Configure how grapheme clusters made of several scalar values are read
+    fn set_grapheme_cluster_handling(&mut self, handling: GraphemeClusterHandling);
+
     #[cfg(feature = "latex_support")]
     /// Configure `latex_support` parameter
     fn set_latex_support(&mut self, latex_support_enabled: bool);
+
+    #[cfg(feature = "pdf_support")]
+    /// Configure which output driver `ship_out` targets
+    fn set_output_driver(&mut self, output_driver: OutputDriver);
+
+    #[cfg(feature = "pdf_support")]
+    /// Configure the size of every page of a PDF file, in big points (`bp`);
+    /// by default each page is as large as its box plus one inch and the
+    /// offsets on every side
+    fn set_pdf_page_size(&mut self, width: f64, height: f64) -> Result<(), ConfigurationError>;
+}
+
+/// A configured value that `TeX` cannot run with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigurationError {
+    parameter: &'static str,
+    reason: &'static str,
+}
+
+impl ConfigurationError {
+    pub(crate) fn new(parameter: &'static str, reason: &'static str) -> Self {
+        ConfigurationError { parameter, reason }
+    }
+
+    /// The name of the parameter whose value was rejected
+    pub fn parameter(&self) -> &'static str {
+        self.parameter
+    }
+}
+
+impl core::fmt::Display for ConfigurationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid {}: {}", self.parameter, self.reason)
+    }
+}
+
+impl std::error::Error for ConfigurationError {}
+
+/// Where the date and time of a job come from.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Clock {
+    /// The system clock, in the local time zone.
+    /// `SOURCE_DATE_EPOCH`, when set, replaces the current instant and is
+    /// reported in UTC, so that it means the same date everywhere.
+    #[default]
+    Local,
+    /// The system clock, in UTC.
+    /// `SOURCE_DATE_EPOCH`, when set, replaces the current instant.
+    Utc,
+    /// A fixed date and time, regardless of the system clock and the environment.
+    Fixed(FixedDateTime),
+}
+
+/// A date and time to the minute, as `TeX` sees them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedDateTime {
+    /// Anno Domini
+    pub year: i32,
+    /// month of the year, `1..=12`
+    pub month: u8,
+    /// day of the month, `1..=31`
+    pub day: u8,
+    /// hour of the day, `0..=23`
+    pub hour: u8,
+    /// minute of the hour, `0..=59`
+    pub minute: u8,
+}
+
+/// The levels of interaction with the user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InteractionMode {
+    /// omits all stops and omits terminal output
+    Batch,
+    /// omits all stops
+    NonStop,
+    /// omits error stops
+    Scroll,
+    /// stops at every opportunity to interact
+    ErrorStop,
+}
+
+/// A system area that file names are looked up in.
+///
+/// Each area has a search path; by default it is taken from the environment
+/// variable named after the area, and it is the current directory when that
+/// variable is not set.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchArea {
+    /// `TeXinputs:`, searched along `TEXINPUTS`
+    Inputs,
+    /// `TeXfonts:`, searched along `TEXFONTS`
+    Fonts,
+    /// `TeXformats:`, searched along `TEXFORMATS`
+    Formats,
+}
+
+#[cfg(feature = "unicode_support")]
+/// How an input grapheme cluster made of several scalar values
+/// (e.g. `e` followed by a combining acute accent) becomes a character.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum GraphemeClusterHandling {
+    /// Normalize the cluster to NFC and, if it still has several scalar values,
+    /// intern it in the grapheme registry as one generalized character.
+    #[default]
+    Intern,
+    /// Normalize the cluster to NFC and read each resulting scalar value
+    /// as a character of its own.
+    NormalizeNfc,
+}
+
+/// The kind of file that shipped out pages are written to.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum OutputDriver {
+    /// Device-independent file (`.dvi`)
+    #[default]
+    Dvi,
+    #[cfg(feature = "pdf_support")]
+    /// Portable document format (`.pdf`)
+    Pdf,
 }
 
 impl TeXConfiguration for crate::section_0004::TeXGlobals {
@@ -31,6 +210,183 @@ impl TeXConfiguration for crate::section_0004::TeXGlobals {
         self.max_print_line = max_print_line;
     }
 
+    fn set_mem_max(&mut self, mem_max: u32) -> Result<(), ConfigurationError> {
+        if mem_max < crate::section_0012::mem_top as u32 {
+            return Err(ConfigurationError::new(
+                "mem_max",
+                "must not be less than mem_top",
+            ));
+        }
+        if mem_max > crate::section_0011::mem_max_TYPENUM::U32 {
+            return Err(ConfigurationError::new(
+                "mem_max",
+                "must be less than max_halfword",
+            ));
+        }
+        self.mem_max = mem_max;
+        Ok(())
+    }
+
+    fn set_buf_size(&mut self, buf_size: u16) -> Result<(), ConfigurationError> {
+        if buf_size < 21 {
+            return Err(ConfigurationError::new("buf_size", "must be at least 21"));
+        }
+        self.buf_size = buf_size;
+        Ok(())
+    }
+
+    fn set_stack_size(&mut self, stack_size: u16) -> Result<(), ConfigurationError> {
+        if stack_size < 1 {
+            return Err(ConfigurationError::new("stack_size", "must be at least 1"));
+        }
+        self.stack_size = stack_size;
+        Ok(())
+    }
+
+    fn set_max_in_open(&mut self, max_in_open: u8) -> Result<(), ConfigurationError> {
+        if max_in_open > crate::section_0011::max_in_open_TYPENUM::U8 {
+            return Err(ConfigurationError::new(
+                "max_in_open",
+                "must be less than 128",
+            ));
+        }
+        self.max_in_open = max_in_open;
+        Ok(())
+    }
+
+    fn set_font_max(&mut self, font_max: u8) -> Result<(), ConfigurationError> {
+        if (font_max as integer) < crate::section_0012::font_base as integer {
+            return Err(ConfigurationError::new(
+                "font_max",
+                "must not be less than font_base",
+            ));
+        }
+        self.font_max = font_max;
+        Ok(())
+    }
+
+    fn set_font_mem_size(&mut self, font_mem_size: u16) -> Result<(), ConfigurationError> {
+        if font_mem_size < 7 {
+            return Err(ConfigurationError::new(
+                "font_mem_size",
+                "must be at least 7",
+            ));
+        }
+        self.font_mem_size = font_mem_size;
+        Ok(())
+    }
+
+    fn set_pool_size(&mut self, pool_size: u32) -> Result<(), ConfigurationError> {
+        if pool_size > crate::section_0011::pool_size_TYPENUM::U32 {
+            return Err(ConfigurationError::new(
+                "pool_size",
+                "must be less than 2147483648",
+            ));
+        }
+        self.pool_size = pool_size;
+        Ok(())
+    }
+
+    fn set_save_size(&mut self, save_size: u16) -> Result<(), ConfigurationError> {
+        if save_size < 6 {
+            return Err(ConfigurationError::new("save_size", "must be at least 6"));
+        }
+        self.save_size = save_size;
+        Ok(())
+    }
+
+    fn set_trie_size(&mut self, trie_size: u16) -> Result<(), ConfigurationError> {
+        if trie_size < 256 {
+            return Err(ConfigurationError::new("trie_size", "must be at least 256"));
+        }
+        self.trie_size = trie_size;
+        Ok(())
+    }
+
+    fn set_dvi_buf_size(&mut self, dvi_buf_size: u16) -> Result<(), ConfigurationError> {
+        if dvi_buf_size == 0 || !dvi_buf_size.is_multiple_of(8) {
+            return Err(ConfigurationError::new(
+                "dvi_buf_size",
+                "must be a positive multiple of 8",
+            ));
+        }
+        self.dvi_buf_size = dvi_buf_size;
+        Ok(())
+    }
+
+    fn set_clock(&mut self, clock: Clock) -> Result<(), ConfigurationError> {
+        if let Clock::Fixed(date_time) = clock {
+            if !crate::time_support::is_valid_date_and_time(date_time) {
+                return Err(ConfigurationError::new(
+                    "clock",
+                    "must be a date and time that exists",
+                ));
+            }
+        }
+        self.clock = clock;
+        Ok(())
+    }
+
+    fn set_search_path(&mut self, area: SearchArea, path: &str) {
+        self.search_paths.set_search_path(area, path);
+    }
+
+    fn set_use_filename_database(&mut self, use_filename_database: bool) {
+        self.search_paths
+            .set_use_filename_database(use_filename_database);
+    }
+
+    #[cfg(feature = "initex")]
+    fn set_ini_version(&mut self, ini_version: bool) {
+        self.ini_version = ini_version;
+    }
+
+    fn set_first_line(&mut self, first_line: &str) {
+        self.first_line_option = first_line.to_owned();
+    }
+
+    fn set_format(&mut self, format: &str) -> Result<(), ConfigurationError> {
+        if format.contains(' ') {
+            return Err(ConfigurationError::new("format", "must not contain spaces"));
+        }
+        self.format_option = format.to_owned();
+        Ok(())
+    }
+
+    fn set_job_name(&mut self, job_name: &str) {
+        self.job_name_option = job_name.to_owned();
+    }
+
+    fn set_output_directory(&mut self, output_directory: &str) {
+        self.output_directory = output_directory.to_owned();
+    }
+
+    fn set_interaction(&mut self, interaction: InteractionMode) {
+        use crate::section_0073::{batch_mode, error_stop_mode, nonstop_mode, scroll_mode};
+        self.interaction_option = Some(match interaction {
+            InteractionMode::Batch => batch_mode,
+            InteractionMode::NonStop => nonstop_mode,
+            InteractionMode::Scroll => scroll_mode,
+            InteractionMode::ErrorStop => error_stop_mode,
+        });
+    }
+
+    fn set_halt_on_error(&mut self, halt_on_error: bool) {
+        self.halt_on_error = halt_on_error;
+    }
+
+    fn set_file_system(&mut self, file_system: Box<dyn crate::file_system::FileSystem>) {
+        self.file_system = file_system.into();
+    }
+
+    fn set_limits(&mut self, limits: crate::limits::Limits) {
+        self.limits = limits;
+    }
+
+    fn cancellation_handle(&self) -> crate::limits::CancellationHandle {
+        self.cancellation.clone()
+    }
+
     #[cfg(feature = "unicode_support")]
     fn set_unicode_support(&mut self, unicode_support_enabled: bool) {
         self.max_allowed_ASCII_code_repr = if unicode_support_enabled {
@@ -38,10 +394,42 @@ impl TeXConfiguration for crate::section_0004::TeXGlobals {
         } else {
             255
         };
+        self.allow_big_char_code = unicode_support_enabled;
+    }
+
+    #[cfg(feature = "unicode_support")]
+    fn set_grapheme_cluster_handling(&mut self, handling: GraphemeClusterHandling) {
+        self.grapheme_cluster_handling = handling;
     }
 
     #[cfg(feature = "latex_support")]
     fn set_latex_support(&mut self, latex_support_enabled: bool) {
         self.latex_support_enabled = latex_support_enabled;
     }
+
+    #[cfg(feature = "pdf_support")]
+    fn set_output_driver(&mut self, output_driver: OutputDriver) {
+        self.output_driver = output_driver;
+    }
+
+    #[cfg(feature = "pdf_support")]
+    fn set_pdf_page_size(&mut self, width: f64, height: f64) -> Result<(), ConfigurationError> {
+        if !(width.is_finite() && width > 0.0) {
+            return Err(ConfigurationError::new(
+                "pdf_page_size",
+                "width must be positive",
+            ));
+        }
+        if !(height.is_finite() && height > 0.0) {
+            return Err(ConfigurationError::new(
+                "pdf_page_size",
+                "height must be positive",
+            ));
+        }
+        self.pdf_page_size = Some((width, height));
+        Ok(())
+    }
 }
+
+use crate::pascal::integer;
+use typenum::Unsigned;
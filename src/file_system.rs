@@ -0,0 +1,299 @@
+//! The files a `TeX` engine reads and writes, terminal included.
+//!
+//! Each `TeXGlobals` owns a [`FileSystem`]; while `entry` runs, every file
+//! `TeX` opens is opened through it. File names are passed to
+//! [`FileSystem::locate`] as `TeX` packs them, so they may start with one of
+//! the system areas `TeXinputs:`, `TeXfonts:` or `TeXformats:`, and each file
+//! system decides where those are; files are then read by the names it gives.
+
+use crate::io_support::ReadLine;
+pub use crate::search_path::SearchPaths;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::{self, Read, Write};
+use std::rc::Rc;
+use std::time::SystemTime;
+
+/// Where the files of a `TeX` engine live.
+pub trait FileSystem {
+    /// The name to read a file that `TeX` calls `path` by, looked up along the
+    /// `search_paths` of the engine if need be, or `None` if there is no such file.
+    /// File systems that keep the system areas to themselves can leave `path` as is.
+    fn locate(&self, path: &str, search_paths: &mut SearchPaths) -> Option<String> {
+        let _ = search_paths;
+        Some(path.to_owned())
+    }
+    /// Open a text file for reading, a line at a time.
+    fn open_text_file_for_read(&self, path: &str) -> io::Result<Box<dyn ReadLine>>;
+    /// Open a binary file for reading.
+    fn open_binary_file_for_read(&self, path: &str) -> io::Result<Box<dyn Read>>;
+    /// Create a file, or truncate an existing one, for writing.
+    fn create(&self, path: &str) -> io::Result<Box<dyn Write>>;
+    /// Whether a file exists at `path`.
+    fn exists(&self, path: &str) -> bool;
+    /// When the file at `path` was last modified.
+    fn modification_time(&self, path: &str) -> io::Result<SystemTime>;
+    /// The size of the file at `path`, in bytes.
+    fn size(&self, path: &str) -> io::Result<u64>;
+    /// Open the terminal for reading what the user types.
+    fn open_terminal_for_read(&self) -> io::Result<Box<dyn ReadLine>>;
+    /// Open the terminal for writing.
+    fn open_terminal_for_write(&self) -> io::Result<Box<dyn Write>>;
+}
+
+/// The files on disk, with the system areas resolved along their search paths,
+/// and the terminal on standard input and output.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DiskFileSystem;
+
+impl FileSystem for DiskFileSystem {
+    fn locate(&self, path: &str, search_paths: &mut SearchPaths) -> Option<String> {
+        search_paths.find(path)?.to_str().map(str::to_owned)
+    }
+
+    fn open_text_file_for_read(&self, path: &str) -> io::Result<Box<dyn ReadLine>> {
+        let file = std::fs::File::open(path)?;
+        Ok(Box::new(io::BufReader::new(file)))
+    }
+
+    fn open_binary_file_for_read(&self, path: &str) -> io::Result<Box<dyn Read>> {
+        let file = std::fs::File::open(path)?;
+        Ok(Box::new(io::BufReader::new(file)))
+    }
+
+    fn create(&self, path: &str) -> io::Result<Box<dyn Write>> {
+        Ok(Box::new(std::fs::File::create(path)?))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        std::path::Path::new(path).is_file()
+    }
+
+    fn modification_time(&self, path: &str) -> io::Result<SystemTime> {
+        std::fs::metadata(path)?.modified()
+    }
+
+    fn size(&self, path: &str) -> io::Result<u64> {
+        Ok(std::fs::metadata(path)?.len())
+    }
+
+    fn open_terminal_for_read(&self) -> io::Result<Box<dyn ReadLine>> {
+        Ok(Box::new(io::stdin()))
+    }
+
+    fn open_terminal_for_write(&self) -> io::Result<Box<dyn Write>> {
+        Ok(Box::new(io::stdout()))
+    }
+}
+
+#[derive(Default)]
+struct MemoryFileSystemState {
+    files: BTreeMap<String, MemoryFile>,
+    terminal_input: Vec<u8>,
+    terminal_output: Vec<u8>,
+    terminal_echo: bool,
+}
+
+struct MemoryFile {
+    contents: Vec<u8>,
+    modified: SystemTime,
+}
+
+/// Files kept in memory, with a terminal that reads prepared input and
+/// keeps what is written to it. The system areas are ignored, so
+/// `TeXfonts:cmr10.tfm` is the same file as `cmr10.tfm`.
+///
+/// Clones share their files, so a host can keep one to look at the
+/// results after handing another to an engine.
+#[derive(Clone, Default)]
+pub struct MemoryFileSystem {
+    state: Rc<RefCell<MemoryFileSystemState>>,
+}
+
+crate::impl_debug_with_literal!(MemoryFileSystem, "MemoryFileSystem");
+
+impl MemoryFileSystem {
+    fn name(path: &str) -> &str {
+        ["TeXinputs:", "TeXfonts:", "TeXformats:"]
+            .iter()
+            .find_map(|area| path.strip_prefix(area))
+            .unwrap_or(path)
+    }
+
+    fn contents(&self, path: &str) -> io::Result<Vec<u8>> {
+        self.file(path)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+
+    /// Add a file, replacing any file of the same name.
+    pub fn add_file(&self, path: &str, contents: &[u8]) {
+        self.state.borrow_mut().files.insert(
+            Self::name(path).to_owned(),
+            MemoryFile {
+                contents: contents.to_owned(),
+                modified: SystemTime::now(),
+            },
+        );
+    }
+
+    /// The contents of a file, if there is one at `path`.
+    pub fn file(&self, path: &str) -> Option<Vec<u8>> {
+        let state = self.state.borrow();
+        let file = state.files.get(Self::name(path))?;
+        Some(file.contents.clone())
+    }
+
+    /// Set what the terminal reads from the next time it is opened.
+    pub fn set_terminal_input(&self, input: &[u8]) {
+        self.state.borrow_mut().terminal_input = input.to_owned();
+    }
+
+    /// Make the terminal behave like an interactive one: the lines it reads
+    /// show up in its output as if typed, and its last line is ended once
+    /// it is closed.
+    pub fn set_terminal_echo(&self, echo: bool) {
+        self.state.borrow_mut().terminal_echo = echo;
+    }
+
+    /// What has been written to the terminal since it was last opened.
+    pub fn terminal_output(&self) -> Vec<u8> {
+        self.state.borrow().terminal_output.clone()
+    }
+}
+
+/// Where a `MemoryFileWriter` puts what is written to it.
+enum MemoryFileTarget {
+    File(String),
+    Terminal,
+}
+
+struct MemoryFileWriter {
+    state: Rc<RefCell<MemoryFileSystemState>>,
+    target: MemoryFileTarget,
+}
+
+impl Write for MemoryFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut state = self.state.borrow_mut();
+        match &self.target {
+            MemoryFileTarget::File(name) => {
+                let file = state.files.get_mut(name).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::NotFound, "file removed while open")
+                })?;
+                file.contents.extend_from_slice(buf);
+                file.modified = SystemTime::now();
+            }
+            MemoryFileTarget::Terminal => state.terminal_output.extend_from_slice(buf),
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for MemoryFileWriter {
+    fn drop(&mut self) {
+        let mut state = self.state.borrow_mut();
+        if let MemoryFileTarget::Terminal = self.target {
+            if state.terminal_echo && !state.terminal_output.ends_with(b"\n") {
+                state.terminal_output.push(b'\n');
+            }
+        }
+    }
+}
+
+/// The terminal of a `MemoryFileSystem` that echoes what it reads.
+struct MemoryTerminalReader {
+    state: Rc<RefCell<MemoryFileSystemState>>,
+    input: io::Cursor<Vec<u8>>,
+}
+
+impl ReadLine for MemoryTerminalReader {
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        let start = buf.len();
+        let len = ReadLine::read_line(&mut self.input, buf)?;
+        let mut state = self.state.borrow_mut();
+        state
+            .terminal_output
+            .extend_from_slice(buf[start..].as_bytes());
+        Ok(len)
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn open_text_file_for_read(&self, path: &str) -> io::Result<Box<dyn ReadLine>> {
+        let contents = self.contents(path)?;
+        Ok(Box::new(io::BufReader::new(io::Cursor::new(contents))))
+    }
+
+    fn open_binary_file_for_read(&self, path: &str) -> io::Result<Box<dyn Read>> {
+        Ok(Box::new(io::Cursor::new(self.contents(path)?)))
+    }
+
+    fn create(&self, path: &str) -> io::Result<Box<dyn Write>> {
+        self.add_file(path, &[]);
+        Ok(Box::new(MemoryFileWriter {
+            state: Rc::clone(&self.state),
+            target: MemoryFileTarget::File(Self::name(path).to_owned()),
+        }))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.state.borrow().files.contains_key(Self::name(path))
+    }
+
+    fn modification_time(&self, path: &str) -> io::Result<SystemTime> {
+        let state = self.state.borrow();
+        let file = state
+            .files
+            .get(Self::name(path))
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        Ok(file.modified)
+    }
+
+    fn size(&self, path: &str) -> io::Result<u64> {
+        let state = self.state.borrow();
+        let file = state
+            .files
+            .get(Self::name(path))
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        Ok(file.contents.len() as u64)
+    }
+
+    fn open_terminal_for_read(&self) -> io::Result<Box<dyn ReadLine>> {
+        let state = self.state.borrow();
+        let input = io::Cursor::new(state.terminal_input.clone());
+        if state.terminal_echo {
+            Ok(Box::new(MemoryTerminalReader {
+                state: Rc::clone(&self.state),
+                input,
+            }))
+        } else {
+            Ok(Box::new(io::BufReader::new(input)))
+        }
+    }
+
+    fn open_terminal_for_write(&self) -> io::Result<Box<dyn Write>> {
+        self.state.borrow_mut().terminal_output.clear();
+        Ok(Box::new(MemoryFileWriter {
+            state: Rc::clone(&self.state),
+            target: MemoryFileTarget::Terminal,
+        }))
+    }
+}
+
+/// the files this engine reads and writes
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsFilenameView)]
+pub(crate) static file_system: Rc<dyn FileSystem> = Rc::new(DiskFileSystem);
+
+#[globals_struct_use(TeXGlobals)]
+use crate::file_system::{DiskFileSystem, FileSystem};
+
+#[globals_struct_use(TeXGlobals)]
+use std::rc::Rc;
+
+use crate::section_0004::TeXGlobals;
+use globals_struct::{globals_struct_field, globals_struct_use};
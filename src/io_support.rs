@@ -46,6 +46,7 @@ impl PascalFile for file_of_text_char {
         }
         #[cfg(feature = "unicode_support")]
         {
+            use crate::unicode_support::generalized_char_from_str;
             use unicode_segmentation::UnicodeSegmentation;
             for grapheme in input.graphemes(true) {
                 if grapheme.as_bytes().len() == 1 {
@@ -55,7 +56,7 @@ impl PascalFile for file_of_text_char {
                     if byte_offset == 0 {
                         units.push(text_char::new(ch as _))
                     } else {
-                        todo!("not yet implemented in {}", file!());
+                        units.push(generalized_char_from_str(grapheme))
                     }
                 }
             }
@@ -86,23 +87,16 @@ impl PascalFile for file_of_text_char {
         self.error_state = error_state;
     }
 
-    fn open_text_file_for_read(path: &str) -> Result<(Box<dyn pascal_io::ReadLine>, bool), usize> {
-        let path = path.trim_end_matches(' ');
-        if path == crate::section_0011::pool_name.trim_end_matches(' ') {
-            Ok((Box::new(crate::string_pool::pool_file()), false))
-        } else {
-            IO_HANDLER_OPEN_TEXT_FILE_FOR_READ.with(|c| c.borrow()(path))
-        }
+    fn open_text_file_for_read(_: &str) -> Result<(Box<dyn pascal_io::ReadLine>, bool), usize> {
+        take_opened_file(OpenedFile::into_text)
     }
 
-    fn open_binary_file_for_read(path: &str) -> Result<Box<dyn Read>, usize> {
-        let path = path.trim_end_matches(' ');
-        IO_HANDLER_OPEN_BINARY_FILE_FOR_READ.with(|c| c.borrow()(path))
+    fn open_binary_file_for_read(_: &str) -> Result<Box<dyn Read>, usize> {
+        take_opened_file(OpenedFile::into_binary)
     }
 
-    fn open_file_for_write(path: &str) -> Result<Box<dyn Write>, usize> {
-        let path = path.trim_end_matches(' ');
-        IO_HANDLER_OPEN_FILE_FOR_WRITE.with(|c| c.borrow()(path))
+    fn open_file_for_write(_: &str) -> Result<Box<dyn Write>, usize> {
+        take_opened_file(OpenedFile::into_write)
     }
 }
 
@@ -173,135 +167,155 @@ impl<T: FromBlob + ToBlob> PascalFile for file_of<T> {
         self.error_state = error_state;
     }
 
-    fn open_text_file_for_read(path: &str) -> Result<(Box<dyn pascal_io::ReadLine>, bool), usize> {
-        let path = path.trim_end_matches(' ');
-        IO_HANDLER_OPEN_TEXT_FILE_FOR_READ.with(|c| c.borrow()(path))
+    fn open_text_file_for_read(_: &str) -> Result<(Box<dyn pascal_io::ReadLine>, bool), usize> {
+        take_opened_file(OpenedFile::into_text)
     }
 
-    fn open_binary_file_for_read(path: &str) -> Result<Box<dyn Read>, usize> {
-        let path = path.trim_end_matches(' ');
-        IO_HANDLER_OPEN_BINARY_FILE_FOR_READ.with(|c| c.borrow()(path))
+    fn open_binary_file_for_read(_: &str) -> Result<Box<dyn Read>, usize> {
+        take_opened_file(OpenedFile::into_binary)
     }
 
-    fn open_file_for_write(path: &str) -> Result<Box<dyn Write>, usize> {
-        let path = path.trim_end_matches(' ');
-        IO_HANDLER_OPEN_FILE_FOR_WRITE.with(|c| c.borrow()(path))
+    fn open_file_for_write(_: &str) -> Result<Box<dyn Write>, usize> {
+        take_opened_file(OpenedFile::into_write)
     }
 }
 
 pub(crate) type packed_file_of<T> = file_of<T>;
 
-/// IO Handlers that allows to override all disk and terminal IO accesses, only used for testing purpose.
-pub struct TeXIoHandler {
-    /// Callback function for reading text files.
-    pub open_text_file_for_read: Box<dyn Fn(&str) -> Result<(Box<dyn ReadLine>, bool), usize>>,
-    /// Callback function for reading binary files.
-    pub open_binary_file_for_read: Box<dyn Fn(&str) -> Result<Box<dyn Read>, usize>>,
-    /// Callback function for writing files.
-    pub open_file_for_write: Box<dyn Fn(&str) -> Result<Box<dyn Write>, usize>>,
+// The terminal is opened under the name `TTY:`; everything else is a file
+// of the file system of the engine that is opening it.
+//
+// `pascal_io` asks the file type itself to open a file, by an associated
+// function that cannot tell which engine it serves. So `reset` and `rewrite`
+// open the file through the engine's file system first, and leave the result
+// for that associated function to collect during the same call.
+
+enum OpenedFile {
+    Text(Box<dyn ReadLine>, bool),
+    Binary(Box<dyn Read>),
+    Write(Box<dyn Write>),
 }
 
-crate::impl_debug_with_literal!(TeXIoHandler, "TeXIoHandler");
-
-/// Install a specific IO handler, only used for testing purpose.
-pub fn install_io_handler(handler: TeXIoHandler) {
-    let TeXIoHandler {
-        open_text_file_for_read,
-        open_binary_file_for_read,
-        open_file_for_write,
-    } = handler;
-    IO_HANDLER_OPEN_TEXT_FILE_FOR_READ.with(|c| {
-        *c.borrow_mut() = open_text_file_for_read;
-    });
-    IO_HANDLER_OPEN_BINARY_FILE_FOR_READ.with(|c| {
-        *c.borrow_mut() = open_binary_file_for_read;
-    });
-    IO_HANDLER_OPEN_FILE_FOR_WRITE.with(|c| {
-        *c.borrow_mut() = open_file_for_write;
-    });
-}
+impl OpenedFile {
+    fn into_text(self) -> Option<(Box<dyn ReadLine>, bool)> {
+        match self {
+            OpenedFile::Text(read, lazy) => Some((read, lazy)),
+            _ => None,
+        }
+    }
+
+    fn into_binary(self) -> Option<Box<dyn Read>> {
+        match self {
+            OpenedFile::Binary(read) => Some(read),
+            _ => None,
+        }
+    }
 
-/// Revert to the default IO handler, only used for testing purpose.
-pub fn reset_io_handler() {
-    install_io_handler(TeXIoHandler {
-        open_text_file_for_read: Box::new(builtin_open_text_file_for_read),
-        open_binary_file_for_read: Box::new(builtin_open_binary_file_for_read),
-        open_file_for_write: Box::new(builtin_open_file_for_write),
-    })
+    fn into_write(self) -> Option<Box<dyn Write>> {
+        match self {
+            OpenedFile::Write(write) => Some(write),
+            _ => None,
+        }
+    }
 }
 
 thread_local! {
-    pub(crate) static IO_HANDLER_OPEN_TEXT_FILE_FOR_READ:
-        RefCell<Box<dyn Fn(&str)-> Result<(Box<dyn ReadLine>, bool), usize>>>
-        = RefCell::new(Box::new(builtin_open_text_file_for_read));
-    pub(crate) static IO_HANDLER_OPEN_BINARY_FILE_FOR_READ:
-        RefCell<Box<dyn Fn(&str)-> Result<Box<dyn Read>, usize>>>
-        = RefCell::new(Box::new(builtin_open_binary_file_for_read));
-    pub(crate) static IO_HANDLER_OPEN_FILE_FOR_WRITE:
-        RefCell<Box<dyn Fn(&str)-> Result<Box<dyn Write>, usize>>>
-        = RefCell::new(Box::new(builtin_open_file_for_write));
+    static OPENED_FILE: RefCell<Option<Result<OpenedFile, usize>>> = const { RefCell::new(None) };
 }
 
-fn builtin_open_text_file_for_read(
-    path: &str,
-) -> Result<(Box<dyn pascal_io::ReadLine>, bool), usize> {
-    let mut term_special_handling = false;
-    let new_read_target: Box<dyn ReadLine> = if path == "TTY:" {
-        term_special_handling = true;
-        Box::new(io::stdin())
+fn take_opened_file<T>(kind: fn(OpenedFile) -> Option<T>) -> Result<T, usize> {
+    let opened = OPENED_FILE.with(|opened| opened.borrow_mut().take());
+    let opened = opened.expect("file opened outside of `reset` or `rewrite`")?;
+    Ok(kind(opened).expect("file opened in another mode than it was asked for"))
+}
+
+/// Leave `opened` for the associated function that `open` calls, for as long as it runs.
+fn hand_over_opened_file(opened: Result<OpenedFile, usize>, open: impl FnOnce()) {
+    OPENED_FILE.with(|slot| *slot.borrow_mut() = Some(opened));
+    open();
+    OPENED_FILE.with(|slot| *slot.borrow_mut() = None);
+}
+
+/// Open `f` for input, as `file_system` finds `path` along `search_paths`;
+/// blank spaces at the end of `path` are ignored.
+pub(crate) fn reset<F: PascalFile, P: Into<String>>(
+    file_system: &dyn FileSystem,
+    search_paths: &mut SearchPaths,
+    f: &mut F,
+    path: P,
+    options: &str,
+) {
+    let path: String = path.into();
+    let path = path.trim_end_matches(' ');
+    let opened = if F::is_text_file() {
+        open_text_file_for_read(file_system, search_paths, path)
+            .map(|(read, lazy)| OpenedFile::Text(read, lazy))
     } else {
-        let file = match std::fs::File::open(path) {
-            Ok(f) => f,
-            Err(_) => {
-                return Err(1);
-            }
-        };
-        Box::new(io::BufReader::new(file))
+        open_binary_file_for_read(file_system, search_paths, path).map(OpenedFile::Binary)
     };
-    Ok((new_read_target, term_special_handling))
+    hand_over_opened_file(opened, || pascal_io::reset(f, path, options));
+}
+
+/// Open `f` for output, creating `path` through `file_system`; blank spaces at
+/// the end of `path` are ignored.
+pub(crate) fn rewrite<F: PascalFile, P: Into<String>>(
+    file_system: &dyn FileSystem,
+    f: &mut F,
+    path: P,
+    options: &str,
+) {
+    let path: String = path.into();
+    let path = path.trim_end_matches(' ');
+    let opened = open_file_for_write(file_system, path).map(OpenedFile::Write);
+    hand_over_opened_file(opened, || pascal_io::rewrite(f, path, options));
 }
 
-fn builtin_open_binary_file_for_read(mut path: &str) -> Result<Box<dyn Read>, usize> {
-    let new_read_target: Box<dyn Read> = if path == "TTY:" {
-        Box::new(io::stdin())
+fn open_text_file_for_read(
+    file_system: &dyn FileSystem,
+    search_paths: &mut SearchPaths,
+    path: &str,
+) -> Result<(Box<dyn ReadLine>, bool), usize> {
+    if path == "TTY:" {
+        let read = file_system.open_terminal_for_read().map_err(|_| 1usize)?;
+        Ok((read, true))
+    } else if path == crate::section_0011::pool_name.trim_end_matches(' ') {
+        Ok((Box::new(crate::string_pool::pool_file()), false))
     } else {
-        path = path.trim_start_matches("TeXfonts:");
-        path = path.trim_start_matches("TeXformats:");
-        let file = match std::fs::File::open(path) {
-            Ok(f) => f,
-            Err(_) => {
-                return Err(1);
-            }
-        };
-        Box::new(io::BufReader::new(file))
-    };
-    Ok(new_read_target)
+        let path = file_system.locate(path, search_paths).ok_or(1usize)?;
+        let read = file_system
+            .open_text_file_for_read(&path)
+            .map_err(|_| 1usize)?;
+        Ok((read, false))
+    }
 }
 
-fn builtin_open_file_for_write(path: &str) -> Result<Box<dyn Write>, usize> {
-    let write_target: Box<dyn Write> = if path == "TTY:" {
-        Box::new(io::stdout())
+fn open_binary_file_for_read(
+    file_system: &dyn FileSystem,
+    search_paths: &mut SearchPaths,
+    path: &str,
+) -> Result<Box<dyn Read>, usize> {
+    let path = file_system.locate(path, search_paths).ok_or(1usize)?;
+    file_system
+        .open_binary_file_for_read(&path)
+        .map_err(|_| 1usize)
+}
+
+fn open_file_for_write(file_system: &dyn FileSystem, path: &str) -> Result<Box<dyn Write>, usize> {
+    if path == "TTY:" {
+        file_system.open_terminal_for_write().map_err(|_| 1usize)
     } else {
-        let file = match std::fs::File::create(path) {
-            Ok(f) => f,
-            Err(_) => {
-                return Err(1);
-            }
-        };
-        Box::new(file)
-    };
-    Ok(write_target)
+        file_system.create(path).map_err(|_| 1usize)
+    }
 }
 
+use crate::file_system::FileSystem;
+use crate::file_system::SearchPaths;
 use crate::pascal::char_repr;
 use crate::section_0019::text_char;
 pub(crate) use pascal_io::{
     break_in, buffer_variable, buffer_variable_assign, close, eof, eoln, erstat, get, put, r#break,
-    read_ln, read_onearg, reset, rewrite, write, write_binary, write_ln, write_ln_noargs,
-    FileState, FromBlob, PascalFile, ReadLine, ToBlob,
-};
-use std::{
-    borrow::{Borrow, BorrowMut},
-    cell::RefCell,
-    io::{self, Read, Write},
+    read_ln, read_onearg, write, write_binary, write_ln, write_ln_noargs, FileState, FromBlob,
+    PascalFile, ReadLine, ToBlob,
 };
+use std::cell::RefCell;
+use std::io::{Read, Write};
@@ -0,0 +1,241 @@
+//! An embeddable way to run `TeX`: a [`Job`] is set up with its first line of
+//! input and a few options, runs to completion, and reports what happened
+//! in a [`JobResult`] instead of leaving it to be read off the terminal.
+
+use crate::configure::{ConfigurationError, InteractionMode, TeXConfiguration};
+use crate::file_system::{FileSystem, SearchPaths};
+use crate::io_support::ReadLine;
+use crate::limits::{CancellationHandle, Limits};
+use crate::section_0004::TeXGlobals;
+use crate::section_0038::str_number;
+use crate::section_0076::history_kind;
+use crate::unported::UnportedSection;
+use std::cell::RefCell;
+use std::io::{self, Read, Write};
+use std::rc::Rc;
+use std::time::SystemTime;
+
+/// How clean a job's source input turned out to be, as `TeX` keeps track of it in `history`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum History {
+    /// nothing has been amiss
+    Spotless,
+    /// diagnostics were written
+    WarningIssued,
+    /// errors were reported
+    ErrorMessageIssued,
+    /// the job ended prematurely
+    FatalErrorStop,
+}
+
+impl From<history_kind> for History {
+    fn from(history: history_kind) -> Self {
+        match history {
+            history_kind::spotless => History::Spotless,
+            history_kind::warning_issued => History::WarningIssued,
+            history_kind::error_message_issued => History::ErrorMessageIssued,
+            history_kind::fatal_error_stop => History::FatalErrorStop,
+        }
+    }
+}
+
+/// What a finished job reports back.
+#[derive(Clone, Debug)]
+pub struct JobResult {
+    /// how clean the source input was
+    pub history: History,
+    /// the number of pages that have been shipped out
+    pub total_pages: u32,
+    /// the bytes of the output file (`.dvi`, or `.pdf` with the PDF driver), if one was written
+    pub dvi: Option<Vec<u8>>,
+    /// the text of the transcript file, if one was written
+    pub log: Option<String>,
+    /// the names of all files written, in the order they were opened
+    pub files_written: Vec<String>,
+    /// the section of `TeX` that ended the job because it has not been ported yet, if any
+    pub unported_section: Option<UnportedSection>,
+    /// the message of the internal error (a bug in `TeX-rs`) that ended the job, if any
+    pub internal_error: Option<String>,
+}
+
+/// A single run of `TeX`.
+pub struct Job {
+    globals: Box<TeXGlobals>,
+}
+
+crate::impl_debug_with_literal!(Job, "Job");
+
+impl Job {
+    /// A job whose first line of input is `first_line`, as if typed at the `**` prompt.
+    pub fn new(first_line: &str) -> Self {
+        let mut globals: Box<TeXGlobals> = Default::default();
+        globals.set_first_line(first_line);
+        Job { globals }
+    }
+
+    /// Use `interaction` instead of the level of interaction in the format.
+    pub fn interaction(mut self, interaction: InteractionMode) -> Self {
+        self.globals.set_interaction(interaction);
+        self
+    }
+
+    /// Load the format `format` before the first line is read.
+    pub fn format(mut self, format: &str) -> Result<Self, ConfigurationError> {
+        self.globals.set_format(format)?;
+        Ok(self)
+    }
+
+    /// Stop the job once it uses more than `limits` allow.
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.globals.set_limits(limits);
+        self
+    }
+
+    /// A handle that stops the job from another thread while it runs.
+    pub fn cancellation_handle(&self) -> CancellationHandle {
+        self.globals.cancellation_handle()
+    }
+
+    /// Access the rest of the configuration of this job.
+    pub fn configuration(&mut self) -> &mut impl TeXConfiguration {
+        &mut *self.globals
+    }
+
+    /// Run the job to completion.
+    pub fn run(mut self) -> JobResult {
+        let globals = &mut *self.globals;
+        let written = run_recording_written_files(globals);
+        let output_path = |s: str_number| {
+            if s == 0 {
+                return None;
+            }
+            let name = string_from_str_number(globals, s);
+            Some(crate::command_line::output_file_name(
+                &globals.output_directory,
+                name,
+            ))
+        };
+        let contents_of = |path: Option<String>| {
+            let path = path?;
+            written
+                .iter()
+                .rev()
+                .find(|(written_path, _)| *written_path == path)
+                .map(|(_, contents)| contents.clone())
+        };
+        let dvi = contents_of(output_path(globals.output_file_name));
+        let log = contents_of(output_path(globals.log_name))
+            .map(|contents| String::from_utf8_lossy(&contents).into_owned());
+        let mut files_written = Vec::<String>::new();
+        for (path, _) in written.iter() {
+            if !files_written.contains(path) {
+                files_written.push(path.clone());
+            }
+        }
+        JobResult {
+            history: globals.history.into(),
+            total_pages: globals.total_pages as u32,
+            dvi,
+            log,
+            files_written,
+            unported_section: globals.unported_section.clone(),
+            internal_error: globals.internal_error.clone(),
+        }
+    }
+}
+
+pub(crate) fn string_from_str_number(globals: &TeXGlobals, s: str_number) -> String {
+    let bytes = (globals.str_start[s].get()..globals.str_start[s + 1].get())
+        .map(|k| globals.str_pool[k].0)
+        .collect::<Vec<u8>>();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// The copy of a file's contents, shared between its writer and the file system.
+type RecordedContents = Rc<RefCell<Vec<u8>>>;
+
+struct RecordingWrite {
+    inner: Box<dyn Write>,
+    contents: RecordedContents,
+}
+
+impl Write for RecordingWrite {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.contents
+            .borrow_mut()
+            .extend_from_slice(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A file system that keeps a copy of every file created through it.
+struct RecordingFileSystem {
+    inner: Rc<dyn FileSystem>,
+    written: RefCell<Vec<(String, RecordedContents)>>,
+}
+
+impl FileSystem for RecordingFileSystem {
+    fn locate(&self, path: &str, search_paths: &mut SearchPaths) -> Option<String> {
+        self.inner.locate(path, search_paths)
+    }
+
+    fn open_text_file_for_read(&self, path: &str) -> io::Result<Box<dyn ReadLine>> {
+        self.inner.open_text_file_for_read(path)
+    }
+
+    fn open_binary_file_for_read(&self, path: &str) -> io::Result<Box<dyn Read>> {
+        self.inner.open_binary_file_for_read(path)
+    }
+
+    fn create(&self, path: &str) -> io::Result<Box<dyn Write>> {
+        let inner = self.inner.create(path)?;
+        let contents = Rc::new(RefCell::new(Vec::new()));
+        self.written
+            .borrow_mut()
+            .push((path.to_owned(), Rc::clone(&contents)));
+        Ok(Box::new(RecordingWrite { inner, contents }))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.inner.exists(path)
+    }
+
+    fn modification_time(&self, path: &str) -> io::Result<SystemTime> {
+        self.inner.modification_time(path)
+    }
+
+    fn size(&self, path: &str) -> io::Result<u64> {
+        self.inner.size(path)
+    }
+
+    fn open_terminal_for_read(&self) -> io::Result<Box<dyn ReadLine>> {
+        self.inner.open_terminal_for_read()
+    }
+
+    fn open_terminal_for_write(&self) -> io::Result<Box<dyn Write>> {
+        self.inner.open_terminal_for_write()
+    }
+}
+
+/// Run `entry` on `globals` while keeping a copy of every file it writes;
+/// the copies are returned together with their paths, in the order the files were opened.
+fn run_recording_written_files(globals: &mut TeXGlobals) -> Vec<(String, Vec<u8>)> {
+    let inner = Rc::clone(&globals.file_system);
+    let recording = Rc::new(RecordingFileSystem {
+        inner: Rc::clone(&inner),
+        written: RefCell::new(vec![]),
+    });
+    globals.file_system = recording.clone();
+    crate::section_1332::entry(globals);
+    globals.file_system = inner;
+    let written = recording.written.borrow();
+    written
+        .iter()
+        .map(|(path, contents)| (path.clone(), contents.borrow().clone()))
+        .collect()
+}
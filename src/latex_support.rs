@@ -1,15 +1,60 @@
-const unimplemented_code: quarterword = if_case_code + 1;
+/// `\ifdefined`
+pub(crate) const if_def_code: quarterword = if_case_code + 1;
+/// `\ifcsname`
+pub(crate) const if_cs_code: quarterword = if_case_code + 2;
+/// amount added for `\unless` prefix
+pub(crate) const unless_code: quarterword = 32;
+/// first of the `last_item` codes for expressions, `\numexpr` and so on
+pub(crate) const eTeX_expr: quarterword = last_item_command_kind::badness_code as quarterword + 1;
+/// prefix code of `\protected`
+pub(crate) const protected_code: chr_code_repr = 8;
+/// marks the text of a `\protected` macro, ahead of its parameter part
+pub(crate) const protected_token: cur_tok_repr = end_match_token + 1;
+/// chr code of `\unexpanded`; like `\detokenize`, it is odd
+pub(crate) const unexpanded_code: chr_code_repr = 1;
+/// chr code of `\detokenize`, the `show_tokens` code of e-TeX
+pub(crate) const detokenize_code: chr_code_repr = 5;
+/// chr code of `\readline`
+pub(crate) const readline_code: chr_code_repr = 1;
+/// chr code of `\scantokens`, after those of `\input` and `\endinput`
+pub(crate) const scan_tokens_code: chr_code_repr = 2;
+/// `token_type` code for `\everyeof`
+pub(crate) const every_eof_text: quarterword = write_text + 1;
 
 #[globals_struct_field(TeXGlobals)]
 pub(crate) static latex_support_enabled: bool = false;
 
+/// the stack of pseudo files read by `\scantokens`; each entry is a list of lines
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static pseudo_files: pointer = null;
+
+/// has `\everyeof` been inserted at the end of the file at this `index`?
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static eof_seen: [boolean; max_in_open_TYPENUM::USIZE + 1] =
+    [false; max_in_open_TYPENUM::USIZE + 1];
+
+#[globals_struct_use(TeXGlobals)]
+use crate::section_0011::max_in_open_TYPENUM;
+
+#[globals_struct_use(TeXGlobals)]
+use typenum::Unsigned;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::section_0115::null;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::section_0115::pointer;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::pascal::boolean;
+
 #[cfg(feature = "initex")]
 pub(crate) fn external_primitive(
     globals: &mut TeXGlobals,
     s: &'static str,
     c: quarterword,
     o: halfword,
-) {
+) -> TeXResult<()> {
     assert!(s.chars().all(|ch| ch.is_ascii_graphic()));
     let l = s.chars().count();
     assert!(l > 0);
@@ -19,40 +64,2020 @@ pub(crate) fn external_primitive(
         for (idx, ch) in s.chars().enumerate() {
             globals.buffer[idx as u16] = ASCII_code::from((ch as u32) as integer);
         }
-        globals.cur_val = id_lookup(globals, 0, l as _) as _;
+        globals.cur_val = id_lookup(globals, 0, l as _)? as _;
     }
     eq_level!(globals, globals.cur_val as u32) = level_one;
     eq_type!(globals, globals.cur_val as u32) = c;
     equiv!(globals, globals.cur_val as u32) = o;
-    // end;
-    // tini
+    crate::ok_nojump!()
 }
 
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_latex_support($globals:expr) {{
     let globals = &mut *$globals;
     if globals.latex_support_enabled {
-        external_primitive(globals, "ifcsname", if_test, unimplemented_code as _);
+        external_primitive(globals, "unless", expand_after, 1)?;
+        external_primitive(globals, "ifdefined", if_test, if_def_code as _)?;
+        external_primitive(globals, "ifcsname", if_test, if_cs_code as _)?;
+        external_primitive(
+            globals,
+            "numexpr",
+            last_item,
+            (eTeX_expr + cur_val_level_kind::int_val as quarterword) as _,
+        )?;
+        external_primitive(
+            globals,
+            "dimexpr",
+            last_item,
+            (eTeX_expr + cur_val_level_kind::dimen_val as quarterword) as _,
+        )?;
+        external_primitive(
+            globals,
+            "glueexpr",
+            last_item,
+            (eTeX_expr + cur_val_level_kind::glue_val as quarterword) as _,
+        )?;
+        external_primitive(
+            globals,
+            "muexpr",
+            last_item,
+            (eTeX_expr + cur_val_level_kind::mu_val as quarterword) as _,
+        )?;
+        external_primitive(globals, "protected", prefix, protected_code as _)?;
+        external_primitive(globals, "unexpanded", the, unexpanded_code as _)?;
+        external_primitive(globals, "detokenize", the, detokenize_code as _)?;
+        external_primitive(globals, "everyeof", assign_toks, every_eof_loc as _)?;
+        external_primitive(globals, "scantokens", input, scan_tokens_code as _)?;
+        external_primitive(globals, "readline", read_to_cs, readline_code as _)?;
     }
     // globals.error_line = 79;
     // globals.half_error_line = 50;
     // globals.max_print_line = 79;
 }}
 
+/// Print the `\unless` prefix of an `if_test` chr code, and return the code
+/// of the underlying conditional.
+pub(crate) fn print_unless_prefix(
+    globals: &mut TeXGlobals,
+    chr_code: chr_code_repr,
+) -> chr_code_repr {
+    if chr_code >= unless_code as chr_code_repr {
+        print_esc(globals, crate::strpool_str!("unless"));
+        chr_code - unless_code as chr_code_repr
+    } else {
+        chr_code
+    }
+}
+
+/// Print the conditionals added by `latex_support`; returns `false` for the others.
+pub(crate) fn print_if_test(globals: &mut TeXGlobals, chr_code: chr_code_repr) -> bool {
+    if chr_code == if_def_code as chr_code_repr {
+        print_esc(globals, crate::strpool_str!("ifdefined"));
+        true
+    } else if chr_code == if_cs_code as chr_code_repr {
+        print_esc(globals, crate::strpool_str!("ifcsname"));
+        true
+    } else {
+        false
+    }
+}
+
+/// `\unless` shares the command code of `\expandafter`. As in e-TeX, the
+/// conditional that follows is expanded with `unless_code` added to its chr
+/// code, so `conditional` knows to negate it.
+pub(crate) macro Negate_a_boolean_conditional($globals:expr) {{
+    get_token($globals)?;
+    if $globals.cur_cmd == if_test && $globals.cur_chr.get() != if_case_code as chr_code_repr {
+        $globals.cur_chr =
+            chr_code_type::new($globals.cur_chr.get() + unless_code as chr_code_repr);
+        // the `goto reswitch` of e-TeX, with |cur_cmd=if_test| known
+        if tracing_commands!($globals) > 1 {
+            show_cur_cmd_chr($globals);
+        }
+        conditional($globals)?;
+    } else {
+        print_err!($globals, crate::strpool_str!("You can't use `"));
+        print_esc($globals, crate::strpool_str!("unless"));
+        print($globals, crate::strpool_str!("' before `").get() as _);
+        print_cmd_chr($globals, $globals.cur_cmd, $globals.cur_chr);
+        print_char(
+            make_globals_io_string_log_view!($globals),
+            ASCII_code_literal!(b'\''),
+        );
+        help1!(
+            $globals,
+            crate::strpool_str!("Continue, and I'll forget that it ever happened.")
+        );
+        back_error($globals)?;
+    }
+    use crate::section_0004::make_globals_io_string_log_view;
+    use crate::section_0004::TeXGlobalsIoStringLogView;
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0058::print_char;
+    use crate::section_0059::print;
+    use crate::section_0236::tracing_commands;
+    use crate::section_0298::print_cmd_chr;
+    use crate::section_0299::show_cur_cmd_chr;
+    use crate::section_0365::get_token;
+    use crate::section_0498::conditional;
+}}
+
+/// Set `b` for `\ifdefined` and `\ifcsname`. Neither of them puts a new
+/// control sequence into the hash table.
+pub(crate) macro Test_a_latex_support_conditional($globals:expr, $this_if:expr, $b:expr) {{
+    if $this_if == if_def_code {
+        let save_scanner_status = $globals.scanner_status;
+        $globals.scanner_status = scanner_status_kind::normal;
+        get_next($globals)?;
+        $b = $globals.cur_cmd != undefined_cs;
+        $globals.scanner_status = save_scanner_status;
+    } else if $this_if == if_cs_code {
+        let (mut p, mut q, n): (pointer, pointer, pointer);
+        n = get_avail($globals)?;
+        // head of the list of characters
+        p = n;
+        loop {
+            get_x_token($globals)?;
+            if $globals.cur_cs == 0 {
+                store_new_token!($globals, $globals.cur_tok.get(), p, q);
+            }
+            if $globals.cur_cs != 0 {
+                break;
+            }
+        }
+        if $globals.cur_cmd != end_cs_name {
+            crate::section_0373::Complain_about_missing_endcsname!($globals);
+        }
+        Look_up_the_characters_of_list_n_in_the_hash_table!($globals, p, n);
+        flush_list($globals, n);
+        $b = eq_type!($globals, $globals.cur_cs) != undefined_cs;
+    } else {
+        crate::trace_error_expr!("this_if = {}", $this_if.get());
+        confusion($globals, crate::strpool_str!("if"))?;
+    }
+    use crate::section_0095::confusion;
+    use crate::section_0115::pointer;
+    use crate::section_0120::get_avail;
+    use crate::section_0123::flush_list;
+    use crate::section_0208::end_cs_name;
+    use crate::section_0210::undefined_cs;
+    use crate::section_0305::scanner_status_kind;
+    use crate::section_0341::get_next;
+    use crate::section_0371::store_new_token;
+    use crate::section_0380::get_x_token;
+}}
+
+/// Like `Look_up_the_characters_of_list_r_in_the_hash_table__and_set_cur_cs`,
+/// but with `no_new_control_sequence` left `true`.
+macro Look_up_the_characters_of_list_n_in_the_hash_table($globals:expr, $p:expr, $n:expr) {{
+    let mut m: u16_from_0_to_n<buf_size_TYPENUM>;
+    m = $globals.first;
+    $p = link!($globals, $n);
+    while $p != null {
+        if m >= $globals.max_buf_stack {
+            $globals.max_buf_stack = m + 1;
+            if $globals.max_buf_stack == $globals.buf_size {
+                overflow(
+                    $globals,
+                    crate::strpool_str!("buffer size"),
+                    $globals.buf_size as _,
+                )?;
+            }
+        }
+        $globals.buffer[m] = info_tok!($globals, $p).get_cmd_and_chr().unwrap().1.into();
+        incr!(m);
+        $p = link!($globals, $p);
+    }
+    if m > $globals.first + 1 {
+        $globals.cur_cs = id_lookup(
+            $globals,
+            $globals.first.get() as integer,
+            m.get() as integer - $globals.first.get() as integer,
+        )?;
+    } else if m == $globals.first {
+        // the list is empty
+        $globals.cur_cs = null_cs;
+    } else {
+        // the list has length one
+        #[cfg(feature = "unicode_support")]
+        let beyond_single_base = $globals.buffer[$globals.first].numeric_value() > 255;
+        #[cfg(not(feature = "unicode_support"))]
+        let beyond_single_base = false;
+        if beyond_single_base {
+            $globals.cur_cs = id_lookup($globals, $globals.first.get() as integer, 1)?;
+        } else {
+            $globals.cur_cs =
+                (single_base + $globals.buffer[$globals.first].numeric_value()) as pointer;
+        }
+    }
+    use crate::pascal::u16_from_0_to_n;
+    use crate::section_0011::buf_size_TYPENUM;
+    use crate::section_0016::incr;
+    use crate::section_0094::overflow;
+    use crate::section_0115::null;
+    use crate::section_0115::pointer;
+    use crate::section_0118::info_tok;
+    use crate::section_0118::link;
+    use crate::section_0222::null_cs;
+}}
+
+/// Print the expression primitives; returns `false` for the other `last_item` codes.
+pub(crate) fn print_last_item(globals: &mut TeXGlobals, chr_code: chr_code_repr) -> bool {
+    if chr_code == (eTeX_expr + cur_val_level_kind::int_val as quarterword) as chr_code_repr {
+        print_esc(globals, crate::strpool_str!("numexpr"));
+    } else if chr_code
+        == (eTeX_expr + cur_val_level_kind::dimen_val as quarterword) as chr_code_repr
+    {
+        print_esc(globals, crate::strpool_str!("dimexpr"));
+    } else if chr_code == (eTeX_expr + cur_val_level_kind::glue_val as quarterword) as chr_code_repr
+    {
+        print_esc(globals, crate::strpool_str!("glueexpr"));
+    } else if chr_code == (eTeX_expr + cur_val_level_kind::mu_val as quarterword) as chr_code_repr {
+        print_esc(globals, crate::strpool_str!("muexpr"));
+    } else {
+        return false;
+    }
+    true
+}
+
+/// The value of an expression is computed from scratch, so unlike the other
+/// internal quantities it does not get an extra glue reference; this is why
+/// `scan_something_internal` returns right after it, as in e-TeX.
+pub(crate) macro Process_an_expression_and_return($globals:expr, $m:expr, $level:expr, $negative:expr) {{
+    $globals.cur_val_level = (($m.get() - eTeX_expr as chr_code_repr) as u8).into();
+    scan_expr($globals)?;
+    while $globals.cur_val_level as integer > $level.get() as integer {
+        if $globals.cur_val_level == cur_val_level_kind::glue_val {
+            let m = $globals.cur_val as pointer;
+            $globals.cur_val = width!($globals, m).inner();
+            delete_glue_ref($globals, m);
+        } else if $globals.cur_val_level == cur_val_level_kind::mu_val {
+            mu_error($globals)?;
+        }
+        $globals.cur_val_level = ($globals.cur_val_level as u8 - 1).into();
+    }
+    if $negative {
+        if $globals.cur_val_level >= cur_val_level_kind::glue_val {
+            let m = $globals.cur_val as pointer;
+            $globals.cur_val = new_spec($globals, m)? as _;
+            delete_glue_ref($globals, m);
+            crate::section_0431::Negate_all_three_glue_components_of_cur_val!($globals);
+        } else {
+            negate!($globals.cur_val);
+        }
+    }
+    crate::return_nojump!();
+    use crate::section_0016::negate;
+    use crate::section_0115::pointer;
+    use crate::section_0135::width;
+    use crate::section_0151::new_spec;
+    use crate::section_0201::delete_glue_ref;
+    use crate::section_0408::mu_error;
+}}
+
+/// `(` seen, or `(` expression `)` seen
+const expr_none: u8 = 0;
+/// `(` expression `+` seen
+const expr_add: u8 = 1;
+/// `(` expression `-` seen
+const expr_sub: u8 = 2;
+/// term `*` seen
+const expr_mult: u8 = 3;
+/// term `/` seen
+const expr_div: u8 = 4;
+/// term `*` factor `/` seen
+const expr_scale: u8 = 5;
+
+/// number of words in an expression stack node
+const expr_node_size: integer = 4;
+/// saved expression so far
+macro expr_e_field($globals:expr, $ptr:expr) {
+    $globals.mem[$ptr + 1][MEMORY_WORD_INT]
+}
+/// saved term so far
+macro expr_t_field($globals:expr, $ptr:expr) {
+    $globals.mem[$ptr + 2][MEMORY_WORD_INT]
+}
+/// saved numerator
+macro expr_n_field($globals:expr, $ptr:expr) {
+    $globals.mem[$ptr + 3][MEMORY_WORD_INT]
+}
+
+/// clear a number or dimension and set `arith_error`
+macro num_error($globals:expr, $val:expr) {{
+    $globals.arith_error = true;
+    $val = 0;
+}}
+
+/// clear a glue spec and set `arith_error`
+macro glue_error($globals:expr, $val:expr) {{
+    $globals.arith_error = true;
+    delete_glue_ref($globals, $val as pointer);
+    $val = new_spec($globals, zero_glue)? as _;
+}}
+
+macro normalize_glue($globals:expr, $val:expr) {{
+    if stretch!($globals, $val).inner() == 0 {
+        stretch_order!($globals, $val) = glue_ord::normal as _;
+    }
+    if shrink!($globals, $val).inner() == 0 {
+        shrink_order!($globals, $val) = glue_ord::normal as _;
+    }
+}}
+
+/// Scan and evaluate an expression whose type is given by `cur_val_level`.
+pub(crate) fn scan_expr(globals: &mut TeXGlobals) -> TeXResult<()> {
+    /// saved values of `arith_error`
+    let (a, mut b): (boolean, boolean);
+    /// type of expression
+    let mut l: cur_val_level_kind;
+    /// state of expression so far
+    let mut r: u8;
+    /// state of term so far
+    let mut s: u8;
+    /// next operation or type of next factor
+    let mut o: u8;
+    /// expression so far
+    let mut e: integer;
+    /// term so far
+    let mut t: integer;
+    /// current factor
+    let mut f: integer;
+    /// numerator of combined multiplication and division
+    let mut n: integer;
+    /// top of expression stack
+    let mut p: pointer;
+    /// for stack manipulations
+    let mut q: pointer;
+    l = globals.cur_val_level;
+    a = globals.arith_error;
+    b = false;
+    p = null;
+    // @<Scan and evaluate an expression |e| of type |l|@>
+    crate::region_backward_label! {
+        'restart <- {
+            r = expr_none;
+            e = 0;
+            s = expr_none;
+            t = 0;
+            n = 0;
+            crate::region_backward_label! {
+                'continue_ <- {
+                    o = if s == expr_none { l as u8 } else { cur_val_level_kind::int_val as u8 };
+                    // @<Scan a factor |f| of type |o| or start a subexpression@>
+                    crate::section_0406::Get_the_next_non_blank_non_call_token!(globals);
+                    if globals.cur_tok == other_token + b'(' as cur_tok_repr {
+                        // @<Push the expression stack and |goto restart|@>
+                        q = get_node(globals, expr_node_size)?;
+                        link!(globals, q) = p;
+                        r#type!(globals, q) = l as _;
+                        subtype!(globals, q) = 4 * s + r;
+                        expr_e_field!(globals, q) = e;
+                        expr_t_field!(globals, q) = t;
+                        expr_n_field!(globals, q) = n;
+                        p = q;
+                        l = o.into();
+                        crate::goto_backward_label!('restart);
+                    }
+                    back_input(globals)?;
+                    if o == cur_val_level_kind::int_val as u8 {
+                        scan_int(globals)?;
+                    } else if o == cur_val_level_kind::dimen_val as u8 {
+                        scan_normal_dimen!(globals)?;
+                    } else if o == cur_val_level_kind::glue_val as u8 {
+                        scan_glue(globals, small_number::new(cur_val_level_kind::glue_val as _))?;
+                    } else {
+                        scan_glue(globals, small_number::new(cur_val_level_kind::mu_val as _))?;
+                    }
+                    f = globals.cur_val;
+                    crate::region_backward_label! {
+                        'found <- {
+                            // @<Scan the next operator and set |o|@>
+                            crate::section_0406::Get_the_next_non_blank_non_call_token!(globals);
+                            if globals.cur_tok == other_token + b'+' as cur_tok_repr {
+                                o = expr_add;
+                            } else if globals.cur_tok == other_token + b'-' as cur_tok_repr {
+                                o = expr_sub;
+                            } else if globals.cur_tok == other_token + b'*' as cur_tok_repr {
+                                o = expr_mult;
+                            } else if globals.cur_tok == other_token + b'/' as cur_tok_repr {
+                                o = expr_div;
+                            } else {
+                                o = expr_none;
+                                if p == null {
+                                    if globals.cur_cmd != relax {
+                                        back_input(globals)?;
+                                    }
+                                } else if globals.cur_tok != other_token + b')' as cur_tok_repr {
+                                    print_err!(
+                                        globals,
+                                        crate::strpool_str!("Missing ) inserted for expression")
+                                    );
+                                    help1!(
+                                        globals,
+                                        crate::strpool_str!(
+                                            "I was expecting to see `+', `-', `*', `/', or `)'. Didn't."
+                                        )
+                                    );
+                                    back_error(globals)?;
+                                }
+                            }
+                            globals.arith_error = b;
+                            // @<Make sure that |f| is in the proper range@>
+                            if l == cur_val_level_kind::int_val || s > expr_sub {
+                                if !(-infinity..=infinity).contains(&f) {
+                                    num_error!(globals, f);
+                                }
+                            } else if l == cur_val_level_kind::dimen_val {
+                                if !(-max_dimen..=max_dimen).contains(&f) {
+                                    num_error!(globals, f);
+                                }
+                            } else if !(-max_dimen..=max_dimen)
+                                .contains(&width!(globals, f as pointer).inner())
+                                || !(-max_dimen..=max_dimen)
+                                    .contains(&stretch!(globals, f as pointer).inner())
+                                || !(-max_dimen..=max_dimen)
+                                    .contains(&shrink!(globals, f as pointer).inner())
+                            {
+                                glue_error!(globals, f);
+                            }
+                            // @<Cases for evaluation of the current term@>
+                            if s == expr_none {
+                                if l >= cur_val_level_kind::glue_val && o != expr_none {
+                                    t = new_spec(globals, f as pointer)? as _;
+                                    delete_glue_ref(globals, f as pointer);
+                                    normalize_glue!(globals, t as pointer);
+                                } else {
+                                    t = f;
+                                }
+                            } else if s == expr_mult {
+                                if o == expr_div {
+                                    n = f;
+                                    o = expr_scale;
+                                } else if l == cur_val_level_kind::int_val {
+                                    t = mult_integers!(globals, t, f);
+                                } else if l == cur_val_level_kind::dimen_val {
+                                    t = expr_m(globals, t, f);
+                                } else {
+                                    let t = t as pointer;
+                                    width!(globals, t) =
+                                        scaled::new_from_inner(expr_m(globals, width!(globals, t).inner(), f));
+                                    stretch!(globals, t) =
+                                        scaled::new_from_inner(expr_m(globals, stretch!(globals, t).inner(), f));
+                                    shrink!(globals, t) =
+                                        scaled::new_from_inner(expr_m(globals, shrink!(globals, t).inner(), f));
+                                }
+                            } else if s == expr_div {
+                                if l < cur_val_level_kind::glue_val {
+                                    t = quotient(globals, t, f);
+                                } else {
+                                    let t = t as pointer;
+                                    width!(globals, t) =
+                                        scaled::new_from_inner(quotient(globals, width!(globals, t).inner(), f));
+                                    stretch!(globals, t) =
+                                        scaled::new_from_inner(quotient(globals, stretch!(globals, t).inner(), f));
+                                    shrink!(globals, t) =
+                                        scaled::new_from_inner(quotient(globals, shrink!(globals, t).inner(), f));
+                                }
+                            } else if s == expr_scale {
+                                if l == cur_val_level_kind::int_val {
+                                    t = fract(globals, t, n, f, infinity);
+                                } else if l == cur_val_level_kind::dimen_val {
+                                    t = fract(globals, t, n, f, max_dimen);
+                                } else {
+                                    let t = t as pointer;
+                                    width!(globals, t) = scaled::new_from_inner(fract(
+                                        globals,
+                                        width!(globals, t).inner(),
+                                        n,
+                                        f,
+                                        max_dimen,
+                                    ));
+                                    stretch!(globals, t) = scaled::new_from_inner(fract(
+                                        globals,
+                                        stretch!(globals, t).inner(),
+                                        n,
+                                        f,
+                                        max_dimen,
+                                    ));
+                                    shrink!(globals, t) = scaled::new_from_inner(fract(
+                                        globals,
+                                        shrink!(globals, t).inner(),
+                                        n,
+                                        f,
+                                        max_dimen,
+                                    ));
+                                }
+                            } else {
+                                confusion(globals, crate::strpool_str!("expr"))?;
+                            }
+                            if o > expr_sub {
+                                s = o;
+                            } else {
+                                // @<Evaluate the current expression@>
+                                s = expr_none;
+                                if r == expr_none {
+                                    e = t;
+                                } else if l == cur_val_level_kind::int_val {
+                                    e = add_or_sub(globals, e, t, infinity, r == expr_sub);
+                                } else if l == cur_val_level_kind::dimen_val {
+                                    e = add_or_sub(globals, e, t, max_dimen, r == expr_sub);
+                                } else {
+                                    Compute_the_sum_or_difference_of_two_glue_specs!(
+                                        globals,
+                                        e as pointer,
+                                        t as pointer,
+                                        r == expr_sub
+                                    );
+                                }
+                                r = o;
+                            }
+                            b = globals.arith_error;
+                            if o != expr_none {
+                                crate::goto_backward_label!('continue_);
+                            }
+                            if p != null {
+                                // @<Pop the expression stack and |goto found|@>
+                                f = e;
+                                q = p;
+                                e = expr_e_field!(globals, q);
+                                t = expr_t_field!(globals, q);
+                                n = expr_n_field!(globals, q);
+                                s = subtype!(globals, q) / 4;
+                                r = subtype!(globals, q) % 4;
+                                l = r#type!(globals, q).into();
+                                p = link!(globals, q);
+                                free_node(globals, q, expr_node_size as _);
+                                crate::goto_backward_label!('found);
+                            }
+                        }
+                        |'found|
+                    }
+                }
+                |'continue_|
+            }
+        }
+        |'restart|
+    }
+    if b {
+        print_err!(globals, crate::strpool_str!("Arithmetic overflow"));
+        help2!(
+            globals,
+            crate::strpool_str!("I can't evaluate this expression,"),
+            crate::strpool_str!("since the result is out of range.")
+        );
+        error(globals)?;
+        if l >= cur_val_level_kind::glue_val {
+            delete_glue_ref(globals, e as pointer);
+            e = zero_glue as _;
+            add_glue_ref!(globals, e as pointer);
+        } else {
+            e = 0;
+        }
+    }
+    globals.arith_error = a;
+    globals.cur_val = e;
+    globals.cur_val_level = l;
+    crate::ok_nojump!()
+}
+
+macro Compute_the_sum_or_difference_of_two_glue_specs($globals:expr, $e:expr, $t:expr, $negative:expr) {{
+    let (e, t) = ($e, $t);
+    let w = add_or_sub(
+        $globals,
+        width!($globals, e).inner(),
+        width!($globals, t).inner(),
+        max_dimen,
+        $negative,
+    );
+    width!($globals, e) = scaled::new_from_inner(w);
+    if stretch_order!($globals, e) == stretch_order!($globals, t) {
+        let w = add_or_sub(
+            $globals,
+            stretch!($globals, e).inner(),
+            stretch!($globals, t).inner(),
+            max_dimen,
+            $negative,
+        );
+        stretch!($globals, e) = scaled::new_from_inner(w);
+    } else if stretch_order!($globals, e) < stretch_order!($globals, t)
+        && stretch!($globals, t).inner() != 0
+    {
+        stretch!($globals, e) = stretch!($globals, t);
+        stretch_order!($globals, e) = stretch_order!($globals, t);
+    }
+    if shrink_order!($globals, e) == shrink_order!($globals, t) {
+        let w = add_or_sub(
+            $globals,
+            shrink!($globals, e).inner(),
+            shrink!($globals, t).inner(),
+            max_dimen,
+            $negative,
+        );
+        shrink!($globals, e) = scaled::new_from_inner(w);
+    } else if shrink_order!($globals, e) < shrink_order!($globals, t)
+        && shrink!($globals, t).inner() != 0
+    {
+        shrink!($globals, e) = shrink!($globals, t);
+        shrink_order!($globals, e) = shrink_order!($globals, t);
+    }
+    delete_glue_ref($globals, t);
+    normalize_glue!($globals, e);
+}}
+
+fn expr_m(globals: &mut TeXGlobals, x: integer, f: integer) -> integer {
+    nx_plus_y!(globals, x, scaled::new_from_inner(f), scaled::zero()).inner()
+}
+
+/// Compute `x+y` or `x-y`, with `arith_error` set if the magnitude of the
+/// answer exceeds `max_answer`.
+fn add_or_sub(
+    globals: &mut TeXGlobals,
+    x: integer,
+    mut y: integer,
+    max_answer: integer,
+    negative: boolean,
+) -> integer {
+    /// the answer
+    let a: integer;
+    if negative {
+        negate!(y);
+    }
+    if x >= 0 {
+        if y <= max_answer - x {
+            a = x + y;
+        } else {
+            num_error!(globals, a);
+        }
+    } else if y >= -max_answer - x {
+        a = x + y;
+    } else {
+        num_error!(globals, a);
+    }
+    a
+}
+
+/// Compute `n/d` rounded to the nearest integer, with halves rounded away
+/// from zero.
+fn quotient(globals: &mut TeXGlobals, mut n: integer, mut d: integer) -> integer {
+    /// should the answer be negated?
+    let mut negative: boolean;
+    /// the answer
+    let mut a: integer;
+    if d == 0 {
+        num_error!(globals, a);
+    } else {
+        if d > 0 {
+            negative = false;
+        } else {
+            negate!(d);
+            negative = true;
+        }
+        if n < 0 {
+            negate!(n);
+            negative = !negative;
+        }
+        a = n / d;
+        n -= a * d;
+        // avoid certain compiler optimizations!
+        d = n - d;
+        if d + n >= 0 {
+            incr!(a);
+        }
+        if negative {
+            negate!(a);
+        }
+    }
+    a
+}
+
+/// Compute `x*n/d` rounded to the nearest integer, with `arith_error` set
+/// if the magnitude of the answer exceeds `max_answer`.
+fn fract(
+    globals: &mut TeXGlobals,
+    mut x: integer,
+    mut n: integer,
+    mut d: integer,
+    max_answer: integer,
+) -> integer {
+    /// should the answer be negated?
+    let mut negative: boolean;
+    /// the answer
+    let mut a: integer;
+    /// a proper fraction
+    let mut f: integer;
+    /// smallest integer such that `2*h>=d`
+    let h: integer;
+    /// intermediate remainder
+    let mut r: integer;
+    /// temp variable
+    let mut t: integer;
+    crate::region_forward_label!(
+        |'done|
+        {
+            crate::region_forward_label!(
+                |'too_big|
+                {
+                    if d == 0 {
+                        crate::goto_forward_label!('too_big);
+                    }
+                    a = 0;
+                    if d > 0 {
+                        negative = false;
+                    } else {
+                        negate!(d);
+                        negative = true;
+                    }
+                    if x < 0 {
+                        negate!(x);
+                        negative = !negative;
+                    } else if x == 0 {
+                        crate::goto_forward_label!('done);
+                    }
+                    if n < 0 {
+                        negate!(n);
+                        negative = !negative;
+                    }
+                    crate::region_forward_label!(
+                        |'found|
+                        {
+                            t = n / d;
+                            if t > max_answer / x {
+                                crate::goto_forward_label!('too_big);
+                            }
+                            a = t * x;
+                            n -= t * d;
+                            if n == 0 {
+                                crate::goto_forward_label!('found);
+                            }
+                            t = x / d;
+                            if t > (max_answer - a) / n {
+                                crate::goto_forward_label!('too_big);
+                            }
+                            a += t * n;
+                            x -= t * d;
+                            if x == 0 {
+                                crate::goto_forward_label!('found);
+                            }
+                            if x < n {
+                                t = x;
+                                x = n;
+                                n = t;
+                            }
+                            // now `0<n<=x<d`
+                            // @<Compute \(f)$f=\lfloor xn/d+{1\over2}\rfloor$@>
+                            f = 0;
+                            r = (d / 2) - d;
+                            h = -r;
+                            crate::region_forward_label!(
+                                |'found1|
+                                {
+                                    loop {
+                                        if n % 2 != 0 {
+                                            r += x;
+                                            if r >= 0 {
+                                                r -= d;
+                                                incr!(f);
+                                            }
+                                        }
+                                        n /= 2;
+                                        if n == 0 {
+                                            crate::goto_forward_label!('found1);
+                                        }
+                                        if x < h {
+                                            x += x;
+                                        } else {
+                                            t = x - d;
+                                            x += t;
+                                            f += n;
+                                            if x < n {
+                                                if x == 0 {
+                                                    crate::goto_forward_label!('found1);
+                                                }
+                                                t = x;
+                                                x = n;
+                                                n = t;
+                                            }
+                                        }
+                                    }
+                                }
+                                'found1 <-
+                            );
+                            if f > max_answer - a {
+                                crate::goto_forward_label!('too_big);
+                            }
+                            a += f;
+                        }
+                        'found <-
+                    );
+                    if negative {
+                        negate!(a);
+                    }
+                    crate::goto_forward_label!('done);
+                }
+                'too_big <-
+            );
+            num_error!(globals, a);
+        }
+        'done <-
+    );
+    a
+}
+
+/// Print the `\protected` prefix of a macro, which is kept as the first token
+/// of its text.
+pub(crate) fn print_protected_prefix(
+    globals: &mut TeXGlobals,
+    cmd: quarterword,
+    ref_count: pointer,
+) {
+    if info_tok!(globals, link!(globals, ref_count)).get() == protected_token {
+        print_esc(globals, crate::strpool_str!("protected"));
+        if cmd == call {
+            print_char(
+                make_globals_io_string_log_view!(globals),
+                ASCII_code_literal!(b' '),
+            );
+        }
+    }
+}
+
+/// Print `\unexpanded` and `\detokenize`; returns `false` for `\the`.
+pub(crate) fn print_the(globals: &mut TeXGlobals, chr_code: chr_code_repr) -> bool {
+    if chr_code == unexpanded_code {
+        print_esc(globals, crate::strpool_str!("unexpanded"));
+    } else if chr_code == detokenize_code {
+        print_esc(globals, crate::strpool_str!("detokenize"));
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Split the `\protected` prefix off the accumulated prefix codes `a`;
+/// `j` becomes the token that marks the text of a protected macro.
+pub(crate) macro Split_off_the_protected_prefix($a:expr, $j:expr) {{
+    if $a >= protected_code {
+        $j = protected_token;
+        $a = $a - protected_code;
+    } else {
+        $j = 0;
+    }
+}}
+
+/// Insert the mark of a `\protected` macro ahead of its parameter part.
+pub(crate) macro Mark_the_macro_as_protected($globals:expr, $j:expr) {{
+    let q = get_avail($globals)?;
+    info_tok_assign!($globals, q, cur_tok_type::new($j));
+    link!($globals, q) = link!($globals, $globals.def_ref);
+    link!($globals, $globals.def_ref) = q;
+    use crate::section_0118::info_tok_assign;
+    use crate::section_0120::get_avail;
+}}
+
+/// A protected macro is not expanded while building an expanded token list;
+/// it is passed on as if it were preceded by `\noexpand`.
+pub(crate) macro Suppress_expansion_of_a_protected_macro($globals:expr) {{
+    if $globals.cur_cmd >= call
+        && info_tok!($globals, link!($globals, $globals.cur_chr.get() as pointer)).get()
+            == protected_token
+    {
+        $globals.cur_cmd = relax;
+        $globals.cur_chr = chr_code_type::new(no_expand_flag);
+    }
+    use crate::section_0358::no_expand_flag;
+}}
+
+/// `\unexpanded` and `\detokenize` take a general text instead of an
+/// internal quantity; `the_toks` returns this function's result for them.
+pub(crate) fn the_toks_of_general_text(globals: &mut TeXGlobals) -> TeXResult<pointer> {
+    let c = globals.cur_chr.get();
+    scan_general_text(globals)?;
+    if c == unexpanded_code {
+        return crate::ok_nojump!(globals.cur_val as pointer);
+    }
+    let old_setting = globals.selector;
+    globals.selector = new_string.into();
+    let b = globals.pool_ptr;
+    let p = get_avail(globals)?;
+    link!(globals, p) = link!(globals, temp_head);
+    token_show(globals, p);
+    flush_list(globals, p);
+    globals.selector = old_setting;
+    crate::ok_nojump!(str_toks(globals, b)?)
+}
+
+/// Absorb a balanced text without expanding it. Afterwards
+/// `link(temp_head)` is the list and `cur_val` its tail, or `temp_head`
+/// for an empty list.
+pub(crate) fn scan_general_text(globals: &mut TeXGlobals) -> TeXResult<()> {
+    let (mut p, mut q): (pointer, pointer);
+    let mut unbalance: halfword;
+    let s = globals.scanner_status;
+    let w = globals.warning_index;
+    let d = globals.def_ref;
+    globals.scanner_status = scanner_status_kind::absorbing;
+    globals.warning_index = globals.cur_cs;
+    globals.def_ref = get_avail(globals)?;
+    token_ref_count!(globals, globals.def_ref) = null;
+    p = globals.def_ref;
+    // remove the compulsory left brace
+    scan_left_brace(globals)?;
+    unbalance = 1;
+    loop {
+        get_token(globals)?;
+        if globals.cur_tok < right_brace_limit {
+            if globals.cur_cmd < right_brace {
+                incr!(unbalance);
+            } else {
+                decr!(unbalance);
+                if unbalance == 0 {
+                    break;
+                }
+            }
+        }
+        store_new_token!(globals, globals.cur_tok.get(), p, q);
+    }
+    q = link!(globals, globals.def_ref);
+    // discard reference count
+    free_avail!(globals, globals.def_ref);
+    if q == null {
+        globals.cur_val = temp_head as _;
+    } else {
+        globals.cur_val = p as _;
+    }
+    link!(globals, temp_head) = q;
+    globals.scanner_status = s;
+    globals.warning_index = w;
+    globals.def_ref = d;
+    crate::ok_nojump!()
+}
+
+// @d every_eof==equiv(every_eof_loc)
+pub(crate) macro every_eof($globals:expr) {
+    crate::section_0221::equiv!($globals, crate::section_0230::every_eof_loc)
+}
+
+/// `\scantokens` reads the text of its argument as if it were the contents
+/// of a file. The text is split into lines at `new_line_char` and stored in
+/// a pseudo file; `get_next` then reads it through `pseudo_input`.
+pub(crate) fn pseudo_start(globals: &mut TeXGlobals) -> TeXResult<()> {
+    scan_general_text(globals)?;
+    let old_setting = globals.selector;
+    globals.selector = new_string.into();
+    let b = globals.pool_ptr;
+    token_show(globals, temp_head);
+    globals.selector = old_setting;
+    flush_list(globals, link!(globals, temp_head));
+    #[cfg(feature = "unicode_support")]
+    let chars = globals
+        .str_pool
+        .slice_ascii_codes(b..globals.pool_ptr)
+        .map(xord)
+        .collect::<Vec<ASCII_code>>();
+    #[cfg(not(feature = "unicode_support"))]
+    let chars = globals.str_pool[b..globals.pool_ptr]
+        .iter()
+        .map(|c| ASCII_code::from(c.0 as integer))
+        .collect::<Vec<ASCII_code>>();
+    globals.pool_ptr = b;
+    Convert_the_characters_into_a_new_pseudo_file!(globals, chars);
+    Initiate_input_from_new_pseudo_file!(globals);
+    crate::ok_nojump!()
+}
+
+/// Each line becomes a node whose `info` is its size; the characters follow,
+/// one per word, since they need not fit into a quarterword. An empty line
+/// holds a single space, which `pseudo_input` removes again.
+macro Convert_the_characters_into_a_new_pseudo_file($globals:expr, $chars:expr) {{
+    let nl = new_line_char!($globals);
+    let p = get_avail($globals)?;
+    let mut q = p;
+    let mut l = 0;
+    while l < $chars.len() {
+        let m = l;
+        while l < $chars.len() && $chars[l].numeric_value() as integer != nl {
+            incr!(l);
+        }
+        let mut sz = (l - m + 1) as halfword;
+        if sz == 1 {
+            sz = 2;
+        }
+        let r = get_node($globals, sz as _)?;
+        link!($globals, q) = r;
+        q = r;
+        info_inner!($globals, q) = sz as _;
+        $globals.mem[r + 1][MEMORY_WORD_INT] = b' ' as integer;
+        for (k, c) in $chars[m..l].iter().enumerate() {
+            $globals.mem[r + 1 + k as pointer][MEMORY_WORD_INT] = c.numeric_value() as integer;
+        }
+        if l < $chars.len() {
+            incr!(l);
+        }
+    }
+    info_inner!($globals, p) = link!($globals, p) as _;
+    link!($globals, p) = $globals.pseudo_files;
+    $globals.pseudo_files = p;
+    use crate::section_0236::new_line_char;
+}}
+
+macro Initiate_input_from_new_pseudo_file($globals:expr) {{
+    // set up `cur_file` and new level of input
+    begin_file_reading($globals)?;
+    $globals.line = 0;
+    limit!($globals) = start!($globals);
+    // force line read
+    loc!($globals) = limit!($globals) + 1;
+    name!($globals) = 18;
+    use crate::section_0036::loc;
+    use crate::section_0302::limit;
+    use crate::section_0302::name;
+    use crate::section_0302::start;
+}}
+
+/// Inputs the next line of the current pseudo file, or returns `false` at its end.
+pub(crate) fn pseudo_input(globals: &mut TeXGlobals) -> TeXResult<boolean> {
+    globals.last = globals.first;
+    let p = info_inner!(globals, globals.pseudo_files) as pointer;
+    if p == null {
+        return crate::ok_nojump!(false);
+    }
+    info_inner!(globals, globals.pseudo_files) = link!(globals, p) as _;
+    let sz = info_inner!(globals, p);
+    if sz as integer - 1 >= globals.buf_size as integer - globals.last.get() as integer {
+        loc!(globals) = globals.first.get() as _;
+        limit!(globals) = globals.last.get() as halfword - 1;
+        overflow(
+            globals,
+            crate::strpool_str!("buffer size"),
+            globals.buf_size as _,
+        )?;
+    }
+    for r in p + 1..p + sz as pointer {
+        globals.buffer[globals.last] = ASCII_code::from(globals.mem[r][MEMORY_WORD_INT]);
+        incr!(globals.last);
+    }
+    if globals.last >= globals.max_buf_stack {
+        globals.max_buf_stack = globals.last + 1;
+    }
+    while globals.last > globals.first
+        && globals.buffer[globals.last - 1] == ASCII_code_literal!(b' ')
+    {
+        decr!(globals.last);
+    }
+    free_node(globals, p, sz);
+    crate::ok_nojump!(true)
+}
+
+/// Closes the top level pseudo file.
+pub(crate) fn pseudo_close(globals: &mut TeXGlobals) {
+    let mut p = link!(globals, globals.pseudo_files);
+    let mut q = info_inner!(globals, globals.pseudo_files) as pointer;
+    free_avail!(globals, globals.pseudo_files);
+    globals.pseudo_files = p;
+    while q != null {
+        p = q;
+        q = link!(globals, p);
+        free_node(globals, p, info_inner!(globals, p));
+    }
+}
+
+/// At the end of a file or pseudo file, `\everyeof` is inserted once, as if
+/// the file had one more, empty line.
+pub(crate) macro Insert_everyeof_once_and_goto_restart($globals:expr, $lbl_restart:lifetime) {{
+    if every_eof!($globals) != null && !$globals.eof_seen[index!($globals) as usize] {
+        // fake one empty line
+        limit!($globals) = $globals.first.get() as halfword - 1;
+        $globals.eof_seen[index!($globals) as usize] = true;
+        begin_token_list($globals, every_eof!($globals), every_eof_text);
+        crate::goto_backward_label!($lbl_restart);
+    }
+    use crate::section_0302::index;
+    use crate::section_0302::limit;
+    use crate::section_0323::begin_token_list;
+}}
+
+/// `\readline` stores the rest of the line as characters of category other,
+/// except that spaces keep category space.
+pub(crate) macro Handle_readline_and_goto_done($globals:expr, $p:expr, $q:expr, $lbl_done:lifetime) {{
+    // current line not yet finished
+    while loc!($globals) <= limit!($globals) {
+        let c = $globals.buffer[loc!($globals)];
+        incr!(loc!($globals));
+        let t = if c == ASCII_code_literal!(b' ') {
+            space_token
+        } else {
+            c.numeric_value() as cur_tok_repr + other_token
+        };
+        store_new_token!($globals, t, $p, $q);
+    }
+    crate::goto_forward_label!($lbl_done);
+    use crate::section_0036::loc;
+    use crate::section_0289::space_token;
+    use crate::section_0302::limit;
+}}
+
+/// the box registers, sharing their level code with `ident_val`
+pub(crate) const box_val: quarterword = 4;
+/// the largest register number; `\insert` keeps the limit of 255
+pub(crate) const max_reg_num: integer = 32767;
+/// context code for `\global\setbox0`
+pub(crate) const global_box_flag: integer = box_flag + 32768;
+/// `save_type` when the `sa_chain` of an outer group should be restored
+pub(crate) const restore_sa: quarterword = level_boundary + 2;
+
+/// Registers 256 and above live in sparse arrays in `mem`, one for each of
+/// the classes `int_val..tok_val`, as in e-TeX. Each array is a tree of
+/// index nodes four levels deep, indexed by the hexadecimal digits of the
+/// register number; only the elements that differ from their default values
+/// (and those still referenced) are kept.
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static sa_root: [pointer; 6] = [null; 6];
+
+/// the element found by `find_sa_element`
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static cur_ptr: pointer = null;
+
+/// the chain of saved sparse array elements of the current group
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static sa_chain: pointer = null;
+
+/// the group level of `sa_chain`
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static sa_level: quarterword = level_zero;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::section_0113::quarterword;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::section_0221::level_zero;
+
+/// size of an index node, with sixteen pointers
+const index_node_size: integer = 9;
+/// size of an element with a pointer value
+const pointer_node_size: integer = 2;
+/// size of an element with a word value
+const word_node_size: integer = 3;
+/// `sa_index` limits of the `dimen_val`, `mu_val`, `box_val` and `tok_val` elements
+const dimen_val_limit: quarterword = 0x20;
+const mu_val_limit: quarterword = 0x40;
+const box_val_limit: quarterword = 0x50;
+const tok_val_limit: quarterword = 0x60;
+
+/// a four-bit address or a type or both
+macro sa_index($globals:expr, $p:expr) {
+    r#type!($globals, $p)
+}
+/// count of non-null pointers
+macro sa_used($globals:expr, $p:expr) {
+    subtype!($globals, $p)
+}
+/// grouping level for the current value
+macro sa_lev($globals:expr, $p:expr) {
+    subtype!($globals, $p)
+}
+/// reference count of a sparse array element
+macro sa_ref($globals:expr, $p:expr) {
+    info_inner!($globals, $p + 1)
+}
+/// location of a saved item
+macro sa_loc($globals:expr, $p:expr) {
+    sa_ref!($globals, $p)
+}
+/// a pointer value
+pub(crate) macro sa_ptr($globals:expr, $p:expr) {
+    link!($globals, $p + 1)
+}
+/// the register number of a word element
+macro sa_num($globals:expr, $p:expr) {
+    sa_ptr!($globals, $p)
+}
+/// an integer or dimension value
+pub(crate) macro sa_int($globals:expr, $p:expr) {
+    $globals.mem[$p + 2][MEMORY_WORD_INT]
+}
+
+/// the type part of the combined type and index of an element
+pub(crate) fn sa_type(globals: &TeXGlobals, p: pointer) -> quarterword {
+    sa_index!(globals, p) / 16
+}
+
+/// Is this `chr_code` of `register` or `toks_register` a sparse array element,
+/// as set up by `\countdef` and friends for registers above 255?
+pub(crate) fn is_sa_element(chr_code: chr_code_repr) -> boolean {
+    chr_code as pointer > lo_mem_stat_max
+}
+
+fn get_sa_ptr(globals: &TeXGlobals, q: pointer, i: quarterword) -> pointer {
+    if i.is_odd() {
+        link!(globals, q + i as pointer / 2 + 1)
+    } else {
+        info_inner!(globals, q + i as pointer / 2 + 1)
+    }
+}
+
+fn put_sa_ptr(globals: &mut TeXGlobals, q: pointer, i: quarterword, p: pointer) {
+    if i.is_odd() {
+        link!(globals, q + i as pointer / 2 + 1) = p;
+    } else {
+        info_inner!(globals, q + i as pointer / 2 + 1) = p;
+    }
+}
+
+fn add_sa_ptr(globals: &mut TeXGlobals, q: pointer, i: quarterword) {
+    put_sa_ptr(globals, q, i, globals.cur_ptr);
+    incr!(sa_used!(globals, q));
+}
+
+fn delete_sa_ptr(globals: &mut TeXGlobals, q: pointer, i: quarterword) {
+    put_sa_ptr(globals, q, i, null);
+    decr!(sa_used!(globals, q));
+}
+
+pub(crate) fn add_sa_ref(globals: &mut TeXGlobals, p: pointer) {
+    incr!(sa_ref!(globals, p));
+}
+
+/// Create an index node with address or type `i` below node `q`.
+fn new_index(globals: &mut TeXGlobals, i: quarterword, q: pointer) -> TeXResult<()> {
+    globals.cur_ptr = get_node(globals, index_node_size)?;
+    sa_index!(globals, globals.cur_ptr) = i;
+    sa_used!(globals, globals.cur_ptr) = 0;
+    link!(globals, globals.cur_ptr) = q;
+    // clear all 16 pointers
+    for k in 1..index_node_size as pointer {
+        info_inner!(globals, globals.cur_ptr + k) = null;
+        link!(globals, globals.cur_ptr + k) = null;
+    }
+    crate::ok_nojump!()
+}
+
+/// Set `cur_ptr` to element `n` of the sparse array of type `t`, or to `null`
+/// if there is no such element; it is created if `w` is true.
+pub(crate) fn find_sa_element(
+    globals: &mut TeXGlobals,
+    t: quarterword,
+    n: integer,
+    w: boolean,
+) -> TeXResult<()> {
+    let digits = [
+        (n / 4096) as quarterword,
+        (n / 256 % 16) as quarterword,
+        (n / 16 % 16) as quarterword,
+        (n % 16) as quarterword,
+    ];
+    let mut q = null;
+    let mut i = t;
+    let mut k = 0;
+    globals.cur_ptr = globals.sa_root[t as usize];
+    while k < 4 && globals.cur_ptr != null {
+        q = globals.cur_ptr;
+        i = digits[k];
+        globals.cur_ptr = get_sa_ptr(globals, q, i);
+        k += 1;
+    }
+    if globals.cur_ptr != null || !w {
+        crate::return_nojump!();
+    }
+    if k == 0 {
+        // create first level index node
+        new_index(globals, t, null)?;
+        globals.sa_root[t as usize] = globals.cur_ptr;
+        q = globals.cur_ptr;
+        i = digits[0];
+        k = 1;
+    }
+    while k < 4 {
+        // create the missing lower level index nodes
+        new_index(globals, i, q)?;
+        add_sa_ptr(globals, q, i);
+        q = globals.cur_ptr;
+        i = digits[k];
+        k += 1;
+    }
+    // Create a new array element of type `t` with index `i`
+    if t <= cur_val_level_kind::dimen_val as quarterword {
+        // a count or dimen register
+        globals.cur_ptr = get_node(globals, word_node_size)?;
+        sa_int!(globals, globals.cur_ptr) = 0;
+        sa_num!(globals, globals.cur_ptr) = n as pointer;
+    } else {
+        globals.cur_ptr = get_node(globals, pointer_node_size)?;
+        if t <= cur_val_level_kind::mu_val as quarterword {
+            // a skip or muskip register
+            sa_ptr!(globals, globals.cur_ptr) = zero_glue;
+            add_glue_ref!(globals, zero_glue);
+        } else {
+            // a box or token list register
+            sa_ptr!(globals, globals.cur_ptr) = null;
+        }
+    }
+    // all registers have a reference count
+    sa_ref!(globals, globals.cur_ptr) = null;
+    sa_index!(globals, globals.cur_ptr) = 16 * t + i;
+    sa_lev!(globals, globals.cur_ptr) = level_one;
+    link!(globals, globals.cur_ptr) = q;
+    add_sa_ptr(globals, q, i);
+    crate::ok_nojump!()
+}
+
+/// Reduce the reference count of element `q`, and free it together with any
+/// index nodes that become empty once it is unreferenced and has its default
+/// value again.
+pub(crate) fn delete_sa_ref(globals: &mut TeXGlobals, mut q: pointer) {
+    /// size of a node
+    let mut s: integer;
+    decr!(sa_ref!(globals, q));
+    if sa_ref!(globals, q) != null {
+        return;
+    }
+    if sa_index!(globals, q) < dimen_val_limit {
+        if sa_int!(globals, q) == 0 {
+            s = word_node_size;
+        } else {
+            return;
+        }
+    } else {
+        if sa_index!(globals, q) < mu_val_limit {
+            if sa_ptr!(globals, q) == zero_glue {
+                delete_glue_ref(globals, zero_glue);
+            } else {
+                return;
+            }
+        } else if sa_ptr!(globals, q) != null {
+            return;
+        }
+        s = pointer_node_size;
+    }
+    loop {
+        let i = sa_index!(globals, q) % 16;
+        let p = q;
+        q = link!(globals, p);
+        free_node(globals, p, s as _);
+        if q == null {
+            // the whole tree has been freed
+            globals.sa_root[i as usize] = null;
+            return;
+        }
+        delete_sa_ptr(globals, q, i);
+        // node `q` is an index node
+        s = index_node_size;
+        if sa_used!(globals, q) > 0 {
+            break;
+        }
+    }
+}
+
+/// Print the register number of element `q`.
+pub(crate) fn print_sa_num(globals: &mut TeXGlobals, mut q: pointer) {
+    /// the register number
+    let mut n: integer;
+    if sa_index!(globals, q) < dimen_val_limit {
+        // the easy case
+        n = sa_num!(globals, q) as integer;
+    } else {
+        n = (sa_index!(globals, q) % 16) as integer;
+        q = link!(globals, q);
+        n += 16 * sa_index!(globals, q) as integer;
+        q = link!(globals, q);
+        n += 256
+            * (sa_index!(globals, q) as integer
+                + 16 * sa_index!(globals, link!(globals, q)) as integer);
+    }
+    print_int(globals, n);
+}
+
+/// The `print_cmd_chr` cases of `register` and `toks_register` when the
+/// `chr_code` is a sparse array element; returns `false` for the others.
+pub(crate) fn print_sa_register(globals: &mut TeXGlobals, chr_code: chr_code_repr) -> boolean {
+    if !is_sa_element(chr_code) {
+        return false;
+    }
+    let p = chr_code as pointer;
+    let t = sa_type(globals, p);
+    if t == cur_val_level_kind::int_val as quarterword {
+        print_esc(globals, crate::strpool_str!("count"));
+    } else if t == cur_val_level_kind::dimen_val as quarterword {
+        print_esc(globals, crate::strpool_str!("dimen"));
+    } else if t == cur_val_level_kind::glue_val as quarterword {
+        print_esc(globals, crate::strpool_str!("skip"));
+    } else if t == cur_val_level_kind::mu_val as quarterword {
+        print_esc(globals, crate::strpool_str!("muskip"));
+    } else if t == box_val {
+        print_esc(globals, crate::strpool_str!("box"));
+    } else {
+        print_esc(globals, crate::strpool_str!("toks"));
+    }
+    print_sa_num(globals, p);
+    true
+}
+
+/// The `restore_trace` of sparse array element `p`.
+#[cfg(feature = "statistics")]
+fn show_sa(globals: &mut TeXGlobals, p: pointer, s: str_number) {
+    /// the type of element
+    let t: quarterword;
+    begin_diagnostic(globals);
+    print_char(
+        make_globals_io_string_log_view!(globals),
+        ASCII_code_literal!(b'{'),
+    );
+    print(globals, s.get() as _);
+    print_char(
+        make_globals_io_string_log_view!(globals),
+        ASCII_code_literal!(b' '),
+    );
+    t = sa_type(globals, p);
+    print_sa_register(globals, p as chr_code_repr);
+    print_char(
+        make_globals_io_string_log_view!(globals),
+        ASCII_code_literal!(b'='),
+    );
+    if t == cur_val_level_kind::int_val as quarterword {
+        print_int(globals, sa_int!(globals, p));
+    } else if t == cur_val_level_kind::dimen_val as quarterword {
+        print_scaled(globals, scaled::new_from_inner(sa_int!(globals, p)));
+        print(globals, crate::strpool_str!("pt").get() as _);
+    } else if t == cur_val_level_kind::glue_val as quarterword {
+        print_spec(globals, sa_ptr!(globals, p) as _, crate::strpool_str!("pt"));
+    } else if t == cur_val_level_kind::mu_val as quarterword {
+        print_spec(globals, sa_ptr!(globals, p) as _, crate::strpool_str!("mu"));
+    } else if t == box_val {
+        if sa_ptr!(globals, p) == null {
+            print(globals, crate::strpool_str!("void").get() as _);
+        } else {
+            globals.depth_threshold = 0;
+            globals.breadth_max = 1;
+            show_box(globals, sa_ptr!(globals, p));
+        }
+    } else if sa_ptr!(globals, p) != null {
+        show_token_list(
+            globals,
+            link!(globals, sa_ptr!(globals, p)) as _,
+            null as _,
+            32,
+        );
+    }
+    print_char(
+        make_globals_io_string_log_view!(globals),
+        ASCII_code_literal!(b'}'),
+    );
+    end_diagnostic(globals, false);
+}
+
+/// Save the value of element `p` on `sa_chain`, opening a new chain for the
+/// current group if necessary.
+fn sa_save(globals: &mut TeXGlobals, p: pointer) -> TeXResult<()> {
+    /// the new save node
+    let q: pointer;
+    /// index field of node
+    let mut i: quarterword;
+    if globals.cur_level != globals.sa_level {
+        check_full_save_stack!(globals);
+        save_type!(globals, globals.save_ptr) = restore_sa;
+        save_level!(globals, globals.save_ptr) = globals.sa_level;
+        save_index!(globals, globals.save_ptr) = globals.sa_chain;
+        incr!(globals.save_ptr);
+        globals.sa_chain = null;
+        globals.sa_level = globals.cur_level;
+    }
+    i = sa_index!(globals, p);
+    if i < dimen_val_limit {
+        if sa_int!(globals, p) == 0 {
+            q = get_node(globals, pointer_node_size)?;
+            i = tok_val_limit;
+        } else {
+            q = get_node(globals, word_node_size)?;
+            sa_int!(globals, q) = sa_int!(globals, p);
+        }
+        sa_ptr!(globals, q) = null;
+    } else {
+        q = get_node(globals, pointer_node_size)?;
+        sa_ptr!(globals, q) = sa_ptr!(globals, p);
+    }
+    sa_loc!(globals, q) = p;
+    sa_index!(globals, q) = i;
+    sa_lev!(globals, q) = sa_lev!(globals, p);
+    link!(globals, q) = globals.sa_chain;
+    globals.sa_chain = q;
+    add_sa_ref(globals, p);
+    crate::ok_nojump!()
+}
+
+/// Destroy the pointer value of element `p`.
+fn sa_destroy(globals: &mut TeXGlobals, p: pointer) -> TeXResult<()> {
+    if sa_index!(globals, p) < mu_val_limit {
+        delete_glue_ref(globals, sa_ptr!(globals, p));
+    } else if sa_ptr!(globals, p) != null {
+        if sa_index!(globals, p) < box_val_limit {
+            flush_node_list(globals, sa_ptr!(globals, p))?;
+        } else {
+            delete_token_ref(globals, sa_ptr!(globals, p));
+        }
+    }
+    crate::ok_nojump!()
+}
+
+/// The `eq_define` of sparse array elements with pointer values.
+pub(crate) fn sa_def(globals: &mut TeXGlobals, p: pointer, e: pointer) -> TeXResult<()> {
+    add_sa_ref(globals, p);
+    if sa_ptr!(globals, p) == e {
+        sa_destroy(globals, p)?;
+    } else {
+        if sa_lev!(globals, p) == globals.cur_level {
+            sa_destroy(globals, p)?;
+        } else {
+            sa_save(globals, p)?;
+        }
+        sa_lev!(globals, p) = globals.cur_level;
+        sa_ptr!(globals, p) = e;
+    }
+    delete_sa_ref(globals, p);
+    crate::ok_nojump!()
+}
+
+/// The `eq_word_define` of sparse array elements.
+pub(crate) fn sa_w_def(globals: &mut TeXGlobals, p: pointer, w: integer) -> TeXResult<()> {
+    add_sa_ref(globals, p);
+    if sa_int!(globals, p) != w {
+        if sa_lev!(globals, p) != globals.cur_level {
+            sa_save(globals, p)?;
+        }
+        sa_lev!(globals, p) = globals.cur_level;
+        sa_int!(globals, p) = w;
+    }
+    delete_sa_ref(globals, p);
+    crate::ok_nojump!()
+}
+
+/// The `geq_define` of sparse array elements with pointer values.
+pub(crate) fn gsa_def(globals: &mut TeXGlobals, p: pointer, e: pointer) -> TeXResult<()> {
+    add_sa_ref(globals, p);
+    sa_destroy(globals, p)?;
+    sa_lev!(globals, p) = level_one;
+    sa_ptr!(globals, p) = e;
+    delete_sa_ref(globals, p);
+    crate::ok_nojump!()
+}
+
+/// The `geq_word_define` of sparse array elements.
+pub(crate) fn gsa_w_def(globals: &mut TeXGlobals, p: pointer, w: integer) {
+    add_sa_ref(globals, p);
+    sa_lev!(globals, p) = level_one;
+    sa_int!(globals, p) = w;
+    delete_sa_ref(globals, p);
+}
+
+/// `define` for a location that is a sparse array element if `e`.
+pub(crate) macro sa_define($globals:expr, $a:expr, $e:expr, $p:expr, $t:expr, $v:expr) {
+    if $e {
+        if global!($a) {
+            crate::latex_support::gsa_def($globals, $p, $v)?;
+        } else {
+            crate::latex_support::sa_def($globals, $p, $v)?;
+        }
+    } else {
+        crate::section_1214::define!($globals, $a, $p, $t, $v);
+    }
+}
+
+/// `word_define` for a location that is a sparse array element if `e`.
+pub(crate) macro sa_word_define($globals:expr, $a:expr, $e:expr, $p:expr, $w:expr) {
+    if $e {
+        if global!($a) {
+            crate::latex_support::gsa_w_def($globals, $p, $w);
+        } else {
+            crate::latex_support::sa_w_def($globals, $p, $w)?;
+        }
+    } else {
+        crate::section_1214::word_define!($globals, $a, $p, $w);
+    }
+}
+
+/// Restore the elements on `sa_chain` at the end of a group; `unsave` then
+/// goes back to the chain of the enclosing group.
+pub(crate) fn sa_restore(globals: &mut TeXGlobals) -> TeXResult<()> {
+    /// sparse array element
+    let mut p: pointer;
+    loop {
+        p = sa_loc!(globals, globals.sa_chain);
+        if sa_lev!(globals, p) == level_one {
+            if sa_index!(globals, p) >= dimen_val_limit {
+                sa_destroy(globals, globals.sa_chain)?;
+            }
+            crate::region_stat! {
+                if tracing_restores!(globals) > 0 {
+                    show_sa(globals, p, crate::strpool_str!("retaining"));
+                }
+                use crate::section_0236::tracing_restores;
+            }
+        } else {
+            if sa_index!(globals, p) < dimen_val_limit {
+                if sa_index!(globals, globals.sa_chain) < dimen_val_limit {
+                    sa_int!(globals, p) = sa_int!(globals, globals.sa_chain);
+                } else {
+                    sa_int!(globals, p) = 0;
+                }
+            } else {
+                sa_destroy(globals, p)?;
+                sa_ptr!(globals, p) = sa_ptr!(globals, globals.sa_chain);
+            }
+            sa_lev!(globals, p) = sa_lev!(globals, globals.sa_chain);
+            crate::region_stat! {
+                if tracing_restores!(globals) > 0 {
+                    show_sa(globals, p, crate::strpool_str!("restoring"));
+                }
+                use crate::section_0236::tracing_restores;
+            }
+        }
+        delete_sa_ref(globals, p);
+        p = globals.sa_chain;
+        globals.sa_chain = link!(globals, p);
+        if sa_index!(globals, p) < dimen_val_limit {
+            free_node(globals, p, word_node_size as _);
+        } else {
+            free_node(globals, p, pointer_node_size as _);
+        }
+        if globals.sa_chain == null {
+            break;
+        }
+    }
+    crate::ok_nojump!()
+}
+
+/// The `restore_sa` counterpart of
+/// `Store_s_save_stack_save_ptr_in_eqtb_p__unless_eqtb_p_holds_a_global_value`.
+pub(crate) fn sa_chain_restore(globals: &mut TeXGlobals) -> TeXResult<()> {
+    sa_restore(globals)?;
+    globals.sa_chain = save_index!(globals, globals.save_ptr);
+    globals.sa_level = save_level!(globals, globals.save_ptr);
+    crate::ok_nojump!()
+}
+
+/// `scan_eight_bit_int` for register numbers, which may go up to `max_reg_num`.
+pub(crate) fn scan_register_num(globals: &mut TeXGlobals) -> TeXResult<()> {
+    scan_int(globals)?;
+    let max_reg = if globals.latex_support_enabled {
+        max_reg_num
+    } else {
+        255
+    };
+    if globals.cur_val < 0 || globals.cur_val > max_reg {
+        print_err!(globals, crate::strpool_str!("Bad register code"));
+        if globals.latex_support_enabled {
+            help2!(
+                globals,
+                crate::strpool_str!("A register number must be between 0 and 32767."),
+                crate::strpool_str!("I changed this one to zero.")
+            );
+        } else {
+            help2!(
+                globals,
+                crate::strpool_str!("A register number must be between 0 and 255."),
+                crate::strpool_str!("I changed this one to zero.")
+            );
+        }
+        int_error(globals, globals.cur_val)?;
+        globals.cur_val = 0;
+    }
+    crate::ok_nojump!()
+}
+
+/// The value of register `n` of type `t`, which is either an integer or
+/// dimension, or a pointer to glue or a token list.
+fn fetch_sa_value(globals: &mut TeXGlobals, t: quarterword, n: integer) -> TeXResult<integer> {
+    find_sa_element(globals, t, n, false)?;
+    let v = if globals.cur_ptr == null {
+        if t < cur_val_level_kind::glue_val as quarterword {
+            0
+        } else if t <= cur_val_level_kind::mu_val as quarterword {
+            zero_glue as integer
+        } else {
+            null as integer
+        }
+    } else if t < cur_val_level_kind::glue_val as quarterword {
+        sa_int!(globals, globals.cur_ptr)
+    } else {
+        sa_ptr!(globals, globals.cur_ptr) as integer
+    };
+    crate::ok_nojump!(v)
+}
+
+/// Fetch a register given by `m`, the `chr_code` of a `register` command,
+/// when it is a sparse array element or when the register number that
+/// follows is above 255; otherwise only scan the number and return `false`.
+pub(crate) fn fetch_sa_register(globals: &mut TeXGlobals, m: chr_code_repr) -> TeXResult<boolean> {
+    if is_sa_element(m) {
+        let p = m as pointer;
+        let t = sa_type(globals, p);
+        globals.cur_val = if t < cur_val_level_kind::glue_val as quarterword {
+            sa_int!(globals, p)
+        } else {
+            sa_ptr!(globals, p) as integer
+        };
+        globals.cur_val_level = t.into();
+        return crate::ok_nojump!(true);
+    }
+    scan_register_num(globals)?;
+    if globals.cur_val > 255 {
+        globals.cur_val = fetch_sa_value(globals, m as quarterword, globals.cur_val)?;
+        globals.cur_val_level = (m as quarterword).into();
+        return crate::ok_nojump!(true);
+    }
+    crate::ok_nojump!(false)
+}
+
+/// The token list of the `\toks` register given by `m`, the `chr_code` of a
+/// `toks_register` command.
+pub(crate) fn fetch_toks_register(
+    globals: &mut TeXGlobals,
+    m: chr_code_repr,
+) -> TeXResult<pointer> {
+    if is_sa_element(m) {
+        return crate::ok_nojump!(sa_ptr!(globals, m as pointer));
+    }
+    scan_register_num(globals)?;
+    let q = if globals.cur_val < 256 {
+        equiv!(globals, toks_base as pointer + globals.cur_val as pointer)
+    } else {
+        fetch_sa_value(
+            globals,
+            cur_val_level_kind::tok_val as quarterword,
+            globals.cur_val,
+        )? as pointer
+    };
+    crate::ok_nojump!(q)
+}
+
+/// The old value of the register at `l` for `do_register_command`, as a
+/// word and as a pointer; `l` is a sparse array element if `e`.
+pub(crate) fn fetch_register_location(
+    globals: &TeXGlobals,
+    e: boolean,
+    l: pointer,
+    p: cur_val_level_kind,
+) -> (integer, pointer) {
+    if !e {
+        (globals.eqtb[l][MEMORY_WORD_INT], equiv!(globals, l))
+    } else if p < cur_val_level_kind::glue_val {
+        (sa_int!(globals, l), null)
+    } else {
+        (0, sa_ptr!(globals, l))
+    }
+}
+
+/// The content of box register `n`.
+pub(crate) fn fetch_box(globals: &mut TeXGlobals, n: integer) -> TeXResult<pointer> {
+    if n < 256 {
+        crate::ok_nojump!(r#box!(globals, n))
+    } else {
+        crate::ok_nojump!(fetch_sa_value(globals, box_val, n)? as pointer)
+    }
+}
+
+/// Change the content of box register `n` to `p`, at the same level.
+pub(crate) fn change_box(globals: &mut TeXGlobals, n: integer, p: pointer) -> TeXResult<()> {
+    if n < 256 {
+        r#box!(globals, n) = p;
+    } else {
+        find_sa_element(globals, box_val, n, false)?;
+        if globals.cur_ptr != null {
+            sa_ptr!(globals, globals.cur_ptr) = p;
+            add_sa_ref(globals, globals.cur_ptr);
+            delete_sa_ref(globals, globals.cur_ptr);
+        }
+    }
+    crate::ok_nojump!()
+}
+
+/// Assign `cur_box` to box register `n`, as `\setbox` does.
+pub(crate) fn sa_def_box(globals: &mut TeXGlobals, n: integer, global: boolean) -> TeXResult<()> {
+    find_sa_element(globals, box_val, n, true)?;
+    if global {
+        gsa_def(globals, globals.cur_ptr, globals.cur_box)?;
+    } else {
+        sa_def(globals, globals.cur_ptr, globals.cur_box)?;
+    }
+    crate::ok_nojump!()
+}
+
+/// Store `cur_box` in the box register given by `box_context`, which is at
+/// least `box_flag` and less than `ship_out_flag`.
+pub(crate) macro Store_cur_box_in_a_box_register($globals:expr, $box_context:expr) {{
+    let (n, global) = if $box_context < global_box_flag {
+        ($box_context - box_flag, false)
+    } else {
+        ($box_context - global_box_flag, true)
+    };
+    if n >= 256 {
+        sa_def_box($globals, n, global)?;
+    } else if global {
+        geq_define(
+            $globals,
+            box_base as pointer + n as pointer,
+            box_ref,
+            $globals.cur_box,
+        )?;
+    } else {
+        eq_define(
+            $globals,
+            box_base as pointer + n as pointer,
+            box_ref,
+            $globals.cur_box,
+        )?;
+    }
+    use crate::section_0210::box_ref;
+    use crate::section_0230::box_base;
+    use crate::section_0277::eq_define;
+    use crate::section_0279::geq_define;
+}}
+
+/// `\countdef` and friends for a register number above 255 make `p` a
+/// `register` or `toks_register` command that points to the array element;
+/// returns `false` for the smaller register numbers.
+pub(crate) fn define_sa_register(
+    globals: &mut TeXGlobals,
+    a: chr_code_repr,
+    p: pointer,
+    n: chr_code_repr,
+) -> TeXResult<boolean> {
+    if globals.cur_val <= 255 {
+        return crate::ok_nojump!(false);
+    }
+    let mut j = (n - count_def_code as chr_code_repr) as quarterword;
+    if j > cur_val_level_kind::mu_val as quarterword {
+        j = cur_val_level_kind::tok_val as quarterword;
+    }
+    find_sa_element(globals, j, globals.cur_val, true)?;
+    add_sa_ref(globals, globals.cur_ptr);
+    let cmd = if j == cur_val_level_kind::tok_val as quarterword {
+        toks_register
+    } else {
+        register
+    };
+    define!(globals, a, p, cmd, globals.cur_ptr);
+    crate::ok_nojump!(true)
+}
+
+/// The sparse arrays are part of the dynamic memory; only their roots need
+/// to be dumped.
+#[cfg(feature = "initex")]
+pub(crate) fn dump_the_latex_support_data(globals: &mut TeXGlobals) {
+    for k in 0..globals.sa_root.len() {
+        dump_int!(globals, globals.sa_root[k] as _);
+    }
+}
+
+pub(crate) fn undump_the_latex_support_data(globals: &mut TeXGlobals) -> bool {
+    for k in 0..globals.sa_root.len() {
+        let x: integer;
+        undump_int!(globals, x);
+        if x < null as integer || x > globals.lo_mem_max as integer {
+            return false;
+        }
+        globals.sa_root[k] = x as pointer;
+    }
+    true
+}
+
+use crate::pascal::boolean;
 use crate::pascal::integer;
 use crate::pascal::word;
+use crate::pascal::IsOddOrEven;
+use crate::section_0004::make_globals_io_string_log_view;
 use crate::section_0004::TeXGlobals;
+use crate::section_0004::TeXGlobalsIoStringLogView;
+use crate::section_0016::decr;
+use crate::section_0016::incr;
+use crate::section_0016::negate;
 use crate::section_0018::ASCII_code;
+use crate::section_0018::ASCII_code_literal;
+#[cfg(feature = "unicode_support")]
+use crate::section_0020::xord;
+use crate::section_0036::loc;
+#[cfg(feature = "statistics")]
+use crate::section_0038::str_number;
+use crate::section_0054::new_string;
+use crate::section_0058::print_char;
+#[cfg(feature = "statistics")]
+use crate::section_0059::print;
+use crate::section_0063::print_esc;
+use crate::section_0065::print_int;
+use crate::section_0073::print_err;
+use crate::section_0079::help1;
+use crate::section_0079::help2;
+use crate::section_0081::TeXResult;
+use crate::section_0082::error;
+use crate::section_0091::int_error;
+use crate::section_0094::overflow;
+use crate::section_0095::confusion;
+use crate::section_0101::scaled;
+use crate::section_0101::small_number;
+#[cfg(feature = "statistics")]
+use crate::section_0103::print_scaled;
+use crate::section_0105::mult_integers;
+use crate::section_0105::nx_plus_y;
 use crate::section_0113::halfword;
 use crate::section_0113::quarterword;
+use crate::section_0113::MEMORY_WORD_INT;
+use crate::section_0115::null;
+use crate::section_0115::pointer;
+use crate::section_0118::info_inner;
+use crate::section_0118::info_tok;
+use crate::section_0118::link;
+use crate::section_0120::get_avail;
+use crate::section_0121::free_avail;
+use crate::section_0123::flush_list;
+use crate::section_0125::get_node;
+use crate::section_0130::free_node;
+use crate::section_0133::r#type;
+use crate::section_0133::subtype;
+use crate::section_0135::width;
+use crate::section_0150::glue_ord;
+use crate::section_0150::shrink;
+use crate::section_0150::shrink_order;
+use crate::section_0150::stretch;
+use crate::section_0150::stretch_order;
+use crate::section_0151::new_spec;
+use crate::section_0162::lo_mem_stat_max;
+use crate::section_0162::temp_head;
+use crate::section_0162::zero_glue;
+#[cfg(feature = "statistics")]
+use crate::section_0178::print_spec;
+#[cfg(feature = "statistics")]
+use crate::section_0198::show_box;
+use crate::section_0200::delete_token_ref;
+use crate::section_0200::token_ref_count;
+use crate::section_0201::delete_glue_ref;
+use crate::section_0202::flush_node_list;
+use crate::section_0203::add_glue_ref;
+use crate::section_0207::relax;
+use crate::section_0207::right_brace;
+use crate::section_0208::last_item;
+use crate::section_0209::assign_toks;
+use crate::section_0209::prefix;
+use crate::section_0209::read_to_cs;
+use crate::section_0209::register;
+use crate::section_0209::toks_register;
+use crate::section_0210::call;
+use crate::section_0210::expand_after;
 use crate::section_0210::if_test;
+use crate::section_0210::input;
+use crate::section_0210::the;
 use crate::section_0221::eq_level;
 use crate::section_0221::eq_type;
 use crate::section_0221::equiv;
 use crate::section_0221::level_one;
+use crate::section_0221::level_zero;
 use crate::section_0222::single_base;
 use crate::section_0224::*;
+use crate::section_0230::every_eof_loc;
+use crate::section_0230::r#box;
+use crate::section_0230::toks_base;
+#[cfg(feature = "statistics")]
+use crate::section_0245::begin_diagnostic;
+#[cfg(feature = "statistics")]
+use crate::section_0245::end_diagnostic;
 use crate::section_0259::id_lookup;
 use crate::section_0264::primitive;
+use crate::section_0268::level_boundary;
+use crate::section_0268::save_index;
+use crate::section_0268::save_level;
+use crate::section_0268::save_type;
+use crate::section_0273::check_full_save_stack;
+use crate::section_0289::end_match_token;
+use crate::section_0289::other_token;
+use crate::section_0289::right_brace_limit;
+#[cfg(feature = "statistics")]
+use crate::section_0292::show_token_list;
+use crate::section_0295::token_show;
+use crate::section_0297::chr_code_repr;
+use crate::section_0297::chr_code_type;
+use crate::section_0297::cur_tok_repr;
+use crate::section_0297::cur_tok_type;
+use crate::section_0302::limit;
+use crate::section_0305::scanner_status_kind;
+use crate::section_0307::write_text;
+use crate::section_0325::back_input;
+use crate::section_0327::back_error;
+use crate::section_0328::begin_file_reading;
+use crate::section_0365::get_token;
+use crate::section_0371::store_new_token;
+use crate::section_0403::scan_left_brace;
+use crate::section_0410::cur_val_level_kind;
+use crate::section_0416::last_item_command_kind;
+use crate::section_0421::max_dimen;
+use crate::section_0440::scan_int;
+use crate::section_0445::infinity;
+use crate::section_0448::scan_normal_dimen;
+use crate::section_0461::scan_glue;
+use crate::section_0464::str_toks;
 use crate::section_0487::if_case_code;
+use crate::section_1071::box_flag;
+use crate::section_1214::define;
+use crate::section_1214::global;
+use crate::section_1222::count_def_code;
+#[cfg(feature = "initex")]
+use crate::section_1305::dump_int;
+use crate::section_1306::undump_int;
 
-use globals_struct::globals_struct_field;
+use globals_struct::{globals_struct_field, globals_struct_use};
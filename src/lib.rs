@@ -73,9 +73,12 @@ mod pascal;
 
 mod io_support;
 
-pub use io_support::install_io_handler;
-pub use io_support::reset_io_handler;
-pub use io_support::TeXIoHandler;
+pub mod file_system;
+
+pub mod limits;
+
+mod search_path;
+
 pub use pascal_io::ReadLine as TeXIoReadLine;
 
 mod string_pool;
@@ -85,14 +88,28 @@ pub use string_pool::pool_file;
 use string_pool::strpool_str;
 use string_pool::submit_strpool_str;
 
+mod command_line;
+
+mod time_support;
+
+mod output_driver;
+
 mod tex_the_program;
 
 use tex_the_program::*;
 
 #[cfg(feature = "latex_support")]
 mod latex_support;
+#[cfg(feature = "pdf_support")]
+mod pdf_support;
 #[cfg(feature = "unicode_support")]
 mod unicode_support;
 
+mod unported;
+
+mod job;
+
+pub use job::{History, Job, JobResult};
+pub use unported::UnportedSection;
 pub use section_0004::TeXGlobals;
 pub use section_1332::entry;
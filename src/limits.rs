@@ -0,0 +1,157 @@
+//! Bounds on the resources a single job may use, and a way to stop a job from
+//! another thread.
+//!
+//! A document that loops forever, or that produces output without end, is
+//! stopped from within: once a bound is exceeded, or once the job is cancelled,
+//! `TeX` ends the run with a fatal error that names the reason, just as it does
+//! when one of its own capacities is exceeded.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The resources a job may use; `None` leaves a resource unbounded.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// the number of macro calls and other expansions
+    pub max_expansion_steps: Option<u64>,
+    /// the number of pages shipped out
+    pub max_pages: Option<u32>,
+    /// the time the job may run for, measured from when it starts
+    pub time_budget: Option<Duration>,
+    /// the number of bytes of `DVI` output
+    pub output_byte_budget: Option<u64>,
+}
+
+/// A handle that stops a running job from any thread.
+///
+/// The job notices the request the next time it reads a line, executes a
+/// command or expands a token, and ends with a fatal error.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationHandle {
+    /// Ask the job to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the job has been asked to stop.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// the resources this job may use
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static limits: Limits = Limits::default();
+
+/// stops this job when cancelled
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static cancellation: CancellationHandle = CancellationHandle::default();
+
+/// the number of expansions performed so far
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static expansion_steps: u64 = 0;
+
+/// when this job started running
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static run_started: Option<Instant> = None;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::limits::{CancellationHandle, Limits};
+
+#[globals_struct_use(TeXGlobals)]
+use std::time::Instant;
+
+/// Reading the clock costs more than the rest of the checks together,
+/// so expansions only look at it this often.
+const expansion_steps_per_clock_check: u64 = 1024;
+
+pub(crate) fn start_run_clock(globals: &mut TeXGlobals) {
+    globals.expansion_steps = 0;
+    globals.run_started = Some(Instant::now());
+}
+
+fn limit_exceeded(globals: &mut TeXGlobals, s: str_number) -> TeXResult<()> {
+    // a job that is already stopping has nothing left to bound
+    if globals.history == history_kind::fatal_error_stop {
+        crate::return_nojump!();
+    }
+    fatal_error(globals, s)
+}
+
+/// Checked each time `TeX` reads a line or starts on a command.
+pub(crate) fn check_limits(globals: &mut TeXGlobals) -> TeXResult<()> {
+    if globals.cancellation.is_cancelled() {
+        return limit_exceeded(globals, crate::strpool_str!("*** (job aborted, cancelled)"));
+    }
+    if let (Some(time_budget), Some(run_started)) =
+        (globals.limits.time_budget, globals.run_started)
+    {
+        if run_started.elapsed() > time_budget {
+            return limit_exceeded(
+                globals,
+                crate::strpool_str!("*** (job aborted, time budget exceeded)"),
+            );
+        }
+    }
+    crate::ok_nojump!()
+}
+
+/// Checked each time `TeX` expands a token.
+pub(crate) fn count_expansion_step(globals: &mut TeXGlobals) -> TeXResult<()> {
+    globals.expansion_steps += 1;
+    if let Some(max_expansion_steps) = globals.limits.max_expansion_steps {
+        if globals.expansion_steps > max_expansion_steps {
+            return limit_exceeded(
+                globals,
+                crate::strpool_str!("*** (job aborted, expansion step limit exceeded)"),
+            );
+        }
+    }
+    if globals.cancellation.is_cancelled()
+        || globals
+            .expansion_steps
+            .is_multiple_of(expansion_steps_per_clock_check)
+    {
+        check_limits(globals)?;
+    }
+    crate::ok_nojump!()
+}
+
+/// Checked before a page is shipped out.
+pub(crate) fn check_page_limit(globals: &mut TeXGlobals) -> TeXResult<()> {
+    if let Some(max_pages) = globals.limits.max_pages {
+        if globals.total_pages as u64 >= max_pages as u64 {
+            return limit_exceeded(
+                globals,
+                crate::strpool_str!("*** (job aborted, page limit exceeded)"),
+            );
+        }
+    }
+    crate::ok_nojump!()
+}
+
+/// Checked after a page has been shipped out, and before half of the `DVI` buffer is written.
+pub(crate) fn check_output_limit(globals: &mut TeXGlobals) -> TeXResult<()> {
+    if let Some(output_byte_budget) = globals.limits.output_byte_budget {
+        let output_bytes = globals.dvi_offset as u64 + globals.dvi_ptr.get() as u64;
+        if output_bytes > output_byte_budget {
+            return limit_exceeded(
+                globals,
+                crate::strpool_str!("*** (job aborted, output byte budget exceeded)"),
+            );
+        }
+    }
+    crate::ok_nojump!()
+}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0038::str_number;
+use crate::section_0076::history_kind;
+use crate::section_0081::TeXResult;
+use crate::section_0093::fatal_error;
+use globals_struct::{globals_struct_field, globals_struct_use};
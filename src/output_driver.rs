@@ -0,0 +1,87 @@
+//! Output drivers that `ship_out` targets.
+//!
+//! `hlist_out` and `vlist_out` keep their positions in `DVI` terms and always
+//! assemble `DVI` commands in `dvi_buf`. A driver decides whether those bytes
+//! reach `dvi_file`, is told of every glyph and rule placed on a page, and
+//! writes whatever else its output file needs when the job ends.
+
+/// the driver that shipped out pages are written through
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static output_driver: OutputDriver = OutputDriver::Dvi;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::configure::OutputDriver;
+
+pub(crate) trait ShipOutDriver {
+    /// whether the `DVI` commands in `dvi_buf` are written to `dvi_file`
+    const WRITES_DVI: bool;
+
+    /// The extension of the output file
+    fn extension() -> str_number;
+
+    /// A page whose box is `p` is about to be shipped out
+    fn begin_page(globals: &mut TeXGlobals, p: pointer);
+
+    /// Character `c` of font `f` is placed with its reference point at `(cur_h, cur_v)`
+    fn char(globals: &mut TeXGlobals, f: internal_font_number, c: ASCII_code);
+
+    /// A rule is placed with its lower left corner at `(h, v)`
+    fn rule(globals: &mut TeXGlobals, h: scaled, v: scaled, wd: scaled, ht: scaled);
+
+    /// The page begun last is complete
+    fn end_page(globals: &mut TeXGlobals);
+
+    /// All pages are out and `dvi_buf` has been flushed; returns the length of
+    /// the output file in bytes
+    fn finish(globals: &mut TeXGlobals) -> integer;
+}
+
+/// The driver of `TeX82`, where the `DVI` commands are the output file.
+pub(crate) struct DviDriver;
+
+impl ShipOutDriver for DviDriver {
+    const WRITES_DVI: bool = true;
+
+    fn extension() -> str_number {
+        // TeX-rs: formats do not depend on which drivers are built in
+        #[cfg(not(feature = "pdf_support"))]
+        crate::submit_strpool_str!(".pdf");
+        crate::strpool_str!(".dvi")
+    }
+
+    fn begin_page(_: &mut TeXGlobals, _: pointer) {}
+
+    fn char(_: &mut TeXGlobals, _: internal_font_number, _: ASCII_code) {}
+
+    fn rule(_: &mut TeXGlobals, _: scaled, _: scaled, _: scaled, _: scaled) {}
+
+    fn end_page(_: &mut TeXGlobals) {}
+
+    fn finish(globals: &mut TeXGlobals) -> integer {
+        globals.dvi_offset + globals.dvi_ptr.get() as integer
+    }
+}
+
+/// Evaluate `$e` with `$driver` standing for the driver selected in `$globals`.
+pub(crate) macro with_output_driver($globals:expr, $driver:ident => $e:expr) {
+    match $globals.output_driver {
+        crate::configure::OutputDriver::Dvi => {
+            type $driver = crate::output_driver::DviDriver;
+            $e
+        }
+        #[cfg(feature = "pdf_support")]
+        crate::configure::OutputDriver::Pdf => {
+            type $driver = crate::pdf_support::PdfDriver;
+            $e
+        }
+    }
+}
+
+use crate::pascal::integer;
+use crate::section_0004::TeXGlobals;
+use crate::section_0018::ASCII_code;
+use crate::section_0038::str_number;
+use crate::section_0101::scaled;
+use crate::section_0115::pointer;
+use crate::section_0548::internal_font_number;
+use globals_struct::{globals_struct_field, globals_struct_use};
@@ -414,6 +414,27 @@ pub(crate) macro make_default_array($elem_ty:ty; $elem_cnt:expr) {{
     unsafe { mem::transmute_copy::<_, [$elem_ty; $elem_cnt]>(&data) }
 }}
 
+/// Lets a table keyed by 16-bit indices (like `eqtb`) also be indexed by
+/// the 32-bit pointers of the wide-pointer build, with the same bounds
+/// checking as its other indices.
+#[cfg(feature = "wide_pointers")]
+macro_rules! impl_index_with_wide_pointer {
+    ($name:ident, $start_typenum:path) => {
+        impl<ELEMENT> core::ops::Index<u32> for $name<ELEMENT> {
+            type Output = ELEMENT;
+            fn index(&self, idx: u32) -> &ELEMENT {
+                &(self.0)[(idx as usize).wrapping_sub(<$start_typenum as typenum::Unsigned>::USIZE)]
+            }
+        }
+
+        impl<ELEMENT> core::ops::IndexMut<u32> for $name<ELEMENT> {
+            fn index_mut(&mut self, idx: u32) -> &mut ELEMENT {
+                &mut (self.0)[(idx as usize).wrapping_sub(<$start_typenum as typenum::Unsigned>::USIZE)]
+            }
+        }
+    };
+}
+
 pub(crate) macro make_copied_array($elem_ty:ty; $elem_val:expr; $elem_cnt:expr) {{
     use core::mem::{self, MaybeUninit};
     let mut data: [MaybeUninit<$elem_ty>; $elem_cnt] =
@@ -619,6 +640,116 @@ macro_rules! define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_an
     };
 }
 
+/// Like `define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length`,
+/// but for the tables whose upper bound is chosen at run time: the array
+/// starts out empty and is given its length by `allocate`.
+macro_rules! define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length {
+    ($v:vis $name:ident[$index_type:path] => $base_index_type:path; $typenum_const:ident; $start_typenum:path) => {
+        $v struct $name<ELEMENT>(Vec<ELEMENT>);
+
+        impl<ELEMENT> $name<ELEMENT> {
+            const START: usize = <$start_typenum as typenum::Unsigned>::$typenum_const as usize;
+
+            $v fn iter(&self) -> core::slice::Iter<'_, ELEMENT> {
+                self.0.iter()
+            }
+        }
+
+        impl<ELEMENT> $name<ELEMENT>
+        where
+            ELEMENT: Default {
+            /// Make room for `len` entries, all reset to their default value.
+            $v fn allocate(&mut self, len: usize) {
+                self.0.clear();
+                self.0.resize_with(len, Default::default);
+            }
+        }
+
+        impl<ELEMENT> Clone for $name<ELEMENT>
+        where
+            ELEMENT: Clone {
+            fn clone(&self) -> Self {
+                $name(self.0.clone())
+            }
+        }
+
+        impl<ELEMENT> Default for $name<ELEMENT> {
+            fn default() -> Self {
+                $name(Vec::new())
+            }
+        }
+
+        impl<ELEMENT: core::fmt::Debug> core::fmt::Debug for $name<ELEMENT> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut debug_map = f.debug_map();
+                debug_map.entries((Self::START..).zip(self.0.iter()));
+                debug_map.finish()?;
+                Ok(())
+            }
+        }
+
+        impl<ELEMENT> core::ops::Index<$index_type> for $name<ELEMENT>
+        {
+            type Output = ELEMENT;
+            fn index(&self, idx: $index_type) -> &ELEMENT {
+                &(self.0)[idx.get() as usize - Self::START]
+            }
+        }
+
+        impl<ELEMENT> core::ops::IndexMut<$index_type> for $name<ELEMENT>
+        {
+            fn index_mut(&mut self, idx: $index_type) -> &mut ELEMENT {
+                &mut (self.0)[idx.get() as usize - Self::START]
+            }
+        }
+
+        impl<ELEMENT> core::ops::Index<$base_index_type> for $name<ELEMENT>
+        {
+            type Output = ELEMENT;
+            fn index(&self, idx: $base_index_type) -> &ELEMENT {
+                &(self.0)[idx as usize - Self::START]
+            }
+        }
+
+        impl<ELEMENT> core::ops::IndexMut<$base_index_type> for $name<ELEMENT>
+        {
+            fn index_mut(&mut self, idx: $base_index_type) -> &mut ELEMENT {
+                &mut (self.0)[idx as usize - Self::START]
+            }
+        }
+
+        impl<ELEMENT> core::ops::Index<core::ops::Range<$index_type>> for $name<ELEMENT>
+        {
+            type Output = [ELEMENT];
+            fn index(&self, range: core::ops::Range<$index_type>) -> &[ELEMENT] {
+                &(self.0)[range.start.get() as usize - Self::START..range.end.get() as usize - Self::START]
+            }
+        }
+
+        impl<ELEMENT> core::ops::IndexMut<core::ops::Range<$index_type>> for $name<ELEMENT>
+        {
+            fn index_mut(&mut self, range: core::ops::Range<$index_type>) -> &mut [ELEMENT] {
+                &mut (self.0)[range.start.get() as usize - Self::START..range.end.get() as usize - Self::START]
+            }
+        }
+
+        impl<ELEMENT> core::ops::Index<core::ops::RangeInclusive<$index_type>> for $name<ELEMENT>
+        {
+            type Output = [ELEMENT];
+            fn index(&self, range: core::ops::RangeInclusive<$index_type>) -> &[ELEMENT] {
+                &(self.0)[range.start().get() as usize - Self::START..=range.end().get() as usize - Self::START]
+            }
+        }
+
+        impl<ELEMENT> core::ops::IndexMut<core::ops::RangeInclusive<$index_type>> for $name<ELEMENT>
+        {
+            fn index_mut(&mut self, range: core::ops::RangeInclusive<$index_type>) -> &mut [ELEMENT] {
+                &mut (self.0)[range.start().get() as usize - Self::START..=range.end().get() as usize - Self::START]
+            }
+        }
+    };
+}
+
 macro_rules! define_array_keyed_with_ranged_signed_integer_with_fixed_start_and_length {
     ($v:vis $name:ident[$index_type:path] => $base_index_type:path; $typenum_start_const:ident;
         $typenum_length_const:ident; $start_typenum:path; $length_typenum:path) => {
@@ -0,0 +1,405 @@
+//! A PDF output driver. When it is selected, `ship_out` still runs the
+//! `DVI` machinery to keep track of positions, but the bytes written to
+//! `dvi_file` are those of a PDF document assembled from the glyphs and
+//! rules reported by `hlist_out` and `vlist_out`.
+
+/// the page size configured for every page, in big points
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static pdf_page_size: Option<(f64, f64)> = None;
+
+/// width, height and content stream of the pages shipped out so far
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static pdf_pages: Vec<(f64, f64, Vec<u8>)> = Vec::new();
+
+/// width and height of the page being shipped out
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static pdf_page_dimensions: (f64, f64) = (0.0, 0.0);
+
+/// content stream of the page being shipped out
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static pdf_page_content: Vec<u8> = Vec::new();
+
+/// internal font numbers referenced by some page, each with the block of 256
+/// character codes that the referenced characters fall in
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static pdf_fonts_used: BTreeSet<(u16, u32)> = BTreeSet::new();
+
+#[globals_struct_use(TeXGlobals)]
+use std::collections::BTreeSet;
+
+/// the `DVI` origin is one inch from the top and left edges
+const pdf_origin_offset: f64 = 72.0;
+
+fn bp_from_scaled(s: scaled) -> f64 {
+    s.inner() as f64 / 65536.0 * 72.0 / 72.27
+}
+
+fn pdf_x(h: scaled) -> f64 {
+    pdf_origin_offset + bp_from_scaled(h)
+}
+
+fn pdf_y(globals: &TeXGlobals, v: scaled) -> f64 {
+    globals.pdf_page_dimensions.1 - pdf_origin_offset - bp_from_scaled(v)
+}
+
+/// The name of the font resource that covers character codes `256*block` to `256*block+255` of `f`
+fn pdf_font_resource(f: u16, block: u32) -> String {
+    if block == 0 {
+        format!("F{}", f)
+    } else {
+        format!("F{}b{}", f, block)
+    }
+}
+
+/// The driver that writes a PDF document from the glyphs and rules placed on
+/// each page, discarding the `DVI` commands.
+pub(crate) struct PdfDriver;
+
+impl ShipOutDriver for PdfDriver {
+    const WRITES_DVI: bool = false;
+
+    fn extension() -> str_number {
+        crate::strpool_str!(".pdf")
+    }
+
+    /// Unless a page size is configured, the page is as large as box `p` with
+    /// the `DVI` origin offset and `\hoffset` or `\voffset` on either side.
+    fn begin_page(globals: &mut TeXGlobals, p: pointer) {
+        globals.pdf_page_dimensions = globals.pdf_page_size.unwrap_or_else(|| {
+            (
+                bp_from_scaled(width!(globals, p) + h_offset!(globals) + h_offset!(globals))
+                    + 2.0 * pdf_origin_offset,
+                bp_from_scaled(
+                    height!(globals, p)
+                        + depth!(globals, p)
+                        + v_offset!(globals)
+                        + v_offset!(globals),
+                ) + 2.0 * pdf_origin_offset,
+            )
+        });
+        globals.pdf_page_content.clear();
+    }
+
+    /// Characters of a simple font have one-byte codes, so a character beyond
+    /// 255 is shown with a font resource of its own block of 256 codes.
+    fn char(globals: &mut TeXGlobals, f: internal_font_number, c: ASCII_code) {
+        let c: u32 = c.numeric_value() as _;
+        let block = c / 256;
+        globals.pdf_fonts_used.insert((f.get(), block));
+        let size = bp_from_scaled(globals.font_size[f]);
+        let (x, y) = (pdf_x(globals.cur_h), pdf_y(globals, globals.cur_v));
+        let _ = writeln!(
+            globals.pdf_page_content,
+            "BT /{} {:.4} Tf {:.4} {:.4} Td <{:02X}> Tj ET",
+            pdf_font_resource(f.get(), block),
+            size,
+            x,
+            y,
+            c % 256
+        );
+    }
+
+    fn rule(globals: &mut TeXGlobals, h: scaled, v: scaled, wd: scaled, ht: scaled) {
+        let (x, y) = (pdf_x(h), pdf_y(globals, v));
+        let _ = writeln!(
+            globals.pdf_page_content,
+            "{:.4} {:.4} {:.4} {:.4} re f",
+            x,
+            y,
+            bp_from_scaled(wd),
+            bp_from_scaled(ht)
+        );
+    }
+
+    fn end_page(globals: &mut TeXGlobals) {
+        let content = core::mem::take(&mut globals.pdf_page_content);
+        let (width, height) = globals.pdf_page_dimensions;
+        globals.pdf_pages.push((width, height, content));
+    }
+
+    fn finish(globals: &mut TeXGlobals) -> integer {
+        pdf_finish(globals)
+    }
+}
+
+struct PdfObjects {
+    bytes: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl PdfObjects {
+    fn new() -> Self {
+        PdfObjects {
+            bytes: b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec(),
+            offsets: vec![],
+        }
+    }
+
+    /// object numbers are handed out in advance so that objects can refer to each other
+    fn reserve(&mut self) -> usize {
+        self.offsets.push(0);
+        self.offsets.len()
+    }
+
+    fn begin(&mut self, id: usize) {
+        self.offsets[id - 1] = self.bytes.len();
+        let _ = writeln!(self.bytes, "{} 0 obj", id);
+    }
+
+    fn end(&mut self) {
+        self.bytes.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn dict(&mut self, id: usize, dict: &str) {
+        self.begin(id);
+        self.bytes.extend_from_slice(dict.as_bytes());
+        self.end();
+    }
+
+    fn stream(&mut self, id: usize, extra_entries: &str, data: &[u8]) {
+        self.begin(id);
+        let _ = write!(
+            self.bytes,
+            "<< /Length {}{} >>\nstream\n",
+            data.len(),
+            extra_entries
+        );
+        self.bytes.extend_from_slice(data);
+        self.bytes.extend_from_slice(b"\nendstream");
+        self.end();
+    }
+
+    fn finish(mut self, root: usize) -> Vec<u8> {
+        let xref = self.bytes.len();
+        let _ = write!(
+            self.bytes,
+            "xref\n0 {}\n0000000000 65535 f \n",
+            self.offsets.len() + 1
+        );
+        for offset in self.offsets.iter() {
+            let _ = writeln!(self.bytes, "{:010} 00000 n ", offset);
+        }
+        let _ = write!(
+            self.bytes,
+            "trailer\n<< /Size {} /Root {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+            self.offsets.len() + 1,
+            root,
+            xref
+        );
+        self.bytes
+    }
+}
+
+/// A Type 1 font program read from a `.pfb` file.
+struct Type1Font {
+    font_name: String,
+    data: Vec<u8>,
+    lengths: [usize; 3],
+}
+
+fn read_pfb(bytes: &[u8]) -> Option<Type1Font> {
+    let mut data = vec![];
+    let mut lengths = [0; 3];
+    let mut rest = bytes;
+    while rest.len() >= 2 && rest[0] == 0x80 && rest[1] != 3 {
+        if rest.len() < 6 {
+            return None;
+        }
+        let segment_type = rest[1];
+        let len = u32::from_le_bytes([rest[2], rest[3], rest[4], rest[5]]) as usize;
+        let segment = rest.get(6..6 + len)?;
+        let slot = match (segment_type, lengths[1]) {
+            (1, 0) => 0,
+            (2, _) => 1,
+            (1, _) => 2,
+            _ => return None,
+        };
+        lengths[slot] += len;
+        data.extend_from_slice(segment);
+        rest = &rest[6 + len..];
+    }
+    let clear_text = String::from_utf8_lossy(&data[..lengths[0]]);
+    let font_name = clear_text
+        .split("/FontName")
+        .nth(1)?
+        .split_whitespace()
+        .next()?
+        .trim_start_matches('/')
+        .to_owned();
+    Some(Type1Font {
+        font_name,
+        data,
+        lengths,
+    })
+}
+
+/// Read the Type 1 program of font `name` from the `TeXfonts:` area, through
+/// the file system of the job.
+fn find_type1_font(globals: &mut TeXGlobals, name: &str) -> Option<Type1Font> {
+    let file_system = globals.file_system.clone();
+    let path = file_system.locate(&format!("TeXfonts:{}.pfb", name), &mut globals.search_paths)?;
+    let mut input = file_system.open_binary_file_for_read(&path).ok()?;
+    let mut bytes = vec![];
+    input.read_to_end(&mut bytes).ok()?;
+    read_pfb(&bytes)
+}
+
+/// Write the font dictionary that covers character codes `256*block` to
+/// `256*block+255` of `f`, embedding its Type 1 program when it can be found.
+/// Such a program only encodes 256 characters, so it is embedded for the first
+/// block alone.
+fn pdf_write_font(
+    globals: &mut TeXGlobals,
+    objects: &mut PdfObjects,
+    id: usize,
+    f: internal_font_number,
+    block: u32,
+) {
+    let name = string_from_str_number(globals, globals.font_name[f]);
+    let size = globals.font_size[f].inner() as f64;
+    let first: u32 = globals.font_bc[f].numeric_value() as _;
+    let first = first.max(256 * block);
+    let last: u32 = globals.font_ec[f].numeric_value() as _;
+    let last = last.min(256 * block + 255);
+    let mut widths = String::new();
+    for c in first..=last {
+        let info = char_info!(globals, f, c);
+        let wd = if info.char_exists() {
+            char_width!(globals, f, info).inner() as f64 * 1000.0 / size
+        } else {
+            0.0
+        };
+        let _ = write!(widths, "{:.2} ", wd);
+    }
+    let font = if block == 0 {
+        find_type1_font(globals, &name)
+    } else {
+        None
+    };
+    let base_font = font
+        .as_ref()
+        .map(|font| font.font_name.clone())
+        .unwrap_or_else(|| name.to_uppercase());
+    let descriptor = objects.reserve();
+    objects.dict(
+        id,
+        &format!(
+            "<< /Type /Font /Subtype /Type1 /BaseFont /{} /FirstChar {} /LastChar {} /Widths [{}] /FontDescriptor {} 0 R >>",
+            base_font,
+            first - 256 * block,
+            last - 256 * block,
+            widths,
+            descriptor
+        ),
+    );
+    let font_file = match font {
+        Some(font) => {
+            let font_file = objects.reserve();
+            objects.stream(
+                font_file,
+                &format!(
+                    " /Length1 {} /Length2 {} /Length3 {}",
+                    font.lengths[0], font.lengths[1], font.lengths[2]
+                ),
+                &font.data,
+            );
+            format!(" /FontFile {} 0 R", font_file)
+        }
+        None => String::new(),
+    };
+    objects.dict(
+        descriptor,
+        &format!(
+            "<< /Type /FontDescriptor /FontName /{} /Flags 4 /FontBBox [0 -250 1000 750] /ItalicAngle 0 /Ascent 750 /Descent -250 /CapHeight 683 /StemV 50{} >>",
+            base_font, font_file
+        ),
+    );
+}
+
+/// Write the whole PDF document to `dvi_file`, returning its length in bytes.
+fn pdf_finish(globals: &mut TeXGlobals) -> integer {
+    let mut objects = PdfObjects::new();
+    let catalog = objects.reserve();
+    let pages = objects.reserve();
+    let resources = objects.reserve();
+    let page_ids = (0..globals.pdf_pages.len())
+        .map(|_| (objects.reserve(), objects.reserve()))
+        .collect::<Vec<_>>();
+    let font_ids = globals
+        .pdf_fonts_used
+        .iter()
+        .map(|&(f, block)| (f, block, objects.reserve()))
+        .collect::<Vec<_>>();
+    objects.dict(
+        catalog,
+        &format!("<< /Type /Catalog /Pages {} 0 R >>", pages),
+    );
+    let kids = page_ids
+        .iter()
+        .map(|(page, _)| format!("{} 0 R", page))
+        .collect::<Vec<_>>()
+        .join(" ");
+    objects.dict(
+        pages,
+        &format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids,
+            page_ids.len()
+        ),
+    );
+    let fonts = font_ids
+        .iter()
+        .map(|&(f, block, id)| format!("/{} {} 0 R", pdf_font_resource(f, block), id))
+        .collect::<Vec<_>>()
+        .join(" ");
+    objects.dict(resources, &format!("<< /Font << {} >> >>", fonts));
+    for ((width, height, content), &(page, stream)) in globals.pdf_pages.iter().zip(page_ids.iter())
+    {
+        objects.dict(
+            page,
+            &format!(
+                "<< /Type /Page /Parent {} 0 R /MediaBox [0 0 {:.4} {:.4}] /Resources {} 0 R /Contents {} 0 R >>",
+                pages, width, height, resources, stream
+            ),
+        );
+        objects.stream(stream, "", content);
+    }
+    for &(f, block, id) in font_ids.iter() {
+        pdf_write_font(
+            globals,
+            &mut objects,
+            id,
+            internal_font_number::new(f),
+            block,
+        );
+    }
+    let bytes = objects.finish(catalog);
+    for &byte in bytes.iter() {
+        write_binary(&mut globals.dvi_file, byte);
+    }
+    globals.pdf_pages.clear();
+    bytes.len() as integer
+}
+
+use crate::io_support::write_binary;
+use crate::job::string_from_str_number;
+use crate::output_driver::ShipOutDriver;
+use crate::pascal::integer;
+use crate::section_0004::TeXGlobals;
+use crate::section_0018::ASCII_code;
+use crate::section_0038::str_number;
+use crate::section_0101::scaled;
+use crate::section_0115::pointer;
+use crate::section_0135::depth;
+use crate::section_0135::height;
+use crate::section_0135::width;
+use crate::section_0247::h_offset;
+use crate::section_0247::v_offset;
+use crate::section_0548::internal_font_number;
+use crate::section_0554::char_info;
+use crate::section_0554::char_width;
+use globals_struct::{globals_struct_field, globals_struct_use};
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::io::Read;
+use std::io::Write as _;
@@ -0,0 +1,261 @@
+//! Resolution of the system areas `TeXinputs:`, `TeXfonts:` and `TeXformats:`
+//! against search paths.
+//!
+//! A search path is a list of directories separated like `PATH` is on the host
+//! system. An empty element stands for the default path of the area: the one
+//! from its environment variable for a configured path, and the current
+//! directory for the environment variable itself. An element that ends with
+//! `//` also covers all of its subdirectories, and an element that starts with
+//! `!!` is only looked up in its `ls-R` filename database, never on disk. When
+//! the root of an element holds an `ls-R` file listing its tree, lookups
+//! consult that listing before walking the directories.
+
+use crate::configure::SearchArea;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+/// name of the filename database at the root of a tree
+const ls_r_name: &str = "ls-R";
+
+/// what an empty element of an environment variable stands for
+const built_in_default_path: &str = ".";
+
+/// the search paths of the system areas
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsFilenameView)]
+pub(crate) static search_paths: SearchPaths = SearchPaths::from_env();
+
+#[globals_struct_use(TeXGlobals)]
+use crate::search_path::SearchPaths;
+
+/// The directories that the system areas of one engine are searched along.
+pub struct SearchPaths {
+    inputs: Vec<PathElement>,
+    fonts: Vec<PathElement>,
+    formats: Vec<PathElement>,
+    use_filename_database: bool,
+}
+
+crate::impl_debug_with_literal!(SearchPaths, "SearchPaths");
+
+impl SearchPaths {
+    pub(crate) fn from_env() -> Self {
+        let from_env = |area| parse_search_path(&environment_search_path(area));
+        SearchPaths {
+            inputs: from_env(SearchArea::Inputs),
+            fonts: from_env(SearchArea::Fonts),
+            formats: from_env(SearchArea::Formats),
+            use_filename_database: true,
+        }
+    }
+
+    fn area_mut(&mut self, area: SearchArea) -> &mut Vec<PathElement> {
+        match area {
+            SearchArea::Inputs => &mut self.inputs,
+            SearchArea::Fonts => &mut self.fonts,
+            SearchArea::Formats => &mut self.formats,
+        }
+    }
+
+    pub(crate) fn set_search_path(&mut self, area: SearchArea, path: &str) {
+        let path = expand_search_path(path.as_ref(), &environment_search_path(area));
+        *self.area_mut(area) = parse_search_path(&path);
+    }
+
+    pub(crate) fn set_use_filename_database(&mut self, use_filename_database: bool) {
+        self.use_filename_database = use_filename_database;
+    }
+
+    /// Turn a file name as `TeX` packs it into a path on disk. Names in one of
+    /// the system areas are looked up along the search path of that area, and
+    /// are `None` when they are not found; other names are left alone.
+    pub fn find(&mut self, path: &str) -> Option<PathBuf> {
+        let (area, name) = if let Some(name) = path.strip_prefix("TeXinputs:") {
+            (SearchArea::Inputs, name)
+        } else if let Some(name) = path.strip_prefix("TeXfonts:") {
+            (SearchArea::Fonts, name)
+        } else if let Some(name) = path.strip_prefix("TeXformats:") {
+            (SearchArea::Formats, name)
+        } else {
+            return Some(PathBuf::from(path));
+        };
+        let use_filename_database = self.use_filename_database;
+        self.area_mut(area)
+            .iter_mut()
+            .find_map(|element| element.find(name, use_filename_database))
+    }
+}
+
+fn environment_variable(area: SearchArea) -> &'static str {
+    match area {
+        SearchArea::Inputs => "TEXINPUTS",
+        SearchArea::Fonts => "TEXFONTS",
+        SearchArea::Formats => "TEXFORMATS",
+    }
+}
+
+/// The elements of the search path an area has when it is not configured.
+fn environment_search_path(area: SearchArea) -> Vec<String> {
+    let default = [built_in_default_path.to_owned()];
+    match std::env::var_os(environment_variable(area)) {
+        Some(path) => expand_search_path(&path, &default),
+        None => default.to_vec(),
+    }
+}
+
+/// Split `path` into its elements, putting the elements of `default` in place
+/// of each empty one.
+fn expand_search_path(path: &OsStr, default: &[String]) -> Vec<String> {
+    let mut elements = vec![];
+    for element in std::env::split_paths(path) {
+        let element = element.to_string_lossy();
+        if element.is_empty() {
+            elements.extend(default.iter().cloned());
+        } else {
+            elements.push(element.into_owned());
+        }
+    }
+    elements
+}
+
+struct PathElement {
+    root: PathBuf,
+    recursive: bool,
+    database_only: bool,
+    /// subdirectories of `root`, walked on first use when `recursive`
+    subdirectories: Option<Vec<PathBuf>>,
+    /// `ls-R` of `root`, loaded on first use
+    database: Option<Option<FilenameDatabase>>,
+}
+
+impl PathElement {
+    fn new(element: &str) -> Self {
+        let (database_only, element) = match element.strip_prefix("!!") {
+            Some(rest) => (true, rest),
+            None => (false, element),
+        };
+        let (recursive, element) = match element.strip_suffix("//") {
+            Some(rest) => (true, rest),
+            None => (false, element),
+        };
+        PathElement {
+            root: PathBuf::from(element),
+            recursive,
+            database_only,
+            subdirectories: None,
+            database: None,
+        }
+    }
+
+    fn find(&mut self, name: &str, use_filename_database: bool) -> Option<PathBuf> {
+        if use_filename_database {
+            let root = &self.root;
+            let database = self
+                .database
+                .get_or_insert_with(|| FilenameDatabase::load(root));
+            if let Some(database) = database {
+                if let Some(found) = database.find(name, self.recursive) {
+                    return Some(found);
+                }
+            }
+        }
+        if self.database_only {
+            return None;
+        }
+        let candidate = self.root.join(name);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if !self.recursive {
+            return None;
+        }
+        let root = &self.root;
+        self.subdirectories
+            .get_or_insert_with(|| {
+                let mut subdirectories = vec![];
+                walk_subdirectories(root, &mut subdirectories);
+                subdirectories
+            })
+            .iter()
+            .map(|dir| dir.join(name))
+            .find(|candidate| candidate.is_file())
+    }
+}
+
+fn parse_search_path(elements: &[String]) -> Vec<PathElement> {
+    elements
+        .iter()
+        .map(|element| PathElement::new(element))
+        .collect()
+}
+
+/// Collect the subdirectories of `dir` depth first, in name order.
+fn walk_subdirectories(dir: &Path, subdirectories: &mut Vec<PathBuf>) {
+    let mut entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+            .map(|entry| entry.path())
+            .collect::<Vec<_>>(),
+        Err(_) => return,
+    };
+    entries.sort();
+    for entry in entries {
+        subdirectories.push(entry.clone());
+        walk_subdirectories(&entry, subdirectories);
+    }
+}
+
+/// The contents of an `ls-R` file: for each file name, the directories holding it.
+struct FilenameDatabase {
+    root: PathBuf,
+    directories_by_name: BTreeMap<String, Vec<PathBuf>>,
+}
+
+impl FilenameDatabase {
+    /// An `ls-R` lists each directory as a line ending with `:`, followed by
+    /// the names of its entries, one per line. Directory lines are relative to
+    /// the directory the `ls-R` lives in, unless they are absolute.
+    fn load(root: &Path) -> Option<Self> {
+        let listing = std::fs::read_to_string(root.join(ls_r_name)).ok()?;
+        let mut directories_by_name = BTreeMap::<String, Vec<PathBuf>>::new();
+        let mut current_dir = None;
+        for line in listing.lines() {
+            if line.is_empty() || line.starts_with('%') {
+                continue;
+            }
+            if let Some(dir) = line.strip_suffix(':') {
+                let dir = Path::new(dir);
+                let dir = dir.strip_prefix(".").unwrap_or(dir);
+                current_dir = Some(root.join(dir));
+            } else if let Some(dir) = &current_dir {
+                directories_by_name
+                    .entry(line.to_owned())
+                    .or_default()
+                    .push(dir.clone());
+            }
+        }
+        Some(FilenameDatabase {
+            root: root.to_owned(),
+            directories_by_name,
+        })
+    }
+
+    fn find(&self, name: &str, recursive: bool) -> Option<PathBuf> {
+        // names with a directory part are matched on their last component
+        let name_path = Path::new(name);
+        let file_name = name_path.file_name()?.to_str()?;
+        let parent = name_path.parent().unwrap_or(Path::new(""));
+        self.directories_by_name
+            .get(file_name)?
+            .iter()
+            .filter(|dir| recursive || **dir == self.root)
+            .filter(|dir| dir.ends_with(parent))
+            .map(|dir| dir.join(file_name))
+            .find(|candidate| candidate.is_file())
+    }
+}
+
+use crate::section_0004::TeXGlobals;
+use globals_struct::{globals_struct_field, globals_struct_use};
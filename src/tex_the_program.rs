@@ -190,7 +190,8 @@ pub(crate) mod section_0192;
 pub(crate) mod section_0193;
 pub(crate) mod section_0194;
 pub(crate) mod section_0195;
-pub(crate) mod section_0196_to_0197;
+pub(crate) mod section_0196;
+pub(crate) mod section_0197;
 pub(crate) mod section_0198;
 pub(crate) mod section_0199;
 pub(crate) mod section_0200;
@@ -330,7 +331,10 @@ pub(crate) mod section_0333;
 pub(crate) mod section_0334;
 pub(crate) mod section_0335;
 pub(crate) mod section_0336;
-pub(crate) mod section_0337_to_0340;
+pub(crate) mod section_0337;
+pub(crate) mod section_0338;
+pub(crate) mod section_0339;
+pub(crate) mod section_0340;
 pub(crate) mod section_0341;
 pub(crate) mod section_0342;
 pub(crate) mod section_0343;
@@ -375,7 +379,8 @@ pub(crate) mod section_0381;
 pub(crate) mod section_0382;
 pub(crate) mod section_0383;
 pub(crate) mod section_0384;
-pub(crate) mod section_0385_to_0386;
+pub(crate) mod section_0385;
+pub(crate) mod section_0386;
 pub(crate) mod section_0387;
 pub(crate) mod section_0388;
 pub(crate) mod section_0389;
@@ -384,7 +389,8 @@ pub(crate) mod section_0391;
 pub(crate) mod section_0392;
 pub(crate) mod section_0393;
 pub(crate) mod section_0394;
-pub(crate) mod section_0395_to_0396;
+pub(crate) mod section_0395;
+pub(crate) mod section_0396;
 pub(crate) mod section_0397;
 pub(crate) mod section_0398;
 pub(crate) mod section_0399;
@@ -676,9 +682,13 @@ pub(crate) mod section_0687;
 pub(crate) mod section_0688;
 pub(crate) mod section_0689;
 pub(crate) mod section_0690;
-pub(crate) mod section_0691_to_0693;
+pub(crate) mod section_0691;
+pub(crate) mod section_0692;
+pub(crate) mod section_0693;
 pub(crate) mod section_0694;
-pub(crate) mod section_0695_to_0697;
+pub(crate) mod section_0695;
+pub(crate) mod section_0696;
+pub(crate) mod section_0697;
 pub(crate) mod section_0698;
 pub(crate) mod section_0699;
 pub(crate) mod section_0700;
@@ -1002,7 +1012,8 @@ pub(crate) mod section_1019;
 pub(crate) mod section_1020;
 pub(crate) mod section_1021;
 pub(crate) mod section_1022;
-pub(crate) mod section_1023_to_1024;
+pub(crate) mod section_1023;
+pub(crate) mod section_1024;
 pub(crate) mod section_1025;
 pub(crate) mod section_1026;
 pub(crate) mod section_1027;
@@ -1112,7 +1123,8 @@ pub(crate) mod section_1130;
 pub(crate) mod section_1131;
 pub(crate) mod section_1132;
 pub(crate) mod section_1133;
-pub(crate) mod section_1134_to_1135;
+pub(crate) mod section_1134;
+pub(crate) mod section_1135;
 pub(crate) mod section_1136;
 pub(crate) mod section_1137;
 pub(crate) mod section_1138;
@@ -1345,7 +1357,8 @@ pub(crate) mod section_1364;
 pub(crate) mod section_1365;
 pub(crate) mod section_1366;
 pub(crate) mod section_1367;
-pub(crate) mod section_1368_to_1369;
+pub(crate) mod section_1368;
+pub(crate) mod section_1369;
 pub(crate) mod section_1370;
 pub(crate) mod section_1371;
 pub(crate) mod section_1372;
@@ -126,13 +126,22 @@ pub mod TeXGlobals {
     include!("src/tex_the_program/section_1032.rs");
     include!("src/tex_the_program/section_1074.rs");
     include!("src/tex_the_program/section_1266.rs");
+    include!("src/tex_the_program/section_1281.rs");
     include!("src/tex_the_program/section_1299.rs");
     include!("src/tex_the_program/section_1305.rs");
     include!("src/tex_the_program/section_1331.rs");
     include!("src/tex_the_program/section_1342.rs");
     include!("src/tex_the_program/section_1345.rs");
+    include!("src/command_line.rs");
+    include!("src/file_system.rs");
     include!("src/latex_support.rs");
+    include!("src/limits.rs");
+    include!("src/output_driver.rs");
+    include!("src/pdf_support.rs");
+    include!("src/search_path.rs");
+    include!("src/time_support.rs");
     include!("src/unicode_support.rs");
+    include!("src/unported.rs");
 }
 
 crate::impl_debug_with_literal!(TeXGlobals, "TeXGlobals");
@@ -21,16 +21,21 @@ pub(crate) macro Set_initial_values_of_key_variables($globals:expr) {
     crate::section_0521::Set_initial_values_of_key_variables_0521!($globals);
     crate::section_0593::Set_initial_values_of_key_variables_0593!($globals);
     crate::section_0596::Set_initial_values_of_key_variables_0596!($globals);
+    crate::section_1282::Set_initial_values_of_key_variables_1282!($globals);
 }
 
 pub(crate) macro Initialize_table_entries_done_by_INITEX_only($globals:expr) {
     crate::section_0164::Initialize_table_entries_done_by_initex_only_0164!($globals);
     crate::section_0222::Initialize_table_entries_done_by_initex_only_0222!($globals);
+    crate::section_0228::Initialize_table_entries_done_by_initex_only_0228!($globals);
     crate::section_0232::Initialize_table_entries_done_by_initex_only_0232!($globals);
     crate::section_0240::Initialize_table_entries_done_by_initex_only_0240!($globals);
+    crate::section_0250::Initialize_table_entries_done_by_initex_only_0250!($globals);
     crate::section_0258::Initialize_table_entries_done_by_initex_only_0258!($globals);
     crate::section_0552::Initialize_table_entries_done_by_initex_only_0552!($globals);
+    crate::section_1216::Initialize_table_entries_done_by_initex_only_1216!($globals);
     crate::section_1301::Initialize_table_entries_done_by_initex_only_1301!($globals);
+    crate::section_1369::Initialize_table_entries_done_by_initex_only_1369!($globals);
 }
 
 // @<Initialize whatever...@>=
@@ -39,7 +44,10 @@ pub(crate) macro Initialize_whatever_TeX_might_access($globals:expr) {
     Set_initial_values_of_key_variables!($globals);
     // @!init @<Initialize table entries (done by \.{INITEX} only)@>@;@+tini
     crate::region_initex! {
-        Initialize_table_entries_done_by_INITEX_only!($globals);
+        // TeX-rs: a run that isn't `ini_version` gets its tables from a format.
+        if $globals.ini_version {
+            Initialize_table_entries_done_by_INITEX_only!($globals);
+        }
     }
 }
 
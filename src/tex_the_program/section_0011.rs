@@ -3,6 +3,11 @@
 //! in production versions of \TeX.
 //! @.INITEX@>
 //! @^system dependencies@>
+//!
+//! In \TeX-rs most of them are chosen at run time instead (see
+//! `TeXConfiguration`), and the arrays they bound are allocated when \TeX\
+//! starts; the |..._TYPENUM| types only bound the indices into these arrays,
+//! by the largest values \TeX\ itself allows.
 
 // @<Constants...@>=
 // @!mem_max=30000; {greatest index in \TeX's internal |mem| array;
@@ -11,8 +16,16 @@
 /// greatest index in TeX's internal mem array; must be strictly less
 /// than `max_halfword`; must be equal to mem top in INITEX,
 /// otherwise `>= mem_top`
-pub(crate) const mem_max: u32 = mem_max_TYPENUM::U32;
-pub(crate) type mem_max_TYPENUM = U30000;
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static mem_max: u32 = mem_top as u32;
+#[cfg(not(any(feature = "trip", feature = "wide_pointers")))]
+pub(crate) type mem_max_TYPENUM = U65534;
+/// the wide-pointer build can use every location below `max_halfword`
+#[cfg(all(feature = "wide_pointers", not(feature = "trip")))]
+pub(crate) type mem_max_TYPENUM = ::typenum::op!(U16384 * U16384 - U2);
+/// the TRIP test runs with `mem_max=mem_top=3000`
+#[cfg(feature = "trip")]
+pub(crate) type mem_max_TYPENUM = U3000;
 
 // @!mem_min=0; {smallest index in \TeX's internal |mem| array;
 //   must be |min_halfword| or more;
@@ -20,7 +33,11 @@ pub(crate) type mem_max_TYPENUM = U30000;
 /// smallest index in TeX's internal `mem` array; must be `min_halfword`
 /// or more; must be equal to `mem_bot` in `INITEX`, otherwise `<=mem_bot`
 pub(crate) const mem_min: u32 = mem_min_TYPENUM::U32;
+#[cfg(not(feature = "trip"))]
 pub(crate) type mem_min_TYPENUM = U0;
+/// the TRIP test runs with `mem_min=mem_bot=1`
+#[cfg(feature = "trip")]
+pub(crate) type mem_min_TYPENUM = U1;
 
 // @!buf_size=500; {maximum number of characters simultaneously present in
 //   current lines of open files and in control sequences between
@@ -28,44 +45,70 @@ pub(crate) type mem_min_TYPENUM = U0;
 /// maximum number of characters simultaneously present in
 /// current lines of open files and in control sequences between
 /// `\csname` and `\endcsname`; must not exceed `max_halfword`
-pub(crate) const buf_size: u16 = buf_size_TYPENUM::U16;
-pub(crate) type buf_size_TYPENUM = U500;
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsIoView)]
+pub(crate) static buf_size: u16 = 500;
+pub(crate) type buf_size_TYPENUM = U65535;
 
 // @!error_line=72; {width of context lines on terminal error messages}
 /// width of context lines on terminal error messages
+#[cfg(not(feature = "trip"))]
 #[globals_struct_field(TeXGlobals)]
 #[globals_struct_field_view(TeXGlobalsIoStringLogView)]
 pub(crate) static error_line: quarterword = 72;
+/// the TRIP test runs with `error_line=64`
+#[cfg(feature = "trip")]
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsIoStringLogView)]
+pub(crate) static error_line: quarterword = 64;
 
 // @!half_error_line=42; {width of first lines of contexts in terminal
 //   error messages; should be between 30 and |error_line-15|}
 /// width of first lines of contexts in terminal error messages;
 /// should be between 30 and `error_line-15`
+#[cfg(not(feature = "trip"))]
 #[globals_struct_field(TeXGlobals)]
 #[globals_struct_field_view(TeXGlobalsIoStringLogView)]
 pub(crate) static half_error_line: quarterword = 42;
+/// the TRIP test runs with `half_error_line=32`
+#[cfg(feature = "trip")]
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsIoStringLogView)]
+pub(crate) static half_error_line: quarterword = 32;
 // @!max_print_line=79; {width of longest text lines output; should be at least 60}
 /// width of longest text lines output; should be at least 60
+#[cfg(not(feature = "trip"))]
 #[globals_struct_field(TeXGlobals)]
 #[globals_struct_field_view(TeXGlobalsIoStringLogView)]
 pub(crate) static max_print_line: quarterword = 79;
+/// the TRIP test runs with `max_print_line=72`
+#[cfg(feature = "trip")]
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsIoStringLogView)]
+pub(crate) static max_print_line: quarterword = 72;
 // @!stack_size=200; {maximum number of simultaneous input sources}
 /// maximum number of simultaneous input sources
-pub(crate) const stack_size: u16 = stack_size_TYPENUM::U16;
-pub(crate) type stack_size_TYPENUM = U200;
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static stack_size: u16 = 200;
+pub(crate) type stack_size_TYPENUM = U65535;
 // @!max_in_open=6; {maximum number of input files and error insertions that
 //   can be going on simultaneously}
 /// maximum number of input files and error insertions that can be going on simultaneously
-pub(crate) const max_in_open: u8 = max_in_open_TYPENUM::U8;
-pub(crate) type max_in_open_TYPENUM = U6;
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static max_in_open: u8 = 6;
+/// `max_in_open` must be less than 128
+pub(crate) type max_in_open_TYPENUM = U127;
 // @!font_max=75; {maximum internal font number; must not exceed |max_quarterword|
 //   and must be at most |font_base+256|}
-pub(crate) const font_max: u8 = font_max_TYPENUM::U8;
-pub(crate) type font_max_TYPENUM = U75;
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static font_max: u8 = 75;
+/// `font_max` must not exceed `max_quarterword`
+pub(crate) type font_max_TYPENUM = U255;
 // @!font_mem_size=20000; {number of words of |font_info| for all fonts}
 /// number of words of `font_info` for all fonts
-pub(crate) const font_mem_size: u16 = font_mem_size_TYPENUM::U16;
-pub(crate) type font_mem_size_TYPENUM = U20000;
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static font_mem_size: u16 = 20000;
+pub(crate) type font_mem_size_TYPENUM = U65535;
 // @!param_size=60; {maximum number of simultaneous macro parameters}
 /// maximum number of simultaneous macro parameters
 pub(crate) const param_size: u8 = param_size_TYPENUM::U8;
@@ -92,20 +135,26 @@ const_assert!(max_strings as u32 <= max_halfword as u32);
 ///   error messages and help texts, and the names of all fonts and
 ///   control sequences; must exceed |string_vacancies| by the total
 ///   length of \TeX's own strings, which is currently about 23000
-pub(crate) const pool_size: u16 = pool_size_TYPENUM::U16;
-pub(crate) type pool_size_TYPENUM = U32000;
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsIoStringView)]
+#[globals_struct_field_view(TeXGlobalsIoStringLogView)]
+pub(crate) static pool_size: u32 = 32000;
+/// pool pointers are dumped as integers
+pub(crate) type pool_size_TYPENUM = ::typenum::op!(U2147483648 - U1);
 // @!save_size=600; {space for saving values outside of current group; must be
 //   at most |max_halfword|}
 /// space for saving values outside of current group; must be
 ///   at most `max_halfword`
-pub(crate) const save_size: u16 = save_size_TYPENUM::U16;
-pub(crate) type save_size_TYPENUM = U600;
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static save_size: u16 = 600;
+pub(crate) type save_size_TYPENUM = U65535;
 // @!trie_size=8000; {space for hyphenation patterns; should be larger for
 //   \.{INITEX} than it is in production versions of \TeX}
 /// space for hyphenation patterns; should be larger for `INITEX`
 /// than it is in production versions of `TeX`
-pub(crate) const trie_size: u16 = trie_size_TYPENUM::U16;
-pub(crate) type trie_size_TYPENUM = U8000;
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static trie_size: u16 = 8000;
+pub(crate) type trie_size_TYPENUM = U65535;
 // @!trie_op_size=500; {space for ``opcodes'' in the hyphenation patterns}
 /// space for "opcodes" in the hyphenation patterns
 pub(crate) const trie_op_size: u16 = trie_op_size_TYPENUM::U16;
@@ -115,8 +164,10 @@ pub(crate) type trie_op_size_NEG_TYPENUM = N500;
 
 // @!dvi_buf_size=800; {size of the output buffer; must be a multiple of 8}
 /// size of the output buffer; must be a multiple of 8
-pub(crate) const dvi_buf_size: u16 = dvi_buf_size_TYPENUM::U16;
-pub(crate) type dvi_buf_size_TYPENUM = U800;
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static dvi_buf_size: u16 = 800;
+/// the largest multiple of 8 that fits in 16 bits
+pub(crate) type dvi_buf_size_TYPENUM = ::typenum::op!(U65536 - U8);
 // @!file_name_size=40; {file names shouldn't be longer than this}
 /// file names shouldn't be longer than this
 pub(crate) const file_name_size: u16 = file_name_size_TYPENUM::U16;
@@ -131,16 +182,98 @@ pub(crate) const pool_name: &'static str = "TeXformats:TEX.POOL
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0113::quarterword;
 
+#[globals_struct_use(TeXGlobals)]
+use crate::section_0012::mem_top;
+
 use crate::section_0110::max_halfword;
 type U3000 = ::typenum::op!(U1000 * U3);
-type U8000 = ::typenum::op!(U1000 * U8);
-type U20000 = ::typenum::op!(U10000 * U2);
-type U30000 = ::typenum::op!(U10000 * U3);
-type U32000 = ::typenum::op!(U10000 * U3 + U1000 * U2);
-use globals_struct::{globals_struct_field, globals_struct_use};
+#[cfg(not(any(feature = "trip", feature = "wide_pointers")))]
+type U65534 = ::typenum::op!(U65536 - U2);
+type U65535 = ::typenum::op!(U65536 - U1);
+use globals_struct::{globals_struct_field, globals_struct_field_view, globals_struct_use};
 use static_assertions::const_assert;
 use typenum::{
-    Unsigned, U0, U1000, U10000, U2, U200, U3, U40, U42, U500, U6, U60, U600, U72, U75, U79, U8,
-    U800,
+    Unsigned, U0, U1, U1000, U127, U16384, U2, U2147483648, U255, U3, U40, U500, U60, U65536, U8,
 };
 use typenum::{N500, P500};
+
+/// Give each array whose bounds are configured at run time its room,
+/// as declared in its `@<Glob...@>`.
+pub(crate) fn allocate_the_configured_tables(globals: &mut TeXGlobals) {
+    let mem_len = (globals.mem_max - mem_min) as usize + 1;
+    // @!buffer:array[0..buf_size] of ASCII_code;
+    globals.buffer.allocate(globals.buf_size as usize + 1);
+    // @!str_pool:packed array[pool_pointer] of packed_ASCII_code;
+    globals.str_pool.allocate(globals.pool_size as usize + 1);
+    // @!mem : array[mem_min..mem_max] of memory_word;
+    globals.mem.allocate(mem_len);
+    // @!debug @!free: packed array [mem_min..mem_max] of boolean;
+    // @t\hskip10pt@>@!was_free: packed array [mem_min..mem_max] of boolean;
+    #[cfg(feature = "debugging")]
+    {
+        globals.free.allocate(mem_len);
+        globals.was_free.allocate(mem_len);
+    }
+    // TeX-rs: |page_head| is written before |mem| is initialized or undumped,
+    // so the statically allocated one-word locations get their room now.
+    make_room_in_mem(globals, mem_bot, hi_mem_stat_min);
+    // @!save_stack : array[0..save_size] of memory_word;
+    globals.save_stack.allocate(globals.save_size as usize + 1);
+    // @!input_stack : array[0..stack_size] of in_state_record;
+    globals
+        .input_stack
+        .allocate(globals.stack_size as usize + 1);
+    // @!input_file : array[1..max_in_open] of alpha_file;
+    globals.input_file.allocate(globals.max_in_open as usize);
+    // @!line_stack : array[1..max_in_open] of integer;
+    globals.line_stack.allocate(globals.max_in_open as usize);
+    // @!font_info:array[font_index] of memory_word;
+    globals
+        .font_info
+        .allocate(globals.font_mem_size as usize + 1);
+    // the arrays indexed by |internal_font_number| (sections 549 and 550)
+    let font_len = (globals.font_max - font_base) as usize + 1;
+    globals.font_check.allocate(font_len);
+    globals.font_size.allocate(font_len);
+    globals.font_dsize.allocate(font_len);
+    globals.font_params.allocate(font_len);
+    globals.font_name.allocate(font_len);
+    globals.font_area.allocate(font_len);
+    globals.font_bc.allocate(font_len);
+    globals.font_ec.allocate(font_len);
+    globals.font_glue.allocate(font_len);
+    globals.font_used.allocate(font_len);
+    globals.hyphen_char.allocate(font_len);
+    globals.skew_char.allocate(font_len);
+    globals.bchar_label.allocate(font_len);
+    globals.font_bchar.allocate(font_len);
+    globals.font_false_bchar.allocate(font_len);
+    globals.char_base.allocate(font_len);
+    globals.width_base.allocate(font_len);
+    globals.height_base.allocate(font_len);
+    globals.depth_base.allocate(font_len);
+    globals.italic_base.allocate(font_len);
+    globals.lig_kern_base.allocate(font_len);
+    globals.kern_base.allocate(font_len);
+    globals.exten_base.allocate(font_len);
+    globals.param_base.allocate(font_len);
+    // @!dvi_buf:array[dvi_index] of eight_bits;
+    globals.dvi_buf.allocate(globals.dvi_buf_size as usize + 1);
+    // the arrays indexed by |trie_pointer| (sections 921, 947 and 950)
+    let trie_len = globals.trie_size as usize + 1;
+    globals.trie.allocate(trie_len);
+    crate::region_initex! {
+        globals.trie_c.allocate(trie_len);
+        globals.trie_o.allocate(trie_len);
+        globals.trie_l.allocate(trie_len);
+        globals.trie_r.allocate(trie_len);
+        globals.trie_hash.allocate(trie_len);
+        globals.trie_taken.allocate(globals.trie_size as usize);
+    }
+}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0012::font_base;
+use crate::section_0012::mem_bot;
+use crate::section_0116::make_room_in_mem;
+use crate::section_0162::hi_mem_stat_min;
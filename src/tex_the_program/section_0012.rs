@@ -13,14 +13,27 @@
 //   must not be less than |mem_min|}
 /// smallest index in the `mem` array dumped by `INITEX`;
 ///   must not be less than |mem_min|
+#[cfg(not(feature = "trip"))]
 pub(crate) const mem_bot: pointer = 0;
+/// the TRIP test runs with `mem_min=mem_bot=1`
+#[cfg(feature = "trip")]
+pub(crate) const mem_bot: pointer = 1;
 // @d mem_top==30000 {largest index in the |mem| array dumped by \.{INITEX};
 //   must be substantially larger than |mem_bot|
 //   and not greater than |mem_max|}
 /// largest index in the `mem` array dumped by `INITEX`;
 /// must be substantially larger than `mem_bot` and not
 /// greater than `mem_max`
+#[cfg(not(any(feature = "trip", feature = "wide_pointers")))]
 pub(crate) const mem_top: pointer = 30000;
+/// the wide-pointer build puts the one-word region at the very top of the
+/// address space, leaving all the room below it to the variable-size region
+#[cfg(all(feature = "wide_pointers", not(feature = "trip")))]
+pub(crate) const mem_top: pointer =
+    <crate::section_0011::mem_max_TYPENUM as typenum::Unsigned>::U32;
+/// the TRIP test runs with `mem_max=mem_top=3000`
+#[cfg(feature = "trip")]
+pub(crate) const mem_top: pointer = 3000;
 // @d font_base=0 {smallest internal font number; must not be less
 //   than |min_quarterword|}
 /// smallest internal font number; must not be less than `min_quarterword`
@@ -29,7 +42,7 @@ pub(crate) type font_base_TYPENUM = U0;
 // @d hash_size=2100 {maximum number of control sequences; it should be at most
 //   about |(mem_max-mem_min)/10|}
 /// maximum number of control sequences; it should be at most about `(mem_max-mem_min)/10`
-pub(crate) const hash_size: halfword = hash_size_TYPENUM::U16;
+pub(crate) const hash_size: halfword = hash_size_TYPENUM::U16 as halfword;
 pub(crate) type hash_size_TYPENUM = U2100;
 // @d hash_prime=1777 {a prime number equal to about 85\pct! of |hash_size|}
 /// a prime number equal to about 85% of `hash_size`
@@ -2,12 +2,43 @@
 //! or something similar. (We can't do that until |max_halfword| has been defined.)
 //
 // @<Check the ``constant'' values for consistency@>=
-// bad:=0;
-// if (half_error_line<30)or(half_error_line>error_line-15) then bad:=1;
-// if max_print_line<60 then bad:=2;
-// if dvi_buf_size mod 8<>0 then bad:=3;
-// if mem_bot+1100>mem_top then bad:=4;
-// if hash_prime>hash_size then bad:=5;
-// if max_in_open>=128 then bad:=6;
-// if mem_top<256+11 then bad:=7; {we will want |null_list>255|}
-//
+pub(crate) macro Check_the_constant_values_for_consistency_0014($globals:expr) {{
+    // bad:=0;
+    $globals.bad = 0;
+    // if (half_error_line<30)or(half_error_line>error_line-15) then bad:=1;
+    if ($globals.half_error_line as integer) < 30
+        || $globals.half_error_line as integer > $globals.error_line as integer - 15
+    {
+        $globals.bad = 1;
+    }
+    // if max_print_line<60 then bad:=2;
+    if ($globals.max_print_line as integer) < 60 {
+        $globals.bad = 2;
+    }
+    // if dvi_buf_size mod 8<>0 then bad:=3;
+    if $globals.dvi_buf_size % 8 != 0 {
+        $globals.bad = 3;
+    }
+    // if mem_bot+1100>mem_top then bad:=4;
+    if mem_bot as integer + 1100 > mem_top as integer {
+        $globals.bad = 4;
+    }
+    // if hash_prime>hash_size then bad:=5;
+    if hash_prime as integer > hash_size as integer {
+        $globals.bad = 5;
+    }
+    // if max_in_open>=128 then bad:=6;
+    if $globals.max_in_open as integer >= 128 {
+        $globals.bad = 6;
+    }
+    // if mem_top<256+11 then bad:=7; {we will want |null_list>255|}
+    /// we will want `null_list>255`
+    if (mem_top as integer) < 256 + 11 {
+        $globals.bad = 7;
+    }
+    use crate::pascal::integer;
+    use crate::section_0012::hash_prime;
+    use crate::section_0012::hash_size;
+    use crate::section_0012::mem_bot;
+    use crate::section_0012::mem_top;
+}}
@@ -35,11 +35,9 @@ impl ASCII_code {
         }
         #[cfg(feature = "unicode_support")]
         {
-            if val >= 0 && val <= 255 {
-                ASCII_code(val as _)
-            } else {
-                todo!("{} is > 255", val);
-            }
+            // TeX-rs: character codes are range-checked where they are scanned, against
+            // `max_allowed_ASCII_code_repr`
+            ASCII_code(val as _)
         }
     }
 
@@ -37,7 +37,13 @@ pub(crate) macro rewrite_OK($f:expr) {
 /// open a text file for input
 pub(crate) fn a_open_in(globals: TeXGlobalsFilenameView<'_>, f: &mut alpha_file) -> boolean {
     // begin reset(f,name_of_file,'/O'); a_open_in:=reset_OK(f);
-    reset(f, &*globals.name_of_file, "/O");
+    reset(
+        &**globals.file_system,
+        globals.search_paths,
+        f,
+        &*globals.name_of_file,
+        "/O",
+    );
     return reset_OK!(f);
     // end;
 }
@@ -47,7 +53,13 @@ pub(crate) fn a_open_in(globals: TeXGlobalsFilenameView<'_>, f: &mut alpha_file)
 /// open a text file for output
 pub(crate) fn a_open_out(globals: TeXGlobalsFilenameView<'_>, f: &mut alpha_file) -> boolean {
     // begin rewrite(f,name_of_file,'/O'); a_open_out:=rewrite_OK(f);
-    rewrite(f, &*globals.name_of_file, "/O");
+    // TeX-rs: relative names are placed in the output directory.
+    rewrite(
+        &**globals.file_system,
+        f,
+        &*output_file_name(&*globals.output_directory, (&*globals.name_of_file).into()),
+        "/O",
+    );
     return rewrite_OK!(f);
     // end;
 }
@@ -57,7 +69,13 @@ pub(crate) fn a_open_out(globals: TeXGlobalsFilenameView<'_>, f: &mut alpha_file
 /// open a binary file for input
 pub(crate) fn b_open_in(globals: TeXGlobalsFilenameView<'_>, f: &mut byte_file) -> boolean {
     // begin reset(f,name_of_file,'/O'); b_open_in:=reset_OK(f);
-    reset(f, &*globals.name_of_file, "/O");
+    reset(
+        &**globals.file_system,
+        globals.search_paths,
+        f,
+        &*globals.name_of_file,
+        "/O",
+    );
     return reset_OK!(f);
     // end;
 }
@@ -67,7 +85,13 @@ pub(crate) fn b_open_in(globals: TeXGlobalsFilenameView<'_>, f: &mut byte_file)
 /// open a binary file for output
 pub(crate) fn b_open_out(globals: TeXGlobalsFilenameView<'_>, f: &mut byte_file) -> boolean {
     // begin rewrite(f,name_of_file,'/O'); b_open_out:=rewrite_OK(f);
-    rewrite(f, &*globals.name_of_file, "/O");
+    // TeX-rs: relative names are placed in the output directory.
+    rewrite(
+        &**globals.file_system,
+        f,
+        &*output_file_name(&*globals.output_directory, (&*globals.name_of_file).into()),
+        "/O",
+    );
     return rewrite_OK!(f);
     // end;
 }
@@ -77,7 +101,13 @@ pub(crate) fn b_open_out(globals: TeXGlobalsFilenameView<'_>, f: &mut byte_file)
 /// open a word file for input
 pub(crate) fn w_open_in(globals: TeXGlobalsFilenameView<'_>, f: &mut word_file) -> boolean {
     // begin reset(f,name_of_file,'/O'); w_open_in:=reset_OK(f);
-    reset(f, &*globals.name_of_file, "/O");
+    reset(
+        &**globals.file_system,
+        globals.search_paths,
+        f,
+        &*globals.name_of_file,
+        "/O",
+    );
     return reset_OK!(f);
     // end;
 }
@@ -87,11 +117,18 @@ pub(crate) fn w_open_in(globals: TeXGlobalsFilenameView<'_>, f: &mut word_file)
 /// open a word file for output
 pub(crate) fn w_open_out(globals: TeXGlobalsFilenameView<'_>, f: &mut word_file) -> boolean {
     // begin rewrite(f,name_of_file,'/O'); w_open_out:=rewrite_OK(f);
-    rewrite(f, &*globals.name_of_file, "/O");
+    // TeX-rs: relative names are placed in the output directory.
+    rewrite(
+        &**globals.file_system,
+        f,
+        &*output_file_name(&*globals.output_directory, (&*globals.name_of_file).into()),
+        "/O",
+    );
     return rewrite_OK!(f);
     // end;
 }
 
+use crate::command_line::output_file_name;
 use crate::io_support::reset;
 use crate::io_support::rewrite;
 use crate::pascal::boolean;
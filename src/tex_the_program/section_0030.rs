@@ -46,23 +46,21 @@ use crate::pascal::u16_from_0_to_n;
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0018::ASCII_code;
 
-#[globals_struct_use(TeXGlobals)]
-use crate::section_0011::buf_size;
-
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0011::buf_size_TYPENUM;
 
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0030::buf_size_array;
 
-type buf_size_array_LENGTH_TYPENUM = typenum::op!(buf_size_TYPENUM + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_from_0_with_fixed_length!(
-    pub(crate) buf_size_array[u16_from_0_to_n<buf_size_TYPENUM>] => u16; U16; buf_size_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) buf_size_array[u16_from_0_to_n<buf_size_TYPENUM>] => u16; U16; U0
 );
 
+#[cfg(feature = "wide_pointers")]
+impl_index_with_wide_pointer!(buf_size_array, U0);
+
 use crate::pascal::u16_from_0_to_n;
 use crate::section_0011::buf_size_TYPENUM;
-use typenum::U1;
+use typenum::U0;
 
 use globals_struct::{globals_struct_field, globals_struct_field_view, globals_struct_use};
@@ -41,13 +41,17 @@
 // @p function input_ln(var f:alpha_file;@!bypass_eoln:boolean):boolean;
 //   {inputs the next line or returns |false|}
 /// inputs the next line or returns `false`
+///
+/// TeX-rs: `input_ln` only sees part of the globals, so when the input buffer
+/// overflows it returns `input_buffer_overflow` and leaves the report to its
+/// caller; see `input_ln_or_report_overflow`.
 #[allow(unused_variables)]
 #[cfg_attr(feature = "trace_verbose", tracing::instrument(level = "trace"))]
 pub(crate) fn input_ln(
     globals_view: TeXGlobalsIoView<'_>,
     f: &mut alpha_file,
     bypass_eoln: boolean,
-) -> boolean {
+) -> Result<boolean, input_buffer_overflow> {
     // var last_nonblank:0..buf_size; {|last| with trailing blanks removed}
     /// `last` with trailing blanks removed
     let mut last_nonblank: u16_from_0_to_n<buf_size_TYPENUM>;
@@ -71,25 +75,37 @@ pub(crate) fn input_ln(
         last_nonblank = *globals_view.first;
         // while not eoln(f) do
         while !eoln(f) {
-            // begin if last>=max_buf_stack then
-            if globals_view.last >= globals_view.max_buf_stack {
-                // begin max_buf_stack:=last+1;
-                *globals_view.max_buf_stack = *globals_view.last + 1;
-                // if max_buf_stack=buf_size then
-                if *globals_view.max_buf_stack == buf_size {
-                    // @<Report overflow of the input buffer, and abort@>;
-                    todo!("report overflow");
+            // TeX-rs: with unicode support, one unit read from the file may
+            // go into the buffer as several characters; see
+            // `buffer_units_from_input_unit`.
+            #[cfg(not(feature = "unicode_support"))]
+            let units = core::iter::once(buffer_variable(f));
+            #[cfg(feature = "unicode_support")]
+            let units = buffer_units_from_input_unit(
+                *globals_view.grapheme_cluster_handling,
+                buffer_variable(f),
+            );
+            for unit in units {
+                // begin if last>=max_buf_stack then
+                if globals_view.last >= globals_view.max_buf_stack {
+                    // begin max_buf_stack:=last+1;
+                    *globals_view.max_buf_stack = *globals_view.last + 1;
+                    // if max_buf_stack=buf_size then
+                    if *globals_view.max_buf_stack == *globals_view.buf_size {
+                        // @<Report overflow of the input buffer, and abort@>;
+                        return Err(input_buffer_overflow);
+                    }
+                    // end;
+                }
+                // buffer[last]:=xord[f^]; get(f); incr(last);
+                globals_view.buffer[*globals_view.last] = xord(unit);
+                incr!(*globals_view.last);
+                // if buffer[last-1]<>" " then last_nonblank:=last;
+                if globals_view.buffer[*globals_view.last - 1] != ASCII_code_literal!(b' ') {
+                    last_nonblank = *globals_view.last;
                 }
-                // end;
             }
-            // buffer[last]:=xord[f^]; get(f); incr(last);
-            globals_view.buffer[*globals_view.last] = xord(buffer_variable(f));
             get(f);
-            incr!(*globals_view.last);
-            // if buffer[last-1]<>" " then last_nonblank:=last;
-            if globals_view.buffer[*globals_view.last - 1] != ASCII_code_literal!(b' ') {
-                last_nonblank = *globals_view.last;
-            }
             // end;
         }
         // last:=last_nonblank; input_ln:=true;
@@ -104,16 +120,21 @@ pub(crate) fn input_ln(
         // end;
     }
     // end;
-    input_ln
+    Ok(input_ln)
 }
 
+/// The input buffer of `input_ln` is full.
+#[derive(Debug)]
+pub(crate) struct input_buffer_overflow;
+
 use crate::io_support::{buffer_variable, eof, eoln, get};
 use crate::pascal::boolean;
 use crate::pascal::u16_from_0_to_n;
 use crate::section_0004::TeXGlobalsIoView;
-use crate::section_0011::buf_size;
 use crate::section_0011::buf_size_TYPENUM;
 use crate::section_0016::incr;
 use crate::section_0018::ASCII_code_literal;
 use crate::section_0020::xord;
 use crate::section_0025::alpha_file;
+#[cfg(feature = "unicode_support")]
+use crate::unicode_support::buffer_units_from_input_unit;
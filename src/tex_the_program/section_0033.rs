@@ -7,12 +7,18 @@
 
 /// open the terminal for text input
 pub(crate) fn t_open_in(globals: &mut TeXGlobals) {
-    reset(&mut globals.term_in, "TTY:", "/O/I");
+    reset(
+        &*globals.file_system,
+        &mut globals.search_paths,
+        &mut globals.term_in,
+        "TTY:",
+        "/O/I",
+    );
 }
 
 // @d t_open_out==rewrite(term_out,'TTY:','/O') {open the terminal for text output}
 pub(crate) fn t_open_out(globals: &mut TeXGlobals) {
-    rewrite(&mut globals.term_out, "TTY:", "/O");
+    rewrite(&*globals.file_system, &mut globals.term_out, "TTY:", "/O");
 }
 
 use crate::io_support::{reset, rewrite};
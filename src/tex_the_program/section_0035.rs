@@ -36,3 +36,46 @@
 //! @:TeX capacity exceeded buffer size}{\quad buffer size@>
 //!   end
 //!
+pub(crate) macro Report_overflow_of_the_input_buffer_and_abort($globals:expr) {{
+    // if format_ident=0 then
+    if $globals.format_ident.is_zero() {
+        // begin write_ln(term_out,'Buffer size exceeded!'); goto final_end;
+        write_ln(&mut $globals.term_out, "Buffer size exceeded!");
+        // TeX-rs: this jumps to `end_of_TEX` instead, where no log file is open yet
+        $globals.history = history_kind::fatal_error_stop;
+        jump_out()?
+        // end
+    }
+    // else begin cur_input.loc_field:=first; cur_input.limit_field:=last-1;
+    else {
+        $globals.cur_input.loc_field = $globals.first.get() as _;
+        $globals.cur_input.limit_field = ($globals.last.get() - 1) as _;
+        // overflow("buffer size",buf_size);
+        overflow(
+            $globals,
+            crate::strpool_str!("buffer size"),
+            $globals.buf_size as _,
+        )?;
+        crate::never_nojump!()
+        // end
+    }
+    use crate::io_support::write_ln;
+    use crate::section_0076::history_kind;
+    use crate::section_0081::jump_out;
+    use crate::section_0094::overflow;
+}}
+
+/// `input_ln`, with an overflow of the input buffer reported as in
+/// `Report_overflow_of_the_input_buffer_and_abort`.
+pub(crate) macro input_ln_or_report_overflow($globals:expr, $f:expr, $bypass_eoln:expr) {{
+    use crate::section_0004::make_globals_io_view;
+    use crate::section_0004::TeXGlobalsIoView;
+    use crate::section_0031::input_buffer_overflow;
+    use crate::section_0031::input_ln;
+    match input_ln(make_globals_io_view!($globals), $f, $bypass_eoln) {
+        Ok(not_eof) => not_eof,
+        Err(input_buffer_overflow) => {
+            crate::section_0035::Report_overflow_of_the_input_buffer_and_abort!($globals)
+        }
+    }
+}}
@@ -6,10 +6,49 @@
 //
 // @p function init_terminal:boolean; {gets the terminal input started}
 /// gets the terminal input started
-pub(crate) fn init_terminal(globals: &mut TeXGlobals) -> boolean {
+pub(crate) fn init_terminal(globals: &mut TeXGlobals) -> TeXResult<boolean> {
     // label exit;
     // begin t_open_in;
     t_open_in(globals);
+    // TeX-rs: a first line given along with the job is taken as if it had
+    // been typed, as long as it isn't all blank.
+    if let Some(first_line) = take_first_line(globals) {
+        let mut units = vec![];
+        alpha_file::convert_line_string_to_units(&first_line, &mut units);
+        #[cfg(feature = "unicode_support")]
+        let units = {
+            let handling = globals.grapheme_cluster_handling;
+            units
+                .into_iter()
+                .flat_map(|unit| buffer_units_from_input_unit(handling, unit))
+                .collect::<Vec<_>>()
+        };
+        globals.last = globals.first;
+        for unit in units {
+            if globals.last.get() + 1 >= globals.buf_size {
+                break;
+            }
+            globals.buffer[globals.last] = xord(unit);
+            incr!(globals.last);
+        }
+        while globals.last > globals.first
+            && globals.buffer[globals.last - 1] == ASCII_code_literal!(b' ')
+        {
+            decr!(globals.last);
+        }
+        if globals.last >= globals.max_buf_stack {
+            globals.max_buf_stack = globals.last + 1;
+        }
+        loc!(globals) = globals.first.get() as _;
+        while loc!(globals) < globals.last.get() as _
+            && globals.buffer[loc!(globals)] == ASCII_code_literal!(b' ')
+        {
+            incr!(loc!(globals));
+        }
+        if loc!(globals) < globals.last.get() as _ {
+            return crate::ok_nojump!(true);
+        }
+    }
     // loop@+begin wake_up_terminal; write(term_out,'**'); update_terminal;
     loop {
         wake_up_terminal(globals);
@@ -17,7 +56,7 @@ pub(crate) fn init_terminal(globals: &mut TeXGlobals) -> boolean {
         update_terminal(globals);
         // @.**@>
         //   if not input_ln(term_in,true) then {this shouldn't happen}
-        if !input_ln(make_globals_io_view!(globals), &mut globals.term_in, true) {
+        if !input_ln_or_report_overflow!(globals, &mut globals.term_in, true) {
             /// this shouldn't happen
             const _: () = ();
             // begin write_ln(term_out);
@@ -29,23 +68,23 @@ pub(crate) fn init_terminal(globals: &mut TeXGlobals) -> boolean {
             );
             // @.End of file on the terminal@>
             // init_terminal:=false; return;
-            return false;
+            return crate::ok_nojump!(false);
             // end;
         }
         // loc:=first;
-        loc!(globals) = globals.first.get();
+        loc!(globals) = globals.first.get() as _;
         // while (loc<last)and(buffer[loc]=" ") do incr(loc);
-        while loc!(globals) < globals.last.get()
+        while loc!(globals) < globals.last.get() as _
             && globals.buffer[loc!(globals)] == ASCII_code_literal!(b' ')
         {
             incr!(loc!(globals));
         }
         // if loc<last then
-        if loc!(globals) < globals.last.get() {
+        if loc!(globals) < globals.last.get() as _ {
             // begin init_terminal:=true;
             // return; {return unless the line was all blank}
             /// return unless the line was all blank
-            return true;
+            return crate::ok_nojump!(true);
             // end;
         }
         // write_ln(term_out,'Please type the name of your input file.');
@@ -58,15 +97,23 @@ pub(crate) fn init_terminal(globals: &mut TeXGlobals) -> boolean {
     // exit:end;
 }
 
+use crate::command_line::take_first_line;
+use crate::io_support::PascalFile;
 use crate::io_support::{write, write_ln, write_ln_noargs};
 use crate::pascal::boolean;
 use crate::section_0004::make_globals_io_view;
 use crate::section_0004::TeXGlobals;
 use crate::section_0004::TeXGlobalsIoView;
+use crate::section_0016::decr;
 use crate::section_0016::incr;
 use crate::section_0018::ASCII_code_literal;
-use crate::section_0031::input_ln;
+use crate::section_0020::xord;
+use crate::section_0025::alpha_file;
 use crate::section_0033::t_open_in;
 use crate::section_0034::update_terminal;
 use crate::section_0034::wake_up_terminal;
+use crate::section_0035::input_ln_or_report_overflow;
 use crate::section_0036::loc;
+use crate::section_0081::TeXResult;
+#[cfg(feature = "unicode_support")]
+use crate::unicode_support::buffer_units_from_input_unit;
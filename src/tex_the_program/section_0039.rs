@@ -6,10 +6,8 @@
 #[globals_struct_field_view(TeXGlobalsIoStringLogView)]
 pub(crate) static str_pool: str_pool_array<packed_ASCII_code> = str_pool_array::default();
 
-type str_pool_array_LENGTH_TYPENUM = typenum::op!(pool_size_TYPENUM + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_from_0_with_fixed_length!(
-    pub(crate) str_pool_array[pool_pointer] => u32; U32; str_pool_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) str_pool_array[pool_pointer] => u32; U32; U0
 );
 
 #[globals_struct_use(TeXGlobals)]
@@ -66,11 +64,11 @@ use crate::section_0038::str_number;
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0011::max_strings_TYPENUM;
-use crate::section_0011::pool_size_TYPENUM;
 use crate::section_0038::packed_ASCII_code;
 use crate::section_0038::pool_pointer;
 use crate::section_0038::str_number;
 use globals_struct::{globals_struct_field, globals_struct_use};
+use typenum::U0;
 use typenum::U1;
 
 #[cfg(feature = "unicode_support")]
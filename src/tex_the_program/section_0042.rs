@@ -38,14 +38,19 @@ pub(crate) fn flush_char(globals: &mut TeXGlobals) {
 
 // @d str_room(#) == {make sure that the pool hasn't overflowed}
 /// make sure that the pool hasn't overflowed
-pub(crate) fn str_room(globals: &mut TeXGlobals, bytes_count: integer) {
+pub(crate) fn str_room(globals: &mut TeXGlobals, bytes_count: integer) -> TeXResult<()> {
     // begin if pool_ptr+# > pool_size then
-    if globals.pool_ptr.get() + bytes_count as u32 > pool_size as u32 {
-        todo!("overflow");
+    if globals.pool_ptr.get() + bytes_count as u32 > globals.pool_size {
         // overflow("pool size",pool_size-init_pool_ptr);
+        overflow(
+            globals,
+            crate::strpool_str!("pool size"),
+            (globals.pool_size - globals.init_pool_ptr.get()) as integer,
+        )?;
         // @:TeX capacity exceeded pool size}{\quad pool size@>
         // end
     }
+    crate::ok_nojump!()
 }
 
 #[cfg(not(feature = "unicode_support"))]
@@ -57,7 +62,8 @@ pub(crate) const character_max_room: integer = 6;
 use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
 use crate::section_0004::TeXGlobalsStringView;
-use crate::section_0011::pool_size;
 use crate::section_0016::decr;
 use crate::section_0016::incr;
 use crate::section_0018::ASCII_code;
+use crate::section_0081::TeXResult;
+use crate::section_0094::overflow;
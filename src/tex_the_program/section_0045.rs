@@ -18,7 +18,7 @@ pub(crate) fn str_eq_buf(globals: &mut TeXGlobals, s: str_number, k: integer) ->
     {
         #[cfg(not(feature = "unicode_support"))]
         {
-            todo!("not yet implemented in {}", file!());
+            crate::unported::unported!(globals, 45);
             // begin j:=str_start[s];
             // while j<str_start[s+1] do
             //   begin if so(str_pool[j])<>buffer[k] then
@@ -78,7 +78,7 @@ pub(crate) fn print_char(mut globals: TeXGlobalsIoStringLogView<'_>, s: ASCII_co
     }
     // new_string: begin if pool_ptr<pool_size then append_char(s);
     else if *globals.selector == new_string {
-        if globals.pool_ptr.get() < pool_size as _ {
+        if globals.pool_ptr.get() < *globals.pool_size as _ {
             append_char(make_globals_string_view!(globals), s);
         }
         // end; {we drop characters if the string space is full}
@@ -104,7 +104,6 @@ use crate::section_0004::TeXGlobalsIoStringLogView;
 use crate::section_0004::TeXGlobalsIoView;
 use crate::section_0004::TeXGlobalsLogView;
 use crate::section_0004::TeXGlobalsStringView;
-use crate::section_0011::pool_size;
 use crate::section_0016::do_nothing;
 use crate::section_0016::incr;
 use crate::section_0018::ASCII_code;
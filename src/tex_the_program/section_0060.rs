@@ -35,7 +35,15 @@ pub(crate) fn slow_print(globals: &mut TeXGlobals, s: integer) {
                 .str_ascii_codes(&globals.str_start, str_number::new(s as u32))
                 .collect::<Vec<_>>();
             for ch in chars {
-                print_char(make_globals_io_string_log_view!(globals), xord(ch));
+                // TeX-rs: characters that TeX reads as 8-bit codes are printed
+                // through their own strings, the others are printed as they are.
+                let c = xord(ch);
+                let eight_bit = ASCII_code::max_allowed_repr(globals) <= 255;
+                if c.numeric_value() < 128 || (eight_bit && c.numeric_value() < 256) {
+                    print(globals, c.numeric_value() as _);
+                } else {
+                    print_char(make_globals_io_string_log_view!(globals), c);
+                }
             }
         }
         // end;
@@ -46,6 +54,7 @@ use crate::pascal::integer;
 use crate::section_0004::make_globals_io_string_log_view;
 use crate::section_0004::TeXGlobals;
 use crate::section_0004::TeXGlobalsIoStringLogView;
+use crate::section_0018::ASCII_code;
 use crate::section_0020::xord;
 use crate::section_0038::str_number;
 use crate::section_0058::print_char;
@@ -6,9 +6,29 @@
 // @d print_ASCII == print
 #[allow(non_snake_case)]
 pub(crate) fn print_ASCII(globals: &mut TeXGlobals, s: integer) {
+    // TeX-rs: character codes beyond 255 are not string numbers, so they are
+    // sent to `print_char` directly.
+    #[cfg(feature = "unicode_support")]
+    {
+        if s > 255 {
+            print_char(
+                make_globals_io_string_log_view!(globals),
+                ASCII_code::from(s),
+            );
+            return;
+        }
+    }
     print(globals, s);
 }
 
 use crate::pascal::integer;
+#[cfg(feature = "unicode_support")]
+use crate::section_0004::make_globals_io_string_log_view;
 use crate::section_0004::TeXGlobals;
+#[cfg(feature = "unicode_support")]
+use crate::section_0004::TeXGlobalsIoStringLogView;
+#[cfg(feature = "unicode_support")]
+use crate::section_0018::ASCII_code;
+#[cfg(feature = "unicode_support")]
+use crate::section_0058::print_char;
 use crate::section_0059::print;
@@ -32,7 +32,7 @@ pub(crate) fn term_input(globals: &mut TeXGlobals) -> TeXResult<()> {
     /// now the user sees the prompt for sure
     update_terminal(globals);
     // if not input_ln(term_in,true) then fatal_error("End of file on the terminal!");
-    if !input_ln(make_globals_io_view!(globals), &mut globals.term_in, true) {
+    if !input_ln_or_report_overflow!(globals, &mut globals.term_in, true) {
         fatal_error(globals, crate::strpool_str!("End of file on the terminal!"))?;
     }
     // @.End of file on the terminal@>
@@ -66,8 +66,8 @@ use crate::section_0004::TeXGlobalsIoStringLogView;
 use crate::section_0004::TeXGlobalsIoView;
 use crate::section_0016::decr;
 use crate::section_0016::incr;
-use crate::section_0031::input_ln;
 use crate::section_0034::update_terminal;
+use crate::section_0035::input_ln_or_report_overflow;
 use crate::section_0057::print_ln;
 use crate::section_0059::print;
 use crate::section_0081::TeXResult;
@@ -19,6 +19,11 @@ pub(crate) fn error(globals: &mut TeXGlobals) -> TeXResult<()> {
         ASCII_code_literal!(b'.'),
     );
     show_context(globals);
+    // TeX-rs: with `halt_on_error`, there is no recovery from any error.
+    if globals.halt_on_error {
+        globals.history = fatal_error_stop;
+        jump_out()?;
+    }
     // if interaction=error_stop_mode then if selector<>log_only then
     //   @<Get user's advice and |return|@>;
     if globals.interaction == error_stop_mode && globals.selector != log_only {
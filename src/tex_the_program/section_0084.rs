@@ -26,7 +26,7 @@ pub(crate) macro Interpret_code_c_and_return_if_done($globals:expr, $c:expr, $lb
     // @t\4\4@>@;@+@!debug "D": begin debug_help; goto continue;@+end;@+gubed@/
     else if DEBUGGING_ENABLED && $c == ASCII_code_literal!(b'D') {
         crate::region_debug! {
-            debug_help($globals);
+            debug_help($globals)?;
             crate::goto_backward_label!($lbl_continue);
 
             use crate::section_1338::debug_help;
@@ -3,7 +3,7 @@
 //! running a bit longer.
 //
 // @p procedure normalize_selector;
-pub(crate) fn normalize_selector(globals: &mut TeXGlobals) {
+pub(crate) fn normalize_selector(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin if log_opened then selector:=term_and_log
     if globals.log_opened {
         globals.selector = term_and_log.into();
@@ -14,13 +14,14 @@ pub(crate) fn normalize_selector(globals: &mut TeXGlobals) {
     }
     // if job_name=0 then open_log_file;
     if globals.job_name == 0 {
-        open_log_file(globals);
+        open_log_file(globals)?;
     }
     // if interaction=batch_mode then decr(selector);
     if globals.interaction == batch_mode {
         decr!(globals.selector);
     }
     // end;
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
@@ -28,4 +29,5 @@ use crate::section_0016::decr;
 use crate::section_0054::term_and_log;
 use crate::section_0054::term_only;
 use crate::section_0073::batch_mode;
+use crate::section_0081::TeXResult;
 use crate::section_0534::open_log_file;
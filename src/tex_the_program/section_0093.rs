@@ -16,7 +16,7 @@ pub(crate) fn succumb(globals: &mut TeXGlobals) -> TeXResult<!> {
     // @!debug if interaction>batch_mode then debug_help;@+gubed@;@/
     crate::region_debug! {
         if globals.interaction > batch_mode {
-            debug_help(globals);
+            debug_help(globals)?;
             use crate::section_1338::debug_help;
         }
     }
@@ -33,7 +33,7 @@ pub(crate) fn succumb(globals: &mut TeXGlobals) -> TeXResult<!> {
 /// prints `s`, and that's it
 pub(crate) fn fatal_error(globals: &mut TeXGlobals, s: str_number) -> TeXResult<()> {
     // begin normalize_selector;@/
-    normalize_selector(globals);
+    normalize_selector(globals)?;
     // print_err("Emergency stop"); help1(s); succumb;
     print_err!(globals, crate::strpool_str!("Emergency stop"));
     help1!(globals, s);
@@ -3,20 +3,50 @@
 // @<Error hand...@>=
 // procedure overflow(@!s:str_number;@!n:integer); {stop due to finiteness}
 /// stop due to finiteness
-#[allow(unused_variables)]
 pub(crate) fn overflow(globals: &mut TeXGlobals, s: str_number, n: integer) -> TeXResult<()> {
     // begin normalize_selector;
+    normalize_selector(globals)?;
     // print_err("TeX capacity exceeded, sorry [");
+    print_err!(
+        globals,
+        crate::strpool_str!("TeX capacity exceeded, sorry [")
+    );
     // @.TeX capacity exceeded ...@>
     // print(s); print_char("="); print_int(n); print_char("]");
+    print(globals, s.get() as _);
+    print_char(
+        make_globals_io_string_log_view!(globals),
+        ASCII_code_literal!(b'='),
+    );
+    print_int(globals, n);
+    print_char(
+        make_globals_io_string_log_view!(globals),
+        ASCII_code_literal!(b']'),
+    );
     // help2("If you really absolutely need more capacity,")@/
     //   ("you can ask a wizard to enlarge me.");
+    help2!(
+        globals,
+        crate::strpool_str!("If you really absolutely need more capacity,"),
+        crate::strpool_str!("you can ask a wizard to enlarge me.")
+    );
     // succumb;
+    succumb(globals)?;
     // end;
-    todo!("not yet implemented in {}", file!());
+    crate::ok_nojump!()
 }
 
 use crate::pascal::integer;
+use crate::section_0004::make_globals_io_string_log_view;
 use crate::section_0004::TeXGlobals;
+use crate::section_0004::TeXGlobalsIoStringLogView;
+use crate::section_0018::ASCII_code_literal;
 use crate::section_0038::str_number;
+use crate::section_0058::print_char;
+use crate::section_0059::print;
+use crate::section_0065::print_int;
+use crate::section_0073::print_err;
+use crate::section_0079::help2;
 use crate::section_0081::TeXResult;
+use crate::section_0092::normalize_selector;
+use crate::section_0093::succumb;
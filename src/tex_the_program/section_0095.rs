@@ -13,7 +13,7 @@
 #[allow(unused_variables)]
 pub(crate) fn confusion(globals: &mut TeXGlobals, s: str_number) -> TeXResult<!> {
     // begin normalize_selector;
-    normalize_selector(globals);
+    normalize_selector(globals)?;
     // if history<error_message_issued then
     if globals.history < history_kind::error_message_issued {
         // begin print_err("This can't happen ("); print(s); print_char(")");
@@ -66,6 +66,11 @@ pub const max_quarterword: quarterword = 255;
 pub const min_halfword: halfword = 0;
 
 /// largest allowable value in a `halfword`
+#[cfg(not(feature = "wide_pointers"))]
 pub const max_halfword: halfword = 65535;
+/// largest allowable value in a `halfword`; the wide-pointer build uses
+/// 32-bit halfwords, keeping the top bits clear as other implementations do
+#[cfg(feature = "wide_pointers")]
+pub const max_halfword: halfword = 0x0FFF_FFFF;
 
 use crate::section_0113::{halfword, quarterword};
@@ -1,18 +1,78 @@
 //! @ Here are the inequalities that the quarterword and halfword values
 //! must satisfy (or rather, the inequalities that they mustn't satisfy):
-//!
-//! @<Check the ``constant''...@>=
-//! init if (mem_min<>mem_bot)or(mem_max<>mem_top) then bad:=10;@+tini@;@/
-//! if (mem_min>mem_bot)or(mem_max<mem_top) then bad:=10;
-//! if (min_quarterword>0)or(max_quarterword<127) then bad:=11;
-//! if (min_halfword>0)or(max_halfword<32767) then bad:=12;
-//! if (min_quarterword<min_halfword)or@|
-//!   (max_quarterword>max_halfword) then bad:=13;
-//! if (mem_min<min_halfword)or(mem_max>=max_halfword)or@|
-//!   (mem_bot-mem_min>max_halfword+1) then bad:=14;
-//! if (font_base<min_quarterword)or(font_max>max_quarterword) then bad:=15;
-//! if font_max>font_base+256 then bad:=16;
-//! if (save_size>max_halfword)or(max_strings>max_halfword) then bad:=17;
-//! if buf_size>max_halfword then bad:=18;
-//! if max_quarterword-min_quarterword<255 then bad:=19;
-//!
+//
+// @<Check the ``constant''...@>=
+pub(crate) macro Check_the_constant_values_for_consistency_0111($globals:expr) {{
+    // init if (mem_min<>mem_bot)or(mem_max<>mem_top) then bad:=10;@+tini@;@/
+    crate::region_initex! {
+        // TeX-rs: only a run that is `ini_version` builds `mem` from scratch.
+        if $globals.ini_version
+            && (mem_min as integer != mem_bot as integer
+                || $globals.mem_max as integer != mem_top as integer)
+        {
+            $globals.bad = 10;
+        }
+    }
+    // if (mem_min>mem_bot)or(mem_max<mem_top) then bad:=10;
+    if mem_min as integer > mem_bot as integer || ($globals.mem_max as integer) < mem_top as integer
+    {
+        $globals.bad = 10;
+    }
+    // if (min_quarterword>0)or(max_quarterword<127) then bad:=11;
+    if min_quarterword as integer > 0 || (max_quarterword as integer) < 127 {
+        $globals.bad = 11;
+    }
+    // if (min_halfword>0)or(max_halfword<32767) then bad:=12;
+    if min_halfword as integer > 0 || (max_halfword as integer) < 32767 {
+        $globals.bad = 12;
+    }
+    // if (min_quarterword<min_halfword)or@|
+    //   (max_quarterword>max_halfword) then bad:=13;
+    if (min_quarterword as integer) < min_halfword as integer
+        || max_quarterword as integer > max_halfword as integer
+    {
+        $globals.bad = 13;
+    }
+    // if (mem_min<min_halfword)or(mem_max>=max_halfword)or@|
+    //   (mem_bot-mem_min>max_halfword+1) then bad:=14;
+    if (mem_min as integer) < min_halfword as integer
+        || $globals.mem_max as integer >= max_halfword as integer
+        || mem_bot as integer - mem_min as integer > max_halfword as integer + 1
+    {
+        $globals.bad = 14;
+    }
+    // if (font_base<min_quarterword)or(font_max>max_quarterword) then bad:=15;
+    if (font_base as integer) < min_quarterword as integer
+        || $globals.font_max as integer > max_quarterword as integer
+    {
+        $globals.bad = 15;
+    }
+    // if font_max>font_base+256 then bad:=16;
+    if $globals.font_max as integer > font_base as integer + 256 {
+        $globals.bad = 16;
+    }
+    // if (save_size>max_halfword)or(max_strings>max_halfword) then bad:=17;
+    if $globals.save_size as integer > max_halfword as integer
+        || max_strings as integer > max_halfword as integer
+    {
+        $globals.bad = 17;
+    }
+    // if buf_size>max_halfword then bad:=18;
+    if $globals.buf_size as integer > max_halfword as integer {
+        $globals.bad = 18;
+    }
+    // if max_quarterword-min_quarterword<255 then bad:=19;
+    if (max_quarterword as integer - min_quarterword as integer) < 255 {
+        $globals.bad = 19;
+    }
+    use crate::pascal::integer;
+    use crate::section_0011::max_strings;
+    use crate::section_0011::mem_min;
+    use crate::section_0012::font_base;
+    use crate::section_0012::mem_bot;
+    use crate::section_0012::mem_top;
+    use crate::section_0110::max_halfword;
+    use crate::section_0110::max_quarterword;
+    use crate::section_0110::min_halfword;
+    use crate::section_0110::min_quarterword;
+}}
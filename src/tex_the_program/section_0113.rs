@@ -16,9 +16,16 @@ const_assert!(min_quarterword == quarterword::MIN);
 const_assert!(max_quarterword == quarterword::MAX);
 
 // @!halfword=min_halfword..max_halfword; {1/2 of a word}
+#[cfg(not(feature = "wide_pointers"))]
 pub type halfword = u16;
+/// 1/2 of a word, wide enough for a `mem` beyond 65535 words
+#[cfg(feature = "wide_pointers")]
+pub type halfword = u32;
 const_assert!(min_halfword == halfword::MIN);
+#[cfg(not(feature = "wide_pointers"))]
 const_assert!(max_halfword == halfword::MAX);
+#[cfg(feature = "wide_pointers")]
+const_assert!(max_halfword < halfword::MAX);
 
 // @!two_choices = 1..2; {used when there are two variants in a record}
 // @!four_choices = 1..4; {used when there are four variants in a record}
@@ -99,6 +106,8 @@ pub(crate) union memory_word {
     int: integer,
     gr: glue_ratio,
     w: word,
+    #[cfg(feature = "wide_pointers")]
+    dw: u64,
     hh: two_halves,
     qqqq: four_quarters,
 }
@@ -113,12 +122,22 @@ use crate::section_0109::glue_ratio;
 use crate::section_0110::{max_halfword, max_quarterword, min_halfword, min_quarterword};
 use static_assertions::const_assert;
 
+#[cfg(not(feature = "wide_pointers"))]
 impl Default for memory_word {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
 }
 
+/// Every word starts with all eight bytes zeroed, so the variants
+/// narrower than `dw` never leave bytes uninitialized for `to_blob` to read.
+#[cfg(feature = "wide_pointers")]
+impl Default for memory_word {
+    fn default() -> Self {
+        memory_word { dw: 0 }
+    }
+}
+
 pub(crate) struct MEMORY_WORD_INT;
 
 impl Index<MEMORY_WORD_INT> for memory_word {
@@ -363,9 +382,9 @@ impl IndexMut<FOUR_QUARTERS_B3> for four_quarters {
     }
 }
 
+#[cfg(not(feature = "wide_pointers"))]
 impl FromBlob for memory_word {
     fn from_blob(data: &[u8]) -> Self {
-        use core::mem::transmute;
         assert!(data.len() == 4);
         memory_word {
             int: i32::from_le_bytes([data[0], data[1], data[2], data[3]]),
@@ -373,6 +392,7 @@ impl FromBlob for memory_word {
     }
 }
 
+#[cfg(not(feature = "wide_pointers"))]
 impl ToBlob for memory_word {
     type BlobType = [u8; 4];
 
@@ -381,6 +401,28 @@ impl ToBlob for memory_word {
     }
 }
 
+/// With 32-bit halfwords a `memory_word` takes eight bytes in a format file.
+#[cfg(feature = "wide_pointers")]
+impl FromBlob for memory_word {
+    fn from_blob(data: &[u8]) -> Self {
+        assert!(data.len() == 8);
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(data);
+        memory_word {
+            dw: u64::from_le_bytes(bytes),
+        }
+    }
+}
+
+#[cfg(feature = "wide_pointers")]
+impl ToBlob for memory_word {
+    type BlobType = [u8; 8];
+
+    fn to_blob(&self) -> Self::BlobType {
+        unsafe { self.dw.to_le_bytes() }
+    }
+}
+
 use crate::io_support::FromBlob;
 use crate::io_support::ToBlob;
 use core::ops::{Index, IndexMut};
@@ -30,11 +30,8 @@
 // @<Glob...@>=
 // @!mem : array[mem_min..mem_max] of memory_word; {the big dynamic storage area}
 /// the big dynamic storage area
-///
-/// Note: using explicit zeroed() to workaround large stack memory usage.
 #[globals_struct_field(TeXGlobals)]
-pub(crate) static mem: Box<mem_array<memory_word>> =
-    Box::new(mem_array::<memory_word>::default_zeroed());
+pub(crate) static mem: mem_array<memory_word> = mem_array::default();
 
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0116::mem_array;
@@ -42,13 +39,49 @@ use crate::section_0116::mem_array;
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0113::memory_word;
 
-type mem_array_LENGTH_TYPENUM = typenum::op!(mem_max_TYPENUM - mem_min_TYPENUM + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length!(
+#[cfg(not(feature = "wide_pointers"))]
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
     pub(crate) mem_array[u32_from_m_to_n<mem_min_TYPENUM, mem_max_TYPENUM>] =>
-    u32; U32; mem_min_TYPENUM; mem_array_LENGTH_TYPENUM
+    u32; U32; mem_min_TYPENUM
 );
 
+/// In the wide-pointer build `mem` spans almost all of the 32-bit address
+/// space, so it is kept in two pieces that grow on demand: `lo` holds the
+/// locations from `mem_min` upward, and `hi` holds the locations from
+/// `max` downward, in reverse order. Nothing is allocated for the
+/// gap between `lo_mem_max` and `hi_mem_min`.
+#[cfg(feature = "wide_pointers")]
+#[derive(Default)]
+pub(crate) struct mem_array<ELEMENT> {
+    lo: Vec<ELEMENT>,
+    hi: Vec<ELEMENT>,
+    max: pointer,
+}
+
+#[cfg(feature = "wide_pointers")]
+impl<ELEMENT: Default + Clone> mem_array<ELEMENT> {
+    /// Make `mem` span `len` locations from `mem_min` upward; the storage
+    /// itself is provided by `grow`.
+    pub(crate) fn allocate(&mut self, len: usize) {
+        self.lo.clear();
+        self.hi.clear();
+        self.max = mem_min + len as pointer - 1;
+    }
+
+    /// Make sure that every location from `mem_min` to `lo` and from `hi` to
+    /// `max` can be used.
+    pub(crate) fn grow(&mut self, lo: pointer, hi: pointer) {
+        let lo_len = (lo - mem_min) as usize + 1;
+        if self.lo.len() < lo_len {
+            self.lo.resize(lo_len, ELEMENT::default());
+        }
+        let hi_len = (self.max - hi) as usize + 1;
+        if self.hi.len() < hi_len {
+            self.hi.resize(hi_len, ELEMENT::default());
+        }
+    }
+}
+
 // @!lo_mem_max : pointer; {the largest location of variable-size memory in use}
 /// the largest location of variable-size memory in use
 #[globals_struct_field(TeXGlobals)]
@@ -59,38 +92,73 @@ pub(crate) static lo_mem_max: pointer = null;
 #[globals_struct_field(TeXGlobals)]
 pub(crate) static hi_mem_min: pointer = null;
 
+#[cfg(not(feature = "wide_pointers"))]
 use crate::pascal::u32_from_m_to_n;
+#[cfg(not(feature = "wide_pointers"))]
 use crate::section_0011::mem_max_TYPENUM;
+#[cfg(not(feature = "wide_pointers"))]
 use crate::section_0011::mem_min_TYPENUM;
 use globals_struct::{globals_struct_field, globals_struct_use};
-use typenum::U1;
 
-use crate::section_0113::memory_word;
+#[cfg(not(feature = "wide_pointers"))]
+impl<ELEMENT> core::ops::Index<pointer> for mem_array<ELEMENT> {
+    type Output = ELEMENT;
 
-impl mem_array<memory_word> {
-    pub(crate) fn default_zeroed() -> Self {
-        unsafe { core::mem::zeroed() }
+    fn index(&self, index: pointer) -> &Self::Output {
+        &self[u32_from_m_to_n::<mem_min_TYPENUM, mem_max_TYPENUM>::new(index as _)]
     }
 }
 
-impl mem_array<crate::pascal::boolean> {
-    pub(crate) fn default_zeroed() -> Self {
-        unsafe { core::mem::zeroed() }
+#[cfg(not(feature = "wide_pointers"))]
+impl<ELEMENT> core::ops::IndexMut<pointer> for mem_array<ELEMENT> {
+    fn index_mut(&mut self, index: pointer) -> &mut Self::Output {
+        &mut self[u32_from_m_to_n::<mem_min_TYPENUM, mem_max_TYPENUM>::new(index as _)]
     }
 }
 
+#[cfg(feature = "wide_pointers")]
 impl<ELEMENT> core::ops::Index<pointer> for mem_array<ELEMENT> {
     type Output = ELEMENT;
 
     fn index(&self, index: pointer) -> &Self::Output {
-        &self[u32_from_m_to_n::<mem_min_TYPENUM, mem_max_TYPENUM>::new(index as _)]
+        let k = (index - mem_min) as usize;
+        if k < self.lo.len() {
+            &self.lo[k]
+        } else {
+            &self.hi[(self.max - index) as usize]
+        }
     }
 }
 
+#[cfg(feature = "wide_pointers")]
 impl<ELEMENT> core::ops::IndexMut<pointer> for mem_array<ELEMENT> {
     fn index_mut(&mut self, index: pointer) -> &mut Self::Output {
-        &mut self[u32_from_m_to_n::<mem_min_TYPENUM, mem_max_TYPENUM>::new(index as _)]
+        let k = (index - mem_min) as usize;
+        if k < self.lo.len() {
+            &mut self.lo[k]
+        } else {
+            &mut self.hi[(self.max - index) as usize]
+        }
+    }
+}
+
+/// Provide storage for the locations from `mem_min` to `lo_mem_max` and from
+/// `hi_mem_min` to `mem_max`. Only the wide-pointer build needs to do anything
+/// here; otherwise all of `mem` is allocated when \TeX\ starts.
+#[allow(unused_variables)]
+pub(crate) fn make_room_in_mem(globals: &mut TeXGlobals, lo_mem_max: pointer, hi_mem_min: pointer) {
+    #[cfg(feature = "wide_pointers")]
+    {
+        globals.mem.grow(lo_mem_max, hi_mem_min);
+        #[cfg(feature = "debugging")]
+        {
+            globals.free.grow(lo_mem_max, hi_mem_min);
+            globals.was_free.grow(lo_mem_max, hi_mem_min);
+        }
     }
 }
 
+use crate::section_0004::TeXGlobals;
+#[cfg(feature = "wide_pointers")]
+use crate::section_0011::mem_min;
 use crate::section_0115::pointer;
@@ -10,7 +10,7 @@
 // @p function get_avail : pointer; {single-word node allocation}
 /// single-word node allocation
 #[cfg_attr(feature = "trace_verbose", tracing::instrument(level = "trace"))]
-pub(crate) fn get_avail(globals: &mut TeXGlobals) -> pointer {
+pub(crate) fn get_avail(globals: &mut TeXGlobals) -> TeXResult<pointer> {
     // var p:pointer; {the new node being got}
     /// the new node being got
     let mut p: pointer;
@@ -27,12 +27,13 @@ pub(crate) fn get_avail(globals: &mut TeXGlobals) -> pointer {
         }
     }
     // else if mem_end<mem_max then {or go into virgin territory}
-    else if (globals.mem_end as u32) < mem_max {
+    else if (globals.mem_end as u32) < globals.mem_max {
         /// or go into virgin territory
         crate::trace_expr_verbose!("mem_end = {}", globals.mem_end);
-        todo!("not yet implemented in {}", file!());
-    //   begin incr(mem_end); p:=mem_end;
-    //   end
+        // begin incr(mem_end); p:=mem_end;
+        incr!(globals.mem_end);
+        p = globals.mem_end;
+        // end
     }
     // else   begin decr(hi_mem_min); p:=hi_mem_min;
     else {
@@ -49,10 +50,18 @@ pub(crate) fn get_avail(globals: &mut TeXGlobals) -> pointer {
             //     overflow("main memory size",mem_max+1-mem_min);
             //       {quit; all one-word nodes are busy}
             // @:TeX capacity exceeded main memory size}{\quad main memory size@>
+            /// if memory is exhausted, display possible runaway text
+            runaway(globals);
+            /// quit; all one-word nodes are busy
+            overflow(
+                globals,
+                crate::strpool_str!("main memory size"),
+                (globals.mem_max + 1 - mem_min) as integer,
+            )?;
             //     end;
             //   end;
-            todo!("not yet implemented in {}", file!());
         }
+        make_room_in_mem(globals, globals.lo_mem_max, globals.hi_mem_min);
     }
     // link(p):=null; {provide an oft-desired initialization of the new node}
     /// provide an oft-desired initialization of the new node
@@ -65,13 +74,19 @@ pub(crate) fn get_avail(globals: &mut TeXGlobals) -> pointer {
         use crate::section_0016::incr;
     }
     // get_avail:=p;
-    return p;
+    crate::ok_nojump!(p)
     // end;
 }
 
+use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
-use crate::section_0011::mem_max;
+use crate::section_0011::mem_min;
 use crate::section_0016::decr;
+use crate::section_0016::incr;
+use crate::section_0081::TeXResult;
+use crate::section_0094::overflow;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
+use crate::section_0116::make_room_in_mem;
 use crate::section_0118::link;
+use crate::section_0306::runaway;
@@ -11,7 +11,7 @@ pub(crate) macro fast_get_avail($globals:expr, $val:expr) {{
     $val = $globals.avail;
     // if #=null then #:=get_avail
     if $val == null {
-        $val = get_avail($globals);
+        $val = get_avail($globals)?;
     }
     // else  begin avail:=link(#); link(#):=null;
     else {
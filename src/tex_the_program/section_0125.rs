@@ -56,14 +56,14 @@ pub(crate) fn get_node(globals: &mut TeXGlobals, s: integer) -> TeXResult<pointe
         // end;
     }
     // if lo_mem_max+2<hi_mem_min then if lo_mem_max+2<=mem_bot+max_halfword then
-    if globals.lo_mem_max + 2 < globals.hi_mem_min && globals.lo_mem_max + 2 <= mem_bot + max_halfword {
+    if globals.lo_mem_max + 2 < globals.hi_mem_min && globals.lo_mem_max as integer + 2 <= mem_bot as integer + max_halfword as integer {
         // @<Grow more variable-size memory and |goto restart|@>;
          crate::section_0126::Grow_more_variable_size_memory_and_goto_restart!(globals, p, q, 'restart);
     }
     // overflow("main memory size",mem_max+1-mem_min);
     //   {sorry, nothing satisfactory is left}
     /// sorry, nothing satisfactory is left
-    overflow(globals, crate::strpool_str!("main memory size"), (mem_max + 1 - mem_min) as _)?;
+    overflow(globals, crate::strpool_str!("main memory size"), (globals.mem_max + 1 - mem_min) as _)?;
     // @:TeX capacity exceeded main memory size}{\quad main memory size@>
     // found: link(r):=null; {this node is now nonempty}
     }
@@ -89,7 +89,6 @@ pub(crate) fn get_node(globals: &mut TeXGlobals, s: integer) -> TeXResult<pointe
 
 use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
-use crate::section_0011::mem_max;
 use crate::section_0011::mem_min;
 use crate::section_0012::mem_bot;
 use crate::section_0081::TeXResult;
@@ -26,8 +26,8 @@ pub(crate) macro Grow_more_variable_size_memory_and_goto_restart($globals:expr,
     rlink!($globals, $p) = $q;
     llink!($globals, $globals.rover) = $q;
     // if t>mem_bot+max_halfword then t:=mem_bot+max_halfword;
-    if t > (mem_bot + max_halfword) as integer {
-        t = (mem_bot + max_halfword) as integer;
+    if t > mem_bot as integer + max_halfword as integer {
+        t = mem_bot as integer + max_halfword as integer;
     }
     // rlink(q):=rover; llink(q):=p; link(q):=empty_flag; node_size(q):=t-lo_mem_max;@/
     rlink!($globals, $q) = $globals.rover;
@@ -36,6 +36,7 @@ pub(crate) macro Grow_more_variable_size_memory_and_goto_restart($globals:expr,
     node_size!($globals, $q) = t as halfword - $globals.lo_mem_max;
     // lo_mem_max:=t; link(lo_mem_max):=null; info(lo_mem_max):=null;
     $globals.lo_mem_max = t as _;
+    make_room_in_mem($globals, $globals.lo_mem_max, $globals.hi_mem_min);
     link!($globals, $globals.lo_mem_max) = null;
     info_inner!($globals, $globals.lo_mem_max) = null;
     // rover:=q; goto restart;
@@ -47,6 +48,7 @@ pub(crate) macro Grow_more_variable_size_memory_and_goto_restart($globals:expr,
     use crate::section_0110::max_halfword;
     use crate::section_0113::halfword;
     use crate::section_0115::null;
+    use crate::section_0116::make_room_in_mem;
     use crate::section_0118::info_inner;
     use crate::section_0118::link;
     use crate::section_0124::empty_flag;
@@ -11,6 +11,7 @@ pub(crate) macro Initialize_the_special_list_heads_and_constant_nodes($globals:e
 // @<Initialize table entries...@>=
 pub(crate) macro Initialize_table_entries_done_by_initex_only_0164($globals:expr) {{
     let globals = &mut *$globals;
+    make_room_in_mem(globals, lo_mem_stat_max + 1 + 1000, hi_mem_stat_min);
     // for k:=mem_bot+1 to lo_mem_stat_max do mem[k].sc:=0;
     //   {all glue dimensions are zeroed}
     for k in mem_bot + 1..=lo_mem_stat_max {
@@ -92,6 +93,7 @@ use crate::section_0012::mem_top;
 use crate::section_0101::scaled;
 use crate::section_0101::unity;
 use crate::section_0115::null;
+use crate::section_0116::make_room_in_mem;
 use crate::section_0118::info_inner;
 use crate::section_0118::link;
 use crate::section_0124::empty_flag;
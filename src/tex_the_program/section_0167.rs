@@ -4,7 +4,7 @@
 //
 // @p @!debug procedure check_mem(@!print_locs : boolean);
 #[cfg(feature = "debugging")]
-pub(crate) fn check_mem(globals: &mut TeXGlobals, print_locs: boolean) {
+pub(crate) fn check_mem(globals: &mut TeXGlobals, print_locs: boolean) -> TeXResult<()> {
     // label done1,done2; {loop exits}
     // var p,@!q:pointer; {current locations of interest in |mem|}
     // @!clobbered:boolean; {is something amiss?}
@@ -27,7 +27,7 @@ pub(crate) fn check_mem(globals: &mut TeXGlobals, print_locs: boolean) {
     Check_flags_of_unavailable_nodes!(globals);
     // if print_locs then @<Print newly busy locations@>;
     if print_locs {
-        todo!("Print newly busy locations");
+        crate::unported::unported!(globals, 167, "Print newly busy locations");
     }
     // for p:=mem_min to lo_mem_max do was_free[p]:=free[p];
     // for p:=hi_mem_min to mem_end do was_free[p]:=free[p];
@@ -43,6 +43,7 @@ pub(crate) fn check_mem(globals: &mut TeXGlobals, print_locs: boolean) {
     globals.was_mem_end = globals.mem_end;
     globals.was_lo_max = globals.lo_mem_max;
     globals.was_hi_min = globals.hi_mem_min;
+    crate::ok_nojump!()
     // end;
     // gubed
 }
@@ -50,4 +51,5 @@ pub(crate) fn check_mem(globals: &mut TeXGlobals, print_locs: boolean) {
 use crate::pascal::boolean;
 use crate::section_0004::TeXGlobals;
 use crate::section_0011::mem_min;
+use crate::section_0081::TeXResult;
 use crate::section_0115::pointer;
@@ -15,7 +15,8 @@ pub(crate) fn short_display(globals: &mut TeXGlobals, mut p: pointer) {
                 if font!(globals, p as pointer).get() as integer != globals.font_in_short_display {
                     // begin if (font(p)<font_base)or(font(p)>font_max) then
                     if (font!(globals, p as pointer).get() as integer) < font_base as integer
-                        || (font!(globals, p as pointer).get() as integer) > font_max as integer
+                        || (font!(globals, p as pointer).get() as integer)
+                            > globals.font_max as integer
                     {
                         // print_char("*")
                         print_char(
@@ -61,7 +62,6 @@ use crate::pascal::integer;
 use crate::section_0004::make_globals_io_string_log_view;
 use crate::section_0004::TeXGlobals;
 use crate::section_0004::TeXGlobalsIoStringLogView;
-use crate::section_0011::font_max;
 use crate::section_0011::mem_min;
 use crate::section_0012::font_base;
 use crate::section_0018::ASCII_code_literal;
@@ -12,7 +12,7 @@ pub(crate) fn print_font_and_char(globals: &mut TeXGlobals, p: integer) {
     // else  begin if (font(p)<font_base)or(font(p)>font_max) then print_char("*")
     else {
         if (font!(globals, p as pointer).get() as integer) < font_base as integer
-            || (font!(globals, p as pointer).get() as integer) > font_max as integer
+            || (font!(globals, p as pointer).get() as integer) > globals.font_max as integer
         {
             print_char(
                 make_globals_io_string_log_view!(globals),
@@ -85,7 +85,6 @@ use crate::pascal::integer;
 use crate::section_0004::make_globals_io_string_log_view;
 use crate::section_0004::TeXGlobals;
 use crate::section_0004::TeXGlobalsIoStringLogView;
-use crate::section_0011::font_max;
 use crate::section_0012::font_base;
 use crate::section_0018::ASCII_code_literal;
 use crate::section_0058::print_char;
@@ -49,7 +49,13 @@ pub(crate) macro Display_node_p($globals:expr, $p:expr) {{
             crate::section_0195::Display_discretionary_p!($globals, $p);
         }
         // mark_node: @<Display mark |p|@>;
+        else if type_p == mark_node {
+            crate::section_0196::Display_mark_p!($globals, $p);
+        }
         // adjust_node: @<Display adjustment |p|@>;
+        else if type_p == adjust_node {
+            crate::section_0197::Display_adjustment_p!($globals, $p);
+        }
         // @t\4@>@<Cases of |show_node_list| that arise in mlists only@>@;
         else if crate::section_0690::Cases_of_show_node_list_that_arise_in_mlists_only!(
             $globals, $p, type_p
@@ -74,6 +80,8 @@ pub(crate) macro Display_node_p($globals:expr, $p:expr) {{
     use crate::section_0137::vlist_node;
     use crate::section_0138::rule_node;
     use crate::section_0140::ins_node;
+    use crate::section_0141::mark_node;
+    use crate::section_0142::adjust_node;
     use crate::section_0143::ligature_node;
     use crate::section_0145::disc_node;
     use crate::section_0146::whatsit_node;
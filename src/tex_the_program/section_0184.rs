@@ -28,8 +28,8 @@ pub(crate) macro Display_box_p($globals:expr, $p:expr) {{
     print_scaled($globals, width!($globals, $p as pointer));
     // if type(p)=unset_node then
     if r#type!($globals, $p as pointer) == unset_node {
-        todo!("display unset node special");
         // @<Display special fields of the unset node |p|@>
+        crate::section_0185::Display_special_fields_of_the_unset_node_p!($globals, $p as pointer);
     }
     // else  begin @<Display the value of |glue_set(p)|@>;
     else {
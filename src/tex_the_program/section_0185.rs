@@ -1,13 +1,52 @@
-//! @ @<Display special fields of the unset node |p|@>=
-//! begin if span_count(p)<>min_quarterword then
-//!   begin print(" ("); print_int(qo(span_count(p))+1);
-//!   print(" columns)");
-//!   end;
-//! if glue_stretch(p)<>0 then
-//!   begin print(", stretch "); print_glue(glue_stretch(p),glue_order(p),0);
-//!   end;
-//! if glue_shrink(p)<>0 then
-//!   begin print(", shrink "); print_glue(glue_shrink(p),glue_sign(p),0);
-//!   end;
-//! end
-//!
+//! ` `
+
+// @<Display special fields of the unset node |p|@>=
+pub(crate) macro Display_special_fields_of_the_unset_node_p($globals:expr, $p:expr) {{
+    // begin if span_count(p)<>min_quarterword then
+    if span_count!($globals, $p) != min_quarterword {
+        // begin print(" ("); print_int(qo(span_count(p))+1);
+        print($globals, crate::strpool_str!(" (").get() as _);
+        print_int($globals, qo!(span_count!($globals, $p)) as integer + 1);
+        // print(" columns)");
+        print($globals, crate::strpool_str!(" columns)").get() as _);
+        // end;
+    }
+    // if glue_stretch(p)<>0 then
+    if glue_stretch!($globals, $p) != scaled::zero() {
+        // begin print(", stretch "); print_glue(glue_stretch(p),glue_order(p),0);
+        print($globals, crate::strpool_str!(", stretch ").get() as _);
+        print_glue(
+            $globals,
+            glue_stretch!($globals, $p),
+            glue_order!($globals, $p) as _,
+            str_number::zero(),
+        );
+        // end;
+    }
+    // if glue_shrink(p)<>0 then
+    if glue_shrink!($globals, $p) != scaled::zero() {
+        // begin print(", shrink "); print_glue(glue_shrink(p),glue_sign(p),0);
+        print($globals, crate::strpool_str!(", shrink ").get() as _);
+        print_glue(
+            $globals,
+            glue_shrink!($globals, $p),
+            glue_sign!($globals, $p) as _,
+            str_number::zero(),
+        );
+        // end;
+    }
+    // end
+    use crate::pascal::integer;
+    use crate::section_0038::str_number;
+    use crate::section_0059::print;
+    use crate::section_0065::print_int;
+    use crate::section_0101::scaled;
+    use crate::section_0110::min_quarterword;
+    use crate::section_0112::qo;
+    use crate::section_0135::glue_order;
+    use crate::section_0135::glue_sign;
+    use crate::section_0159::glue_shrink;
+    use crate::section_0159::glue_stretch;
+    use crate::section_0159::span_count;
+    use crate::section_0177::print_glue;
+}}
@@ -0,0 +1,12 @@
+//! ` `
+// @<Display mark |p|@>=
+pub(crate) macro Display_mark_p($globals:expr, $p:expr) {{
+    // begin print_esc("mark"); print_mark(mark_ptr(p));
+    print_esc($globals, crate::strpool_str!("mark"));
+    print_mark($globals, mark_ptr!($globals, $p as pointer));
+    // end
+    use crate::section_0063::print_esc;
+    use crate::section_0115::pointer;
+    use crate::section_0141::mark_ptr;
+    use crate::section_0176::print_mark;
+}}
@@ -0,0 +1,13 @@
+//! ` `
+// @<Display adjustment |p|@>=
+pub(crate) macro Display_adjustment_p($globals:expr, $p:expr) {{
+    // begin print_esc("vadjust"); node_list_display(adjust_ptr(p)); {recursive call}
+    print_esc($globals, crate::strpool_str!("vadjust"));
+    /// recursive call
+    node_list_display!($globals, adjust_ptr!($globals, $p as pointer));
+    // end
+    use crate::section_0063::print_esc;
+    use crate::section_0115::pointer;
+    use crate::section_0142::adjust_ptr;
+    use crate::section_0180::node_list_display;
+}}
@@ -15,11 +15,11 @@ pub(crate) fn show_box(globals: &mut TeXGlobals, p: pointer) {
     }
     // if pool_ptr+depth_threshold>=pool_size then
     if globals.pool_ptr.get() as integer + globals.depth_threshold as integer
-        >= pool_size as integer
+        >= globals.pool_size as integer
     {
         // depth_threshold:=pool_size-pool_ptr-1;
         globals.depth_threshold =
-            (pool_size as integer - globals.pool_ptr.get() as integer - 1) as _;
+            (globals.pool_size as integer - globals.pool_ptr.get() as integer - 1) as _;
     }
     // {now there's enough room for prefix string}
     /// now there's enough room for prefix string
@@ -36,7 +36,6 @@ use crate::pascal::integer;
 use crate::section_0004::make_globals_io_string_log_view;
 use crate::section_0004::TeXGlobals;
 use crate::section_0004::TeXGlobalsIoStringLogView;
-use crate::section_0011::pool_size;
 use crate::section_0057::print_ln;
 use crate::section_0115::pointer;
 use crate::section_0182::show_node_list;
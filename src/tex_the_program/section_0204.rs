@@ -20,7 +20,7 @@ pub(crate) fn copy_node_list(globals: &mut TeXGlobals, mut p: pointer) -> TeXRes
     let mut r;
     // @!words:0..5; {number of words remaining to be copied}
     // begin h:=get_avail; q:=h;
-    h = get_avail(globals);
+    h = get_avail(globals)?;
     q = h;
     // while p<>null do
     while p != null {
@@ -9,7 +9,7 @@ pub(crate) macro Make_a_copy_of_node_p_in_node_r($globals:expr, $p:expr, $r:expr
     const _: () = ();
     // if is_char_node(p) then r:=get_avail
     if is_char_node!($globals, $p) {
-        $r = get_avail($globals);
+        $r = get_avail($globals)?;
     }
     // else @<Case statement to copy different types and set |words| to the number
     //   of initial words not yet copied@>;
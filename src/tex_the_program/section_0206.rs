@@ -43,7 +43,7 @@ pub(crate) macro Case_statement_to_copy_different_types_and_set_words_to_the_num
     // whatsit_node:@<Make a partial copy of the whatsit node |p| and make |r|
     //   point to it; set |words| to the number of initial words not yet copied@>;
     else if type_p == whatsit_node {
-        todo!("Make a partial copy");
+        crate::section_1357::Make_a_partial_copy_of_the_whatsit_node_p_and_make_r_point_to_it__set_words_to_the_number_of_initial_words_not_yet_copied!($globals, $p, $r, $words);
     }
     // glue_node: begin r:=get_node(small_node_size); add_glue_ref(glue_ptr(p));
     else if type_p == glue_node {
@@ -83,11 +83,24 @@ pub(crate) macro Case_statement_to_copy_different_types_and_set_words_to_the_num
         // end;
     }
     // mark_node: begin r:=get_node(small_node_size); add_token_ref(mark_ptr(p));
-    //   words:=small_node_size;
-    //   end;
+    else if type_p == mark_node {
+        $r = get_node($globals, small_node_size as _)?;
+        let mark_ptr_p = mark_ptr!($globals, $p) as pointer;
+        add_token_ref!($globals, mark_ptr_p);
+        //   words:=small_node_size;
+        $words = small_node_size as _;
+        //   end;
+    }
     // adjust_node: begin r:=get_node(small_node_size);
-    //   adjust_ptr(r):=copy_node_list(adjust_ptr(p));
-    //   end; {|words=1=small_node_size-1|}
+    else if type_p == adjust_node {
+        $r = get_node($globals, small_node_size as _)?;
+        //   adjust_ptr(r):=copy_node_list(adjust_ptr(p));
+        adjust_ptr!($globals, $r) =
+            copy_node_list($globals, adjust_ptr!($globals, $p) as _)? as _;
+        //   end; {|words=1=small_node_size-1|}
+        /// `words=1=small_node_size-1`
+        const _: () = ();
+    }
     // othercases confusion("copying")
     else {
         crate::trace_error_expr!("type(p)={}", type_p);
@@ -108,7 +121,12 @@ pub(crate) macro Case_statement_to_copy_different_types_and_set_words_to_the_num
     use crate::section_0140::ins_node_size;
     use crate::section_0140::ins_ptr;
     use crate::section_0140::split_top_ptr;
+    use crate::section_0115::pointer;
+    use crate::section_0141::mark_node;
+    use crate::section_0141::mark_ptr;
     use crate::section_0141::small_node_size;
+    use crate::section_0142::adjust_node;
+    use crate::section_0142::adjust_ptr;
     use crate::section_0143::lig_char;
     use crate::section_0143::lig_ptr;
     use crate::section_0143::ligature_node;
@@ -124,5 +142,6 @@ pub(crate) macro Case_statement_to_copy_different_types_and_set_words_to_the_num
     use crate::section_0157::penalty_node;
     use crate::section_0159::unset_node;
     use crate::section_0203::add_glue_ref;
+    use crate::section_0203::add_token_ref;
     use crate::section_0204::copy_node_list;
 }}
@@ -4,14 +4,18 @@
 //
 // @p procedure push_nest; {enter a new semantic level, save the old}
 /// enter a new semantic level, save the old
-pub(crate) fn push_nest(globals: &mut TeXGlobals) {
+pub(crate) fn push_nest(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin if nest_ptr>max_nest_stack then
     if globals.nest_ptr > globals.max_nest_stack {
         // begin max_nest_stack:=nest_ptr;
         globals.max_nest_stack = globals.nest_ptr;
         // if nest_ptr=nest_size then overflow("semantic nest size",nest_size);
         if globals.nest_ptr == nest_size {
-            todo!("overflow");
+            overflow(
+                globals,
+                crate::strpool_str!("semantic nest size"),
+                nest_size as integer,
+            )?;
         }
         // @:TeX capacity exceeded semantic nest size}{\quad semantic nest size@>
         // end;
@@ -22,16 +26,20 @@ pub(crate) fn push_nest(globals: &mut TeXGlobals) {
     globals.nest[globals.nest_ptr] = globals.cur_list;
     // incr(nest_ptr); head:=get_avail; tail:=head; prev_graf:=0; mode_line:=line;
     incr!(globals.nest_ptr);
-    head!(globals) = get_avail(globals);
+    head!(globals) = get_avail(globals)?;
     tail!(globals) = head!(globals);
     prev_graf!(globals) = 0;
     mode_line!(globals) = globals.line;
+    crate::ok_nojump!()
     // end;
 }
 
+use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
 use crate::section_0011::nest_size;
 use crate::section_0016::incr;
+use crate::section_0081::TeXResult;
+use crate::section_0094::overflow;
 use crate::section_0120::get_avail;
 use crate::section_0213::head;
 use crate::section_0213::mode_line;
@@ -65,6 +65,7 @@ pub(crate) fn show_activities(globals: &mut TeXGlobals) {
             // if link(contrib_head)<>null then
             if link!(globals, contrib_head) != null {
                 // print_nl("### recent contributions:");
+                print_nl(globals, crate::strpool_str!("### recent contributions:"));
             }
             // end;
         }
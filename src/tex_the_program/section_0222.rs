@@ -23,7 +23,7 @@ pub(crate) const single_base: word = single_base_TYPENUM::U32;
 // @d null_cs=single_base+256 {equivalent of \.{\\csname\\endcsname}}
 /// equivalent of `\csname\endcsname`
 pub(crate) type null_cs_TYPENUM = typenum::op!(single_base_TYPENUM + U256);
-pub(crate) const null_cs: halfword = null_cs_TYPENUM::U16;
+pub(crate) const null_cs: halfword = null_cs_TYPENUM::U16 as halfword;
 // @d hash_base=null_cs+1 {beginning of region 2, for the hash table}
 /// beginning of region 2, for the hash table
 pub(crate) type hash_base_TYPENUM = typenum::op!(null_cs_TYPENUM + U1);
@@ -34,6 +34,8 @@ pub(crate) type frozen_control_sequence_TYPENUM =
     typenum::op!(hash_base_TYPENUM + hash_size_TYPENUM);
 pub(crate) const frozen_control_sequence: word = frozen_control_sequence_TYPENUM::U32;
 // @d frozen_protection=frozen_control_sequence {inaccessible but definable}
+/// inaccessible but definable
+pub(crate) const frozen_protection: word = frozen_control_sequence;
 // @d frozen_cr=frozen_control_sequence+1 {permanent `\.{\\cr}'}
 /// permanent `\cr`
 pub(crate) const frozen_cr: word = frozen_control_sequence + 1;
@@ -74,7 +76,8 @@ pub(crate) const font_id_base: word = frozen_null_font - font_base as word;
 // @d undefined_control_sequence=frozen_null_font+257 {dummy location}
 /// dummy location
 pub(crate) type undefined_control_sequence_TYPENUM = typenum::op!(frozen_null_font_TYPENUM + U257);
-pub(crate) const undefined_control_sequence: pointer = undefined_control_sequence_TYPENUM::U16;
+pub(crate) const undefined_control_sequence: pointer =
+    undefined_control_sequence_TYPENUM::U16 as halfword;
 // @d glue_base=undefined_control_sequence+1 {beginning of region 3}
 /// beginning of region 3
 pub(crate) type glue_base_TYPENUM = typenum::op!(undefined_control_sequence_TYPENUM + U1);
@@ -90,7 +93,7 @@ pub(crate) macro Initialize_table_entries_done_by_initex_only_0222($globals:expr
     // eq_level(undefined_control_sequence):=level_zero;
     eq_level!(globals, undefined_control_sequence) = level_zero;
     // for k:=active_base to undefined_control_sequence-1 do
-    for k in active_base as u16..undefined_control_sequence {
+    for k in active_base as pointer..undefined_control_sequence {
         // eqtb[k]:=eqtb[undefined_control_sequence];
         globals.eqtb[k as u16] = globals.eqtb[undefined_control_sequence];
     }
@@ -61,7 +61,7 @@ pub(crate) const thick_mu_skip_code: quarterword = 17;
 // @d glue_pars=18 {total number of glue parameters}
 /// total number of glue parameters
 pub(crate) type glue_pars_TYPENUM = U18;
-pub(crate) const glue_pars: halfword = glue_pars_TYPENUM::U16;
+pub(crate) const glue_pars: halfword = glue_pars_TYPENUM::U16 as halfword;
 // @d skip_base=glue_base+glue_pars {table of 256 ``skip'' registers}
 /// table of 256 ``skip'' registers
 pub(crate) type skip_base_TYPENUM = typenum::op!(glue_base_TYPENUM + glue_pars_TYPENUM);
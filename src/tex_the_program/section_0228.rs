@@ -1,8 +1,31 @@
 //! @ All glue parameters and registers are initially `\.{0pt plus0pt minus0pt}'.
-//!
-//! @<Initialize table entries...@>=
-//! equiv(glue_base):=zero_glue; eq_level(glue_base):=level_one;
-//! eq_type(glue_base):=glue_ref;
-//! for k:=glue_base+1 to local_base-1 do eqtb[k]:=eqtb[glue_base];
-//! glue_ref_count(zero_glue):=glue_ref_count(zero_glue)+local_base-glue_base;
-//!
+//
+// @<Initialize table entries...@>=
+pub(crate) macro Initialize_table_entries_done_by_initex_only_0228($globals:expr) {{
+    let globals = &mut *$globals;
+    // equiv(glue_base):=zero_glue; eq_level(glue_base):=level_one;
+    equiv!(globals, glue_base) = zero_glue;
+    eq_level!(globals, glue_base) = level_one;
+    // eq_type(glue_base):=glue_ref;
+    eq_type!(globals, glue_base) = glue_ref;
+    // for k:=glue_base+1 to local_base-1 do eqtb[k]:=eqtb[glue_base];
+    for k in glue_base + 1..=local_base - 1 {
+        globals.eqtb[k as pointer] = globals.eqtb[glue_base as pointer];
+    }
+    // glue_ref_count(zero_glue):=glue_ref_count(zero_glue)+local_base-glue_base;
+    glue_ref_count!(globals, zero_glue) =
+        glue_ref_count!(globals, zero_glue) + (local_base - glue_base) as halfword;
+}}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0113::halfword;
+use crate::section_0115::pointer;
+use crate::section_0150::glue_ref_count;
+use crate::section_0162::zero_glue;
+use crate::section_0210::glue_ref;
+use crate::section_0221::eq_level;
+use crate::section_0221::eq_type;
+use crate::section_0221::equiv;
+use crate::section_0221::level_one;
+use crate::section_0222::glue_base;
+use crate::section_0224::local_base;
@@ -35,9 +35,16 @@ pub(crate) const every_cr_loc: pointer = local_base as pointer + 8;
 // @d err_help_loc=local_base+9 {points to token list for \.{\\errhelp}}
 /// points to token list for `\errhelp`
 pub(crate) const err_help_loc: pointer = local_base as pointer + 9;
+// TeX-rs: `latex_support` puts `\everyeof` after `\errhelp`, as e-TeX does
+/// points to token list for `\everyeof`
+#[cfg(feature = "latex_support")]
+pub(crate) const every_eof_loc: pointer = local_base as pointer + 10;
 // @d toks_base=local_base+10 {table of 256 token list registers}
 /// table of 256 token list registers
+#[cfg(not(feature = "latex_support"))]
 pub(crate) type toks_base_TYPENUM = typenum::op!(local_base_TYPENUM + U10);
+#[cfg(feature = "latex_support")]
+pub(crate) type toks_base_TYPENUM = typenum::op!(local_base_TYPENUM + U11);
 pub(crate) const toks_base: word = toks_base_TYPENUM::U32;
 // @d box_base=toks_base+256 {table of 256 box registers}
 /// table of 256 box registers
@@ -115,11 +122,7 @@ pub(crate) macro every_cr($globals:expr) {
 }
 // @d err_help==equiv(err_help_loc)
 pub(crate) macro err_help($globals:expr) {
-    crate::section_0221::equiv!(
-        $globals,
-        crate::section_0230::box_base as crate::section_0115::pointer
-            + crate::section_0230::err_help_loc as crate::section_0115::pointer
-    )
+    crate::section_0221::equiv!($globals, crate::section_0230::err_help_loc)
 }
 // @d toks(#)==equiv(toks_base+#)
 // @d box(#)==equiv(box_base+#)
@@ -142,45 +145,47 @@ pub(crate) macro fam_fnt($globals:expr, $val:expr) {
     )
 }
 // @d cat_code(#)==equiv(cat_code_base+#)
-pub(crate) macro cat_code($globals:expr, $val:expr) {
-    crate::section_0221::equiv!(
+pub(crate) macro cat_code($globals:expr, $val:expr) {{
+    crate::section_0221::equiv_field!(crate::section_0230::code_table_word(
         $globals,
-        crate::section_0230::index_offset_with_ASCII_code(crate::section_0230::cat_code_base, $val)
-    )
-}
+        crate::section_0230::cat_code_base,
+        $val
+    ))
+}}
 // @d lc_code(#)==equiv(lc_code_base+#)
-pub(crate) macro lc_code($globals:expr, $val:expr) {
-    crate::section_0221::equiv!(
+pub(crate) macro lc_code($globals:expr, $val:expr) {{
+    crate::section_0221::equiv_field!(crate::section_0230::code_table_word(
         $globals,
-        crate::section_0230::index_offset_with_ASCII_code(crate::section_0230::lc_code_base, $val)
-    )
-}
+        crate::section_0230::lc_code_base,
+        $val
+    ))
+}}
 // @d uc_code(#)==equiv(uc_code_base+#)
-pub(crate) macro uc_code($globals:expr, $val:expr) {
-    crate::section_0221::equiv!(
+pub(crate) macro uc_code($globals:expr, $val:expr) {{
+    crate::section_0221::equiv_field!(crate::section_0230::code_table_word(
         $globals,
-        crate::section_0230::index_offset_with_ASCII_code(crate::section_0230::uc_code_base, $val)
-    )
-}
+        crate::section_0230::uc_code_base,
+        $val
+    ))
+}}
 // @d sf_code(#)==equiv(sf_code_base+#)
-pub(crate) macro sf_code($globals:expr, $val:expr) {
-    crate::section_0221::equiv!(
+pub(crate) macro sf_code($globals:expr, $val:expr) {{
+    crate::section_0221::equiv_field!(crate::section_0230::code_table_word(
         $globals,
-        crate::section_0230::index_offset_with_ASCII_code(crate::section_0230::sf_code_base, $val)
-    )
-}
+        crate::section_0230::sf_code_base,
+        $val
+    ))
+}}
 // @d math_code(#)==equiv(math_code_base+#)
 //   {Note: |math_code(c)| is the true math code plus |min_halfword|}
 /// Note: `math_code(c)` is the true math code plus `min_halfword`
-pub(crate) macro math_code($globals:expr, $val:expr) {
-    crate::section_0221::equiv!(
+pub(crate) macro math_code($globals:expr, $val:expr) {{
+    crate::section_0221::equiv_field!(crate::section_0230::code_table_word(
         $globals,
-        crate::section_0230::index_offset_with_ASCII_code(
-            crate::section_0230::math_code_base,
-            $val
-        )
-    )
-}
+        crate::section_0230::math_code_base,
+        $val
+    ))
+}}
 
 // @<Put each...@>=
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals:expr) {{
@@ -191,7 +196,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("output"),
         assign_toks,
         output_routine_loc as _,
-    );
+    )?;
     // @!@:output_}{\.{\\output} primitive@>
     // primitive("everypar",assign_toks,every_par_loc);
     primitive(
@@ -199,7 +204,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("everypar"),
         assign_toks,
         every_par_loc as _,
-    );
+    )?;
     // @!@:every_par_}{\.{\\everypar} primitive@>
     // primitive("everymath",assign_toks,every_math_loc);
     primitive(
@@ -207,7 +212,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("everymath"),
         assign_toks,
         every_math_loc as _,
-    );
+    )?;
     // @!@:every_math_}{\.{\\everymath} primitive@>
     // primitive("everydisplay",assign_toks,every_display_loc);
     primitive(
@@ -215,7 +220,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("everydisplay"),
         assign_toks,
         every_display_loc as _,
-    );
+    )?;
     // @!@:every_display_}{\.{\\everydisplay} primitive@>
     // primitive("everyhbox",assign_toks,every_hbox_loc);
     primitive(
@@ -223,7 +228,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("everyhbox"),
         assign_toks,
         every_hbox_loc as _,
-    );
+    )?;
     // @!@:every_hbox_}{\.{\\everyhbox} primitive@>
     // primitive("everyvbox",assign_toks,every_vbox_loc);
     primitive(
@@ -231,7 +236,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("everyvbox"),
         assign_toks,
         every_vbox_loc as _,
-    );
+    )?;
     // @!@:every_vbox_}{\.{\\everyvbox} primitive@>
     // primitive("everyjob",assign_toks,every_job_loc);
     primitive(
@@ -239,7 +244,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("everyjob"),
         assign_toks,
         every_job_loc as _,
-    );
+    )?;
     // @!@:every_job_}{\.{\\everyjob} primitive@>
     // primitive("everycr",assign_toks,every_cr_loc);
     primitive(
@@ -247,7 +252,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("everycr"),
         assign_toks,
         every_cr_loc as _,
-    );
+    )?;
     // @!@:every_cr_}{\.{\\everycr} primitive@>
     // primitive("errhelp",assign_toks,err_help_loc);
     primitive(
@@ -255,7 +260,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0230($globals
         crate::strpool_str!("errhelp"),
         assign_toks,
         err_help_loc as _,
-    );
+    )?;
     // @!@:err_help_}{\.{\\errhelp} primitive@>
 }}
 
@@ -268,22 +273,44 @@ use crate::section_0115::pointer;
 use crate::section_0224::local_base;
 use crate::section_0224::local_base_TYPENUM;
 
+/// The `eqtb` entry `offset` places after `initial`; with `unicode_support` the
+/// codes beyond 255 are kept in `unicode_code_table` instead, so `offset` must be
+/// below 256 here.
 #[allow(non_snake_case)]
 pub(crate) fn index_offset_with_ASCII_code(initial: word, offset: ASCII_code) -> word {
-    #[cfg(not(feature = "unicode_support"))]
-    {
-        initial + offset.0
-    }
+    initial + offset.0 as word
+}
+
+/// The `eqtb` word of a character code table entry, or its counterpart in
+/// `unicode_code_table` for characters beyond 255.
+pub(crate) fn code_table_word(
+    globals: &TeXGlobals,
+    initial: word,
+    offset: ASCII_code,
+) -> memory_word {
     #[cfg(feature = "unicode_support")]
     {
-        if offset.0 < 256 {
-            initial + offset.0
-        } else {
-            todo!("not yet implemented in {}", file!());
+        if offset.0 >= 256 {
+            return crate::unicode_support::unicode_code_word(globals, initial, offset.0);
         }
     }
+    globals.eqtb[index_offset_with_ASCII_code(initial, offset) as pointer]
+}
+
+/// The `equiv` field of a code table entry in `eqtb`, as assigned by \.{INITEX};
+/// codes beyond 255 are only assigned through `unicode_code_assign`.
+pub(crate) macro code_table_equiv($globals:expr, $initial:expr, $val:expr) {
+    crate::section_0221::equiv!(
+        $globals,
+        crate::section_0230::index_offset_with_ASCII_code($initial, $val)
+    )
 }
 
 use crate::section_0018::ASCII_code;
+use crate::section_0113::memory_word;
 use typenum::Unsigned;
-use typenum::{U1, U10, U256, U48};
+#[cfg(not(feature = "latex_support"))]
+use typenum::U10;
+#[cfg(feature = "latex_support")]
+use typenum::U11;
+use typenum::{U1, U256, U48};
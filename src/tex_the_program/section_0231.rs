@@ -49,6 +49,14 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0231
             }
             // othercases print_esc("errhelp")
             else {
+                // TeX-rs: `\everyeof` of `latex_support`
+                #[cfg(feature = "latex_support")]
+                if $chr_code.get() == crate::section_0230::every_eof_loc as chr_code_repr {
+                    print_esc($globals, crate::strpool_str!("everyeof"));
+                } else {
+                    print_esc($globals, crate::strpool_str!("errhelp"));
+                }
+                #[cfg(not(feature = "latex_support"))]
                 print_esc($globals, crate::strpool_str!("errhelp"));
             }
             // endcases;
@@ -25,7 +25,7 @@ pub(crate) macro Initialize_table_entries_done_by_initex_only_0232($globals:expr
     // eq_level(par_shape_loc):=level_one;@/
     eq_level!(globals, par_shape_loc) = level_one;
     // for k:=output_routine_loc to toks_base+255 do
-    for k in output_routine_loc..=toks_base as u16 + 255 {
+    for k in output_routine_loc..=toks_base as pointer + 255 {
         // eqtb[k]:=eqtb[undefined_control_sequence];
         globals.eqtb[k as u16] = globals.eqtb[undefined_control_sequence as u16];
     }
@@ -59,44 +59,61 @@ pub(crate) macro Initialize_table_entries_done_by_initex_only_0232($globals:expr
     for k_u8 in 0..=255u8 {
         let k = ASCII_code_literal!(k_u8);
         // begin cat_code(k):=other_char; math_code(k):=hi(k); sf_code(k):=1000;
-        cat_code!(globals, k) = other_char as _;
-        math_code!(globals, k) = hi!(k_u8 as halfword) as _;
-        sf_code!(globals, k) = 1000;
+        code_table_equiv!(globals, cat_code_base, k) = other_char as _;
+        code_table_equiv!(globals, math_code_base, k) = hi!(k_u8 as halfword) as _;
+        code_table_equiv!(globals, sf_code_base, k) = 1000;
         // end;
     }
     // cat_code(carriage_return):=car_ret; cat_code(" "):=spacer;
-    cat_code!(globals, ASCII_code_literal!(carriage_return)) = car_ret as _;
-    cat_code!(globals, ASCII_code_literal!(b' ')) = spacer as _;
+    code_table_equiv!(globals, cat_code_base, ASCII_code_literal!(carriage_return)) = car_ret as _;
+    code_table_equiv!(globals, cat_code_base, ASCII_code_literal!(b' ')) = spacer as _;
     // cat_code("\"):=escape; cat_code("%"):=comment;
-    cat_code!(globals, ASCII_code_literal!(b'\\')) = escape as _;
-    cat_code!(globals, ASCII_code_literal!(b'%')) = comment as _;
+    code_table_equiv!(globals, cat_code_base, ASCII_code_literal!(b'\\')) = escape as _;
+    code_table_equiv!(globals, cat_code_base, ASCII_code_literal!(b'%')) = comment as _;
     // cat_code(invalid_code):=invalid_char; cat_code(null_code):=ignore;
-    cat_code!(globals, ASCII_code_literal!(invalid_code)) = invalid_char as _;
-    cat_code!(globals, ASCII_code_literal!(null_code)) = ignore as _;
+    code_table_equiv!(globals, cat_code_base, ASCII_code_literal!(invalid_code)) =
+        invalid_char as _;
+    code_table_equiv!(globals, cat_code_base, ASCII_code_literal!(null_code)) = ignore as _;
     // for k:="0" to "9" do math_code(k):=hi(k+var_code);
     for k_u8 in b'0'..=b'9' {
-        math_code!(globals, ASCII_code_literal!(k_u8)) =
+        code_table_equiv!(globals, math_code_base, ASCII_code_literal!(k_u8)) =
             hi!((k_u8 as integer + var_code) as halfword);
     }
     // for k:="A" to "Z" do
     for k_u8 in b'A'..=b'Z' {
         // begin cat_code(k):=letter; cat_code(k+"a"-"A"):=letter;@/
-        cat_code!(globals, ASCII_code_literal!(k_u8)) = letter as _;
-        cat_code!(globals, ASCII_code_literal!(k_u8 + b'a' - b'A')) = letter as _;
+        code_table_equiv!(globals, cat_code_base, ASCII_code_literal!(k_u8)) = letter as _;
+        code_table_equiv!(
+            globals,
+            cat_code_base,
+            ASCII_code_literal!(k_u8 + b'a' - b'A')
+        ) = letter as _;
         // math_code(k):=hi(k+var_code+@"100);
-        math_code!(globals, ASCII_code_literal!(k_u8)) =
+        code_table_equiv!(globals, math_code_base, ASCII_code_literal!(k_u8)) =
             hi!((k_u8 as integer + var_code + 0x100) as halfword);
         // math_code(k+"a"-"A"):=hi(k+"a"-"A"+var_code+@"100);@/
-        math_code!(globals, ASCII_code_literal!(k_u8 + b'a' - b'A')) =
-            hi!(((k_u8 + b'a' - b'A') as integer + var_code + 0x100) as halfword);
+        code_table_equiv!(
+            globals,
+            math_code_base,
+            ASCII_code_literal!(k_u8 + b'a' - b'A')
+        ) = hi!(((k_u8 + b'a' - b'A') as integer + var_code + 0x100) as halfword);
         // lc_code(k):=k+"a"-"A"; lc_code(k+"a"-"A"):=k+"a"-"A";@/
-        lc_code!(globals, ASCII_code_literal!(k_u8)) = (k_u8 + b'a' - b'A') as _;
-        lc_code!(globals, ASCII_code_literal!(k_u8 + b'a' - b'A')) = (k_u8 + b'a' - b'A') as _;
+        code_table_equiv!(globals, lc_code_base, ASCII_code_literal!(k_u8)) =
+            (k_u8 + b'a' - b'A') as _;
+        code_table_equiv!(
+            globals,
+            lc_code_base,
+            ASCII_code_literal!(k_u8 + b'a' - b'A')
+        ) = (k_u8 + b'a' - b'A') as _;
         // uc_code(k):=k; uc_code(k+"a"-"A"):=k;@/
-        uc_code!(globals, ASCII_code_literal!(k_u8)) = (k_u8) as _;
-        uc_code!(globals, ASCII_code_literal!(k_u8 + b'a' - b'A')) = (k_u8) as _;
+        code_table_equiv!(globals, uc_code_base, ASCII_code_literal!(k_u8)) = (k_u8) as _;
+        code_table_equiv!(
+            globals,
+            uc_code_base,
+            ASCII_code_literal!(k_u8 + b'a' - b'A')
+        ) = (k_u8) as _;
         // sf_code(k):=999;
-        sf_code!(globals, ASCII_code_literal!(k_u8)) = 999;
+        code_table_equiv!(globals, sf_code_base, ASCII_code_literal!(k_u8)) = 999;
         // end;
     }
 }}
@@ -131,19 +148,19 @@ use crate::section_0221::equiv;
 use crate::section_0221::level_one;
 use crate::section_0222::undefined_control_sequence;
 use crate::section_0230::box_base;
-use crate::section_0230::cat_code;
 use crate::section_0230::cat_code_base;
+use crate::section_0230::code_table_equiv;
 use crate::section_0230::cur_font;
 use crate::section_0230::cur_font_loc;
 use crate::section_0230::int_base;
-use crate::section_0230::lc_code;
-use crate::section_0230::math_code;
+use crate::section_0230::lc_code_base;
+use crate::section_0230::math_code_base;
 use crate::section_0230::math_font_base;
 use crate::section_0230::output_routine_loc;
 use crate::section_0230::par_shape_loc;
 use crate::section_0230::par_shape_ptr;
 use crate::section_0230::r#box;
-use crate::section_0230::sf_code;
+use crate::section_0230::sf_code_base;
 use crate::section_0230::toks_base;
-use crate::section_0230::uc_code;
+use crate::section_0230::uc_code_base;
 use crate::section_0548::internal_font_number;
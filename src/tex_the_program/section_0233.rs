@@ -48,9 +48,22 @@ pub(crate) macro Show_equivalent_n__in_region_4($globals:expr, $n:expr) {{
     // else if n<box_base then
     else if ($n as integer) < box_base as integer {
         // begin print_esc("toks"); print_int(n-toks_base); print_char("=");
+        print_esc($globals, crate::strpool_str!("toks"));
+        print_int($globals, $n as integer - toks_base as integer);
+        print_char(
+            make_globals_io_string_log_view!($globals),
+            ASCII_code_literal!(b'='),
+        );
         // if equiv(n)<>null then show_token_list(link(equiv(n)),null,32);
+        if equiv!($globals, $n) != null {
+            show_token_list(
+                $globals,
+                link!($globals, equiv!($globals, $n)) as _,
+                null as _,
+                32,
+            );
+        }
         // end
-        todo!("toks");
     }
     // else if n<cur_font_loc then
     else if ($n as integer) < cur_font_loc as integer {
@@ -176,7 +176,7 @@ pub(crate) const error_context_lines_code: quarterword = 54;
 // @d int_pars=55 {total number of integer parameters}
 /// total number of integer parameters
 pub(crate) type int_pars_TYPENUM = typenum::U55;
-pub(crate) const int_pars: halfword = int_pars_TYPENUM::U16;
+pub(crate) const int_pars: halfword = int_pars_TYPENUM::U16 as halfword;
 // @d count_base=int_base+int_pars {256 user \.{\\count} registers}
 /// 256 user `\count` registers
 pub(crate) type count_base_TYPENUM = typenum::op!(int_base_TYPENUM + int_pars_TYPENUM);
@@ -191,12 +191,10 @@ pub(crate) type dimen_base_TYPENUM = typenum::op!(del_code_base_TYPENUM + U256);
 pub(crate) const dimen_base: word = dimen_base_TYPENUM::U32;
 // @#
 // @d del_code(#)==eqtb[del_code_base+#].int
-pub(crate) macro del_code($globals:expr, $val:expr) {
-    $globals.eqtb[crate::section_0230::index_offset_with_ASCII_code(
-        crate::section_0236::del_code_base,
-        $val,
-    ) as crate::section_0115::pointer][crate::section_0113::MEMORY_WORD_INT]
-}
+pub(crate) macro del_code($globals:expr, $val:expr) {{
+    crate::section_0230::code_table_word($globals, crate::section_0236::del_code_base, $val)
+        [crate::section_0113::MEMORY_WORD_INT]
+}}
 // @d count(#)==eqtb[count_base+#].int
 pub(crate) macro count($globals:expr, $val:expr) {
     $globals.eqtb[crate::section_0236::count_base as crate::section_0115::pointer
@@ -9,7 +9,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("pretolerance"),
         assign_int,
         (int_base + pretolerance_code as word) as _,
-    );
+    )?;
     // @!@:pretolerance_}{\.{\\pretolerance} primitive@>
     // primitive("tolerance",assign_int,int_base+tolerance_code);@/
     primitive(
@@ -17,7 +17,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tolerance"),
         assign_int,
         (int_base + tolerance_code as word) as _,
-    );
+    )?;
     // @!@:tolerance_}{\.{\\tolerance} primitive@>
     // primitive("linepenalty",assign_int,int_base+line_penalty_code);@/
     primitive(
@@ -25,7 +25,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("linepenalty"),
         assign_int,
         (int_base + line_penalty_code as word) as _,
-    );
+    )?;
     // @!@:line_penalty_}{\.{\\linepenalty} primitive@>
     // primitive("hyphenpenalty",assign_int,int_base+hyphen_penalty_code);@/
     primitive(
@@ -33,7 +33,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("hyphenpenalty"),
         assign_int,
         (int_base + hyphen_penalty_code as word) as _,
-    );
+    )?;
     // @!@:hyphen_penalty_}{\.{\\hyphenpenalty} primitive@>
     // primitive("exhyphenpenalty",assign_int,int_base+ex_hyphen_penalty_code);@/
     primitive(
@@ -41,7 +41,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("exhyphenpenalty"),
         assign_int,
         (int_base + ex_hyphen_penalty_code as word) as _,
-    );
+    )?;
     // @!@:ex_hyphen_penalty_}{\.{\\exhyphenpenalty} primitive@>
     // primitive("clubpenalty",assign_int,int_base+club_penalty_code);@/
     primitive(
@@ -49,7 +49,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("clubpenalty"),
         assign_int,
         (int_base + club_penalty_code as word) as _,
-    );
+    )?;
     // @!@:club_penalty_}{\.{\\clubpenalty} primitive@>
     // primitive("widowpenalty",assign_int,int_base+widow_penalty_code);@/
     primitive(
@@ -57,7 +57,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("widowpenalty"),
         assign_int,
         (int_base + widow_penalty_code as word) as _,
-    );
+    )?;
     // @!@:widow_penalty_}{\.{\\widowpenalty} primitive@>
     // primitive("displaywidowpenalty",
     //   assign_int,int_base+display_widow_penalty_code);@/
@@ -66,7 +66,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("displaywidowpenalty"),
         assign_int,
         (int_base + display_widow_penalty_code as word) as _,
-    );
+    )?;
     // @!@:display_widow_penalty_}{\.{\\displaywidowpenalty} primitive@>
     // primitive("brokenpenalty",assign_int,int_base+broken_penalty_code);@/
     primitive(
@@ -74,7 +74,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("brokenpenalty"),
         assign_int,
         (int_base + broken_penalty_code as word) as _,
-    );
+    )?;
     // @!@:broken_penalty_}{\.{\\brokenpenalty} primitive@>
     // primitive("binoppenalty",assign_int,int_base+bin_op_penalty_code);@/
     primitive(
@@ -82,7 +82,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("binoppenalty"),
         assign_int,
         (int_base + bin_op_penalty_code as word) as _,
-    );
+    )?;
     // @!@:bin_op_penalty_}{\.{\\binoppenalty} primitive@>
     // primitive("relpenalty",assign_int,int_base+rel_penalty_code);@/
     primitive(
@@ -90,7 +90,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("relpenalty"),
         assign_int,
         (int_base + rel_penalty_code as word) as _,
-    );
+    )?;
     // @!@:rel_penalty_}{\.{\\relpenalty} primitive@>
     // primitive("predisplaypenalty",assign_int,int_base+pre_display_penalty_code);@/
     primitive(
@@ -98,7 +98,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("predisplaypenalty"),
         assign_int,
         (int_base + pre_display_penalty_code as word) as _,
-    );
+    )?;
     // @!@:pre_display_penalty_}{\.{\\predisplaypenalty} primitive@>
     // primitive("postdisplaypenalty",assign_int,int_base+post_display_penalty_code);@/
     primitive(
@@ -106,7 +106,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("postdisplaypenalty"),
         assign_int,
         (int_base + post_display_penalty_code as word) as _,
-    );
+    )?;
     // @!@:post_display_penalty_}{\.{\\postdisplaypenalty} primitive@>
     // primitive("interlinepenalty",assign_int,int_base+inter_line_penalty_code);@/
     primitive(
@@ -114,7 +114,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("interlinepenalty"),
         assign_int,
         (int_base + inter_line_penalty_code as word) as _,
-    );
+    )?;
     // @!@:inter_line_penalty_}{\.{\\interlinepenalty} primitive@>
     // primitive("doublehyphendemerits",
     //   assign_int,int_base+double_hyphen_demerits_code);@/
@@ -123,7 +123,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("doublehyphendemerits"),
         assign_int,
         (int_base + double_hyphen_demerits_code as word) as _,
-    );
+    )?;
     // @!@:double_hyphen_demerits_}{\.{\\doublehyphendemerits} primitive@>
     // primitive("finalhyphendemerits",
     //   assign_int,int_base+final_hyphen_demerits_code);@/
@@ -132,7 +132,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("finalhyphendemerits"),
         assign_int,
         (int_base + final_hyphen_demerits_code as word) as _,
-    );
+    )?;
     // @!@:final_hyphen_demerits_}{\.{\\finalhyphendemerits} primitive@>
     // primitive("adjdemerits",assign_int,int_base+adj_demerits_code);@/
     primitive(
@@ -140,7 +140,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("adjdemerits"),
         assign_int,
         (int_base + adj_demerits_code as word) as _,
-    );
+    )?;
     // @!@:adj_demerits_}{\.{\\adjdemerits} primitive@>
     // primitive("mag",assign_int,int_base+mag_code);@/
     primitive(
@@ -148,7 +148,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("mag"),
         assign_int,
         (int_base + mag_code as word) as _,
-    );
+    )?;
     // @!@:mag_}{\.{\\mag} primitive@>
     // primitive("delimiterfactor",assign_int,int_base+delimiter_factor_code);@/
     primitive(
@@ -156,7 +156,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("delimiterfactor"),
         assign_int,
         (int_base + delimiter_factor_code as word) as _,
-    );
+    )?;
     // @!@:delimiter_factor_}{\.{\\delimiterfactor} primitive@>
     // primitive("looseness",assign_int,int_base+looseness_code);@/
     primitive(
@@ -164,7 +164,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("looseness"),
         assign_int,
         (int_base + looseness_code as word) as _,
-    );
+    )?;
     // @!@:looseness_}{\.{\\looseness} primitive@>
     // primitive("time",assign_int,int_base+time_code);@/
     primitive(
@@ -172,7 +172,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("time"),
         assign_int,
         (int_base + time_code as word) as _,
-    );
+    )?;
     // @!@:time_}{\.{\\time} primitive@>
     // primitive("day",assign_int,int_base+day_code);@/
     primitive(
@@ -180,7 +180,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("day"),
         assign_int,
         (int_base + day_code as word) as _,
-    );
+    )?;
     // @!@:day_}{\.{\\day} primitive@>
     // primitive("month",assign_int,int_base+month_code);@/
     primitive(
@@ -188,7 +188,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("month"),
         assign_int,
         (int_base + month_code as word) as _,
-    );
+    )?;
     // @!@:month_}{\.{\\month} primitive@>
     // primitive("year",assign_int,int_base+year_code);@/
     primitive(
@@ -196,7 +196,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("year"),
         assign_int,
         (int_base + year_code as word) as _,
-    );
+    )?;
     // @!@:year_}{\.{\\year} primitive@>
     // primitive("showboxbreadth",assign_int,int_base+show_box_breadth_code);@/
     primitive(
@@ -204,7 +204,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("showboxbreadth"),
         assign_int,
         (int_base + show_box_breadth_code as word) as _,
-    );
+    )?;
     // @!@:show_box_breadth_}{\.{\\showboxbreadth} primitive@>
     // primitive("showboxdepth",assign_int,int_base+show_box_depth_code);@/
     primitive(
@@ -212,7 +212,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("showboxdepth"),
         assign_int,
         (int_base + show_box_depth_code as word) as _,
-    );
+    )?;
     // @!@:show_box_depth_}{\.{\\showboxdepth} primitive@>
     // primitive("hbadness",assign_int,int_base+hbadness_code);@/
     primitive(
@@ -220,7 +220,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("hbadness"),
         assign_int,
         (int_base + hbadness_code as word) as _,
-    );
+    )?;
     // @!@:hbadness_}{\.{\\hbadness} primitive@>
     // primitive("vbadness",assign_int,int_base+vbadness_code);@/
     primitive(
@@ -228,7 +228,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("vbadness"),
         assign_int,
         (int_base + vbadness_code as word) as _,
-    );
+    )?;
     // @!@:vbadness_}{\.{\\vbadness} primitive@>
     // primitive("pausing",assign_int,int_base+pausing_code);@/
     primitive(
@@ -236,7 +236,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("pausing"),
         assign_int,
         (int_base + pausing_code as word) as _,
-    );
+    )?;
     // @!@:pausing_}{\.{\\pausing} primitive@>
     // primitive("tracingonline",assign_int,int_base+tracing_online_code);@/
     primitive(
@@ -244,7 +244,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracingonline"),
         assign_int,
         (int_base + tracing_online_code as word) as _,
-    );
+    )?;
     // @!@:tracing_online_}{\.{\\tracingonline} primitive@>
     // primitive("tracingmacros",assign_int,int_base+tracing_macros_code);@/
     primitive(
@@ -252,7 +252,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracingmacros"),
         assign_int,
         (int_base + tracing_macros_code as word) as _,
-    );
+    )?;
     // @!@:tracing_macros_}{\.{\\tracingmacros} primitive@>
     // primitive("tracingstats",assign_int,int_base+tracing_stats_code);@/
     primitive(
@@ -260,7 +260,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracingstats"),
         assign_int,
         (int_base + tracing_stats_code as word) as _,
-    );
+    )?;
     // @!@:tracing_stats_}{\.{\\tracingstats} primitive@>
     // primitive("tracingparagraphs",assign_int,int_base+tracing_paragraphs_code);@/
     primitive(
@@ -268,7 +268,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracingparagraphs"),
         assign_int,
         (int_base + tracing_paragraphs_code as word) as _,
-    );
+    )?;
     // @!@:tracing_paragraphs_}{\.{\\tracingparagraphs} primitive@>
     // primitive("tracingpages",assign_int,int_base+tracing_pages_code);@/
     primitive(
@@ -276,7 +276,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracingpages"),
         assign_int,
         (int_base + tracing_pages_code as word) as _,
-    );
+    )?;
     // @!@:tracing_pages_}{\.{\\tracingpages} primitive@>
     // primitive("tracingoutput",assign_int,int_base+tracing_output_code);@/
     primitive(
@@ -284,7 +284,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracingoutput"),
         assign_int,
         (int_base + tracing_output_code as word) as _,
-    );
+    )?;
     // @!@:tracing_output_}{\.{\\tracingoutput} primitive@>
     // primitive("tracinglostchars",assign_int,int_base+tracing_lost_chars_code);@/
     primitive(
@@ -292,7 +292,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracinglostchars"),
         assign_int,
         (int_base + tracing_lost_chars_code as word) as _,
-    );
+    )?;
     // @!@:tracing_lost_chars_}{\.{\\tracinglostchars} primitive@>
     // primitive("tracingcommands",assign_int,int_base+tracing_commands_code);@/
     primitive(
@@ -300,7 +300,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracingcommands"),
         assign_int,
         (int_base + tracing_commands_code as word) as _,
-    );
+    )?;
     // @!@:tracing_commands_}{\.{\\tracingcommands} primitive@>
     // primitive("tracingrestores",assign_int,int_base+tracing_restores_code);@/
     primitive(
@@ -308,7 +308,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("tracingrestores"),
         assign_int,
         (int_base + tracing_restores_code as word) as _,
-    );
+    )?;
     // @!@:tracing_restores_}{\.{\\tracingrestores} primitive@>
     // primitive("uchyph",assign_int,int_base+uc_hyph_code);@/
     primitive(
@@ -316,7 +316,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("uchyph"),
         assign_int,
         (int_base + uc_hyph_code as word) as _,
-    );
+    )?;
     // @!@:uc_hyph_}{\.{\\uchyph} primitive@>
     // primitive("outputpenalty",assign_int,int_base+output_penalty_code);@/
     primitive(
@@ -324,7 +324,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("outputpenalty"),
         assign_int,
         (int_base + output_penalty_code as word) as _,
-    );
+    )?;
     // @!@:output_penalty_}{\.{\\outputpenalty} primitive@>
     // primitive("maxdeadcycles",assign_int,int_base+max_dead_cycles_code);@/
     primitive(
@@ -332,7 +332,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("maxdeadcycles"),
         assign_int,
         (int_base + max_dead_cycles_code as word) as _,
-    );
+    )?;
     // @!@:max_dead_cycles_}{\.{\\maxdeadcycles} primitive@>
     // primitive("hangafter",assign_int,int_base+hang_after_code);@/
     primitive(
@@ -340,7 +340,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("hangafter"),
         assign_int,
         (int_base + hang_after_code as word) as _,
-    );
+    )?;
     // @!@:hang_after_}{\.{\\hangafter} primitive@>
     // primitive("floatingpenalty",assign_int,int_base+floating_penalty_code);@/
     primitive(
@@ -348,7 +348,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("floatingpenalty"),
         assign_int,
         (int_base + floating_penalty_code as word) as _,
-    );
+    )?;
     // @!@:floating_penalty_}{\.{\\floatingpenalty} primitive@>
     // primitive("globaldefs",assign_int,int_base+global_defs_code);@/
     primitive(
@@ -356,7 +356,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("globaldefs"),
         assign_int,
         (int_base + global_defs_code as word) as _,
-    );
+    )?;
     // @!@:global_defs_}{\.{\\globaldefs} primitive@>
     // primitive("fam",assign_int,int_base+cur_fam_code);@/
     primitive(
@@ -364,7 +364,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("fam"),
         assign_int,
         (int_base + cur_fam_code as word) as _,
-    );
+    )?;
     // @!@:fam_}{\.{\\fam} primitive@>
     // primitive("escapechar",assign_int,int_base+escape_char_code);@/
     primitive(
@@ -372,7 +372,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("escapechar"),
         assign_int,
         (int_base + escape_char_code as word) as _,
-    );
+    )?;
     // @!@:escape_char_}{\.{\\escapechar} primitive@>
     // primitive("defaulthyphenchar",assign_int,int_base+default_hyphen_char_code);@/
     primitive(
@@ -380,7 +380,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("defaulthyphenchar"),
         assign_int,
         (int_base + default_hyphen_char_code as word) as _,
-    );
+    )?;
     // @!@:default_hyphen_char_}{\.{\\defaulthyphenchar} primitive@>
     // primitive("defaultskewchar",assign_int,int_base+default_skew_char_code);@/
     primitive(
@@ -388,7 +388,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("defaultskewchar"),
         assign_int,
         (int_base + default_skew_char_code as word) as _,
-    );
+    )?;
     // @!@:default_skew_char_}{\.{\\defaultskewchar} primitive@>
     // primitive("endlinechar",assign_int,int_base+end_line_char_code);@/
     primitive(
@@ -396,7 +396,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("endlinechar"),
         assign_int,
         (int_base + end_line_char_code as word) as _,
-    );
+    )?;
     // @!@:end_line_char_}{\.{\\endlinechar} primitive@>
     // primitive("newlinechar",assign_int,int_base+new_line_char_code);@/
     primitive(
@@ -404,7 +404,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("newlinechar"),
         assign_int,
         (int_base + new_line_char_code as word) as _,
-    );
+    )?;
     // @!@:new_line_char_}{\.{\\newlinechar} primitive@>
     // primitive("language",assign_int,int_base+language_code);@/
     primitive(
@@ -412,7 +412,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("language"),
         assign_int,
         (int_base + language_code as word) as _,
-    );
+    )?;
     // @!@:language_}{\.{\\language} primitive@>
     // primitive("lefthyphenmin",assign_int,int_base+left_hyphen_min_code);@/
     primitive(
@@ -420,7 +420,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("lefthyphenmin"),
         assign_int,
         (int_base + left_hyphen_min_code as word) as _,
-    );
+    )?;
     // @!@:left_hyphen_min_}{\.{\\lefthyphenmin} primitive@>
     // primitive("righthyphenmin",assign_int,int_base+right_hyphen_min_code);@/
     primitive(
@@ -428,7 +428,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("righthyphenmin"),
         assign_int,
         (int_base + right_hyphen_min_code as word) as _,
-    );
+    )?;
     // @!@:right_hyphen_min_}{\.{\\righthyphenmin} primitive@>
     // primitive("holdinginserts",assign_int,int_base+holding_inserts_code);@/
     primitive(
@@ -436,7 +436,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("holdinginserts"),
         assign_int,
         (int_base + holding_inserts_code as word) as _,
-    );
+    )?;
     // @!@:holding_inserts_}{\.{\\holdinginserts} primitive@>
     // primitive("errorcontextlines",assign_int,int_base+error_context_lines_code);@/
     primitive(
@@ -444,7 +444,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0238($globals
         crate::strpool_str!("errorcontextlines"),
         assign_int,
         (int_base + error_context_lines_code as word) as _,
-    );
+    )?;
     // @!@:error_context_lines_}{\.{\\errorcontextlines} primitive@>
 }}
 
@@ -16,11 +16,21 @@ pub(crate) macro Initialize_table_entries_done_by_initex_only_0240($globals:expr
     escape_char!(globals) = b'\\'.into();
     end_line_char!(globals) = carriage_return.into();
     // for k:=0 to 255 do del_code(k):=-1;
+    for k in 0..=255 {
+        globals.eqtb[(del_code_base + k) as pointer][MEMORY_WORD_INT] = -1;
+    }
     // del_code("."):=0; {this null delimiter is used in error recovery}
+    /// this null delimiter is used in error recovery
+    const _: () = ();
+    globals.eqtb[(del_code_base + b'.' as word) as pointer][MEMORY_WORD_INT] = 0;
 }}
 
+use crate::pascal::word;
 use crate::section_0004::TeXGlobals;
 use crate::section_0022::carriage_return;
+use crate::section_0113::MEMORY_WORD_INT;
+use crate::section_0115::pointer;
+use crate::section_0236::del_code_base;
 use crate::section_0236::end_line_char;
 use crate::section_0236::escape_char;
 use crate::section_0236::hang_after;
@@ -11,15 +11,14 @@
 #[allow(unused_variables)]
 pub(crate) fn fix_date_and_time(globals: &mut TeXGlobals) {
     // begin sys_time:=12*60;
-    {
-        globals.sys_time = 12 * 60;
-    }
     // sys_day:=4; sys_month:=7; sys_year:=1776;  {self-evident truths}
-    /// self-evident truths
+    // TeX-rs: ask the configured clock instead of assuming self-evident truths.
     {
-        globals.sys_day = 4;
-        globals.sys_month = 7;
-        globals.sys_year = 1776;
+        let now = current_date_and_time(globals.clock);
+        globals.sys_time = now.hour as integer * 60 + now.minute as integer;
+        globals.sys_day = now.day as integer;
+        globals.sys_month = now.month as integer;
+        globals.sys_year = now.year as integer;
     }
     // time:=sys_time; {minutes since midnight}
     /// minutes since midnight
@@ -27,12 +26,12 @@ pub(crate) fn fix_date_and_time(globals: &mut TeXGlobals) {
         time!(globals) = globals.sys_time;
     }
     // day:=sys_day; {day of the month}
-    /// fourth day of the month
+    /// day of the month
     {
         day!(globals) = globals.sys_day;
     }
     // month:=sys_month; {month of the year}
-    /// seventh month of the year
+    /// month of the year
     {
         month!(globals) = globals.sys_month;
     }
@@ -44,8 +43,10 @@ pub(crate) fn fix_date_and_time(globals: &mut TeXGlobals) {
     // end;
 }
 
+use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
 use crate::section_0236::day;
 use crate::section_0236::month;
 use crate::section_0236::time;
 use crate::section_0236::year;
+use crate::time_support::current_date_and_time;
@@ -67,7 +67,7 @@ pub(crate) const emergency_stretch_code: quarterword = 20;
 // @d dimen_pars=21 {total number of dimension parameters}
 /// total number of dimension parameters
 pub(crate) type dimen_pars_TYPENUM = U21;
-pub(crate) const dimen_pars: halfword = dimen_pars_TYPENUM::U16;
+pub(crate) const dimen_pars: halfword = dimen_pars_TYPENUM::U16 as halfword;
 // @d scaled_base=dimen_base+dimen_pars
 //   {table of 256 user-defined \.{\\dimen} registers}
 /// table of 256 user-defined `\dimen` registers
@@ -10,7 +10,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("parindent"),
         assign_dimen,
         (dimen_base + par_indent_code as word) as _,
-    );
+    )?;
     // @!@:par_indent_}{\.{\\parindent} primitive@>
     // primitive("mathsurround",assign_dimen,dimen_base+math_surround_code);@/
     primitive(
@@ -18,7 +18,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("mathsurround"),
         assign_dimen,
         (dimen_base + math_surround_code as word) as _,
-    );
+    )?;
     // @!@:math_surround_}{\.{\\mathsurround} primitive@>
     // primitive("lineskiplimit",assign_dimen,dimen_base+line_skip_limit_code);@/
     primitive(
@@ -26,7 +26,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("lineskiplimit"),
         assign_dimen,
         (dimen_base + line_skip_limit_code as word) as _,
-    );
+    )?;
     // @!@:line_skip_limit_}{\.{\\lineskiplimit} primitive@>
     // primitive("hsize",assign_dimen,dimen_base+hsize_code);@/
     primitive(
@@ -34,7 +34,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("hsize"),
         assign_dimen,
         (dimen_base + hsize_code as word) as _,
-    );
+    )?;
     // @!@:hsize_}{\.{\\hsize} primitive@>
     // primitive("vsize",assign_dimen,dimen_base+vsize_code);@/
     primitive(
@@ -42,7 +42,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("vsize"),
         assign_dimen,
         (dimen_base + vsize_code as word) as _,
-    );
+    )?;
     // @!@:vsize_}{\.{\\vsize} primitive@>
     // primitive("maxdepth",assign_dimen,dimen_base+max_depth_code);@/
     primitive(
@@ -50,7 +50,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("maxdepth"),
         assign_dimen,
         (dimen_base + max_depth_code as word) as _,
-    );
+    )?;
     // @!@:max_depth_}{\.{\\maxdepth} primitive@>
     // primitive("splitmaxdepth",assign_dimen,dimen_base+split_max_depth_code);@/
     primitive(
@@ -58,7 +58,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("splitmaxdepth"),
         assign_dimen,
         (dimen_base + split_max_depth_code as word) as _,
-    );
+    )?;
     // @!@:split_max_depth_}{\.{\\splitmaxdepth} primitive@>
     // primitive("boxmaxdepth",assign_dimen,dimen_base+box_max_depth_code);@/
     primitive(
@@ -66,7 +66,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("boxmaxdepth"),
         assign_dimen,
         (dimen_base + box_max_depth_code as word) as _,
-    );
+    )?;
     // @!@:box_max_depth_}{\.{\\boxmaxdepth} primitive@>
     // primitive("hfuzz",assign_dimen,dimen_base+hfuzz_code);@/
     primitive(
@@ -74,7 +74,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("hfuzz"),
         assign_dimen,
         (dimen_base + hfuzz_code as word) as _,
-    );
+    )?;
     // @!@:hfuzz_}{\.{\\hfuzz} primitive@>
     // primitive("vfuzz",assign_dimen,dimen_base+vfuzz_code);@/
     primitive(
@@ -82,7 +82,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("vfuzz"),
         assign_dimen,
         (dimen_base + vfuzz_code as word) as _,
-    );
+    )?;
     // @!@:vfuzz_}{\.{\\vfuzz} primitive@>
     // primitive("delimitershortfall",
     //   assign_dimen,dimen_base+delimiter_shortfall_code);@/
@@ -91,7 +91,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("delimitershortfall"),
         assign_dimen,
         (dimen_base + delimiter_shortfall_code as word) as _,
-    );
+    )?;
     // @!@:delimiter_shortfall_}{\.{\\delimitershortfall} primitive@>
     // primitive("nulldelimiterspace",
     //   assign_dimen,dimen_base+null_delimiter_space_code);@/
@@ -100,7 +100,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("nulldelimiterspace"),
         assign_dimen,
         (dimen_base + null_delimiter_space_code as word) as _,
-    );
+    )?;
     // @!@:null_delimiter_space_}{\.{\\nulldelimiterspace} primitive@>
     // primitive("scriptspace",assign_dimen,dimen_base+script_space_code);@/
     primitive(
@@ -108,7 +108,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("scriptspace"),
         assign_dimen,
         (dimen_base + script_space_code as word) as _,
-    );
+    )?;
     // @!@:script_space_}{\.{\\scriptspace} primitive@>
     // primitive("predisplaysize",assign_dimen,dimen_base+pre_display_size_code);@/
     primitive(
@@ -116,7 +116,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("predisplaysize"),
         assign_dimen,
         (dimen_base + pre_display_size_code as word) as _,
-    );
+    )?;
     // @!@:pre_display_size_}{\.{\\predisplaysize} primitive@>
     // primitive("displaywidth",assign_dimen,dimen_base+display_width_code);@/
     primitive(
@@ -124,7 +124,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("displaywidth"),
         assign_dimen,
         (dimen_base + display_width_code as word) as _,
-    );
+    )?;
     // @!@:display_width_}{\.{\\displaywidth} primitive@>
     // primitive("displayindent",assign_dimen,dimen_base+display_indent_code);@/
     primitive(
@@ -132,7 +132,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("displayindent"),
         assign_dimen,
         (dimen_base + display_indent_code as word) as _,
-    );
+    )?;
     // @!@:display_indent_}{\.{\\displayindent} primitive@>
     // primitive("overfullrule",assign_dimen,dimen_base+overfull_rule_code);@/
     primitive(
@@ -140,7 +140,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("overfullrule"),
         assign_dimen,
         (dimen_base + overfull_rule_code as word) as _,
-    );
+    )?;
     // @!@:overfull_rule_}{\.{\\overfullrule} primitive@>
     // primitive("hangindent",assign_dimen,dimen_base+hang_indent_code);@/
     primitive(
@@ -148,7 +148,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("hangindent"),
         assign_dimen,
         (dimen_base + hang_indent_code as word) as _,
-    );
+    )?;
     // @!@:hang_indent_}{\.{\\hangindent} primitive@>
     // primitive("hoffset",assign_dimen,dimen_base+h_offset_code);@/
     primitive(
@@ -156,7 +156,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("hoffset"),
         assign_dimen,
         (dimen_base + h_offset_code as word) as _,
-    );
+    )?;
     // @!@:h_offset_}{\.{\\hoffset} primitive@>
     // primitive("voffset",assign_dimen,dimen_base+v_offset_code);@/
     primitive(
@@ -164,7 +164,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("voffset"),
         assign_dimen,
         (dimen_base + v_offset_code as word) as _,
-    );
+    )?;
     // @!@:v_offset_}{\.{\\voffset} primitive@>
     // primitive("emergencystretch",assign_dimen,dimen_base+emergency_stretch_code);@/
     primitive(
@@ -172,7 +172,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0248($globals
         crate::strpool_str!("emergencystretch"),
         assign_dimen,
         (dimen_base + emergency_stretch_code as word) as _,
-    );
+    )?;
     // @!@:emergency_stretch_}{\.{\\emergencystretch} primitive@>
 }}
 
@@ -1,3 +1,15 @@
 //! @ @<Initialize table entries...@>=
-//! for k:=dimen_base to eqtb_size do eqtb[k].sc:=0;
-//!
+// for k:=dimen_base to eqtb_size do eqtb[k].sc:=0;
+pub(crate) macro Initialize_table_entries_done_by_initex_only_0250($globals:expr) {{
+    let globals = &mut *$globals;
+    for k in dimen_base..=eqtb_size {
+        globals.eqtb[k as pointer][MEMORY_WORD_SC] = scaled::zero();
+    }
+}}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0101::scaled;
+use crate::section_0101::MEMORY_WORD_SC;
+use crate::section_0115::pointer;
+use crate::section_0236::dimen_base;
+use crate::section_0247::eqtb_size;
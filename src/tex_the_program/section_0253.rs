@@ -39,6 +39,11 @@ define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length!(
     u16; U16; int_base_TYPENUM; xeq_level_range_array_LENGTH_TYPENUM
 );
 
+#[cfg(feature = "wide_pointers")]
+impl_index_with_wide_pointer!(eqtb_range_array, active_base_TYPENUM);
+#[cfg(feature = "wide_pointers")]
+impl_index_with_wide_pointer!(xeq_level_range_array, int_base_TYPENUM);
+
 use crate::pascal::u16_from_m_to_n;
 use crate::section_0222::active_base_TYPENUM;
 use crate::section_0230::int_base_TYPENUM;
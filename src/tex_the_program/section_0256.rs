@@ -65,6 +65,9 @@ define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length!(
     u16; U16; hash_base_TYPENUM; hash_array_LENGTH_TYPENUM
 );
 
+#[cfg(feature = "wide_pointers")]
+impl_index_with_wide_pointer!(hash_array, hash_base_TYPENUM);
+
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0256::hash_array;
 
@@ -10,9 +10,10 @@
 /// search the hash table
 #[allow(unused_variables, unreachable_code, unused_mut, unused_assignments)]
 #[cfg_attr(feature = "trace_verbose", tracing::instrument(level = "trace"))]
-pub(crate) fn id_lookup(globals: &mut TeXGlobals, j: integer, l: integer) -> pointer {
-    debug_assert!(l > 1);
-    crate::trace_expr_verbose!("id_lookup(j = {}, l = {})", j, l);
+pub(crate) fn id_lookup(globals: &mut TeXGlobals, j: integer, l: integer) -> TeXResult<pointer> {
+    // single characters beyond 255 are also kept in the hash table
+    debug_assert!(l > 1 || (cfg!(feature = "unicode_support") && l == 1));
+    crate::trace_expr_verbose!("id_lookup(j = {}, l = {})?", j, l);
     // label found; {go here if you found it}
     // var h:integer; {hash code}
     /// hash code
@@ -77,7 +78,7 @@ pub(crate) fn id_lookup(globals: &mut TeXGlobals, j: integer, l: integer) -> poi
     'found <-
     );
     crate::trace_expr_verbose!("final_p = {}", p);
-    return p;
+    crate::ok_nojump!(p)
     // end;
 }
 
@@ -86,6 +87,7 @@ use crate::section_0004::TeXGlobals;
 use crate::section_0038::str_number;
 use crate::section_0040::length;
 use crate::section_0045::str_eq_buf;
+use crate::section_0081::TeXResult;
 use crate::section_0115::pointer;
 use crate::section_0222::hash_base;
 use crate::section_0222::undefined_control_sequence;
@@ -6,7 +6,7 @@ pub(crate) macro Insert_a_new_control_sequence_after_p_then_make_p_point_to_it($
         // begin repeat if hash_is_full then overflow("hash size",hash_size);
         loop {
             if hash_is_full!($globals) {
-                todo!("not yet implemented in {}", file!());
+                crate::unported::unported!($globals, 260);
             }
             // @:TeX capacity exceeded hash size}{\quad hash size@>
             // decr(hash_used);
@@ -26,7 +26,7 @@ pub(crate) macro Insert_a_new_control_sequence_after_p_then_make_p_point_to_it($
     let l = $l_raw;
     #[cfg(feature = "unicode_support")]
     let l = buffer_range_bytes($globals, $j, $l_raw);
-    str_room($globals, l);
+    str_room($globals, l)?;
     let d = cur_length!($globals);
     // str_room(l); d:=cur_length;
     // while pool_ptr>str_start[str_ptr] do
@@ -38,7 +38,7 @@ pub(crate) macro Insert_a_new_control_sequence_after_p_then_make_p_point_to_it($
         // end; {move current string up to make room for another}
     }
     // for k:=j to j+l-1 do append_char(buffer[k]);
-    for k in $j..=$j + l - 1 {
+    for k in $j..=$j + $l_raw - 1 {
         append_char(
             make_globals_string_view!($globals),
             $globals.buffer[k as u16],
@@ -67,7 +67,15 @@ pub(crate) fn print_cs(globals: &mut TeXGlobals, p: integer) {
     // @.NONEXISTENT@>
     // else  begin print_esc(text(p));
     else {
-        print_esc(globals, str_number::new(text!(globals, p as pointer) as _));
+        let s = str_number::new(text!(globals, p as pointer) as _);
+        print_esc(globals, s);
+        #[cfg(feature = "unicode_support")]
+        if let Some(c) = crate::unicode_support::single_char_of_str(globals, s) {
+            // a single nonletter beyond 255 gets no space, like `single_base` ones
+            if cat_code!(globals, c) != letter as halfword {
+                return;
+            }
+        }
         // print_char(" ");
         print_char(
             make_globals_io_string_log_view!(globals),
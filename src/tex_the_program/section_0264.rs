@@ -11,7 +11,12 @@
     feature = "trace_verbose",
     tracing::instrument(level = "trace", skip(globals))
 )]
-pub(crate) fn primitive(globals: &mut TeXGlobals, s: str_number, c: quarterword, o: halfword) {
+pub(crate) fn primitive(
+    globals: &mut TeXGlobals,
+    s: str_number,
+    c: quarterword,
+    o: halfword,
+) -> TeXResult<()> {
     // var k:pool_pointer; {index into |str_pool|}
     // @!j:small_number; {index into |buffer|}
     // @!l:small_number; {length of the string}
@@ -37,7 +42,7 @@ pub(crate) fn primitive(globals: &mut TeXGlobals, s: str_number, c: quarterword,
         }
         #[cfg(feature = "unicode_support")]
         {
-            let mut len = 0;
+            let mut len: u16 = 0;
             for ch in globals.str_pool.str_ascii_codes(&globals.str_start, s) {
                 globals.buffer[len] = xord(ch);
                 len = len + 1;
@@ -47,7 +52,7 @@ pub(crate) fn primitive(globals: &mut TeXGlobals, s: str_number, c: quarterword,
         // cur_val:=id_lookup(0,l); {|no_new_control_sequence| is |false|}
         /// `no_new_control_sequence` is `false`
         {
-            globals.cur_val = id_lookup(globals, 0, l) as _;
+            globals.cur_val = id_lookup(globals, 0, l)? as _;
         }
         // flush_string; text(cur_val):=s; {we don't want to have the string twice}
         /// we don't want to have the string twice
@@ -63,6 +68,7 @@ pub(crate) fn primitive(globals: &mut TeXGlobals, s: str_number, c: quarterword,
     eq_level!(globals, globals.cur_val as u32) = level_one;
     eq_type!(globals, globals.cur_val as u32) = c;
     equiv!(globals, globals.cur_val as u32) = o;
+    crate::ok_nojump!()
     // end;
     // tini
 }
@@ -73,6 +79,7 @@ use crate::section_0004::TeXGlobals;
 use crate::section_0020::xord;
 use crate::section_0038::str_number;
 use crate::section_0044::flush_string;
+use crate::section_0081::TeXResult;
 use crate::section_0113::halfword;
 use crate::section_0113::quarterword;
 use crate::section_0115::pointer;
@@ -86,6 +86,14 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0266
     }
     // expand_after: print_esc("expandafter");
     else if $cmd == expand_after {
+        // TeX-rs: `\unless` of `latex_support` shares this command code
+        #[cfg(feature = "latex_support")]
+        if $chr_code.get() != 0 {
+            print_esc($globals, crate::strpool_str!("unless"));
+        } else {
+            print_esc($globals, crate::strpool_str!("expandafter"));
+        }
+        #[cfg(not(feature = "latex_support"))]
         print_esc($globals, crate::strpool_str!("expandafter"));
         true
     }
@@ -171,6 +179,14 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0266
     }
     // read_to_cs: print_esc("read");
     else if $cmd == read_to_cs {
+        // TeX-rs: `\readline` of `latex_support` shares this command code
+        #[cfg(feature = "latex_support")]
+        if $chr_code.get() == crate::latex_support::readline_code {
+            print_esc($globals, crate::strpool_str!("readline"));
+        } else {
+            print_esc($globals, crate::strpool_str!("read"));
+        }
+        #[cfg(not(feature = "latex_support"))]
         print_esc($globals, crate::strpool_str!("read"));
         true
     }
@@ -196,12 +212,26 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0266
     }
     // the: print_esc("the");
     else if $cmd == the {
-        print_esc($globals, crate::strpool_str!("the"));
+        // TeX-rs: `\unexpanded` and `\detokenize` of `latex_support` share this command code
+        #[cfg(feature = "latex_support")]
+        let processed = crate::latex_support::print_the($globals, $chr_code.get());
+        #[cfg(not(feature = "latex_support"))]
+        let processed = false;
+        if !processed {
+            print_esc($globals, crate::strpool_str!("the"));
+        }
         true
     }
     // toks_register: print_esc("toks");
     else if $cmd == toks_register {
-        print_esc($globals, crate::strpool_str!("toks"));
+        // TeX-rs: `\toks` registers above 255 defined with `\toksdef`
+        #[cfg(feature = "latex_support")]
+        let processed = crate::latex_support::print_sa_register($globals, $chr_code.get());
+        #[cfg(not(feature = "latex_support"))]
+        let processed = false;
+        if !processed {
+            print_esc($globals, crate::strpool_str!("toks"));
+        }
         true
     }
     // vadjust: print_esc("vadjust");
@@ -5,10 +5,8 @@
 #[globals_struct_field(TeXGlobals)]
 pub(crate) static save_stack: save_stack_array<memory_word> = save_stack_array::default();
 
-type save_stack_array_LENGTH_TYPENUM = typenum::op!(save_size_TYPENUM + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_from_0_with_fixed_length!(
-    pub(crate) save_stack_array[u16_from_0_to_n<save_size_TYPENUM>] => u16; U16; save_stack_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) save_stack_array[u16_from_0_to_n<save_size_TYPENUM>] => u16; U16; U0
 );
 
 #[globals_struct_use(TeXGlobals)]
@@ -46,4 +44,4 @@ use crate::pascal::u16_from_0_to_n;
 use crate::section_0004::TeXGlobals;
 use crate::section_0011::save_size_TYPENUM;
 use globals_struct::{globals_struct_field, globals_struct_use};
-use typenum::U1;
+use typenum::U0;
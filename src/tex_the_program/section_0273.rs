@@ -4,13 +4,18 @@
 //
 // @d check_full_save_stack==if save_ptr>max_save_stack then
 pub(crate) macro check_full_save_stack($globals:expr) {{
-    // begin max_save_stack:=save_ptr;
-    $globals.max_save_stack = $globals.save_ptr;
-    // if max_save_stack>save_size-6 then overflow("save size",save_size);
-    if $globals.max_save_stack > save_size - 6 {
-        todo!("not yet implemented in {}", file!());
+    if $globals.save_ptr > $globals.max_save_stack {
+        // begin max_save_stack:=save_ptr;
+        $globals.max_save_stack = $globals.save_ptr;
+        // if max_save_stack>save_size-6 then overflow("save size",save_size);
+        if $globals.max_save_stack > $globals.save_size - 6 {
+            crate::section_0094::overflow(
+                $globals,
+                crate::strpool_str!("save size"),
+                $globals.save_size as _,
+            )?;
+        }
+        // @:TeX capacity exceeded save size}{\quad save size@>
+        // end
     }
-    // @:TeX capacity exceeded save size}{\quad save size@>
-    // end
-    use crate::section_0011::save_size;
 }}
@@ -25,32 +25,41 @@ pub(crate) macro saved {
 //
 // @p procedure new_save_level(@!c:group_code); {begin a new level of grouping}
 /// begin a new level of grouping
-pub(crate) fn new_save_level(globals: &mut TeXGlobals, c: group_code) {
+pub(crate) fn new_save_level(globals: &mut TeXGlobals, c: group_code) -> TeXResult<()> {
     // begin check_full_save_stack;
     check_full_save_stack!(globals);
     // save_type(save_ptr):=level_boundary; save_level(save_ptr):=cur_group;
     // save_index(save_ptr):=cur_boundary;
     save_type!(globals, globals.save_ptr) = level_boundary;
     save_level!(globals, globals.save_ptr) = globals.cur_group.get();
-    save_index!(globals, globals.save_ptr) = globals.cur_boundary.get();
+    save_index!(globals, globals.save_ptr) = globals.cur_boundary.get() as _;
     // if cur_level=max_quarterword then overflow("grouping levels",
     // @:TeX capacity exceeded grouping levels}{\quad grouping levels@>
     //   max_quarterword-min_quarterword);
     //   {quit if |(cur_level+1)| is too big to be stored in |eqtb|}
     if globals.cur_level == max_quarterword {
-        todo!("overflow");
+        overflow(
+            globals,
+            crate::strpool_str!("grouping levels"),
+            (max_quarterword - min_quarterword) as integer,
+        )?;
     }
     // cur_boundary:=save_ptr; incr(cur_level); incr(save_ptr); cur_group:=c;
     globals.cur_boundary = globals.save_ptr;
     incr!(globals.cur_level);
     incr!(globals.save_ptr);
     globals.cur_group = c;
+    crate::ok_nojump!()
     // end;
 }
 
+use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::incr;
+use crate::section_0081::TeXResult;
+use crate::section_0094::overflow;
 use crate::section_0110::max_quarterword;
+use crate::section_0110::min_quarterword;
 use crate::section_0268::level_boundary;
 use crate::section_0268::save_index;
 use crate::section_0268::save_level;
@@ -10,6 +10,15 @@
 pub(crate) fn eq_destroy(globals: &mut TeXGlobals, w: memory_word) -> TeXResult<()> {
     // var q:pointer; {|equiv| field of |w|}
     let eq_type_field_w = eq_type_field!(w);
+    // TeX-rs: a `register` or `toks_register` defined by `\countdef` and so on
+    // may refer to a sparse array element of `latex_support`
+    #[cfg(feature = "latex_support")]
+    if (eq_type_field_w == register || eq_type_field_w == toks_register)
+        && crate::latex_support::is_sa_element(equiv_field!(w) as _)
+    {
+        crate::latex_support::delete_sa_ref(globals, equiv_field!(w));
+        crate::return_nojump!();
+    }
     // begin case eq_type_field(w) of
     // call,long_call,outer_call,long_outer_call: delete_token_ref(equiv_field(w));
     if eq_type_field_w == call
@@ -62,6 +71,10 @@ use crate::section_0130::free_node;
 use crate::section_0200::delete_token_ref;
 use crate::section_0201::delete_glue_ref;
 use crate::section_0202::flush_node_list;
+#[cfg(feature = "latex_support")]
+use crate::section_0209::register;
+#[cfg(feature = "latex_support")]
+use crate::section_0209::toks_register;
 use crate::section_0210::*;
 use crate::section_0221::eq_type_field;
 use crate::section_0221::equiv_field;
@@ -5,7 +5,7 @@
 /// saves `eqtb[p]`
 #[allow(unused_variables)]
 #[cfg_attr(feature = "trace", tracing::instrument(level = "trace"))]
-pub(crate) fn eq_save(globals: &mut TeXGlobals, p: pointer, l: quarterword) {
+pub(crate) fn eq_save(globals: &mut TeXGlobals, p: pointer, l: quarterword) -> TeXResult<()> {
     // begin check_full_save_stack;
     check_full_save_stack!(globals);
     // if l=level_zero then save_type(save_ptr):=restore_zero
@@ -25,10 +25,12 @@ pub(crate) fn eq_save(globals: &mut TeXGlobals, p: pointer, l: quarterword) {
     save_index!(globals, globals.save_ptr) = p;
     incr!(globals.save_ptr);
     // end;
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::incr;
+use crate::section_0081::TeXResult;
 use crate::section_0113::quarterword;
 use crate::section_0115::pointer;
 use crate::section_0221::level_zero;
@@ -23,7 +23,7 @@ pub(crate) fn eq_define(
     }
     // else if cur_level>level_one then eq_save(p,eq_level(p));
     else if globals.cur_level > level_one {
-        eq_save(globals, p, eq_level!(globals, p));
+        eq_save(globals, p, eq_level!(globals, p))?;
     }
     // eq_level(p):=cur_level; eq_type(p):=t; equiv(p):=e;
     eq_level!(globals, p) = globals.cur_level;
@@ -4,22 +4,27 @@
 
 // @p procedure eq_word_define(@!p:pointer;@!w:integer);
 #[allow(unused_variables)]
-#[cfg_attr(feature = "trace_verbose", tracing::instrument(level = "trace", skip(globals)))]
-pub(crate) fn eq_word_define(globals: &mut TeXGlobals, p: pointer, w: integer) {
+#[cfg_attr(
+    feature = "trace_verbose",
+    tracing::instrument(level = "trace", skip(globals))
+)]
+pub(crate) fn eq_word_define(globals: &mut TeXGlobals, p: pointer, w: integer) -> TeXResult<()> {
     // begin if xeq_level[p]<>cur_level then
     if globals.xeq_level[p] != globals.cur_level {
         // begin eq_save(p,xeq_level[p]); xeq_level[p]:=cur_level;
-        eq_save(globals, p, globals.xeq_level[p]);
+        eq_save(globals, p, globals.xeq_level[p])?;
         globals.xeq_level[p] = globals.cur_level;
         // end;
     }
     // eqtb[p].int:=w;
     globals.eqtb[p][MEMORY_WORD_INT] = w;
     // end;
+    crate::ok_nojump!()
 }
 
 use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
 use crate::section_0113::MEMORY_WORD_INT;
 use crate::section_0115::pointer;
 use crate::section_0276::eq_save;
@@ -1,7 +1,7 @@
 //! @ Subroutine |save_for_after| puts a token on the stack for save-keeping.
 //
 // @p procedure save_for_after(@!t:halfword);
-pub(crate) fn save_for_after(globals: &mut TeXGlobals, t: cur_tok_type) {
+pub(crate) fn save_for_after(globals: &mut TeXGlobals, t: cur_tok_type) -> TeXResult<()> {
     // begin if cur_level>level_one then
     if globals.cur_level > level_one {
         // begin check_full_save_stack;
@@ -23,10 +23,12 @@ pub(crate) fn save_for_after(globals: &mut TeXGlobals, t: cur_tok_type) {
         // end;
     }
     // end;
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::incr;
+use crate::section_0081::TeXResult;
 use crate::section_0221::level_one;
 use crate::section_0221::level_zero;
 use crate::section_0268::insert_token;
@@ -17,6 +17,16 @@ pub(crate) macro Clear_off_top_level_from_save_stack {
                     if save_type!($globals, $globals.save_ptr) == level_boundary {
                         crate::goto_forward_label!('done);
                     }
+                    #[cfg(feature = "unicode_support")]
+                    if save_type!($globals, $globals.save_ptr) == crate::unicode_support::restore_unicode_code {
+                        crate::unicode_support::unicode_code_restore($globals);
+                        continue;
+                    }
+                    #[cfg(feature = "latex_support")]
+                    if save_type!($globals, $globals.save_ptr) == crate::latex_support::restore_sa {
+                        crate::latex_support::sa_chain_restore($globals)?;
+                        continue;
+                    }
                     /// position to be restored
                     let p: pointer;
                     // p:=save_index(save_ptr);
@@ -50,7 +60,7 @@ pub(crate) macro Clear_off_top_level_from_save_stack {
             'done <-
         );
         $globals.cur_group = save_level!($globals, $globals.save_ptr).into();
-        $globals.cur_boundary = save_index!($globals, $globals.save_ptr).into();
+        $globals.cur_boundary = (save_index!($globals, $globals.save_ptr) as u16).into();
 
         use crate::section_0016::decr;
         use crate::section_0113::quarterword;
@@ -2,30 +2,74 @@
 //! for magnification.
 //
 // @p procedure prepare_mag;
-pub(crate) fn prepare_mag(globals: &mut TeXGlobals) {
+pub(crate) fn prepare_mag(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin if (mag_set>0)and(mag<>mag_set) then
     if globals.mag_set > 0 && mag!(globals) != globals.mag_set {
         // begin print_err("Incompatible magnification ("); print_int(mag);
+        print_err!(globals, crate::strpool_str!("Incompatible magnification ("));
+        print_int(globals, mag!(globals));
         // @.Incompatible magnification@>
         // print(");"); print_nl(" the previous value will be retained");
+        print(globals, crate::strpool_str!(");").get() as _);
+        print_nl(
+            globals,
+            crate::strpool_str!(" the previous value will be retained"),
+        );
         // help2("I can handle only one magnification ratio per job. So I've")@/
         // ("reverted to the magnification you used earlier on this run.");@/
+        help2!(
+            globals,
+            crate::strpool_str!("I can handle only one magnification ratio per job. So I've"),
+            crate::strpool_str!("reverted to the magnification you used earlier on this run.")
+        );
         // int_error(mag_set);
+        int_error(globals, globals.mag_set)?;
         // geq_word_define(int_base+mag_code,mag_set); {|mag:=mag_set|}
+        /// `mag:=mag_set`
+        const _: () = ();
+        geq_word_define(
+            globals,
+            (int_base + mag_code as word) as pointer,
+            globals.mag_set,
+        );
         // end;
     }
     // if (mag<=0)or(mag>32768) then
     if mag!(globals) <= 0 || mag!(globals) > 32768 {
         //   begin print_err("Illegal magnification has been changed to 1000");@/
+        print_err!(
+            globals,
+            crate::strpool_str!("Illegal magnification has been changed to 1000")
+        );
         // @.Illegal magnification...@>
         //   help1("The magnification ratio must be between 1 and 32768.");
+        help1!(
+            globals,
+            crate::strpool_str!("The magnification ratio must be between 1 and 32768.")
+        );
         //   int_error(mag); geq_word_define(int_base+mag_code,1000);
+        int_error(globals, mag!(globals))?;
+        geq_word_define(globals, (int_base + mag_code as word) as pointer, 1000);
         //   end;
     }
     // mag_set:=mag;
     globals.mag_set = mag!(globals);
     // end;
+    crate::ok_nojump!()
 }
 
+use crate::pascal::word;
 use crate::section_0004::TeXGlobals;
+use crate::section_0059::print;
+use crate::section_0062::print_nl;
+use crate::section_0065::print_int;
+use crate::section_0073::print_err;
+use crate::section_0079::help1;
+use crate::section_0079::help2;
+use crate::section_0081::TeXResult;
+use crate::section_0091::int_error;
+use crate::section_0115::pointer;
+use crate::section_0230::int_base;
 use crate::section_0236::mag;
+use crate::section_0236::mag_code;
+use crate::section_0279::geq_word_define;
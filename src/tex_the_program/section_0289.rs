@@ -72,12 +72,13 @@ pub(crate) const end_match_token: cur_tok_repr =
 use crate::section_0207::end_match;
 use crate::section_0207::left_brace;
 use crate::section_0207::letter;
+use crate::section_0207::math_shift;
 use crate::section_0207::other_char;
 use crate::section_0207::out_param;
 use crate::section_0207::r#match;
 use crate::section_0207::right_brace;
 use crate::section_0207::spacer;
-use crate::section_0207::tab_mark;use crate::section_0207::math_shift;
+use crate::section_0207::tab_mark;
 use crate::section_0297::cur_tok_repr;
 use crate::section_0297::cur_tok_type;
 use crate::section_0297::cur_tok_type_cmd_multiplier;
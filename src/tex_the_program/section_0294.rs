@@ -18,12 +18,13 @@ pub(crate) macro Display_the_token_m_c($globals:expr, $m:expr, $c:expr, $n:expr,
         || $m == letter
         || $m == other_char
     {
-        print($globals, $c.get() as _);
+        // TeX-rs: `print_ASCII` also copes with character codes beyond 255
+        print_ASCII($globals, $c.get() as _);
     }
     // mac_param: begin print(c); print(c);
     else if $m == mac_param {
-        print($globals, $c.get() as _);
-        print($globals, $c.get() as _);
+        print_ASCII($globals, $c.get() as _);
+        print_ASCII($globals, $c.get() as _);
         // end;
     }
     // out_param: begin print(match_chr);
@@ -61,6 +62,12 @@ pub(crate) macro Display_the_token_m_c($globals:expr, $m:expr, $c:expr, $n:expr,
     }
     // end_match: print("->");
     else if $m == end_match {
+        // TeX-rs: the mark of a `\protected` macro of `latex_support` has `c<>0`
+        #[cfg(feature = "latex_support")]
+        if $c.get() == 0 {
+            print($globals, crate::strpool_str!("->").get() as _);
+        }
+        #[cfg(not(feature = "latex_support"))]
         print($globals, crate::strpool_str!("->").get() as _);
     }
     // @.->@>
@@ -78,5 +85,6 @@ pub(crate) macro Display_the_token_m_c($globals:expr, $m:expr, $c:expr, $n:expr,
     use crate::section_0058::print_char;
     use crate::section_0059::print;
     use crate::section_0063::print_esc;
+    use crate::section_0068::print_ASCII;
     use crate::section_0207::*;
 }}
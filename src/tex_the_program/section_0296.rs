@@ -18,9 +18,14 @@ pub(crate) fn print_meaning(globals: &mut TeXGlobals) {
     }
     // else if cur_cmd=top_bot_mark then
     else if globals.cur_cmd == top_bot_mark {
-        todo!("not yet implemented in {}", file!());
         // begin print_char(":"); print_ln;
+        print_char(
+            make_globals_io_string_log_view!(globals),
+            ASCII_code_literal!(b':'),
+        );
+        print_ln(make_globals_io_string_log_view!(globals));
         // token_show(cur_mark[cur_chr]);
+        token_show(globals, globals.cur_mark[globals.cur_chr.get() as u8]);
         // end;
     }
     // end;
@@ -50,6 +50,10 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives($glo
         $globals, $cmd, $chr_code
     ) {
         true
+    } else if crate::section_0385::Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0385!(
+        $globals, $cmd, $chr_code
+    ) {
+        true
     } else if crate::section_0412::Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0412!(
         $globals, $cmd, $chr_code
     ) {
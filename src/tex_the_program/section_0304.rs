@@ -68,11 +68,8 @@ use crate::section_0011::max_in_open_TYPENUM;
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0304::input_file_range_array;
 
-pub(crate) type input_file_range_array_LENGTH_TYPENUM = typenum::op!(max_in_open_TYPENUM - U1 + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length!(
-    pub(crate) input_file_range_array[u8_from_m_to_n<U1, max_in_open_TYPENUM>] =>
-    u8; U8; U1; input_file_range_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) input_file_range_array[u8_from_m_to_n<U1, max_in_open_TYPENUM>] => u8; U8; U1
 );
 
 // @!line : integer; {current line number in the current source file}
@@ -87,11 +84,8 @@ pub(crate) static line_stack: line_stack_array<integer> = line_stack_array::defa
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0304::line_stack_array;
 
-pub(crate) type line_stack_array_LENGTH_TYPENUM = typenum::op!(max_in_open_TYPENUM - U1 + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length!(
-    pub(crate) line_stack_array[u8_from_m_to_n<U1, max_in_open_TYPENUM>] =>
-    u8; U8; U1; line_stack_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) line_stack_array[u8_from_m_to_n<U1, max_in_open_TYPENUM>] => u8; U8; U1
 );
 
 #[globals_struct_use(TeXGlobals)]
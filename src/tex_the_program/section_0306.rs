@@ -1,23 +1,77 @@
 //! @ Here is a procedure that uses |scanner_status| to print a warning message
 //! when a subfile has ended, and at certain other crucial times:
-//!
-//! @<Declare the procedure called |runaway|@>=
-//! procedure runaway;
-//! var p:pointer; {head of runaway list}
-//! begin if scanner_status>skipping then
-//!   begin print_nl("Runaway ");
-//! @.Runaway...@>
-//!   case scanner_status of
-//!   defining: begin print("definition"); p:=def_ref;
-//!     end;
-//!   matching: begin print("argument"); p:=temp_head;
-//!     end;
-//!   aligning: begin print("preamble"); p:=hold_head;
-//!     end;
-//!   absorbing: begin print("text"); p:=def_ref;
-//!     end;
-//!   end; {there are no other cases}
-//!   print_char("?");print_ln; show_token_list(link(p),null,error_line-10);
-//!   end;
-//! end;
-//!
+//
+// @<Declare the procedure called |runaway|@>=
+// procedure runaway;
+#[allow(unused_variables)]
+pub(crate) fn runaway(globals: &mut TeXGlobals) {
+    // var p:pointer; {head of runaway list}
+    /// head of runaway list
+    let p: pointer;
+    // begin if scanner_status>skipping then
+    if globals.scanner_status > scanner_status_kind::skipping {
+        // begin print_nl("Runaway ");
+        print_nl(globals, crate::strpool_str!("Runaway "));
+        // @.Runaway...@>
+        // case scanner_status of
+        match globals.scanner_status {
+            // defining: begin print("definition"); p:=def_ref;
+            //   end;
+            scanner_status_kind::defining => {
+                print(globals, crate::strpool_str!("definition").get() as _);
+                p = globals.def_ref;
+            }
+            // matching: begin print("argument"); p:=temp_head;
+            //   end;
+            scanner_status_kind::matching => {
+                print(globals, crate::strpool_str!("argument").get() as _);
+                p = temp_head;
+            }
+            // aligning: begin print("preamble"); p:=hold_head;
+            //   end;
+            scanner_status_kind::aligning => {
+                print(globals, crate::strpool_str!("preamble").get() as _);
+                p = hold_head;
+            }
+            // absorbing: begin print("text"); p:=def_ref;
+            //   end;
+            scanner_status_kind::absorbing => {
+                print(globals, crate::strpool_str!("text").get() as _);
+                p = globals.def_ref;
+            }
+            // end; {there are no other cases}
+            scanner_status_kind::normal | scanner_status_kind::skipping => return,
+        }
+        // print_char("?");print_ln; show_token_list(link(p),null,error_line-10);
+        print_char(
+            make_globals_io_string_log_view!(globals),
+            ASCII_code_literal!(b'?'),
+        );
+        print_ln(make_globals_io_string_log_view!(globals));
+        show_token_list(
+            globals,
+            link!(globals, p) as _,
+            null as _,
+            globals.error_line as integer - 10,
+        );
+        // end;
+    }
+    // end;
+}
+
+use crate::pascal::integer;
+use crate::section_0004::make_globals_io_string_log_view;
+use crate::section_0004::TeXGlobals;
+use crate::section_0004::TeXGlobalsIoStringLogView;
+use crate::section_0018::ASCII_code_literal;
+use crate::section_0057::print_ln;
+use crate::section_0058::print_char;
+use crate::section_0059::print;
+use crate::section_0062::print_nl;
+use crate::section_0115::null;
+use crate::section_0115::pointer;
+use crate::section_0118::link;
+use crate::section_0162::hold_head;
+use crate::section_0162::temp_head;
+use crate::section_0292::show_token_list;
+use crate::section_0305::scanner_status_kind;
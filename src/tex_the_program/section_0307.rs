@@ -111,6 +111,8 @@ pub(crate) const every_par_text: quarterword = 7;
 /// `token_type` code for `\everymath`
 pub(crate) const every_math_text: quarterword = 8;
 // @d every_display_text=9 {|token_type| code for \.{\\everydisplay}}
+/// `token_type` code for `\everydisplay`
+pub(crate) const every_display_text: quarterword = 9;
 // @d every_hbox_text=10 {|token_type| code for \.{\\everyhbox}}
 /// `token_type` code for `\everyhbox`
 pub(crate) const every_hbox_text: quarterword = 10;
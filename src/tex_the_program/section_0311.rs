@@ -40,7 +40,12 @@ pub(crate) fn show_context(globals: &mut TeXGlobals) {
             // if (state<>token_list) then
             if state!(globals) != token_list {
                 // if (name>17) or (base_ptr=0) then bottom_line:=true;
-                if name!(globals) > 17 || globals.base_ptr == 0 {
+                // TeX-rs: pseudo files of `latex_support` show the file they come from
+                #[cfg(feature = "latex_support")]
+                let bottom_name = 19;
+                #[cfg(not(feature = "latex_support"))]
+                let bottom_name = 17;
+                if name!(globals) > bottom_name || globals.base_ptr == 0 {
                     bottom_line = true;
                 }
             }
@@ -39,8 +39,27 @@ pub(crate) macro Print_location_of_current_line($globals:expr) {{
     }
     // else  begin print_nl("l."); print_int(line);
     else {
-        print_nl($globals, crate::strpool_str!("l."));
-        print_int($globals, $globals.line);
+        // TeX-rs: a pseudo file of `\scantokens` in `latex_support` is labeled as such;
+        // when it is not the current level, its line number has been saved
+        #[cfg(feature = "latex_support")]
+        {
+            if name!($globals) == 18 || name!($globals) == 19 {
+                print_nl($globals, crate::strpool_str!("<scantokens> "));
+                print($globals, crate::strpool_str!("l.").get() as _);
+            } else {
+                print_nl($globals, crate::strpool_str!("l."));
+            }
+            if index!($globals) == $globals.in_open.get() {
+                print_int($globals, $globals.line);
+            } else {
+                print_int($globals, $globals.line_stack[index!($globals) + 1]);
+            }
+        }
+        #[cfg(not(feature = "latex_support"))]
+        {
+            print_nl($globals, crate::strpool_str!("l."));
+            print_int($globals, $globals.line);
+        }
         // end;
     }
     // print_char(" ")
@@ -53,8 +72,12 @@ pub(crate) macro Print_location_of_current_line($globals:expr) {{
     use crate::section_0004::TeXGlobalsIoStringLogView;
     use crate::section_0018::ASCII_code_literal;
     use crate::section_0058::print_char;
+    #[cfg(feature = "latex_support")]
+    use crate::section_0059::print;
     use crate::section_0062::print_nl;
     use crate::section_0065::print_int;
+    #[cfg(feature = "latex_support")]
+    use crate::section_0302::index;
     use crate::section_0302::name;
     use crate::section_0304::terminal_input;
 }}
@@ -37,19 +37,58 @@ pub(crate) macro Print_type_of_token_list($globals:expr) {{
         print_nl($globals, crate::strpool_str!("<output> "));
     }
     // every_par_text: print_nl("<everypar> ");
+    else if token_type == every_par_text {
+        print_nl($globals, crate::strpool_str!("<everypar> "));
+    }
     // every_math_text: print_nl("<everymath> ");
+    else if token_type == every_math_text {
+        print_nl($globals, crate::strpool_str!("<everymath> "));
+    }
     // every_display_text: print_nl("<everydisplay> ");
+    else if token_type == every_display_text {
+        print_nl($globals, crate::strpool_str!("<everydisplay> "));
+    }
     // every_hbox_text: print_nl("<everyhbox> ");
+    else if token_type == every_hbox_text {
+        print_nl($globals, crate::strpool_str!("<everyhbox> "));
+    }
     // every_vbox_text: print_nl("<everyvbox> ");
+    else if token_type == every_vbox_text {
+        print_nl($globals, crate::strpool_str!("<everyvbox> "));
+    }
     // every_job_text: print_nl("<everyjob> ");
+    else if token_type == every_job_text {
+        print_nl($globals, crate::strpool_str!("<everyjob> "));
+    }
     // every_cr_text: print_nl("<everycr> ");
+    else if token_type == every_cr_text {
+        print_nl($globals, crate::strpool_str!("<everycr> "));
+    }
     // mark_text: print_nl("<mark> ");
+    else if token_type == mark_text {
+        print_nl($globals, crate::strpool_str!("<mark> "));
+    }
     // write_text: print_nl("<write> ");
+    else if token_type == write_text {
+        print_nl($globals, crate::strpool_str!("<write> "));
+    }
     // othercases print_nl("?") {this should never happen}
     else {
-        crate::trace_error_expr!("token_type = {}", token_type);
-        /// this should never happen
-        print_nl($globals, crate::strpool_str!("?"));
+        // TeX-rs: `\everyeof` of `latex_support`
+        #[cfg(feature = "latex_support")]
+        let processed = if token_type == crate::latex_support::every_eof_text {
+            print_nl($globals, crate::strpool_str!("<everyeof> "));
+            true
+        } else {
+            false
+        };
+        #[cfg(not(feature = "latex_support"))]
+        let processed = false;
+        if !processed {
+            crate::trace_error_expr!("token_type = {}", token_type);
+            /// this should never happen
+            print_nl($globals, crate::strpool_str!("?"));
+        }
     }
     // endcases
     use crate::section_0004::make_globals_io_string_log_view;
@@ -5,16 +5,16 @@
 // @<Pseudoprint the line@>=
 pub(crate) macro Pseudoprint_the_line($globals:expr, $l:expr) {{
     /// end of current line in `buffer`
-    let j: u8_from_0_to_n<buf_size_TYPENUM>;
+    let j: u16_from_0_to_n<buf_size_TYPENUM>;
     // begin_pseudoprint;
     begin_pseudoprint!($globals, $l);
     // if buffer[limit]=end_line_char then j:=limit
     if $globals.buffer[limit!($globals)].numeric_value() as integer == end_line_char!($globals) {
-        j = (limit!($globals) as u8).into();
+        j = (limit!($globals) as u16).into();
     }
     // else j:=limit+1; {determine the effective end of the line}
     else {
-        j = (limit!($globals) as u8 + 1).into();
+        j = (limit!($globals) as u16 + 1).into();
         /// determine the effective end of the line
         const _: () = ();
     }
@@ -26,19 +26,16 @@ pub(crate) macro Pseudoprint_the_line($globals:expr, $l:expr) {{
                 set_trick_count!($globals);
             }
             // print(buffer[i]);
-            let ch = $globals.buffer[i].numeric_value();
-            if ch >= 256 {
-                todo!("not yet implemented in {}", file!());
-            }
-            print($globals, ch as _);
+            // TeX-rs: `print_ASCII` also copes with character codes beyond 255
+            print_ASCII($globals, $globals.buffer[i].numeric_value() as _);
             // end
         }
     }
     use crate::pascal::integer;
-    use crate::pascal::u8_from_0_to_n;
+    use crate::pascal::u16_from_0_to_n;
     use crate::section_0011::buf_size_TYPENUM;
     use crate::section_0036::loc;
-    use crate::section_0059::print;
+    use crate::section_0068::print_ASCII;
     use crate::section_0113::halfword;
     use crate::section_0236::end_line_char;
     use crate::section_0302::limit;
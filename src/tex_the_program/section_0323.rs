@@ -50,14 +50,26 @@ pub(crate) fn begin_token_list(globals: &mut TeXGlobals, p: pointer, t: quarterw
                 }
                 // othercases print_cmd_chr(assign_toks,t-output_text+output_routine_loc)
                 else {
-                    print_cmd_chr(
-                        globals,
-                        assign_toks,
-                        chr_code_type::new(
-                            t as chr_code_repr - output_text as chr_code_repr
-                                + output_routine_loc as chr_code_repr,
-                        ),
-                    );
+                    // TeX-rs: `\everyeof` of `latex_support` does not follow `\everycr`
+                    #[cfg(feature = "latex_support")]
+                    let processed = if t == crate::latex_support::every_eof_text {
+                        print_esc(globals, crate::strpool_str!("everyeof"));
+                        true
+                    } else {
+                        false
+                    };
+                    #[cfg(not(feature = "latex_support"))]
+                    let processed = false;
+                    if !processed {
+                        print_cmd_chr(
+                            globals,
+                            assign_toks,
+                            chr_code_type::new(
+                                t as chr_code_repr - output_text as chr_code_repr
+                                    + output_routine_loc as chr_code_repr,
+                            ),
+                        );
+                    }
                 }
                 // endcases;@/
                 // print("->"); token_show(p); end_diagnostic(false);
@@ -7,7 +7,7 @@
 /// leave a token-list input level
 #[allow(unused_variables)]
 #[cfg_attr(feature = "trace_verbose", tracing::instrument(level = "trace"))]
-pub(crate) fn end_token_list(globals: &mut TeXGlobals) {
+pub(crate) fn end_token_list(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin if token_type>=backed_up then {token list to be deleted}
     if token_type!(globals) >= backed_up {
         /// token list to be deleted
@@ -45,7 +45,10 @@ pub(crate) fn end_token_list(globals: &mut TeXGlobals) {
         }
         // else fatal_error("(interwoven alignment preambles are not allowed)");
         else {
-            todo!("not yet implemented in {}", file!());
+            fatal_error(
+                globals,
+                crate::strpool_str!("(interwoven alignment preambles are not allowed)"),
+            )?;
         }
     }
     // @.interwoven alignment preambles...@>
@@ -54,10 +57,13 @@ pub(crate) fn end_token_list(globals: &mut TeXGlobals) {
     // check_interrupt;
     check_interrupt!(globals);
     // end;
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::decr;
+use crate::section_0081::TeXResult;
+use crate::section_0093::fatal_error;
 use crate::section_0096::check_interrupt;
 use crate::section_0113::halfword;
 use crate::section_0123::flush_list;
@@ -13,7 +13,7 @@
     feature = "trace_verbose",
     tracing::instrument(level = "trace", skip(globals))
 )]
-pub(crate) fn back_input(globals: &mut TeXGlobals) {
+pub(crate) fn back_input(globals: &mut TeXGlobals) -> TeXResult<()> {
     // var p:pointer; {a token list of length one}
     /// a token list of length one
     let p: pointer;
@@ -24,10 +24,10 @@ pub(crate) fn back_input(globals: &mut TeXGlobals) {
     {
         // end_token_list; {conserve stack space}
         /// conserve stack space
-        end_token_list(globals);
+        end_token_list(globals)?;
     }
     // p:=get_avail; info(p):=cur_tok;
-    p = get_avail(globals);
+    p = get_avail(globals)?;
     info_tok_assign!(globals, p, globals.cur_tok);
     // if cur_tok<right_brace_limit then
     if globals.cur_tok < right_brace_limit {
@@ -49,6 +49,7 @@ pub(crate) fn back_input(globals: &mut TeXGlobals) {
     loc!(globals) = p;
     /// that was `back_list(p)`, without procedure overhead
     const _: () = ();
+    crate::ok_nojump!()
     // end;
 }
 
@@ -56,6 +57,7 @@ use crate::section_0004::TeXGlobals;
 use crate::section_0016::decr;
 use crate::section_0016::incr;
 use crate::section_0036::loc;
+use crate::section_0081::TeXResult;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
 use crate::section_0118::info_tok_assign;
@@ -16,7 +16,7 @@ pub(crate) macro Insert_token_p_into_TeX_s_input($globals:expr, $p:expr) {{
             crate::unicode_support::info_value($globals, $p),
         );
     }
-    back_input($globals);
+    back_input($globals)?;
     $globals.cur_tok = t;
     // end
     use crate::section_0297::cur_tok_type;
@@ -8,7 +8,7 @@
 pub(crate) fn back_error(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin OK_to_interrupt:=false; back_input; OK_to_interrupt:=true; error;
     globals.OK_to_interrupt = false;
-    back_input(globals);
+    back_input(globals)?;
     globals.OK_to_interrupt = true;
     error(globals)?;
     // end;
@@ -21,7 +21,7 @@ pub(crate) fn back_error(globals: &mut TeXGlobals) -> TeXResult<()> {
 pub(crate) fn ins_error(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin OK_to_interrupt:=false; back_input; token_type:=inserted;
     globals.OK_to_interrupt = false;
-    back_input(globals);
+    back_input(globals)?;
     token_type!(globals) = inserted;
     // OK_to_interrupt:=true; error;
     globals.OK_to_interrupt = true;
@@ -6,10 +6,24 @@
 //
 // @p procedure begin_file_reading;
 #[allow(unused_variables)]
-pub(crate) fn begin_file_reading(globals: &mut TeXGlobals) {
+pub(crate) fn begin_file_reading(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin if in_open=max_in_open then overflow("text input levels",max_in_open);
+    if globals.in_open.get() as integer == globals.max_in_open as integer {
+        overflow(
+            globals,
+            crate::strpool_str!("text input levels"),
+            globals.max_in_open as integer,
+        )?;
+    }
     // @:TeX capacity exceeded text input levels}{\quad text input levels@>
     // if first=buf_size then overflow("buffer size",buf_size);
+    if globals.first.get() as integer == globals.buf_size as integer {
+        overflow(
+            globals,
+            crate::strpool_str!("buffer size"),
+            globals.buf_size as integer,
+        )?;
+    }
     // @:TeX capacity exceeded buffer size}{\quad buffer size@>
     // incr(in_open); push_input; index:=in_open;
     incr!(globals.in_open);
@@ -17,17 +31,26 @@ pub(crate) fn begin_file_reading(globals: &mut TeXGlobals) {
     index!(globals) = globals.in_open.get();
     // line_stack[index]:=line; start:=first; state:=mid_line;
     globals.line_stack[index!(globals)] = globals.line;
-    start!(globals) = globals.first.get();
+    start!(globals) = globals.first.get() as _;
     state!(globals) = mid_line;
     // name:=0; {|terminal_input| is now |true|}
     name!(globals) = 0;
     /// `terminal_input` is now `true`
     const _: () = ();
+    // TeX-rs: `\everyeof` of `latex_support` has not been seen at this level yet
+    #[cfg(feature = "latex_support")]
+    {
+        globals.eof_seen[index!(globals) as usize] = false;
+    }
+    crate::ok_nojump!()
     // end;
 }
 
+use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::incr;
+use crate::section_0081::TeXResult;
+use crate::section_0094::overflow;
 use crate::section_0302::index;
 use crate::section_0302::name;
 use crate::section_0302::start;
@@ -5,9 +5,17 @@
 #[allow(unused_variables)]
 pub(crate) fn end_file_reading(globals: &mut TeXGlobals) {
     // begin first:=start; line:=line_stack[index];
-    globals.first = start!(globals).into();
+    globals.first = (start!(globals) as u16).into();
     globals.line = globals.line_stack[index!(globals)];
+    // TeX-rs: pseudo files of `\scantokens` in `latex_support` have names 18 and 19
+    #[cfg(feature = "latex_support")]
+    if name!(globals) == 18 || name!(globals) == 19 {
+        crate::latex_support::pseudo_close(globals);
+    } else if name!(globals) > 17 {
+        a_close(&mut cur_file!(globals));
+    }
     // if name>17 then a_close(cur_file); {forget it}
+    #[cfg(not(feature = "latex_support"))]
     if name!(globals) > 17 {
         /// forget it
         a_close(&mut cur_file!(globals));
@@ -2,7 +2,7 @@
 //! actions.
 //
 // @<Initialize the input routines@>=
-pub(crate) macro Initialize_the_input_routines($globals:expr, $lbl_final_end:lifetime) {
+pub(crate) macro Initialize_the_input_routines($globals:expr, $lbl_end_of_TEX:lifetime, $lbl_final_end:lifetime) {
     crate::trace_span!("Initialize_the_input_routines");
     // begin input_ptr:=0; max_in_stack:=0;
     $globals.input_ptr = 0.into();
@@ -15,7 +15,7 @@ pub(crate) macro Initialize_the_input_routines($globals:expr, $lbl_final_end:lif
     $globals.param_ptr = 0.into();
     $globals.max_param_stack = 0.into();
     // first:=buf_size; repeat buffer[first]:=0; decr(first); until first=0;
-    $globals.first = buf_size.into();
+    $globals.first = $globals.buf_size.into();
     loop {
         $globals.buffer[$globals.first] = 0.into();
         decr!($globals.first);
@@ -38,18 +38,18 @@ pub(crate) macro Initialize_the_input_routines($globals:expr, $lbl_final_end:lif
     // align_state:=1000000;@/
     $globals.align_state = 1000000;
     // if not init_terminal then goto final_end;
-    if !init_terminal($globals) {
+    if !try_or_jump!(init_terminal($globals), $lbl_end_of_TEX) {
         crate::goto_forward_label!($lbl_final_end);
     }
     // limit:=last; first:=last+1; {|init_terminal| has set |loc| and |last|}
     /// `init_terminal` has set `loc` and `last`
     const _: () = ();
-    limit!($globals) = $globals.last.get();
+    limit!($globals) = $globals.last.get() as _;
     $globals.first = $globals.last + 1;
     // end
-    use crate::section_0011::buf_size;
     use crate::section_0016::decr;
     use crate::section_0037::init_terminal;
+    use crate::section_0081::try_or_jump;
     use crate::section_0115::null;
     use crate::section_0302::index;
     use crate::section_0302::limit;
@@ -6,7 +6,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0334($globals
     let globals = &mut *$globals;
     // primitive("par",par_end,256); {cf.\ |scan_file_name|}
     /// cf. `scan_file_name`
-    primitive(globals, crate::strpool_str!("par"), par_end, 256);
+    primitive(globals, crate::strpool_str!("par"), par_end, 256)?;
     // @!@:par_}{\.{\\par} primitive@>
     // par_loc:=cur_val; par_token:=cs_token_flag+par_loc;
     globals.par_loc = globals.cur_val as _;
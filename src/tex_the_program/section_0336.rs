@@ -5,28 +5,77 @@
 //
 // @p procedure check_outer_validity;
 #[allow(unused_variables)]
-pub(crate) fn check_outer_validity(globals: &mut TeXGlobals) {
+pub(crate) fn check_outer_validity(globals: &mut TeXGlobals) -> TeXResult<()> {
     // var p:pointer; {points to inserted token list}
     // @!q:pointer; {auxiliary pointer}
     // begin if scanner_status<>normal then
-    //   begin deletions_allowed:=false;
-    //   @<Back up an outer control sequence so that it can be reread@>;
-    //   if scanner_status>skipping then
-    //     @<Tell the user what has run away and try to recover@>
-    //   else  begin print_err("Incomplete "); print_cmd_chr(if_test,cur_if);
-    // @.Incomplete \\if...@>
-    //     print("; all text was ignored after line "); print_int(skip_line);
-    //     help3("A forbidden control sequence occurred in skipped text.")@/
-    //     ("This kind of error happens when you say `\if...' and forget")@/
-    //     ("the matching `\fi'. I've inserted a `\fi'; this might work.");
-    //     if cur_cs<>0 then cur_cs:=0
-    //     else help_line[2]:=@|
-    //       "The file ended while I was skipping conditional text.";
-    //     cur_tok:=cs_token_flag+frozen_fi; ins_error;
-    //     end;
-    //   deletions_allowed:=true;
-    //   end;
+    if globals.scanner_status != scanner_status_kind::normal {
+        // begin deletions_allowed:=false;
+        // @<Back up an outer control sequence so that it can be reread@>;
+        crate::section_0337::Back_up_an_outer_control_sequence_so_that_it_can_be_reread!(globals);
+        // if scanner_status>skipping then
+        if globals.scanner_status > scanner_status_kind::skipping {
+            // @<Tell the user what has run away and try to recover@>
+            crate::section_0338::Tell_the_user_what_has_run_away_and_try_to_recover!(globals);
+        }
+        // else  begin print_err("Incomplete "); print_cmd_chr(if_test,cur_if);
+        else {
+            print_err!(globals, crate::strpool_str!("Incomplete "));
+            print_cmd_chr(
+                globals,
+                if_test,
+                chr_code_type::new(globals.cur_if.get() as _),
+            );
+            // @.Incomplete \\if...@>
+            // print("; all text was ignored after line "); print_int(skip_line);
+            print(
+                globals,
+                crate::strpool_str!("; all text was ignored after line ").get() as _,
+            );
+            print_int(globals, globals.skip_line);
+            // help3("A forbidden control sequence occurred in skipped text.")@/
+            // ("This kind of error happens when you say `\if...' and forget")@/
+            // ("the matching `\fi'. I've inserted a `\fi'; this might work.");
+            help3!(
+                globals,
+                crate::strpool_str!("A forbidden control sequence occurred in skipped text."),
+                crate::strpool_str!("This kind of error happens when you say `\\if...' and forget"),
+                crate::strpool_str!(
+                    "the matching `\\fi'. I've inserted a `\\fi'; this might work."
+                )
+            );
+            // if cur_cs<>0 then cur_cs:=0
+            if globals.cur_cs != 0 {
+                globals.cur_cs = 0;
+            }
+            // else help_line[2]:=@|
+            //   "The file ended while I was skipping conditional text.";
+            else {
+                globals.help_line[2] =
+                    crate::strpool_str!("The file ended while I was skipping conditional text.");
+            }
+            // cur_tok:=cs_token_flag+frozen_fi; ins_error;
+            globals.cur_tok = cur_tok_type::from_cs(frozen_fi as _);
+            ins_error(globals)?;
+            // end;
+        }
+        // deletions_allowed:=true;
+        // end;
+    }
     // end;
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0059::print;
+use crate::section_0065::print_int;
+use crate::section_0073::print_err;
+use crate::section_0079::help3;
+use crate::section_0081::TeXResult;
+use crate::section_0210::if_test;
+use crate::section_0222::frozen_fi;
+use crate::section_0297::chr_code_type;
+use crate::section_0297::cur_tok_type;
+use crate::section_0298::print_cmd_chr;
+use crate::section_0305::scanner_status_kind;
+use crate::section_0327::ins_error;
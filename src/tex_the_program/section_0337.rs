@@ -0,0 +1,32 @@
+//! @ An outer control sequence that occurs in a \.{\\read} will not be reread,
+//! since the error recovery for \.{\\read} is not very powerful.
+//
+// @<Back up an outer control sequence so that it can be reread@>=
+pub(crate) macro Back_up_an_outer_control_sequence_so_that_it_can_be_reread($globals:expr) {{
+    // if cur_cs<>0 then
+    if $globals.cur_cs != 0 {
+        // begin if (state=token_list)or(name<1)or(name>17) then
+        if state!($globals) == token_list || name!($globals) < 1 || name!($globals) > 17 {
+            // begin p:=get_avail; info(p):=cs_token_flag+cur_cs;
+            let p = get_avail($globals)?;
+            info_tok_assign!($globals, p, cur_tok_type::from_cs($globals.cur_cs));
+            // back_list(p); {prepare to read the control sequence again}
+            /// prepare to read the control sequence again
+            back_list!($globals, p);
+            // end;
+        }
+        // cur_cmd:=spacer; cur_chr:=" "; {replace it by a space}
+        $globals.cur_cmd = spacer;
+        $globals.cur_chr = chr_code_type::new(b' ' as _);
+        // end
+    }
+    use crate::section_0118::info_tok_assign;
+    use crate::section_0120::get_avail;
+    use crate::section_0207::spacer;
+    use crate::section_0297::chr_code_type;
+    use crate::section_0297::cur_tok_type;
+    use crate::section_0302::name;
+    use crate::section_0302::state;
+    use crate::section_0307::token_list;
+    use crate::section_0323::back_list;
+}}
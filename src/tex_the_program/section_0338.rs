@@ -0,0 +1,46 @@
+//! @ @<Tell the user what has run away...@>=
+pub(crate) macro Tell_the_user_what_has_run_away_and_try_to_recover($globals:expr) {{
+    // begin runaway; {print a definition, argument, or preamble}
+    /// print a definition, argument, or preamble
+    runaway($globals);
+    // if cur_cs=0 then print_err("File ended")
+    if $globals.cur_cs == 0 {
+        print_err!($globals, crate::strpool_str!("File ended"));
+    }
+    // @.File ended while scanning...@>
+    // else  begin cur_cs:=0; print_err("Forbidden control sequence found");
+    else {
+        $globals.cur_cs = 0;
+        print_err!($globals, crate::strpool_str!("Forbidden control sequence found"));
+        // @.Forbidden control sequence...@>
+        //   end;
+    }
+    // print(" while scanning ");
+    print($globals, crate::strpool_str!(" while scanning ").get() as _);
+    // @<Print either `\.{definition}' or `\.{use}' or `\.{preamble}' or `\.{text}',
+    //   and insert tokens that should lead to recovery@>;
+    crate::section_0339::Print_either_definition_or_use_or_preamble_or_text_and_insert_tokens_that_should_lead_to_recovery!($globals);
+    // print(" of "); sprint_cs(warning_index);
+    print($globals, crate::strpool_str!(" of ").get() as _);
+    sprint_cs($globals, $globals.warning_index);
+    // help4("I suspect you have forgotten a `}', causing me")@/
+    // ("to read past where you wanted me to stop.")@/
+    // ("I'll try to recover; but if the error is serious,")@/
+    // ("you'd better type `E' or `X' now and fix your file.");@/
+    help4!(
+        $globals,
+        crate::strpool_str!("I suspect you have forgotten a `}', causing me"),
+        crate::strpool_str!("to read past where you wanted me to stop."),
+        crate::strpool_str!("I'll try to recover; but if the error is serious,"),
+        crate::strpool_str!("you'd better type `E' or `X' now and fix your file.")
+    );
+    // error;
+    error($globals)?;
+    // end
+    use crate::section_0059::print;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help4;
+    use crate::section_0082::error;
+    use crate::section_0263::sprint_cs;
+    use crate::section_0306::runaway;
+}}
@@ -0,0 +1,76 @@
+//! @ The recovery procedure can't be fully understood without knowing more
+//! about the \TeX\ routines that should be aborted, but we can sketch the
+//! ideas here:  For a runaway definition or a runaway balanced text
+//! we will insert a right brace; for a
+//! runaway preamble, we will insert a special \.{\\cr} token and a right
+//! brace; and for a runaway argument, we will set |long_state| to
+//! |outer_call| and insert \.{\\par}.
+//
+// @<Print either `\.{definition}' or ...@>=
+pub(crate) macro Print_either_definition_or_use_or_preamble_or_text_and_insert_tokens_that_should_lead_to_recovery($globals:expr) {{
+    // p:=get_avail;
+    let mut p = get_avail($globals)?;
+    // case scanner_status of
+    match $globals.scanner_status {
+        // defining:begin print("definition"); info(p):=right_brace_token+"}";
+        //   end;
+        scanner_status_kind::defining => {
+            print($globals, crate::strpool_str!("definition").get() as _);
+            info_tok_assign!(
+                $globals,
+                p,
+                cur_tok_type::new(right_brace_token + b'}' as cur_tok_repr)
+            );
+        }
+        // matching:begin print("use"); info(p):=par_token; long_state:=outer_call;
+        //   end;
+        scanner_status_kind::matching => {
+            print($globals, crate::strpool_str!("use").get() as _);
+            info_tok_assign!($globals, p, $globals.par_token);
+            $globals.long_state = outer_call.into();
+        }
+        // aligning:begin print("preamble"); info(p):=right_brace_token+"}"; q:=p;
+        //   p:=get_avail; link(p):=q; info(p):=cs_token_flag+frozen_cr;
+        //   align_state:=-1000000;
+        //   end;
+        scanner_status_kind::aligning => {
+            print($globals, crate::strpool_str!("preamble").get() as _);
+            info_tok_assign!(
+                $globals,
+                p,
+                cur_tok_type::new(right_brace_token + b'}' as cur_tok_repr)
+            );
+            let q = p;
+            p = get_avail($globals)?;
+            link!($globals, p) = q;
+            info_tok_assign!($globals, p, cur_tok_type::from_cs(frozen_cr as _));
+            $globals.align_state = -1000000;
+        }
+        // absorbing:begin print("text"); info(p):=right_brace_token+"}";
+        //   end;
+        scanner_status_kind::absorbing => {
+            print($globals, crate::strpool_str!("text").get() as _);
+            info_tok_assign!(
+                $globals,
+                p,
+                cur_tok_type::new(right_brace_token + b'}' as cur_tok_repr)
+            );
+        }
+        // end; {there are no other cases}
+        _ => confusion($globals, crate::strpool_str!("runaway"))?,
+    }
+    // ins_list(p)
+    ins_list!($globals, p);
+    use crate::section_0059::print;
+    use crate::section_0095::confusion;
+    use crate::section_0118::info_tok_assign;
+    use crate::section_0118::link;
+    use crate::section_0120::get_avail;
+    use crate::section_0210::outer_call;
+    use crate::section_0222::frozen_cr;
+    use crate::section_0289::right_brace_token;
+    use crate::section_0297::cur_tok_repr;
+    use crate::section_0297::cur_tok_type;
+    use crate::section_0305::scanner_status_kind;
+    use crate::section_0323::ins_list;
+}}
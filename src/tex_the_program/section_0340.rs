@@ -0,0 +1,3 @@
+//! @ We need to mention a procedure here that may be called by |get_next|.
+//!
+//! @p procedure@?firm_up_the_line; forward;
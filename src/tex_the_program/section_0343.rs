@@ -26,7 +26,7 @@ pub(crate) macro Input_from_external_file__goto_restart_if_no_input_found
                     //   or |goto reswitch| if the current character
                     //   changes to another@>;
                     crate::section_0344::Change_state_if_necessary_and_goto_switch_if_the_current_character_should_be_ignored_or_goto_reswitch_if_the_current_character_changes_to_another!
-                        ($globals, 'switch, 'reswitch);
+                        ($globals, $lbl_restart, 'switch, 'reswitch);
                     // end
                 }
                 |'reswitch|
@@ -41,6 +41,8 @@ pub(crate) macro Input_from_external_file__goto_restart_if_no_input_found
                     ($globals, $lbl_restart);
                 // check_interrupt;
                 check_interrupt!($globals);
+                // TeX-rs: a job that is out of time or has been cancelled stops here
+                crate::limits::check_limits($globals)?;
                 // goto switch;
                 crate::goto_backward_label!('switch);
                 // end;
@@ -14,7 +14,7 @@ pub(crate) macro state_plus_cur_cmd_matches_any_case_plus($state_plus_cur_cmd:ex
 //
 // @<Change state if necessary...@>=
 pub(crate) macro Change_state_if_necessary_and_goto_switch_if_the_current_character_should_be_ignored_or_goto_reswitch_if_the_current_character_changes_to_another {
-    ($globals:expr, $lbl_switch:lifetime, $lbl_reswitch:lifetime) => {{
+    ($globals:expr, $lbl_restart:lifetime, $lbl_switch:lifetime, $lbl_reswitch:lifetime) => {{
         crate::trace_span_verbose!("Change state if...");
         // case state+cur_cmd of
         let state_plus_cur_cmd = state!($globals) + $globals.cur_cmd;
@@ -42,7 +42,7 @@ pub(crate) macro Change_state_if_necessary_and_goto_switch_if_the_current_charac
         else if state_plus_cur_cmd_matches_any_case_plus!(state_plus_cur_cmd, invalid_char) {
             // any_state_plus(invalid_char): @<Decry the invalid character and
             //   |goto restart|@>;
-            todo!("invalid_char");
+            crate::section_0346::Decry_the_invalid_character_and_goto_restart!($globals, $lbl_restart);
         }
         // @t\4@>@<Handle situations involving spaces, braces, changes of state@>@;
         else if crate::section_0347::Handle_situations_involving_spaces_braces_changes_of_state!(
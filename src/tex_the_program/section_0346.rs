@@ -1,13 +1,28 @@
 //! @ We go to |restart| instead of to |switch|, because |state| might equal
 //! |token_list| after the error has been dealt with
 //! (cf.\ |clear_for_error_prompt|).
-//!
-//! @<Decry the invalid...@>=
-//! begin print_err("Text line contains an invalid character");
-//! @.Text line contains...@>
-//! help2("A funny symbol that I can't read has just been input.")@/
-//! ("Continue, and I'll forget that it ever happened.");@/
-//! deletions_allowed:=false; error; deletions_allowed:=true;
-//! goto restart;
-//! end
-//!
+//
+// @<Decry the invalid...@>=
+pub(crate) macro Decry_the_invalid_character_and_goto_restart($globals:expr, $lbl_restart:lifetime) {{
+    // begin print_err("Text line contains an invalid character");
+    print_err!(
+        $globals,
+        crate::strpool_str!("Text line contains an invalid character")
+    );
+    // @.Text line contains...@>
+    // help2("A funny symbol that I can't read has just been input.")@/
+    // ("Continue, and I'll forget that it ever happened.");@/
+    help2!(
+        $globals,
+        crate::strpool_str!("A funny symbol that I can't read has just been input."),
+        crate::strpool_str!("Continue, and I'll forget that it ever happened.")
+    );
+    // deletions_allowed:=false; error; deletions_allowed:=true;
+    error($globals)?;
+    // goto restart;
+    crate::goto_backward_label!($lbl_restart);
+    // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+}}
@@ -9,10 +9,15 @@ pub(crate) macro Finish_line__emit_a_par($globals:expr) {
     // cur_chr:=equiv(cur_cs);
     $globals.cur_chr = chr_code_type::new(equiv!($globals, $globals.cur_cs) as _);
     // if cur_cmd>=outer_call then check_outer_validity;
+    if $globals.cur_cmd >= outer_call {
+        check_outer_validity($globals)?;
+    }
     // end
     use crate::section_0036::loc;
+    use crate::section_0210::outer_call;
     use crate::section_0221::eq_type;
     use crate::section_0221::equiv;
     use crate::section_0297::chr_code_type;
     use crate::section_0302::limit;
+    use crate::section_0336::check_outer_validity;
 }
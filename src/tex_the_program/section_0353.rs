@@ -11,7 +11,7 @@ pub(crate) macro Process_an_active_character_control_sequence_and_set_state_mid_
     state!($globals) = mid_line;
     // if cur_cmd>=outer_call then check_outer_validity;
     if $globals.cur_cmd >= outer_call {
-        check_outer_validity($globals);
+        check_outer_validity($globals)?;
     }
     // end
     use crate::section_0210::*;
@@ -64,7 +64,11 @@ pub(crate) macro Scan_a_control_sequence_and_set_state_skip_blanks_or_mid_line {
                 // cur_cs:=single_base+buffer[loc]; incr(loc);
                 #[cfg(feature = "unicode_support")]
                 if $globals.buffer[loc!($globals)] > ASCII_code::from(255) {
-                    todo!("buffer item > 255, is {}", $globals.buffer[loc!($globals)].0);
+                    /// there is no `single_base` entry beyond 255, so look it up by name
+                    const _: () = ();
+                    $globals.cur_cs = id_lookup($globals, loc!($globals) as _, 1)?;
+                    incr!(loc!($globals));
+                    crate::goto_forward_label!('found);
                 }
                 $globals.cur_cs = single_base as halfword +
                     $globals.buffer[loc!($globals)].numeric_value() as halfword;
@@ -85,7 +89,7 @@ pub(crate) macro Scan_a_control_sequence_and_set_state_skip_blanks_or_mid_line {
         crate::trace_expr_verbose!("cur_chr = {:?}", $globals.cur_chr);
         // if cur_cmd>=outer_call then check_outer_validity;
         if $globals.cur_cmd >= outer_call {
-            check_outer_validity($globals);
+            check_outer_validity($globals)?;
             // end
         }
         use crate::section_0016::incr;
@@ -100,6 +104,8 @@ pub(crate) macro Scan_a_control_sequence_and_set_state_skip_blanks_or_mid_line {
         use crate::section_0210::outer_call;
         use crate::section_0221::eq_type;
         use crate::section_0230::cat_code;
+        #[cfg(feature = "unicode_support")]
+        use crate::section_0259::id_lookup;
         use crate::section_0302::limit;
         use crate::section_0336::check_outer_validity;
         use crate::section_0207::spacer;
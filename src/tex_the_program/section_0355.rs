@@ -52,7 +52,7 @@ pub(crate) macro If_an_expanded_code_is_present_reduce_it_and_goto_start_cs($glo
             crate::trace_expr_verbose!("d={}", d);
             // limit:=limit-d; first:=first-d;
             limit!($globals) -= d;
-            $globals.first -= d;
+            $globals.first -= d as u16;
             // while k<=limit do
             while $k <= limit!($globals) {
                 // begin buffer[k]:=buffer[k+d]; incr(k);
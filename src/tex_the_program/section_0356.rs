@@ -30,7 +30,7 @@ pub(crate) macro Scan_ahead_in_the_buffer_until_finding_a_nonletter__if_an_expan
     /// multiletter control sequence has been scanned
     if $k > loc!($globals) + 1 {
         // begin cur_cs:=id_lookup(loc,k-loc); loc:=k; goto found;
-        $globals.cur_cs = id_lookup($globals, loc!($globals) as _, ($k - loc!($globals)) as _);
+        $globals.cur_cs = id_lookup($globals, loc!($globals) as _, ($k - loc!($globals)) as _)?;
         crate::trace_expr_verbose!("cur_cs = {}", $globals.cur_cs);
         loc!($globals) = $k;
         crate::goto_forward_label!($lbl_found);
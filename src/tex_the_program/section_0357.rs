@@ -42,7 +42,7 @@ pub(crate) macro Input_from_token_list__goto_restart_if_end_of_list_or_if_a_para
                 }
                 // else check_outer_validity;
                 else {
-                    check_outer_validity($globals);
+                    check_outer_validity($globals)?;
                 }
             }
             // end
@@ -78,7 +78,7 @@ pub(crate) macro Input_from_token_list__goto_restart_if_end_of_list_or_if_a_para
         /// we are done with this token list
         const _: () = ();
         // end_token_list; goto restart; {resume previous level}
-        end_token_list($globals);
+        end_token_list($globals)?;
         /// resume previous level
         crate::goto_backward_label!($lbl_restart);
         //end
@@ -48,7 +48,7 @@ pub(crate) macro Move_to_next_line_of_file_or_goto_restart_if_there_is_no_next_l
         }
         // if selector<log_only then open_log_file;
         if $globals.selector < log_only {
-            open_log_file($globals);
+            open_log_file($globals)?;
         }
         // if interaction>nonstop_mode then
         if $globals.interaction > nonstop_mode {
@@ -67,13 +67,13 @@ pub(crate) macro Move_to_next_line_of_file_or_goto_restart_if_there_is_no_next_l
             // @.Please type...@>
             // print_ln; first:=start;
             print_ln(make_globals_io_string_log_view!($globals));
-            $globals.first = start!($globals).into();
+            $globals.first = (start!($globals) as u16).into();
             // prompt_input("*"); {input on-line into |buffer|}
             /// input on-line into `buffer`
             prompt_input!($globals, crate::strpool_str!("*"));
             // @.*\relax@>
             // limit:=last;
-            limit!($globals) = $globals.last.get();
+            limit!($globals) = $globals.last.get() as _;
             // if end_line_char_inactive then decr(limit)
             if end_line_char_inactive!($globals) {
                 decr!(limit!($globals));
@@ -82,17 +82,23 @@ pub(crate) macro Move_to_next_line_of_file_or_goto_restart_if_there_is_no_next_l
                 $globals.buffer[limit!($globals)] = end_line_char!($globals).into();
             }
             // first:=limit+1;
-            $globals.first = (limit!($globals) + 1).into();
+            $globals.first = (limit!($globals) as u16 + 1).into();
             // loc:=start;
             loc!($globals) = start!($globals);
             // end
         } else {
-            todo!("not yet implemented in {}", file!());
-            //   else fatal_error("*** (job aborted, no legal \end found)");
+            // else fatal_error("*** (job aborted, no legal \end found)");
             // @.job aborted@>
-            //     {nonstop mode, which is intended for overnight batch processing,
-            //     never waits for on-line input}
-            //   end
+            //   {nonstop mode, which is intended for overnight batch processing,
+            //   never waits for on-line input}
+            /// nonstop mode, which is intended for overnight batch processing,
+            /// never waits for on-line input
+            const _: () = ();
+            fatal_error(
+                $globals,
+                crate::strpool_str!("*** (job aborted, no legal \\end found)"),
+            )?;
+            // end
         }
     }
 
@@ -106,6 +112,7 @@ pub(crate) macro Move_to_next_line_of_file_or_goto_restart_if_there_is_no_next_l
     use crate::section_0062::print_nl;
     use crate::section_0071::prompt_input;
     use crate::section_0073::nonstop_mode;
+    use crate::section_0093::fatal_error;
     use crate::section_0236::end_line_char;
     use crate::section_0297::chr_code_type;
     use crate::section_0302::limit;
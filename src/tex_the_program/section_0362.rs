@@ -5,34 +5,49 @@
 pub(crate) macro Read_next_line_of_file_into_buffer_or_goto_restart_if_the_file_has_ended($globals:expr, $lbl_restart:lifetime) {{
     // begin incr(line); first:=start;
     incr!($globals.line);
-    $globals.first = start!($globals).into();
+    $globals.first = (start!($globals) as u16).into();
     // if not force_eof then
     if !$globals.force_eof {
         // begin if input_ln(cur_file,true) then {not end of file}
+        // TeX-rs: pseudo files of `\scantokens` in `latex_support` are read here too
+        #[cfg(feature = "latex_support")]
+        let not_eof = if name!($globals) <= 19 {
+            crate::latex_support::pseudo_input($globals)?
+        } else {
+            input_ln_or_report_overflow!($globals, &mut cur_file!($globals), true)
+        };
+        #[cfg(not(feature = "latex_support"))]
+        let not_eof = input_ln_or_report_overflow!($globals, &mut cur_file!($globals), true);
         /// not end of file
-        if input_ln(
-            make_globals_io_view!($globals),
-            &mut cur_file!($globals),
-            true,
-        ) {
+        if not_eof {
             // firm_up_the_line {this sets |limit|}
             /// this sets `limit`
             firm_up_the_line($globals);
         }
         // else force_eof:=true;
         else {
+            // TeX-rs: `\everyeof` of `latex_support` comes before the end of the file
+            #[cfg(feature = "latex_support")]
+            crate::latex_support::Insert_everyeof_once_and_goto_restart!($globals, $lbl_restart);
             $globals.force_eof = true;
         }
         // end;
     }
     // if force_eof then
     if $globals.force_eof {
+        // TeX-rs: pseudo files of `latex_support` with name 18 were not announced
+        #[cfg(feature = "latex_support")]
+        let announced = name!($globals) >= 19;
+        #[cfg(not(feature = "latex_support"))]
+        let announced = true;
         // begin print_char(")"); decr(open_parens);
-        print_char(
-            make_globals_io_string_log_view!($globals),
-            ASCII_code_literal!(b')'),
-        );
-        decr!($globals.open_parens);
+        if announced {
+            print_char(
+                make_globals_io_string_log_view!($globals),
+                ASCII_code_literal!(b')'),
+            );
+            decr!($globals.open_parens);
+        }
         crate::trace_expr_verbose!("open_parens = {:?}", $globals.open_parens);
         // update_terminal; {show user that file has been read}
         /// show user that file has been read
@@ -43,7 +58,7 @@ pub(crate) macro Read_next_line_of_file_into_buffer_or_goto_restart_if_the_file_
         /// resume previous level
         end_file_reading($globals);
         // check_outer_validity; goto restart;
-        check_outer_validity($globals);
+        check_outer_validity($globals)?;
         crate::goto_backward_label!($lbl_restart);
         // end;
     }
@@ -56,7 +71,7 @@ pub(crate) macro Read_next_line_of_file_into_buffer_or_goto_restart_if_the_file_
         $globals.buffer[limit!($globals)] = end_line_char!($globals).into();
     }
     // first:=limit+1; loc:=start; {ready to read}
-    $globals.first = (limit!($globals) + 1).into();
+    $globals.first = (limit!($globals) as u16 + 1).into();
     loc!($globals) = start!($globals);
     /// ready to read
     const _: () = ();
@@ -68,12 +83,13 @@ pub(crate) macro Read_next_line_of_file_into_buffer_or_goto_restart_if_the_file_
     use crate::section_0016::decr;
     use crate::section_0016::incr;
     use crate::section_0018::ASCII_code_literal;
-    use crate::section_0031::input_ln;
     use crate::section_0034::update_terminal;
+    use crate::section_0035::input_ln_or_report_overflow;
     use crate::section_0036::loc;
     use crate::section_0058::print_char;
     use crate::section_0236::end_line_char;
     use crate::section_0302::limit;
+    use crate::section_0302::name;
     use crate::section_0302::start;
     use crate::section_0304::cur_file;
     use crate::section_0329::end_file_reading;
@@ -9,7 +9,7 @@
 pub(crate) fn firm_up_the_line(globals: &mut TeXGlobals) {
     // var k:0..buf_size; {an index into |buffer|}
     // begin limit:=last;
-    limit!(globals) = globals.last.get();
+    limit!(globals) = globals.last.get() as _;
     // if pausing>0 then if interaction>nonstop_mode then
     //   begin wake_up_terminal; print_ln;
     //   if start<limit then for k:=start to limit-1 do print(buffer[k]);
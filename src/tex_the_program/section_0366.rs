@@ -53,6 +53,8 @@ pub(crate) fn expand(globals: &mut TeXGlobals) -> TeXResult<()> {
     backup_backup = link!(globals, backup_head);
     // if cur_cmd<call then @<Expand a nonmacro@>
     if globals.cur_cmd < call {
+        // TeX-rs: every expansion counts against the expansion step limit
+        crate::limits::count_expansion_step(globals)?;
         crate::section_0367::Expand_a_nonmacro!(globals);
     }
     // else if cur_cmd<end_template then macro_call
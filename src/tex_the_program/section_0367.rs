@@ -11,8 +11,19 @@ pub(crate) macro Expand_a_nonmacro($globals:expr) {{
         unreachable!();
     }
     // top_bot_mark:@<Insert the \(a)appropriate mark text into the scanner@>;
+    else if $globals.cur_cmd == top_bot_mark {
+        crate::section_0386::Insert_the_appropriate_mark_text_into_the_scanner!($globals);
+    }
     // expand_after:@<Expand the token after the next token@>;
     else if $globals.cur_cmd == expand_after {
+        // TeX-rs: `\unless` of `latex_support` shares this command code
+        #[cfg(feature = "latex_support")]
+        if $globals.cur_chr.get() != 0 {
+            crate::latex_support::Negate_a_boolean_conditional!($globals);
+        } else {
+            crate::section_0368::Expand_the_token_after_the_next_token!($globals);
+        }
+        #[cfg(not(feature = "latex_support"))]
         crate::section_0368::Expand_the_token_after_the_next_token!($globals);
     }
     // no_expand:@<Suppress expansion of the next token@>;
@@ -61,6 +72,7 @@ pub(crate) macro Expand_a_nonmacro($globals:expr) {{
     use crate::section_0210::input;
     use crate::section_0210::no_expand;
     use crate::section_0210::the;
+    use crate::section_0210::top_bot_mark;
     use crate::section_0236::tracing_commands;
     use crate::section_0299::show_cur_cmd_chr;
     use crate::section_0467::ins_the_toks;
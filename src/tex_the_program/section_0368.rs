@@ -13,11 +13,11 @@ pub(crate) macro Expand_the_token_after_the_next_token($globals:expr) {{
     if $globals.cur_cmd > max_command {
         expand($globals)?;
     } else {
-        back_input($globals);
+        back_input($globals)?;
     }
     // cur_tok:=t; back_input;
     $globals.cur_tok = t;
-    back_input($globals);
+    back_input($globals)?;
     // end
 
     use crate::section_0209::max_command;
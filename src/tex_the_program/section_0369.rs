@@ -23,13 +23,13 @@ pub(crate) macro Suppress_expansion_of_the_next_token($globals:expr) {{
     $globals.scanner_status = save_scanner_status;
     t = $globals.cur_tok;
     // back_input; {now |start| and |loc| point to the backed-up token |t|}
-    back_input($globals);
+    back_input($globals)?;
     /// now `start` and `loc` point to the backed-up token `t`
     const _: () = ();
     // if t>=cs_token_flag then
     if t.get_cs().is_some() {
         // begin p:=get_avail; info(p):=cs_token_flag+frozen_dont_expand;
-        p = get_avail($globals);
+        p = get_avail($globals)?;
         info_tok_assign!($globals, p, cur_tok_type::from_cs(frozen_dont_expand as _));
         // link(p):=loc; start:=p; loc:=p;
         link!($globals, p) = loc!($globals);
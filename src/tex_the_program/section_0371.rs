@@ -4,7 +4,7 @@
 
 // @d store_new_token(#)==begin q:=get_avail; link(p):=q; info(q):=#;
 pub(crate) macro store_new_token($globals:expr, $val:expr, $p:expr, $q:expr) {{
-    $q = get_avail($globals);
+    $q = get_avail($globals)?;
     link!($globals, $p) = $q;
     info_tok_assign!($globals, $q, cur_tok_type::new($val as _));
     // p:=q; {|link(p)| is |null|}
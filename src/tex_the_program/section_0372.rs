@@ -6,7 +6,7 @@ pub(crate) macro Manufacture_a_control_sequence_name($globals:expr) {{
     /// for list manipulation
     let (mut p, mut q, r): (pointer, pointer, pointer);
     // begin r:=get_avail; p:=r; {head of the list of characters}
-    r = get_avail($globals);
+    r = get_avail($globals)?;
     /// head of the list of characters
     const _: () = ();
     p = r;
@@ -24,7 +24,7 @@ pub(crate) macro Manufacture_a_control_sequence_name($globals:expr) {{
     }
     // if cur_cmd<>end_cs_name then @<Complain about missing \.{\\endcsname}@>;
     if $globals.cur_cmd != end_cs_name {
-        todo!("complain");
+        crate::section_0373::Complain_about_missing_endcsname!($globals);
     }
     // @<Look up the characters of list |r| in the hash table, and set |cur_cs|@>;
     crate::section_0374::Look_up_the_characters_of_list_r_in_the_hash_table__and_set_cur_cs!(
@@ -45,7 +45,7 @@ pub(crate) macro Manufacture_a_control_sequence_name($globals:expr) {{
     }
     // cur_tok:=cur_cs+cs_token_flag; back_input;
     $globals.cur_tok = cur_tok_type::from_cs($globals.cur_cs);
-    back_input($globals);
+    back_input($globals)?;
 
     // end
     use crate::section_0115::pointer;
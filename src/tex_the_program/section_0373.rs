@@ -1,8 +1,23 @@
 //! @ @<Complain about missing \.{\\endcsname}@>=
-//! begin print_err("Missing "); print_esc("endcsname"); print(" inserted");
-//! @.Missing \\endcsname...@>
-//! help2("The control sequence marked <to be read again> should")@/
-//!   ("not appear between \csname and \endcsname.");
-//! back_error;
-//! end
-//!
+pub(crate) macro Complain_about_missing_endcsname($globals:expr) {{
+    // begin print_err("Missing "); print_esc("endcsname"); print(" inserted");
+    print_err!($globals, crate::strpool_str!("Missing "));
+    print_esc($globals, crate::strpool_str!("endcsname"));
+    print($globals, crate::strpool_str!(" inserted").get() as _);
+    // @.Missing \\endcsname...@>
+    // help2("The control sequence marked <to be read again> should")@/
+    //   ("not appear between \csname and \endcsname.");
+    help2!(
+        $globals,
+        crate::strpool_str!("The control sequence marked <to be read again> should"),
+        crate::strpool_str!("not appear between \\csname and \\endcsname.")
+    );
+    // back_error;
+    back_error($globals)?;
+    // end
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0327::back_error;
+}}
@@ -15,9 +15,13 @@ pub(crate) macro Look_up_the_characters_of_list_r_in_the_hash_table__and_set_cur
             // begin max_buf_stack:=j+1;
             $globals.max_buf_stack = j + 1;
             // if max_buf_stack=buf_size then
-            if $globals.max_buf_stack == buf_size {
+            if $globals.max_buf_stack == $globals.buf_size {
                 // overflow("buffer size",buf_size);
-                todo!("overflow");
+                overflow(
+                    $globals,
+                    crate::strpool_str!("buffer size"),
+                    $globals.buf_size as _,
+                )?;
                 // @:TeX capacity exceeded buffer size}{\quad buffer size@>
             }
             // end;
@@ -37,7 +41,7 @@ pub(crate) macro Look_up_the_characters_of_list_r_in_the_hash_table__and_set_cur
             $globals,
             $globals.first.get() as integer,
             j.get() as integer - $globals.first.get() as integer,
-        );
+        )?;
         // no_new_control_sequence:=true;
         $globals.no_new_control_sequence = true;
     // end
@@ -52,18 +56,25 @@ pub(crate) macro Look_up_the_characters_of_list_r_in_the_hash_table__and_set_cur
     else {
         /// the list has length one
         const _: () = ();
-        if $globals.buffer[$globals.first].numeric_value() > 255 {
-            todo!("not yet implemented in {}", file!());
+        #[cfg(feature = "unicode_support")]
+        let beyond_single_base = $globals.buffer[$globals.first].numeric_value() > 255;
+        #[cfg(not(feature = "unicode_support"))]
+        let beyond_single_base = false;
+        if beyond_single_base {
+            $globals.no_new_control_sequence = false;
+            $globals.cur_cs = id_lookup($globals, $globals.first.get() as integer, 1)?;
+            $globals.no_new_control_sequence = true;
+        } else {
+            $globals.cur_cs =
+                (single_base + $globals.buffer[$globals.first].numeric_value()) as pointer;
         }
-        $globals.cur_cs =
-            (single_base + $globals.buffer[$globals.first].numeric_value()) as pointer;
     }
 
     use crate::pascal::integer;
     use crate::pascal::u16_from_0_to_n;
-    use crate::section_0011::buf_size;
     use crate::section_0011::buf_size_TYPENUM;
     use crate::section_0016::incr;
+    use crate::section_0094::overflow;
     use crate::section_0115::null;
     use crate::section_0115::pointer;
     use crate::section_0118::info_tok;
@@ -8,7 +8,7 @@
 pub(crate) macro Insert_a_token_containing_frozen_endv($globals:expr) {{
     // begin cur_tok:=cs_token_flag+frozen_endv; back_input;
     $globals.cur_tok = cur_tok_type::from_cs(frozen_endv as _);
-    back_input($globals);
+    back_input($globals)?;
     // end
     use crate::section_0222::frozen_endv;
     use crate::section_0297::cur_tok_type;
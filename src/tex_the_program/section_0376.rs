@@ -6,10 +6,10 @@
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0376($globals:expr) {{
     let globals = &mut *$globals;
     // primitive("input",input,0);@/
-    primitive(globals, crate::strpool_str!("input"), input, 0);
+    primitive(globals, crate::strpool_str!("input"), input, 0)?;
     // @!@:input_}{\.{\\input} primitive@>
     // primitive("endinput",input,1);@/
-    primitive(globals, crate::strpool_str!("endinput"), input, 1);
+    primitive(globals, crate::strpool_str!("endinput"), input, 1)?;
     // @!@:end_input_}{\.{\\endinput} primitive@>
 }}
 
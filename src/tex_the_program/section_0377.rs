@@ -7,6 +7,14 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0377
         if $chr_code.get() == 0 {
             print_esc($globals, crate::strpool_str!("input"));
         } else {
+            // TeX-rs: `\scantokens` of `latex_support` shares this command code
+            #[cfg(feature = "latex_support")]
+            if $chr_code.get() == crate::latex_support::scan_tokens_code {
+                print_esc($globals, crate::strpool_str!("scantokens"));
+            } else {
+                print_esc($globals, crate::strpool_str!("endinput"));
+            }
+            #[cfg(not(feature = "latex_support"))]
             print_esc($globals, crate::strpool_str!("endinput"));
         }
         true
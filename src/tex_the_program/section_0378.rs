@@ -4,11 +4,21 @@
 pub(crate) macro Initiate_or_terminate_input_from_a_file($globals:expr) {{
     // if cur_chr>0 then force_eof:=true
     if $globals.cur_chr.get() > 0 {
-        $globals.force_eof = true;
+        // TeX-rs: `\scantokens` of `latex_support` shares this command code
+        #[cfg(feature = "latex_support")]
+        if $globals.cur_chr.get() == crate::latex_support::scan_tokens_code {
+            crate::latex_support::pseudo_start($globals)?;
+        } else {
+            $globals.force_eof = true;
+        }
+        #[cfg(not(feature = "latex_support"))]
+        {
+            $globals.force_eof = true;
+        }
     }
     // else if name_in_progress then insert_relax
     else if $globals.name_in_progress {
-        insert_relax($globals);
+        insert_relax($globals)?;
     }
     // else start_input
     else {
@@ -0,0 +1,36 @@
+//! @ @<Cases of |print_cmd_chr|...@>=
+pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0385($globals:expr, $cmd:expr, $chr_code:expr) {{
+    // top_bot_mark: case chr_code of
+    let processed = if $cmd == top_bot_mark {
+        let chr_code = $chr_code.get();
+        // first_mark_code: print_esc("firstmark");
+        if chr_code == mark_code_kind::first_mark_code as chr_code_repr {
+            print_esc($globals, crate::strpool_str!("firstmark"));
+        }
+        // bot_mark_code: print_esc("botmark");
+        else if chr_code == mark_code_kind::bot_mark_code as chr_code_repr {
+            print_esc($globals, crate::strpool_str!("botmark"));
+        }
+        // split_first_mark_code: print_esc("splitfirstmark");
+        else if chr_code == mark_code_kind::split_first_mark_code as chr_code_repr {
+            print_esc($globals, crate::strpool_str!("splitfirstmark"));
+        }
+        // split_bot_mark_code: print_esc("splitbotmark");
+        else if chr_code == mark_code_kind::split_bot_mark_code as chr_code_repr {
+            print_esc($globals, crate::strpool_str!("splitbotmark"));
+        }
+        // othercases print_esc("topmark")
+        else {
+            print_esc($globals, crate::strpool_str!("topmark"));
+        }
+        // endcases;
+        true
+    } else {
+        false
+    };
+    use crate::section_0063::print_esc;
+    use crate::section_0210::top_bot_mark;
+    use crate::section_0297::chr_code_repr;
+    use crate::section_0382::mark_code_kind;
+    processed
+}}
@@ -0,0 +1,16 @@
+//! @ The following code is activated when |cur_cmd=top_bot_mark| and
+//! when |cur_chr| is a code like |top_mark_code|.
+//
+// @<Insert the \(a)appropriate mark text into the scanner@>=
+pub(crate) macro Insert_the_appropriate_mark_text_into_the_scanner($globals:expr) {{
+    let c = $globals.cur_chr.get() as u8;
+    // begin if cur_mark[cur_chr]<>null then
+    if $globals.cur_mark[c] != null {
+        // begin_token_list(cur_mark[cur_chr],mark_text);
+        begin_token_list($globals, $globals.cur_mark[c], mark_text);
+    }
+    // end
+    use crate::section_0115::null;
+    use crate::section_0307::mark_text;
+    use crate::section_0323::begin_token_list;
+}}
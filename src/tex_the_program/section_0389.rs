@@ -19,6 +19,8 @@
 #[allow(unused_assignments)]
 #[cfg_attr(feature = "trace", tracing::instrument(level = "trace", skip(globals)))]
 pub(crate) fn macro_call(globals: &mut TeXGlobals) -> TeXResult<()> {
+    // TeX-rs: every macro call counts against the expansion step limit
+    crate::limits::count_expansion_step(globals)?;
     /// `scanner_status` upon entry
     let mut save_scanner_status: scanner_status_kind = Default::default();
     /// `warning_index` upon entry
@@ -80,6 +82,11 @@ pub(crate) fn macro_call(globals: &mut TeXGlobals) -> TeXResult<()> {
         if tracing_macros!(globals) > 0 {
             crate::section_0401::Show_the_text_of_the_macro_being_expanded!(globals, ref_count);
         }
+        // TeX-rs: skip the mark of a `\protected` macro of `latex_support`
+        #[cfg(feature = "latex_support")]
+        if info_tok!(globals, r).get() == crate::latex_support::protected_token {
+            r = link!(globals, r);
+        }
         let mut info_r = info_tok!(globals, r);
         // if info(r)<>end_match_token then
         if info_r != end_match_token {
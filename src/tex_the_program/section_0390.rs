@@ -11,7 +11,7 @@ pub(crate) macro Feed_the_macro_body_and_its_parameters_to_the_scanner($globals:
     {
         // end_token_list; {conserve stack space}
         /// conserve stack space
-        end_token_list($globals);
+        end_token_list($globals)?;
     }
     // begin_token_list(ref_count,macro); name:=warning_index; loc:=link(r);
     begin_token_list($globals, $ref_count, r#macro);
@@ -25,8 +25,12 @@ pub(crate) macro Feed_the_macro_body_and_its_parameters_to_the_scanner($globals:
             $globals.max_param_stack = ($globals.param_ptr.get() + $n.get()) as _;
             // if max_param_stack>param_size then
             if $globals.max_param_stack > param_size as _ {
-                todo!("overflow");
                 //   overflow("parameter stack size",param_size);
+                overflow(
+                    $globals,
+                    crate::strpool_str!("parameter stack size"),
+                    param_size as integer,
+                )?;
                 // @:TeX capacity exceeded parameter stack size}{\quad parameter stack size@>
             }
             // end;
@@ -39,8 +43,10 @@ pub(crate) macro Feed_the_macro_body_and_its_parameters_to_the_scanner($globals:
         $globals.param_ptr = $globals.param_ptr + $n.get();
         // end
     }
+    use crate::pascal::integer;
     use crate::section_0011::param_size;
     use crate::section_0036::loc;
+    use crate::section_0094::overflow;
     use crate::section_0115::null;
     use crate::section_0118::link;
     use crate::section_0302::name;
@@ -33,18 +33,18 @@ pub(crate) macro Scan_a_parameter_until_its_delimiter_string_has_been_found_or_i
                         // if cur_tok=par_token then if long_state<>long_call then
                         if $globals.cur_tok == $globals.par_token && $globals.long_state != long_call {
                             // @<Report a runaway argument and abort@>;
-                            todo!("report");
+                            crate::section_0396::Report_a_runaway_argument_and_abort!($globals, $n, $m, $unbalance);
                         }
                         // if cur_tok<right_brace_limit then
                         if $globals.cur_tok < right_brace_limit {
                             // if cur_tok<left_brace_limit then
                             if $globals.cur_tok < left_brace_limit {
                                 // @<Contribute an entire group to the current parameter@>
-                                crate::section_0399::Contribute_an_entire_group_to_the_current_parameter!($globals, $p, $q, $unbalance, rbrace_ptr);
+                                crate::section_0399::Contribute_an_entire_group_to_the_current_parameter!($globals, $p, $q, $m, $n, $unbalance, rbrace_ptr);
                             }
                             // else @<Report an extra right brace and |goto continue|@>
                             else {
-                                todo!("extra brace");
+                                crate::section_0395::Report_an_extra_right_brace_and_goto_continue!($globals, 'continue_);
                             }
                         }
                         // else @<Store the current token, but |goto continue| if it is
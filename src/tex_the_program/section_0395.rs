@@ -0,0 +1,42 @@
+//! ` `
+// @<Report an extra right brace and |goto continue|@>=
+pub(crate) macro Report_an_extra_right_brace_and_goto_continue($globals:expr, $lbl_continue:lifetime) {{
+    // begin back_input; print_err("Argument of "); sprint_cs(warning_index);
+    back_input($globals)?;
+    print_err!($globals, crate::strpool_str!("Argument of "));
+    sprint_cs($globals, $globals.warning_index);
+    // @.Argument of \\x has...@>
+    // print(" has an extra }");
+    print($globals, crate::strpool_str!(" has an extra }").get() as _);
+    // help6("I've run across a `}' that doesn't seem to match anything.")@/
+    //   ("For example, `\def\a#1{...}' and `\a}' would produce")@/
+    //   ("this error. If you simply proceed now, the `\par' that")@/
+    //   ("I've just inserted will cause me to report a runaway")@/
+    //   ("argument that might be the root of the problem. But if")@/
+    //   ("your `}' was spurious, just type `2' and it will go away.");
+    help6!(
+        $globals,
+        crate::strpool_str!("I've run across a `}' that doesn't seem to match anything."),
+        crate::strpool_str!("For example, `\\def\\a#1{...}' and `\\a}' would produce"),
+        crate::strpool_str!("this error. If you simply proceed now, the `\\par' that"),
+        crate::strpool_str!("I've just inserted will cause me to report a runaway"),
+        crate::strpool_str!("argument that might be the root of the problem. But if"),
+        crate::strpool_str!("your `}' was spurious, just type `2' and it will go away.")
+    );
+    // incr(align_state); long_state:=call; cur_tok:=par_token; ins_error;
+    incr!($globals.align_state);
+    $globals.long_state = call.into();
+    $globals.cur_tok = $globals.par_token;
+    ins_error($globals)?;
+    // goto continue;
+    crate::goto_backward_label!($lbl_continue);
+    // end {a white lie; the \.{\\par} won't always trigger a runaway}
+    use crate::section_0016::incr;
+    use crate::section_0059::print;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help6;
+    use crate::section_0210::call;
+    use crate::section_0263::sprint_cs;
+    use crate::section_0325::back_input;
+    use crate::section_0327::ins_error;
+}}
@@ -0,0 +1,53 @@
+//! @ If |long_state=outer_call|, a runaway argument has already been reported.
+//
+// @<Report a runaway argument and abort@>=
+pub(crate) macro Report_a_runaway_argument_and_abort($globals:expr, $n:expr, $m:expr, $unbalance:expr) {{
+    // begin if long_state=call then
+    if $globals.long_state == call {
+        // begin runaway; print_err("Paragraph ended before ");
+        runaway($globals);
+        print_err!($globals, crate::strpool_str!("Paragraph ended before "));
+        // @.Paragraph ended before...@>
+        // sprint_cs(warning_index); print(" was complete");
+        sprint_cs($globals, $globals.warning_index);
+        print($globals, crate::strpool_str!(" was complete").get() as _);
+        // help3("I suspect you've forgotten a `}', causing me to apply this")@/
+        //   ("control sequence to too much text. How can we recover?")@/
+        //   ("My plan is to forget the whole thing and hope for the best.");
+        help3!(
+            $globals,
+            crate::strpool_str!("I suspect you've forgotten a `}', causing me to apply this"),
+            crate::strpool_str!("control sequence to too much text. How can we recover?"),
+            crate::strpool_str!("My plan is to forget the whole thing and hope for the best.")
+        );
+        // back_error;
+        back_error($globals)?;
+        // end;
+    }
+    // pstack[n]:=link(temp_head); align_state:=align_state-unbalance;
+    $globals.pstack[$n.get()] = link!($globals, temp_head);
+    $globals.align_state = $globals.align_state - $unbalance as integer;
+    // for m:=0 to n do flush_list(pstack[m]);
+    $m = 0;
+    while $m <= $n.get() as halfword {
+        let p = $globals.pstack[$m as u8];
+        flush_list($globals, p);
+        incr!($m);
+    }
+    // return;
+    crate::return_nojump!();
+    // end
+    use crate::pascal::integer;
+    use crate::section_0016::incr;
+    use crate::section_0059::print;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
+    use crate::section_0113::halfword;
+    use crate::section_0118::link;
+    use crate::section_0123::flush_list;
+    use crate::section_0162::temp_head;
+    use crate::section_0210::call;
+    use crate::section_0263::sprint_cs;
+    use crate::section_0306::runaway;
+    use crate::section_0327::back_error;
+}}
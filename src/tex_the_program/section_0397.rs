@@ -47,6 +47,7 @@ pub(crate) macro Contribute_the_recently_matched_tokens_to_the_current_parameter
                                     // else  begin r:=link(v); goto continue;
                                     else {
                                         $r = link!($globals, v);
+                                        $info_r = info_tok!($globals, $r);
                                         crate::goto_backward_label!($lbl_continue);
                                         // end;
                                     }
@@ -2,7 +2,7 @@
 
 // @<Contribute an entire group to the current parameter@>=
 pub(crate) macro Contribute_an_entire_group_to_the_current_parameter {
-    ($globals:expr, $p:expr, $q:expr, $unbalance:expr, $rbrace_ptr:expr) => {{
+    ($globals:expr, $p:expr, $q:expr, $m:expr, $n:expr, $unbalance:expr, $rbrace_ptr:expr) => {{
         // begin unbalance:=1;
         $unbalance = 1;
         crate::region_forward_label!(
@@ -18,7 +18,7 @@ pub(crate) macro Contribute_an_entire_group_to_the_current_parameter {
                     if $globals.cur_tok == $globals.par_token {
                         if $globals.long_state != long_call {
                             // @<Report a runaway argument and abort@>;
-                            todo!("report a runaway...");
+                            crate::section_0396::Report_a_runaway_argument_and_abort!($globals, $n, $m, $unbalance);
                         }
                     }
                     // if cur_tok<right_brace_limit then
@@ -19,8 +19,20 @@ pub(crate) fn scan_left_brace(globals: &mut TeXGlobals) -> TeXResult<()> {
         //   ("You might want to delete and/or insert some corrections")@/
         //   ("so that I will find a matching right brace soon.")@/
         //   ("(If you're confused by all this, try typing `I}' now.)");
+        help4!(
+            globals,
+            crate::strpool_str!("A left brace was mandatory here, so I've put one in."),
+            crate::strpool_str!("You might want to delete and/or insert some corrections"),
+            crate::strpool_str!("so that I will find a matching right brace soon."),
+            crate::strpool_str!("(If you're confused by all this, try typing `I}' now.)")
+        );
         // back_error; cur_tok:=left_brace_token+"{"; cur_cmd:=left_brace;
+        back_error(globals)?;
+        globals.cur_tok = cur_tok_type::new(left_brace_token + b'{' as cur_tok_repr);
+        globals.cur_cmd = left_brace;
         // cur_chr:="{"; incr(align_state);
+        globals.cur_chr = chr_code_type::new(b'{' as _);
+        incr!(globals.align_state);
         // end;
     }
     // end;
@@ -28,6 +40,13 @@ pub(crate) fn scan_left_brace(globals: &mut TeXGlobals) -> TeXResult<()> {
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0016::incr;
 use crate::section_0073::print_err;
+use crate::section_0079::help4;
 use crate::section_0081::TeXResult;
 use crate::section_0207::left_brace;
+use crate::section_0289::left_brace_token;
+use crate::section_0297::chr_code_type;
+use crate::section_0297::cur_tok_repr;
+use crate::section_0297::cur_tok_type;
+use crate::section_0327::back_error;
@@ -9,7 +9,7 @@ pub(crate) fn scan_optional_equals(globals: &mut TeXGlobals) -> TeXResult<()> {
     crate::section_0406::Get_the_next_non_blank_non_call_token!(globals);
     // if cur_tok<>other_token+"=" then back_input;
     if globals.cur_tok.get() != other_token + b'=' as cur_tok_repr {
-        back_input(globals);
+        back_input(globals)?;
     }
     // end;
     crate::return_nojump!();
@@ -81,7 +81,7 @@ pub(crate) fn scan_keyword(globals: &mut TeXGlobals, s: str_number) -> TeXResult
         // else if (cur_cmd<>spacer)or(p<>backup_head) then
         else if globals.cur_cmd != spacer || p != backup_head {
             // begin back_input;
-            back_input(globals);
+            back_input(globals)?;
             // if p<>backup_head then back_list(link(backup_head));
             if p != backup_head {
                 back_list!(globals, link!(globals, backup_head));
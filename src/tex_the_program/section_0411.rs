@@ -13,7 +13,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0411($globals
         crate::strpool_str!("count"),
         register,
         int_val as _,
-    );
+    )?;
     // @!@:count_}{\.{\\count} primitive@>
     // primitive("dimen",register,dimen_val);
     primitive(
@@ -21,7 +21,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0411($globals
         crate::strpool_str!("dimen"),
         register,
         dimen_val as _,
-    );
+    )?;
     // @!@:dimen_}{\.{\\dimen} primitive@>
     // primitive("skip",register,glue_val);
     primitive(
@@ -29,7 +29,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0411($globals
         crate::strpool_str!("skip"),
         register,
         glue_val as _,
-    );
+    )?;
     // @!@:skip_}{\.{\\skip} primitive@>
     // primitive("muskip",register,mu_val);
     primitive(
@@ -37,7 +37,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0411($globals
         crate::strpool_str!("muskip"),
         register,
         mu_val as _,
-    );
+    )?;
     // @!@:mu_skip_}{\.{\\muskip} primitive@>
     use cur_val_level_kind::*;
 }}
@@ -4,7 +4,14 @@
 pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0412($globals:expr, $cmd:expr, $chr_code:expr) {{
     // register: if chr_code=int_val then print_esc("count")
     let processed = if $cmd == register {
-        if $chr_code.get() == int_val as chr_code_repr {
+        // TeX-rs: registers above 255 defined with `\countdef` and so on
+        #[cfg(feature = "latex_support")]
+        let sa_printed = crate::latex_support::print_sa_register($globals, $chr_code.get());
+        #[cfg(not(feature = "latex_support"))]
+        let sa_printed = false;
+        if sa_printed {
+            // already printed
+        } else if $chr_code.get() == int_val as chr_code_repr {
             print_esc($globals, crate::strpool_str!("count"));
         }
         // else if chr_code=dimen_val then print_esc("dimen")
@@ -23,6 +23,8 @@ pub(crate) fn scan_something_internal(
 ) -> TeXResult<()> {
     // var m:halfword; {|chr_code| part of the operand token}
     /// `chr_code` part of the operand token
+    // TeX-rs: only the `\toks` case without `latex_support` changes `m`
+    #[cfg_attr(feature = "latex_support", allow(unused_mut))]
     let mut m: chr_code_type;
     // @!p:0..nest_size; {index into |nest|}
     // begin m:=cur_chr;
@@ -79,7 +81,7 @@ pub(crate) fn scan_something_internal(
     }
     // set_aux: @<Fetch the |space_factor| or the |prev_depth|@>;
     else if globals.cur_cmd == set_aux {
-        crate::section_0418::Fetch_the_space_factor_or_the_prev_depth!(globals, m);
+        crate::section_0418::Fetch_the_space_factor_or_the_prev_depth!(globals, level, m);
     }
     // set_prev_graf: @<Fetch the |prev_graf|@>;
     else if globals.cur_cmd == set_prev_graf {
@@ -95,7 +97,7 @@ pub(crate) fn scan_something_internal(
     }
     // set_shape: @<Fetch the |par_shape| size@>;
     else if globals.cur_cmd == set_shape {
-        todo!("Fetch the |par_shape| size");
+        crate::section_0423::Fetch_the_par_shape_size!(globals);
     }
     // set_box_dimen: @<Fetch a box dimension@>;
     else if globals.cur_cmd == set_box_dimen {
@@ -123,6 +125,11 @@ pub(crate) fn scan_something_internal(
     }
     // last_item: @<Fetch an item in the current node, if appropriate@>;
     else if globals.cur_cmd == last_item {
+        // TeX-rs: the expressions added by `latex_support`
+        #[cfg(feature = "latex_support")]
+        if m.get() >= crate::latex_support::eTeX_expr as _ {
+            crate::latex_support::Process_an_expression_and_return!(globals, m, level, negative);
+        }
         crate::section_0424::Fetch_an_item_in_the_current_node__if_appropriate!(globals);
     }
     // othercases @<Complain that \.{\\the} can't do this; give zero result@>
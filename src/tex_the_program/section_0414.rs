@@ -6,13 +6,21 @@ pub(crate) macro Fetch_a_character_code_from_some_table($globals:expr, $m:expr)
     scan_char_num($globals, true)?;
     // if m=math_code_base then scanned_result(ho(math_code(cur_val)))(int_val)
     if $m.get() as integer == math_code_base as integer {
-        todo!("a");
+        scanned_result!(
+            $globals,
+            ho!(math_code!($globals, ASCII_code::from($globals.cur_val))) as _,
+            cur_val_level_kind::int_val
+        );
     }
     // else if m<math_code_base then scanned_result(equiv(m+cur_val))(int_val)
     else if ($m.get() as integer) < math_code_base as integer {
         scanned_result!(
             $globals,
-            equiv!($globals, $m.get() as integer + $globals.cur_val) as _,
+            equiv_field!(code_table_word(
+                $globals,
+                $m.get() as word,
+                ASCII_code::from($globals.cur_val)
+            )) as _,
             cur_val_level_kind::int_val
         );
     }
@@ -20,15 +28,23 @@ pub(crate) macro Fetch_a_character_code_from_some_table($globals:expr, $m:expr)
     else {
         scanned_result!(
             $globals,
-            $globals.eqtb[($m.get() as integer + $globals.cur_val) as pointer][MEMORY_WORD_INT],
+            code_table_word(
+                $globals,
+                $m.get() as word,
+                ASCII_code::from($globals.cur_val)
+            )[MEMORY_WORD_INT],
             cur_val_level_kind::int_val
         );
     }
     // end
     use crate::pascal::integer;
+    use crate::pascal::word;
+    use crate::section_0018::ASCII_code;
+    use crate::section_0112::ho;
     use crate::section_0113::MEMORY_WORD_INT;
-    use crate::section_0115::pointer;
-    use crate::section_0221::equiv;
+    use crate::section_0221::equiv_field;
+    use crate::section_0230::code_table_word;
+    use crate::section_0230::math_code;
     use crate::section_0230::math_code_base;
     use crate::section_0410::cur_val_level_kind;
     use crate::section_0413::scanned_result;
@@ -4,14 +4,25 @@
 pub(crate) macro Fetch_a_token_list_or_font_identifier__provided_that_level_is_tok_val($globals:expr, $level:expr, $m:expr) {{
     // if level<>tok_val then
     if $level.get() as integer != cur_val_level_kind::tok_val as integer {
-        todo!("fetch 1");
         //   begin print_err("Missing number, treated as zero");
+        print_err!(
+            $globals,
+            crate::strpool_str!("Missing number, treated as zero")
+        );
         // @.Missing number...@>
         //   help3("A number should have been here; I inserted `0'.")@/
         //     ("(If you can't figure out why I needed to see a number,")@/
         //     ("look up `weird error' in the index to The TeXbook.)");
+        help3!(
+            $globals,
+            crate::strpool_str!("A number should have been here; I inserted `0'."),
+            crate::strpool_str!("(If you can't figure out why I needed to see a number,"),
+            crate::strpool_str!("look up `weird error' in the index to The TeXbook.)")
+        );
         // @:TeXbook}{\sl The \TeX book@>
         //   back_error; scanned_result(0)(dimen_val);
+        back_error($globals)?;
+        scanned_result!($globals, 0, cur_val_level_kind::dimen_val);
         //   end
     }
     // else if cur_cmd<=assign_toks then
@@ -20,22 +31,38 @@ pub(crate) macro Fetch_a_token_list_or_font_identifier__provided_that_level_is_t
         if $globals.cur_cmd < assign_toks {
             /// `cur_cmd=toks_register`
             const _: () = ();
-            // begin scan_eight_bit_int; m:=toks_base+cur_val;
-            scan_eight_bit_int($globals)?;
-            $m = chr_code_type::new((toks_base as integer + $globals.cur_val) as _);
-            // end;
+            // TeX-rs: `latex_support` keeps `\toks` registers above 255 in a sparse array
+            #[cfg(feature = "latex_support")]
+            {
+                let q = crate::latex_support::fetch_toks_register($globals, $m.get())?;
+                scanned_result!($globals, q as _, cur_val_level_kind::tok_val);
+            }
+            #[cfg(not(feature = "latex_support"))]
+            {
+                // begin scan_eight_bit_int; m:=toks_base+cur_val;
+                scan_eight_bit_int($globals)?;
+                $m = chr_code_type::new((toks_base as integer + $globals.cur_val) as _);
+                // end;
+                scanned_result!(
+                    $globals,
+                    equiv!($globals, $m.get() as pointer) as _,
+                    cur_val_level_kind::tok_val
+                );
+            }
         }
         // scanned_result(equiv(m))(tok_val);
-        scanned_result!(
-            $globals,
-            equiv!($globals, $m.get() as pointer) as _,
-            cur_val_level_kind::tok_val
-        );
+        else {
+            scanned_result!(
+                $globals,
+                equiv!($globals, $m.get() as pointer) as _,
+                cur_val_level_kind::tok_val
+            );
+        }
         // end
     }
     // else  begin back_input; scan_font_ident;
     else {
-        back_input($globals);
+        back_input($globals)?;
         scan_font_ident($globals)?;
         // scanned_result(font_id_base+cur_val)(ident_val);
         scanned_result!(
@@ -46,15 +73,21 @@ pub(crate) macro Fetch_a_token_list_or_font_identifier__provided_that_level_is_t
         // end
     }
     use crate::pascal::integer;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
     use crate::section_0115::pointer;
     use crate::section_0209::*;
     use crate::section_0221::equiv;
     use crate::section_0222::font_id_base;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0230::toks_base;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0297::chr_code_type;
     use crate::section_0325::back_input;
+    use crate::section_0327::back_error;
     use crate::section_0410::cur_val_level_kind;
     use crate::section_0413::scanned_result;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0433::scan_eight_bit_int;
     use crate::section_0577::scan_font_ident;
 }}
@@ -34,7 +34,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("spacefactor"),
         set_aux,
         hmode as _,
-    );
+    )?;
     // @!@:space_factor_}{\.{\\spacefactor} primitive@>
     // primitive("prevdepth",set_aux,vmode);@/
     primitive(
@@ -42,10 +42,10 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("prevdepth"),
         set_aux,
         vmode as _,
-    );
+    )?;
     // @!@:prev_depth_}{\.{\\prevdepth} primitive@>
     // primitive("deadcycles",set_page_int,0);
-    primitive(globals, crate::strpool_str!("deadcycles"), set_page_int, 0);
+    primitive(globals, crate::strpool_str!("deadcycles"), set_page_int, 0)?;
     // @!@:dead_cycles_}{\.{\\deadcycles} primitive@>
     // primitive("insertpenalties",set_page_int,1);
     primitive(
@@ -53,7 +53,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("insertpenalties"),
         set_page_int,
         1,
-    );
+    )?;
     // @!@:insert_penalties_}{\.{\\insertpenalties} primitive@>
     // primitive("wd",set_box_dimen,width_offset);
     primitive(
@@ -61,7 +61,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("wd"),
         set_box_dimen,
         width_offset as _,
-    );
+    )?;
     // @!@:wd_}{\.{\\wd} primitive@>
     // primitive("ht",set_box_dimen,height_offset);
     primitive(
@@ -69,7 +69,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("ht"),
         set_box_dimen,
         height_offset as _,
-    );
+    )?;
     // @!@:ht_}{\.{\\ht} primitive@>
     // primitive("dp",set_box_dimen,depth_offset);
     primitive(
@@ -77,7 +77,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("dp"),
         set_box_dimen,
         depth_offset as _,
-    );
+    )?;
     // @!@:dp_}{\.{\\dp} primitive@>
     // primitive("lastpenalty",last_item,int_val);
     primitive(
@@ -85,7 +85,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("lastpenalty"),
         last_item,
         last_item_command_kind::int_val as _,
-    );
+    )?;
     // @!@:last_penalty_}{\.{\\lastpenalty} primitive@>
     // primitive("lastkern",last_item,dimen_val);
     primitive(
@@ -93,7 +93,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("lastkern"),
         last_item,
         last_item_command_kind::dimen_val as _,
-    );
+    )?;
     // @!@:last_kern_}{\.{\\lastkern} primitive@>
     // primitive("lastskip",last_item,glue_val);
     primitive(
@@ -101,7 +101,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("lastskip"),
         last_item,
         last_item_command_kind::glue_val as _,
-    );
+    )?;
     // @!@:last_skip_}{\.{\\lastskip} primitive@>
     // primitive("inputlineno",last_item,input_line_no_code);
     primitive(
@@ -109,7 +109,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("inputlineno"),
         last_item,
         last_item_command_kind::input_line_no_code as _,
-    );
+    )?;
     // @!@:input_line_no_}{\.{\\inputlineno} primitive@>
     // primitive("badness",last_item,badness_code);
     primitive(
@@ -117,7 +117,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0416($globals
         crate::strpool_str!("badness"),
         last_item,
         last_item_command_kind::badness_code as _,
-    );
+    )?;
     // @!@:badness_}{\.{\\badness} primitive@>
 }}
 
@@ -18,7 +18,16 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0417
         true
     }
     // set_page_int: if chr_code=0 then print_esc("deadcycles")
-    // @+else print_esc("insertpenalties");
+    else if $cmd == set_page_int {
+        if $chr_code.get() == 0 {
+            print_esc($globals, crate::strpool_str!("deadcycles"));
+        }
+        // @+else print_esc("insertpenalties");
+        else {
+            print_esc($globals, crate::strpool_str!("insertpenalties"));
+        }
+        true
+    }
     // set_box_dimen: if chr_code=width_offset then print_esc("wd")
     else if $cmd == set_box_dimen {
         if $chr_code.get() == width_offset as chr_code_repr {
@@ -57,7 +66,14 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0417
         }
         // othercases print_esc("badness")
         else {
-            print_esc($globals, crate::strpool_str!("badness"));
+            // TeX-rs: the expressions added by `latex_support`
+            #[cfg(feature = "latex_support")]
+            let processed = crate::latex_support::print_last_item($globals, $chr_code.get());
+            #[cfg(not(feature = "latex_support"))]
+            let processed = false;
+            if !processed {
+                print_esc($globals, crate::strpool_str!("badness"));
+            }
         }
         // endcases;
         use crate::section_0297::chr_code_repr;
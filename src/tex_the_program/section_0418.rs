@@ -1,20 +1,35 @@
 //! ` `
 // @<Fetch the |space_factor| or the |prev_depth|@>=
-pub(crate) macro Fetch_the_space_factor_or_the_prev_depth($globals:expr, $m:expr) {{
+pub(crate) macro Fetch_the_space_factor_or_the_prev_depth($globals:expr, $level:expr, $m:expr) {{
     // if abs(mode)<>m then
     if mode!($globals).get().abs() != $m.get() as _ {
         // begin print_err("Improper "); print_cmd_chr(set_aux,m);
+        print_err!($globals, crate::strpool_str!("Improper "));
+        print_cmd_chr($globals, set_aux, $m);
         // @.Improper \\spacefactor@>
         // @.Improper \\prevdepth@>
         // help4("You can refer to \spacefactor only in horizontal mode;")@/
         //   ("you can refer to \prevdepth only in vertical mode; and")@/
         //   ("neither of these is meaningful inside \write. So")@/
         //   ("I'm forgetting what you said and using zero instead.");
+        help4!(
+            $globals,
+            crate::strpool_str!("You can refer to \\spacefactor only in horizontal mode;"),
+            crate::strpool_str!("you can refer to \\prevdepth only in vertical mode; and"),
+            crate::strpool_str!("neither of these is meaningful inside \\write. So"),
+            crate::strpool_str!("I'm forgetting what you said and using zero instead.")
+        );
         // error;
+        error($globals)?;
         // if level<>tok_val then scanned_result(0)(dimen_val)
+        if $level.get() != cur_val_level_kind::tok_val as u8 {
+            scanned_result!($globals, 0, cur_val_level_kind::dimen_val);
+        }
         // else scanned_result(0)(int_val);
+        else {
+            scanned_result!($globals, 0, cur_val_level_kind::int_val);
+        }
         // end
-        todo!("error");
     }
     // else if m=vmode then scanned_result(prev_depth)(dimen_val)
     else if $m.get() as integer == vmode as _ {
@@ -33,10 +48,15 @@ pub(crate) macro Fetch_the_space_factor_or_the_prev_depth($globals:expr, $m:expr
         );
     }
     use crate::pascal::integer;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help4;
+    use crate::section_0082::error;
+    use crate::section_0209::set_aux;
     use crate::section_0211::vmode;
     use crate::section_0213::mode;
     use crate::section_0213::prev_depth;
     use crate::section_0213::space_factor;
+    use crate::section_0298::print_cmd_chr;
     use crate::section_0410::cur_val_level_kind;
     use crate::section_0413::scanned_result;
 }}
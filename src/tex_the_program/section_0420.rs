@@ -1,15 +1,25 @@
 //! ` `
 // @<Fetch a box dimension@>=
 pub(crate) macro Fetch_a_box_dimension($globals:expr, $m:expr) {{
+    /// the box register
+    let q: pointer;
     // begin scan_eight_bit_int;
-    scan_eight_bit_int($globals)?;
+    // TeX-rs: `latex_support` has box registers up to 32767
+    #[cfg(feature = "latex_support")]
+    {
+        crate::latex_support::scan_register_num($globals)?;
+        q = crate::latex_support::fetch_box($globals, $globals.cur_val)?;
+    }
+    #[cfg(not(feature = "latex_support"))]
+    {
+        scan_eight_bit_int($globals)?;
+        q = r#box!($globals, $globals.cur_val);
+    }
     // if box(cur_val)=null then cur_val:=0 @+else cur_val:=mem[box(cur_val)+m].sc;
-    if r#box!($globals, $globals.cur_val) == null {
+    if q == null {
         $globals.cur_val = 0;
     } else {
-        $globals.cur_val = $globals.mem[r#box!($globals, $globals.cur_val) + $m.get() as pointer]
-            [MEMORY_WORD_SC]
-            .inner()
+        $globals.cur_val = $globals.mem[q + $m.get() as pointer][MEMORY_WORD_SC].inner()
     }
     // cur_val_level:=dimen_val;
     $globals.cur_val_level = cur_val_level_kind::dimen_val;
@@ -18,7 +28,9 @@ pub(crate) macro Fetch_a_box_dimension($globals:expr, $m:expr) {{
     use crate::section_0101::MEMORY_WORD_SC;
     use crate::section_0115::null;
     use crate::section_0115::pointer;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0230::r#box;
     use crate::section_0410::cur_val_level_kind;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0433::scan_eight_bit_int;
 }}
@@ -1,6 +1,19 @@
-//! @ @<Fetch the |par_shape| size@>=
-//! begin if par_shape_ptr=null then cur_val:=0
-//! else cur_val:=info(par_shape_ptr);
-//! cur_val_level:=int_val;
-//! end
-//!
+//! ` `
+// @<Fetch the |par_shape| size@>=
+pub(crate) macro Fetch_the_par_shape_size($globals:expr) {{
+    // begin if par_shape_ptr=null then cur_val:=0
+    if par_shape_ptr!($globals) == null {
+        $globals.cur_val = 0;
+    }
+    // else cur_val:=info(par_shape_ptr);
+    else {
+        $globals.cur_val = info_inner!($globals, par_shape_ptr!($globals)) as _;
+    }
+    // cur_val_level:=int_val;
+    $globals.cur_val_level = cur_val_level_kind::int_val;
+    // end
+    use crate::section_0115::null;
+    use crate::section_0118::info_inner;
+    use crate::section_0230::par_shape_ptr;
+    use crate::section_0410::cur_val_level_kind;
+}}
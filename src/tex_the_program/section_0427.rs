@@ -3,10 +3,20 @@
 // @<Fetch a register@>=
 pub(crate) macro Fetch_a_register($globals:expr, $m:expr) {{
     // begin scan_eight_bit_int;
-    scan_eight_bit_int($globals)?;
+    // TeX-rs: `latex_support` fetches registers above 255 from its sparse arrays
+    #[cfg(feature = "latex_support")]
+    let processed = crate::latex_support::fetch_sa_register($globals, $m.get())?;
+    #[cfg(not(feature = "latex_support"))]
+    let processed = {
+        scan_eight_bit_int($globals)?;
+        false
+    };
+    if processed {
+        // already fetched
+    }
     // case m of
     // int_val:cur_val:=count(cur_val);
-    if $m.get() == int_val as chr_code_repr {
+    else if $m.get() == int_val as chr_code_repr {
         $globals.cur_val = count!($globals, $globals.cur_val);
     }
     // dimen_val:cur_val:=dimen(cur_val);
@@ -27,7 +37,9 @@ pub(crate) macro Fetch_a_register($globals:expr, $m:expr) {{
         unreachable!();
     }
     // cur_val_level:=m;
-    $globals.cur_val_level = ($m.get() as u8).into();
+    if !processed {
+        $globals.cur_val_level = ($m.get() as u8).into();
+    }
     // end
     use crate::section_0224::mu_skip;
     use crate::section_0224::skip;
@@ -35,5 +47,6 @@ pub(crate) macro Fetch_a_register($globals:expr, $m:expr) {{
     use crate::section_0247::dimen;
     use crate::section_0297::chr_code_repr;
     use crate::section_0410::cur_val_level_kind::*;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0433::scan_eight_bit_int;
 }}
@@ -8,11 +8,18 @@ pub(crate) fn scan_four_bit_int(globals: &mut TeXGlobals) -> TeXResult<()> {
     scan_int(globals)?;
     // if (cur_val<0)or(cur_val>15) then
     if globals.cur_val < 0 || globals.cur_val > 15 {
-        todo!("not yet implemented in {}", file!());
         // begin print_err("Bad number");
+        print_err!(globals, crate::strpool_str!("Bad number"));
         // @.Bad number@>
         // help2("Since I expected to read a number between 0 and 15,")@/
         //   ("I changed this one to zero."); int_error(cur_val); cur_val:=0;
+        help2!(
+            globals,
+            crate::strpool_str!("Since I expected to read a number between 0 and 15,"),
+            crate::strpool_str!("I changed this one to zero.")
+        );
+        int_error(globals, globals.cur_val)?;
+        globals.cur_val = 0;
         // end;
     }
     // end;
@@ -20,5 +27,8 @@ pub(crate) fn scan_four_bit_int(globals: &mut TeXGlobals) -> TeXResult<()> {
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0073::print_err;
+use crate::section_0079::help2;
 use crate::section_0081::TeXResult;
+use crate::section_0091::int_error;
 use crate::section_0440::scan_int;
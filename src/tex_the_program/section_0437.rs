@@ -8,16 +8,26 @@ pub(crate) fn scan_twenty_seven_bit_int(globals: &mut TeXGlobals) -> TeXResult<(
     // if (cur_val<0)or(cur_val>@'777777777) then
     if globals.cur_val < 0 || globals.cur_val > 0o777777777 {
         // begin print_err("Bad delimiter code");
+        print_err!(globals, crate::strpool_str!("Bad delimiter code"));
         // @.Bad delimiter code@>
         // help2("A numeric delimiter code must be between 0 and 2^{27}-1.")@/
         //   ("I changed this one to zero."); int_error(cur_val); cur_val:=0;
+        help2!(
+            globals,
+            crate::strpool_str!("A numeric delimiter code must be between 0 and 2^{27}-1."),
+            crate::strpool_str!("I changed this one to zero.")
+        );
+        int_error(globals, globals.cur_val)?;
+        globals.cur_val = 0;
         // end;
-        todo!("bad input");
     }
     // end;
     crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0073::print_err;
+use crate::section_0079::help2;
 use crate::section_0081::TeXResult;
+use crate::section_0091::int_error;
 use crate::section_0440::scan_int;
@@ -19,7 +19,7 @@ pub(crate) fn scan_int(globals: &mut TeXGlobals) -> TeXResult<()> {
     // @!vacuous:boolean; {have no digits appeared?}
     // @!OK_so_far:boolean; {has an error message been issued?}
     /// has an error message been issued?
-    let OK_so_far: boolean;
+    let mut OK_so_far: boolean;
     // begin radix:=0; OK_so_far:=true;@/
     globals.radix = 0.into();
     OK_so_far = true;
@@ -38,7 +38,7 @@ pub(crate) fn scan_int(globals: &mut TeXGlobals) -> TeXResult<()> {
     }
     // else @<Scan a numeric constant@>;
     else {
-        crate::section_0444::Scan_a_numeric_constant!(globals);
+        crate::section_0444::Scan_a_numeric_constant!(globals, OK_so_far);
     }
     // if negative then negate(cur_val);
     if negative {
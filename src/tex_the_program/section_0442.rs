@@ -34,18 +34,41 @@ pub(crate) macro Scan_an_alphabetic_character_code_into_cur_val($globals:expr) {
             // else cur_val:=cur_tok-cs_token_flag-single_base;
             else {
                 $globals.cur_val = (cs - single_base as pointer) as _;
+                // TeX-rs: a control sequence named by one character beyond 255
+                // is found in the hash table; any other name stays improper.
+                #[cfg(feature = "unicode_support")]
+                if cs >= hash_base as pointer {
+                    $globals.cur_val = match single_char_of_str(
+                        $globals,
+                        str_number::new(text!($globals, cs) as _),
+                    ) {
+                        Some(c) => c.numeric_value() as _,
+                        None => ASCII_code::max_allowed_repr($globals) + 1,
+                    };
+                }
             }
         }
     }
     // if cur_val>255 then
-    if $globals.cur_val > 255 {
-        todo!("not yet implemented in {}", file!());
-        //   begin print_err("Improper alphabetic constant");
+    // TeX-rs: with unicode support, any character that can be read is allowed.
+    if $globals.cur_val > ASCII_code::max_allowed_repr($globals) {
+        // begin print_err("Improper alphabetic constant");
+        print_err!(
+            $globals,
+            crate::strpool_str!("Improper alphabetic constant")
+        );
         // @.Improper alphabetic constant@>
-        //   help2("A one-character control sequence belongs after a ` mark.")@/
-        //     ("So I'm essentially inserting \0 here.");
-        //   cur_val:="0"; back_error;
-        //   end
+        // help2("A one-character control sequence belongs after a ` mark.")@/
+        //   ("So I'm essentially inserting \0 here.");
+        help2!(
+            $globals,
+            crate::strpool_str!("A one-character control sequence belongs after a ` mark."),
+            crate::strpool_str!("So I'm essentially inserting \\0 here.")
+        );
+        // cur_val:="0"; back_error;
+        $globals.cur_val = b'0' as _;
+        back_error($globals)?;
+        // end
     }
     // else @<Scan an optional space@>;
     else {
@@ -55,9 +78,21 @@ pub(crate) macro Scan_an_alphabetic_character_code_into_cur_val($globals:expr) {
     crate::trace_expr_verbose!("cur_val={}", $globals.cur_val);
     use crate::section_0016::decr;
     use crate::section_0016::incr;
+    use crate::section_0018::ASCII_code;
+    #[cfg(feature = "unicode_support")]
+    use crate::section_0038::str_number;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
     use crate::section_0115::pointer;
     use crate::section_0207::right_brace;
     use crate::section_0222::active_base;
+    #[cfg(feature = "unicode_support")]
+    use crate::section_0222::hash_base;
     use crate::section_0222::single_base;
+    #[cfg(feature = "unicode_support")]
+    use crate::section_0256::text;
+    use crate::section_0327::back_error;
     use crate::section_0365::get_token;
+    #[cfg(feature = "unicode_support")]
+    use crate::unicode_support::single_char_of_str;
 }}
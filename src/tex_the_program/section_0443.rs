@@ -4,7 +4,7 @@ pub(crate) macro Scan_an_optional_space($globals:expr) {{
     // begin get_x_token; if cur_cmd<>spacer then back_input;
     get_x_token($globals)?;
     if $globals.cur_cmd != spacer {
-        back_input($globals);
+        back_input($globals)?;
     }
     // end
     use crate::section_0207::spacer;
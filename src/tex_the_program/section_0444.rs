@@ -1,7 +1,7 @@
 //! ` `
 
 // @<Scan a numeric constant@>=
-pub(crate) macro Scan_a_numeric_constant($globals:expr) {{
+pub(crate) macro Scan_a_numeric_constant($globals:expr, $OK_so_far:expr) {{
     crate::trace_span_verbose!("Scan a numeric constant");
     // begin radix:=10; m:=214748364;
     $globals.radix = 10.into();
@@ -28,7 +28,7 @@ pub(crate) macro Scan_a_numeric_constant($globals:expr) {{
     $globals.cur_val = 0;
     // @<Accumulate the constant until |cur_tok| is not a suitable digit@>;
     crate::section_0445::Accumulate_the_constant_until_cur_tok_is_not_a_suitable_digit!(
-        $globals, d, m, vacuous
+        $globals, d, m, vacuous, $OK_so_far
     );
     // if vacuous then @<Express astonishment that no number was here@>
     if vacuous {
@@ -36,7 +36,7 @@ pub(crate) macro Scan_a_numeric_constant($globals:expr) {{
     }
     // else if cur_cmd<>spacer then back_input;
     else if $globals.cur_cmd != spacer {
-        back_input($globals);
+        back_input($globals)?;
     }
     // end
     use crate::section_0207::spacer;
@@ -1,6 +1,8 @@
 //! ` `
 
 // @d infinity==@'17777777777 {the largest positive value that \TeX\ knows}
+/// the largest positive value that `TeX` knows
+pub(crate) const infinity: integer = 0o17777777777;
 // @d zero_token=other_token+"0" {zero, the smallest digit}
 /// zero, the smallest digit
 pub(crate) const zero_token: cur_tok_repr = other_token + b'0' as cur_tok_repr;
@@ -12,7 +14,7 @@ pub(crate) const A_token: cur_tok_repr = letter_token + b'A' as cur_tok_repr;
 pub(crate) const other_A_token: cur_tok_repr = other_token + b'A' as cur_tok_repr;
 
 // @<Accumulate the constant...@>=
-pub(crate) macro Accumulate_the_constant_until_cur_tok_is_not_a_suitable_digit($globals:expr, $d:expr, $m:expr, $vacuous:expr) {{
+pub(crate) macro Accumulate_the_constant_until_cur_tok_is_not_a_suitable_digit($globals:expr, $d:expr, $m:expr, $vacuous:expr, $OK_so_far:expr) {{
     crate::trace_span_verbose!("Accumulate the constant...");
     crate::region_forward_label! {
     |'done|
@@ -49,14 +51,24 @@ pub(crate) macro Accumulate_the_constant_until_cur_tok_is_not_a_suitable_digit($
             $vacuous = false;
             //   if (cur_val>=m)and((cur_val>m)or(d>7)or(radix<>10)) then
             if $globals.cur_val >= $m && ($globals.cur_val > $m || $d > 7 || $globals.radix != 10) {
-                todo!("not yet implemented in {}", file!());
                 //     begin if OK_so_far then
-                //       begin print_err("Number too big");
-                // @.Number too big@>
-                //       help2("I can only go up to 2147483647='17777777777=""7FFFFFFF,")@/
-                //         ("so I'm using that number instead of yours.");
-                //       error; cur_val:=infinity; OK_so_far:=false;
-                //       end;
+                if $OK_so_far {
+                    //       begin print_err("Number too big");
+                    print_err!($globals, crate::strpool_str!("Number too big"));
+                    // @.Number too big@>
+                    //       help2("I can only go up to 2147483647='17777777777=""7FFFFFFF,")@/
+                    //         ("so I'm using that number instead of yours.");
+                    help2!(
+                        $globals,
+                        crate::strpool_str!("I can only go up to 2147483647='17777777777=\"7FFFFFFF,"),
+                        crate::strpool_str!("so I'm using that number instead of yours.")
+                    );
+                    //       error; cur_val:=infinity; OK_so_far:=false;
+                    error($globals)?;
+                    $globals.cur_val = infinity;
+                    $OK_so_far = false;
+                    //       end;
+                }
                 //     end
             }
             // else cur_val:=cur_val*radix+d;
@@ -72,13 +84,18 @@ pub(crate) macro Accumulate_the_constant_until_cur_tok_is_not_a_suitable_digit($
     'done <-
     }
     use crate::pascal::integer;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
     use crate::section_0297::cur_tok_repr;
     use crate::section_0380::get_x_token;
+    use crate::section_0445::infinity;
     use crate::section_0445::other_A_token;
     use crate::section_0445::zero_token;
     use crate::section_0445::A_token;
 }}
 
+use crate::pascal::integer;
 use crate::section_0289::letter_token;
 use crate::section_0289::other_token;
 use crate::section_0297::cur_tok_repr;
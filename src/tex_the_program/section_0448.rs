@@ -55,7 +55,7 @@ pub(crate) fn scan_dimen(
         }
         // else  begin back_input;
         else {
-            back_input(globals);
+            back_input(globals)?;
             // if cur_tok=continental_point_token then cur_tok:=point_token;
             if globals.cur_tok == continental_point_token {
                 globals.cur_tok = cur_tok_type::new(point_token);
@@ -103,7 +103,7 @@ pub(crate) fn scan_dimen(
     'attach_sign <-
     );
     if globals.arith_error || globals.cur_val.abs() >= 0o10000000000 {
-        todo!("out of range");
+        crate::section_0460::Report_that_this_dimension_is_out_of_range!(globals);
     }
     // if negative then negate(cur_val);
     if negative {
@@ -31,7 +31,7 @@ pub(crate) macro Scan_decimal_fraction
                     /// digits for `k>=17` cannot affect the result
                     const _ : () = ();
                     // begin q:=get_avail; link(q):=p; info(q):=cur_tok-zero_token;
-                    q = get_avail($globals);
+                    q = get_avail($globals)?;
                     link!($globals, q) = p;
                     info_inner!($globals, q) = ($globals.cur_tok.get() - zero_token) as _;
                     // p:=q; incr(k);
@@ -57,7 +57,7 @@ pub(crate) macro Scan_decimal_fraction
         $f = round_decimals($globals, k).inner();
         // if cur_cmd<>spacer then back_input;
         if $globals.cur_cmd != spacer {
-            back_input($globals);
+            back_input($globals)?;
         }
         // end
         use crate::pascal::u8_from_m_to_n;
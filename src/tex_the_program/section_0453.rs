@@ -29,7 +29,7 @@ pub(crate) macro Scan_units_and_set_cur_val_to_x_dot_cur_val_f_2_16_where_there_
         }
         // if scan_keyword("true") then @<Adjust \(f)for the magnification ratio@>;
         if scan_keyword($globals, crate::strpool_str!("true"))? {
-            todo!("magnification");
+            crate::section_0457::Adjust_f_for_the_magnification_ratio!($globals, $f);
         }
         // @.true@>
         // if scan_keyword("pt") then goto attach_fraction; {the easy case}
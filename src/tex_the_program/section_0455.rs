@@ -18,7 +18,7 @@ pub(crate) macro Scan_for_u_units_that_are_internal_dimensions__goto_attach_sign
         {
         // if (cur_cmd<min_internal)or(cur_cmd>max_internal) then back_input
         if $globals.cur_cmd < min_internal || $globals.cur_cmd > max_internal {
-            back_input($globals);
+            back_input($globals)?;
         }
         // else  begin if mu then
         else {
@@ -9,15 +9,29 @@ pub(crate) macro Scan_for_m_mu_units_and_goto_attach_fraction($globals:expr, $lb
     // @.mu@>
     // else  begin print_err("Illegal unit of measure ("); print("mu inserted)");
     else {
-        todo!("illegal unit");
+        print_err!($globals, crate::strpool_str!("Illegal unit of measure ("));
+        print($globals, crate::strpool_str!("mu inserted)").get() as _);
         // @.Illegal unit of measure@>
         //   help4("The unit of measurement in math glue must be mu.")@/
         //     ("To recover gracefully from this error, it's best to")@/
         //     ("delete the erroneous units; e.g., type `2' to delete")@/
         //     ("two letters. (See Chapter 27 of The TeXbook.)");
+        help4!(
+            $globals,
+            crate::strpool_str!("The unit of measurement in math glue must be mu."),
+            crate::strpool_str!("To recover gracefully from this error, it's best to"),
+            crate::strpool_str!("delete the erroneous units; e.g., type `2' to delete"),
+            crate::strpool_str!("two letters. (See Chapter 27 of The TeXbook.)")
+        );
         // @:TeXbook}{\sl The \TeX book@>
         //   error; goto attach_fraction;
+        error($globals)?;
+        crate::goto_forward_label!($lbl_attach_fraction);
         //   end
     }
+    use crate::section_0059::print;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help4;
+    use crate::section_0082::error;
     use crate::section_0407::scan_keyword;
 }}
@@ -1,9 +1,29 @@
-//! @ @<Adjust \(f)for the magnification ratio@>=
-//! begin prepare_mag;
-//! if mag<>1000 then
-//!   begin cur_val:=xn_over_d(cur_val,1000,mag);
-//!   f:=(1000*f+@'200000*remainder) div mag;
-//!   cur_val:=cur_val+(f div @'200000); f:=f mod @'200000;
-//!   end;
-//! end
-//!
+//! ` `
+
+// @<Adjust \(f)for the magnification ratio@>=
+pub(crate) macro Adjust_f_for_the_magnification_ratio($globals:expr, $f:expr) {{
+    // begin prepare_mag;
+    prepare_mag($globals)?;
+    // if mag<>1000 then
+    if mag!($globals) != 1000 {
+        // begin cur_val:=xn_over_d(cur_val,1000,mag);
+        $globals.cur_val = xn_over_d(
+            $globals,
+            scaled::new_from_inner($globals.cur_val),
+            1000,
+            mag!($globals),
+        )
+        .inner();
+        // f:=(1000*f+@'200000*remainder) div mag;
+        $f = (1000 * $f + 0o200000 * $globals.remainder.inner()) / mag!($globals);
+        // cur_val:=cur_val+(f div @'200000); f:=f mod @'200000;
+        $globals.cur_val = $globals.cur_val + ($f / 0o200000);
+        $f = $f % 0o200000;
+        // end;
+    }
+    // end
+    use crate::section_0101::scaled;
+    use crate::section_0107::xn_over_d;
+    use crate::section_0236::mag;
+    use crate::section_0288::prepare_mag;
+}}
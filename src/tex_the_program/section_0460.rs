@@ -1,8 +1,24 @@
-//! @ @<Report that this dimension is out of range@>=
-//! begin print_err("Dimension too large");
-//! @.Dimension too large@>
-//! help2("I can't work with sizes bigger than about 19 feet.")@/
-//!   ("Continue and I'll use the largest value I can.");@/
-//! error; cur_val:=max_dimen; arith_error:=false;
-//! end
-//!
+//! ` `
+
+// @<Report that this dimension is out of range@>=
+pub(crate) macro Report_that_this_dimension_is_out_of_range($globals:expr) {{
+    // begin print_err("Dimension too large");
+    print_err!($globals, crate::strpool_str!("Dimension too large"));
+    // @.Dimension too large@>
+    // help2("I can't work with sizes bigger than about 19 feet.")@/
+    //   ("Continue and I'll use the largest value I can.");@/
+    help2!(
+        $globals,
+        crate::strpool_str!("I can't work with sizes bigger than about 19 feet."),
+        crate::strpool_str!("Continue and I'll use the largest value I can.")
+    );
+    // error; cur_val:=max_dimen; arith_error:=false;
+    error($globals)?;
+    $globals.cur_val = max_dimen;
+    $globals.arith_error = false;
+    // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+    use crate::section_0421::max_dimen;
+}}
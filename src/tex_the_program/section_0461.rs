@@ -33,7 +33,7 @@ pub(crate) fn scan_glue(globals: &mut TeXGlobals, level: small_number) -> TeXRes
         if globals.cur_val_level >= cur_val_level_kind::glue_val {
             // begin if cur_val_level<>level then mu_error;
             if globals.cur_val_level as quarterword != level.get() {
-                todo!("mu_error");
+                mu_error(globals)?;
             }
             // return;
             crate::return_nojump!();
@@ -45,13 +45,13 @@ pub(crate) fn scan_glue(globals: &mut TeXGlobals, level: small_number) -> TeXRes
         }
         // else if level=mu_val then mu_error;
         else if level == cur_val_level_kind::mu_val as u8 {
-            todo!("mu_error");
+            mu_error(globals)?;
         }
         // end
     }
     // else  begin back_input; scan_dimen(mu,false,false);
     else {
-        back_input(globals);
+        back_input(globals)?;
         scan_dimen(globals, mu, false, false)?;
         // if negative then negate(cur_val);
         if negative {
@@ -77,6 +77,7 @@ use crate::section_0113::quarterword;
 use crate::section_0208::min_internal;
 use crate::section_0209::max_internal;
 use crate::section_0325::back_input;
+use crate::section_0408::mu_error;
 use crate::section_0410::cur_val_level_kind;
 use crate::section_0413::scan_something_internal;
 use crate::section_0448::scan_dimen;
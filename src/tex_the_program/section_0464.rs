@@ -14,7 +14,7 @@
 //   {converts |str_pool[b..pool_ptr-1]| to a token list}
 /// converts `str_pool[b..pool_ptr-1]` to a token list
 #[allow(unused_variables)]
-pub(crate) fn str_toks(globals: &mut TeXGlobals, b: pool_pointer) -> pointer {
+pub(crate) fn str_toks(globals: &mut TeXGlobals, b: pool_pointer) -> TeXResult<pointer> {
     // var p:pointer; {tail of the token list}
     /// tail of the token list
     let mut p: pointer;
@@ -24,7 +24,7 @@ pub(crate) fn str_toks(globals: &mut TeXGlobals, b: pool_pointer) -> pointer {
     // @!t:halfword; {token being appended}
     // @!k:pool_pointer; {index into |str_pool|}
     // begin str_room(1);
-    str_room(globals, 1 * character_max_room);
+    str_room(globals, 1 * character_max_room)?;
     // p:=temp_head; link(p):=null; k:=b;
     p = temp_head;
     link!(globals, p) = null;
@@ -35,7 +35,7 @@ pub(crate) fn str_toks(globals: &mut TeXGlobals, b: pool_pointer) -> pointer {
         k = b;
         // while k<pool_ptr do
         while k < globals.pool_ptr {
-            todo!("str_toks");
+            crate::unported::unported!(globals, 464, "str_toks");
             // begin t:=so(str_pool[k]);
             // if t=" " then t:=space_token
             // else t:=other_token+t;
@@ -67,7 +67,7 @@ pub(crate) fn str_toks(globals: &mut TeXGlobals, b: pool_pointer) -> pointer {
     globals.pool_ptr = b;
     str_toks = p;
     // end;
-    str_toks
+    crate::ok_nojump!(str_toks)
 }
 
 use crate::section_0004::TeXGlobals;
@@ -77,6 +77,7 @@ use crate::section_0020::xord;
 use crate::section_0038::pool_pointer;
 use crate::section_0042::character_max_room;
 use crate::section_0042::str_room;
+use crate::section_0081::TeXResult;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
 use crate::section_0118::link;
@@ -15,6 +15,11 @@ pub(crate) fn the_toks(globals: &mut TeXGlobals) -> TeXResult<pointer> {
     // @!b:pool_pointer; {base of temporary string}
     /// base of temporary string
     let b: pool_pointer;
+    // TeX-rs: `\unexpanded` and `\detokenize` of `latex_support` share this command code
+    #[cfg(feature = "latex_support")]
+    if globals.cur_cmd == the && globals.cur_chr.get().is_odd() {
+        return crate::latex_support::the_toks_of_general_text(globals);
+    }
     // begin get_x_token; scan_something_internal(tok_val,false);
     get_x_token(globals)?;
     scan_something_internal(
@@ -61,13 +66,15 @@ pub(crate) fn the_toks(globals: &mut TeXGlobals) -> TeXResult<pointer> {
         }
         // selector:=old_setting; the_toks:=str_toks(b);
         globals.selector = old_setting;
-        the_toks = str_toks(globals, b);
+        the_toks = str_toks(globals, b)?;
         // end;
     }
     // end;
     crate::ok_nojump!(the_toks)
 }
 
+#[cfg(feature = "latex_support")]
+use crate::pascal::IsOddOrEven;
 use crate::section_0004::TeXGlobals;
 use crate::section_0038::pool_pointer;
 use crate::section_0054::new_string;
@@ -80,6 +87,8 @@ use crate::section_0103::print_scaled;
 use crate::section_0115::pointer;
 use crate::section_0178::print_spec;
 use crate::section_0201::delete_glue_ref;
+#[cfg(feature = "latex_support")]
+use crate::section_0210::the;
 use crate::section_0380::get_x_token;
 use crate::section_0410::cur_val_level_kind;
 use crate::section_0413::scan_something_internal;
@@ -48,7 +48,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0468($globals
         crate::strpool_str!("number"),
         convert,
         convert_code_kind::number_code as _,
-    );
+    )?;
     // @!@:number_}{\.{\\number} primitive@>
     // primitive("romannumeral",convert,roman_numeral_code);@/
     primitive(
@@ -56,7 +56,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0468($globals
         crate::strpool_str!("romannumeral"),
         convert,
         convert_code_kind::roman_numeral_code as _,
-    );
+    )?;
     // @!@:roman_numeral_}{\.{\\romannumeral} primitive@>
     // primitive("string",convert,string_code);@/
     primitive(
@@ -64,7 +64,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0468($globals
         crate::strpool_str!("string"),
         convert,
         convert_code_kind::string_code as _,
-    );
+    )?;
     // @!@:string_}{\.{\\string} primitive@>
     // primitive("meaning",convert,meaning_code);@/
     primitive(
@@ -72,7 +72,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0468($globals
         crate::strpool_str!("meaning"),
         convert,
         convert_code_kind::meaning_code as _,
-    );
+    )?;
     // @!@:meaning_}{\.{\\meaning} primitive@>
     // primitive("fontname",convert,font_name_code);@/
     primitive(
@@ -80,7 +80,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0468($globals
         crate::strpool_str!("fontname"),
         convert,
         convert_code_kind::font_name_code as _,
-    );
+    )?;
     // @!@:font_name_}{\.{\\fontname} primitive@>
     // primitive("jobname",convert,job_name_code);@/
     primitive(
@@ -88,7 +88,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0468($globals
         crate::strpool_str!("jobname"),
         convert,
         convert_code_kind::job_name_code as _,
-    );
+    )?;
     // @!@:job_name_}{\.{\\jobname} primitive@>
 }}
 
@@ -27,7 +27,7 @@ pub(crate) fn conv_toks(globals: &mut TeXGlobals) -> TeXResult<()> {
     crate::section_0472::Print_the_result_of_command_c!(globals, c);
     // selector:=old_setting; link(garbage):=str_toks(b); ins_list(link(temp_head));
     globals.selector = old_setting;
-    link!(globals, garbage) = str_toks(globals, b);
+    link!(globals, garbage) = str_toks(globals, b)?;
     ins_list!(globals, link!(globals, temp_head));
     // end;
     crate::ok_nojump!()
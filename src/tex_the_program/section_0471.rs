@@ -24,7 +24,7 @@ pub(crate) macro Scan_the_argument_for_command_c($globals:expr, $c:expr, $save_s
         // job_name_code: if job_name=0 then open_log_file;
         convert_code_kind::job_name_code => {
             if $globals.job_name == 0 {
-                open_log_file($globals);
+                open_log_file($globals)?;
             }
         }
     }
@@ -30,11 +30,17 @@ pub(crate) macro Print_the_result_of_command_c($globals:expr, $c:expr) {{
         }
         // font_name_code: begin print(font_name[cur_val]);
         convert_code_kind::font_name_code => {
-            todo!("font_name_code");
+            let f = internal_font_number::new($globals.cur_val as _);
+            print($globals, $globals.font_name[f].get() as _);
             // if font_size[cur_val]<>font_dsize[cur_val] then
-            //   begin print(" at "); print_scaled(font_size[cur_val]);
-            //   print("pt");
-            //   end;
+            if $globals.font_size[f] != $globals.font_dsize[f] {
+                //   begin print(" at "); print_scaled(font_size[cur_val]);
+                print($globals, crate::strpool_str!(" at ").get() as _);
+                print_scaled($globals, $globals.font_size[f]);
+                //   print("pt");
+                print($globals, crate::strpool_str!("pt").get() as _);
+                //   end;
+            }
             // end;
         }
         // job_name_code: print(job_name);
@@ -49,7 +55,9 @@ pub(crate) macro Print_the_result_of_command_c($globals:expr, $c:expr) {{
     use crate::section_0059::print;
     use crate::section_0065::print_int;
     use crate::section_0069::print_roman_int;
+    use crate::section_0103::print_scaled;
     use crate::section_0263::sprint_cs;
     use crate::section_0296::print_meaning;
     use crate::section_0468::convert_code_kind;
+    use crate::section_0548::internal_font_number;
 }}
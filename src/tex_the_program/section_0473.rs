@@ -50,29 +50,34 @@ pub(crate) fn scan_toks(
     let mut hash_brace: cur_tok_repr;
     // begin if macro_def then scanner_status:=defining
     // @+else scanner_status:=absorbing;
+    if macro_def {
+        globals.scanner_status = scanner_status_kind::defining;
+    } else {
+        globals.scanner_status = scanner_status_kind::absorbing;
+    }
     // warning_index:=cur_cs; def_ref:=get_avail; token_ref_count(def_ref):=null;
     globals.warning_index = globals.cur_cs;
-    globals.def_ref = get_avail(globals);
+    globals.def_ref = get_avail(globals)?;
     token_ref_count!(globals, globals.def_ref) = null;
     // p:=def_ref; hash_brace:=0; t:=zero_token;
     p = globals.def_ref;
     hash_brace = 0;
     t = zero_token as _;
     crate::trace_expr_verbose!("t = zero_token = {}", t);
-    // if macro_def then @<Scan and build the parameter part of the macro definition@>
-    if macro_def {
-        crate::section_0474::Scan_and_build_the_parameter_part_of_the_macro_definition!(
-            globals, t, hash_brace, p, q
-        );
-    }
-    // else scan_left_brace; {remove the compulsory left brace}
-    else {
-        /// remove the compulsory left brace
-        scan_left_brace(globals)?;
-    }
     crate::region_forward_label! {
     |'found|
     {
+        // if macro_def then @<Scan and build the parameter part of the macro definition@>
+        if macro_def {
+            crate::section_0474::Scan_and_build_the_parameter_part_of_the_macro_definition!(
+                globals, t, hash_brace, p, q, 'found
+            );
+        }
+        // else scan_left_brace; {remove the compulsory left brace}
+        else {
+            /// remove the compulsory left brace
+            scan_left_brace(globals)?;
+        }
         // @<Scan and build the body of the token list; |goto found| when finished@>;
         crate::section_0477::Scan_and_build_the_body_of_the_token_list__goto_found_when_finished!(globals,
             macro_def, xpand, unbalance, t, p, q, 'found);
@@ -2,7 +2,7 @@
 
 // @<Scan and build the parameter part...@>=
 pub(crate) macro Scan_and_build_the_parameter_part_of_the_macro_definition {
-    ($globals:expr, $t:expr, $hash_brace:expr, $p:expr, $q:expr) => {{
+    ($globals:expr, $t:expr, $hash_brace:expr, $p:expr, $q:expr, $lbl_found:lifetime) => {{
         crate::region_forward_label! {
         |'done|
         {
@@ -43,7 +43,9 @@ pub(crate) macro Scan_and_build_the_parameter_part_of_the_macro_definition {
         // if cur_cmd=right_brace then
         if $globals.cur_cmd == right_brace {
             // @<Express shock at the missing left brace; |goto found|@>;
-            todo!("shock")
+            crate::section_0475::Express_shock_at_the_missing_left_brace__goto_found!(
+                $globals, $lbl_found
+            );
         }
         }
         // done: end
@@ -1,7 +1,23 @@
-//! @ @<Express shock...@>=
-//! begin print_err("Missing { inserted"); incr(align_state);
-//! @.Missing \{ inserted@>
-//! help2("Where was the left brace? You said something like `\def\a}',")@/
-//!   ("which I'm going to interpret as `\def\a{}'."); error; goto found;
-//! end
-//!
+//! ` `
+
+// @<Express shock...@>=
+pub(crate) macro Express_shock_at_the_missing_left_brace__goto_found($globals:expr, $lbl_found:lifetime) {{
+    // begin print_err("Missing { inserted"); incr(align_state);
+    print_err!($globals, crate::strpool_str!("Missing { inserted"));
+    incr!($globals.align_state);
+    // @.Missing \{ inserted@>
+    // help2("Where was the left brace? You said something like `\def\a}',")@/
+    //   ("which I'm going to interpret as `\def\a{}'."); error; goto found;
+    help2!(
+        $globals,
+        crate::strpool_str!("Where was the left brace? You said something like `\\def\\a}',"),
+        crate::strpool_str!("which I'm going to interpret as `\\def\\a{}'.")
+    );
+    error($globals)?;
+    crate::goto_forward_label!($lbl_found);
+    // end
+    use crate::section_0016::incr;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+}}
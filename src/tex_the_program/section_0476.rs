@@ -19,11 +19,21 @@ pub(crate) macro If_the_next_character_is_a_parameter_number__make_cur_tok_a_mat
     }
     // if t=zero_token+9 then
     if $t == (zero_token + 9) as cur_tok_repr {
-        todo!("not yet implemented in {}", file!());
         //   begin print_err("You already have nine parameters");
+        print_err!(
+            $globals,
+            crate::strpool_str!("You already have nine parameters")
+        );
         // @.You already have nine...@>
         //   help2("I'm going to ignore the # sign you just used,")@/
         //     ("as well as the token that followed it."); error; goto continue;
+        help2!(
+            $globals,
+            crate::strpool_str!("I'm going to ignore the # sign you just used,"),
+            crate::strpool_str!("as well as the token that followed it.")
+        );
+        error($globals)?;
+        crate::goto_backward_label!($lbl_continue);
         //   end
     }
     // else  begin incr(t);
@@ -33,11 +43,20 @@ pub(crate) macro If_the_next_character_is_a_parameter_number__make_cur_tok_a_mat
         crate::trace_expr_verbose!("cur_tok = {}", $globals.cur_tok.get());
         crate::trace_expr_verbose!("t = {}", $t);
         if $globals.cur_tok.get() != $t as cur_tok_repr {
-            todo!("numbering");
             // begin print_err("Parameters must be numbered consecutively");
+            print_err!(
+                $globals,
+                crate::strpool_str!("Parameters must be numbered consecutively")
+            );
             // @.Parameters...consecutively@>
             // help2("I've inserted the digit you should have used after the #.")@/
             //   ("Type `1' to delete what you did use."); back_error;
+            help2!(
+                $globals,
+                crate::strpool_str!("I've inserted the digit you should have used after the #."),
+                crate::strpool_str!("Type `1' to delete what you did use.")
+            );
+            back_error($globals)?;
             // end;
         }
         // cur_tok:=s;
@@ -46,11 +65,15 @@ pub(crate) macro If_the_next_character_is_a_parameter_number__make_cur_tok_a_mat
     }
     // end
     use crate::section_0016::incr;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
     use crate::section_0289::end_match_token;
     use crate::section_0289::left_brace_limit;
     use crate::section_0289::match_token;
     use crate::section_0297::cur_tok_repr;
     use crate::section_0297::cur_tok_type;
+    use crate::section_0327::back_error;
     use crate::section_0365::get_token;
     use crate::section_0371::store_new_token;
     use crate::section_0445::zero_token;
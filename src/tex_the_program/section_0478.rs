@@ -9,6 +9,9 @@ pub(crate) macro Expand_the_next_part_of_the_input($globals:expr, $p:expr, $q:ex
         // begin loop begin get_next;
         loop {
             get_next($globals)?;
+            // TeX-rs: `\protected` macros of `latex_support` are not expanded here
+            #[cfg(feature = "latex_support")]
+            crate::latex_support::Suppress_expansion_of_a_protected_macro!($globals);
             // if cur_cmd<=max_command then goto done2;
             if $globals.cur_cmd <= max_command {
                 crate::goto_forward_label!('done2);
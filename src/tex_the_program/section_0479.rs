@@ -14,14 +14,26 @@ pub(crate) macro Look_for_parameter_number_or_sharpsharp($globals:expr, $xpand:e
     if $globals.cur_cmd != mac_param {
         //   if (cur_tok<=zero_token)or(cur_tok>t) then
         if $globals.cur_tok <= zero_token || $globals.cur_tok > $t {
-            todo!("not yet implemented in {}", file!());
             //     begin print_err("Illegal parameter number in definition of ");
+            print_err!(
+                $globals,
+                crate::strpool_str!("Illegal parameter number in definition of ")
+            );
             // @.Illegal parameter number...@>
             //     sprint_cs(warning_index);
+            sprint_cs($globals, $globals.warning_index);
             //     help3("You meant to type ## instead of #, right?")@/
             //     ("Or maybe a } was forgotten somewhere earlier, and things")@/
             //     ("are all screwed up? I'm going to assume that you meant ##.");
+            help3!(
+                $globals,
+                crate::strpool_str!("You meant to type ## instead of #, right?"),
+                crate::strpool_str!("Or maybe a } was forgotten somewhere earlier, and things"),
+                crate::strpool_str!("are all screwed up? I'm going to assume that you meant ##.")
+            );
             //     back_error; cur_tok:=s;
+            back_error($globals)?;
+            $globals.cur_tok = s;
             //     end
         }
         //   else cur_tok:=out_param_token-"0"+cur_chr;
@@ -32,10 +44,14 @@ pub(crate) macro Look_for_parameter_number_or_sharpsharp($globals:expr, $xpand:e
         }
     }
     // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
     use crate::section_0207::*;
+    use crate::section_0263::sprint_cs;
     use crate::section_0289::out_param_token;
     use crate::section_0297::cur_tok_repr;
     use crate::section_0297::cur_tok_type;
+    use crate::section_0327::back_error;
     use crate::section_0365::get_token;
     use crate::section_0380::get_x_token;
     use crate::section_0445::zero_token;
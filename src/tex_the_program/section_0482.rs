@@ -3,7 +3,14 @@
 //! to the control sequence that will receive this token list.
 //
 // @p procedure read_toks(@!n:integer;@!r:pointer);
-pub(crate) fn read_toks(globals: &mut TeXGlobals, mut n: integer, r: pointer) -> TeXResult<()> {
+// TeX-rs: `j` is the chr code of `\read`, or of `\readline` in `latex_support`
+#[allow(unused_variables)]
+pub(crate) fn read_toks(
+    globals: &mut TeXGlobals,
+    mut n: integer,
+    r: pointer,
+    j: chr_code_repr,
+) -> TeXResult<()> {
     // label done;
     // var p:pointer; {tail of the token list}
     /// tail of the token list
@@ -21,7 +28,7 @@ pub(crate) fn read_toks(globals: &mut TeXGlobals, mut n: integer, r: pointer) ->
     globals.scanner_status = scanner_status_kind::defining;
     globals.warning_index = r;
     // def_ref:=get_avail; token_ref_count(def_ref):=null;
-    globals.def_ref = get_avail(globals);
+    globals.def_ref = get_avail(globals)?;
     token_ref_count!(globals, globals.def_ref) = null;
     // p:=def_ref; {the reference count}
     /// the reference count
@@ -43,7 +50,7 @@ pub(crate) fn read_toks(globals: &mut TeXGlobals, mut n: integer, r: pointer) ->
     // repeat @<Input and store tokens from the next line of the file@>;
     loop {
         crate::section_0483::Input_and_store_tokens_from_the_next_line_of_the_file!(
-            globals, m, p, q, n, r
+            globals, m, p, q, n, r, j
         );
         // until align_state=1000000;
         if globals.align_state == 1000000 {
@@ -67,5 +74,6 @@ use crate::section_0115::pointer;
 use crate::section_0120::get_avail;
 use crate::section_0200::token_ref_count;
 use crate::section_0289::end_match_token;
+use crate::section_0297::chr_code_repr;
 use crate::section_0305::scanner_status_kind;
 use crate::section_0371::store_new_token;
@@ -2,9 +2,9 @@
 
 // @<Input and store tokens from the next line of the file@>=
 pub(crate) macro Input_and_store_tokens_from_the_next_line_of_the_file {
-    ($globals:expr, $m:expr, $p:expr, $q:expr, $n:expr, $r:expr) => {{
+    ($globals:expr, $m:expr, $p:expr, $q:expr, $n:expr, $r:expr, $j:expr) => {{
         // begin_file_reading; name:=m+1;
-        begin_file_reading($globals);
+        begin_file_reading($globals)?;
         name!($globals) = $m.get() as halfword + 1;
         // if read_open[m]=closed then @<Input for \.{\\read} from the terminal@>
         if $globals.read_open[$m.get()] == read_open_kind::closed {
@@ -19,7 +19,7 @@ pub(crate) macro Input_and_store_tokens_from_the_next_line_of_the_file {
             crate::section_0486::Input_the_next_line_of_read_file_m!($globals, $m);
         }
         // limit:=last;
-        limit!($globals) = $globals.last.get();
+        limit!($globals) = $globals.last.get() as _;
         // if end_line_char_inactive then decr(limit)
         if end_line_char_inactive!($globals) {
             decr!(limit!($globals));
@@ -29,12 +29,17 @@ pub(crate) macro Input_and_store_tokens_from_the_next_line_of_the_file {
             $globals.buffer[limit!($globals)] = ASCII_code::from(end_line_char!($globals));
         }
         // first:=limit+1; loc:=start; state:=new_line;@/
-        $globals.first = (limit!($globals) + 1).into();
+        $globals.first = (limit!($globals) as u16 + 1).into();
         loc!($globals) = start!($globals);
         state!($globals) = new_line;
         crate::region_forward_label!(
             |'done|
             {
+                // TeX-rs: `\readline` of `latex_support` does not tokenize the line
+                #[cfg(feature = "latex_support")]
+                if $j == crate::latex_support::readline_code {
+                    crate::latex_support::Handle_readline_and_goto_done!($globals, $p, $q, 'done);
+                }
                 // loop@+  begin get_token;
                 loop {
                     get_token($globals)?;
@@ -5,11 +5,7 @@
 // @<Input the first line of |read_file[m]|@>=
 pub(crate) macro Input_the_first_line_of_read_file_m($globals:expr, $m:expr) {{
     // if input_ln(read_file[m],false) then read_open[m]:=normal
-    if input_ln(
-        make_globals_io_view!($globals),
-        &mut $globals.read_file[$m.get()],
-        false,
-    ) {
+    if input_ln_or_report_overflow!($globals, &mut $globals.read_file[$m.get()], false) {
         $globals.read_open[$m.get()] = read_open_kind::normal;
     }
     // else  begin a_close(read_file[m]); read_open[m]:=closed;
@@ -21,6 +17,6 @@ pub(crate) macro Input_the_first_line_of_read_file_m($globals:expr, $m:expr) {{
     use crate::section_0004::make_globals_io_view;
     use crate::section_0004::TeXGlobalsIoView;
     use crate::section_0028::a_close;
-    use crate::section_0031::input_ln;
+    use crate::section_0035::input_ln_or_report_overflow;
     use crate::section_0480::read_open_kind;
 }}
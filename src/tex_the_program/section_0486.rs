@@ -4,24 +4,41 @@
 // @<Input the next line of |read_file[m]|@>=
 pub(crate) macro Input_the_next_line_of_read_file_m($globals:expr, $m:expr) {{
     // begin if not input_ln(read_file[m],true) then
-    if !input_ln(
-        make_globals_io_view!($globals),
-        &mut $globals.read_file[$m.get()],
-        true,
-    ) {
-        todo!("close");
+    if !input_ln_or_report_overflow!($globals, &mut $globals.read_file[$m.get()], true) {
         // begin a_close(read_file[m]); read_open[m]:=closed;
+        a_close(&mut $globals.read_file[$m.get()]);
+        $globals.read_open[$m.get()] = read_open_kind::closed;
         // if align_state<>1000000 then
-        //   begin runaway;
-        //   print_err("File ended within "); print_esc("read");
-        // @.File ended within \\read@>
-        //   help1("This \read has unbalanced braces.");
-        //   align_state:=1000000; limit:=0; error;
-        //   end;
+        if $globals.align_state != 1000000 {
+            // begin runaway;
+            runaway($globals);
+            // print_err("File ended within "); print_esc("read");
+            print_err!($globals, crate::strpool_str!("File ended within "));
+            print_esc($globals, crate::strpool_str!("read"));
+            // @.File ended within \\read@>
+            // help1("This \read has unbalanced braces.");
+            help1!(
+                $globals,
+                crate::strpool_str!("This \\read has unbalanced braces.")
+            );
+            // align_state:=1000000; limit:=0; error;
+            $globals.align_state = 1000000;
+            limit!($globals) = 0;
+            error($globals)?;
+            // end;
+        }
         // end;
     }
     // end
     use crate::section_0004::make_globals_io_view;
     use crate::section_0004::TeXGlobalsIoView;
-    use crate::section_0031::input_ln;
+    use crate::section_0028::a_close;
+    use crate::section_0035::input_ln_or_report_overflow;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help1;
+    use crate::section_0082::error;
+    use crate::section_0302::limit;
+    use crate::section_0306::runaway;
+    use crate::section_0480::read_open_kind;
 }}
@@ -63,7 +63,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("if"),
         if_test,
         if_char_code as _,
-    );
+    )?;
     // @!@:if_char_}{\.{\\if} primitive@>
     // primitive("ifcat",if_test,if_cat_code);
     primitive(
@@ -71,7 +71,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifcat"),
         if_test,
         if_cat_code as _,
-    );
+    )?;
     // @!@:if_cat_code_}{\.{\\ifcat} primitive@>
     // primitive("ifnum",if_test,if_int_code);
     primitive(
@@ -79,7 +79,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifnum"),
         if_test,
         if_int_code as _,
-    );
+    )?;
     // @!@:if_int_}{\.{\\ifnum} primitive@>
     // primitive("ifdim",if_test,if_dim_code);
     primitive(
@@ -87,7 +87,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifdim"),
         if_test,
         if_dim_code as _,
-    );
+    )?;
     // @!@:if_dim_}{\.{\\ifdim} primitive@>
     // primitive("ifodd",if_test,if_odd_code);
     primitive(
@@ -95,7 +95,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifodd"),
         if_test,
         if_odd_code as _,
-    );
+    )?;
     // @!@:if_odd_}{\.{\\ifodd} primitive@>
     // primitive("ifvmode",if_test,if_vmode_code);
     primitive(
@@ -103,7 +103,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifvmode"),
         if_test,
         if_vmode_code as _,
-    );
+    )?;
     // @!@:if_vmode_}{\.{\\ifvmode} primitive@>
     // primitive("ifhmode",if_test,if_hmode_code);
     primitive(
@@ -111,7 +111,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifhmode"),
         if_test,
         if_hmode_code as _,
-    );
+    )?;
     // @!@:if_hmode_}{\.{\\ifhmode} primitive@>
     // primitive("ifmmode",if_test,if_mmode_code);
     primitive(
@@ -119,7 +119,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifmmode"),
         if_test,
         if_mmode_code as _,
-    );
+    )?;
     // @!@:if_mmode_}{\.{\\ifmmode} primitive@>
     // primitive("ifinner",if_test,if_inner_code);
     primitive(
@@ -127,7 +127,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifinner"),
         if_test,
         if_inner_code as _,
-    );
+    )?;
     // @!@:if_inner_}{\.{\\ifinner} primitive@>
     // primitive("ifvoid",if_test,if_void_code);
     primitive(
@@ -135,7 +135,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifvoid"),
         if_test,
         if_void_code as _,
-    );
+    )?;
     // @!@:if_void_}{\.{\\ifvoid} primitive@>
     // primitive("ifhbox",if_test,if_hbox_code);
     primitive(
@@ -143,7 +143,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifhbox"),
         if_test,
         if_hbox_code as _,
-    );
+    )?;
     // @!@:if_hbox_}{\.{\\ifhbox} primitive@>
     // primitive("ifvbox",if_test,if_vbox_code);
     primitive(
@@ -151,10 +151,10 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifvbox"),
         if_test,
         if_vbox_code as _,
-    );
+    )?;
     // @!@:if_vbox_}{\.{\\ifvbox} primitive@>
     // primitive("ifx",if_test,ifx_code);
-    primitive(globals, crate::strpool_str!("ifx"), if_test, ifx_code as _);
+    primitive(globals, crate::strpool_str!("ifx"), if_test, ifx_code as _)?;
     // @!@:ifx_}{\.{\\ifx} primitive@>
     // primitive("ifeof",if_test,if_eof_code);
     primitive(
@@ -162,7 +162,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifeof"),
         if_test,
         if_eof_code as _,
-    );
+    )?;
     // @!@:if_eof_}{\.{\\ifeof} primitive@>
     // primitive("iftrue",if_test,if_true_code);
     primitive(
@@ -170,7 +170,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("iftrue"),
         if_test,
         if_true_code as _,
-    );
+    )?;
     // @!@:if_true_}{\.{\\iftrue} primitive@>
     // primitive("iffalse",if_test,if_false_code);
     primitive(
@@ -178,7 +178,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("iffalse"),
         if_test,
         if_false_code as _,
-    );
+    )?;
     // @!@:if_false_}{\.{\\iffalse} primitive@>
     // primitive("ifcase",if_test,if_case_code);
     primitive(
@@ -186,7 +186,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0487($globals
         crate::strpool_str!("ifcase"),
         if_test,
         if_case_code as _,
-    );
+    )?;
     // @!@:if_case_}{\.{\\ifcase} primitive@>
 }}
 
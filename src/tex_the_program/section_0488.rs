@@ -5,6 +5,9 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0488
     // if_test: case chr_code of
     let processed = if $cmd == if_test {
         let chr_code = $chr_code.get();
+        // TeX-rs: `\unless` of `latex_support` is folded into the chr code
+        #[cfg(feature = "latex_support")]
+        let chr_code = crate::latex_support::print_unless_prefix($globals, chr_code);
         if false {
             unreachable!();
         }
@@ -74,7 +77,14 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_0488
         }
         // othercases print_esc("if")
         else {
-            print_esc($globals, crate::strpool_str!("if"));
+            // TeX-rs: the conditionals added by `latex_support`
+            #[cfg(feature = "latex_support")]
+            let processed = crate::latex_support::print_if_test($globals, chr_code);
+            #[cfg(not(feature = "latex_support"))]
+            let processed = false;
+            if !processed {
+                print_esc($globals, crate::strpool_str!("if"));
+            }
         }
         // endcases;
         use crate::section_0297::chr_code_repr;
@@ -5,13 +5,13 @@
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0491($globals:expr) {{
     let globals = &mut *$globals;
     // primitive("fi",fi_or_else,fi_code);
-    primitive(globals, crate::strpool_str!("fi"), fi_or_else, fi_code as _);
+    primitive(globals, crate::strpool_str!("fi"), fi_or_else, fi_code as _)?;
     // @!@:fi_}{\.{\\fi} primitive@>
     // text(frozen_fi):="fi"; eqtb[frozen_fi]:=eqtb[cur_val];
     text!(globals, frozen_fi as pointer) = crate::strpool_str!("fi").get() as _;
     globals.eqtb[frozen_fi as pointer] = globals.eqtb[globals.cur_val as pointer];
     // primitive("or",fi_or_else,or_code);
-    primitive(globals, crate::strpool_str!("or"), fi_or_else, or_code as _);
+    primitive(globals, crate::strpool_str!("or"), fi_or_else, or_code as _)?;
     // @!@:or_}{\.{\\or} primitive@>
     // primitive("else",fi_or_else,else_code);
     primitive(
@@ -19,7 +19,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0491($globals
         crate::strpool_str!("else"),
         fi_or_else,
         else_code as _,
-    );
+    )?;
     // @!@:else_}{\.{\\else} primitive@>
 }}
 
@@ -2,9 +2,14 @@
 //! a given value of |cond_ptr|.
 //
 // @p procedure change_if_limit(@!l:small_number;@!p:pointer);
-pub(crate) fn change_if_limit(globals: &mut TeXGlobals, l: small_number, p: pointer) {
+pub(crate) fn change_if_limit(
+    globals: &mut TeXGlobals,
+    l: small_number,
+    p: pointer,
+) -> TeXResult<()> {
     // label exit;
     // var q:pointer;
+    let mut q: pointer;
     // begin if p=cond_ptr then if_limit:=l {that's the easy case}
     if p == globals.cond_ptr {
         /// that's the easy case
@@ -14,19 +19,35 @@ pub(crate) fn change_if_limit(globals: &mut TeXGlobals, l: small_number, p: poin
     }
     // else  begin q:=cond_ptr;
     else {
-        todo!("not yet implemented in {}", file!());
-        //   loop@+  begin if q=null then confusion("if");
-        // @:this can't happen if}{\quad if@>
-        //     if link(q)=p then
-        //       begin type(q):=l; return;
-        //       end;
-        //     q:=link(q);
-        //     end;
-        //   end;
+        q = globals.cond_ptr;
+        // loop@+  begin if q=null then confusion("if");
+        loop {
+            if q == null {
+                confusion(globals, crate::strpool_str!("if"))?;
+            }
+            // @:this can't happen if}{\quad if@>
+            // if link(q)=p then
+            if link!(globals, q) == p {
+                // begin type(q):=l; return;
+                r#type!(globals, q) = l.get() as _;
+                crate::return_nojump!();
+                // end;
+            }
+            // q:=link(q);
+            q = link!(globals, q);
+            // end;
+        }
+        // end;
     }
     // exit:end;
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
+use crate::section_0095::confusion;
 use crate::section_0101::small_number;
+use crate::section_0115::null;
 use crate::section_0115::pointer;
+use crate::section_0118::link;
+use crate::section_0133::r#type;
@@ -24,11 +24,18 @@ pub(crate) fn conditional(globals: &mut TeXGlobals) -> TeXResult<()> {
     crate::section_0495::Push_the_condition_stack!(globals);
     save_cond_ptr = globals.cond_ptr;
     this_if = (globals.cur_chr.get() as u8).into();
+    // TeX-rs: `\unless` of `latex_support` is folded into the chr code
+    #[cfg(feature = "latex_support")]
+    let is_unless = this_if.get() >= crate::latex_support::unless_code as u8;
+    #[cfg(feature = "latex_support")]
+    let this_if: small_number = (this_if.get() % crate::latex_support::unless_code as u8).into();
     crate::region_forward_label!(
     |'common_ending|
     {
         // @<Either process \.{\\ifcase} or set |b| to the value of a boolean condition@>;
         crate::section_0501::Either_process_ifcase_or_set_b_to_the_value_of_a_boolean_condition!(globals, this_if, b, save_cond_ptr, 'common_ending);
+        #[cfg(feature = "latex_support")]
+        let b = if is_unless { !b } else { b };
         // if tracing_commands>1 then @<Display the value of |b|@>;
         if tracing_commands!(globals) > 1 {
             crate::section_0502::Display_the_value_of_b!(globals, b);
@@ -36,7 +43,7 @@ pub(crate) fn conditional(globals: &mut TeXGlobals) -> TeXResult<()> {
         // if b then
         if b {
             // begin change_if_limit(else_code,save_cond_ptr);
-            change_if_limit(globals, else_code.into(), save_cond_ptr);
+            change_if_limit(globals, else_code.into(), save_cond_ptr)?;
             // return; {wait for \.{\\else} or \.{\\fi}}
             /// wait for `\else` or `\fi`
             crate::return_nojump!();
@@ -11,7 +11,7 @@ pub(crate) macro Skip_to_else_or_fi__then_goto_common_ending($globals:expr, $sav
             if $globals.cur_chr.get() != or_code as chr_code_repr {
                 crate::goto_forward_label!($lbl_common_ending);
             }
-            todo!("extra \\or");
+            crate::unported::unported!($globals, 500, "extra \\or");
             // print_err("Extra "); print_esc("or");
             // @.Extra \\or@>
             // help1("I'm ignoring this; it doesn't match any \if.");
@@ -20,7 +20,7 @@ pub(crate) macro Skip_to_else_or_fi__then_goto_common_ending($globals:expr, $sav
         }
         // else if cur_chr=fi_code then @<Pop the condition stack@>;
         else if $globals.cur_chr.get() == fi_code as chr_code_repr {
-            todo!("pop stack");
+            crate::unported::unported!($globals, 500, "pop stack");
         }
         // end
     }
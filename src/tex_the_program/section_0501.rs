@@ -69,9 +69,15 @@ pub(crate) macro Either_process_ifcase_or_set_b_to_the_value_of_a_boolean_condit
     }
     // end {there are no other cases}
     else {
-        crate::trace_error_expr!("this_if = {}", $this_if.get());
-        /// there are no other cases
-        unreachable!();
+        // TeX-rs: the conditionals added by `latex_support`
+        #[cfg(feature = "latex_support")]
+        crate::latex_support::Test_a_latex_support_conditional!($globals, $this_if, $b);
+        #[cfg(not(feature = "latex_support"))]
+        {
+            crate::trace_error_expr!("this_if = {}", $this_if.get());
+            /// there are no other cases
+            unreachable!();
+        }
     }
     use crate::section_0211::hmode;
     use crate::section_0211::mmode;
@@ -5,8 +5,17 @@ pub(crate) macro Test_box_register_status($globals:expr, $this_if:expr, $b:expr)
     /// for traversing token lists in `\ifx` tests
     let p: pointer;
     // begin scan_eight_bit_int; p:=box(cur_val);
-    scan_eight_bit_int($globals)?;
-    p = r#box!($globals, $globals.cur_val);
+    // TeX-rs: `latex_support` has box registers up to 32767
+    #[cfg(feature = "latex_support")]
+    {
+        crate::latex_support::scan_register_num($globals)?;
+        p = crate::latex_support::fetch_box($globals, $globals.cur_val)?;
+    }
+    #[cfg(not(feature = "latex_support"))]
+    {
+        scan_eight_bit_int($globals)?;
+        p = r#box!($globals, $globals.cur_val);
+    }
     // if this_if=if_void_code then b:=(p=null)
     if $this_if == if_void_code {
         $b = p == null;
@@ -29,7 +38,9 @@ pub(crate) macro Test_box_register_status($globals:expr, $this_if:expr, $b:expr)
     use crate::section_0133::r#type;
     use crate::section_0135::hlist_node;
     use crate::section_0137::vlist_node;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0230::r#box;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0433::scan_eight_bit_int;
     use crate::section_0487::*;
 }}
@@ -46,7 +46,7 @@ pub(crate) macro Select_the_appropriate_case_and_return_or_goto_common_ending($g
         // end;
     }
     // change_if_limit(or_code,save_cond_ptr);
-    change_if_limit($globals, or_code.into(), $save_cond_ptr);
+    change_if_limit($globals, or_code.into(), $save_cond_ptr)?;
     // return; {wait for \.{\\or}, \.{\\else}, or \.{\\fi}}
     /// wait for `\or`, `\else`, or `\fi`
     crate::return_nojump!();
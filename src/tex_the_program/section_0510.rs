@@ -9,7 +9,7 @@ pub(crate) macro Terminate_the_current_conditional_and_skip_to_fi($globals:expr)
         //   if if_limit=if_code then insert_relax {condition not yet evaluated}
         if $globals.if_limit == if_code {
             /// condition not yet evaluated
-            insert_relax($globals);
+            insert_relax($globals)?;
         }
         //   else  begin print_err("Extra "); print_cmd_chr(fi_or_else,cur_chr);
         else {
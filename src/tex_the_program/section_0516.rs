@@ -10,15 +10,15 @@
     feature = "trace_verbose",
     tracing::instrument(level = "trace", skip(globals))
 )]
-pub(crate) fn more_name(globals: &mut TeXGlobals, c: ASCII_code) -> boolean {
+pub(crate) fn more_name(globals: &mut TeXGlobals, c: ASCII_code) -> TeXResult<boolean> {
     // begin if c=" " then more_name:=false
     if c == ASCII_code_literal!(b' ') {
-        return false;
+        crate::return_nojump!(false);
     }
     // else  begin str_room(1); append_char(c); {contribute |c| to the current string}
     else {
         /// contribute `c` to the current string
-        str_room(globals, c.len_bytes() as _);
+        str_room(globals, c.len_bytes() as _)?;
         append_char(make_globals_string_view!(globals), c);
         // if (c=">")or(c=":") then
         if c == ASCII_code_literal!(b'>') || c == ASCII_code_literal!(b':') {
@@ -32,7 +32,7 @@ pub(crate) fn more_name(globals: &mut TeXGlobals, c: ASCII_code) -> boolean {
             globals.ext_delimiter = pool_pointer::new(cur_length!(globals) as _);
         }
         // more_name:=true;
-        return true;
+        crate::return_nojump!(true);
         // end;
     }
     // end;
@@ -48,3 +48,4 @@ use crate::section_0038::pool_pointer;
 use crate::section_0041::cur_length;
 use crate::section_0042::append_char;
 use crate::section_0042::str_room;
+use crate::section_0081::TeXResult;
@@ -14,7 +14,7 @@ pub(crate) fn make_name_string(mut globals: TeXGlobalsIoStringView<'_>) -> str_n
     // begin if (pool_ptr+name_length>pool_size)or(str_ptr=max_strings)or
     //  (cur_length>0) then
     if globals.pool_ptr.get() + globals.name_length.get() as pool_pointer_repr
-        > pool_size as pool_pointer_repr
+        > *globals.pool_size as pool_pointer_repr
         || globals.str_ptr.get() == max_strings as str_number_repr
         || cur_length!(globals) > 0
     {
@@ -71,7 +71,6 @@ use crate::section_0004::TeXGlobals;
 use crate::section_0004::TeXGlobalsIoStringView;
 use crate::section_0004::TeXGlobalsStringView;
 use crate::section_0011::max_strings;
-use crate::section_0011::pool_size;
 use crate::section_0020::xord;
 use crate::section_0025::alpha_file;
 use crate::section_0025::byte_file;
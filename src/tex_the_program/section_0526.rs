@@ -23,7 +23,7 @@ pub(crate) fn scan_file_name(globals: &mut TeXGlobals) -> TeXResult<()> {
             /// not a character
             {
                 // begin back_input; goto done;
-                back_input(globals);
+                back_input(globals)?;
                 crate::goto_forward_label!('done);
             }
             //   end;
@@ -31,7 +31,7 @@ pub(crate) fn scan_file_name(globals: &mut TeXGlobals) -> TeXResult<()> {
 
         crate::trace_expr_verbose!("cur_chr = {:?}", globals.cur_chr);
         // if not more_name(cur_chr) then goto done;
-        if !more_name(globals, ASCII_code::from(globals.cur_chr)) {
+        if !more_name(globals, ASCII_code::from(globals.cur_chr))? {
             crate::goto_forward_label!('done);
         }
         // get_x_token;
@@ -15,7 +15,7 @@ pub(crate) macro Scan_file_name_in_the_buffer($globals:expr) {{
                 crate::goto_forward_label!('done);
             }
             // if not more_name(buffer[k]) then goto done;
-            if !more_name($globals, $globals.buffer[k]) {
+            if !more_name($globals, $globals.buffer[k])? {
                 crate::goto_forward_label!('done);
             }
             // incr(k);
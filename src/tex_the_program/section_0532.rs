@@ -6,10 +6,13 @@ pub(crate) macro ensure_dvi_open($globals:expr) {{
     if $globals.output_file_name == 0 {
         // begin if job_name=0 then open_log_file;
         if $globals.job_name == 0 {
-            open_log_file($globals);
+            open_log_file($globals)?;
         }
+        /// extension of the output file
+        // TeX-rs: it is `.dvi` unless another output driver is selected
+        let ext = with_output_driver!($globals, D => D::extension());
         // pack_job_name(".dvi");
-        pack_job_name($globals, crate::strpool_str!(".dvi"));
+        pack_job_name($globals, ext);
         // while not b_open_out(dvi_file) do
         while !b_open_out(
             make_globals_filename_view!($globals),
@@ -19,7 +22,7 @@ pub(crate) macro ensure_dvi_open($globals:expr) {{
             prompt_file_name(
                 $globals,
                 crate::strpool_str!("file name for output"),
-                crate::strpool_str!(".dvi"),
+                ext,
             )?;
         }
         // output_file_name:=b_make_name_string(dvi_file);
@@ -29,6 +32,8 @@ pub(crate) macro ensure_dvi_open($globals:expr) {{
         );
         // end
     }
+    use crate::output_driver::with_output_driver;
+    use crate::output_driver::ShipOutDriver;
     use crate::section_0004::make_globals_filename_view;
     use crate::section_0004::make_globals_io_string_view;
     use crate::section_0004::TeXGlobalsFilenameView;
@@ -2,7 +2,7 @@
 //! it catch up to what has previously been printed on the terminal.
 //
 // @p procedure open_log_file;
-pub(crate) fn open_log_file(globals: &mut TeXGlobals) {
+pub(crate) fn open_log_file(globals: &mut TeXGlobals) -> TeXResult<()> {
     // var old_setting:0..max_selector; {previous |selector| setting}
     /// previous `selector` setting
     let old_setting;
@@ -15,14 +15,18 @@ pub(crate) fn open_log_file(globals: &mut TeXGlobals) {
     old_setting = globals.selector;
     // if job_name=0 then job_name:="texput";
     if globals.job_name == 0 {
-        globals.job_name = crate::strpool_str!("texput");
+        // TeX-rs: a job name given along with the job comes first.
+        globals.job_name = match job_name_from_option(globals) {
+            Some(job_name) => job_name,
+            None => crate::strpool_str!("texput"),
+        };
     }
     // @.texput@>
     // pack_job_name(".log");
     pack_job_name(globals, crate::strpool_str!(".log"));
     // while not a_open_out(log_file) do @<Try to get a different log file name@>;
     while !a_open_out(make_globals_filename_view!(globals), &mut globals.log_file) {
-        todo!("not yet implemented in {}", file!())
+        crate::section_0535::Try_to_get_a_different_log_file_name!(globals);
     }
     // log_name:=a_make_name_string(log_file);
     globals.log_name =
@@ -63,8 +67,10 @@ pub(crate) fn open_log_file(globals: &mut TeXGlobals) {
     const _: () = ();
     globals.selector = old_setting + 2;
     // end;
+    crate::ok_nojump!()
 }
 
+use crate::command_line::job_name_from_option;
 use crate::section_0004::make_globals_filename_view;
 use crate::section_0004::make_globals_io_string_log_view;
 use crate::section_0004::make_globals_io_string_view;
@@ -79,6 +85,7 @@ use crate::section_0054::log_only;
 use crate::section_0057::print_ln;
 use crate::section_0059::print;
 use crate::section_0062::print_nl;
+use crate::section_0081::TeXResult;
 use crate::section_0236::end_line_char;
 use crate::section_0525::a_make_name_string;
 use crate::section_0529::pack_job_name;
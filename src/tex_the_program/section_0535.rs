@@ -11,9 +11,18 @@
 //! Incidentally, the program always refers to the log file as a `\.{transcript
 //! file}', because some systems cannot use the extension `\.{.log}' for
 //! this file.
-//!
-//! @<Try to get a different log file name@>=
-//! begin selector:=term_only;
-//! prompt_file_name("transcript file name",".log");
-//! end
-//!
+//
+// @<Try to get a different log file name@>=
+pub(crate) macro Try_to_get_a_different_log_file_name($globals:expr) {{
+    // begin selector:=term_only;
+    $globals.selector = term_only.into();
+    // prompt_file_name("transcript file name",".log");
+    prompt_file_name(
+        $globals,
+        crate::strpool_str!("transcript file name"),
+        crate::strpool_str!(".log"),
+    )?;
+    // end
+    use crate::section_0054::term_only;
+    use crate::section_0530::prompt_file_name;
+}}
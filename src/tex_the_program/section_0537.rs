@@ -25,7 +25,7 @@ pub(crate) fn start_input(globals: &mut TeXGlobals) -> TeXResult<()> {
     // loop@+  begin begin_file_reading; {set up |cur_file| and new level of input}
     loop {
         /// set up `cur_file` and new level of input
-        begin_file_reading(globals);
+        begin_file_reading(globals)?;
         // if a_open_in(cur_file) then goto done;
         if a_open_in(make_globals_filename_view!(globals), &mut cur_file!(globals)) {
             crate::goto_forward_label!('done);
@@ -60,8 +60,12 @@ pub(crate) fn start_input(globals: &mut TeXGlobals) -> TeXResult<()> {
     // if job_name=0 then
     if globals.job_name == 0 {
         // begin job_name:=cur_name; open_log_file;
-        globals.job_name = globals.cur_name;
-        open_log_file(globals);
+        // TeX-rs: a job name given along with the job comes first.
+        globals.job_name = match job_name_from_option(globals) {
+            Some(job_name) => job_name,
+            None => globals.cur_name,
+        };
+        open_log_file(globals)?;
         // end; {|open_log_file| doesn't |show_context|, so |limit|
         //   and |loc| needn't be set to meaningful values yet}
         /// `open_log_file` doesn't `show_context`, so `limit`
@@ -88,7 +92,7 @@ pub(crate) fn start_input(globals: &mut TeXGlobals) -> TeXResult<()> {
     );
     incr!(globals.open_parens);
     crate::trace_expr_verbose!("open_parens = {:?}", globals.open_parens);
-    slow_print(globals, name!(globals).into());
+    slow_print(globals, name!(globals) as _);
     update_terminal(globals);
     // state:=new_line;
     state!(globals) = new_line;
@@ -107,6 +111,7 @@ pub(crate) fn start_input(globals: &mut TeXGlobals) -> TeXResult<()> {
     crate::return_nojump!();
 }
 
+use crate::command_line::job_name_from_option;
 use crate::pascal::integer;
 use crate::section_0004::make_globals_filename_view;
 use crate::section_0004::make_globals_io_string_log_view;
@@ -9,11 +9,7 @@ pub(crate) macro Read_the_first_line_of_the_new_file($globals:expr) {
     // begin line:=1;
     $globals.line = 1;
     // if input_ln(cur_file,false) then do_nothing;
-    if input_ln(
-        make_globals_io_view!($globals),
-        &mut cur_file!($globals),
-        false,
-    ) {
+    if input_ln_or_report_overflow!($globals, &mut cur_file!($globals), false) {
         do_nothing!();
     }
     // firm_up_the_line;
@@ -27,7 +23,7 @@ pub(crate) macro Read_the_first_line_of_the_new_file($globals:expr) {
         $globals.buffer[limit!($globals)] = end_line_char!($globals).into();
     }
     // first:=limit+1; loc:=start;
-    $globals.first = (limit!($globals) + 1).into();
+    $globals.first = (limit!($globals) as u16 + 1).into();
     loc!($globals) = start!($globals);
     // end
 
@@ -35,7 +31,7 @@ pub(crate) macro Read_the_first_line_of_the_new_file($globals:expr) {
     use crate::section_0004::TeXGlobalsIoView;
     use crate::section_0016::decr;
     use crate::section_0016::do_nothing;
-    use crate::section_0031::input_ln;
+    use crate::section_0035::input_ln_or_report_overflow;
     use crate::section_0036::loc;
     use crate::section_0236::end_line_char;
     use crate::section_0302::limit;
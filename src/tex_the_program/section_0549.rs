@@ -13,7 +13,7 @@ pub(crate) const non_address: font_index = font_index::zero();
 // @!font_info:array[font_index] of memory_word;
 //   {the big collection of font data}
 #[globals_struct_field(TeXGlobals)]
-pub(crate) static font_info: font_index_array<memory_word> = font_index_array::default_zeroed();
+pub(crate) static font_info: font_index_array<memory_word> = font_index_array::default();
 
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0549::font_index_array;
@@ -125,40 +125,29 @@ pub(crate) static font_false_bchar: internal_font_array<ASCII_code_or_non_char>
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0549::internal_font_array;
 
-type font_index_array_LENGTH_TYPENUM = typenum::op!(font_mem_size_TYPENUM - U0 + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length!(
-    pub(crate) font_index_array[font_index] =>
-    u16; U16; U0; font_index_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) font_index_array[font_index] => u16; U16; U0
 );
 
-type internal_font_array_LENGTH_TYPENUM = typenum::op!(font_max_TYPENUM - font_base_TYPENUM + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length!(
-    pub(crate) internal_font_array[internal_font_number] =>
-    u16; U16; font_base_TYPENUM; internal_font_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) internal_font_array[internal_font_number] => u16; U16; font_base_TYPENUM
 );
+
+#[cfg(feature = "wide_pointers")]
+impl_index_with_wide_pointer!(font_index_array, U0);
+#[cfg(feature = "wide_pointers")]
+impl_index_with_wide_pointer!(internal_font_array, font_base_TYPENUM);
 use crate::pascal::boolean;
 use crate::section_0004::TeXGlobals;
-use crate::section_0011::font_max_TYPENUM;
-use crate::section_0011::font_mem_size_TYPENUM;
 use crate::section_0012::font_base_TYPENUM;
 use crate::section_0018::ASCII_code;
 use crate::section_0038::str_number;
-use crate::section_0113::memory_word;
 use crate::section_0548::font_index;
 use crate::section_0548::internal_font_number;
 use crate::section_0907::ASCII_code_or_non_char;
 
 use globals_struct::{globals_struct_field, globals_struct_use};
 use typenum::U0;
-use typenum::U1;
-
-impl font_index_array<memory_word> {
-    pub(crate) fn default_zeroed() -> Self {
-        unsafe { core::mem::zeroed() }
-    }
-}
 
 #[allow(unused_variables)]
 pub(crate) fn font_name_str(globals: &mut TeXGlobals, font: internal_font_number) -> str_number {
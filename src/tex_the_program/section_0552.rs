@@ -43,7 +43,7 @@ pub(crate) macro Initialize_table_entries_done_by_initex_only_0552($globals:expr
     // param_base[null_font]:=-1;
     globals.param_base[null_font] = -1;
     // for k:=0 to 6 do font_info[k].sc:=0;
-    for k in 0..=6 {
+    for k in 0..=6u16 {
         globals.font_info[k][MEMORY_WORD_SC] = scaled::zero();
     }
 }}
@@ -9,7 +9,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0553($globals
         crate::strpool_str!("nullfont"),
         set_font,
         null_font.get() as _,
-    );
+    )?;
     // @!@:null_font_}{\.{\\nullfont} primitive@>
     // text(frozen_null_font):="nullfont"; eqtb[frozen_null_font]:=eqtb[cur_val];
     text!(globals, frozen_null_font as pointer) = crate::strpool_str!("nullfont").get() as _;
@@ -39,7 +39,9 @@ pub(crate) macro extra_space($globals:expr, $v:expr) {
 pub(crate) macro The_em_width_for_cur_font($globals:expr) {
     crate::section_0558::quad!(
         $globals,
-        crate::section_0548::internal_font_number::new(crate::section_0230::cur_font!($globals))
+        crate::section_0548::internal_font_number::new(
+            crate::section_0230::cur_font!($globals) as u16
+        )
     )
 }
 //
@@ -6,7 +6,9 @@
 //! @.TFtoPL@> @.PLtoTF@>
 //
 // @d start_font_error_message==print_err("Font "); sprint_cs(u);
-pub(crate) macro start_font_error_message($globals:expr, $nom:expr, $aire:expr, $s:expr) {{
+pub(crate) macro start_font_error_message($globals:expr, $u:expr, $nom:expr, $aire:expr, $s:expr) {{
+    print_err!($globals, crate::strpool_str!("Font "));
+    sprint_cs($globals, $u);
     // print_char("="); print_file_name(nom,aire,"");
     print_char(
         make_globals_io_string_log_view!($globals),
@@ -39,14 +41,16 @@ pub(crate) macro start_font_error_message($globals:expr, $nom:expr, $aire:expr,
     use crate::section_0058::print_char;
     use crate::section_0059::print;
     use crate::section_0065::print_int;
+    use crate::section_0073::print_err;
     use crate::section_0103::print_scaled;
+    use crate::section_0263::sprint_cs;
     use crate::section_0518::print_file_name;
 }}
 
 // @<Report that the font won't be loaded@>=
-pub(crate) macro Report_that_the_font_wont_be_loaded($globals:expr, $nom:expr, $aire:expr, $s:expr, $file_opened:expr) {{
+pub(crate) macro Report_that_the_font_wont_be_loaded($globals:expr, $u:expr, $nom:expr, $aire:expr, $s:expr, $file_opened:expr) {{
     // start_font_error_message;
-    start_font_error_message!($globals, $nom, $aire, $s);
+    start_font_error_message!($globals, $u, $nom, $aire, $s);
     // @.Font x=xx not loadable...@>
     // if file_opened then print(" not loadable: Bad metric (TFM) file")
     if $file_opened {
@@ -67,8 +71,17 @@ pub(crate) macro Report_that_the_font_wont_be_loaded($globals:expr, $nom:expr, $
     // ("[Wizards can fix TFM files using TFtoPL/PLtoTF.]")@/
     // ("You might try inserting a different font spec;")@/
     // ("e.g., type `I\font<same font id>=<substitute font name>'.");
+    help5!(
+        $globals,
+        crate::strpool_str!("I wasn't able to read the size data for this font,"),
+        crate::strpool_str!("so I will ignore the font specification."),
+        crate::strpool_str!("[Wizards can fix TFM files using TFtoPL/PLtoTF.]"),
+        crate::strpool_str!("You might try inserting a different font spec;"),
+        crate::strpool_str!("e.g., type `I\\font<same font id>=<substitute font name>'.")
+    );
     // error
     error($globals)?;
     use crate::section_0059::print;
+    use crate::section_0079::help5;
     use crate::section_0082::error;
 }}
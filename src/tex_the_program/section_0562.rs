@@ -1,7 +1,7 @@
 //! ` `
 // @<Read and check...@>=
 pub(crate) macro Read_and_check_the_font_data__abort_if_the_TFM_file_is_malformed__if_there_s_no_room_for_this_font__say_so_and_goto_done__otherwise_incr_font_ptr_and_goto_done {
-    ($globals:expr, $s:expr, $g:expr, $nom:expr, $aire:expr, $file_opened:expr, $lbl_bad_tfm:lifetime, $lbl_done:lifetime) => {{
+    ($globals:expr, $u:expr, $s:expr, $g:expr, $nom:expr, $aire:expr, $file_opened:expr, $lbl_bad_tfm:lifetime, $lbl_done:lifetime) => {{
         /// sizes of subfiles
         #[rustfmt::skip]
         let (mut lf, mut lh, mut bc, mut ec, mut nw, mut nh, mut nd, mut ni, mut nl, mut nk, mut ne, mut np):
@@ -31,7 +31,7 @@ pub(crate) macro Read_and_check_the_font_data__abort_if_the_TFM_file_is_malforme
         );
         // @<Use size fields to allocate font information@>;
         crate::section_0566::Use_size_fields_to_allocate_font_information!(
-            $globals, f, lf, lh, bc, ec, nw, nh, nd, ni, nl, nk, ne, np
+            $globals, $u, $nom, $aire, $s, f, lf, lh, bc, ec, nw, nh, nd, ni, nl, nk, ne, np, $lbl_done
         );
         // @<Read the {\.{TFM}} header@>;
         crate::section_0568::Read_the_TFM_header!($globals, f, $s, z, lh, $lbl_bad_tfm);
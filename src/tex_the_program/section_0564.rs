@@ -16,14 +16,14 @@ pub(crate) macro fbyte($globals:expr) {
 }
 // @d read_sixteen(#)==begin #:=fbyte;
 pub(crate) macro read_sixteen($globals:expr, $val:expr, $lbl_bad_tfm:lifetime) {
-    $val = fbyte!($globals) as u16;
+    $val = fbyte!($globals) as crate::section_0113::halfword;
     // if #>127 then abort;
     if $val > 127 {
         crate::goto_forward_label!($lbl_bad_tfm);
     }
     // fget; #:=#*@'400+fbyte;
     fget!($globals);
-    $val = $val * 0o400 + fbyte!($globals) as u16;
+    $val = $val * 0o400 + fbyte!($globals) as crate::section_0113::halfword;
     // end
 }
 
@@ -5,8 +5,8 @@
 //! until later.
 //
 // @<Use size fields to allocate font information@>=
-pub(crate) macro Use_size_fields_to_allocate_font_information($globals:expr, $f:expr, $lf:expr, $lh:expr, $bc:expr, $ec:expr, $nw:expr, $nh:expr,
-        $nd:expr, $ni:expr, $nl:expr, $nk:expr, $ne:expr, $np:expr) {{
+pub(crate) macro Use_size_fields_to_allocate_font_information($globals:expr, $u:expr, $nom:expr, $aire:expr, $s:expr, $f:expr, $lf:expr, $lh:expr, $bc:expr, $ec:expr, $nw:expr, $nh:expr,
+        $nd:expr, $ni:expr, $nl:expr, $nk:expr, $ne:expr, $np:expr, $lbl_done:lifetime) {{
     // lf:=lf-6-lh; {|lf| words should be loaded into |font_info|}
     /// `lf` words should be loaded into `font_info`
     const _: () = ();
@@ -17,9 +17,13 @@ pub(crate) macro Use_size_fields_to_allocate_font_information($globals:expr, $f:
         $lf = $lf + 7 - $np;
     }
     // if (font_ptr=font_max)or(fmem_ptr+lf>font_mem_size) then
-    if $globals.font_ptr.get() == font_max as u16 || $globals.fmem_ptr + $lf > font_mem_size {
+    if $globals.font_ptr.get() == $globals.font_max as u16
+        || $globals.fmem_ptr.get() as integer + $lf as integer > $globals.font_mem_size as integer
+    {
         //   @<Apologize for not loading the font, |goto done|@>;
-        todo!("apologize");
+        crate::section_0567::Apologize_for_not_loading_the_font__goto_done!(
+            $globals, $u, $nom, $aire, $s, $lbl_done
+        );
     }
     // f:=font_ptr+1;
     $f = $globals.font_ptr + 1;
@@ -43,7 +47,5 @@ pub(crate) macro Use_size_fields_to_allocate_font_information($globals:expr, $f:
     $globals.param_base[$f] = $globals.exten_base[$f] + $ne as integer;
 
     use crate::pascal::integer;
-    use crate::section_0011::font_max;
-    use crate::section_0011::font_mem_size;
     use crate::section_0557::kern_base_offset;
 }}
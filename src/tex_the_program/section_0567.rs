@@ -1,11 +1,32 @@
-//! @ @<Apologize for not loading...@>=
-//! begin start_font_error_message;
-//! print(" not loaded: Not enough room left");
-//! @.Font x=xx not loaded...@>
-//! help4("I'm afraid I won't be able to make use of this font,")@/
-//! ("because my memory for character-size data is too small.")@/
-//! ("If you're really stuck, ask a wizard to enlarge me.")@/
-//! ("Or maybe try `I\font<same font id>=<name of loaded font>'.");
-//! error; goto done;
-//! end
-//!
+//! ` `
+
+// @<Apologize for not loading...@>=
+pub(crate) macro Apologize_for_not_loading_the_font__goto_done($globals:expr, $u:expr, $nom:expr, $aire:expr, $s:expr, $lbl_done:lifetime) {{
+    // begin start_font_error_message;
+    start_font_error_message!($globals, $u, $nom, $aire, $s);
+    // print(" not loaded: Not enough room left");
+    print(
+        $globals,
+        crate::strpool_str!(" not loaded: Not enough room left").get() as _,
+    );
+    // @.Font x=xx not loaded...@>
+    // help4("I'm afraid I won't be able to make use of this font,")@/
+    // ("because my memory for character-size data is too small.")@/
+    // ("If you're really stuck, ask a wizard to enlarge me.")@/
+    // ("Or maybe try `I\font<same font id>=<name of loaded font>'.");
+    help4!(
+        $globals,
+        crate::strpool_str!("I'm afraid I won't be able to make use of this font,"),
+        crate::strpool_str!("because my memory for character-size data is too small."),
+        crate::strpool_str!("If you're really stuck, ask a wizard to enlarge me."),
+        crate::strpool_str!("Or maybe try `I\\font<same font id>=<name of loaded font>'.")
+    );
+    // error; goto done;
+    error($globals)?;
+    crate::goto_forward_label!($lbl_done);
+    // end
+    use crate::section_0059::print;
+    use crate::section_0079::help4;
+    use crate::section_0082::error;
+    use crate::section_0561::start_font_error_message;
+}}
@@ -12,7 +12,7 @@ pub(crate) macro Read_the_TFM_header($globals:expr, $f:expr, $s:expr, $z:expr, $
     /// this rejects a negative design size
     const _: () = ();
     fget!($globals);
-    let mut z0: u16;
+    let mut z0: crate::section_0113::halfword;
     read_sixteen!($globals, z0, $lbl_bad_tfm);
     let mut z0: integer = z0 as integer;
     // fget; z:=z*@'400+fbyte; fget; z:=(z*@'20)+(fbyte div@'20);
@@ -12,10 +12,10 @@ pub(crate) macro Read_character_data($globals:expr, $f:expr, $bc:expr, $ec:expr,
         $globals.font_info[k][MEMORY_WORD_QQQQ] = qqqq;
         // if (a>=nw)or(b div @'20>=nh)or(b mod @'20>=nd)or
         //   (c div 4>=ni) then abort;
-        if qqqq[FOUR_QUARTERS_B0] as u16 >= $nw
-            || qqqq[FOUR_QUARTERS_B1] as u16 / 0o20 >= $nh
-            || qqqq[FOUR_QUARTERS_B1] as u16 % 0o20 >= $nd
-            || qqqq[FOUR_QUARTERS_B2] as u16 / 4 >= $ni
+        if qqqq[FOUR_QUARTERS_B0] as halfword >= $nw
+            || qqqq[FOUR_QUARTERS_B1] as halfword / 0o20 >= $nh
+            || qqqq[FOUR_QUARTERS_B1] as halfword % 0o20 >= $nd
+            || qqqq[FOUR_QUARTERS_B2] as halfword / 4 >= $ni
         {
             crate::goto_forward_label!($lbl_bad_tfm);
         }
@@ -23,13 +23,13 @@ pub(crate) macro Read_character_data($globals:expr, $f:expr, $bc:expr, $ec:expr,
         let c_mod_4: char_tag = char_tag::from(qqqq[FOUR_QUARTERS_B2] % 4);
         // lig_tag: if d>=nl then abort;
         if c_mod_4 == char_tag::lig_tag {
-            if qqqq[FOUR_QUARTERS_B3] as u16 >= $nl {
+            if qqqq[FOUR_QUARTERS_B3] as halfword >= $nl {
                 crate::goto_forward_label!($lbl_bad_tfm);
             }
         }
         // ext_tag: if d>=ne then abort;
         else if c_mod_4 == char_tag::ext_tag {
-            if qqqq[FOUR_QUARTERS_B3] as u16 >= $ne {
+            if qqqq[FOUR_QUARTERS_B3] as halfword >= $ne {
                 crate::goto_forward_label!($lbl_bad_tfm);
             }
         }
@@ -57,6 +57,7 @@ pub(crate) macro Read_character_data($globals:expr, $f:expr, $bc:expr, $ec:expr,
     use crate::pascal::integer;
     use crate::section_0016::do_nothing;
     use crate::section_0113::four_quarters;
+    use crate::section_0113::halfword;
     use crate::section_0113::FOUR_QUARTERS_B0;
     use crate::section_0113::FOUR_QUARTERS_B1;
     use crate::section_0113::FOUR_QUARTERS_B2;
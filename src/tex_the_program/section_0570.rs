@@ -12,7 +12,8 @@ pub(crate) macro check_byte_range($globals:expr, $chr:expr, $bc:expr, $ec:expr,
 }}
 // @d current_character_being_worked_on==k+bc-fmem_ptr
 pub(crate) macro current_character_being_worked_on($globals:expr, $k:expr, $bc:expr) {
-    ($k + $bc - $globals.fmem_ptr.get()) as crate::section_0113::quarterword
+    ($k + $bc - $globals.fmem_ptr.get() as crate::section_0113::halfword)
+        as crate::section_0113::quarterword
 }
 
 // @<Check for charlist cycle@>=
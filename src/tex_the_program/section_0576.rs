@@ -14,7 +14,7 @@ pub(crate) macro Make_final_adjustments_and_goto_done($globals:expr, $f:expr, $g
         $nom:expr, $aire:expr, $lbl_done:lifetime) {{
     // if np>=7 then font_params[f]:=np@+else font_params[f]:=7;
     if $np >= 7 {
-        $globals.font_params[$f] = $np.into();
+        $globals.font_params[$f] = ($np as u16).into();
     } else {
         $globals.font_params[$f] = 7.into();
     }
@@ -65,7 +65,7 @@ pub(crate) macro Make_final_adjustments_and_goto_done($globals:expr, $f:expr, $g
     // decr(param_base[f]);
     decr!($globals.param_base[$f]);
     // fmem_ptr:=fmem_ptr+lf; font_ptr:=f; g:=f; goto done
-    $globals.fmem_ptr = $globals.fmem_ptr + $lf;
+    $globals.fmem_ptr = $globals.fmem_ptr + $lf as u16;
     $globals.font_ptr = $f;
     $g = $f;
     crate::goto_forward_label!($lbl_done);
@@ -3,7 +3,6 @@
 //
 // @<Declare procedures that scan font-related stuff@>=
 // procedure scan_font_ident;
-#[allow(unused_variables)]
 pub(crate) fn scan_font_ident(globals: &mut TeXGlobals) -> TeXResult<()> {
     // var f:internal_font_number;
     let f: internal_font_number;
@@ -24,17 +23,24 @@ pub(crate) fn scan_font_ident(globals: &mut TeXGlobals) -> TeXResult<()> {
         // begin m:=cur_chr; scan_four_bit_int; f:=equiv(m+cur_val);
         m = globals.cur_chr.get() as _;
         scan_four_bit_int(globals)?;
-        f = equiv!(globals, m + globals.cur_val).into();
+        f = (equiv!(globals, m + globals.cur_val) as u16).into();
         // end
     }
     // else  begin print_err("Missing font identifier");
     else {
-        todo!("error");
+        print_err!(globals, crate::strpool_str!("Missing font identifier"));
         // @.Missing font identifier@>
-        //   help2("I was looking for a control sequence whose")@/
-        //   ("current meaning has been defined by \font.");
-        //   back_error; f:=null_font;
-        //   end;
+        // help2("I was looking for a control sequence whose")@/
+        // ("current meaning has been defined by \font.");
+        help2!(
+            globals,
+            crate::strpool_str!("I was looking for a control sequence whose"),
+            crate::strpool_str!("current meaning has been defined by \\font.")
+        );
+        // back_error; f:=null_font;
+        back_error(globals)?;
+        f = null_font;
+        // end;
     }
     // cur_val:=f;
     globals.cur_val = f.get() as _;
@@ -44,10 +50,14 @@ pub(crate) fn scan_font_ident(globals: &mut TeXGlobals) -> TeXResult<()> {
 
 use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
+use crate::section_0073::print_err;
+use crate::section_0079::help2;
 use crate::section_0081::TeXResult;
 use crate::section_0113::halfword;
 use crate::section_0209::*;
 use crate::section_0221::equiv;
 use crate::section_0230::cur_font;
+use crate::section_0232::null_font;
+use crate::section_0327::back_error;
 use crate::section_0435::scan_four_bit_int;
 use crate::section_0548::internal_font_number;
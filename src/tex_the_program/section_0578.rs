@@ -55,7 +55,7 @@ pub(crate) fn find_font_dimen(globals: &mut TeXGlobals, writing: boolean) -> TeX
         // end;
     }
     // @<Issue an error message if |cur_val=fmem_ptr|@>;
-    crate::section_0579::Issue_an_error_message_if_cur_val_eq_fmem_ptr!(globals);
+    crate::section_0579::Issue_an_error_message_if_cur_val_eq_fmem_ptr!(globals, f);
     // end;
     crate::ok_nojump!()
 }
@@ -1,17 +1,41 @@
 //! ` `
 // @<Issue an error message if |cur_val=fmem_ptr|@>=
-pub(crate) macro Issue_an_error_message_if_cur_val_eq_fmem_ptr($globals:expr) {{
+pub(crate) macro Issue_an_error_message_if_cur_val_eq_fmem_ptr($globals:expr, $f:expr) {{
     // if cur_val=fmem_ptr then
     if $globals.cur_val == $globals.fmem_ptr.get() as integer {
-        todo!("issue error message");
-        //   begin print_err("Font "); print_esc(font_id_text(f));
-        //   print(" has only "); print_int(font_params[f]);
-        //   print(" fontdimen parameters");
+        // begin print_err("Font "); print_esc(font_id_text(f));
+        print_err!($globals, crate::strpool_str!("Font "));
+        print_esc(
+            $globals,
+            str_number::new(font_id_text!($globals, $f.get()) as _),
+        );
+        // print(" has only "); print_int(font_params[f]);
+        print($globals, crate::strpool_str!(" has only ").get() as _);
+        print_int($globals, $globals.font_params[$f].get() as _);
+        // print(" fontdimen parameters");
+        print(
+            $globals,
+            crate::strpool_str!(" fontdimen parameters").get() as _,
+        );
         // @.Font x has only...@>
-        //   help2("To increase the number of font parameters, you must")@/
-        //     ("use \fontdimen immediately after the \font is loaded.");
-        //   error;
-        //   end
+        // help2("To increase the number of font parameters, you must")@/
+        //   ("use \fontdimen immediately after the \font is loaded.");
+        help2!(
+            $globals,
+            crate::strpool_str!("To increase the number of font parameters, you must"),
+            crate::strpool_str!("use \\fontdimen immediately after the \\font is loaded.")
+        );
+        // error;
+        error($globals)?;
+        // end
     }
     use crate::pascal::integer;
+    use crate::section_0038::str_number;
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0065::print_int;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+    use crate::section_0256::font_id_text;
 }}
@@ -3,7 +3,7 @@
 pub(crate) macro Increase_the_number_of_parameters_in_the_last_font($globals:expr, $n:expr, $f:expr) {{
     // begin repeat if fmem_ptr=font_mem_size then
     loop {
-        if $globals.fmem_ptr.get() == font_mem_size {
+        if $globals.fmem_ptr.get() == $globals.font_mem_size {
             panic!("overflow");
             // overflow("font memory",font_mem_size);
         }
@@ -24,7 +24,6 @@ pub(crate) macro Increase_the_number_of_parameters_in_the_last_font($globals:exp
     // end
 
     use crate::pascal::integer;
-    use crate::section_0011::font_mem_size;
     use crate::section_0016::incr;
     use crate::section_0101::scaled;
     use crate::section_0101::MEMORY_WORD_SC;
@@ -8,7 +8,7 @@ pub(crate) fn new_character(
     globals: &mut TeXGlobals,
     f: internal_font_number,
     c: ASCII_code,
-) -> pointer {
+) -> TeXResult<pointer> {
     // label exit;
     // var p:pointer; {newly allocated node}
     // begin if font_bc[f]<=c then if font_ec[f]>=c then
@@ -19,22 +19,23 @@ pub(crate) fn new_character(
             let p: pointer;
 
             // begin p:=get_avail; font(p):=f; character(p):=qi(c);
-            p = get_avail(globals);
+            p = get_avail(globals)?;
             assign_font_and_character!(globals, p, f, c);
             // new_character:=p; return;
-            return p;
+            crate::return_nojump!(p);
             // end;
         }
     }
     // char_warning(f,c);
     char_warning(globals, f, c);
     // new_character:=null;
-    return null;
+    crate::ok_nojump!(null)
     // exit:end;
 }
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0018::ASCII_code;
+use crate::section_0081::TeXResult;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
 use crate::section_0120::get_avail;
@@ -28,11 +28,9 @@ pub(crate) type dvi_index = u16_from_0_to_n<dvi_buf_size_TYPENUM>;
 
 use crate::pascal::u16_from_0_to_n;
 
-type dvi_index_array_LENGTH_TYPENUM = typenum::op!(dvi_buf_size_TYPENUM + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_from_0_with_fixed_length!(
-    pub(crate) dvi_index_array[dvi_index] => u16; U16; dvi_index_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) dvi_index_array[dvi_index] => u16; U16; U0
 );
 
 use crate::section_0011::dvi_buf_size_TYPENUM;
-use typenum::U1;
+use typenum::U0;
@@ -13,6 +13,10 @@
 pub(crate) fn write_dvi(globals: &mut TeXGlobals, a: dvi_index, b: dvi_index) {
     // var k:dvi_index;
     // begin for k:=a to b do write(dvi_file,dvi_buf[k]);
+    // TeX-rs: a driver that writes its own kind of file keeps these bytes out of it
+    if !with_output_driver!(globals, D => D::WRITES_DVI) {
+        return;
+    }
     for k in a.get()..=b.get() {
         write_binary(&mut globals.dvi_file, globals.dvi_buf[k]);
     }
@@ -20,5 +24,7 @@ pub(crate) fn write_dvi(globals: &mut TeXGlobals, a: dvi_index, b: dvi_index) {
 }
 
 use crate::io_support::write_binary;
+use crate::output_driver::with_output_driver;
+use crate::output_driver::ShipOutDriver;
 use crate::section_0004::TeXGlobals;
 use crate::section_0594::dvi_index;
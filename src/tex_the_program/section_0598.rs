@@ -7,7 +7,7 @@ pub(crate) macro dvi_out($globals:expr, $v:expr) {{
     incr!($globals.dvi_ptr);
     // if dvi_ptr=dvi_limit then dvi_swap;
     if $globals.dvi_ptr == $globals.dvi_limit {
-        dvi_swap($globals);
+        dvi_swap($globals)?;
     }
     // end
     use crate::section_0016::incr;
@@ -16,30 +16,37 @@ pub(crate) macro dvi_out($globals:expr, $v:expr) {{
 
 // @p procedure dvi_swap; {outputs half of the buffer}
 /// outputs half of the buffer
-pub(crate) fn dvi_swap(globals: &mut TeXGlobals) {
+pub(crate) fn dvi_swap(globals: &mut TeXGlobals) -> TeXResult<()> {
+    // TeX-rs: a job that has produced all the output it may stops before writing more
+    crate::limits::check_output_limit(globals)?;
     // begin if dvi_limit=dvi_buf_size then
-    if globals.dvi_limit == dvi_buf_size {
+    if globals.dvi_limit == globals.dvi_buf_size {
         // begin write_dvi(0,half_buf-1); dvi_limit:=half_buf;
         write_dvi(globals, 0.into(), globals.half_buf - 1);
         globals.dvi_limit = globals.half_buf;
         // dvi_offset:=dvi_offset+dvi_buf_size; dvi_ptr:=0;
-        globals.dvi_offset += dvi_buf_size as integer;
+        globals.dvi_offset += globals.dvi_buf_size as integer;
         globals.dvi_ptr = 0.into();
         // end
     }
     // else  begin write_dvi(half_buf,dvi_buf_size-1); dvi_limit:=dvi_buf_size;
     else {
-        write_dvi(globals, globals.half_buf, dvi_index::new(dvi_buf_size - 1));
-        globals.dvi_limit = dvi_buf_size.into();
+        write_dvi(
+            globals,
+            globals.half_buf,
+            dvi_index::new(globals.dvi_buf_size - 1),
+        );
+        globals.dvi_limit = globals.dvi_buf_size.into();
         // end;
     }
     // dvi_gone:=dvi_gone+half_buf;
     globals.dvi_gone += globals.half_buf.get() as integer;
+    crate::ok_nojump!()
     // end;
 }
 
 use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
-use crate::section_0011::dvi_buf_size;
+use crate::section_0081::TeXResult;
 use crate::section_0594::dvi_index;
 use crate::section_0597::write_dvi;
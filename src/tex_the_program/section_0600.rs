@@ -2,7 +2,7 @@
 //! without risking arithmetic overflow.
 //
 // @p procedure dvi_four(@!x:integer);
-pub(crate) fn dvi_four(globals: &mut TeXGlobals, x: integer) {
+pub(crate) fn dvi_four(globals: &mut TeXGlobals, x: integer) -> TeXResult<()> {
     // begin if x>=0 then dvi_out(x div @'100000000)
     let mut x: word = if x >= 0 {
         dvi_out!(globals, x / 0o100000000);
@@ -27,10 +27,12 @@ pub(crate) fn dvi_four(globals: &mut TeXGlobals, x: integer) {
     dvi_out!(globals, x / 0o400);
     // dvi_out(x mod @'400);
     dvi_out!(globals, x % 0o400);
+    crate::ok_nojump!()
     // end;
 }
 
 use crate::pascal::integer;
 use crate::pascal::word;
 use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
 use crate::section_0598::dvi_out;
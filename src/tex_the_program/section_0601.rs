@@ -4,7 +4,7 @@
 //! following the old |push| that matches the new |pop|.
 //
 // @p procedure dvi_pop(@!l:integer);
-pub(crate) fn dvi_pop(globals: &mut TeXGlobals, l: integer) {
+pub(crate) fn dvi_pop(globals: &mut TeXGlobals, l: integer) -> TeXResult<()> {
     // begin if (l=dvi_offset+dvi_ptr)and(dvi_ptr>0) then decr(dvi_ptr)
     if l == globals.dvi_offset + globals.dvi_ptr.get() as integer && globals.dvi_ptr.get() > 0 {
         decr!(globals.dvi_ptr);
@@ -13,11 +13,13 @@ pub(crate) fn dvi_pop(globals: &mut TeXGlobals, l: integer) {
     else {
         dvi_out!(globals, pop.byte());
     }
+    crate::ok_nojump!()
     // end;
 }
 
 use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::decr;
+use crate::section_0081::TeXResult;
 use crate::section_0586::pop;
 use crate::section_0598::dvi_out;
@@ -2,7 +2,7 @@
 //! most 256 different fonts per job, |fnt_def1| is always used as the command code.
 //
 // @p procedure dvi_font_def(@!f:internal_font_number);
-pub(crate) fn dvi_font_def(globals: &mut TeXGlobals, f: internal_font_number) {
+pub(crate) fn dvi_font_def(globals: &mut TeXGlobals, f: internal_font_number) -> TeXResult<()> {
     // var k:pool_pointer; {index into |str_pool|}
     // begin dvi_out(fnt_def1);
     dvi_out!(globals, fnt_def1.byte());
@@ -17,15 +17,16 @@ pub(crate) fn dvi_font_def(globals: &mut TeXGlobals, f: internal_font_number) {
     // dvi_out(qo(font_check[f].b3));@/
     dvi_out!(globals, globals.font_check[f][FOUR_QUARTERS_B3]);
     // dvi_four(font_size[f]);
-    dvi_four(globals, globals.font_size[f].inner());
+    dvi_four(globals, globals.font_size[f].inner())?;
     // dvi_four(font_dsize[f]);@/
-    dvi_four(globals, globals.font_dsize[f].inner());
+    dvi_four(globals, globals.font_dsize[f].inner())?;
     // dvi_out(length(font_area[f]));
     dvi_out!(globals, length(globals, globals.font_area[f].get() as _));
     // dvi_out(length(font_name[f]));
     dvi_out!(globals, length(globals, globals.font_name[f].get() as _));
     // @<Output the font name whose internal number is |f|@>;
     crate::section_0603::Output_the_font_name_whose_internal_number_is_f!(globals, f);
+    crate::ok_nojump!()
     // end;
 }
 
@@ -33,6 +34,7 @@ use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
 use crate::section_0012::font_base;
 use crate::section_0040::length;
+use crate::section_0081::TeXResult;
 use crate::section_0113::FOUR_QUARTERS_B0;
 use crate::section_0113::FOUR_QUARTERS_B1;
 use crate::section_0113::FOUR_QUARTERS_B2;
@@ -16,7 +16,7 @@ pub(crate) macro Generate_a_down_or_right_command_for_w_and_return
             /// `down4` or `right4`
             dvi_out!($globals, $o.byte() + 3);
             // dvi_four(w); return;
-            dvi_four($globals, $w.inner());
+            dvi_four($globals, $w.inner())?;
             crate::return_nojump!();
             // end;
         }
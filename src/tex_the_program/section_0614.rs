@@ -8,7 +8,7 @@ pub(crate) macro Change_buffered_instruction_to_z_or_x_and_goto_found($globals:e
     k = location!($globals, $p) - $globals.dvi_offset;
     // if k<0 then k:=k+dvi_buf_size;
     if k < 0 {
-        k = k + dvi_buf_size as integer;
+        k = k + $globals.dvi_buf_size as integer;
     }
     // dvi_buf[k]:=dvi_buf[k]+z1-down1;
     $globals.dvi_buf[dvi_index::new(k as _)] = ($globals.dvi_buf[dvi_index::new(k as _)] as integer
@@ -19,7 +19,6 @@ pub(crate) macro Change_buffered_instruction_to_z_or_x_and_goto_found($globals:e
     crate::goto_forward_label!($lbl_found);
     // end
     use crate::pascal::integer;
-    use crate::section_0011::dvi_buf_size;
     use crate::section_0118::info_inner;
     use crate::section_0586::down1;
     use crate::section_0586::z1;
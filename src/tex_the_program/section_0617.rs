@@ -21,13 +21,13 @@ pub(crate) macro Initialize_variables_as_ship_out_begins($globals:expr) {{
         // dvi_four(25400000); dvi_four(473628672); {conversion ratio for sp}
         /// conversion ratio for sp
         const _: () = ();
-        dvi_four($globals, 25400000);
-        dvi_four($globals, 473628672);
+        dvi_four($globals, 25400000)?;
+        dvi_four($globals, 473628672)?;
         // prepare_mag; dvi_four(mag); {magnification factor is frozen}
         /// magnification factor is frozen
         const _: () = ();
-        prepare_mag($globals);
-        dvi_four($globals, mag!($globals));
+        prepare_mag($globals)?;
+        dvi_four($globals, mag!($globals))?;
         // old_setting:=selector; selector:=new_string;
         old_setting = $globals.selector;
         $globals.selector = new_string.into();
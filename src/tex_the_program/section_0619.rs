@@ -82,7 +82,7 @@ pub(crate) fn hlist_out(globals: &mut TeXGlobals) -> TeXResult<()> {
     prune_movements(globals, save_loc);
     // if cur_s>0 then dvi_pop(save_loc);
     if globals.cur_s > 0 {
-        dvi_pop(globals, save_loc);
+        dvi_pop(globals, save_loc)?;
     }
     // decr(cur_s);
     decr!(globals.cur_s);
@@ -22,6 +22,12 @@ pub(crate) macro Output_node_p_for_hlist_out_and_move_to_the_next_node__maintain
                 if $globals.ship_out_f != $globals.dvi_f {
                     crate::section_0621::Change_font_dvi_f_to_f!($globals);
                 }
+                // TeX-rs: the output driver is told of the character as well
+                with_output_driver!($globals, D => D::char(
+                    $globals,
+                    $globals.ship_out_f,
+                    $globals.ship_out_c
+                ));
                 #[cfg(not(feature = "unicode_support"))]
                 {
                     // if c>=qi(128) then dvi_out(set1);
@@ -35,7 +41,7 @@ pub(crate) macro Output_node_p_for_hlist_out_and_move_to_the_next_node__maintain
                 {
                     if $globals.ship_out_c.numeric_value() >= 256 {
                         dvi_out!($globals, set4.byte());
-                        dvi_four($globals, $globals.ship_out_c.numeric_value() as _);
+                        dvi_four($globals, $globals.ship_out_c.numeric_value() as _)?;
                         use crate::section_0586::set4;
                         use crate::section_0600::dvi_four;
                     } else {
@@ -76,6 +82,8 @@ pub(crate) macro Output_node_p_for_hlist_out_and_move_to_the_next_node__maintain
     }
     |'reswitch|
     };
+    use crate::output_driver::with_output_driver;
+    use crate::output_driver::ShipOutDriver;
     use crate::section_0118::link;
     use crate::section_0134::character;
     use crate::section_0134::font;
@@ -4,7 +4,7 @@ pub(crate) macro Change_font_dvi_f_to_f($globals:expr) {{
     // begin if not font_used[f] then
     if !$globals.font_used[$globals.ship_out_f] {
         // begin dvi_font_def(f); font_used[f]:=true;
-        dvi_font_def($globals, $globals.ship_out_f);
+        dvi_font_def($globals, $globals.ship_out_f)?;
         $globals.font_used[$globals.ship_out_f] = true;
         // end;
     }
@@ -23,14 +23,24 @@ pub(crate) macro Output_a_rule_in_an_hlist($globals:expr, $this_box:expr, $base_
         $globals.cur_v = $base_line + $globals.rule_dp;
         synch_v!($globals);
         // dvi_out(set_rule); dvi_four(rule_ht); dvi_four(rule_wd);
+        // TeX-rs: the output driver is told of the rule as well
+        with_output_driver!($globals, D => D::rule(
+            $globals,
+            $globals.cur_h,
+            $globals.cur_v,
+            $globals.rule_wd,
+            $globals.rule_ht
+        ));
         dvi_out!($globals, set_rule.byte());
-        dvi_four($globals, $globals.rule_ht.inner());
-        dvi_four($globals, $globals.rule_wd.inner());
+        dvi_four($globals, $globals.rule_ht.inner())?;
+        dvi_four($globals, $globals.rule_wd.inner())?;
         // cur_v:=base_line; dvi_h:=dvi_h+rule_wd;
         $globals.cur_v = $base_line;
         $globals.dvi_h += $globals.rule_wd;
         // end
     }
+    use crate::output_driver::with_output_driver;
+    use crate::output_driver::ShipOutDriver;
     use crate::section_0101::scaled;
     use crate::section_0135::depth;
     use crate::section_0135::height;
@@ -20,13 +20,23 @@ pub(crate) macro Output_a_rule_in_a_vlist__goto_next_p($globals:expr, $this_box:
         synch_h!($globals);
         synch_v!($globals);
         // dvi_out(put_rule); dvi_four(rule_ht); dvi_four(rule_wd);
+        // TeX-rs: the output driver is told of the rule as well
+        with_output_driver!($globals, D => D::rule(
+            $globals,
+            $globals.cur_h,
+            $globals.cur_v,
+            $globals.rule_wd,
+            $globals.rule_ht
+        ));
         dvi_out!($globals, put_rule.byte());
-        dvi_four($globals, $globals.rule_ht.inner());
-        dvi_four($globals, $globals.rule_wd.inner());
+        dvi_four($globals, $globals.rule_ht.inner())?;
+        dvi_four($globals, $globals.rule_wd.inner())?;
         // end;
     }
     // goto next_p
     crate::goto_forward_label!($lbl_next_p);
+    use crate::output_driver::with_output_driver;
+    use crate::output_driver::ShipOutDriver;
     use crate::section_0101::scaled;
     use crate::section_0135::height;
     use crate::section_0135::width;
@@ -3,7 +3,7 @@
 //! may confuse the printing software.
 //
 // @<Update the values of |max_h| and |max_v|; but if the page is too large...@>=
-pub(crate) macro Update_the_values_of_max_h_and_max_v__but_if_the_page_is_too_large__goto_done($globals:expr, $p:expr) {{
+pub(crate) macro Update_the_values_of_max_h_and_max_v__but_if_the_page_is_too_large__goto_done($globals:expr, $p:expr, $lbl_done:lifetime) {{
     // if (height(p)>max_dimen)or@|(depth(p)>max_dimen)or@|
     //    (height(p)+depth(p)+v_offset>max_dimen)or@|
     //    (width(p)+h_offset>max_dimen) then
@@ -29,15 +29,24 @@ pub(crate) macro Update_the_values_of_max_h_and_max_v__but_if_the_page_is_too_la
         // error;
         error($globals)?;
         // if tracing_output<=0 then
-        //   begin begin_diagnostic;
-        //   print_nl("The following box has been deleted:");
-        // @.The following...deleted@>
-        //   show_box(p);
-        //   end_diagnostic(true);
-        //   end;
+        if tracing_output!($globals) <= 0 {
+            // begin begin_diagnostic;
+            begin_diagnostic($globals);
+            // print_nl("The following box has been deleted:");
+            print_nl(
+                $globals,
+                crate::strpool_str!("The following box has been deleted:"),
+            );
+            // @.The following...deleted@>
+            // show_box(p);
+            show_box($globals, $p);
+            // end_diagnostic(true);
+            end_diagnostic($globals, true);
+            // end;
+        }
         // goto done;
+        crate::goto_forward_label!($lbl_done);
         // end;
-        todo!("update the values of max_h and max_v");
     }
     // if height(p)+depth(p)+v_offset>max_v then max_v:=height(p)+depth(p)+v_offset;
     if height!($globals, $p) + depth!($globals, $p) + v_offset!($globals) > $globals.max_v {
@@ -47,6 +56,7 @@ pub(crate) macro Update_the_values_of_max_h_and_max_v__but_if_the_page_is_too_la
     if width!($globals, $p) + h_offset!($globals) > $globals.max_h {
         $globals.max_h = width!($globals, $p) + h_offset!($globals);
     }
+    use crate::section_0062::print_nl;
     use crate::section_0073::print_err;
     use crate::section_0079::help2;
     use crate::section_0082::error;
@@ -54,6 +64,10 @@ pub(crate) macro Update_the_values_of_max_h_and_max_v__but_if_the_page_is_too_la
     use crate::section_0135::depth;
     use crate::section_0135::height;
     use crate::section_0135::width;
+    use crate::section_0198::show_box;
+    use crate::section_0236::tracing_output;
+    use crate::section_0245::begin_diagnostic;
+    use crate::section_0245::end_diagnostic;
     use crate::section_0247::h_offset;
     use crate::section_0247::v_offset;
     use crate::section_0421::max_dimen;
@@ -35,21 +35,21 @@ pub(crate) macro Finish_the_DVI_file($globals:expr) {{
         // dvi_four(last_bop); last_bop:=dvi_offset+dvi_ptr-5; {|post| location}
         /// `post` location
         const _: () = ();
-        dvi_four($globals, $globals.last_bop);
+        dvi_four($globals, $globals.last_bop)?;
         $globals.last_bop = $globals.dvi_offset + $globals.dvi_ptr.get() as integer - 5;
         // dvi_four(25400000); dvi_four(473628672); {conversion ratio for sp}
         /// conversion ratio for sp
         const _: () = ();
-        dvi_four($globals, 25400000);
-        dvi_four($globals, 473628672);
+        dvi_four($globals, 25400000)?;
+        dvi_four($globals, 473628672)?;
         // prepare_mag; dvi_four(mag); {magnification factor}
         /// magnification factor
         const _: () = ();
-        prepare_mag($globals);
-        dvi_four($globals, mag!($globals));
+        prepare_mag($globals)?;
+        dvi_four($globals, mag!($globals))?;
         // dvi_four(max_v); dvi_four(max_h);@/
-        dvi_four($globals, $globals.max_v.inner());
-        dvi_four($globals, $globals.max_h.inner());
+        dvi_four($globals, $globals.max_v.inner())?;
+        dvi_four($globals, $globals.max_h.inner())?;
         // dvi_out(max_push div 256); dvi_out(max_push mod 256);@/
         dvi_out!($globals, $globals.max_push / 256);
         dvi_out!($globals, $globals.max_push % 256);
@@ -60,14 +60,14 @@ pub(crate) macro Finish_the_DVI_file($globals:expr) {{
         crate::section_0643::Output_the_font_definitions_for_all_fonts_that_were_used!($globals);
         // dvi_out(post_post); dvi_four(last_bop); dvi_out(id_byte);@/
         dvi_out!($globals, post_post.byte());
-        dvi_four($globals, $globals.last_bop);
+        dvi_four($globals, $globals.last_bop)?;
         dvi_out!($globals, id_byte);
         /// all-purpose index
         let mut k;
         // k:=4+((dvi_buf_size-dvi_ptr) mod 4); {the number of 223's}
         /// the number of 223's
         const _: () = ();
-        k = 4 + ((dvi_buf_size - $globals.dvi_ptr.get()) % 4);
+        k = 4 + (($globals.dvi_buf_size - $globals.dvi_ptr.get()) % 4);
         // while k>0 do
         while k > 0 {
             // begin dvi_out(223); decr(k);
@@ -77,6 +77,9 @@ pub(crate) macro Finish_the_DVI_file($globals:expr) {{
         }
         // @<Empty the last bytes out of |dvi_buf|@>;
         crate::section_0599::Empty_the_last_bytes_out_of_dvi_buf!($globals);
+        /// number of bytes in the output file
+        // TeX-rs: it is `dvi_offset+dvi_ptr` unless another output driver is selected
+        let output_bytes = with_output_driver!($globals, D => D::finish($globals));
         // print_nl("Output written on "); slow_print(output_file_name);
         print_nl($globals, crate::strpool_str!("Output written on "));
         slow_print($globals, $globals.output_file_name.get() as _);
@@ -94,18 +97,16 @@ pub(crate) macro Finish_the_DVI_file($globals:expr) {{
         }
         // print(", "); print_int(dvi_offset+dvi_ptr); print(" bytes).");
         print($globals, crate::strpool_str!(", ").get() as _);
-        print_int(
-            $globals,
-            $globals.dvi_offset + $globals.dvi_ptr.get() as integer,
-        );
+        print_int($globals, output_bytes);
         print($globals, crate::strpool_str!(" bytes).").get() as _);
         // b_close(dvi_file);
         b_close(&mut $globals.dvi_file);
         // end
     }
+    use crate::output_driver::with_output_driver;
+    use crate::output_driver::ShipOutDriver;
     use crate::pascal::integer;
     use crate::section_0004::make_globals_io_string_log_view;
-    use crate::section_0011::dvi_buf_size;
     use crate::section_0016::decr;
     use crate::section_0016::incr;
     use crate::section_0018::ASCII_code_literal;
@@ -69,7 +69,7 @@ pub(crate) fn scan_spec(
     saved!(globals, 1) = globals.cur_val;
     globals.save_ptr = globals.save_ptr + 2;
     // new_save_level(c); scan_left_brace;
-    new_save_level(globals, c);
+    new_save_level(globals, c)?;
     scan_left_brace(globals)?;
     // end;
     crate::ok_nojump!()
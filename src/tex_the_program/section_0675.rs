@@ -10,21 +10,42 @@ pub(crate) macro Finish_issuing_a_diagnostic_message_for_an_overfull_or_underful
     }
     // else  begin if pack_begin_line<>0 then {it's actually negative}
     else {
-        todo!("xxx");
-        //   begin print(") in alignment at lines ");
-        //   print_int(abs(pack_begin_line));
-        //   print("--");
-        //   end
+        if $globals.pack_begin_line != 0 {
+            /// it's actually negative
+            const _: () = ();
+            // begin print(") in alignment at lines ");
+            print(
+                $globals,
+                crate::strpool_str!(") in alignment at lines ").get() as _,
+            );
+            // print_int(abs(pack_begin_line));
+            print_int($globals, $globals.pack_begin_line.abs());
+            // print("--");
+            print($globals, crate::strpool_str!("--").get() as _);
+            // end
+        }
         // else print(") detected at line ");
+        else {
+            print(
+                $globals,
+                crate::strpool_str!(") detected at line ").get() as _,
+            );
+        }
         // print_int(line);
+        print_int($globals, $globals.line);
         // print_ln;@/
+        print_ln(make_globals_io_string_log_view!($globals));
         // end;
     }
     // begin_diagnostic; show_box(r); end_diagnostic(true)
     begin_diagnostic($globals);
     show_box($globals, $r);
     end_diagnostic($globals, true);
+    use crate::section_0004::make_globals_io_string_log_view;
+    use crate::section_0004::TeXGlobalsIoStringLogView;
+    use crate::section_0057::print_ln;
     use crate::section_0059::print;
+    use crate::section_0065::print_int;
     use crate::section_0198::show_box;
     use crate::section_0245::begin_diagnostic;
     use crate::section_0245::end_diagnostic;
@@ -26,23 +26,36 @@ pub(crate) macro Determine_vertical_glue_shrink_setting__then_return_or_goto_com
     // if (total_shrink[o]<-x)and(o=normal)and(list_ptr(r)<>null) then
     if $globals.total_shrink[o] < -$x && o == glue_ord::normal && list_ptr!($globals, $r) != null {
         // begin last_badness:=1000000;
+        $globals.last_badness = 1000000;
         // set_glue_ratio_one(glue_set(r)); {use the maximum shrinkage}
+        /// use the maximum shrinkage
+        const _: () = ();
+        set_glue_ratio_one!(glue_set!($globals, $r));
         // @<Report an overfull vbox and |goto common_ending|, if this box
         //   is sufficiently bad@>;
+        crate::section_0677::Report_an_overfull_vbox_and_goto_common_ending__if_this_box_is_sufficiently_bad!(
+            $globals,
+            $x,
+            $lbl_common_ending
+        );
         // end
-        todo!("shrink 1");
     }
     // else if o=normal then if list_ptr(r)<>null then
     else if o == glue_ord::normal && list_ptr!($globals, $r) != null {
         // @<Report a tight vbox and |goto common_ending|, if this box
         //   is sufficiently bad@>;
-        crate::section_0678::Report_a_tight_vbox_and_goto_common_ending__if_this_box_is_sufficiently_bad!($globals, $x);
+        crate::section_0678::Report_a_tight_vbox_and_goto_common_ending__if_this_box_is_sufficiently_bad!(
+            $globals,
+            $x,
+            $lbl_common_ending
+        );
     }
     // return;
     crate::return_nojump!($r);
     // end
     use crate::pascal::real;
     use crate::section_0101::scaled;
+    use crate::section_0109::set_glue_ratio_one;
     use crate::section_0109::set_glue_ratio_zero;
     use crate::section_0109::unfloat;
     use crate::section_0115::null;
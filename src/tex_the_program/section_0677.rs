@@ -1,8 +1,28 @@
-//! @ @<Report an overfull vbox and |goto common_ending|, if...@>=
-//! if (-x-total_shrink[normal]>vfuzz)or(vbadness<100) then
-//!   begin print_ln; print_nl("Overfull \vbox (");
-//! @.Overfull \\vbox...@>
-//!   print_scaled(-x-total_shrink[normal]); print("pt too high");
-//!   goto common_ending;
-//!   end
-//!
+//! ` `
+
+// @<Report an overfull vbox and |goto common_ending|, if...@>=
+pub(crate) macro Report_an_overfull_vbox_and_goto_common_ending__if_this_box_is_sufficiently_bad($globals:expr, $x:expr, $lbl_common_ending:lifetime) {{
+    // if (-x-total_shrink[normal]>vfuzz)or(vbadness<100) then
+    if -$x - $globals.total_shrink[glue_ord::normal] > vfuzz!($globals) || vbadness!($globals) < 100
+    {
+        // begin print_ln; print_nl("Overfull \vbox (");
+        print_ln(make_globals_io_string_log_view!($globals));
+        print_nl($globals, crate::strpool_str!("Overfull \\vbox ("));
+        // @.Overfull \\vbox...@>
+        // print_scaled(-x-total_shrink[normal]); print("pt too high");
+        print_scaled($globals, -$x - $globals.total_shrink[glue_ord::normal]);
+        print($globals, crate::strpool_str!("pt too high").get() as _);
+        // goto common_ending;
+        crate::goto_forward_label!($lbl_common_ending);
+        // end
+    }
+    use crate::section_0004::make_globals_io_string_log_view;
+    use crate::section_0004::TeXGlobalsIoStringLogView;
+    use crate::section_0057::print_ln;
+    use crate::section_0059::print;
+    use crate::section_0062::print_nl;
+    use crate::section_0103::print_scaled;
+    use crate::section_0150::glue_ord;
+    use crate::section_0236::vbadness;
+    use crate::section_0247::vfuzz;
+}}
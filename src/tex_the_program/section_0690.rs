@@ -17,12 +17,12 @@
 pub(crate) macro Cases_of_show_node_list_that_arise_in_mlists_only($globals:expr, $p:expr, $type_p:expr) {{
     // style_node:print_style(subtype(p));
     let processed = if $type_p == style_node {
-        todo!("style_node");
+        print_style($globals, subtype!($globals, $p as pointer) as _);
         true
     }
     // choice_node:@<Display choice node |p|@>;
     else if $type_p == choice_node {
-        todo!("choice_node");
+        crate::section_0695::Display_choice_node_p!($globals, $p);
         true
     }
     // ord_noad,op_noad,bin_noad,rel_noad,open_noad,close_noad,punct_noad,inner_noad,
@@ -44,21 +44,23 @@ pub(crate) macro Cases_of_show_node_list_that_arise_in_mlists_only($globals:expr
         || $type_p == left_noad
         || $type_p == right_noad
     {
-        todo!("normal noad");
+        crate::section_0696::Display_normal_noad_p!($globals, $p);
         true
     }
     // fraction_noad:@<Display fraction noad |p|@>;
     else if $type_p == fraction_noad {
-        todo!("fraction noad");
+        crate::section_0697::Display_fraction_noad_p!($globals, $p);
         true
     } else {
         false
     };
-
+    use crate::section_0115::pointer;
+    use crate::section_0133::subtype;
     use crate::section_0682::*;
     use crate::section_0683::*;
     use crate::section_0687::*;
     use crate::section_0688::*;
     use crate::section_0689::*;
+    use crate::section_0694::print_style;
     processed
 }}
@@ -0,0 +1,64 @@
+//! @ Here are some simple routines used in the display of noads.
+//
+// @<Declare procedures needed for displaying the elements of mlists@>=
+// procedure print_fam_and_char(@!p:pointer); {prints family and character}
+/// prints family and character
+pub(crate) fn print_fam_and_char(globals: &mut TeXGlobals, p: pointer) {
+    // begin print_esc("fam"); print_int(fam(p)); print_char(" ");
+    print_esc(globals, crate::strpool_str!("fam"));
+    let fam = fam!(globals, p);
+    print_int(globals, fam.get() as _);
+    print_char(
+        make_globals_io_string_log_view!(globals),
+        ASCII_code_literal!(b' '),
+    );
+    // print_ASCII(qo(character(p)));
+    let c = character!(globals, p);
+    print_ASCII(globals, c.numeric_value() as _);
+    // end;
+}
+// @#
+// procedure print_delimiter(@!p:pointer); {prints a delimiter as 24-bit hex value}
+/// prints a delimiter as 24-bit hex value
+pub(crate) fn print_delimiter(globals: &mut TeXGlobals, p: pointer) {
+    // var a:integer; {accumulator}
+    /// accumulator
+    let mut a: integer;
+    // begin a:=small_fam(p)*256+qo(small_char(p));
+    let small_fam = small_fam!(globals, p);
+    let small_char = small_char!(globals, p);
+    a = small_fam.get() as integer * 256 + small_char.numeric_value() as integer;
+    // a:=a*@"1000+large_fam(p)*256+qo(large_char(p));
+    let large_fam = large_fam!(globals, p);
+    let large_char = large_char!(globals, p);
+    a = a * 0x1000 + large_fam.get() as integer * 256 + large_char.numeric_value() as integer;
+    // if a<0 then print_int(a) {this should never happen}
+    if a < 0 {
+        /// this should never happen
+        const _: () = ();
+        print_int(globals, a);
+    }
+    // else print_hex(a);
+    else {
+        print_hex(globals, a);
+    }
+    // end;
+}
+
+use crate::pascal::integer;
+use crate::section_0004::make_globals_io_string_log_view;
+use crate::section_0004::TeXGlobals;
+use crate::section_0004::TeXGlobalsIoStringLogView;
+use crate::section_0018::ASCII_code_literal;
+use crate::section_0058::print_char;
+use crate::section_0063::print_esc;
+use crate::section_0065::print_int;
+use crate::section_0067::print_hex;
+use crate::section_0068::print_ASCII;
+use crate::section_0115::pointer;
+use crate::section_0134::character;
+use crate::section_0681::fam;
+use crate::section_0683::large_char;
+use crate::section_0683::large_fam;
+use crate::section_0683::small_char;
+use crate::section_0683::small_fam;
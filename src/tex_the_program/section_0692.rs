@@ -0,0 +1,93 @@
+//! @ The next subroutine will descend to another level of recursion when a
+//! subsidiary mlist needs to be displayed. The parameter |c| indicates what
+//! character is to become part of the recursion history. An empty mlist is
+//! distinguished from a field with |math_type(p)=empty|, because these are
+//! not equivalent (as explained above).
+//! @^recursion@>
+//
+// @<Declare procedures needed for displaying...@>=
+// procedure@?show_info; forward;@t\2@>@?{|show_node_list(info(temp_ptr))|}
+// procedure print_subsidiary_data(@!p:pointer;@!c:ASCII_code);
+//   {display a noad field}
+/// display a noad field
+pub(crate) fn print_subsidiary_data(globals: &mut TeXGlobals, p: pointer, c: ASCII_code) {
+    // begin if cur_length>=depth_threshold then
+    if cur_length!(globals) as integer >= globals.depth_threshold {
+        // begin if math_type(p)<>empty then print(" []");
+        if math_type!(globals, p) != math_type_kind::empty as _ {
+            print(globals, crate::strpool_str!(" []").get() as _);
+        }
+        // end
+    }
+    // else  begin append_char(c); {include |c| in the recursion history}
+    else {
+        /// include `c` in the recursion history
+        append_char(make_globals_string_view!(globals), c);
+        // temp_ptr:=p; {prepare for |show_info| if recursion is needed}
+        /// prepare for `show_info` if recursion is needed
+        const _: () = ();
+        globals.temp_ptr = p;
+        // case math_type(p) of
+        let math_type_p = math_type!(globals, p);
+        // math_char: begin print_ln; print_current_string; print_fam_and_char(p);
+        if math_type_p == math_type_kind::math_char as _ {
+            print_ln(make_globals_io_string_log_view!(globals));
+            print_current_string(globals);
+            print_fam_and_char(globals, p);
+            // end;
+        }
+        // sub_box: show_info; {recursive call}
+        else if math_type_p == math_type_kind::sub_box as _ {
+            /// recursive call
+            show_info(globals);
+        }
+        // sub_mlist: if info(p)=null then
+        else if math_type_p == math_type_kind::sub_mlist as _ {
+            if info_inner!(globals, p) == null {
+                // begin print_ln; print_current_string; print("{}");
+                print_ln(make_globals_io_string_log_view!(globals));
+                print_current_string(globals);
+                print(globals, crate::strpool_str!("{}").get() as _);
+                // end
+            }
+            // else show_info; {recursive call}
+            else {
+                /// recursive call
+                show_info(globals);
+            }
+        }
+        // othercases do_nothing {|empty|}
+        else {
+            /// `empty`
+            do_nothing!();
+        }
+        // endcases;@/
+        // flush_char; {remove |c| from the recursion history}
+        /// remove `c` from the recursion history
+        flush_char(globals);
+        // end;
+    }
+    // end;
+}
+
+use crate::pascal::integer;
+use crate::section_0004::make_globals_io_string_log_view;
+use crate::section_0004::make_globals_string_view;
+use crate::section_0004::TeXGlobals;
+use crate::section_0004::TeXGlobalsIoStringLogView;
+use crate::section_0004::TeXGlobalsStringView;
+use crate::section_0016::do_nothing;
+use crate::section_0018::ASCII_code;
+use crate::section_0041::cur_length;
+use crate::section_0042::append_char;
+use crate::section_0042::flush_char;
+use crate::section_0057::print_ln;
+use crate::section_0059::print;
+use crate::section_0070::print_current_string;
+use crate::section_0115::null;
+use crate::section_0115::pointer;
+use crate::section_0118::info_inner;
+use crate::section_0681::math_type;
+use crate::section_0681::math_type_kind;
+use crate::section_0691::print_fam_and_char;
+use crate::section_0693::show_info;
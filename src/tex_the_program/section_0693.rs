@@ -0,0 +1,21 @@
+//! @ The inelegant introduction of |show_info| in the code above seems better
+//! than the alternative of using \PASCAL's strange |forward| declaration for a
+//! procedure with parameters. The \PASCAL\ convention about dropping parameters
+//! from a post-|forward| procedure is, frankly, so intolerable to the author
+//! of \TeX\ that he would rather stoop to communication via a global temporary
+//! variable. (A similar stoopidity occurred with respect to |hlist_out| and
+//! |vlist_out| above, and it will occur with respect to |mlist_to_hlist| below.)
+//! @^Knuth, Donald Ervin@>
+//! @:PASCAL}{\PASCAL@>
+//
+// @p procedure show_info; {the reader will kindly forgive this}
+/// the reader will kindly forgive this
+pub(crate) fn show_info(globals: &mut TeXGlobals) {
+    // begin show_node_list(info(temp_ptr));
+    show_node_list(globals, info_inner!(globals, globals.temp_ptr) as _);
+    // end;
+}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0118::info_inner;
+use crate::section_0182::show_node_list;
@@ -0,0 +1,46 @@
+//! ` `
+// @<Display choice node |p|@>=
+pub(crate) macro Display_choice_node_p($globals:expr, $p:expr) {{
+    // begin print_esc("mathchoice");
+    print_esc($globals, crate::strpool_str!("mathchoice"));
+    // append_char("D"); show_node_list(display_mlist(p)); flush_char;
+    append_char(
+        make_globals_string_view!($globals),
+        ASCII_code_literal!(b'D'),
+    );
+    show_node_list($globals, display_mlist!($globals, $p as pointer) as _);
+    flush_char($globals);
+    // append_char("T"); show_node_list(text_mlist(p)); flush_char;
+    append_char(
+        make_globals_string_view!($globals),
+        ASCII_code_literal!(b'T'),
+    );
+    show_node_list($globals, text_mlist!($globals, $p as pointer) as _);
+    flush_char($globals);
+    // append_char("S"); show_node_list(script_mlist(p)); flush_char;
+    append_char(
+        make_globals_string_view!($globals),
+        ASCII_code_literal!(b'S'),
+    );
+    show_node_list($globals, script_mlist!($globals, $p as pointer) as _);
+    flush_char($globals);
+    // append_char("s"); show_node_list(script_script_mlist(p)); flush_char;
+    append_char(
+        make_globals_string_view!($globals),
+        ASCII_code_literal!(b's'),
+    );
+    show_node_list($globals, script_script_mlist!($globals, $p as pointer) as _);
+    flush_char($globals);
+    // end
+    use crate::section_0004::make_globals_string_view;
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0042::append_char;
+    use crate::section_0042::flush_char;
+    use crate::section_0063::print_esc;
+    use crate::section_0115::pointer;
+    use crate::section_0182::show_node_list;
+    use crate::section_0689::display_mlist;
+    use crate::section_0689::script_mlist;
+    use crate::section_0689::script_script_mlist;
+    use crate::section_0689::text_mlist;
+}}
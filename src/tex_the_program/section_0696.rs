@@ -0,0 +1,112 @@
+//! ` `
+// @<Display normal noad |p|@>=
+pub(crate) macro Display_normal_noad_p($globals:expr, $p:expr) {{
+    let p = $p as pointer;
+    // begin case type(p) of
+    let type_p = r#type!($globals, p);
+    // ord_noad: print_esc("mathord");
+    if type_p == ord_noad {
+        print_esc($globals, crate::strpool_str!("mathord"));
+    }
+    // op_noad: print_esc("mathop");
+    else if type_p == op_noad {
+        print_esc($globals, crate::strpool_str!("mathop"));
+    }
+    // bin_noad: print_esc("mathbin");
+    else if type_p == bin_noad {
+        print_esc($globals, crate::strpool_str!("mathbin"));
+    }
+    // rel_noad: print_esc("mathrel");
+    else if type_p == rel_noad {
+        print_esc($globals, crate::strpool_str!("mathrel"));
+    }
+    // open_noad: print_esc("mathopen");
+    else if type_p == open_noad {
+        print_esc($globals, crate::strpool_str!("mathopen"));
+    }
+    // close_noad: print_esc("mathclose");
+    else if type_p == close_noad {
+        print_esc($globals, crate::strpool_str!("mathclose"));
+    }
+    // punct_noad: print_esc("mathpunct");
+    else if type_p == punct_noad {
+        print_esc($globals, crate::strpool_str!("mathpunct"));
+    }
+    // inner_noad: print_esc("mathinner");
+    else if type_p == inner_noad {
+        print_esc($globals, crate::strpool_str!("mathinner"));
+    }
+    // over_noad: print_esc("overline");
+    else if type_p == over_noad {
+        print_esc($globals, crate::strpool_str!("overline"));
+    }
+    // under_noad: print_esc("underline");
+    else if type_p == under_noad {
+        print_esc($globals, crate::strpool_str!("underline"));
+    }
+    // vcenter_noad: print_esc("vcenter");
+    else if type_p == vcenter_noad {
+        print_esc($globals, crate::strpool_str!("vcenter"));
+    }
+    // radical_noad: begin print_esc("radical"); print_delimiter(left_delimiter(p));
+    //   end;
+    else if type_p == radical_noad {
+        print_esc($globals, crate::strpool_str!("radical"));
+        print_delimiter($globals, left_delimiter!(p));
+    }
+    // accent_noad: begin print_esc("accent"); print_fam_and_char(accent_chr(p));
+    //   end;
+    else if type_p == accent_noad {
+        print_esc($globals, crate::strpool_str!("accent"));
+        print_fam_and_char($globals, accent_chr!(p));
+    }
+    // left_noad: begin print_esc("left"); print_delimiter(delimiter(p));
+    //   end;
+    else if type_p == left_noad {
+        print_esc($globals, crate::strpool_str!("left"));
+        print_delimiter($globals, delimiter!(p));
+    }
+    // right_noad: begin print_esc("right"); print_delimiter(delimiter(p));
+    //   end;
+    else if type_p == right_noad {
+        print_esc($globals, crate::strpool_str!("right"));
+        print_delimiter($globals, delimiter!(p));
+    }
+    // end;
+    // if subtype(p)<>normal then
+    if subtype!($globals, p) != noad_subtype::normal as _ {
+        // if subtype(p)=limits then print_esc("limits")
+        if subtype!($globals, p) == op_noad_subtype::limits as _ {
+            print_esc($globals, crate::strpool_str!("limits"));
+        }
+        // else print_esc("nolimits");
+        else {
+            print_esc($globals, crate::strpool_str!("nolimits"));
+        }
+    }
+    // if type(p)<left_noad then print_subsidiary_data(nucleus(p),".");
+    if type_p < left_noad {
+        print_subsidiary_data($globals, nucleus!(p), ASCII_code_literal!(b'.'));
+    }
+    // print_subsidiary_data(supscr(p),"^");
+    print_subsidiary_data($globals, supscr!(p), ASCII_code_literal!(b'^'));
+    // print_subsidiary_data(subscr(p),"_");
+    print_subsidiary_data($globals, subscr!(p), ASCII_code_literal!(b'_'));
+    // end
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0063::print_esc;
+    use crate::section_0115::pointer;
+    use crate::section_0133::r#type;
+    use crate::section_0133::subtype;
+    use crate::section_0681::nucleus;
+    use crate::section_0681::subscr;
+    use crate::section_0681::supscr;
+    use crate::section_0682::*;
+    use crate::section_0683::left_delimiter;
+    use crate::section_0683::radical_noad;
+    use crate::section_0686::noad_subtype;
+    use crate::section_0687::*;
+    use crate::section_0691::print_delimiter;
+    use crate::section_0691::print_fam_and_char;
+    use crate::section_0692::print_subsidiary_data;
+}}
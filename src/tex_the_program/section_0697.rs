@@ -0,0 +1,72 @@
+//! ` `
+// @<Display fraction noad |p|@>=
+pub(crate) macro Display_fraction_noad_p($globals:expr, $p:expr) {{
+    let p = $p as pointer;
+    // begin print_esc("fraction, thickness ");
+    print_esc($globals, crate::strpool_str!("fraction, thickness "));
+    // if thickness(p)=default_code then print("= default")
+    if thickness!($globals, p) == default_code {
+        print($globals, crate::strpool_str!("= default").get() as _);
+    }
+    // else print_scaled(thickness(p));
+    else {
+        print_scaled($globals, thickness!($globals, p));
+    }
+    // if (small_fam(left_delimiter(p))<>0)or@+
+    //   (small_char(left_delimiter(p))<>min_quarterword)or@|
+    //   (large_fam(left_delimiter(p))<>0)or@|
+    //   (large_char(left_delimiter(p))<>min_quarterword) then
+    if small_fam!($globals, left_delimiter!(p)).get() != 0
+        || small_char!($globals, left_delimiter!(p)).numeric_value() != min_quarterword as _
+        || large_fam!($globals, left_delimiter!(p)).get() != 0
+        || large_char!($globals, left_delimiter!(p)).numeric_value() != min_quarterword as _
+    {
+        // begin print(", left-delimiter "); print_delimiter(left_delimiter(p));
+        print(
+            $globals,
+            crate::strpool_str!(", left-delimiter ").get() as _,
+        );
+        print_delimiter($globals, left_delimiter!(p));
+        // end;
+    }
+    // if (small_fam(right_delimiter(p))<>0)or@|
+    //   (small_char(right_delimiter(p))<>min_quarterword)or@|
+    //   (large_fam(right_delimiter(p))<>0)or@|
+    //   (large_char(right_delimiter(p))<>min_quarterword) then
+    if small_fam!($globals, right_delimiter!(p)).get() != 0
+        || small_char!($globals, right_delimiter!(p)).numeric_value() != min_quarterword as _
+        || large_fam!($globals, right_delimiter!(p)).get() != 0
+        || large_char!($globals, right_delimiter!(p)).numeric_value() != min_quarterword as _
+    {
+        // begin print(", right-delimiter "); print_delimiter(right_delimiter(p));
+        print(
+            $globals,
+            crate::strpool_str!(", right-delimiter ").get() as _,
+        );
+        print_delimiter($globals, right_delimiter!(p));
+        // end;
+    }
+    // print_subsidiary_data(numerator(p),"\");
+    print_subsidiary_data($globals, numerator!(p), ASCII_code_literal!(b'\\'));
+    // print_subsidiary_data(denominator(p),"/");
+    print_subsidiary_data($globals, denominator!(p), ASCII_code_literal!(b'/'));
+    // end
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0103::print_scaled;
+    use crate::section_0110::min_quarterword;
+    use crate::section_0115::pointer;
+    use crate::section_0683::default_code;
+    use crate::section_0683::denominator;
+    use crate::section_0683::large_char;
+    use crate::section_0683::large_fam;
+    use crate::section_0683::left_delimiter;
+    use crate::section_0683::numerator;
+    use crate::section_0683::right_delimiter;
+    use crate::section_0683::small_char;
+    use crate::section_0683::small_fam;
+    use crate::section_0683::thickness;
+    use crate::section_0691::print_delimiter;
+    use crate::section_0692::print_subsidiary_data;
+}}
@@ -4,18 +4,24 @@
 pub(crate) macro Cases_of_flush_node_list_that_arise_in_mlists_only($globals:expr, $p:expr, $type_p:expr, $lbl_done:lifetime) {{
     // style_node: begin free_node(p,style_node_size); goto done;
     let processed = if $type_p == style_node {
-        todo!("style_node");
+        free_node($globals, $p, style_node_size as _);
+        crate::goto_forward_label!($lbl_done);
         // end;
         true
     }
     // choice_node:begin flush_node_list(display_mlist(p));
     else if $type_p == choice_node {
+        flush_node_list($globals, display_mlist!($globals, $p))?;
         // flush_node_list(text_mlist(p));
+        flush_node_list($globals, text_mlist!($globals, $p))?;
         // flush_node_list(script_mlist(p));
+        flush_node_list($globals, script_mlist!($globals, $p))?;
         // flush_node_list(script_script_mlist(p));
+        flush_node_list($globals, script_script_mlist!($globals, $p))?;
         // free_node(p,style_node_size); goto done;
+        free_node($globals, $p, style_node_size as _);
+        crate::goto_forward_label!($lbl_done);
         // end;
-        todo!("choice_node");
         true
     }
     // ord_noad,op_noad,bin_noad,rel_noad,open_noad,close_noad,punct_noad,inner_noad,
@@ -68,16 +74,20 @@ pub(crate) macro Cases_of_flush_node_list_that_arise_in_mlists_only($globals:exp
     }
     // left_noad,right_noad: begin free_node(p,noad_size); goto done;
     else if $type_p == left_noad || $type_p == right_noad {
-        todo!("l|r noad");
+        free_node($globals, $p, noad_size as _);
+        crate::goto_forward_label!($lbl_done);
         //   end;
         true
     }
     // fraction_noad: begin flush_node_list(info(numerator(p)));
     else if $type_p == fraction_noad {
+        flush_node_list($globals, info_inner!($globals, numerator!($p)))?;
         // flush_node_list(info(denominator(p)));
+        flush_node_list($globals, info_inner!($globals, denominator!($p)))?;
         // free_node(p,fraction_noad_size); goto done;
+        free_node($globals, $p, fraction_noad_size as _);
+        crate::goto_forward_label!($lbl_done);
         // end;
-        todo!("fraction_noad");
         true
     } else {
         false
@@ -100,7 +110,10 @@ pub(crate) macro Cases_of_flush_node_list_that_arise_in_mlists_only($globals:exp
     use crate::section_0682::ord_noad;
     use crate::section_0682::punct_noad;
     use crate::section_0682::rel_noad;
+    use crate::section_0683::denominator;
     use crate::section_0683::fraction_noad;
+    use crate::section_0683::fraction_noad_size;
+    use crate::section_0683::numerator;
     use crate::section_0683::radical_noad;
     use crate::section_0683::radical_noad_size;
     use crate::section_0687::accent_noad;
@@ -111,6 +124,11 @@ pub(crate) macro Cases_of_flush_node_list_that_arise_in_mlists_only($globals:exp
     use crate::section_0687::under_noad;
     use crate::section_0687::vcenter_noad;
     use crate::section_0688::style_node;
+    use crate::section_0688::style_node_size;
     use crate::section_0689::choice_node;
+    use crate::section_0689::display_mlist;
+    use crate::section_0689::script_mlist;
+    use crate::section_0689::script_script_mlist;
+    use crate::section_0689::text_mlist;
     processed
 }}
@@ -13,7 +13,7 @@ pub(crate) macro Look_at_the_variants_of_z_x__set_f_and_c_whenever_a_better_char
             /// best-so-far and tentative font codes
             let g: internal_font_number;
             $z = $z - 16;
-            g = fam_fnt!($globals, $z).into();
+            g = (fam_fnt!($globals, $z) as u16).into();
             // if g<>null_font then
             if g != null_font {
                 // @<Look at the list of characters starting with |x| in
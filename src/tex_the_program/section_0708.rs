@@ -38,7 +38,7 @@ pub(crate) macro Look_at_the_list_of_characters_starting_with_x_in_font_g__set_f
                         $c = y;
                         $w = u;
                         // if u>=v then goto found;
-                        if u > $v {
+                        if u >= $v {
                             crate::goto_forward_label!($lbl_found);
                         }
                         // end;
@@ -29,7 +29,7 @@ pub(crate) fn char_box(
     height!(globals, b) = char_height!(globals, f, hd);
     depth!(globals, b) = char_depth!(globals, f, hd);
     // p:=get_avail; character(p):=c; font(p):=f; list_ptr(b):=p; char_box:=b;
-    p = get_avail(globals);
+    p = get_avail(globals)?;
     assign_font_and_character!(globals, p, f, c);
     list_ptr!(globals, b) = p;
     // end;
@@ -1,8 +1,24 @@
 //! @ Sometimes it is necessary to destroy an mlist. The following
 //! subroutine empties the current list, assuming that |abs(mode)=mmode|.
-//!
-//! @p procedure flush_math;
-//! begin flush_node_list(link(head)); flush_node_list(incompleat_noad);
-//! link(head):=null; tail:=head; incompleat_noad:=null;
-//! end;
-//!
+//
+// @p procedure flush_math;
+pub(crate) fn flush_math(globals: &mut TeXGlobals) -> TeXResult<()> {
+    // begin flush_node_list(link(head)); flush_node_list(incompleat_noad);
+    flush_node_list(globals, link!(globals, head!(globals)))?;
+    flush_node_list(globals, incompleat_noad!(globals) as _)?;
+    // link(head):=null; tail:=head; incompleat_noad:=null;
+    link!(globals, head!(globals)) = null;
+    tail!(globals) = head!(globals);
+    incompleat_noad!(globals) = null as _;
+    // end;
+    crate::ok_nojump!()
+}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
+use crate::section_0115::null;
+use crate::section_0118::link;
+use crate::section_0202::flush_node_list;
+use crate::section_0213::head;
+use crate::section_0213::incompleat_noad;
+use crate::section_0213::tail;
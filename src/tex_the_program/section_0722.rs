@@ -8,16 +8,18 @@
 // @p procedure fetch(@!a:pointer); {unpack the |math_char| field |a|}
 /// unpack the `math_char` field `a`
 #[allow(unused_variables, unused_assignments)]
-pub(crate) fn fetch(globals: &mut TeXGlobals, a: pointer) -> FetchedMathCharInfo {
-    let (cur_c, cur_f, cur_i);
+pub(crate) fn fetch(globals: &mut TeXGlobals, a: pointer) -> TeXResult<FetchedMathCharInfo> {
+    let (cur_c, cur_f, mut cur_i);
     // begin cur_c:=character(a); cur_f:=fam_fnt(fam(a)+cur_size);
     cur_c = character!(globals, a);
     let fam = fam!(globals, a).get();
-    cur_f = fam_fnt!(globals, fam + globals.cur_size.get() as u16).into();
+    cur_f = (fam_fnt!(globals, fam + globals.cur_size.get() as u16) as u16).into();
     // if cur_f=null_font then
     if cur_f == null_font {
         // @<Complain about an undefined family and set |cur_i| null@>
-        todo!("complain");
+        crate::section_0723::Complain_about_an_undefined_family_and_set_cur_i_null!(
+            globals, a, cur_c, cur_i
+        );
     }
     // else  begin if (qo(cur_c)>=font_bc[cur_f])and(qo(cur_c)<=font_ec[cur_f]) then
     else {
@@ -31,26 +33,32 @@ pub(crate) fn fetch(globals: &mut TeXGlobals, a: pointer) -> FetchedMathCharInfo
         // if not(char_exists(cur_i)) then
         if !cur_i.char_exists() {
             // begin char_warning(cur_f,qo(cur_c));
+            char_warning(globals, cur_f, cur_c);
             // math_type(a):=empty; cur_i:=null_character;
+            math_type!(globals, a) = math_type_kind::empty as _;
+            cur_i = NULL_CHARACTER;
             // end;
-            todo!("char_warning");
         }
         // end;
     }
     // end;
-    FetchedMathCharInfo {
+    crate::ok_nojump!(FetchedMathCharInfo {
         cur_f,
         cur_c,
         cur_i,
-    }
+    })
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
 use crate::section_0115::pointer;
 use crate::section_0134::character;
 use crate::section_0230::fam_fnt;
 use crate::section_0232::null_font;
 use crate::section_0554::char_info;
 use crate::section_0556::NULL_CHARACTER;
+use crate::section_0581::char_warning;
 use crate::section_0681::fam;
+use crate::section_0681::math_type;
+use crate::section_0681::math_type_kind;
 use crate::section_0724::FetchedMathCharInfo;
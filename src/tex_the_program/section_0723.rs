@@ -1,11 +1,56 @@
-//! @ @<Complain about an undefined family...@>=
-//! begin print_err(""); print_size(cur_size); print_char(" ");
-//! print_int(fam(a)); print(" is undefined (character ");
-//! print_ASCII(qo(cur_c)); print_char(")");
-//! help4("Somewhere in the math formula just ended, you used the")@/
-//! ("stated character from an undefined font family. For example,")@/
-//! ("plain TeX doesn't allow \it or \sl in subscripts. Proceed,")@/
-//! ("and I'll try to forget that I needed that character.");
-//! error; cur_i:=null_character; math_type(a):=empty;
-//! end
-//!
+//! ` `
+
+// @<Complain about an undefined family...@>=
+pub(crate) macro Complain_about_an_undefined_family_and_set_cur_i_null($globals:expr, $a:expr, $cur_c:expr, $cur_i:expr) {{
+    // begin print_err(""); print_size(cur_size); print_char(" ");
+    print_err!($globals, crate::strpool_str!(""));
+    print_size($globals, $globals.cur_size.get() as _);
+    print_char(
+        make_globals_io_string_log_view!($globals),
+        ASCII_code_literal!(b' '),
+    );
+    // print_int(fam(a)); print(" is undefined (character ");
+    let fam = fam!($globals, $a).get();
+    print_int($globals, fam as _);
+    print(
+        $globals,
+        crate::strpool_str!(" is undefined (character ").get() as _,
+    );
+    // print_ASCII(qo(cur_c)); print_char(")");
+    print_ASCII($globals, $cur_c.numeric_value() as _);
+    print_char(
+        make_globals_io_string_log_view!($globals),
+        ASCII_code_literal!(b')'),
+    );
+    // help4("Somewhere in the math formula just ended, you used the")@/
+    // ("stated character from an undefined font family. For example,")@/
+    // ("plain TeX doesn't allow \it or \sl in subscripts. Proceed,")@/
+    // ("and I'll try to forget that I needed that character.");
+    help4!(
+        $globals,
+        crate::strpool_str!("Somewhere in the math formula just ended, you used the"),
+        crate::strpool_str!("stated character from an undefined font family. For example,"),
+        crate::strpool_str!("plain TeX doesn't allow \\it or \\sl in subscripts. Proceed,"),
+        crate::strpool_str!("and I'll try to forget that I needed that character.")
+    );
+    // error; cur_i:=null_character; math_type(a):=empty;
+    error($globals)?;
+    $cur_i = NULL_CHARACTER;
+    math_type!($globals, $a) = math_type_kind::empty as _;
+    // end
+    use crate::section_0004::make_globals_io_string_log_view;
+    use crate::section_0004::TeXGlobalsIoStringLogView;
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0058::print_char;
+    use crate::section_0059::print;
+    use crate::section_0065::print_int;
+    use crate::section_0068::print_ASCII;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help4;
+    use crate::section_0082::error;
+    use crate::section_0556::NULL_CHARACTER;
+    use crate::section_0681::fam;
+    use crate::section_0681::math_type;
+    use crate::section_0681::math_type_kind;
+    use crate::section_0699::print_size;
+}}
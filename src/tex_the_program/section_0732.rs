@@ -26,17 +26,30 @@ pub(crate) macro Convert_math_glue_to_ordinary_glue($globals:expr, $q:expr) {{
     else if $globals.cur_size != text_size
         && subtype!($globals, $q) == glue_node_subtype::cond_math_glue as _
     {
+        /// temporary registers for list construction
+        let p;
         // begin p:=link(q);
+        p = link!($globals, $q);
         // if p<>null then if (type(p)=glue_node)or(type(p)=kern_node) then
-        //   begin link(q):=link(p); link(p):=null; flush_node_list(p);
-        //   end;
+        if p != null && (r#type!($globals, p) == glue_node || r#type!($globals, p) == kern_node) {
+            // begin link(q):=link(p); link(p):=null; flush_node_list(p);
+            link!($globals, $q) = link!($globals, p);
+            link!($globals, p) = null;
+            flush_node_list($globals, p)?;
+            // end;
+        }
         // end
-        todo!("cond_math_glue");
     }
+    use crate::section_0115::null;
+    use crate::section_0118::link;
+    use crate::section_0133::r#type;
     use crate::section_0133::subtype;
+    use crate::section_0149::glue_node;
     use crate::section_0149::glue_node_subtype;
     use crate::section_0149::glue_ptr;
+    use crate::section_0155::kern_node;
     use crate::section_0201::delete_glue_ref;
+    use crate::section_0202::flush_node_list;
     use crate::section_0699::text_size;
     use crate::section_0716::math_glue;
 }}
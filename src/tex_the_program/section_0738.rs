@@ -33,7 +33,7 @@ pub(crate) fn make_math_accent(globals: &mut TeXGlobals, q: pointer) -> TeXResul
     /// width of the accentee, not including sub/superscripts
     let w;
     // begin fetch(accent_chr(q));
-    let fetched = fetch(globals, accent_chr!(q));
+    let fetched = fetch(globals, accent_chr!(q))?;
     // if char_exists(cur_i) then
     if fetched.cur_i.char_exists() {
         // begin i:=cur_i; c:=cur_c; f:=cur_f;@/
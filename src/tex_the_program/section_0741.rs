@@ -9,7 +9,7 @@ pub(crate) macro Compute_the_amount_of_skew($globals:expr, $q:expr, $s:expr) {{
             // if math_type(nucleus(q))=math_char then
             if math_type!($globals, nucleus!($q)) == math_type_kind::math_char as _ {
                 // begin fetch(nucleus(q));
-                let fetched = fetch($globals, nucleus!($q));
+                let fetched = fetch($globals, nucleus!($q))?;
                 // if char_tag(cur_i)=lig_tag then
                 if fetched.cur_i.char_tag() == char_tag::lig_tag {
                     /// address of lig/kern instruction
@@ -14,7 +14,7 @@ pub(crate) macro Adjust_shift_up_and_shift_down_for_the_case_of_a_fraction_line(
         clr = thickness!($globals, $q);
     }
     // delta:=half(thickness(q));
-    $delta = thickness!($globals, $q);
+    $delta = scaled::new_from_inner(half(thickness!($globals, $q).inner()));
     // delta1:=clr-((shift_up-depth(x))-(axis_height(cur_size)+delta));
     delta1 = clr
         - (($shift_up - depth!($globals, $x))
@@ -32,6 +32,7 @@ pub(crate) macro Adjust_shift_up_and_shift_down_for_the_case_of_a_fraction_line(
         $shift_down += delta2;
     }
     // end
+    use crate::section_0100::half;
     use crate::section_0101::scaled;
     use crate::section_0135::depth;
     use crate::section_0135::height;
@@ -36,7 +36,7 @@ pub(crate) fn make_op(globals: &mut TeXGlobals, q: pointer) -> TeXResult<scaled>
     // if math_type(nucleus(q))=math_char then
     if math_type!(globals, nucleus!(q)) == math_type_kind::math_char as _ {
         // begin fetch(nucleus(q));
-        let mut fetched = fetch(globals, nucleus!(q));
+        let mut fetched = fetch(globals, nucleus!(q))?;
         // if (cur_style<text_style)and(char_tag(cur_i)=list_tag) then {make it larger}
         if globals.cur_style.get() < style_node_subtype::text_style.get()
             && fetched.cur_i.char_tag() == char_tag::list_tag
@@ -44,7 +44,7 @@ pub(crate) fn make_ord(globals: &mut TeXGlobals, q: pointer) -> TeXResult<()> {
                         cur_f,
                         cur_c: _,
                         cur_i,
-                    } = fetch(globals, nucleus!(q));
+                    } = fetch(globals, nucleus!(q))?;
                     // if char_tag(cur_i)=lig_tag then
                     if cur_i.char_tag() == char_tag::lig_tag {
                         /// address of lig/kern instruction
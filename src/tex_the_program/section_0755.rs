@@ -3,12 +3,12 @@
 // @<Create a character node |p| for |nucleus(q)|...@>=
 pub(crate) macro Create_a_character_node_p_for_nucleus_q__possibly_followed_by_a_kern_node_for_the_italic_correction__and_set_delta_to_the_italic_correction_if_a_subscript_is_present($globals:expr, $q:expr, $p:expr, $delta:expr) {{
     // begin fetch(nucleus(q));
-    let fetched = fetch($globals, nucleus!($q));
+    let fetched = fetch($globals, nucleus!($q))?;
     // if char_exists(cur_i) then
     if fetched.cur_i.char_exists() {
         // begin delta:=char_italic(cur_f)(cur_i); p:=new_character(cur_f,qo(cur_c));
         $delta = char_italic!($globals, fetched.cur_f, fetched.cur_i);
-        $p = new_character($globals, fetched.cur_f, fetched.cur_c);
+        $p = new_character($globals, fetched.cur_f, fetched.cur_c)?;
         // if (math_type(nucleus(q))=math_text_char)and(space(cur_f)<>0) then
         if math_type!($globals, nucleus!($q)) == math_type_kind::math_text_char as _
             && space!($globals, fetched.cur_f) != scaled::zero()
@@ -15,7 +15,7 @@ pub(crate) fn push_alignment(globals: &mut TeXGlobals) -> TeXResult<()> {
     llink!(globals, p) = preamble!(globals);
     rlink!(globals, p) = globals.cur_span;
     // mem[p+2].int:=cur_loop; mem[p+3].int:=align_state;
-    globals.mem[p + 2][MEMORY_WORD_INT] = globals.cur_loop.into();
+    globals.mem[p + 2][MEMORY_WORD_INT] = globals.cur_loop as _;
     globals.mem[p + 3][MEMORY_WORD_INT] = globals.align_state;
     // info(p+4):=cur_head; link(p+4):=cur_tail;
     info_inner!(globals, p + 4) = globals.cur_head;
@@ -23,7 +23,7 @@ pub(crate) fn push_alignment(globals: &mut TeXGlobals) -> TeXResult<()> {
     // align_ptr:=p;
     globals.align_ptr = p;
     // cur_head:=get_avail;
-    globals.cur_head = get_avail(globals);
+    globals.cur_head = get_avail(globals)?;
     // end;
     crate::ok_nojump!()
 }
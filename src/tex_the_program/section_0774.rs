@@ -28,7 +28,7 @@ pub(crate) fn init_align(globals: &mut TeXGlobals) -> TeXResult<()> {
     crate::section_0776::Check_for_improper_alignment_in_displayed_math!(globals);
     // push_nest; {enter a new semantic level}
     /// enter a new semantic level
-    push_nest(globals);
+    push_nest(globals)?;
     // @<Change current mode to |-vmode| for \.{\\halign}, |-hmode| for \.{\\valign}@>;
     crate::section_0775::Change_current_mode_to_neg_vmode_for_halign__neg_hmode_for_valign!(
         globals
@@ -41,7 +41,7 @@ pub(crate) fn init_align(globals: &mut TeXGlobals) -> TeXResult<()> {
         save_cs_ptr
     );
     // new_save_level(align_group);
-    new_save_level(globals, align_group.into());
+    new_save_level(globals, align_group.into())?;
     // if every_cr<>null then begin_token_list(every_cr,every_cr_text);
     if every_cr!(globals) != null {
         begin_token_list(globals, every_cr!(globals), every_cr_text);
@@ -7,15 +7,30 @@ pub(crate) macro Check_for_improper_alignment_in_displayed_math($globals:expr) {
     if mode!($globals) == mmode
         && (tail!($globals) != head!($globals) || incompleat_noad!($globals) as pointer != null)
     {
-        todo!("improper alignment");
-        //   begin print_err("Improper "); print_esc("halign"); print(" inside $$'s");
+        // begin print_err("Improper "); print_esc("halign"); print(" inside $$'s");
+        print_err!($globals, crate::strpool_str!("Improper "));
+        print_esc($globals, crate::strpool_str!("halign"));
+        print($globals, crate::strpool_str!(" inside $$'s").get() as _);
         // @.Improper \\halign...@>
-        //   help3("Displays can use special alignments (like \eqalignno)")@/
-        //   ("only if nothing but the alignment itself is between $$'s.")@/
-        //   ("So I've deleted the formulas that preceded this alignment.");
-        //   error; flush_math;
-        //   end
+        // help3("Displays can use special alignments (like \eqalignno)")@/
+        // ("only if nothing but the alignment itself is between $$'s.")@/
+        // ("So I've deleted the formulas that preceded this alignment.");
+        help3!(
+            $globals,
+            crate::strpool_str!("Displays can use special alignments (like \\eqalignno)"),
+            crate::strpool_str!("only if nothing but the alignment itself is between $$'s."),
+            crate::strpool_str!("So I've deleted the formulas that preceded this alignment.")
+        );
+        // error; flush_math;
+        error($globals)?;
+        flush_math($globals)?;
+        // end
     }
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
+    use crate::section_0082::error;
     use crate::section_0115::null;
     use crate::section_0115::pointer;
     use crate::section_0211::mmode;
@@ -23,4 +38,5 @@ pub(crate) macro Check_for_improper_alignment_in_displayed_math($globals:expr) {
     use crate::section_0213::incompleat_noad;
     use crate::section_0213::mode;
     use crate::section_0213::tail;
+    use crate::section_0718::flush_math;
 }}
@@ -34,10 +34,10 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0780($globals
         crate::strpool_str!("span"),
         tab_mark,
         span_code as _,
-    );
+    )?;
     // @!@:span_}{\.{\\span} primitive@>
     // primitive("cr",car_ret,cr_code);
-    primitive(globals, crate::strpool_str!("cr"), car_ret, cr_code as _);
+    primitive(globals, crate::strpool_str!("cr"), car_ret, cr_code as _)?;
     // @!@:cr_}{\.{\\cr} primitive@>
     // text(frozen_cr):="cr"; eqtb[frozen_cr]:=eqtb[cur_val];@/
     text!(globals, frozen_cr as pointer) = crate::strpool_str!("cr").get() as _;
@@ -48,7 +48,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0780($globals
         crate::strpool_str!("crcr"),
         car_ret,
         cr_cr_code as _,
-    );
+    )?;
     // @!@:cr_cr_}{\.{\\crcr} primitive@>
     // text(frozen_end_template):="endtemplate"; text(frozen_endv):="endtemplate";
     // @.endtemplate@>
@@ -33,8 +33,8 @@ pub(crate) fn get_preamble_token(globals: &mut TeXGlobals) -> TeXResult<()> {
             }
             // if cur_cmd=endv then
             if globals.cur_cmd == endv {
-                todo!("fatal error");
                 // fatal_error("(interwoven alignment preambles are not allowed)");
+                fatal_error(globals, crate::strpool_str!("(interwoven alignment preambles are not allowed)"))?;
             }
             // @.interwoven alignment preambles...@>
             // if (cur_cmd=assign_glue)and(cur_chr=glue_base+tab_skip_code) then
@@ -66,6 +66,7 @@ use crate::pascal::integer;
 use crate::pascal::word;
 use crate::section_0004::TeXGlobals;
 use crate::section_0081::TeXResult;
+use crate::section_0093::fatal_error;
 use crate::section_0101::small_number;
 use crate::section_0115::pointer;
 use crate::section_0207::endv;
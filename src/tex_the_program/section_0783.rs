@@ -27,19 +27,27 @@ pub(crate) macro Scan_the_template_u_j__putting_the_resulting_token_list_in_hold
                 }
                 // else  begin print_err("Missing # inserted in alignment preamble");
                 else {
+                    print_err!($globals, crate::strpool_str!("Missing # inserted in alignment preamble"));
                     // @.Missing \# inserted...@>
-                    //  help3("There should be exactly one # between &'s, when an")@/
-                    //  ("\halign or \valign is being set up. In this case you had")@/
-                    //  ("none, so I've put one in; maybe that will work.");
-                    //  back_error; goto done1;
-                    //  end
-                    todo!("error");
+                    // help3("There should be exactly one # between &'s, when an")@/
+                    // ("\halign or \valign is being set up. In this case you had")@/
+                    // ("none, so I've put one in; maybe that will work.");
+                    help3!(
+                        $globals,
+                        crate::strpool_str!("There should be exactly one # between &'s, when an"),
+                        crate::strpool_str!("\\halign or \\valign is being set up. In this case you had"),
+                        crate::strpool_str!("none, so I've put one in; maybe that will work.")
+                    );
+                    // back_error; goto done1;
+                    back_error($globals)?;
+                    crate::goto_forward_label!('done1);
+                    // end
                 }
             }
             // else if (cur_cmd<>spacer)or(p<>hold_head) then
             else if $globals.cur_cmd != spacer || p != hold_head {
                 // begin link(p):=get_avail; p:=link(p); info(p):=cur_tok;
-                link!($globals, p) = get_avail($globals);
+                link!($globals, p) = get_avail($globals)?;
                 p = link!($globals, p);
                 info_tok_assign!($globals, p, $globals.cur_tok);
                 // end;
@@ -50,6 +58,8 @@ pub(crate) macro Scan_the_template_u_j__putting_the_resulting_token_list_in_hold
         }
         'done1 <-
         );
+        use crate::section_0073::print_err;
+        use crate::section_0079::help3;
         use crate::section_0115::null;
         use crate::section_0115::pointer;
         use crate::section_0118::link;
@@ -60,6 +70,7 @@ pub(crate) macro Scan_the_template_u_j__putting_the_resulting_token_list_in_hold
         use crate::section_0207::tab_mark;
         use crate::section_0207::car_ret;
         use crate::section_0118::info_tok_assign;
+        use crate::section_0327::back_error;
         use crate::section_0782::get_preamble_token;
     }}
 }
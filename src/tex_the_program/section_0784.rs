@@ -11,7 +11,7 @@ pub(crate) macro Scan_the_template_v_j__putting_the_resulting_token_list_in_hold
         |'done2|
         {
         // loop@+  begin continue: get_preamble_token;
-        loop {
+        'continue_: loop {
             get_preamble_token($globals)?;
             // if (cur_cmd<=car_ret)and(cur_cmd>=tab_mark)and(align_state=-1000000) then
             //   goto done2;
@@ -20,17 +20,25 @@ pub(crate) macro Scan_the_template_v_j__putting_the_resulting_token_list_in_hold
             }
             // if cur_cmd=mac_param then
             if $globals.cur_cmd == mac_param {
-                todo!("error");
                 // begin print_err("Only one # is allowed per tab");
+                print_err!($globals, crate::strpool_str!("Only one # is allowed per tab"));
                 // @.Only one \# is allowed...@>
                 // help3("There should be exactly one # between &'s, when an")@/
                 // ("\halign or \valign is being set up. In this case you had")@/
                 // ("more than one, so I'm ignoring all but the first.");
+                help3!(
+                    $globals,
+                    crate::strpool_str!("There should be exactly one # between &'s, when an"),
+                    crate::strpool_str!("\\halign or \\valign is being set up. In this case you had"),
+                    crate::strpool_str!("more than one, so I'm ignoring all but the first.")
+                );
                 // error; goto continue;
+                error($globals)?;
+                crate::goto_backward_label!('continue_);
                 // end;
             }
             // link(p):=get_avail; p:=link(p); info(p):=cur_tok;
-            link!($globals, p) = get_avail($globals);
+            link!($globals, p) = get_avail($globals)?;
             p = link!($globals, p);
             info_tok_assign!($globals, p, $globals.cur_tok);
             // end;
@@ -39,12 +47,15 @@ pub(crate) macro Scan_the_template_v_j__putting_the_resulting_token_list_in_hold
         }
         'done2 <-
         );
-        link!($globals, p) = get_avail($globals);
+        link!($globals, p) = get_avail($globals)?;
         p = link!($globals, p);
         // info(p):=end_template_token {put \.{\\endtemplate} at the end}
         info_tok_assign!($globals, p, cur_tok_type::new(end_template_token));
         /// put `\endtemplate` at the end
         const _: () = ();
+        use crate::section_0073::print_err;
+        use crate::section_0079::help3;
+        use crate::section_0082::error;
         use crate::section_0115::pointer;
         use crate::section_0115::null;
         use crate::section_0118::link;
@@ -22,7 +22,7 @@ pub(crate) fn align_peek(globals: &mut TeXGlobals) -> TeXResult<()> {
             if globals.cur_cmd == no_align {
                 // begin scan_left_brace; new_save_level(no_align_group);
                 scan_left_brace(globals)?;
-                new_save_level(globals, no_align_group.into());
+                new_save_level(globals, no_align_group.into())?;
                 // if mode=-vmode then normal_paragraph;
                 if mode!(globals) == -vmode {
                     normal_paragraph(globals)?;
@@ -47,7 +47,7 @@ pub(crate) fn align_peek(globals: &mut TeXGlobals) -> TeXResult<()> {
                 init_row(globals)?;
                 // init_col; {start a new column and replace what we peeked at}
                 /// start a new column and replace what we peeked at
-                init_col(globals);
+                init_col(globals)?;
                 // end;
             }
             // end;
@@ -8,7 +8,7 @@
 // procedure init_row;
 pub(crate) fn init_row(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin push_nest; mode:=(-hmode-vmode)-mode;
-    push_nest(globals);
+    push_nest(globals)?;
     mode!(globals) = ((-hmode - vmode) - mode!(globals).get()).into();
     // if mode=-hmode then space_factor:=0 @+else prev_depth:=0;
     if mode!(globals) == -hmode {
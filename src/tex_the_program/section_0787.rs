@@ -6,7 +6,7 @@
 // procedure init_span(@!p:pointer);
 pub(crate) fn init_span(globals: &mut TeXGlobals, p: pointer) -> TeXResult<()> {
     // begin push_nest;
-    push_nest(globals);
+    push_nest(globals)?;
     // if mode=-hmode then space_factor:=1000
     if mode!(globals) == -hmode {
         space_factor!(globals) = 1000;
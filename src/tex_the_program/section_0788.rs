@@ -6,7 +6,7 @@
 //! called for.
 //
 // @p procedure init_col;
-pub(crate) fn init_col(globals: &mut TeXGlobals) {
+pub(crate) fn init_col(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin extra_info(cur_align):=cur_cmd;
     extra_info!(globals, globals.cur_align) = globals.cur_cmd.into();
     // if cur_cmd=omit then align_state:=0
@@ -15,7 +15,7 @@ pub(crate) fn init_col(globals: &mut TeXGlobals) {
     }
     // else  begin back_input; begin_token_list(u_part(cur_align),u_template);
     else {
-        back_input(globals);
+        back_input(globals)?;
         begin_token_list(
             globals,
             u_part!(globals, globals.cur_align) as _,
@@ -25,10 +25,12 @@ pub(crate) fn init_col(globals: &mut TeXGlobals) {
         /// now `align_state=1000000`
         const _: () = ();
     }
+    crate::ok_nojump!()
     // end;
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
 use crate::section_0208::omit;
 use crate::section_0307::u_template;
 use crate::section_0323::begin_token_list;
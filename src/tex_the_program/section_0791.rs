@@ -47,7 +47,7 @@ pub(crate) fn fin_col(globals: &mut TeXGlobals) -> TeXResult<boolean> {
     if extra_info!(globals, globals.cur_align) != span_code {
         // begin unsave; new_save_level(align_group);@/
         unsave(globals)?;
-        new_save_level(globals, align_group.into());
+        new_save_level(globals, align_group.into())?;
         // @<Package an unset box for the current column and record its width@>;
         crate::section_0796::Package_an_unset_box_for_the_current_column_and_record_its_width!(
             globals
@@ -70,7 +70,7 @@ pub(crate) fn fin_col(globals: &mut TeXGlobals) -> TeXResult<boolean> {
     // cur_align:=p;
     globals.cur_align = p;
     // init_col; fin_col:=false;
-    init_col(globals);
+    init_col(globals)?;
     crate::ok_nojump!(false)
     // exit: end;
 }
@@ -9,7 +9,7 @@ pub(crate) macro Copy_the_templates_from_node_cur_loop_into_node_p($globals:expr
     // while r<>null do
     while r != null {
         // begin link(q):=get_avail; q:=link(q); info(q):=info(r); r:=link(r);
-        link!($globals, q) = get_avail($globals);
+        link!($globals, q) = get_avail($globals)?;
         q = link!($globals, q);
         info_inner!($globals, q) = info_inner!($globals, r);
         r = link!($globals, r);
@@ -24,7 +24,7 @@ pub(crate) macro Copy_the_templates_from_node_cur_loop_into_node_p($globals:expr
     // while r<>null do
     while r != null {
         // begin link(q):=get_avail; q:=link(q); info(q):=info(r); r:=link(r);
-        link!($globals, q) = get_avail($globals);
+        link!($globals, q) = get_avail($globals)?;
         q = link!($globals, q);
         info_inner!($globals, q) = info_inner!($globals, r);
         r = link!($globals, r);
@@ -33,18 +33,33 @@ pub(crate) macro Make_the_unset_node_r_into_a_vlist_node_of_height_w__setting_th
     }
     // else  begin glue_order(r):=glue_sign(r); glue_sign(r):=shrinking;
     else {
+        glue_order!($globals, $r) = glue_sign!($globals, $r);
+        glue_sign!($globals, $r) = glue_sign::shrinking as _;
         // if glue_shrink(r)=0 then set_glue_ratio_zero(glue_set(r))
+        if glue_shrink!($globals, $r) == scaled::zero() {
+            set_glue_ratio_zero!(glue_set!($globals, $r));
+        }
         // else if (glue_order(r)=normal)and(height(r)-t>glue_shrink(r)) then
-        //   set_glue_ratio_one(glue_set(r))
+        else if glue_order!($globals, $r) == glue_ord::normal as _
+            && height!($globals, $r) - $t > glue_shrink!($globals, $r)
+        {
+            //   set_glue_ratio_one(glue_set(r))
+            set_glue_ratio_one!(glue_set!($globals, $r));
+        }
         // else glue_set(r):=unfloat((height(r)-t)/glue_shrink(r));
+        else {
+            glue_set!($globals, $r) = unfloat!(
+                (height!($globals, $r) - $t).inner_real() / glue_shrink!($globals, $r).inner_real()
+            );
+        }
         // end;
-        todo!("t < height(r)");
     }
     // height(r):=w; type(r):=vlist_node;
     height!($globals, $r) = $w;
     r#type!($globals, $r) = vlist_node;
     // end
     use crate::section_0101::scaled;
+    use crate::section_0109::set_glue_ratio_one;
     use crate::section_0109::set_glue_ratio_zero;
     use crate::section_0109::unfloat;
     use crate::section_0133::r#type;
@@ -55,5 +70,6 @@ pub(crate) macro Make_the_unset_node_r_into_a_vlist_node_of_height_w__setting_th
     use crate::section_0135::width;
     use crate::section_0137::vlist_node;
     use crate::section_0150::glue_ord;
+    use crate::section_0159::glue_shrink;
     use crate::section_0159::glue_stretch;
 }}
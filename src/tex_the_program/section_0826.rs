@@ -10,6 +10,14 @@ pub(crate) fn finite_shrink(globals: &mut TeXGlobals, p: pointer) -> TeXResult<p
     if globals.no_shrink_error_yet {
         // begin no_shrink_error_yet:=false;
         globals.no_shrink_error_yet = false;
+        // @!stat if tracing_paragraphs>0 then end_diagnostic(true);@+tats@;
+        crate::region_stat! {
+            if tracing_paragraphs!(globals) > 0 {
+                end_diagnostic(globals, true);
+            }
+            use crate::section_0236::tracing_paragraphs;
+            use crate::section_0245::end_diagnostic;
+        }
         // print_err("Infinite glue shrinkage found in a paragraph");
         print_err!(
             globals,
@@ -31,11 +39,19 @@ pub(crate) fn finite_shrink(globals: &mut TeXGlobals, p: pointer) -> TeXResult<p
         );
         // error;
         error(globals)?;
+        // @!stat if tracing_paragraphs>0 then begin_diagnostic;@+tats@;
+        crate::region_stat! {
+            if tracing_paragraphs!(globals) > 0 {
+                begin_diagnostic(globals);
+            }
+            use crate::section_0236::tracing_paragraphs;
+            use crate::section_0245::begin_diagnostic;
+        }
         // end;
     }
     // q:=new_spec(p); shrink_order(q):=normal;
     q = new_spec(globals, p)?;
-    shrink_order!(globals, p) = glue_ord::normal as _;
+    shrink_order!(globals, q) = glue_ord::normal as _;
     // delete_glue_ref(p); finite_shrink:=q;
     delete_glue_ref(globals, p);
     crate::ok_nojump!(q)
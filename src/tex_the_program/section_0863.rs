@@ -139,7 +139,7 @@ pub(crate) macro Find_optimal_breakpoints
             if tracing_paragraphs!($globals) > 0 {
                 // begin end_diagnostic(true); normalize_selector;
                 end_diagnostic($globals, true);
-                normalize_selector($globals);
+                normalize_selector($globals)?;
                 // end;@+tats@/
             }
             use crate::section_0092::normalize_selector;
@@ -31,8 +31,9 @@ pub(crate) macro Carry_out_a_ligature_replacement__updating_the_cursor_structure
             $globals.cur_r = $q.rem_byte() as _;
             // if lig_stack>null then character(lig_stack):=cur_r
             if $globals.lig_stack > null {
-                character!($globals, $globals.lig_stack) =
-                    ASCII_code::from($globals.cur_r as integer);
+                let f = null_font;
+                let c = ASCII_code::from($globals.cur_r as integer);
+                assign_font_and_character!($globals, $globals.lig_stack, f, c);
             }
             // else begin lig_stack:=new_lig_item(cur_r);
             else {
@@ -46,7 +47,7 @@ pub(crate) macro Carry_out_a_ligature_replacement__updating_the_cursor_structure
                 else {
                     /// temporary register for list manipulation
                     let p: pointer;
-                    p = get_avail($globals);
+                    p = get_avail($globals)?;
                     lig_ptr!($globals, $globals.lig_stack) = p;
                     // character(p):=qi(hu[j+1]); font(p):=hf;
                     assign_font_and_character!(
@@ -132,9 +133,9 @@ pub(crate) macro Carry_out_a_ligature_replacement__updating_the_cursor_structure
     use crate::section_0118::link;
     use crate::section_0120::get_avail;
     use crate::section_0134::assign_font_and_character;
-    use crate::section_0134::character;
     use crate::section_0143::lig_ptr;
     use crate::section_0144::new_lig_item;
+    use crate::section_0232::null_font;
     use crate::section_0549::non_char;
     use crate::section_0908::append_charnode_to_t;
     use crate::section_0908::set_cur_r;
@@ -9,7 +9,7 @@ pub(crate) macro Put_the_characters_hu_l_to_i_and_a_hyphen_into_pre_break_r($glo
     // minor_tail:=null; pre_break(r):=null; hyf_node:=new_character(hf,hyf_char);
     $minor_tail = null;
     pre_break!($globals, $r) = null;
-    hyf_node = new_character($globals, $globals.hf, ASCII_code::from($globals.hyf_char));
+    hyf_node = new_character($globals, $globals.hf, ASCII_code::from($globals.hyf_char))?;
     // if hyf_node<>null then
     if hyf_node != null {
         // begin incr(i); c:=hu[i]; hu[i]:=hyf_char; free_avail(hyf_node);
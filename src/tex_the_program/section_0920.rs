@@ -28,12 +28,10 @@
 pub(crate) type trie_pointer = u16_from_0_to_n<trie_size_TYPENUM>;
 pub(crate) type trie_pointer_repr = u16;
 
-type trie_pointer_array_LENGTH_TYPENUM = typenum::op!(trie_size_TYPENUM + U1);
-
-define_array_keyed_with_ranged_unsigned_integer_from_0_with_fixed_length!(
-    pub(crate) trie_pointer_array[trie_pointer] => u16; U16; trie_pointer_array_LENGTH_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) trie_pointer_array[trie_pointer] => u16; U16; U0
 );
 
 use crate::pascal::u16_from_0_to_n;
 use crate::section_0011::trie_size_TYPENUM;
-use typenum::U1;
+use typenum::U0;
@@ -34,7 +34,7 @@ pub(crate) macro Find_hyphen_locations_for_the_word_in_hc__or_return($globals:ex
                 /// indices into `hc` or `hu`
                 let mut l;
                 // begin z:=trie_link(cur_lang+1)+hc[j]; l:=j;
-                z = trie_link!($globals, $globals.cur_lang.numeric_value() as u16 + 1) + $globals.hc[j as usize] as u16;
+                z = trie_link!($globals, $globals.cur_lang.numeric_value() as u16 + 1) as u16 + $globals.hc[j as usize] as u16;
                 l = j;
                 // while hc[l]=qo(trie_char(z)) do
                 while $globals.hc[l as usize] as integer == trie_char!($globals, z).numeric_value() as integer {
@@ -45,7 +45,7 @@ pub(crate) macro Find_hyphen_locations_for_the_word_in_hc__or_return($globals:ex
                     }
                     // incr(l); z:=trie_link(z)+hc[l];
                     incr!(l);
-                    z = trie_link!($globals, z) + $globals.hc[l as usize] as u16;
+                    z = trie_link!($globals, z) as u16 + $globals.hc[l as usize] as u16;
                     // end;
                 }
                 // end;
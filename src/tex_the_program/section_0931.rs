@@ -28,7 +28,7 @@ pub(crate) macro If_the_string_hyph_word_h_is_less_than_hc_1_to_hn__goto_not_fou
                     // @<Insert hyphens as specified in |hyph_list[h]|@>;
                     // decr(hn); goto found;
                     // end;
-                    todo!("without unicode support")
+                    crate::unported::unported!($globals, 931, "without unicode support")
                 }
             }
             #[cfg(feature = "unicode_support")]
@@ -67,7 +67,7 @@ pub(crate) macro If_the_string_hyph_word_h_is_less_than_hc_1_to_hn__goto_not_fou
                     decr!($globals.hn);
                     crate::goto_forward_label!($lbl_found);
                     // end;
-                    todo!("without unicode support")
+                    crate::unported::unported!($globals, 931, "without unicode support")
                 }
             }
         }
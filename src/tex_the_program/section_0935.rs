@@ -47,7 +47,7 @@ pub(crate) macro Enter_as_many_hyphenation_exceptions_as_are_listed__until_comin
                 }
                 // othercases @<Give improper \.{\\hyphenation} error@>
                 else {
-                    todo!("error");
+                    crate::section_0936::Give_improper_hyphenation_error!($globals);
                 }
                 // endcases;
             }
@@ -1,9 +1,26 @@
-//! @ @<Give improper \.{\\hyph...@>=
-//! begin print_err("Improper "); print_esc("hyphenation");
-//! @.Improper \\hyphenation...@>
-//!   print(" will be flushed");
-//! help2("Hyphenation exceptions must contain only letters")@/
-//!   ("and hyphens. But continue; I'll forgive and forget.");
-//! error;
-//! end
-//!
+//! ` `
+
+// @<Give improper \.{\\hyph...@>=
+pub(crate) macro Give_improper_hyphenation_error($globals:expr) {{
+    // begin print_err("Improper "); print_esc("hyphenation");
+    print_err!($globals, crate::strpool_str!("Improper "));
+    print_esc($globals, crate::strpool_str!("hyphenation"));
+    // @.Improper \\hyphenation...@>
+    //   print(" will be flushed");
+    print($globals, crate::strpool_str!(" will be flushed").get() as _);
+    // help2("Hyphenation exceptions must contain only letters")@/
+    //   ("and hyphens. But continue; I'll forgive and forget.");
+    help2!(
+        $globals,
+        crate::strpool_str!("Hyphenation exceptions must contain only letters"),
+        crate::strpool_str!("and hyphens. But continue; I'll forgive and forget.")
+    );
+    // error;
+    error($globals)?;
+    // end
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+}}
@@ -7,7 +7,7 @@ pub(crate) macro Append_the_value_n_to_list_p($globals:expr, $n:expr, $p:expr) {
     // begin if n<63 then
     if $n < 63 {
         // begin q:=get_avail; link(q):=p; info(q):=n; p:=q;
-        q = get_avail($globals);
+        q = get_avail($globals)?;
         link!($globals, q) = $p;
         info_inner!($globals, q) = $n.get().into();
         $p = q;
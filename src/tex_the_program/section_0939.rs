@@ -6,7 +6,7 @@ pub(crate) macro Enter_a_hyphenation_exception($globals:expr, $n:expr, $p:expr)
     // begin incr(n); hc[n]:=cur_lang; str_room(n); h:=0;
     incr!($n);
     $globals.hc[$n.get() as usize] = $globals.cur_lang.numeric_value() as _;
-    str_room($globals, $n.get() as integer * character_max_room);
+    str_room($globals, $n.get() as integer * character_max_room)?;
 
     /// an index into `hyph_word` and `hyph_list`
     let mut h: hyph_pointer;
@@ -28,7 +28,7 @@ pub(crate) macro Enter_a_hyphenation_exception($globals:expr, $n:expr, $p:expr)
     }
 
     /// strings being compared or stored
-    let s: str_number;
+    let mut s: str_number;
     // s:=make_string;
     s = make_string(make_globals_string_view!($globals));
     // @<Insert the \(p)pair |(s,p)| into the exception table@>;
@@ -5,17 +5,28 @@ pub(crate) macro Insert_the_pair_s_p_into_the_exception_table($globals:expr, $h:
     crate::trace_span_verbose!("Insert the pair `(s,p)`...");
     // if hyph_count=hyph_size then overflow("exception dictionary",hyph_size);
     if $globals.hyph_count.get() as integer == hyph_size {
-        todo!("overflow");
+        overflow(
+            $globals,
+            crate::strpool_str!("exception dictionary"),
+            hyph_size,
+        )?;
     }
     // @:TeX capacity exceeded exception dictionary}{\quad exception dictionary@>
     // incr(hyph_count);
     incr!($globals.hyph_count);
     // while hyph_word[h]<>0 do
     while $globals.hyph_word[$h] != 0 {
-        todo!("!=0")
         // begin @<If the string |hyph_word[h]| is less than \(or)or equal to
         // |s|, interchange |(hyph_word[h],hyph_list[h])| with |(s,p)|@>;
+        crate::section_0941::If_the_string_hyph_word_h_is_less_than_or_equal_to_s__interchange_hyph_word_h_hyph_list_h_with_s_p!(
+            $globals, $h, $s, $p
+        );
         // if h>0 then decr(h)@+else h:=hyph_size;
+        if $h.get() > 0 {
+            $h = hyph_pointer::new($h.get() - 1);
+        } else {
+            $h = hyph_pointer::new(hyph_size as _);
+        }
         // end;
     }
     // hyph_word[h]:=s; hyph_list[h]:=p
@@ -24,4 +35,6 @@ pub(crate) macro Insert_the_pair_s_p_into_the_exception_table($globals:expr, $h:
     use crate::pascal::integer;
     use crate::section_0012::hyph_size;
     use crate::section_0016::incr;
+    use crate::section_0094::overflow;
+    use crate::section_0925::hyph_pointer;
 }}
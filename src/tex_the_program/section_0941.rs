@@ -1,13 +1,86 @@
-//! @ @<If the string |hyph_word[h]| is less than \(or)...@>=
-//! k:=hyph_word[h];
-//! if length(k)<length(s) then goto found;
-//! if length(k)>length(s) then goto not_found;
-//! u:=str_start[k]; v:=str_start[s];
-//! repeat if str_pool[u]<str_pool[v] then goto found;
-//! if str_pool[u]>str_pool[v] then goto not_found;
-//! incr(u); incr(v);
-//! until u=str_start[k+1];
-//! found:q:=hyph_list[h]; hyph_list[h]:=p; p:=q;@/
-//! t:=hyph_word[h]; hyph_word[h]:=s; s:=t;
-//! not_found:
-//!
+//! ` `
+
+// @<If the string |hyph_word[h]| is less than \(or)...@>=
+pub(crate) macro If_the_string_hyph_word_h_is_less_than_or_equal_to_s__interchange_hyph_word_h_hyph_list_h_with_s_p($globals:expr, $h:expr, $s:expr, $p:expr) {{
+    crate::region_forward_label! {
+        |'not_found|
+        {
+            crate::region_forward_label! {
+                |'found|
+                {
+                    /// an index into `str_start`
+                    let k;
+                    // k:=hyph_word[h];
+                    k = $globals.hyph_word[$h];
+                    #[cfg(not(feature = "unicode_support"))]
+                    {
+                        // if length(k)<length(s) then goto found;
+                        // if length(k)>length(s) then goto not_found;
+                        // u:=str_start[k]; v:=str_start[s];
+                        // repeat if str_pool[u]<str_pool[v] then goto found;
+                        // if str_pool[u]>str_pool[v] then goto not_found;
+                        // incr(u); incr(v);
+                        // until u=str_start[k+1];
+                        crate::unported::unported!($globals, 941, "without unicode support");
+                    }
+                    #[cfg(feature = "unicode_support")]
+                    {
+                        let length_k = $globals
+                            .str_pool
+                            .str_ascii_codes(&$globals.str_start, k)
+                            .count();
+                        let length_s = $globals
+                            .str_pool
+                            .str_ascii_codes(&$globals.str_start, $s)
+                            .count();
+                        // if length(k)<length(s) then goto found;
+                        if length_k < length_s {
+                            crate::goto_forward_label!('found);
+                        }
+                        // if length(k)>length(s) then goto not_found;
+                        if length_k > length_s {
+                            crate::goto_forward_label!('not_found);
+                        }
+                        // u:=str_start[k]; v:=str_start[s];
+                        // repeat if str_pool[u]<str_pool[v] then goto found;
+                        // if str_pool[u]>str_pool[v] then goto not_found;
+                        // incr(u); incr(v);
+                        // until u=str_start[k+1];
+                        for (u, v) in $globals
+                            .str_pool
+                            .str_ascii_codes(&$globals.str_start, k)
+                            .zip($globals.str_pool.str_ascii_codes(&$globals.str_start, $s))
+                        {
+                            let u = xord(u).numeric_value();
+                            let v = xord(v).numeric_value();
+                            if u < v {
+                                crate::goto_forward_label!('found);
+                            }
+                            if u > v {
+                                crate::goto_forward_label!('not_found);
+                            }
+                        }
+                    }
+                }
+                'found <-
+            }
+            // found:q:=hyph_list[h]; hyph_list[h]:=p; p:=q;@/
+            /// temporary registers for list manipulation
+            let q: pointer;
+            q = $globals.hyph_list[$h];
+            $globals.hyph_list[$h] = $p;
+            $p = q;
+            // t:=hyph_word[h]; hyph_word[h]:=s; s:=t;
+            /// strings being compared or stored
+            let t: str_number;
+            t = $globals.hyph_word[$h];
+            $globals.hyph_word[$h] = $s;
+            $s = t;
+        }
+        // not_found:
+        'not_found <-
+    }
+    use crate::section_0020::xord;
+    use crate::section_0038::str_number;
+    use crate::section_0115::pointer;
+}}
@@ -101,7 +101,7 @@ use typenum::U1;
 pub(crate) macro trie_used($globals:expr, $sel:expr) {{
     let idx = $sel.numeric_value();
     if idx >= 256 {
-        todo!(">= 256");
+        crate::unported::unported!($globals, 943, ">= 256");
     }
     $globals.trie_used[idx as usize]
 }}
@@ -109,7 +109,7 @@ pub(crate) macro trie_used($globals:expr, $sel:expr) {{
 pub(crate) macro trie_used_assign($globals:expr, $sel:expr, $val:expr) {{
     let idx = $sel.numeric_value();
     if idx >= 256 {
-        todo!(">= 256");
+        crate::unported::unported!($globals, 943, ">= 256");
     }
     $globals.trie_used[idx as usize] = $val;
 }}
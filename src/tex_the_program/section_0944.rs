@@ -12,7 +12,7 @@ pub(crate) fn new_trie_op(
     d: small_number,
     n: small_number,
     v: quarterword,
-) -> quarterword {
+) -> TeXResult<quarterword> {
     // label exit;
     // var h:-trie_op_size..trie_op_size; {trial hash location}
     /// trial hash location
@@ -42,16 +42,24 @@ pub(crate) fn new_trie_op(
             const _: () = ();
             // begin if trie_op_ptr=trie_op_size then
             if globals.trie_op_ptr == trie_op_size {
-                todo!("overflow");
                 // overflow("pattern memory ops",trie_op_size);
+                overflow(
+                    globals,
+                    crate::strpool_str!("pattern memory ops"),
+                    trie_op_size as integer,
+                )?;
             }
             // u:=trie_used[cur_lang];
             u = trie_used!(globals, globals.cur_lang);
             // if u=max_quarterword then
             if u == max_quarterword {
-                todo!("overflow");
                 // overflow("pattern memory ops per language",
                 //   max_quarterword-min_quarterword);
+                overflow(
+                    globals,
+                    crate::strpool_str!("pattern memory ops per language"),
+                    (max_quarterword - min_quarterword) as integer,
+                )?;
             }
             // incr(trie_op_ptr); incr(u); trie_used[cur_lang]:=u;
             incr!(globals.trie_op_ptr);
@@ -67,7 +75,7 @@ pub(crate) fn new_trie_op(
             globals.trie_op_hash[h] = globals.trie_op_ptr;
             // trie_op_val[trie_op_ptr]:=u; new_trie_op:=u; return;
             globals.trie_op_val[globals.trie_op_ptr.get()] = u;
-            return u;
+            crate::return_nojump!(u);
             // end;
         }
         // if (hyf_distance[l]=d)and(hyf_num[l]=n)and(hyf_next[l]=v)
@@ -78,7 +86,7 @@ pub(crate) fn new_trie_op(
             && globals.trie_op_lang[l.get()] == globals.cur_lang
         {
             // begin new_trie_op:=trie_op_val[l]; return;
-            return globals.trie_op_val[l.get()];
+            crate::return_nojump!(globals.trie_op_val[l.get()]);
             // end;
         }
         // if h>-trie_op_size then decr(h)@+else h:=trie_op_size;
@@ -102,8 +110,11 @@ use crate::section_0011::trie_op_size_POS_TYPENUM;
 use crate::section_0011::trie_op_size_TYPENUM;
 use crate::section_0016::decr;
 use crate::section_0016::incr;
+use crate::section_0081::TeXResult;
+use crate::section_0094::overflow;
 use crate::section_0101::small_number;
 use crate::section_0110::max_quarterword;
+use crate::section_0110::min_quarterword;
 use crate::section_0113::quarterword;
 use crate::section_0943::trie_used;
 use crate::section_0943::trie_used_assign;
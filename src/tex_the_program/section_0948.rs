@@ -31,7 +31,7 @@ pub(crate) fn trie_node(globals: &mut TeXGlobals, p: trie_pointer) -> trie_point
             + 1009 * globals.trie_o[p] as integer
             + 2718 * globals.trie_l[p].get() as integer
             + 3142 * globals.trie_r[p].get() as integer)
-            % (trie_size as integer)) as _,
+            % (globals.trie_size as integer)) as _,
     );
     // loop@+  begin q:=trie_hash[h];
     loop {
@@ -58,7 +58,7 @@ pub(crate) fn trie_node(globals: &mut TeXGlobals, p: trie_pointer) -> trie_point
         if h > 0 {
             decr!(h);
         } else {
-            h = trie_size.into();
+            h = globals.trie_size.into();
         }
         // end;
     }
@@ -67,6 +67,5 @@ pub(crate) fn trie_node(globals: &mut TeXGlobals, p: trie_pointer) -> trie_point
 
 use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
-use crate::section_0011::trie_size;
 use crate::section_0016::decr;
 use crate::section_0920::trie_pointer;
@@ -39,9 +39,8 @@ pub(crate) static trie_taken: trie_taken_array<boolean> = Default::default();
 #[globals_struct_use(TeXGlobals)]
 use crate::section_0950::trie_taken_array;
 
-define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_length!(
-    pub(crate) trie_taken_array[u16_from_m_to_n<U1, trie_size_TYPENUM>] =>
-    u16; U16; U1; trie_size_TYPENUM
+define_array_keyed_with_ranged_unsigned_integer_with_fixed_start_and_run_time_length!(
+    pub(crate) trie_taken_array[u16_from_m_to_n<U1, trie_size_TYPENUM>] => u16; U16; U1
 );
 
 // @t\hskip10pt@>@!trie_min:array[ASCII_code] of trie_pointer;
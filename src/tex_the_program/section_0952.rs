@@ -8,7 +8,7 @@ pub(crate) macro Get_ready_to_compress_the_trie($globals:expr) {{
     // @<Sort \(t)the hyphenation...@>;
     crate::section_0945::Sort_the_hyphenation_op_tables_into_proper_order!($globals);
     // for p:=0 to trie_size do trie_hash[p]:=0;
-    for p in 0..=trie_size {
+    for p in 0..=$globals.trie_size {
         $globals.trie_hash[p] = 0.into();
     }
     // trie_root:=compress_trie(trie_root); {identify equivalent subtries}
@@ -27,7 +27,6 @@ pub(crate) macro Get_ready_to_compress_the_trie($globals:expr) {{
     trie_link!($globals, 0) = 1;
     $globals.trie_max = 0.into();
 
-    use crate::section_0011::trie_size;
     use crate::section_0921::trie_link;
     use crate::section_0947::trie_root;
     use crate::section_0949::compress_trie;
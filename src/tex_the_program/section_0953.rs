@@ -31,7 +31,7 @@ pub(crate) fn first_fit(globals: &mut TeXGlobals, p: trie_pointer) -> TeXResult<
     let c_u8 = if c.numeric_value() < 256 {
         c.numeric_value() as u8
     } else {
-        todo!("not yet implemented in {}", file!());
+        crate::unported::unported!(globals, 953);
     };
     z = globals.trie_min[c_u8 as usize];
     crate::region_forward_label!(
@@ -59,7 +59,7 @@ pub(crate) fn first_fit(globals: &mut TeXGlobals, p: trie_pointer) -> TeXResult<
                 );
                 /// move to the next hole
                 const _: () = ();
-                z = trie_link!(globals, z.get()).into();
+                z = (trie_link!(globals, z.get()) as u16).into();
                 // end;
             }
         }
@@ -7,11 +7,11 @@ pub(crate) macro Ensure_that_trie_max_ge_h_plus_256($globals:expr, $h:expr) {{
     // if trie_max<h+256 then
     if $globals.trie_max < $h + 256 {
         // begin if trie_size<=h+256 then overflow("pattern memory",trie_size);
-        if trie_size <= $h.get() + 256 {
+        if $globals.trie_size as u32 <= $h.get() as u32 + 256 {
             overflow(
                 $globals,
                 crate::strpool_str!("pattern memory"),
-                trie_size as _,
+                $globals.trie_size as _,
             )?;
         }
         // @:TeX capacity exceeded pattern memory}{\quad pattern memory@>
@@ -20,8 +20,8 @@ pub(crate) macro Ensure_that_trie_max_ge_h_plus_256($globals:expr, $h:expr) {{
             incr!($globals.trie_max);
             $globals.trie_taken[$globals.trie_max.get()] = false;
             // trie_link(trie_max):=trie_max+1; trie_back(trie_max):=trie_max-1;
-            trie_link!($globals, $globals.trie_max) = $globals.trie_max.get() + 1;
-            trie_back!($globals, $globals.trie_max) = $globals.trie_max.get() - 1;
+            trie_link!($globals, $globals.trie_max) = ($globals.trie_max.get() + 1) as _;
+            trie_back!($globals, $globals.trie_max) = ($globals.trie_max.get() - 1) as _;
             // until trie_max=h+256;
             if $globals.trie_max == $h + 256 {
                 break;
@@ -29,7 +29,6 @@ pub(crate) macro Ensure_that_trie_max_ge_h_plus_256($globals:expr, $h:expr) {{
         }
         // end
     }
-    use crate::section_0011::trie_size;
     use crate::section_0016::incr;
     use crate::section_0094::overflow;
     use crate::section_0921::trie_link;
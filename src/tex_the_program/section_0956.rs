@@ -15,16 +15,16 @@ pub(crate) macro Pack_the_family_into_trie_relative_to_h($globals:expr, $h:expr,
 
         let trie_c_q = $globals.trie_c[q];
         let trie_c_q_u8 = if trie_c_q.numeric_value() > 255 {
-            todo!("not yet implemented in {}", file!());
+            crate::unported::unported!($globals, 956);
         } else {
             trie_c_q.numeric_value() as u8
         };
         $z = trie_pointer::new(($h.get() as integer + trie_c_q_u8 as integer) as _);
-        l = trie_back!($globals, $z.get()).into();
-        r = trie_link!($globals, $z.get()).into();
+        l = (trie_back!($globals, $z.get()) as u16).into();
+        r = (trie_link!($globals, $z.get()) as u16).into();
         // trie_back(r):=l; trie_link(l):=r; trie_link(z):=0;
-        trie_back!($globals, r) = l.get();
-        trie_link!($globals, l) = r.get();
+        trie_back!($globals, r) = l.get() as _;
+        trie_link!($globals, l) = r.get() as _;
         trie_link!($globals, $z.get()) = 0;
         // if l<256 then
         if l < 256 {
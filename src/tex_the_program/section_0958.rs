@@ -49,14 +49,14 @@ pub(crate) macro Move_the_data_into_trie($globals:expr) {{
         /// used to clean up the packed `trie`
         let (mut r, mut s): (trie_pointer, trie_pointer);
 
-        trie_fix($globals, trie_root!($globals));
+        trie_fix($globals, trie_root!($globals))?;
         // r:=0; {now we will zero out all the holes}
         /// now we will zero out all the holes
         const _: () = ();
         r = 0.into();
         // repeat s:=trie_link(r); trie[r]:=h; r:=s;
         loop {
-            s = trie_link!($globals, r.get()).into();
+            s = (trie_link!($globals, r.get()) as u16).into();
             $globals.trie[r.get()] = h;
             r = s;
             // until r>trie_max;
@@ -7,7 +7,7 @@
 // @<Declare procedures for preprocessing hyph...@>=
 // procedure trie_fix(@!p:trie_pointer); {moves |p| and its siblings into |trie|}
 /// moves `p` and its siblings into `trie`
-pub(crate) fn trie_fix(globals: &mut TeXGlobals, mut p: trie_pointer) {
+pub(crate) fn trie_fix(globals: &mut TeXGlobals, mut p: trie_pointer) -> TeXResult<()> {
     // var q:trie_pointer; {a local variable that need not be saved on recursive calls}
     /// a local variable that need not be saved on recursive calls
     let mut q: trie_pointer;
@@ -26,15 +26,15 @@ pub(crate) fn trie_fix(globals: &mut TeXGlobals, mut p: trie_pointer) {
         let c_u8 = if c.numeric_value() < 256 {
             c.numeric_value() as u8
         } else {
-            todo!("not yet implemented in {}", file!());
+            crate::unported::unported!(globals, 959);
         };
         // trie_link(z+c):=trie_ref[q]; trie_char(z+c):=qi(c); trie_op(z+c):=trie_o[p];
-        trie_link!(globals, z + c_u8 as trie_pointer_repr) = trie_ref!(globals, q).get();
+        trie_link!(globals, z + c_u8 as trie_pointer_repr) = trie_ref!(globals, q).get() as _;
         let trie_op = globals.trie_o[p];
         assign_trie_char_and_op!(globals, z + c_u8 as trie_pointer_repr, c, trie_op);
         // if q>0 then trie_fix(q);
         if q > 0 {
-            trie_fix(globals, q);
+            trie_fix(globals, q)?;
         }
         // p:=trie_r[p];
         p = globals.trie_r[p];
@@ -44,10 +44,12 @@ pub(crate) fn trie_fix(globals: &mut TeXGlobals, mut p: trie_pointer) {
         }
     }
     // end;
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0018::ASCII_code;
+use crate::section_0081::TeXResult;
 use crate::section_0920::trie_pointer;
 use crate::section_0920::trie_pointer_repr;
 use crate::section_0921::assign_trie_char_and_op;
@@ -3,8 +3,12 @@
 // @<Insert a new trie node between |q| and |p|...@>=
 pub(crate) macro Insert_a_new_trie_node_between_q_and_p__and_make_p_point_to_it($globals:expr, $p:expr, $q:expr, $c:expr, $first_child:expr) {{
     // begin if trie_ptr=trie_size then overflow("pattern memory",trie_size);
-    if $globals.trie_ptr == trie_size {
-        todo!("overflow");
+    if $globals.trie_ptr == $globals.trie_size {
+        overflow(
+            $globals,
+            crate::strpool_str!("pattern memory"),
+            $globals.trie_size as integer,
+        )?;
     }
     // @:TeX capacity exceeded pattern memory}{\quad pattern memory@>
     // incr(trie_ptr); trie_r[trie_ptr]:=p; p:=trie_ptr; trie_l[p]:=0;
@@ -22,8 +26,9 @@ pub(crate) macro Insert_a_new_trie_node_between_q_and_p__and_make_p_point_to_it(
     trie_c_assign!($globals, $p, $c);
     $globals.trie_o[$p] = min_quarterword;
     // end
-    use crate::section_0011::trie_size;
+    use crate::pascal::integer;
     use crate::section_0016::incr;
+    use crate::section_0094::overflow;
     use crate::section_0110::min_quarterword;
     use crate::section_0947::trie_c_assign;
 }}
@@ -20,7 +20,7 @@ pub(crate) macro Compute_the_trie_op_code__v__and_set_l_to_0
         // loop@+  begin if hyf[l]<>0 then v:=new_trie_op(k-l,hyf[l],v);
         loop {
             if $globals.hyf[$l.get() as usize] != 0 {
-                $v = new_trie_op($globals, ($k - $l).get().into(), $globals.hyf[$l.get() as usize].get().into(), $v);
+                $v = new_trie_op($globals, ($k - $l).get().into(), $globals.hyf[$l.get() as usize].get().into(), $v)?;
             }
             // if l>0 then decr(l)@+else goto done1;
             if $l > 0 {
@@ -14,7 +14,11 @@ pub(crate) macro Use_node_p_to_update_the_current_height_and_depth_measurements_
     }
     // whatsit_node:@<Process whatsit |p| in |vert_break| loop, |goto not_found|@>;
     else if type_p == whatsit_node {
-        todo!("whatsit");
+        crate::section_1365::Process_whatsit_p_in_vert_break_loop__goto_not_found!(
+            $globals,
+            $p,
+            $lbl_not_found
+        );
     }
     // glue_node: if precedes_break(prev_p) then pi:=0
     else if type_p == glue_node {
@@ -28,10 +32,22 @@ pub(crate) macro Use_node_p_to_update_the_current_height_and_depth_measurements_
     }
     // kern_node: begin if link(p)=null then t:=penalty_node
     else if type_p == kern_node {
+        /// `type` of the node following a kern
+        let t;
+        if link!($globals, $p) == null {
+            t = penalty_node;
+        }
         // else t:=type(link(p));
+        else {
+            t = r#type!($globals, link!($globals, $p));
+        }
         // if t=glue_node then pi:=0@+else goto update_heights;
+        if t == glue_node {
+            $pi = 0;
+        } else {
+            crate::goto_forward_label!($lbl_update_heights);
+        }
         // end;
-        todo!("kern_node");
     }
     // penalty_node: pi:=penalty(p);
     else if type_p == penalty_node {
@@ -48,6 +64,8 @@ pub(crate) macro Use_node_p_to_update_the_current_height_and_depth_measurements_
     }
     // endcases
     use crate::section_0095::confusion;
+    use crate::section_0115::null;
+    use crate::section_0118::link;
     use crate::section_0133::r#type;
     use crate::section_0135::depth;
     use crate::section_0135::height;
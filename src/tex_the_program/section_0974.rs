@@ -40,7 +40,7 @@ pub(crate) macro Check_if_node_p_is_a_new_champion_breakpoint__then_goto_done_if
             // end;
         }
         // if (b=awful_bad)or(pi<=eject_penalty) then goto done;
-        if b == awful_bad || $pi < eject_penalty {
+        if b == awful_bad || $pi <= eject_penalty {
             crate::goto_forward_label!($lbl_done);
         }
         // end
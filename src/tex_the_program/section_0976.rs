@@ -5,7 +5,7 @@
 // @<Update the current height and depth measurements with...@>=
 pub(crate) macro Update_the_current_height_and_depth_measurements_with_respect_to_a_glue_or_kern_node_p($globals:expr, $p:expr, $prev_dp:expr) {{
     /// glue specifications
-    let q;
+    let mut q;
     // if type(p)=kern_node then q:=p
     if r#type!($globals, $p) == kern_node {
         q = $p;
@@ -22,23 +22,44 @@ pub(crate) macro Update_the_current_height_and_depth_measurements_with_respect_t
         if shrink_order!($globals, q) != glue_ord::normal as _
             && shrink!($globals, q) != scaled::zero()
         {
+            /// glue specifications
+            let r;
             // begin@t@>@;@/
             // print_err("Infinite glue shrinkage found in box being split");@/
+            print_err!(
+                $globals,
+                crate::strpool_str!("Infinite glue shrinkage found in box being split")
+            );
             // @.Infinite glue shrinkage...@>
             // help4("The box you are \vsplitting contains some infinitely")@/
             //   ("shrinkable glue, e.g., `\vss' or `\vskip 0pt minus 1fil'.")@/
             //   ("Such glue doesn't belong there; but you can safely proceed,")@/
             //   ("since the offensive shrinkability has been made finite.");
+            help4!(
+                $globals,
+                crate::strpool_str!("The box you are \\vsplitting contains some infinitely"),
+                crate::strpool_str!("shrinkable glue, e.g., `\\vss' or `\\vskip 0pt minus 1fil'."),
+                crate::strpool_str!("Such glue doesn't belong there; but you can safely proceed,"),
+                crate::strpool_str!("since the offensive shrinkability has been made finite.")
+            );
             // error; r:=new_spec(q); shrink_order(r):=normal; delete_glue_ref(q);
+            error($globals)?;
+            r = new_spec($globals, q)?;
+            shrink_order!($globals, r) = glue_ord::normal as _;
+            delete_glue_ref($globals, q);
             // glue_ptr(p):=r; q:=r;
+            glue_ptr!($globals, $p) = r;
+            q = r;
             // end;
-            todo!("report error");
         }
         // end;
     }
     // cur_height:=cur_height+prev_dp+width(q); prev_dp:=0
     cur_height!($globals) += $prev_dp + width!($globals, q);
     $prev_dp = scaled::zero();
+    use crate::section_0073::print_err;
+    use crate::section_0079::help4;
+    use crate::section_0082::error;
     use crate::section_0101::scaled;
     use crate::section_0133::r#type;
     use crate::section_0135::width;
@@ -48,7 +69,9 @@ pub(crate) macro Update_the_current_height_and_depth_measurements_with_respect_t
     use crate::section_0150::shrink_order;
     use crate::section_0150::stretch;
     use crate::section_0150::stretch_order;
+    use crate::section_0151::new_spec;
     use crate::section_0155::kern_node;
+    use crate::section_0201::delete_glue_ref;
     use crate::section_0970::active_height;
     use crate::section_0970::cur_height;
 }}
@@ -17,7 +17,13 @@
 //   {extracts a page of height |h| from box |n|}
 /// extracts a page of height `h` from box `n`
 #[allow(unused_variables, unused_assignments)]
-pub(crate) fn vsplit(globals: &mut TeXGlobals, n: eight_bits, h: scaled) -> TeXResult<pointer> {
+pub(crate) fn vsplit(
+    globals: &mut TeXGlobals,
+    #[cfg(not(feature = "latex_support"))] n: eight_bits,
+    // TeX-rs: `latex_support` has box registers up to 32767
+    #[cfg(feature = "latex_support")] n: halfword,
+    h: scaled,
+) -> TeXResult<pointer> {
     // label exit,done;
     // var v:pointer; {the box to be split}
     /// the box to be split
@@ -29,13 +35,23 @@ pub(crate) fn vsplit(globals: &mut TeXGlobals, n: eight_bits, h: scaled) -> TeXR
     /// points to where the break occurs
     let mut q;
     // begin v:=box(n);
-    v = r#box!(globals, n);
+    #[cfg(feature = "latex_support")]
+    {
+        v = crate::latex_support::fetch_box(globals, n as integer)?;
+    }
+    #[cfg(not(feature = "latex_support"))]
+    {
+        v = r#box!(globals, n);
+    }
     // if split_first_mark<>null then
     if split_first_mark!(globals) != null {
         // begin delete_token_ref(split_first_mark); split_first_mark:=null;
+        delete_token_ref(globals, split_first_mark!(globals));
+        split_first_mark!(globals) = null;
         // delete_token_ref(split_bot_mark); split_bot_mark:=null;
+        delete_token_ref(globals, split_bot_mark!(globals));
+        split_bot_mark!(globals) = null;
         // end;
-        todo!("split_first_mark != null");
     }
     // @<Dispense with trivial cases of void or bad boxes@>;
     crate::section_0978::Dispense_with_trivial_cases_of_void_or_bad_boxes!(globals, v);
@@ -51,14 +67,17 @@ pub(crate) fn vsplit(globals: &mut TeXGlobals, n: eight_bits, h: scaled) -> TeXR
     p = list_ptr!(globals, v);
     free_node(globals, v, box_node_size as _);
     // if q=null then box(n):=null {the |eq_level| of the box stays the same}
-    if q == null {
-        r#box!(globals, n) = null;
-        /// the `eq_level` of the box stays the same
-        const _: () = ();
-    }
     // else box(n):=vpack(q,natural);
-    else {
-        r#box!(globals, n) = vpack(globals, q, natural0!(), natural1!())?;
+    // TeX-rs: the new content of box `n` is computed first, so that
+    // `latex_support` can store it in a sparse array element
+    if q != null {
+        q = vpack(globals, q, natural0!(), natural1!())?;
+    }
+    #[cfg(feature = "latex_support")]
+    crate::latex_support::change_box(globals, n as integer, q)?;
+    #[cfg(not(feature = "latex_support"))]
+    {
+        r#box!(globals, n) = q;
     }
     // vsplit:=vpackage(p,h,exactly,split_max_depth);
     let vsplit = vpackage(globals, p, h, exactly.into(), split_max_depth!(globals))?;
@@ -66,18 +85,26 @@ pub(crate) fn vsplit(globals: &mut TeXGlobals, n: eight_bits, h: scaled) -> TeXR
     crate::ok_nojump!(vsplit)
 }
 
+#[cfg(feature = "latex_support")]
+use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0025::eight_bits;
 use crate::section_0081::TeXResult;
 use crate::section_0101::scaled;
+#[cfg(feature = "latex_support")]
+use crate::section_0113::halfword;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
 use crate::section_0130::free_node;
 use crate::section_0135::box_node_size;
 use crate::section_0135::list_ptr;
 use crate::section_0137::vlist_node;
+use crate::section_0200::delete_token_ref;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0230::r#box;
 use crate::section_0247::split_max_depth;
+use crate::section_0382::split_bot_mark;
 use crate::section_0382::split_first_mark;
 use crate::section_0644::exactly;
 use crate::section_0644::natural0;
@@ -10,14 +10,29 @@ pub(crate) macro Dispense_with_trivial_cases_of_void_or_bad_boxes($globals:expr,
     // if type(v)<>vlist_node then
     if r#type!($globals, $v) != vlist_node {
         // begin print_err(""); print_esc("vsplit"); print(" needs a ");
+        print_err!($globals, crate::strpool_str!(""));
+        print_esc($globals, crate::strpool_str!("vsplit"));
+        print($globals, crate::strpool_str!(" needs a ").get() as _);
         // print_esc("vbox");
+        print_esc($globals, crate::strpool_str!("vbox"));
         // @:vsplit_}{\.{\\vsplit needs a \\vbox}@>
         // help2("The box you are trying to split is an \hbox.")@/
         // ("I can't split such a box, so I'll leave it alone.");
+        help2!(
+            $globals,
+            crate::strpool_str!("The box you are trying to split is an \\hbox."),
+            crate::strpool_str!("I can't split such a box, so I'll leave it alone.")
+        );
         // error; vsplit:=null; return;
+        error($globals)?;
+        crate::return_nojump!(null);
         // end
-        todo!("err");
     }
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
     use crate::section_0115::null;
     use crate::section_0133::r#type;
     use crate::section_0137::vlist_node;
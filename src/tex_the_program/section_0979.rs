@@ -17,16 +17,27 @@ pub(crate) macro Look_at_all_the_marks_in_nodes_before_the_break__and_set_the_fi
                 loop {
                     if r#type!($globals, $p) == mark_node {
                         // if split_first_mark=null then
-                        //   begin split_first_mark:=mark_ptr(p);
-                        //   split_bot_mark:=split_first_mark;
-                        //   token_ref_count(split_first_mark):=@|
-                        //     token_ref_count(split_first_mark)+2;
-                        //   end
+                        if split_first_mark!($globals) == null {
+                            // begin split_first_mark:=mark_ptr(p);
+                            split_first_mark!($globals) = mark_ptr!($globals, $p) as _;
+                            // split_bot_mark:=split_first_mark;
+                            split_bot_mark!($globals) = split_first_mark!($globals);
+                            // token_ref_count(split_first_mark):=@|
+                            //   token_ref_count(split_first_mark)+2;
+                            let split_first_mark = split_first_mark!($globals);
+                            token_ref_count!($globals, split_first_mark) += 2;
+                            // end
+                        }
                         // else  begin delete_token_ref(split_bot_mark);
-                        //   split_bot_mark:=mark_ptr(p);
-                        //   add_token_ref(split_bot_mark);
-                        //   end;
-                        todo!("mark_node");
+                        else {
+                            delete_token_ref($globals, split_bot_mark!($globals));
+                            // split_bot_mark:=mark_ptr(p);
+                            split_bot_mark!($globals) = mark_ptr!($globals, $p) as _;
+                            // add_token_ref(split_bot_mark);
+                            let split_bot_mark = split_bot_mark!($globals);
+                            add_token_ref!($globals, split_bot_mark);
+                            // end;
+                        }
                     }
                     // if link(p)=q then
                     if link!($globals, $p) == $q {
@@ -49,4 +60,10 @@ pub(crate) macro Look_at_all_the_marks_in_nodes_before_the_break__and_set_the_fi
     use crate::section_0133::r#type;
     use crate::section_0135::list_ptr;
     use crate::section_0141::mark_node;
+    use crate::section_0141::mark_ptr;
+    use crate::section_0200::delete_token_ref;
+    use crate::section_0200::token_ref_count;
+    use crate::section_0203::add_token_ref;
+    use crate::section_0382::split_bot_mark;
+    use crate::section_0382::split_first_mark;
 }}
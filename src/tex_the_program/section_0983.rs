@@ -5,10 +5,10 @@
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0983($globals:expr) {{
     let globals = &mut *$globals;
     // primitive("pagegoal",set_page_dimen,0);
-    primitive(globals, crate::strpool_str!("pagegoal"), set_page_dimen, 0);
+    primitive(globals, crate::strpool_str!("pagegoal"), set_page_dimen, 0)?;
     // @!@:page_goal_}{\.{\\pagegoal} primitive@>
     // primitive("pagetotal",set_page_dimen,1);
-    primitive(globals, crate::strpool_str!("pagetotal"), set_page_dimen, 1);
+    primitive(globals, crate::strpool_str!("pagetotal"), set_page_dimen, 1)?;
     // @!@:page_total_}{\.{\\pagetotal} primitive@>
     // primitive("pagestretch",set_page_dimen,2);
     primitive(
@@ -16,7 +16,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0983($globals
         crate::strpool_str!("pagestretch"),
         set_page_dimen,
         2,
-    );
+    )?;
     // @!@:page_stretch_}{\.{\\pagestretch} primitive@>
     // primitive("pagefilstretch",set_page_dimen,3);
     primitive(
@@ -24,7 +24,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0983($globals
         crate::strpool_str!("pagefilstretch"),
         set_page_dimen,
         3,
-    );
+    )?;
     // @!@:page_fil_stretch_}{\.{\\pagefilstretch} primitive@>
     // primitive("pagefillstretch",set_page_dimen,4);
     primitive(
@@ -32,7 +32,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0983($globals
         crate::strpool_str!("pagefillstretch"),
         set_page_dimen,
         4,
-    );
+    )?;
     // @!@:page_fill_stretch_}{\.{\\pagefillstretch} primitive@>
     // primitive("pagefilllstretch",set_page_dimen,5);
     primitive(
@@ -40,7 +40,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0983($globals
         crate::strpool_str!("pagefilllstretch"),
         set_page_dimen,
         5,
-    );
+    )?;
     // @!@:page_filll_stretch_}{\.{\\pagefilllstretch} primitive@>
     // primitive("pageshrink",set_page_dimen,6);
     primitive(
@@ -48,10 +48,10 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_0983($globals
         crate::strpool_str!("pageshrink"),
         set_page_dimen,
         6,
-    );
+    )?;
     // @!@:page_shrink_}{\.{\\pageshrink} primitive@>
     // primitive("pagedepth",set_page_dimen,7);
-    primitive(globals, crate::strpool_str!("pagedepth"), set_page_dimen, 7);
+    primitive(globals, crate::strpool_str!("pagedepth"), set_page_dimen, 7)?;
     // @!@:page_depth_}{\.{\\pagedepth} primitive@>
 }}
 
@@ -2,12 +2,36 @@
 //! or an insertion box is supposed to be ready to accept a vertical list.
 //! If not, an error message is printed, and the following subroutine
 //! flushes the unwanted contents, reporting them to the user.
-//!
-//! @p procedure box_error(@!n:eight_bits);
-//! begin error; begin_diagnostic;
-//! print_nl("The following box has been deleted:");
-//! @.The following...deleted@>
-//! show_box(box(n)); end_diagnostic(true);
-//! flush_node_list(box(n)); box(n):=null;
-//! end;
-//!
+//
+// @p procedure box_error(@!n:eight_bits);
+pub(crate) fn box_error(globals: &mut TeXGlobals, n: eight_bits) -> TeXResult<()> {
+    // begin error; begin_diagnostic;
+    error(globals)?;
+    begin_diagnostic(globals);
+    // print_nl("The following box has been deleted:");
+    print_nl(
+        globals,
+        crate::strpool_str!("The following box has been deleted:"),
+    );
+    // @.The following...deleted@>
+    // show_box(box(n)); end_diagnostic(true);
+    show_box(globals, r#box!(globals, n));
+    end_diagnostic(globals, true);
+    // flush_node_list(box(n)); box(n):=null;
+    flush_node_list(globals, r#box!(globals, n))?;
+    r#box!(globals, n) = null;
+    // end;
+    crate::ok_nojump!()
+}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0025::eight_bits;
+use crate::section_0062::print_nl;
+use crate::section_0081::TeXResult;
+use crate::section_0082::error;
+use crate::section_0115::null;
+use crate::section_0198::show_box;
+use crate::section_0202::flush_node_list;
+use crate::section_0230::r#box;
+use crate::section_0245::begin_diagnostic;
+use crate::section_0245::end_diagnostic;
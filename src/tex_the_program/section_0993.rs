@@ -11,13 +11,23 @@ pub(crate) fn ensure_vbox(globals: &mut TeXGlobals, n: eight_bits) -> TeXResult<
     // if p<>null then if type(p)=hlist_node then
     if p != null && r#type!(globals, p) == hlist_node {
         // begin print_err("Insertions can only be added to a vbox");
+        print_err!(
+            globals,
+            crate::strpool_str!("Insertions can only be added to a vbox")
+        );
         // @.Insertions can only...@>
         // help3("Tut tut: You're trying to \insert into a")@/
         //   ("\box register that now contains an \hbox.")@/
         //   ("Proceed, and I'll discard its present contents.");
+        help3!(
+            globals,
+            crate::strpool_str!("Tut tut: You're trying to \\insert into a"),
+            crate::strpool_str!("\\box register that now contains an \\hbox."),
+            crate::strpool_str!("Proceed, and I'll discard its present contents.")
+        );
         // box_error(n);
+        box_error(globals, n)?;
         // end;
-        todo!("box error");
     }
     // end;
     crate::ok_nojump!()
@@ -25,9 +35,12 @@ pub(crate) fn ensure_vbox(globals: &mut TeXGlobals, n: eight_bits) -> TeXResult<
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0025::eight_bits;
+use crate::section_0073::print_err;
+use crate::section_0079::help3;
 use crate::section_0081::TeXResult;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
 use crate::section_0133::r#type;
 use crate::section_0135::hlist_node;
 use crate::section_0230::r#box;
+use crate::section_0992::box_error;
@@ -75,7 +75,7 @@ pub(crate) macro If_the_current_page_is_empty_and_node_p_is_to_be_deleted__goto_
     }
     // mark_node: goto contribute;
     else if type_p == mark_node {
-        todo!("move mark_node");
+        crate::goto_forward_label!($lbl_contribute);
     }
     // ins_node: @<Append an insertion to the current page and |goto contribute|@>;
     else if type_p == ins_node {
@@ -3,7 +3,7 @@
 // @<Update the current page measurements with respect to the glue...@>=
 pub(crate) macro Update_the_current_page_measurements_with_respect_to_the_glue_or_kern_specified_by_node_p($globals:expr, $p:expr) {{
     /// nodes being examined
-    let q: pointer;
+    let mut q: pointer;
     // if type(p)=kern_node then q:=p
     if r#type!($globals, $p) == kern_node {
         q = $p;
@@ -20,17 +20,35 @@ pub(crate) macro Update_the_current_page_measurements_with_respect_to_the_glue_o
         if shrink_order!($globals, q) as integer != glue_ord::normal as integer
             && shrink!($globals, q) != scaled::zero()
         {
-            todo!("infinite shrinkage");
+            /// nodes being examined
+            let r: pointer;
             // begin@t@>@;@/
             // print_err("Infinite glue shrinkage found on current page");@/
+            print_err!(
+                $globals,
+                crate::strpool_str!("Infinite glue shrinkage found on current page")
+            );
             // @.Infinite glue shrinkage...@>
             // help4("The page about to be output contains some infinitely")@/
             //   ("shrinkable glue, e.g., `\vss' or `\vskip 0pt minus 1fil'.")@/
             //   ("Such glue doesn't belong there; but you can safely proceed,")@/
             //   ("since the offensive shrinkability has been made finite.");
+            help4!(
+                $globals,
+                crate::strpool_str!("The page about to be output contains some infinitely"),
+                crate::strpool_str!("shrinkable glue, e.g., `\\vss' or `\\vskip 0pt minus 1fil'."),
+                crate::strpool_str!("Such glue doesn't belong there; but you can safely proceed,"),
+                crate::strpool_str!("since the offensive shrinkability has been made finite.")
+            );
             // error;
+            error($globals)?;
             // r:=new_spec(q); shrink_order(r):=normal; delete_glue_ref(q);
+            r = new_spec($globals, q)?;
+            shrink_order!($globals, r) = glue_ord::normal as _;
+            delete_glue_ref($globals, q);
             // glue_ptr(p):=r; q:=r;
+            glue_ptr!($globals, $p) = r;
+            q = r;
             // end;
         }
         // end;
@@ -39,6 +57,9 @@ pub(crate) macro Update_the_current_page_measurements_with_respect_to_the_glue_o
     page_total!($globals) += page_depth!($globals) + width!($globals, q);
     page_depth!($globals) = scaled::zero();
     use crate::pascal::integer;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help4;
+    use crate::section_0082::error;
     use crate::section_0101::scaled;
     use crate::section_0115::pointer;
     use crate::section_0133::r#type;
@@ -49,7 +70,9 @@ pub(crate) macro Update_the_current_page_measurements_with_respect_to_the_glue_o
     use crate::section_0150::shrink_order;
     use crate::section_0150::stretch;
     use crate::section_0150::stretch_order;
+    use crate::section_0151::new_spec;
     use crate::section_0155::kern_node;
+    use crate::section_0201::delete_glue_ref;
     use crate::section_0982::page_depth;
     use crate::section_0982::page_shrink;
     use crate::section_0982::page_total;
@@ -51,17 +51,34 @@ pub(crate) macro Create_a_page_insertion_node_with_subtype_r_eq_qi_n__and_includ
     // if (shrink_order(q)<>normal)and(shrink(q)<>0) then
     if shrink_order!($globals, q) != glue_ord::normal as _ && shrink!($globals, q) != scaled::zero()
     {
-        todo!("Report error");
         // begin print_err("Infinite glue shrinkage inserted from "); print_esc("skip");
+        print_err!(
+            $globals,
+            crate::strpool_str!("Infinite glue shrinkage inserted from ")
+        );
+        print_esc($globals, crate::strpool_str!("skip"));
         // @.Infinite glue shrinkage...@>
         // print_int(n);
+        print_int($globals, $n as _);
         // help3("The correction glue for page breaking with insertions")@/
         //   ("must have finite shrinkability. But you may proceed,")@/
         //   ("since the offensive shrinkability has been made finite.");
+        help3!(
+            $globals,
+            crate::strpool_str!("The correction glue for page breaking with insertions"),
+            crate::strpool_str!("must have finite shrinkability. But you may proceed,"),
+            crate::strpool_str!("since the offensive shrinkability has been made finite.")
+        );
         // error;
+        error($globals)?;
         // end;
     }
     // end
+    use crate::section_0063::print_esc;
+    use crate::section_0065::print_int;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
+    use crate::section_0082::error;
     use crate::section_0101::scaled;
     use crate::section_0106::x_over_n;
     use crate::section_0112::qi;
@@ -38,10 +38,15 @@ pub(crate) macro Find_the_best_way_to_split_the_insertion__and_change_type_r_to_
     // @!stat if tracing_pages>0 then @<Display the insertion split cost@>;@+tats@;@/
     crate::region_stat! {
         if tracing_pages!($globals) > 0 {
-            todo!("Display the insertion...");
+            crate::section_1011::Display_the_insertion_split_cost!($globals, $n, w, q);
         }
         use crate::section_0236::tracing_pages;
     }
+    crate::region_non_stat! {
+        crate::submit_strpool_str!("% split");
+        crate::submit_strpool_str!(" to ");
+        crate::submit_strpool_str!(" p=");
+    }
     // if count(n)<>1000 then
     if count!($globals, $n) != 1000 {
         // best_height_plus_depth:=x_over_n(best_height_plus_depth,1000)*count(n);
@@ -1,12 +1,53 @@
-//! @ @<Display the insertion split cost@>=
-//! begin begin_diagnostic; print_nl("% split"); print_int(n);
-//! @.split@>
-//! print(" to "); print_scaled(w);
-//! print_char(","); print_scaled(best_height_plus_depth);@/
-//! print(" p=");
-//! if q=null then print_int(eject_penalty)
-//! else if type(q)=penalty_node then print_int(penalty(q))
-//! else print_char("0");
-//! end_diagnostic(false);
-//! end
-//!
+//! ` `
+// @<Display the insertion split cost@>=
+pub(crate) macro Display_the_insertion_split_cost($globals:expr, $n:expr, $w:expr, $q:expr) {{
+    // begin begin_diagnostic; print_nl("% split"); print_int(n);
+    begin_diagnostic($globals);
+    print_nl($globals, crate::strpool_str!("% split"));
+    print_int($globals, $n as _);
+    // @.split@>
+    // print(" to "); print_scaled(w);
+    print($globals, crate::strpool_str!(" to ").get() as _);
+    print_scaled($globals, $w);
+    // print_char(","); print_scaled(best_height_plus_depth);@/
+    print_char(
+        make_globals_io_string_log_view!($globals),
+        ASCII_code_literal!(b','),
+    );
+    print_scaled($globals, $globals.best_height_plus_depth);
+    // print(" p=");
+    print($globals, crate::strpool_str!(" p=").get() as _);
+    // if q=null then print_int(eject_penalty)
+    if $q == null {
+        print_int($globals, eject_penalty);
+    }
+    // else if type(q)=penalty_node then print_int(penalty(q))
+    else if r#type!($globals, $q) == penalty_node {
+        print_int($globals, penalty!($globals, $q));
+    }
+    // else print_char("0");
+    else {
+        print_char(
+            make_globals_io_string_log_view!($globals),
+            ASCII_code_literal!(b'0'),
+        );
+    }
+    // end_diagnostic(false);
+    end_diagnostic($globals, false);
+    // end
+    use crate::section_0004::make_globals_io_string_log_view;
+    use crate::section_0004::TeXGlobalsIoStringLogView;
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0058::print_char;
+    use crate::section_0059::print;
+    use crate::section_0062::print_nl;
+    use crate::section_0065::print_int;
+    use crate::section_0103::print_scaled;
+    use crate::section_0115::null;
+    use crate::section_0133::r#type;
+    use crate::section_0157::eject_penalty;
+    use crate::section_0157::penalty;
+    use crate::section_0157::penalty_node;
+    use crate::section_0245::begin_diagnostic;
+    use crate::section_0245::end_diagnostic;
+}}
@@ -30,10 +30,16 @@ pub(crate) fn fire_up(globals: &mut TeXGlobals, c: pointer) -> TeXResult<()> {
     // if bot_mark<>null then
     if bot_mark!(globals) != null {
         // begin if top_mark<>null then delete_token_ref(top_mark);
+        if top_mark!(globals) != null {
+            delete_token_ref(globals, top_mark!(globals));
+        }
         // top_mark:=bot_mark; add_token_ref(top_mark);
+        top_mark!(globals) = bot_mark!(globals);
+        add_token_ref!(globals, top_mark!(globals));
         // delete_token_ref(first_mark); first_mark:=null;
+        delete_token_ref(globals, first_mark!(globals));
+        first_mark!(globals) = null;
         // end;
-        todo!("bot_mark");
     }
     // @<Put the \(o)optimal current page into box 255, update |first_mark| and
     //   |bot_mark|, append insertions to their boxes, and put the
@@ -53,16 +59,15 @@ pub(crate) fn fire_up(globals: &mut TeXGlobals, c: pointer) -> TeXResult<()> {
         // if dead_cycles>=max_dead_cycles then
         if globals.dead_cycles >= max_dead_cycles!(globals) {
             // @<Explain that too many dead cycles have occurred in a row@>
-            todo!("explain");
+            crate::section_1024::Explain_that_too_many_dead_cycles_have_occurred_in_a_row!(globals);
         }
         // else @<Fire up the user's output routine and |return|@>;
         else {
             crate::section_1025::Fire_up_the_user_s_output_routine_and_return!(globals);
         }
-    } else {
-        // @<Perform the default output routine@>;
-        todo!("perform default output");
     }
+    // @<Perform the default output routine@>;
+    crate::section_1023::Perform_the_default_output_routine!(globals);
     // exit:end;
     crate::ok_nojump!()
 }
@@ -71,6 +76,7 @@ use crate::section_0004::TeXGlobals;
 use crate::section_0081::TeXResult;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
+use crate::section_0200::delete_token_ref;
 use crate::section_0203::add_token_ref;
 use crate::section_0230::output_routine;
 use crate::section_0236::max_dead_cycles;
@@ -52,7 +52,7 @@ pub(crate) macro Put_the_optimal_current_page_into_box_255__update_first_mark_an
             // else if type(p)=mark_node then @<Update the values of
             //   |first_mark| and |bot_mark|@>;
             else if r#type!($globals, p) == mark_node {
-                todo!("mark node prior of page break");
+                crate::section_1016::Update_the_values_of_first_mark_and_bot_mark!($globals, p);
             }
             // prev_p:=p; p:=link(prev_p);
             prev_p = p;
@@ -4,13 +4,26 @@ pub(crate) macro Ensure_that_box_255_is_empty_before_output($globals:expr) {{
     // if box(255)<>null then
     if r#box!($globals, 255) != null {
         // begin print_err(""); print_esc("box"); print("255 is not void");
+        print_err!($globals, crate::strpool_str!(""));
+        print_esc($globals, crate::strpool_str!("box"));
+        print($globals, crate::strpool_str!("255 is not void").get() as _);
         // @:box255}{\.{\\box255 is not void}@>
         // help2("You shouldn't use \box255 except in \output routines.")@/
         //   ("Proceed, and I'll discard its present contents.");
+        help2!(
+            $globals,
+            crate::strpool_str!("You shouldn't use \\box255 except in \\output routines."),
+            crate::strpool_str!("Proceed, and I'll discard its present contents.")
+        );
         // box_error(255);
+        box_error($globals, 255)?;
         // end
-        todo!("box error");
     }
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
     use crate::section_0115::null;
     use crate::section_0230::r#box;
+    use crate::section_0992::box_error;
 }}
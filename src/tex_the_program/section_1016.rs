@@ -1,9 +1,28 @@
-//! @ @<Update the values of |first_mark| and |bot_mark|@>=
-//! begin if first_mark=null then
-//!   begin first_mark:=mark_ptr(p);
-//!   add_token_ref(first_mark);
-//!   end;
-//! if bot_mark<>null then delete_token_ref(bot_mark);
-//! bot_mark:=mark_ptr(p); add_token_ref(bot_mark);
-//! end
-//!
+//! ` `
+// @<Update the values of |first_mark| and |bot_mark|@>=
+pub(crate) macro Update_the_values_of_first_mark_and_bot_mark($globals:expr, $p:expr) {{
+    // begin if first_mark=null then
+    if first_mark!($globals) == null {
+        // begin first_mark:=mark_ptr(p);
+        first_mark!($globals) = mark_ptr!($globals, $p) as _;
+        // add_token_ref(first_mark);
+        let first_mark = first_mark!($globals);
+        add_token_ref!($globals, first_mark);
+        // end;
+    }
+    // if bot_mark<>null then delete_token_ref(bot_mark);
+    if bot_mark!($globals) != null {
+        delete_token_ref($globals, bot_mark!($globals));
+    }
+    // bot_mark:=mark_ptr(p); add_token_ref(bot_mark);
+    bot_mark!($globals) = mark_ptr!($globals, $p) as _;
+    let bot_mark = bot_mark!($globals);
+    add_token_ref!($globals, bot_mark);
+    // end
+    use crate::section_0115::null;
+    use crate::section_0141::mark_ptr;
+    use crate::section_0200::delete_token_ref;
+    use crate::section_0203::add_token_ref;
+    use crate::section_0382::bot_mark;
+    use crate::section_0382::first_mark;
+}}
@@ -0,0 +1,41 @@
+//! @ The list of heldover insertions, running from |link(page_head)| to
+//! |page_tail|, must be moved to the contribution list when the user has
+//! specified no output routine.
+//
+// @<Perform the default output routine@>=
+pub(crate) macro Perform_the_default_output_routine($globals:expr) {{
+    // begin if link(page_head)<>null then
+    if link!($globals, page_head) != null {
+        // begin if link(contrib_head)=null then
+        if link!($globals, contrib_head) == null {
+            // if nest_ptr=0 then tail:=page_tail@+else contrib_tail:=page_tail
+            if $globals.nest_ptr == 0 {
+                tail!($globals) = $globals.page_tail;
+            } else {
+                contrib_tail!($globals) = $globals.page_tail;
+            }
+        }
+        // else link(page_tail):=link(contrib_head);
+        else {
+            link!($globals, $globals.page_tail) = link!($globals, contrib_head);
+        }
+        // link(contrib_head):=link(page_head);
+        link!($globals, contrib_head) = link!($globals, page_head);
+        // link(page_head):=null; page_tail:=page_head;
+        link!($globals, page_head) = null;
+        $globals.page_tail = page_head;
+        // end;
+    }
+    // ship_out(box(255)); box(255):=null;
+    ship_out($globals, r#box!($globals, 255))?;
+    r#box!($globals, 255) = null;
+    // end
+    use crate::section_0115::null;
+    use crate::section_0118::link;
+    use crate::section_0162::contrib_head;
+    use crate::section_0162::page_head;
+    use crate::section_0213::tail;
+    use crate::section_0230::r#box;
+    use crate::section_0638::ship_out;
+    use crate::section_0995::contrib_tail;
+}}
@@ -0,0 +1,29 @@
+//! ` `
+// @<Explain that too many dead cycles have occurred in a row@>=
+pub(crate) macro Explain_that_too_many_dead_cycles_have_occurred_in_a_row($globals:expr) {{
+    // begin print_err("Output loop---"); print_int(dead_cycles);
+    print_err!($globals, crate::strpool_str!("Output loop---"));
+    print_int($globals, $globals.dead_cycles);
+    // @.Output loop...@>
+    // print(" consecutive dead cycles");
+    print(
+        $globals,
+        crate::strpool_str!(" consecutive dead cycles").get() as _,
+    );
+    // help3("I've concluded that your \output is awry; it never does a")@/
+    // ("\shipout, so I'm shipping \box255 out myself. Next time")@/
+    // ("increase \maxdeadcycles if you want me to be more patient!"); error;
+    help3!(
+        $globals,
+        crate::strpool_str!("I've concluded that your \\output is awry; it never does a"),
+        crate::strpool_str!("\\shipout, so I'm shipping \\box255 out myself. Next time"),
+        crate::strpool_str!("increase \\maxdeadcycles if you want me to be more patient!")
+    );
+    error($globals)?;
+    // end
+    use crate::section_0059::print;
+    use crate::section_0065::print_int;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
+    use crate::section_0082::error;
+}}
@@ -6,14 +6,14 @@ pub(crate) macro Fire_up_the_user_s_output_routine_and_return($globals:expr) {{
     // incr(dead_cycles);
     incr!($globals.dead_cycles);
     // push_nest; mode:=-vmode; prev_depth:=ignore_depth; mode_line:=-line;
-    push_nest($globals);
+    push_nest($globals)?;
     mode!($globals) = (-vmode).into();
     prev_depth!($globals) = ignore_depth;
     mode_line!($globals) = -$globals.line;
     // begin_token_list(output_routine,output_text);
     begin_token_list($globals, output_routine!($globals), output_text);
     // new_save_level(output_group); normal_paragraph;
-    new_save_level($globals, output_group.into());
+    new_save_level($globals, output_group.into())?;
     normal_paragraph($globals)?;
     // scan_left_brace;
     scan_left_brace($globals)?;
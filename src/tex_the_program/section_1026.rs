@@ -9,12 +9,12 @@ pub(crate) macro Resume_the_page_builder_after_an_output_routine_has_come_to_an_
         || (token_type!($globals) != output_text && token_type!($globals) != backed_up)
     {
         // @<Recover from an unbalanced output routine@>;
-        todo!("recover");
+        crate::section_1027::Recover_from_an_unbalanced_output_routine!($globals);
     }
     // end_token_list; {conserve stack space in case more outputs are triggered}
     /// conserve stack space in case more outputs are triggered
     const _: () = ();
-    end_token_list($globals);
+    end_token_list($globals)?;
     // end_graf; unsave; output_active:=false; insert_penalties:=0;@/
     end_graf($globals)?;
     unsave($globals)?;
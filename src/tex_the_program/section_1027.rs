@@ -1,9 +1,30 @@
-//! @ @<Recover from an unbalanced output routine@>=
-//! begin print_err("Unbalanced output routine");
-//! @.Unbalanced output routine@>
-//! help2("Your sneaky output routine has problematic {'s and/or }'s.")@/
-//! ("I can't handle that very well; good luck."); error;
-//! repeat get_token;
-//! until loc=null;
-//! end {loops forever if reading from a file, since |null=min_halfword<=0|}
-//!
+//! ` `
+// @<Recover from an unbalanced output routine@>=
+pub(crate) macro Recover_from_an_unbalanced_output_routine($globals:expr) {{
+    // begin print_err("Unbalanced output routine");
+    print_err!($globals, crate::strpool_str!("Unbalanced output routine"));
+    // @.Unbalanced output routine@>
+    // help2("Your sneaky output routine has problematic {'s and/or }'s.")@/
+    // ("I can't handle that very well; good luck."); error;
+    help2!(
+        $globals,
+        crate::strpool_str!("Your sneaky output routine has problematic {'s and/or }'s."),
+        crate::strpool_str!("I can't handle that very well; good luck.")
+    );
+    error($globals)?;
+    // repeat get_token;
+    // until loc=null;
+    loop {
+        get_token($globals)?;
+        if loc!($globals) == null {
+            break;
+        }
+    }
+    // end {loops forever if reading from a file, since |null=min_halfword<=0|}
+    use crate::section_0036::loc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+    use crate::section_0115::null;
+    use crate::section_0365::get_token;
+}}
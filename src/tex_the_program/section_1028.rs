@@ -4,15 +4,32 @@ pub(crate) macro Ensure_that_box_255_is_empty_after_output($globals:expr) {{
     // if box(255)<>null then
     if r#box!($globals, 255) != null {
         // begin print_err("Output routine didn't use all of ");
+        print_err!(
+            $globals,
+            crate::strpool_str!("Output routine didn't use all of ")
+        );
         // print_esc("box"); print_int(255);
+        print_esc($globals, crate::strpool_str!("box"));
+        print_int($globals, 255);
         // @.Output routine didn't use...@>
         // help3("Your \output commands should empty \box255,")@/
         //   ("e.g., by saying `\shipout\box255'.")@/
         //   ("Proceed; I'll discard its present contents.");
+        help3!(
+            $globals,
+            crate::strpool_str!("Your \\output commands should empty \\box255,"),
+            crate::strpool_str!("e.g., by saying `\\shipout\\box255'."),
+            crate::strpool_str!("Proceed; I'll discard its present contents.")
+        );
         // box_error(255);
+        box_error($globals, 255)?;
         // end
-        todo!("box error");
     }
+    use crate::section_0063::print_esc;
+    use crate::section_0065::print_int;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
     use crate::section_0115::null;
     use crate::section_0230::r#box;
+    use crate::section_0992::box_error;
 }}
@@ -8,16 +8,18 @@ pub(crate) macro Give_diagnostic_information_if_requested($globals:expr, $big_sw
     if $globals.interrupt != 0 {
         if $globals.OK_to_interrupt {
             // begin back_input; check_interrupt; goto big_switch;
-            back_input($globals);
+            back_input($globals)?;
             check_interrupt!($globals);
             crate::goto_backward_label!($big_switch);
             // end;
         }
     }
+    // TeX-rs: a job that is out of time or has been cancelled stops here
+    crate::limits::check_limits($globals)?;
     // @!debug if panicking then check_mem(false);@+@;@+gubed
     crate::region_debug! {
         if $globals.panicking {
-            check_mem($globals, false);
+            check_mem($globals, false)?;
         }
 
         use crate::section_0167::check_mem;
@@ -50,7 +50,7 @@ pub(crate) macro Append_character_cur_chr_and_the_following_characters_if_any_to
         // adjust_space_factor;@/
         adjust_space_factor!($globals);
         // main_f:=cur_font;
-        $globals.main_f = cur_font!($globals).into();
+        $globals.main_f = (cur_font!($globals) as u16).into();
         // bchar:=font_bchar[main_f]; false_bchar:=font_false_bchar[main_f];
         $globals.bchar = $globals.font_bchar[$globals.main_f];
         $globals.false_bchar = $globals.font_false_bchar[$globals.main_f];
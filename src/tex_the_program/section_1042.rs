@@ -12,8 +12,10 @@ pub(crate) macro Find_the_glue_specification_main_p_for_text_spaces_in_the_curre
     if $globals.main_p == null {
         // begin main_p:=new_spec(zero_glue); main_k:=param_base[cur_font]+space_code;
         $globals.main_p = new_spec($globals, zero_glue)?;
-        $globals.main_k = font_index::new($globals.param_base[cur_font!($globals)] as _)
-            + font_index::new(space_code as _);
+        // TeX-rs: `param_base[null_font]` is negative, so add before converting
+        $globals.main_k = font_index::new(
+            ($globals.param_base[cur_font!($globals)] + space_code as integer) as _,
+        );
         // width(main_p):=font_info[main_k].sc; {that's |space(cur_font)|}
         width!($globals, $globals.main_p) = $globals.font_info[$globals.main_k][MEMORY_WORD_SC];
         /// that's `space(cur_font)`
@@ -33,6 +35,7 @@ pub(crate) macro Find_the_glue_specification_main_p_for_text_spaces_in_the_curre
         // end;
     }
     // end
+    use crate::pascal::integer;
     use crate::section_0101::MEMORY_WORD_SC;
     use crate::section_0115::null;
     use crate::section_0115::pointer;
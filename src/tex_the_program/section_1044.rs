@@ -4,13 +4,16 @@ pub(crate) macro Modify_the_glue_specification_in_main_p_according_to_the_space_
     // if space_factor>=2000 then width(main_p):=width(main_p)+extra_space(cur_font);
     if space_factor!($globals) >= 2000 {
         width!($globals, $globals.main_p) = width!($globals, $globals.main_p)
-            + extra_space!($globals, internal_font_number::new(cur_font!($globals)));
+            + extra_space!(
+                $globals,
+                internal_font_number::new(cur_font!($globals) as u16)
+            );
     }
     // stretch(main_p):=xn_over_d(stretch(main_p),space_factor,1000);
     stretch!($globals, $globals.main_p) = xn_over_d(
         $globals,
         stretch!($globals, $globals.main_p),
-        space_factor!($globals).into(),
+        space_factor!($globals) as _,
         1000,
     );
     // shrink(main_p):=xn_over_d(shrink(main_p),1000,space_factor)
@@ -18,7 +21,7 @@ pub(crate) macro Modify_the_glue_specification_in_main_p_according_to_the_space_
         $globals,
         shrink!($globals, $globals.main_p),
         1000,
-        space_factor!($globals).into(),
+        space_factor!($globals) as _,
     );
 
     use crate::section_0107::xn_over_d;
@@ -97,6 +97,10 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists($globals:expr,
             $globals,
             $abs_mode_plus_cur_cmd
         )
+        || crate::section_1134::Cases_of_main_control_that_build_boxes_and_lists_1134!(
+            $globals,
+            $abs_mode_plus_cur_cmd
+        )
         || crate::section_1137::Cases_of_main_control_that_build_boxes_and_lists_1137!(
             $globals,
             $abs_mode_plus_cur_cmd
@@ -4,7 +4,7 @@
 #[allow(unused_variables)]
 pub(crate) fn insert_dollar_sign(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin back_input; cur_tok:=math_shift_token+"$";
-    back_input(globals);
+    back_input(globals)?;
     globals.cur_tok = cur_tok_type::new(math_shift_token + b'$' as cur_tok_repr);
     // print_err("Missing $ inserted");
     print_err!(globals, crate::strpool_str!("Missing $ inserted"));
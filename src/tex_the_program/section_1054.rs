@@ -26,7 +26,7 @@ pub(crate) fn its_all_over(globals: &mut TeXGlobals) -> TeXResult<boolean> {
         }
         // back_input; {we will try to end again after ejecting residual material}
         /// we will try to end again after ejecting residual material
-        back_input(globals);
+        back_input(globals)?;
         // tail_append(new_null_box);
         tail_append!(globals, new_null_box(globals)?);
         // width(tail):=hsize;
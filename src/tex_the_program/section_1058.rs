@@ -26,37 +26,37 @@ pub(crate) const mskip_code: halfword = 5;
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1058($globals:expr) {{
     let globals = &mut *$globals;
     // primitive("hskip",hskip,skip_code);@/
-    primitive(globals, crate::strpool_str!("hskip"), hskip, skip_code);
+    primitive(globals, crate::strpool_str!("hskip"), hskip, skip_code)?;
     // @!@:hskip_}{\.{\\hskip} primitive@>
     // primitive("hfil",hskip,fil_code);
-    primitive(globals, crate::strpool_str!("hfil"), hskip, fil_code);
+    primitive(globals, crate::strpool_str!("hfil"), hskip, fil_code)?;
     // @!@:hfil_}{\.{\\hfil} primitive@>
     // primitive("hfill",hskip,fill_code);@/
-    primitive(globals, crate::strpool_str!("hfill"), hskip, fill_code);
+    primitive(globals, crate::strpool_str!("hfill"), hskip, fill_code)?;
     // @!@:hfill_}{\.{\\hfill} primitive@>
     // primitive("hss",hskip,ss_code);
-    primitive(globals, crate::strpool_str!("hss"), hskip, ss_code);
+    primitive(globals, crate::strpool_str!("hss"), hskip, ss_code)?;
     // @!@:hss_}{\.{\\hss} primitive@>
     // primitive("hfilneg",hskip,fil_neg_code);@/
-    primitive(globals, crate::strpool_str!("hfilneg"), hskip, fil_neg_code);
+    primitive(globals, crate::strpool_str!("hfilneg"), hskip, fil_neg_code)?;
     // @!@:hfil_neg_}{\.{\\hfilneg} primitive@>
     // primitive("vskip",vskip,skip_code);@/
-    primitive(globals, crate::strpool_str!("vskip"), vskip, skip_code);
+    primitive(globals, crate::strpool_str!("vskip"), vskip, skip_code)?;
     // @!@:vskip_}{\.{\\vskip} primitive@>
     // primitive("vfil",vskip,fil_code);
-    primitive(globals, crate::strpool_str!("vfil"), vskip, fil_code);
+    primitive(globals, crate::strpool_str!("vfil"), vskip, fil_code)?;
     // @!@:vfil_}{\.{\\vfil} primitive@>
     // primitive("vfill",vskip,fill_code);@/
-    primitive(globals, crate::strpool_str!("vfill"), vskip, fill_code);
+    primitive(globals, crate::strpool_str!("vfill"), vskip, fill_code)?;
     // @!@:vfill_}{\.{\\vfill} primitive@>
     // primitive("vss",vskip,ss_code);
-    primitive(globals, crate::strpool_str!("vss"), vskip, ss_code);
+    primitive(globals, crate::strpool_str!("vss"), vskip, ss_code)?;
     // @!@:vss_}{\.{\\vss} primitive@>
     // primitive("vfilneg",vskip,fil_neg_code);@/
-    primitive(globals, crate::strpool_str!("vfilneg"), vskip, fil_neg_code);
+    primitive(globals, crate::strpool_str!("vfilneg"), vskip, fil_neg_code)?;
     // @!@:vfil_neg_}{\.{\\vfilneg} primitive@>
     // primitive("mskip",mskip,mskip_code);@/
-    primitive(globals, crate::strpool_str!("mskip"), mskip, mskip_code);
+    primitive(globals, crate::strpool_str!("mskip"), mskip, mskip_code)?;
     // @!@:mskip_}{\.{\\mskip} primitive@>
     // primitive("kern",kern,explicit);
     primitive(
@@ -64,7 +64,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1058($globals
         crate::strpool_str!("kern"),
         kern,
         kern_node_subtype::explicit as _,
-    );
+    )?;
     // @!@:kern_}{\.{\\kern} primitive@>
     // primitive("mkern",mkern,mu_glue);@/
     primitive(
@@ -72,7 +72,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1058($globals
         crate::strpool_str!("mkern"),
         mkern,
         kern_node_subtype::mu_glue as _,
-    );
+    )?;
     // @!@:mkern_}{\.{\\mkern} primitive@>
 }}
 
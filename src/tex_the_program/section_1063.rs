@@ -10,14 +10,14 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1063($globals:
         $abs_mode_plus_cur_cmd,
         left_brace as u16
     ) {
-        new_save_level($globals, simple_group.into());
+        new_save_level($globals, simple_group.into())?;
         use crate::section_0269::simple_group;
         use crate::section_0274::new_save_level;
         true
     }
     // any_mode(begin_group): new_save_level(semi_simple_group);
     else if abs_mode_plus_cur_cmd_matches_any_mode!($abs_mode_plus_cur_cmd, begin_group as u16) {
-        new_save_level($globals, semi_simple_group.into());
+        new_save_level($globals, semi_simple_group.into())?;
         use crate::section_0269::semi_simple_group;
         use crate::section_0274::new_save_level;
         true
@@ -28,7 +28,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1063($globals:
         if $globals.cur_group == semi_simple_group {
             unsave($globals)?;
         } else {
-            off_save($globals);
+            off_save($globals)?;
         }
         use crate::section_0269::semi_simple_group;
         use crate::section_0281::unsave;
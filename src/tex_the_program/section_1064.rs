@@ -11,24 +11,61 @@
 // @<Declare act...@>=
 // procedure off_save;
 #[allow(unused_variables)]
-pub(crate) fn off_save(globals: &mut TeXGlobals) {
-    todo!("off_save")
+pub(crate) fn off_save(globals: &mut TeXGlobals) -> TeXResult<()> {
     // var p:pointer; {inserted token}
+    /// inserted token
+    let mut p: pointer;
     // begin if cur_group=bottom_level then
-    //   @<Drop current token and complain that it was unmatched@>
+    if globals.cur_group == bottom_level {
+        // @<Drop current token and complain that it was unmatched@>
+        crate::section_1066::Drop_current_token_and_complain_that_it_was_unmatched!(globals);
+    }
     // else  begin back_input; p:=get_avail; link(temp_head):=p;
-    //   print_err("Missing ");
-    //   @<Prepare to insert a token that matches |cur_group|,
-    //     and print what it is@>;
-    //   print(" inserted"); ins_list(link(temp_head));
-    //   help5("I've inserted something that you may have forgotten.")@/
-    //   ("(See the <inserted text> above.)")@/
-    //   ("With luck, this will get me unwedged. But if you")@/
-    //   ("really didn't forget anything, try typing `2' now; then")@/
-    //   ("my insertion and my current dilemma will both disappear.");
-    //   error;
-    //   end;
+    else {
+        back_input(globals)?;
+        p = get_avail(globals)?;
+        link!(globals, temp_head) = p;
+        // print_err("Missing ");
+        print_err!(globals, crate::strpool_str!("Missing "));
+        // @<Prepare to insert a token that matches |cur_group|,
+        //   and print what it is@>;
+        crate::section_1065::Prepare_to_insert_a_token_that_matches_cur_group__and_print_what_it_is!(
+            globals, p
+        );
+        // print(" inserted"); ins_list(link(temp_head));
+        print(globals, crate::strpool_str!(" inserted").get() as _);
+        ins_list!(globals, link!(globals, temp_head));
+        // help5("I've inserted something that you may have forgotten.")@/
+        // ("(See the <inserted text> above.)")@/
+        // ("With luck, this will get me unwedged. But if you")@/
+        // ("really didn't forget anything, try typing `2' now; then")@/
+        // ("my insertion and my current dilemma will both disappear.");
+        help5!(
+            globals,
+            crate::strpool_str!("I've inserted something that you may have forgotten."),
+            crate::strpool_str!("(See the <inserted text> above.)"),
+            crate::strpool_str!("With luck, this will get me unwedged. But if you"),
+            crate::strpool_str!("really didn't forget anything, try typing `2' now; then"),
+            crate::strpool_str!("my insertion and my current dilemma will both disappear.")
+        );
+        // error;
+        error(globals)?;
+        // end;
+    }
     // end;
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0059::print;
+use crate::section_0073::print_err;
+use crate::section_0079::help5;
+use crate::section_0081::TeXResult;
+use crate::section_0082::error;
+use crate::section_0115::pointer;
+use crate::section_0118::link;
+use crate::section_0120::get_avail;
+use crate::section_0162::temp_head;
+use crate::section_0269::bottom_level;
+use crate::section_0323::ins_list;
+use crate::section_0325::back_input;
@@ -1,21 +1,77 @@
 //! @ At this point, |link(temp_head)=p|, a pointer to an empty one-word node.
-//!
-//! @<Prepare to insert a token that matches |cur_group|...@>=
-//! case cur_group of
-//! semi_simple_group: begin info(p):=cs_token_flag+frozen_end_group;
-//!   print_esc("endgroup");
-//! @.Missing \\endgroup inserted@>
-//!   end;
-//! math_shift_group: begin info(p):=math_shift_token+"$"; print_char("$");
-//! @.Missing \$ inserted@>
-//!   end;
-//! math_left_group: begin info(p):=cs_token_flag+frozen_right; link(p):=get_avail;
-//!   p:=link(p); info(p):=other_token+"."; print_esc("right.");
-//! @.Missing \\right\hbox{.} inserted@>
-//! @^null delimiter@>
-//!   end;
-//! othercases begin info(p):=right_brace_token+"}"; print_char("}");
-//! @.Missing \} inserted@>
-//!   end
-//! endcases
-//!
+//
+// @<Prepare to insert a token that matches |cur_group|...@>=
+pub(crate) macro Prepare_to_insert_a_token_that_matches_cur_group__and_print_what_it_is($globals:expr, $p:expr) {{
+    // case cur_group of
+    // semi_simple_group: begin info(p):=cs_token_flag+frozen_end_group;
+    if $globals.cur_group == semi_simple_group {
+        info_tok_assign!($globals, $p, cur_tok_type::from_cs(frozen_end_group as _));
+        // print_esc("endgroup");
+        print_esc($globals, crate::strpool_str!("endgroup"));
+        // @.Missing \\endgroup inserted@>
+        // end;
+    }
+    // math_shift_group: begin info(p):=math_shift_token+"$"; print_char("$");
+    else if $globals.cur_group == math_shift_group {
+        info_tok_assign!(
+            $globals,
+            $p,
+            cur_tok_type::new(math_shift_token + b'$' as cur_tok_repr)
+        );
+        print_char(
+            make_globals_io_string_log_view!($globals),
+            ASCII_code_literal!(b'$'),
+        );
+        // @.Missing \$ inserted@>
+        // end;
+    }
+    // math_left_group: begin info(p):=cs_token_flag+frozen_right; link(p):=get_avail;
+    else if $globals.cur_group == math_left_group {
+        info_tok_assign!($globals, $p, cur_tok_type::from_cs(frozen_right as _));
+        link!($globals, $p) = get_avail($globals)?;
+        // p:=link(p); info(p):=other_token+"."; print_esc("right.");
+        $p = link!($globals, $p);
+        info_tok_assign!(
+            $globals,
+            $p,
+            cur_tok_type::new(other_token + b'.' as cur_tok_repr)
+        );
+        print_esc($globals, crate::strpool_str!("right."));
+        // @.Missing \\right\hbox{.} inserted@>
+        // @^null delimiter@>
+        // end;
+    }
+    // othercases begin info(p):=right_brace_token+"}"; print_char("}");
+    else {
+        info_tok_assign!(
+            $globals,
+            $p,
+            cur_tok_type::new(right_brace_token + b'}' as cur_tok_repr)
+        );
+        print_char(
+            make_globals_io_string_log_view!($globals),
+            ASCII_code_literal!(b'}'),
+        );
+        // @.Missing \} inserted@>
+        // end
+    }
+    // endcases
+    use crate::section_0004::make_globals_io_string_log_view;
+    use crate::section_0004::TeXGlobalsIoStringLogView;
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0058::print_char;
+    use crate::section_0063::print_esc;
+    use crate::section_0118::info_tok_assign;
+    use crate::section_0118::link;
+    use crate::section_0120::get_avail;
+    use crate::section_0222::frozen_end_group;
+    use crate::section_0222::frozen_right;
+    use crate::section_0269::math_left_group;
+    use crate::section_0269::math_shift_group;
+    use crate::section_0269::semi_simple_group;
+    use crate::section_0289::math_shift_token;
+    use crate::section_0289::other_token;
+    use crate::section_0289::right_brace_token;
+    use crate::section_0297::cur_tok_repr;
+    use crate::section_0297::cur_tok_type;
+}}
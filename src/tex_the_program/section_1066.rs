@@ -1,7 +1,20 @@
-//! @ @<Drop current token and complain that it was unmatched@>=
-//! begin print_err("Extra "); print_cmd_chr(cur_cmd,cur_chr);
-//! @.Extra x@>
-//! help1("Things are pretty mixed up, but I think the worst is over.");@/
-//! error;
-//! end
-//!
+//! ` `
+// @<Drop current token and complain that it was unmatched@>=
+pub(crate) macro Drop_current_token_and_complain_that_it_was_unmatched($globals:expr) {{
+    // begin print_err("Extra "); print_cmd_chr(cur_cmd,cur_chr);
+    print_err!($globals, crate::strpool_str!("Extra "));
+    print_cmd_chr($globals, $globals.cur_cmd, $globals.cur_chr);
+    // @.Extra x@>
+    // help1("Things are pretty mixed up, but I think the worst is over.");@/
+    help1!(
+        $globals,
+        crate::strpool_str!("Things are pretty mixed up, but I think the worst is over.")
+    );
+    // error;
+    error($globals)?;
+    // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help1;
+    use crate::section_0082::error;
+    use crate::section_0298::print_cmd_chr;
+}}
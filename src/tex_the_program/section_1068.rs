@@ -79,7 +79,7 @@ pub(crate) fn handle_right_brace(globals: &mut TeXGlobals) -> TeXResult<()> {
         || globals.cur_group == math_shift_group
         || globals.cur_group == math_left_group
     {
-        todo!("extra right brace");
+        extra_right_brace(globals)?;
     }
     // @t\4@>@<Cases of |handle_right_brace| where a |right_brace| triggers
     //   a delayed action@>@;
@@ -106,3 +106,4 @@ use crate::section_0082::error;
 use crate::section_0095::confusion;
 use crate::section_0269::*;
 use crate::section_0281::unsave;
+use crate::section_1069::extra_right_brace;
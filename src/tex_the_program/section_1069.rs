@@ -1,17 +1,58 @@
-//! @ @<Declare act...@>=
-//! procedure extra_right_brace;
-//! begin print_err("Extra }, or forgotten ");
-//! @.Extra \}, or forgotten x@>
-//! case cur_group of
-//! semi_simple_group: print_esc("endgroup");
-//! math_shift_group: print_char("$");
-//! math_left_group: print_esc("right");
-//! end;@/
-//! help5("I've deleted a group-closing symbol because it seems to be")@/
-//! ("spurious, as in `$x}$'. But perhaps the } is legitimate and")@/
-//! ("you forgot something else, as in `\hbox{$x}'. In such cases")@/
-//! ("the way to recover is to insert both the forgotten and the")@/
-//! ("deleted material, e.g., by typing `I$}'."); error;
-//! incr(align_state);
-//! end;
-//!
+//! ` `
+// @<Declare act...@>=
+// procedure extra_right_brace;
+pub(crate) fn extra_right_brace(globals: &mut TeXGlobals) -> TeXResult<()> {
+    // begin print_err("Extra }, or forgotten ");
+    print_err!(globals, crate::strpool_str!("Extra }, or forgotten "));
+    // @.Extra \}, or forgotten x@>
+    // case cur_group of
+    // semi_simple_group: print_esc("endgroup");
+    if globals.cur_group == semi_simple_group {
+        print_esc(globals, crate::strpool_str!("endgroup"));
+    }
+    // math_shift_group: print_char("$");
+    else if globals.cur_group == math_shift_group {
+        print_char(
+            make_globals_io_string_log_view!(globals),
+            ASCII_code_literal!(b'$'),
+        );
+    }
+    // math_left_group: print_esc("right");
+    else if globals.cur_group == math_left_group {
+        print_esc(globals, crate::strpool_str!("right"));
+    }
+    // end;@/
+    // help5("I've deleted a group-closing symbol because it seems to be")@/
+    // ("spurious, as in `$x}$'. But perhaps the } is legitimate and")@/
+    // ("you forgot something else, as in `\hbox{$x}'. In such cases")@/
+    // ("the way to recover is to insert both the forgotten and the")@/
+    // ("deleted material, e.g., by typing `I$}'."); error;
+    help5!(
+        globals,
+        crate::strpool_str!("I've deleted a group-closing symbol because it seems to be"),
+        crate::strpool_str!("spurious, as in `$x}$'. But perhaps the } is legitimate and"),
+        crate::strpool_str!("you forgot something else, as in `\\hbox{$x}'. In such cases"),
+        crate::strpool_str!("the way to recover is to insert both the forgotten and the"),
+        crate::strpool_str!("deleted material, e.g., by typing `I$}'.")
+    );
+    error(globals)?;
+    // incr(align_state);
+    incr!(globals.align_state);
+    // end;
+    crate::ok_nojump!()
+}
+
+use crate::section_0004::make_globals_io_string_log_view;
+use crate::section_0004::TeXGlobals;
+use crate::section_0004::TeXGlobalsIoStringLogView;
+use crate::section_0016::incr;
+use crate::section_0018::ASCII_code_literal;
+use crate::section_0058::print_char;
+use crate::section_0063::print_esc;
+use crate::section_0073::print_err;
+use crate::section_0079::help5;
+use crate::section_0081::TeXResult;
+use crate::section_0082::error;
+use crate::section_0269::math_left_group;
+use crate::section_0269::math_shift_group;
+use crate::section_0269::semi_simple_group;
@@ -7,7 +7,7 @@
 pub(crate) fn normal_paragraph(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin if looseness<>0 then eq_word_define(int_base+looseness_code,0);
     if looseness!(globals) != 0 {
-        eq_word_define(globals, int_base as pointer + looseness_code as pointer, 0);
+        eq_word_define(globals, int_base as pointer + looseness_code as pointer, 0)?;
     }
     // if hang_indent<>0 then eq_word_define(dimen_base+hang_indent_code,0);
     if hang_indent!(globals) != scaled::zero() {
@@ -15,11 +15,11 @@ pub(crate) fn normal_paragraph(globals: &mut TeXGlobals) -> TeXResult<()> {
             globals,
             dimen_base as pointer + hang_indent_code as pointer,
             0,
-        );
+        )?;
     }
     // if hang_after<>1 then eq_word_define(int_base+hang_after_code,1);
     if hang_after!(globals) != 1 {
-        eq_word_define(globals, int_base as pointer + hang_after_code as pointer, 1);
+        eq_word_define(globals, int_base as pointer + hang_after_code as pointer, 1)?;
     }
     // if par_shape_ptr<>null then eq_define(par_shape_loc,shape_ref,null);
     if par_shape_ptr!(globals) != null {
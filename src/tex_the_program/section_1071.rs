@@ -34,10 +34,17 @@
 pub(crate) const box_flag: integer = 0o10000000000;
 // @d ship_out_flag==box_flag+512 {context code for `\.{\\shipout}'}
 /// context code for `\shipout`
+#[cfg(not(feature = "latex_support"))]
 pub(crate) const ship_out_flag: integer = box_flag + 512;
+/// context code for `\shipout`
+///
+/// TeX-rs: `latex_support` has box registers up to 32767, so the codes after
+/// those of `\setbox` and `\global\setbox` move up, as in e-TeX.
+#[cfg(feature = "latex_support")]
+pub(crate) const ship_out_flag: integer = box_flag + 65536;
 // @d leader_flag==box_flag+513 {context code for `\.{\\leaders}'}
 /// context code for `\leaders`
-pub(crate) const leader_flag: integer = box_flag + 513;
+pub(crate) const leader_flag: integer = ship_out_flag + 1;
 // @d box_code=0 {|chr_code| for `\.{\\box}'}
 /// `chr_code` for `\box'
 pub(crate) const box_code: chr_code_repr = 0;
@@ -59,20 +66,20 @@ pub(crate) const vtop_code: chr_code_repr = 4;
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals:expr) {{
     let globals = &mut *$globals;
     // primitive("moveleft",hmove,1);
-    primitive(globals, crate::strpool_str!("moveleft"), hmove, 1);
+    primitive(globals, crate::strpool_str!("moveleft"), hmove, 1)?;
     // @!@:move_left_}{\.{\\moveleft} primitive@>
     // primitive("moveright",hmove,0);@/
-    primitive(globals, crate::strpool_str!("moveright"), hmove, 0);
+    primitive(globals, crate::strpool_str!("moveright"), hmove, 0)?;
     // @!@:move_right_}{\.{\\moveright} primitive@>
     // primitive("raise",vmove,1);
-    primitive(globals, crate::strpool_str!("raise"), vmove, 1);
+    primitive(globals, crate::strpool_str!("raise"), vmove, 1)?;
     // @!@:raise_}{\.{\\raise} primitive@>
     // primitive("lower",vmove,0);
-    primitive(globals, crate::strpool_str!("lower"), vmove, 0);
+    primitive(globals, crate::strpool_str!("lower"), vmove, 0)?;
     // @!@:lower_}{\.{\\lower} primitive@>
     // @#
     // primitive("box",make_box,box_code);
-    primitive(globals, crate::strpool_str!("box"), make_box, box_code as _);
+    primitive(globals, crate::strpool_str!("box"), make_box, box_code as _)?;
     // @!@:box_}{\.{\\box} primitive@>
     // primitive("copy",make_box,copy_code);
     primitive(
@@ -80,7 +87,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("copy"),
         make_box,
         copy_code as _,
-    );
+    )?;
     // @!@:copy_}{\.{\\copy} primitive@>
     // primitive("lastbox",make_box,last_box_code);
     primitive(
@@ -88,7 +95,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("lastbox"),
         make_box,
         last_box_code as _,
-    );
+    )?;
     // @!@:last_box_}{\.{\\lastbox} primitive@>
     // primitive("vsplit",make_box,vsplit_code);
     primitive(
@@ -96,7 +103,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("vsplit"),
         make_box,
         vsplit_code as _,
-    );
+    )?;
     // @!@:vsplit_}{\.{\\vsplit} primitive@>
     // primitive("vtop",make_box,vtop_code);@/
     primitive(
@@ -104,7 +111,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("vtop"),
         make_box,
         (vtop_code as chr_code_repr) as _,
-    );
+    )?;
     // @!@:vtop_}{\.{\\vtop} primitive@>
     // primitive("vbox",make_box,vtop_code+vmode);
     primitive(
@@ -112,7 +119,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("vbox"),
         make_box,
         (vtop_code as chr_code_repr + vmode as chr_code_repr) as _,
-    );
+    )?;
     // @!@:vbox_}{\.{\\vbox} primitive@>
     // primitive("hbox",make_box,vtop_code+hmode);@/
     primitive(
@@ -120,7 +127,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("hbox"),
         make_box,
         (vtop_code as chr_code_repr + hmode as chr_code_repr) as _,
-    );
+    )?;
     // @!@:hbox_}{\.{\\hbox} primitive@>
     // primitive("shipout",leader_ship,a_leaders-1); {|ship_out_flag=leader_flag-1|}
     /// `ship_out_flag=leader_flag-1`
@@ -130,7 +137,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("shipout"),
         leader_ship,
         glue_node_subtype::a_leaders as halfword - 1,
-    );
+    )?;
     // @!@:ship_out_}{\.{\\shipout} primitive@>
     // primitive("leaders",leader_ship,a_leaders);
     primitive(
@@ -138,7 +145,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("leaders"),
         leader_ship,
         glue_node_subtype::a_leaders as _,
-    );
+    )?;
     // @!@:leaders_}{\.{\\leaders} primitive@>
     // primitive("cleaders",leader_ship,c_leaders);
     primitive(
@@ -146,7 +153,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("cleaders"),
         leader_ship,
         glue_node_subtype::c_leaders as _,
-    );
+    )?;
     // @!@:c_leaders_}{\.{\\cleaders} primitive@>
     // primitive("xleaders",leader_ship,x_leaders);
     primitive(
@@ -154,7 +161,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1071($globals
         crate::strpool_str!("xleaders"),
         leader_ship,
         glue_node_subtype::x_leaders as _,
-    );
+    )?;
     // @!@:x_leaders_}{\.{\\xleaders} primitive@>
 }}
 
@@ -2,6 +2,15 @@
 
 // @<Store \(c)|cur_box| in a box register@>=
 pub(crate) macro Store_cur_box_in_a_box_register($globals:expr, $box_context:expr) {{
+    // TeX-rs: `latex_support` has box registers up to 32767
+    #[cfg(feature = "latex_support")]
+    crate::latex_support::Store_cur_box_in_a_box_register!($globals, $box_context);
+    #[cfg(not(feature = "latex_support"))]
+    Store_cur_box_in_an_eight_bit_box_register!($globals, $box_context);
+}}
+
+#[cfg(not(feature = "latex_support"))]
+macro Store_cur_box_in_an_eight_bit_box_register($globals:expr, $box_context:expr) {{
     // if box_context<box_flag+256 then
     if $box_context < box_flag + 256 {
         // eq_define(box_base-box_flag+box_context,box_ref,cur_box)
@@ -19,23 +19,38 @@ pub(crate) macro Append_a_new_leader_node_that_uses_cur_box($globals:expr, $box_
     }
     // else  begin print_err("Leaders not followed by proper glue");
     else {
+        print_err!(
+            $globals,
+            crate::strpool_str!("Leaders not followed by proper glue")
+        );
         // @.Leaders not followed by...@>
         // help3("You should say `\leaders <box or rule><hskip or vskip>'.")@/
         // ("I found the <box or rule>, but there's no suitable")@/
         // ("<hskip or vskip>, so I'm ignoring these leaders."); back_error;
+        help3!(
+            $globals,
+            crate::strpool_str!("You should say `\\leaders <box or rule><hskip or vskip>'."),
+            crate::strpool_str!("I found the <box or rule>, but there's no suitable"),
+            crate::strpool_str!("<hskip or vskip>, so I'm ignoring these leaders.")
+        );
+        back_error($globals)?;
         // flush_node_list(cur_box);
+        flush_node_list($globals, $globals.cur_box)?;
         // end;
-        todo!("leader not followed by proper glue");
     }
     // end
     use crate::pascal::integer;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
     use crate::section_0133::subtype;
     use crate::section_0149::glue_node_subtype;
     use crate::section_0149::leader_ptr;
+    use crate::section_0202::flush_node_list;
     use crate::section_0208::*;
     use crate::section_0211::*;
     use crate::section_0213::mode;
     use crate::section_0213::tail;
+    use crate::section_0327::back_error;
     use crate::section_1060::append_glue;
     use crate::section_1071::leader_flag;
 }}
@@ -19,18 +19,37 @@ pub(crate) fn begin_box(globals: &mut TeXGlobals, box_context: integer) -> TeXRe
     }
     // box_code: begin scan_eight_bit_int; cur_box:=box(cur_val);
     else if cur_chr == box_code as chr_code_repr {
-        scan_eight_bit_int(globals)?;
-        globals.cur_box = r#box!(globals, globals.cur_val);
-        // box(cur_val):=null; {the box becomes void, at the same level}
-        /// the box becomes void, at the same level
-        const _: () = ();
-        r#box!(globals, globals.cur_val) = null;
+        // TeX-rs: `latex_support` has box registers up to 32767
+        #[cfg(feature = "latex_support")]
+        {
+            crate::latex_support::scan_register_num(globals)?;
+            globals.cur_box = crate::latex_support::fetch_box(globals, globals.cur_val)?;
+            crate::latex_support::change_box(globals, globals.cur_val, null)?;
+        }
+        #[cfg(not(feature = "latex_support"))]
+        {
+            scan_eight_bit_int(globals)?;
+            globals.cur_box = r#box!(globals, globals.cur_val);
+            // box(cur_val):=null; {the box becomes void, at the same level}
+            /// the box becomes void, at the same level
+            const _: () = ();
+            r#box!(globals, globals.cur_val) = null;
+        }
         // end;
     }
     // copy_code: begin scan_eight_bit_int; cur_box:=copy_node_list(box(cur_val));
     else if cur_chr == copy_code as chr_code_repr {
-        scan_eight_bit_int(globals)?;
-        globals.cur_box = copy_node_list(globals, r#box!(globals, globals.cur_val))?;
+        #[cfg(feature = "latex_support")]
+        {
+            crate::latex_support::scan_register_num(globals)?;
+            let p = crate::latex_support::fetch_box(globals, globals.cur_val)?;
+            globals.cur_box = copy_node_list(globals, p)?;
+        }
+        #[cfg(not(feature = "latex_support"))]
+        {
+            scan_eight_bit_int(globals)?;
+            globals.cur_box = copy_node_list(globals, r#box!(globals, globals.cur_val))?;
+        }
         // end;
     }
     // last_box_code: @<If the current list ends with a box node, delete it from
@@ -64,8 +83,10 @@ use crate::section_0004::TeXGlobals;
 use crate::section_0081::TeXResult;
 use crate::section_0115::null;
 use crate::section_0204::copy_node_list;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0230::r#box;
 use crate::section_0297::chr_code_repr;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0433::scan_eight_bit_int;
 use crate::section_1071::*;
 use crate::section_1075::box_end;
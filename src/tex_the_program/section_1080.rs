@@ -8,16 +8,27 @@ pub(crate) macro If_the_current_list_ends_with_a_box_node__delete_it_from_the_li
     // if abs(mode)=mmode then
     if mode!($globals).get().abs() == mmode {
         // begin you_cant; help1("Sorry; this \lastbox will be void."); error;
+        you_cant($globals);
+        help1!(
+            $globals,
+            crate::strpool_str!("Sorry; this \\lastbox will be void.")
+        );
+        error($globals)?;
         // end
-        todo!("you_cant");
     }
     // else if (mode=vmode)and(head=tail) then
     else if mode!($globals) == vmode && head!($globals) == tail!($globals) {
         // begin you_cant;
         // help2("Sorry...I usually can't take things from the current page.")@/
         //   ("This \lastbox will therefore be void."); error;
+        you_cant($globals);
+        help2!(
+            $globals,
+            crate::strpool_str!("Sorry...I usually can't take things from the current page."),
+            crate::strpool_str!("This \\lastbox will therefore be void.")
+        );
+        error($globals)?;
         // end
-        todo!("you_cant");
     }
     // else  begin if not is_char_node(tail) then
     else {
@@ -35,6 +46,9 @@ pub(crate) macro If_the_current_list_ends_with_a_box_node__delete_it_from_the_li
         // end;
     }
     // end
+    use crate::section_0079::help1;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
     use crate::section_0115::null;
     use crate::section_0133::r#type;
     use crate::section_0134::is_char_node;
@@ -45,4 +59,5 @@ pub(crate) macro If_the_current_list_ends_with_a_box_node__delete_it_from_the_li
     use crate::section_0213::head;
     use crate::section_0213::mode;
     use crate::section_0213::tail;
+    use crate::section_1049::you_cant;
 }}
@@ -5,6 +5,10 @@ pub(crate) macro Split_off_part_of_a_vertical_box__make_cur_box_point_to_it($glo
     /// a box number
     let n;
     // begin scan_eight_bit_int; n:=cur_val;
+    // TeX-rs: `latex_support` has box registers up to 32767
+    #[cfg(feature = "latex_support")]
+    crate::latex_support::scan_register_num($globals)?;
+    #[cfg(not(feature = "latex_support"))]
     scan_eight_bit_int($globals)?;
     n = $globals.cur_val;
     // if not scan_keyword("to") then
@@ -12,18 +16,28 @@ pub(crate) macro Split_off_part_of_a_vertical_box__make_cur_box_point_to_it($glo
         // @.to@>
         // begin print_err("Missing `to' inserted");
         // @.Missing `to' inserted@>
+        print_err!($globals, crate::strpool_str!("Missing `to' inserted"));
         // help2("I'm working on `\vsplit<box number> to <dimen>';")@/
         // ("will look for the <dimen> next."); error;
+        help2!(
+            $globals,
+            crate::strpool_str!("I'm working on `\\vsplit<box number> to <dimen>';"),
+            crate::strpool_str!("will look for the <dimen> next.")
+        );
+        error($globals)?;
         // end;
-        todo!("to");
     }
     // scan_normal_dimen;
     scan_normal_dimen!($globals)?;
     // cur_box:=vsplit(n,cur_val);
     $globals.cur_box = vsplit($globals, n as _, scaled::new_from_inner($globals.cur_val))?;
     // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
     use crate::section_0101::scaled;
     use crate::section_0407::scan_keyword;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0433::scan_eight_bit_int;
     use crate::section_0448::scan_normal_dimen;
     use crate::section_0977::vsplit;
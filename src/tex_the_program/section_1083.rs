@@ -36,7 +36,7 @@ pub(crate) macro Initiate_the_construction_of_an_hbox_or_vbox_then_return($globa
         // end;
     }
     // push_nest; mode:=-k;
-    push_nest($globals);
+    push_nest($globals)?;
     mode!($globals) = (-(k as i16)).into();
     // if k=vmode then
     if k == vmode as halfword {
@@ -17,7 +17,7 @@ pub(crate) macro Readjust_the_height_and_depth_of_cur_box_for_vtop($globals:expr
     }
     // depth(cur_box):=depth(cur_box)-h+height(cur_box); height(cur_box):=h;
     depth!($globals, $globals.cur_box) =
-        depth!($globals, $globals.cur_box) - h + height!($globals, p);
+        depth!($globals, $globals.cur_box) - h + height!($globals, $globals.cur_box);
     height!($globals, $globals.cur_box) = h;
     // end
     use crate::section_0101::scaled;
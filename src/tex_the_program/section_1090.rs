@@ -25,7 +25,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1090($globals:
         || $abs_mode_plus_cur_cmd == vmode as u16 + no_boundary as u16
     {
         // begin back_input; new_graf(true);
-        back_input($globals);
+        back_input($globals)?;
         new_graf($globals, true)?;
         // end;
         use crate::section_0325::back_input;
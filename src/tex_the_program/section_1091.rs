@@ -28,7 +28,7 @@ pub(crate) fn new_graf(globals: &mut TeXGlobals, indented: boolean) -> TeXResult
         tail_append!(globals, new_param_glue(globals, par_skip_code.into())?);
     }
     // push_nest; mode:=hmode; space_factor:=1000; set_cur_lang; clang:=cur_lang;
-    push_nest(globals);
+    push_nest(globals)?;
     mode!(globals) = hmode.into();
     space_factor!(globals) = 1000;
     set_cur_lang!(globals);
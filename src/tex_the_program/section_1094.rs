@@ -24,7 +24,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1094($globals:
         if $globals.align_state < 0 {
             /// this tries to recover from an alignment that didn't end properly
             const _: () = ();
-            off_save($globals);
+            off_save($globals)?;
         }
         // end_graf; {this takes us to the enclosing mode, if |mode>0|}
         /// this takes us to the enclosing mode, if |mode>0|
@@ -48,7 +48,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1094($globals:
         || $abs_mode_plus_cur_cmd == hmode as u16 + halign as u16
     {
         crate::trace_span!("Cases of `main_control` that build...1094");
-        head_for_vmode($globals);
+        head_for_vmode($globals)?;
         use crate::section_1095::head_for_vmode;
         true
     } else {
@@ -1,32 +1,57 @@
 //! ` `
 // @<Declare act...@>=
 // procedure head_for_vmode;
-pub(crate) fn head_for_vmode(globals: &mut TeXGlobals) {
+pub(crate) fn head_for_vmode(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin if mode<0 then
     if mode!(globals) < 0 {
-        todo!("head_for 1");
-        //   if cur_cmd<>hrule then off_save
-        //   else  begin print_err("You can't use `");
-        //     print_esc("hrule"); print("' here except with leaders");
-        // @.You can't use \\hrule...@>
-        //     help2("To put a horizontal rule in an hbox or an alignment,")@/
-        //       ("you should use \leaders or \hrulefill (see The TeXbook).");
-        //     error;
-        //     end
+        // if cur_cmd<>hrule then off_save
+        if globals.cur_cmd != hrule {
+            off_save(globals)?;
+        }
+        // else  begin print_err("You can't use `");
+        else {
+            print_err!(globals, crate::strpool_str!("You can't use `"));
+            // print_esc("hrule"); print("' here except with leaders");
+            print_esc(globals, crate::strpool_str!("hrule"));
+            print(
+                globals,
+                crate::strpool_str!("' here except with leaders").get() as _,
+            );
+            // @.You can't use \\hrule...@>
+            // help2("To put a horizontal rule in an hbox or an alignment,")@/
+            //   ("you should use \leaders or \hrulefill (see The TeXbook).");
+            help2!(
+                globals,
+                crate::strpool_str!("To put a horizontal rule in an hbox or an alignment,"),
+                crate::strpool_str!("you should use \\leaders or \\hrulefill (see The TeXbook).")
+            );
+            // error;
+            error(globals)?;
+            // end
+        }
     }
     // else  begin back_input; cur_tok:=par_token; back_input; token_type:=inserted;
     else {
-        back_input(globals);
+        back_input(globals)?;
         globals.cur_tok = globals.par_token;
-        back_input(globals);
+        back_input(globals)?;
         token_type!(globals) = inserted;
         // end;
     }
+    crate::ok_nojump!()
     // end;
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0059::print;
+use crate::section_0063::print_esc;
+use crate::section_0073::print_err;
+use crate::section_0079::help2;
+use crate::section_0081::TeXResult;
+use crate::section_0082::error;
+use crate::section_0208::hrule;
 use crate::section_0213::mode;
 use crate::section_0307::inserted;
 use crate::section_0307::token_type;
 use crate::section_0325::back_input;
+use crate::section_1064::off_save;
@@ -14,7 +14,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1097($globals:
         }
         // any_mode(mark): make_mark;
         else if abs_mode_plus_cur_cmd_matches_any_mode!($abs_mode_plus_cur_cmd, mark as u16) {
-            todo!("make_mark");
+            make_mark($globals)?;
             true
         } else {
             false
@@ -23,5 +23,6 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1097($globals:
     use crate::section_0211::*;
     use crate::section_1045::abs_mode_plus_cur_cmd_matches_any_mode;
     use crate::section_1099::begin_insert_or_adjust;
+    use crate::section_1101::make_mark;
     processed
 }}
@@ -11,11 +11,19 @@ pub(crate) fn begin_insert_or_adjust(globals: &mut TeXGlobals) -> TeXResult<()>
         scan_eight_bit_int(globals)?;
         // if cur_val=255 then
         if globals.cur_val == 255 {
-            todo!("error");
             // begin print_err("You can't "); print_esc("insert"); print_int(255);
+            print_err!(globals, crate::strpool_str!("You can't "));
+            print_esc(globals, crate::strpool_str!("insert"));
+            print_int(globals, 255);
             // @.You can't \\insert255@>
             // help1("I'm changing to \insert0; box 255 is special.");
+            help1!(
+                globals,
+                crate::strpool_str!("I'm changing to \\insert0; box 255 is special.")
+            );
             // error; cur_val:=0;
+            error(globals)?;
+            globals.cur_val = 0;
             // end;
         }
         // end;
@@ -24,11 +32,11 @@ pub(crate) fn begin_insert_or_adjust(globals: &mut TeXGlobals) -> TeXResult<()>
     saved!(globals, 0) = globals.cur_val;
     incr!(globals.save_ptr);
     // new_save_level(insert_group); scan_left_brace; normal_paragraph;
-    new_save_level(globals, insert_group.into());
+    new_save_level(globals, insert_group.into())?;
     scan_left_brace(globals)?;
     normal_paragraph(globals)?;
     // push_nest; mode:=-vmode; prev_depth:=ignore_depth;
-    push_nest(globals);
+    push_nest(globals)?;
     mode!(globals) = (-vmode).into();
     prev_depth!(globals) = ignore_depth;
     // end;
@@ -37,7 +45,12 @@ pub(crate) fn begin_insert_or_adjust(globals: &mut TeXGlobals) -> TeXResult<()>
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::incr;
+use crate::section_0063::print_esc;
+use crate::section_0065::print_int;
+use crate::section_0073::print_err;
+use crate::section_0079::help1;
 use crate::section_0081::TeXResult;
+use crate::section_0082::error;
 use crate::section_0208::vadjust;
 use crate::section_0211::vmode;
 use crate::section_0212::ignore_depth;
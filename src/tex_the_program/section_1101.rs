@@ -1,9 +1,36 @@
 //! ` `
 // @<Declare act...@>=
 // procedure make_mark;
-// var p:pointer; {new node}
-// begin p:=scan_toks(false,true); p:=get_node(small_node_size);
-// type(p):=mark_node; subtype(p):=0; {the |subtype| is not used}
-// mark_ptr(p):=def_ref; link(tail):=p; tail:=p;
-// end;
-//
+#[allow(unused_assignments)]
+pub(crate) fn make_mark(globals: &mut TeXGlobals) -> TeXResult<()> {
+    // var p:pointer; {new node}
+    /// new node
+    let mut p: pointer;
+    // begin p:=scan_toks(false,true); p:=get_node(small_node_size);
+    p = scan_toks(globals, false, true)?;
+    p = get_node(globals, small_node_size as _)?;
+    // type(p):=mark_node; subtype(p):=0; {the |subtype| is not used}
+    r#type!(globals, p) = mark_node;
+    /// the `subtype` is not used
+    const _: () = ();
+    subtype!(globals, p) = 0;
+    // mark_ptr(p):=def_ref; link(tail):=p; tail:=p;
+    mark_ptr!(globals, p) = globals.def_ref as _;
+    link!(globals, tail!(globals)) = p;
+    tail!(globals) = p;
+    // end;
+    crate::ok_nojump!()
+}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
+use crate::section_0115::pointer;
+use crate::section_0118::link;
+use crate::section_0125::get_node;
+use crate::section_0133::r#type;
+use crate::section_0133::subtype;
+use crate::section_0141::mark_node;
+use crate::section_0141::mark_ptr;
+use crate::section_0141::small_node_size;
+use crate::section_0213::tail;
+use crate::section_0473::scan_toks;
@@ -11,7 +11,9 @@ pub(crate) fn delete_last(globals: &mut TeXGlobals) -> TeXResult<()> {
     if mode!(globals) == vmode && tail!(globals) == head!(globals) {
         // @<Apologize for inability to do the operation now,
         //   unless \.{\\unskip} follows non-glue@>
-        todo!("apologize");
+        crate::section_1106::Apologize_for_inability_to_do_the_operation_now__unless_unskip_follows_non_glue!(
+            globals
+        );
     }
     // else  begin if not is_char_node(tail) then if type(tail)=cur_chr then
     else {
@@ -1,13 +1,39 @@
-//! @ @<Apologize for inability to do the operation...@>=
-//! begin if (cur_chr<>glue_node)or(last_glue<>max_halfword) then
-//!   begin you_cant;
-//!   help2("Sorry...I usually can't take things from the current page.")@/
-//!     ("Try `I\vskip-\lastskip' instead.");
-//!   if cur_chr=kern_node then help_line[0]:=
-//!     ("Try `I\kern-\lastkern' instead.")
-//!   else if cur_chr<>glue_node then help_line[0]:=@|
-//!     ("Perhaps you can make the output routine do it.");
-//!   error;
-//!   end;
-//! end
-//!
+//! ` `
+
+// @<Apologize for inability to do the operation...@>=
+pub(crate) macro Apologize_for_inability_to_do_the_operation_now__unless_unskip_follows_non_glue($globals:expr) {{
+    // begin if (cur_chr<>glue_node)or(last_glue<>max_halfword) then
+    if $globals.cur_chr.get() != glue_node as chr_code_repr || $globals.last_glue != max_halfword {
+        // begin you_cant;
+        you_cant($globals);
+        // help2("Sorry...I usually can't take things from the current page.")@/
+        //   ("Try `I\vskip-\lastskip' instead.");
+        help2!(
+            $globals,
+            crate::strpool_str!("Sorry...I usually can't take things from the current page."),
+            crate::strpool_str!("Try `I\\vskip-\\lastskip' instead.")
+        );
+        // if cur_chr=kern_node then help_line[0]:=
+        //   ("Try `I\kern-\lastkern' instead.")
+        if $globals.cur_chr.get() == kern_node as chr_code_repr {
+            $globals.help_line[0] = crate::strpool_str!("Try `I\\kern-\\lastkern' instead.");
+        }
+        // else if cur_chr<>glue_node then help_line[0]:=@|
+        //   ("Perhaps you can make the output routine do it.");
+        else if $globals.cur_chr.get() != glue_node as chr_code_repr {
+            $globals.help_line[0] =
+                crate::strpool_str!("Perhaps you can make the output routine do it.");
+        }
+        // error;
+        error($globals)?;
+        // end;
+    }
+    // end
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+    use crate::section_0110::max_halfword;
+    use crate::section_0149::glue_node;
+    use crate::section_0155::kern_node;
+    use crate::section_0297::chr_code_repr;
+    use crate::section_1049::you_cant;
+}}
@@ -9,7 +9,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1107($globals
         crate::strpool_str!("unpenalty"),
         remove_item,
         penalty_node as _,
-    );
+    )?;
     // @!@:un_penalty_}{\.{\\unpenalty} primitive@>
     // primitive("unkern",remove_item,kern_node);@/
     primitive(
@@ -17,7 +17,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1107($globals
         crate::strpool_str!("unkern"),
         remove_item,
         kern_node as _,
-    );
+    )?;
     // @!@:un_kern_}{\.{\\unkern} primitive@>
     // primitive("unskip",remove_item,glue_node);@/
     primitive(
@@ -25,7 +25,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1107($globals
         crate::strpool_str!("unskip"),
         remove_item,
         glue_node as _,
-    );
+    )?;
     // @!@:un_skip_}{\.{\\unskip} primitive@>
     // primitive("unhbox",un_hbox,box_code);@/
     primitive(
@@ -33,7 +33,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1107($globals
         crate::strpool_str!("unhbox"),
         un_hbox,
         box_code as _,
-    );
+    )?;
     // @!@:un_hbox_}{\.{\\unhbox} primitive@>
     // primitive("unhcopy",un_hbox,copy_code);@/
     primitive(
@@ -41,7 +41,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1107($globals
         crate::strpool_str!("unhcopy"),
         un_hbox,
         copy_code as _,
-    );
+    )?;
     // @!@:un_hcopy_}{\.{\\unhcopy} primitive@>
     // primitive("unvbox",un_vbox,box_code);@/
     primitive(
@@ -49,7 +49,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1107($globals
         crate::strpool_str!("unvbox"),
         un_vbox,
         box_code as _,
-    );
+    )?;
     // @!@:un_vbox_}{\.{\\unvbox} primitive@>
     // primitive("unvcopy",un_vbox,copy_code);@/
     primitive(
@@ -57,7 +57,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1107($globals
         crate::strpool_str!("unvcopy"),
         un_vbox,
         copy_code as _,
-    );
+    )?;
     // @!@:un_vcopy_}{\.{\\unvcopy} primitive@>
 }}
 
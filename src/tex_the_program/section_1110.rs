@@ -12,8 +12,17 @@ pub(crate) fn unpackage(globals: &mut TeXGlobals) -> TeXResult<()> {
     let c;
     // begin c:=cur_chr; scan_eight_bit_int; p:=box(cur_val);
     c = globals.cur_chr.get();
-    scan_eight_bit_int(globals)?;
-    p = r#box!(globals, globals.cur_val);
+    // TeX-rs: `latex_support` has box registers up to 32767
+    #[cfg(feature = "latex_support")]
+    {
+        crate::latex_support::scan_register_num(globals)?;
+        p = crate::latex_support::fetch_box(globals, globals.cur_val)?;
+    }
+    #[cfg(not(feature = "latex_support"))]
+    {
+        scan_eight_bit_int(globals)?;
+        p = r#box!(globals, globals.cur_val);
+    }
     // if p=null then return;
     if p == null {
         crate::return_nojump!();
@@ -24,13 +33,24 @@ pub(crate) fn unpackage(globals: &mut TeXGlobals) -> TeXResult<()> {
         || (mode!(globals).get().abs() == vmode && r#type!(globals, p) != vlist_node)
         || (mode!(globals).get().abs() == hmode && r#type!(globals, p) != hlist_node)
     {
-        todo!("incompatible list");
         //   begin print_err("Incompatible list can't be unboxed");
+        print_err!(
+            globals,
+            crate::strpool_str!("Incompatible list can't be unboxed")
+        );
         // @.Incompatible list...@>
         //   help3("Sorry, Pandora. (You sneaky devil.)")@/
         //   ("I refuse to unbox an \hbox in vertical mode or vice versa.")@/
         //   ("And I can't open any boxes in math mode.");@/
+        help3!(
+            globals,
+            crate::strpool_str!("Sorry, Pandora. (You sneaky devil.)"),
+            crate::strpool_str!("I refuse to unbox an \\hbox in vertical mode or vice versa."),
+            crate::strpool_str!("And I can't open any boxes in math mode.")
+        );
         //   error; return;
+        error(globals)?;
+        crate::return_nojump!();
         //   end;
     }
     // if c=copy_code then link(tail):=copy_node_list(list_ptr(p))
@@ -40,7 +60,12 @@ pub(crate) fn unpackage(globals: &mut TeXGlobals) -> TeXResult<()> {
     // else  begin link(tail):=list_ptr(p); box(cur_val):=null;
     else {
         link!(globals, tail!(globals)) = list_ptr!(globals, p);
-        r#box!(globals, globals.cur_val) = null;
+        #[cfg(feature = "latex_support")]
+        crate::latex_support::change_box(globals, globals.cur_val, null)?;
+        #[cfg(not(feature = "latex_support"))]
+        {
+            r#box!(globals, globals.cur_val) = null;
+        }
         // free_node(p,box_node_size);
         free_node(globals, p, box_node_size.into());
         // end;
@@ -56,7 +81,10 @@ pub(crate) fn unpackage(globals: &mut TeXGlobals) -> TeXResult<()> {
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0073::print_err;
+use crate::section_0079::help3;
 use crate::section_0081::TeXResult;
+use crate::section_0082::error;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
 use crate::section_0130::free_node;
@@ -70,7 +98,9 @@ use crate::section_0211::mmode;
 use crate::section_0211::vmode;
 use crate::section_0213::mode;
 use crate::section_0213::tail;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0230::r#box;
 use crate::section_0297::chr_code_repr;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0433::scan_eight_bit_int;
 use crate::section_1071::copy_code;
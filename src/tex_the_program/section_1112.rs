@@ -11,13 +11,16 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1112($globals:
     }
     // mmode+ital_corr: tail_append(new_kern(0));
     else if $abs_mode_plus_cur_cmd == mmode as u16 + ital_corr as u16 {
-        todo!("tail_append");
+        tail_append!($globals, new_kern($globals, scaled::zero())?);
         true
     } else {
         false
     };
+    use crate::section_0101::scaled;
+    use crate::section_0156::new_kern;
     use crate::section_0208::ital_corr;
     use crate::section_0211::*;
+    use crate::section_0214::tail_append;
     use crate::section_1113::append_italic_correction;
     processed
 }}
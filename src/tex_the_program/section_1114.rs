@@ -5,7 +5,7 @@
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1114($globals:expr) {{
     let globals = &mut *$globals;
     // primitive("-",discretionary,1);
-    primitive(globals, crate::strpool_str!("-"), discretionary, 1);
+    primitive(globals, crate::strpool_str!("-"), discretionary, 1)?;
     // @!@:Single-character primitives -}{\quad\.{\\-}@>
     // primitive("discretionary",discretionary,0);
     primitive(
@@ -13,7 +13,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1114($globals
         crate::strpool_str!("discretionary"),
         discretionary,
         0,
-    );
+    )?;
     // @!@:discretionary_}{\.{\\discretionary} primitive@>
 }}
 
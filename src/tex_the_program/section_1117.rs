@@ -16,8 +16,11 @@ pub(crate) fn append_discretionary(globals: &mut TeXGlobals) -> TeXResult<()> {
         c = globals.hyphen_char[cur_font!(globals)];
         // if c>=0 then if c<256 then pre_break(tail):=new_character(cur_font,c);
         if c >= 0 && c <= ASCII_code::max_allowed_repr(globals) {
-            pre_break!(globals, tail!(globals)) =
-                new_character(globals, cur_font!(globals).into(), ASCII_code::from(c));
+            pre_break!(globals, tail!(globals)) = new_character(
+                globals,
+                (cur_font!(globals) as u16).into(),
+                ASCII_code::from(c),
+            )?;
         }
         // end
     }
@@ -25,10 +28,10 @@ pub(crate) fn append_discretionary(globals: &mut TeXGlobals) -> TeXResult<()> {
     else {
         incr!(globals.save_ptr);
         saved!(globals, @neg 1) = 0;
-        new_save_level(globals, disc_group.into());
+        new_save_level(globals, disc_group.into())?;
         // scan_left_brace; push_nest; mode:=-hmode; space_factor:=1000;
         scan_left_brace(globals)?;
-        push_nest(globals);
+        push_nest(globals)?;
         mode!(globals) = (-hmode).into();
         space_factor!(globals) = 1000;
         // end;
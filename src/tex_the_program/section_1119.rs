@@ -47,10 +47,10 @@ pub(crate) fn build_discretionary(globals: &mut TeXGlobals) -> TeXResult<()> {
     }
     // incr(saved(-1)); new_save_level(disc_group); scan_left_brace;
     incr!(saved!(globals, @neg 1));
-    new_save_level(globals, disc_group.into());
+    new_save_level(globals, disc_group.into())?;
     scan_left_brace(globals)?;
     // push_nest; mode:=-hmode; space_factor:=1000;
-    push_nest(globals);
+    push_nest(globals)?;
     mode!(globals) = (-hmode).into();
     space_factor!(globals) = 1000;
     // exit:end;
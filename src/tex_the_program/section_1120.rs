@@ -5,12 +5,21 @@ pub(crate) macro Attach_list_p_to_the_current_list_and_record_its_length__then_f
     // begin if (n>0)and(abs(mode)=mmode) then
     if $n > 0 && mode!($globals).get().abs() == mmode {
         // begin print_err("Illegal math "); print_esc("discretionary");
+        print_err!($globals, crate::strpool_str!("Illegal math "));
+        print_esc($globals, crate::strpool_str!("discretionary"));
         // @.Illegal math \\disc...@>
         // help2("Sorry: The third part of a discretionary break must be")@/
         // ("empty, in math formulas. I had to delete your third part.");
+        help2!(
+            $globals,
+            crate::strpool_str!("Sorry: The third part of a discretionary break must be"),
+            crate::strpool_str!("empty, in math formulas. I had to delete your third part.")
+        );
         // flush_node_list(p); n:=0; error;
+        flush_node_list($globals, $p)?;
+        $n = 0;
+        error($globals)?;
         // end
-        todo!("illegal math");
     }
     // else link(tail):=p;
     else {
@@ -22,12 +31,21 @@ pub(crate) macro Attach_list_p_to_the_current_list_and_record_its_length__then_f
     }
     // else  begin print_err("Discretionary list is too long");
     else {
+        print_err!(
+            $globals,
+            crate::strpool_str!("Discretionary list is too long")
+        );
         // @.Discretionary list is too long@>
         //   help2("Wow---I never thought anybody would tweak me here.")@/
         //   ("You can't seriously need such a huge discretionary list?");
+        help2!(
+            $globals,
+            crate::strpool_str!("Wow---I never thought anybody would tweak me here."),
+            crate::strpool_str!("You can't seriously need such a huge discretionary list?")
+        );
         //   error;
+        error($globals)?;
         //   end;
-        todo!("too long");
     }
     // if n>0 then tail:=q;
     if $n > 0 {
@@ -39,9 +57,14 @@ pub(crate) macro Attach_list_p_to_the_current_list_and_record_its_length__then_f
     // end
     use crate::pascal::integer;
     use crate::section_0016::decr;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
     use crate::section_0110::max_quarterword;
     use crate::section_0118::link;
     use crate::section_0145::replace_count;
+    use crate::section_0202::flush_node_list;
     use crate::section_0211::mmode;
     use crate::section_0213::mode;
     use crate::section_0213::tail;
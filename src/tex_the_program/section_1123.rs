@@ -24,8 +24,8 @@ pub(crate) fn make_accent(globals: &mut TeXGlobals) -> TeXResult<()> {
     // @!i:four_quarters; {character information}
     // begin scan_char_num; f:=cur_font; p:=new_character(f,cur_val);
     scan_char_num(globals, true)?;
-    f = cur_font!(globals).into();
-    p = new_character(globals, f, ASCII_code::from(globals.cur_val));
+    f = (cur_font!(globals) as u16).into();
+    p = new_character(globals, f, ASCII_code::from(globals.cur_val))?;
     // if p<>null then
     if p != null {
         /// heights and widths, as explained above
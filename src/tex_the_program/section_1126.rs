@@ -19,12 +19,14 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1126($globals:
         }
         // any_mode(no_align): no_align_error;
         else if abs_mode_plus_cur_cmd_matches_any_mode!($abs_mode_plus_cur_cmd, no_align as u16) {
-            todo!("no_align_error");
+            no_align_error($globals)?;
+            use crate::section_1129::no_align_error;
             true
         }
         // any_mode(omit): omit_error;
         else if abs_mode_plus_cur_cmd_matches_any_mode!($abs_mode_plus_cur_cmd, omit as u16) {
-            todo!("omit_error");
+            omit_error($globals)?;
+            use crate::section_1129::omit_error;
             true
         } else {
             false
@@ -11,21 +11,25 @@ pub(crate) fn align_error(globals: &mut TeXGlobals) -> TeXResult<()> {
     }
     // else  begin back_input;
     else {
-        back_input(globals);
+        back_input(globals)?;
         // if align_state<0 then
         if globals.align_state < 0 {
-            todo!("Missing {{");
-            //   begin print_err("Missing { inserted");
+            // begin print_err("Missing { inserted");
+            print_err!(globals, crate::strpool_str!("Missing { inserted"));
             // @.Missing \{ inserted@>
-            //   incr(align_state); cur_tok:=left_brace_token+"{";
-            //   end
+            // incr(align_state); cur_tok:=left_brace_token+"{";
+            incr!(globals.align_state);
+            globals.cur_tok = cur_tok_type::new(left_brace_token + b'{' as cur_tok_repr);
+            // end
         }
         // else  begin print_err("Missing } inserted");
         else {
-            todo!("Missing }}");
+            print_err!(globals, crate::strpool_str!("Missing } inserted"));
             // @.Missing \} inserted@>
-            //   decr(align_state); cur_tok:=right_brace_token+"}";
-            //   end;
+            // decr(align_state); cur_tok:=right_brace_token+"}";
+            decr!(globals.align_state);
+            globals.cur_tok = cur_tok_type::new(right_brace_token + b'}' as cur_tok_repr);
+            // end;
         }
         // help3("I've put in what seems to be necessary to fix")@/
         //   ("the current column of the current alignment.")@/
@@ -44,7 +48,14 @@ pub(crate) fn align_error(globals: &mut TeXGlobals) -> TeXResult<()> {
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0016::decr;
+use crate::section_0016::incr;
+use crate::section_0073::print_err;
 use crate::section_0079::help3;
 use crate::section_0081::TeXResult;
+use crate::section_0289::left_brace_token;
+use crate::section_0289::right_brace_token;
+use crate::section_0297::cur_tok_repr;
+use crate::section_0297::cur_tok_type;
 use crate::section_0325::back_input;
 use crate::section_0327::ins_error;
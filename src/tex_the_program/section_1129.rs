@@ -1,17 +1,45 @@
 //! @ The help messages here contain a little white lie, since \.{\\noalign}
 //! and \.{\\omit} are allowed also after `\.{\\noalign\{...\}}'.
-//!
-//! @<Declare act...@>=
-//! procedure no_align_error;
-//! begin print_err("Misplaced "); print_esc("noalign");
-//! @.Misplaced \\noalign@>
-//! help2("I expect to see \noalign only after the \cr of")@/
-//!   ("an alignment. Proceed, and I'll ignore this case."); error;
-//! end;
-//! procedure omit_error;
-//! begin print_err("Misplaced "); print_esc("omit");
-//! @.Misplaced \\omit@>
-//! help2("I expect to see \omit only after tab marks or the \cr of")@/
-//!   ("an alignment. Proceed, and I'll ignore this case."); error;
-//! end;
-//!
+//
+// @<Declare act...@>=
+// procedure no_align_error;
+pub(crate) fn no_align_error(globals: &mut TeXGlobals) -> TeXResult<()> {
+    // begin print_err("Misplaced "); print_esc("noalign");
+    print_err!(globals, crate::strpool_str!("Misplaced "));
+    print_esc(globals, crate::strpool_str!("noalign"));
+    // @.Misplaced \\noalign@>
+    // help2("I expect to see \noalign only after the \cr of")@/
+    //   ("an alignment. Proceed, and I'll ignore this case."); error;
+    help2!(
+        globals,
+        crate::strpool_str!("I expect to see \\noalign only after the \\cr of"),
+        crate::strpool_str!("an alignment. Proceed, and I'll ignore this case.")
+    );
+    error(globals)?;
+    // end;
+    crate::ok_nojump!()
+}
+// procedure omit_error;
+pub(crate) fn omit_error(globals: &mut TeXGlobals) -> TeXResult<()> {
+    // begin print_err("Misplaced "); print_esc("omit");
+    print_err!(globals, crate::strpool_str!("Misplaced "));
+    print_esc(globals, crate::strpool_str!("omit"));
+    // @.Misplaced \\omit@>
+    // help2("I expect to see \omit only after tab marks or the \cr of")@/
+    //   ("an alignment. Proceed, and I'll ignore this case."); error;
+    help2!(
+        globals,
+        crate::strpool_str!("I expect to see \\omit only after tab marks or the \\cr of"),
+        crate::strpool_str!("an alignment. Proceed, and I'll ignore this case.")
+    );
+    error(globals)?;
+    // end;
+    crate::ok_nojump!()
+}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0063::print_esc;
+use crate::section_0073::print_err;
+use crate::section_0079::help2;
+use crate::section_0081::TeXResult;
+use crate::section_0082::error;
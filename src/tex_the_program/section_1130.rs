@@ -18,7 +18,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1130($globals:
             }
             // else off_save;
             else {
-                off_save($globals);
+                off_save($globals)?;
             }
         }
         true
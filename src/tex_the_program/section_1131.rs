@@ -27,7 +27,10 @@ pub(crate) fn do_endv(globals: &mut TeXGlobals) -> TeXResult<()> {
         || globals.input_stack[globals.base_ptr].state_field != token_list
     {
         // fatal_error("(interwoven alignment preambles are not allowed)");
-        todo!("fatal error");
+        fatal_error(
+            globals,
+            crate::strpool_str!("(interwoven alignment preambles are not allowed)"),
+        )?;
     }
     // @.interwoven alignment preambles...@>
     // if cur_group=align_group then
@@ -42,7 +45,7 @@ pub(crate) fn do_endv(globals: &mut TeXGlobals) -> TeXResult<()> {
     }
     // else off_save;
     else {
-        off_save(globals);
+        off_save(globals)?;
     }
     // end;
     crate::ok_nojump!()
@@ -51,6 +54,7 @@ pub(crate) fn do_endv(globals: &mut TeXGlobals) -> TeXResult<()> {
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::decr;
 use crate::section_0081::TeXResult;
+use crate::section_0093::fatal_error;
 use crate::section_0115::null;
 use crate::section_0269::align_group;
 use crate::section_0307::token_list;
@@ -3,7 +3,7 @@
 pub(crate) macro Cases_of_handle_right_brace_where_a_right_brace_triggers_a_delayed_action_1132($globals:expr) {{
     // align_group: begin back_input; cur_tok:=cs_token_flag+frozen_cr;
     let processed = if $globals.cur_group == align_group {
-        back_input($globals);
+        back_input($globals)?;
         $globals.cur_tok = cur_tok_type::from_cs(frozen_cr as _);
         // print_err("Missing "); print_esc("cr"); print(" inserted");
         print_err!($globals, crate::strpool_str!("Missing "));
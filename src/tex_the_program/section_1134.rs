@@ -0,0 +1,17 @@
+//! @ Finally, \.{\\endcsname} is not supposed to get through to |main_control|.
+//
+// @<Cases of |main_control| that build...@>=
+pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1134($globals:expr, $abs_mode_plus_cur_cmd:expr) {{
+    // any_mode(end_cs_name): cs_error;
+    let processed =
+        if abs_mode_plus_cur_cmd_matches_any_mode!($abs_mode_plus_cur_cmd, end_cs_name as u16) {
+            cs_error($globals)?;
+            true
+        } else {
+            false
+        };
+    use crate::section_0208::end_cs_name;
+    use crate::section_1045::abs_mode_plus_cur_cmd_matches_any_mode;
+    use crate::section_1135::cs_error;
+    processed
+}}
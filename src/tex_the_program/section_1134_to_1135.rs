@@ -1,13 +0,0 @@
-//! @ Finally, \.{\\endcsname} is not supposed to get through to |main_control|.
-//!
-//! @<Cases of |main_control| that build...@>=
-//! any_mode(end_cs_name): cs_error;
-//!
-//! @ @<Declare act...@>=
-//! procedure cs_error;
-//! begin print_err("Extra "); print_esc("endcsname");
-//! @.Extra \\endcsname@>
-//! help1("I'm ignoring this, since I wasn't doing a \csname.");
-//! error;
-//! end;
-//!
@@ -0,0 +1,25 @@
+//! ` `
+// @<Declare act...@>=
+// procedure cs_error;
+pub(crate) fn cs_error(globals: &mut TeXGlobals) -> TeXResult<()> {
+    // begin print_err("Extra "); print_esc("endcsname");
+    print_err!(globals, crate::strpool_str!("Extra "));
+    print_esc(globals, crate::strpool_str!("endcsname"));
+    // @.Extra \\endcsname@>
+    // help1("I'm ignoring this, since I wasn't doing a \csname.");
+    help1!(
+        globals,
+        crate::strpool_str!("I'm ignoring this, since I wasn't doing a \\csname.")
+    );
+    // error;
+    error(globals)?;
+    // end;
+    crate::ok_nojump!()
+}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0063::print_esc;
+use crate::section_0073::print_err;
+use crate::section_0079::help1;
+use crate::section_0081::TeXResult;
+use crate::section_0082::error;
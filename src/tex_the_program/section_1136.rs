@@ -10,16 +10,18 @@
 //
 // @<Declare act...@>=
 // procedure push_math(@!c:group_code);
-pub(crate) fn push_math(globals: &mut TeXGlobals, c: group_code) {
+pub(crate) fn push_math(globals: &mut TeXGlobals, c: group_code) -> TeXResult<()> {
     // begin push_nest; mode:=-mmode; incompleat_noad:=null; new_save_level(c);
-    push_nest(globals);
+    push_nest(globals)?;
     mode!(globals) = (-mmode).into();
     incompleat_noad!(globals) = null as _;
-    new_save_level(globals, c);
+    new_save_level(globals, c)?;
+    crate::ok_nojump!()
     // end;
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
 use crate::section_0115::null;
 use crate::section_0211::mmode;
 use crate::section_0213::incompleat_noad;
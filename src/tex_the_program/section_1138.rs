@@ -22,7 +22,7 @@ pub(crate) fn init_math(globals: &mut TeXGlobals) -> TeXResult<()> {
     }
     // else  begin back_input; @<Go into ordinary math mode@>;
     else {
-        back_input(globals);
+        back_input(globals)?;
         crate::section_1139::Go_into_ordinary_math_mode!(globals);
         // end;
     }
@@ -2,8 +2,8 @@
 // @<Go into ordinary math mode@>=
 pub(crate) macro Go_into_ordinary_math_mode($globals:expr) {{
     // begin push_math(math_shift_group); eq_word_define(int_base+cur_fam_code,-1);
-    push_math($globals, math_shift_group.into());
-    eq_word_define($globals, int_base as pointer + cur_fam_code as pointer, -1);
+    push_math($globals, math_shift_group.into())?;
+    eq_word_define($globals, int_base as pointer + cur_fam_code as pointer, -1)?;
     // if every_math<>null then begin_token_list(every_math,every_math_text);
     if every_math!($globals) != null {
         begin_token_list($globals, every_math!($globals), every_math_text);
@@ -9,11 +9,11 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1140($globals:
     let processed = if $abs_mode_plus_cur_cmd == mmode as u16 + eq_no as u16 {
         if privileged($globals)? {
             if $globals.cur_group == math_shift_group {
-                start_eq_no($globals);
+                start_eq_no($globals)?;
             }
             // else off_save;
             else {
-                off_save($globals);
+                off_save($globals)?;
             }
         }
         true
@@ -4,15 +4,17 @@
 //
 // @<Declare act...@>=
 // procedure start_eq_no;
-pub(crate) fn start_eq_no(globals: &mut TeXGlobals) {
+pub(crate) fn start_eq_no(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin saved(0):=cur_chr; incr(save_ptr);
     saved!(globals, 0) = globals.cur_chr.get() as _;
     incr!(globals.save_ptr);
     // @<Go into ordinary math mode@>;
     crate::section_1139::Go_into_ordinary_math_mode!(globals);
+    crate::ok_nojump!()
     // end;
 }
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::incr;
+use crate::section_0081::TeXResult;
 use crate::section_0274::saved;
@@ -39,23 +39,23 @@ pub(crate) macro Go_into_display_math_mode($globals:expr) {
         // @<Calculate the length, |l|, and the shift amount, |s|, of the display lines@>;
         crate::section_1149::Calculate_the_length__l__and_the_shift_amount__s__of_the_display_lines!($globals, l, s);
         // push_math(math_shift_group); mode:=mmode;
-        push_math($globals, math_shift_group.into());
+        push_math($globals, math_shift_group.into())?;
         mode!($globals) = mmode.into();
         // eq_word_define(int_base+cur_fam_code,-1);@/
-        eq_word_define($globals, (int_base + cur_fam_code as word) as _, -1);
+        eq_word_define($globals, (int_base + cur_fam_code as word) as _, -1)?;
         // eq_word_define(dimen_base+pre_display_size_code,w);
         eq_word_define(
             $globals,
             (dimen_base + pre_display_size_code as word) as _,
             w.inner(),
-        );
+        )?;
         // eq_word_define(dimen_base+display_width_code,l);
-        eq_word_define($globals, (dimen_base + display_width_code as word) as _, l.inner());
+        eq_word_define($globals, (dimen_base + display_width_code as word) as _, l.inner())?;
         // eq_word_define(dimen_base+display_indent_code,s);
-        eq_word_define($globals, (dimen_base + display_indent_code as word) as _, s.inner());
+        eq_word_define($globals, (dimen_base + display_indent_code as word) as _, s.inner())?;
         // if every_display<>null then begin_token_list(every_display,every_display_text);
         if every_display!($globals) != null {
-            todo!("token_list");
+            begin_token_list($globals, every_display!($globals), every_display_text);
         }
         // if nest_ptr=1 then build_page;
         if $globals.nest_ptr == 1 {
@@ -80,6 +80,8 @@ pub(crate) macro Go_into_display_math_mode($globals:expr) {
         use crate::section_0247::pre_display_size_code;
         use crate::section_0269::math_shift_group;
         use crate::section_0278::eq_word_define;
+        use crate::section_0307::every_display_text;
+        use crate::section_0323::begin_token_list;
         use crate::section_0421::max_dimen;
         use crate::section_0815::line_break;
         use crate::section_0994::build_page;
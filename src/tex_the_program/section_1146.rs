@@ -8,7 +8,7 @@ pub(crate) macro Calculate_the_natural_width__w__by_which_the_characters_of_the_
     let mut p;
     // v:=shift_amount(just_box)+2*quad(cur_font); w:=-max_dimen;
     v = shift_amount!($globals, $globals.just_box) + scaled::new_from_inner(2 * quad!($globals,
-        internal_font_number::new(cur_font!($globals))).inner());
+        internal_font_number::new(cur_font!($globals) as u16)).inner());
     $w = scaled::new_from_inner(-max_dimen);
     // p:=list_ptr(just_box);
     p = list_ptr!($globals, $globals.just_box);
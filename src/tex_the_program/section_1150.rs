@@ -18,7 +18,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1150($globals:
     let processed = if $abs_mode_plus_cur_cmd == mmode as u16 + left_brace as u16 {
         tail_append!($globals, new_noad($globals)?);
         // back_input; scan_math(nucleus(tail));
-        back_input($globals);
+        back_input($globals)?;
         scan_math($globals, nucleus!(tail!($globals)))?;
         // end;
         true
@@ -12,52 +12,70 @@ pub(crate) macro fam_in_range($globals:expr) {
 
 // @<Declare act...@>=
 // procedure scan_math(@!p:pointer);
-#[allow(unused_variables)]
 pub(crate) fn scan_math(globals: &mut TeXGlobals, p: pointer) -> TeXResult<()> {
     // label restart,reswitch,exit;
     // var c:integer; {math character code}
     /// math character code
-    let c: integer;
-    // begin restart:@<Get the next non-blank non-relax...@>;
-    crate::section_0404::Get_the_next_non_blank_non_relax_non_call_token!(globals);
-    // reswitch:case cur_cmd of
-    // letter,other_char,char_given: begin c:=ho(math_code(cur_chr));
-    if globals.cur_cmd == letter || globals.cur_cmd == other_char || globals.cur_cmd == char_given {
-        c = ho!(math_code!(globals, globals.cur_chr.into())) as _;
-        // if c=@'100000 then
-        if c == 0o100000 {
-            // begin @<Treat |cur_chr| as an active character@>;
-            crate::section_1152::Treat_cur_chr_as_an_active_character!(globals);
-            // goto restart;
-            // end;
-            todo!("treat");
+    let mut c: integer;
+    crate::region_backward_label! {
+        'restart <-
+        {
+            // begin restart:@<Get the next non-blank non-relax...@>;
+            crate::section_0404::Get_the_next_non_blank_non_relax_non_call_token!(globals);
+            crate::region_backward_label! {
+                'reswitch <-
+                {
+                    // reswitch:case cur_cmd of
+                    // letter,other_char,char_given: begin c:=ho(math_code(cur_chr));
+                    if globals.cur_cmd == letter
+                        || globals.cur_cmd == other_char
+                        || globals.cur_cmd == char_given
+                    {
+                        c = ho!(math_code!(globals, globals.cur_chr.into())) as _;
+                        // if c=@'100000 then
+                        if c == 0o100000 {
+                            // begin @<Treat |cur_chr| as an active character@>;
+                            crate::section_1152::Treat_cur_chr_as_an_active_character!(globals);
+                            // goto restart;
+                            crate::goto_backward_label!('restart);
+                            // end;
+                        }
+                        // end;
+                    }
+                    // char_num: begin scan_char_num; cur_chr:=cur_val; cur_cmd:=char_given;
+                    else if globals.cur_cmd == char_num {
+                        scan_char_num(globals, true)?;
+                        globals.cur_chr = chr_code_type::new(globals.cur_val as _);
+                        globals.cur_cmd = char_given;
+                        // goto reswitch;
+                        crate::goto_backward_label!('reswitch);
+                        // end;
+                    }
+                    // math_char_num: begin scan_fifteen_bit_int; c:=cur_val;
+                    else if globals.cur_cmd == math_char_num {
+                        scan_fifteen_bit_int(globals)?;
+                        c = globals.cur_val;
+                        // end;
+                    }
+                    // math_given: c:=cur_chr;
+                    else if globals.cur_cmd == math_given {
+                        c = globals.cur_chr.get() as _;
+                    }
+                    // delim_num: begin scan_twenty_seven_bit_int; c:=cur_val div @'10000;
+                    else if globals.cur_cmd == delim_num {
+                        scan_twenty_seven_bit_int(globals)?;
+                        c = globals.cur_val / 0o10000;
+                        // end;
+                    }
+                    // othercases @<Scan a subformula enclosed in braces and |return|@>
+                    else {
+                        crate::section_1153::Scan_a_subformula_enclosed_in_braces_and_return!(globals, p);
+                    }
+                }
+                |'reswitch|
+            }
         }
-        // end;
-    }
-    // char_num: begin scan_char_num; cur_chr:=cur_val; cur_cmd:=char_given;
-    else if globals.cur_cmd == char_num {
-        // goto reswitch;
-        // end;
-        todo!("char_num");
-    }
-    // math_char_num: begin scan_fifteen_bit_int; c:=cur_val;
-    else if globals.cur_cmd == math_char_num {
-        // end;
-        todo!("math_char_num");
-    }
-    // math_given: c:=cur_chr;
-    else if globals.cur_cmd == math_given {
-        c = globals.cur_chr.get() as _;
-    }
-    // delim_num: begin scan_twenty_seven_bit_int; c:=cur_val div @'10000;
-    else if globals.cur_cmd == delim_num {
-        scan_twenty_seven_bit_int(globals)?;
-        c = globals.cur_val / 0o10000;
-        // end;
-    }
-    // othercases @<Scan a subformula enclosed in braces and |return|@>
-    else {
-        crate::section_1153::Scan_a_subformula_enclosed_in_braces_and_return!(globals, p);
+        |'restart|
     }
     // endcases;@/
     // math_type(p):=math_char; character(p):=qi(c mod 256);
@@ -88,6 +106,9 @@ use crate::section_0208::*;
 use crate::section_0230::math_code;
 use crate::section_0232::var_code;
 use crate::section_0236::cur_fam;
+use crate::section_0297::chr_code_type;
+use crate::section_0434::scan_char_num;
+use crate::section_0436::scan_fifteen_bit_int;
 use crate::section_0437::scan_twenty_seven_bit_int;
 use crate::section_0548::internal_font_number;
 use crate::section_0548::internal_font_number_repr;
@@ -3,6 +3,11 @@
 // @<Treat |cur_chr|...@>=
 pub(crate) macro Treat_cur_chr_as_an_active_character($globals:expr) {{
     // begin cur_cs:=cur_chr+active_base;
+    // TeX-rs: there are no active characters beyond 255 yet
+    #[cfg(feature = "unicode_support")]
+    if $globals.cur_chr.get() > 255 {
+        crate::unported::unported!($globals, 1152);
+    }
     $globals.cur_cs = crate::section_0230::index_offset_with_ASCII_code(
         crate::section_0222::active_base,
         $globals.cur_chr.into(),
@@ -12,7 +17,7 @@ pub(crate) macro Treat_cur_chr_as_an_active_character($globals:expr) {{
     $globals.cur_chr = chr_code_type::new(equiv!($globals, $globals.cur_cs) as _);
     // x_token; back_input;
     x_token($globals)?;
-    back_input($globals);
+    back_input($globals)?;
     // end
     use crate::pascal::integer;
     use crate::section_0221::eq_type;
@@ -21,9 +21,14 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1154($globals:
     }
     // mmode+char_num: begin scan_char_num; cur_chr:=cur_val;
     else if $abs_mode_plus_cur_cmd == mmode as u16 + char_num as u16 {
+        scan_char_num($globals, true)?;
+        $globals.cur_chr = chr_code_type::new($globals.cur_val as _);
         // set_math_char(ho(math_code(cur_chr)));
+        set_math_char(
+            $globals,
+            ho!(math_code!($globals, ASCII_code::from($globals.cur_chr))) as _,
+        )?;
         // end;
-        todo!("mmode + char_num");
         true
     }
     // mmode+math_char_num: begin scan_fifteen_bit_int; set_math_char(cur_val);
@@ -55,6 +60,8 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1154($globals:
     use crate::section_0208::*;
     use crate::section_0211::*;
     use crate::section_0230::math_code;
+    use crate::section_0297::chr_code_type;
+    use crate::section_0434::scan_char_num;
     use crate::section_0436::scan_fifteen_bit_int;
     use crate::section_1155::set_math_char;
     processed
@@ -12,14 +12,26 @@ pub(crate) fn math_limit_switch(globals: &mut TeXGlobals) -> TeXResult<()> {
         // end;
     }
     // print_err("Limit controls must follow a math operator");
+    print_err!(
+        globals,
+        crate::strpool_str!("Limit controls must follow a math operator")
+    );
     // @.Limit controls must follow...@>
     // help1("I'm ignoring this misplaced \limits or \nolimits command."); error;
+    help1!(
+        globals,
+        crate::strpool_str!("I'm ignoring this misplaced \\limits or \\nolimits command.")
+    );
+    error(globals)?;
     // exit:end;
-    todo!("error");
+    crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0073::print_err;
+use crate::section_0079::help1;
 use crate::section_0081::TeXResult;
+use crate::section_0082::error;
 use crate::section_0133::r#type;
 use crate::section_0133::subtype;
 use crate::section_0213::head;
@@ -32,7 +32,9 @@ pub(crate) fn scan_delimiter(globals: &mut TeXGlobals, p: pointer, r: boolean) -
     // if cur_val<0 then @<Report that an invalid delimiter code is being changed
     //    to null; set~|cur_val:=0|@>;
     if globals.cur_val < 0 {
-        todo!("report error");
+        crate::section_1161::Report_that_an_invalid_delimiter_code_is_being_changed_to_null__set_cur_val_to_0!(
+            globals
+        );
     }
     // small_fam(p):=(cur_val div @'4000000) mod 16;
     // small_char(p):=qi((cur_val div @'10000) mod 256);
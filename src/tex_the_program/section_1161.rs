@@ -1,12 +1,33 @@
-//! @ @<Report that an invalid delimiter...@>=
-//! begin print_err("Missing delimiter (. inserted)");
-//! @.Missing delimiter...@>
-//! help6("I was expecting to see something like `(' or `\{' or")@/
-//!   ("`\}' here. If you typed, e.g., `{' instead of `\{', you")@/
-//!   ("should probably delete the `{' by typing `1' now, so that")@/
-//!   ("braces don't get unbalanced. Otherwise just proceed.")@/
-//!   ("Acceptable delimiters are characters whose \delcode is")@/
-//!   ("nonnegative, or you can use `\delimiter <delimiter code>'.");
-//! back_error; cur_val:=0;
-//! end
-//!
+//! ` `
+
+// @<Report that an invalid delimiter...@>=
+pub(crate) macro Report_that_an_invalid_delimiter_code_is_being_changed_to_null__set_cur_val_to_0($globals:expr) {{
+    // begin print_err("Missing delimiter (. inserted)");
+    print_err!(
+        $globals,
+        crate::strpool_str!("Missing delimiter (. inserted)")
+    );
+    // @.Missing delimiter...@>
+    // help6("I was expecting to see something like `(' or `\{' or")@/
+    //   ("`\}' here. If you typed, e.g., `{' instead of `\{', you")@/
+    //   ("should probably delete the `{' by typing `1' now, so that")@/
+    //   ("braces don't get unbalanced. Otherwise just proceed.")@/
+    //   ("Acceptable delimiters are characters whose \delcode is")@/
+    //   ("nonnegative, or you can use `\delimiter <delimiter code>'.");
+    help6!(
+        $globals,
+        crate::strpool_str!("I was expecting to see something like `(' or `\\{' or"),
+        crate::strpool_str!("`\\}' here. If you typed, e.g., `{' instead of `\\{', you"),
+        crate::strpool_str!("should probably delete the `{' by typing `1' now, so that"),
+        crate::strpool_str!("braces don't get unbalanced. Otherwise just proceed."),
+        crate::strpool_str!("Acceptable delimiters are characters whose \\delcode is"),
+        crate::strpool_str!("nonnegative, or you can use `\\delimiter <delimiter code>'.")
+    );
+    // back_error; cur_val:=0;
+    back_error($globals)?;
+    $globals.cur_val = 0;
+    // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help6;
+    use crate::section_0327::back_error;
+}}
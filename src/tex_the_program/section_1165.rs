@@ -7,7 +7,7 @@ pub(crate) fn math_ac(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin if cur_cmd=accent then
     if globals.cur_cmd == accent {
         // @<Complain that the user should have said \.{\\mathaccent}@>;
-        todo!("complain");
+        crate::section_1166::Complain_that_the_user_should_have_said_mathaccent!(globals);
     }
     // tail_append(get_node(accent_noad_size));
     tail_append!(globals, get_node(globals, accent_noad_size as _)?);
@@ -1,9 +1,29 @@
-//! @ @<Complain that the user should have said \.{\\mathaccent}@>=
-//! begin print_err("Please use "); print_esc("mathaccent");
-//! print(" for accents in math mode");
-//! @.Please use \\mathaccent...@>
-//! help2("I'm changing \accent to \mathaccent here; wish me luck.")@/
-//!   ("(Accents are not the same in formulas as they are in text.)");
-//! error;
-//! end
-//!
+//! ` `
+
+// @<Complain that the user should have said \.{\\mathaccent}@>=
+pub(crate) macro Complain_that_the_user_should_have_said_mathaccent($globals:expr) {{
+    // begin print_err("Please use "); print_esc("mathaccent");
+    print_err!($globals, crate::strpool_str!("Please use "));
+    print_esc($globals, crate::strpool_str!("mathaccent"));
+    // print(" for accents in math mode");
+    print(
+        $globals,
+        crate::strpool_str!(" for accents in math mode").get() as _,
+    );
+    // @.Please use \\mathaccent...@>
+    // help2("I'm changing \accent to \mathaccent here; wish me luck.")@/
+    //   ("(Accents are not the same in formulas as they are in text.)");
+    help2!(
+        $globals,
+        crate::strpool_str!("I'm changing \\accent to \\mathaccent here; wish me luck."),
+        crate::strpool_str!("(Accents are not the same in formulas as they are in text.)")
+    );
+    // error;
+    error($globals)?;
+    // end
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+}}
@@ -6,7 +6,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1167($globals:
         scan_spec($globals, vcenter_group.into(), false)?;
         normal_paragraph($globals)?;
         // push_nest; mode:=-vmode; prev_depth:=ignore_depth;
-        push_nest($globals);
+        push_nest($globals)?;
         mode!($globals) = (-vmode).into();
         prev_depth!($globals) = ignore_depth;
         // if every_vbox<>null then begin_token_list(every_vbox,every_vbox_text);
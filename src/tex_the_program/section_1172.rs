@@ -9,7 +9,7 @@ pub(crate) fn append_choices(globals: &mut TeXGlobals) -> TeXResult<()> {
     incr!(globals.save_ptr);
     saved!(globals, @neg 1) = 0;
     // push_math(math_choice_group); scan_left_brace;
-    push_math(globals, math_choice_group.into());
+    push_math(globals, math_choice_group.into())?;
     scan_left_brace(globals)?;
     // end;
     crate::ok_nojump!()
@@ -10,7 +10,7 @@ pub(crate) fn build_choices(globals: &mut TeXGlobals) -> TeXResult<()> {
     let p;
     // begin unsave; p:=fin_mlist(null);
     unsave(globals)?;
-    p = fin_mlist(globals, null);
+    p = fin_mlist(globals, null)?;
     // case saved(-1) of
     let saved_neg_1 = saved!(globals, @neg 1);
     // 0:display_mlist(tail):=p;
@@ -40,7 +40,7 @@ pub(crate) fn build_choices(globals: &mut TeXGlobals) -> TeXResult<()> {
     }
     // incr(saved(-1)); push_math(math_choice_group); scan_left_brace;
     incr!(saved!(globals, @neg 1));
-    push_math(globals, math_choice_group.into());
+    push_math(globals, math_choice_group.into())?;
     scan_left_brace(globals)?;
     // exit:end;
     crate::ok_nojump!()
@@ -10,19 +10,36 @@ pub(crate) macro Insert_a_dummy_noad_to_be_sub_superscripted($globals:expr, $p:e
     // if t<>empty then
     if $t != math_type_kind::empty as _ {
         // begin if cur_cmd=sup_mark then
-        //   begin print_err("Double superscript");
-        // @.Double superscript@>
-        //   help1("I treat `x^1^2' essentially like `x^1{}^2'.");
-        //   end
+        if $globals.cur_cmd == sup_mark {
+            //   begin print_err("Double superscript");
+            print_err!($globals, crate::strpool_str!("Double superscript"));
+            // @.Double superscript@>
+            //   help1("I treat `x^1^2' essentially like `x^1{}^2'.");
+            help1!(
+                $globals,
+                crate::strpool_str!("I treat `x^1^2' essentially like `x^1{}^2'.")
+            );
+            //   end
+        }
         // else  begin print_err("Double subscript");
-        // @.Double subscript@>
-        //   help1("I treat `x_1_2' essentially like `x_1{}_2'.");
-        //   end;
+        else {
+            print_err!($globals, crate::strpool_str!("Double subscript"));
+            // @.Double subscript@>
+            //   help1("I treat `x_1_2' essentially like `x_1{}_2'.");
+            help1!(
+                $globals,
+                crate::strpool_str!("I treat `x_1_2' essentially like `x_1{}_2'.")
+            );
+            //   end;
+        }
         // error;
+        error($globals)?;
         // end;
-        todo!("t != empty");
     }
     // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help1;
+    use crate::section_0082::error;
     use crate::section_0115::pointer;
     use crate::section_0207::sup_mark;
     use crate::section_0213::tail;
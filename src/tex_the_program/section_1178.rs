@@ -30,13 +30,13 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1178($globals
         crate::strpool_str!("above"),
         above,
         above_code as _,
-    );
+    )?;
     // @!@:above_}{\.{\\above} primitive@>
     // primitive("over",above,over_code);@/
-    primitive(globals, crate::strpool_str!("over"), above, over_code as _);
+    primitive(globals, crate::strpool_str!("over"), above, over_code as _)?;
     // @!@:over_}{\.{\\over} primitive@>
     // primitive("atop",above,atop_code);@/
-    primitive(globals, crate::strpool_str!("atop"), above, atop_code as _);
+    primitive(globals, crate::strpool_str!("atop"), above, atop_code as _)?;
     // @!@:atop_}{\.{\\atop} primitive@>
     // primitive("abovewithdelims",above,delimited_code+above_code);@/
     primitive(
@@ -44,7 +44,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1178($globals
         crate::strpool_str!("abovewithdelims"),
         above,
         (delimited_code + above_code) as _,
-    );
+    )?;
     // @!@:above_with_delims_}{\.{\\abovewithdelims} primitive@>
     // primitive("overwithdelims",above,delimited_code+over_code);@/
     primitive(
@@ -52,7 +52,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1178($globals
         crate::strpool_str!("overwithdelims"),
         above,
         (delimited_code + over_code) as _,
-    );
+    )?;
     // @!@:over_with_delims_}{\.{\\overwithdelims} primitive@>
     // primitive("atopwithdelims",above,delimited_code+atop_code);
     primitive(
@@ -60,7 +60,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1178($globals
         crate::strpool_str!("atopwithdelims"),
         above,
         (delimited_code + atop_code) as _,
-    );
+    )?;
     // @!@:atop_with_delims_}{\.{\\atopwithdelims} primitive@>
 
     use crate::section_0208::*;
@@ -12,7 +12,9 @@ pub(crate) fn math_fraction(globals: &mut TeXGlobals) -> TeXResult<()> {
     // if incompleat_noad<>null then
     if incompleat_noad!(globals) != null as _ {
         // @<Ignore the fraction operation and complain about this ambiguous case@>
-        todo!("Ignore");
+        crate::section_1183::Ignore_the_fraction_operation_and_complain_about_this_ambiguous_case!(
+            globals, c
+        );
     }
     // else  begin incompleat_noad:=get_node(fraction_noad_size);
     else {
@@ -1,13 +1,42 @@
-//! @ @<Ignore the fraction...@>=
-//! begin if c>=delimited_code then
-//!   begin scan_delimiter(garbage,false); scan_delimiter(garbage,false);
-//!   end;
-//! if c mod delimited_code=above_code then scan_normal_dimen;
-//! print_err("Ambiguous; you need another { and }");
-//! @.Ambiguous...@>
-//! help3("I'm ignoring this fraction specification, since I don't")@/
-//!   ("know whether a construction like `x \over y \over z'")@/
-//!   ("means `{x \over y} \over z' or `x \over {y \over z}'.");
-//! error;
-//! end
-//!
+//! ` `
+// @<Ignore the fraction...@>=
+pub(crate) macro Ignore_the_fraction_operation_and_complain_about_this_ambiguous_case($globals:expr, $c:expr) {{
+    // begin if c>=delimited_code then
+    if $c >= delimited_code as _ {
+        // begin scan_delimiter(garbage,false); scan_delimiter(garbage,false);
+        scan_delimiter($globals, garbage, false)?;
+        scan_delimiter($globals, garbage, false)?;
+        // end;
+    }
+    // if c mod delimited_code=above_code then scan_normal_dimen;
+    if $c % (delimited_code as chr_code_repr) == above_code as _ {
+        scan_normal_dimen!($globals)?;
+    }
+    // print_err("Ambiguous; you need another { and }");
+    print_err!(
+        $globals,
+        crate::strpool_str!("Ambiguous; you need another { and }")
+    );
+    // @.Ambiguous...@>
+    // help3("I'm ignoring this fraction specification, since I don't")@/
+    //   ("know whether a construction like `x \over y \over z'")@/
+    //   ("means `{x \over y} \over z' or `x \over {y \over z}'.");
+    help3!(
+        $globals,
+        crate::strpool_str!("I'm ignoring this fraction specification, since I don't"),
+        crate::strpool_str!("know whether a construction like `x \\over y \\over z'"),
+        crate::strpool_str!("means `{x \\over y} \\over z' or `x \\over {y \\over z}'.")
+    );
+    // error;
+    error($globals)?;
+    // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
+    use crate::section_0082::error;
+    use crate::section_0162::garbage;
+    use crate::section_0297::chr_code_repr;
+    use crate::section_0448::scan_normal_dimen;
+    use crate::section_1160::scan_delimiter;
+    use crate::section_1178::above_code;
+    use crate::section_1178::delimited_code;
+}}
@@ -8,7 +8,7 @@
 //
 // @<Declare the function called |fin_mlist|@>=
 // function fin_mlist(@!p:pointer):pointer;
-pub(crate) fn fin_mlist(globals: &mut TeXGlobals, p: pointer) -> pointer {
+pub(crate) fn fin_mlist(globals: &mut TeXGlobals, p: pointer) -> TeXResult<pointer> {
     // var q:pointer; {the mlist to return}
     /// the mlist to return
     let q;
@@ -24,11 +24,12 @@ pub(crate) fn fin_mlist(globals: &mut TeXGlobals, p: pointer) -> pointer {
     }
     // pop_nest; fin_mlist:=q;
     pop_nest(globals);
-    return q;
+    crate::return_nojump!(q);
     // end;
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
 use crate::section_0118::link;
@@ -20,7 +20,7 @@ pub(crate) macro Compleat_the_incompleat_noad($globals:expr, $p:expr, $q:expr) {
         $q = info_inner!($globals, numerator!(incompleat_noad!($globals) as pointer));
         // if type(q)<>left_noad then confusion("right");
         if r#type!($globals, $q) != left_noad {
-            todo!("confusion");
+            confusion($globals, crate::strpool_str!("right"))?;
             // @:this can't happen right}{\quad right@>
         }
         // info(numerator(incompleat_noad)):=link(q);
@@ -28,11 +28,11 @@ pub(crate) macro Compleat_the_incompleat_noad($globals:expr, $p:expr, $q:expr) {
             link!($globals, $q);
         // link(q):=incompleat_noad; link(incompleat_noad):=p;
         link!($globals, $q) = incompleat_noad!($globals) as _;
-
+        link!($globals, incompleat_noad!($globals) as pointer) = $p;
         // end;
     }
     // end
-
+    use crate::section_0095::confusion;
     use crate::section_0115::null;
     use crate::section_0115::pointer;
     use crate::section_0118::info_inner;
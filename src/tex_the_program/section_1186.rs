@@ -14,7 +14,7 @@ pub(crate) macro Cases_of_handle_right_brace_where_a_right_brace_triggers_a_dela
         decr!($globals.save_ptr);
         // math_type(saved(0)):=sub_mlist; p:=fin_mlist(null); info(saved(0)):=p;
         math_type!($globals, saved!($globals, 0) as pointer) = math_type_kind::sub_mlist as _;
-        p = fin_mlist($globals, null);
+        p = fin_mlist($globals, null)?;
         info_inner!($globals, saved!($globals, 0) as pointer) = p;
         // if p<>null then if link(p)=null then
         if p != null && link!($globals, p) == null {
@@ -12,7 +12,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1188($globals
         crate::strpool_str!("left"),
         left_right,
         left_noad as _,
-    );
+    )?;
     // @!@:left_}{\.{\\left} primitive@>
     // primitive("right",left_right,right_noad);
     primitive(
@@ -20,7 +20,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1188($globals
         crate::strpool_str!("right"),
         left_right,
         right_noad as _,
-    );
+    )?;
     // @!@:right_}{\.{\\right} primitive@>
     // text(frozen_right):="right"; eqtb[frozen_right]:=eqtb[cur_val];
     text!(globals, frozen_right as u16) = crate::strpool_str!("right").get() as _;
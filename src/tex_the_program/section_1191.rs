@@ -13,7 +13,7 @@ pub(crate) fn math_left_right(globals: &mut TeXGlobals) -> TeXResult<()> {
     // if (t=right_noad)and(cur_group<>math_left_group) then
     if t == right_noad as _ && globals.cur_group != math_left_group {
         //   @<Try to recover from mismatched \.{\\right}@>
-        todo!("Try to recover");
+        crate::section_1192::Try_to_recover_from_mismatched_right!(globals);
     }
     // else  begin p:=new_noad; type(p):=t;
     else {
@@ -24,14 +24,14 @@ pub(crate) fn math_left_right(globals: &mut TeXGlobals) -> TeXResult<()> {
         // if t=left_noad then
         if t == left_noad as _ {
             // begin push_math(math_left_group); link(head):=p; tail:=p;
-            push_math(globals, math_left_group.into());
+            push_math(globals, math_left_group.into())?;
             link!(globals, head!(globals)) = p;
             tail!(globals) = p;
             // end
         }
         // else  begin p:=fin_mlist(p); unsave; {end of |math_left_group|}
         else {
-            p = fin_mlist(globals, p);
+            p = fin_mlist(globals, p)?;
             unsave(globals)?;
             /// end of `math_left_group`
             const _: () = ();
@@ -1,11 +1,34 @@
-//! @ @<Try to recover from mismatch...@>=
-//! begin if cur_group=math_shift_group then
-//!   begin scan_delimiter(garbage,false);
-//!   print_err("Extra "); print_esc("right");
-//! @.Extra \\right.@>
-//!   help1("I'm ignoring a \right that had no matching \left.");
-//!   error;
-//!   end
-//! else off_save;
-//! end
-//!
+//! ` `
+// @<Try to recover from mismatch...@>=
+pub(crate) macro Try_to_recover_from_mismatched_right($globals:expr) {{
+    // begin if cur_group=math_shift_group then
+    if $globals.cur_group == math_shift_group {
+        // begin scan_delimiter(garbage,false);
+        scan_delimiter($globals, garbage, false)?;
+        // print_err("Extra "); print_esc("right");
+        print_err!($globals, crate::strpool_str!("Extra "));
+        print_esc($globals, crate::strpool_str!("right"));
+        // @.Extra \\right.@>
+        // help1("I'm ignoring a \right that had no matching \left.");
+        help1!(
+            $globals,
+            crate::strpool_str!("I'm ignoring a \\right that had no matching \\left.")
+        );
+        // error;
+        error($globals)?;
+        // end
+    }
+    // else off_save;
+    else {
+        off_save($globals)?;
+    }
+    // end
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help1;
+    use crate::section_0082::error;
+    use crate::section_0162::garbage;
+    use crate::section_0269::math_shift_group;
+    use crate::section_1064::off_save;
+    use crate::section_1160::scan_delimiter;
+}}
@@ -9,7 +9,7 @@ pub(crate) macro Cases_of_main_control_that_build_boxes_and_lists_1193($globals:
         }
         // else off_save;
         else {
-            off_save($globals);
+            off_save($globals)?;
         }
         use crate::section_0269::math_shift_group;
         use crate::section_1064::off_save;
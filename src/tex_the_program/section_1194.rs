@@ -31,7 +31,7 @@ pub(crate) fn after_math(globals: &mut TeXGlobals) -> TeXResult<()> {
     l = false;
     /// this pops the nest
     const _: () = ();
-    p = fin_mlist(globals, null);
+    p = fin_mlist(globals, null)?;
     // if mode=-m then {end of equation number}
     if mode!(globals) == -m {
         /// end of equation number
@@ -63,7 +63,7 @@ pub(crate) fn after_math(globals: &mut TeXGlobals) -> TeXResult<()> {
         );
         // m:=mode; p:=fin_mlist(null);
         m = mode!(globals);
-        p = fin_mlist(globals, null);
+        p = fin_mlist(globals, null)?;
         // end
     }
     // else a:=null;
@@ -5,36 +5,66 @@ pub(crate) macro Check_that_the_necessary_fonts_for_math_symbols_are_present__if
     // if (font_params[fam_fnt(2+text_size)]<total_mathsy_params)or@|
     //    (font_params[fam_fnt(2+script_size)]<total_mathsy_params)or@|
     //    (font_params[fam_fnt(2+script_script_size)]<total_mathsy_params) then
-    if $globals.font_params[fam_fnt!($globals, 2 + text_size)] < total_mathsy_params
-        || $globals.font_params[fam_fnt!($globals, 2 + script_size)] < total_mathsy_params
-        || $globals.font_params[fam_fnt!($globals, 2 + script_script_size)] < total_mathsy_params
+    if $globals.font_params[fam_fnt!($globals, 2 + text_size)] < total_mathsy_params as u16
+        || $globals.font_params[fam_fnt!($globals, 2 + script_size)] < total_mathsy_params as u16
+        || $globals.font_params[fam_fnt!($globals, 2 + script_script_size)]
+            < total_mathsy_params as u16
     {
         // begin print_err("Math formula deleted: Insufficient symbol fonts");@/
+        print_err!(
+            $globals,
+            crate::strpool_str!("Math formula deleted: Insufficient symbol fonts")
+        );
         // @.Math formula deleted...@>
         // help3("Sorry, but I can't typeset math unless \textfont 2")@/
         //   ("and \scriptfont 2 and \scriptscriptfont 2 have all")@/
         //   ("the \fontdimen values needed in math symbol fonts.");
+        help3!(
+            $globals,
+            crate::strpool_str!("Sorry, but I can't typeset math unless \\textfont 2"),
+            crate::strpool_str!("and \\scriptfont 2 and \\scriptscriptfont 2 have all"),
+            crate::strpool_str!("the \\fontdimen values needed in math symbol fonts.")
+        );
         // error; flush_math; danger:=true;
+        error($globals)?;
+        flush_math($globals)?;
+        $danger = true;
         // end
-        todo!("Math formula deleted: Insufficient symbol fonts 1");
     }
     // else if (font_params[fam_fnt(3+text_size)]<total_mathex_params)or@|
     //    (font_params[fam_fnt(3+script_size)]<total_mathex_params)or@|
     //    (font_params[fam_fnt(3+script_script_size)]<total_mathex_params) then
-    else if $globals.font_params[fam_fnt!($globals, 3 + text_size)] < total_mathex_params
-        || $globals.font_params[fam_fnt!($globals, 3 + script_size)] < total_mathex_params
-        || $globals.font_params[fam_fnt!($globals, 3 + script_script_size)] < total_mathex_params
+    else if $globals.font_params[fam_fnt!($globals, 3 + text_size)] < total_mathex_params as u16
+        || $globals.font_params[fam_fnt!($globals, 3 + script_size)] < total_mathex_params as u16
+        || $globals.font_params[fam_fnt!($globals, 3 + script_script_size)]
+            < total_mathex_params as u16
     {
         // begin print_err("Math formula deleted: Insufficient extension fonts");@/
+        print_err!(
+            $globals,
+            crate::strpool_str!("Math formula deleted: Insufficient extension fonts")
+        );
         // help3("Sorry, but I can't typeset math unless \textfont 3")@/
         //   ("and \scriptfont 3 and \scriptscriptfont 3 have all")@/
         //   ("the \fontdimen values needed in math extension fonts.");
+        help3!(
+            $globals,
+            crate::strpool_str!("Sorry, but I can't typeset math unless \\textfont 3"),
+            crate::strpool_str!("and \\scriptfont 3 and \\scriptscriptfont 3 have all"),
+            crate::strpool_str!("the \\fontdimen values needed in math extension fonts.")
+        );
         // error; flush_math; danger:=true;
+        error($globals)?;
+        flush_math($globals)?;
+        $danger = true;
         // end
-        todo!("Math formula deleted: Insufficient symbol fonts 2");
     }
+    use crate::section_0073::print_err;
+    use crate::section_0079::help3;
+    use crate::section_0082::error;
     use crate::section_0230::fam_fnt;
     use crate::section_0699::*;
     use crate::section_0700::total_mathsy_params;
     use crate::section_0701::total_mathex_params;
+    use crate::section_0718::flush_math;
 }}
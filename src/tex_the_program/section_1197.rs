@@ -8,14 +8,26 @@ pub(crate) macro Check_that_another_dollar_follows($globals:expr) {{
     // if cur_cmd<>math_shift then
     if $globals.cur_cmd != math_shift {
         // begin print_err("Display math should end with $$");
+        print_err!(
+            $globals,
+            crate::strpool_str!("Display math should end with $$")
+        );
         // @.Display math...with \$\$@>
         // help2("The `$' that I just saw supposedly matches a previous `$$'.")@/
         //   ("So I shall assume that you typed `$$' both times.");
+        help2!(
+            $globals,
+            crate::strpool_str!("The `$' that I just saw supposedly matches a previous `$$'."),
+            crate::strpool_str!("So I shall assume that you typed `$$' both times.")
+        );
         // back_error;
+        back_error($globals)?;
         // end;
-        todo!("cur_cmd != math_shift");
     }
     // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0327::back_error;
 }}
 
 use crate::section_0207::math_shift;
@@ -64,13 +64,13 @@ pub(crate) macro Finish_displayed_math($globals:expr, $p:expr, $a:expr, $l:expr,
 
     // @<Determine the displacement, |d|, of the left edge of the equation, with
     //   respect to the line size |z|, assuming that |l=false|@>;
-    crate::section_1202::Determine_the_displacement__d__of_the_left_edge_of_the_equation__with_respect_to_the_line_size_z__assuming_that_l_is_false!($globals, d, z, w, e);
+    crate::section_1202::Determine_the_displacement__d__of_the_left_edge_of_the_equation__with_respect_to_the_line_size_z__assuming_that_l_is_false!($globals, d, z, w, e, $p);
     // @<Append the glue or equation number preceding the display@>;
-    crate::section_1203::Append_the_glue_or_equation_number_preceding_the_display!($globals, d, s, $l, e, g1, g2);
+    crate::section_1203::Append_the_glue_or_equation_number_preceding_the_display!($globals, $a, d, s, $l, e, g1, g2);
     // @<Append the display and perhaps also the equation number@>;
     crate::section_1204::Append_the_display_and_perhaps_also_the_equation_number!($globals, e, s, d, $a, b, z, w, $l);
     // @<Append the glue or equation number following the display@>;
-    crate::section_1205::Append_the_glue_or_equation_number_following_the_display!($globals, $a, e, $l, t, g2);
+    crate::section_1205::Append_the_glue_or_equation_number_following_the_display!($globals, $a, e, $l, s, z, t, g2);
     // resume_after_display
     resume_after_display($globals)?;
 
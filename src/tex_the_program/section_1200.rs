@@ -11,7 +11,7 @@ pub(crate) fn resume_after_display(globals: &mut TeXGlobals) -> TeXResult<()> {
     unsave(globals)?;
     prev_graf!(globals) += 3;
     // push_nest; mode:=hmode; space_factor:=1000; set_cur_lang; clang:=cur_lang;
-    push_nest(globals);
+    push_nest(globals)?;
     mode!(globals) = hmode.into();
     space_factor!(globals) = 1000;
     set_cur_lang!(globals);
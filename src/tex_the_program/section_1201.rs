@@ -11,9 +11,10 @@ pub(crate) macro Squeeze_the_equation_as_much_as_possible__if_there_is_an_equati
         ($globals.total_shrink[glue_ord::fill] != scaled::zero()) ||
         ($globals.total_shrink[glue_ord::filll] != scaled::zero())) {
         // begin free_node(b,box_node_size);
+        free_node($globals, $b, box_node_size as _);
         // b:=hpack(p,z-q,exactly);
+        $b = hpack($globals, $p, $z - $q, exactly.into())?;
         // end
-        todo!("e != 0");
     }
     // else  begin e:=0;
     else {
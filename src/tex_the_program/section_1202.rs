@@ -7,7 +7,7 @@
 //! user put glue there to control the spacing precisely.
 //
 // @<Determine the displacement, |d|, of the left edge of the equation...@>=
-pub(crate) macro Determine_the_displacement__d__of_the_left_edge_of_the_equation__with_respect_to_the_line_size_z__assuming_that_l_is_false($globals:expr, $d:expr, $z:expr, $w:expr, $e:expr) {{
+pub(crate) macro Determine_the_displacement__d__of_the_left_edge_of_the_equation__with_respect_to_the_line_size_z__assuming_that_l_is_false($globals:expr, $d:expr, $z:expr, $w:expr, $e:expr, $p:expr) {{
     // d:=half(z-w);
     $d = scaled::new_from_inner(half(($z - $w).inner()));
     // if (e>0)and(d<2*e) then {too close}
@@ -15,10 +15,17 @@ pub(crate) macro Determine_the_displacement__d__of_the_left_edge_of_the_equation
         /// too close
         const _ : () = ();
         // begin d:=half(z-w-e);
+        $d = scaled::new_from_inner(half(($z - $w - $e).inner()));
         // if p<>null then if not is_char_node(p) then if type(p)=glue_node then d:=0;
+        if $p != null && !is_char_node!($globals, $p) && r#type!($globals, $p) == glue_node {
+            $d = scaled::zero();
+        }
         // end
-        todo!("too close");
     }
     use crate::section_0100::half;
     use crate::section_0101::scaled;
+    use crate::section_0115::null;
+    use crate::section_0133::r#type;
+    use crate::section_0134::is_char_node;
+    use crate::section_0149::glue_node;
 }}
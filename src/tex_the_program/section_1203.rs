@@ -5,7 +5,7 @@
 //! `\.{\\parshape}' may specify them differently.
 //
 // @<Append the glue or equation number preceding the display@>=
-pub(crate) macro Append_the_glue_or_equation_number_preceding_the_display($globals:expr, $d:expr, $s:expr, $l:expr, $e:expr, $g1:expr, $g2:expr) {{
+pub(crate) macro Append_the_glue_or_equation_number_preceding_the_display($globals:expr, $a:expr, $d:expr, $s:expr, $l:expr, $e:expr, $g1:expr, $g2:expr) {{
     // tail_append(new_penalty(pre_display_penalty));@/
     tail_append!(
         $globals,
@@ -32,16 +32,20 @@ pub(crate) macro Append_the_glue_or_equation_number_preceding_the_display($globa
         /// it follows that `type(a)=hlist_node`
         const _ : () = ();
         // begin shift_amount(a):=s; append_to_vlist(a);
+        shift_amount!($globals, $a) = $s;
+        append_to_vlist($globals, $a)?;
         // tail_append(new_penalty(inf_penalty));
+        tail_append!($globals, new_penalty($globals, inf_penalty)?);
         // end
-        todo!("l and e == 0");
     }
     // else tail_append(new_param_glue(g1))
     else {
         tail_append!($globals, new_param_glue($globals, $g1.into())?);
     }
     use crate::section_0101::scaled;
+    use crate::section_0135::shift_amount;
     use crate::section_0152::new_param_glue;
+    use crate::section_0157::inf_penalty;
     use crate::section_0158::new_penalty;
     use crate::section_0214::tail_append;
     use crate::section_0224::above_display_short_skip_code;
@@ -50,4 +54,5 @@ pub(crate) macro Append_the_glue_or_equation_number_preceding_the_display($globa
     use crate::section_0224::below_display_skip_code;
     use crate::section_0236::pre_display_penalty;
     use crate::section_0247::pre_display_size;
+    use crate::section_0679::append_to_vlist;
 }}
@@ -1,16 +1,18 @@
 //! ` `
 
 // @<Append the glue or equation number following the display@>=
-pub(crate) macro Append_the_glue_or_equation_number_following_the_display($globals:expr, $a:expr, $e:expr, $l:expr, $t:expr, $g2:expr) {{
+pub(crate) macro Append_the_glue_or_equation_number_following_the_display($globals:expr, $a:expr, $e:expr, $l:expr, $s:expr, $z:expr, $t:expr, $g2:expr) {{
     // if (a<>null)and(e=0)and not l then
     if $a != null && $e == scaled::zero() && !$l {
         // begin tail_append(new_penalty(inf_penalty));
+        tail_append!($globals, new_penalty($globals, inf_penalty)?);
         // shift_amount(a):=s+z-width(a);
+        shift_amount!($globals, $a) = $s + $z - width!($globals, $a);
         // append_to_vlist(a);
+        append_to_vlist($globals, $a)?;
         // g2:=0;
         $g2 = 0;
         // end;
-        todo!("a<>null && e == 0 && !l");
     }
     // if t<>adjust_head then {migrating material comes after equation number}
     if $t != adjust_head {
@@ -30,10 +32,14 @@ pub(crate) macro Append_the_glue_or_equation_number_following_the_display($globa
     use crate::section_0101::scaled;
     use crate::section_0115::null;
     use crate::section_0118::link;
+    use crate::section_0135::shift_amount;
+    use crate::section_0135::width;
     use crate::section_0152::new_param_glue;
+    use crate::section_0157::inf_penalty;
     use crate::section_0158::new_penalty;
     use crate::section_0162::adjust_head;
     use crate::section_0213::tail;
     use crate::section_0214::tail_append;
     use crate::section_0236::post_display_penalty;
+    use crate::section_0679::append_to_vlist;
 }}
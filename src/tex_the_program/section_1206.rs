@@ -9,7 +9,7 @@ pub(crate) macro Finish_an_alignment_in_a_display($globals:expr, $p:expr, $q:exp
     do_assignments($globals)?;
     // if cur_cmd<>math_shift then @<Pontificate about improper alignment in display@>
     if $globals.cur_cmd != math_shift {
-        todo!("Pontificate");
+        crate::section_1207::Pontificate_about_improper_alignment_in_display!($globals);
     }
     // else @<Check that another \.\$ follows@>;
     else {
@@ -1,8 +1,21 @@
-//! @ @<Pontificate...@>=
-//! begin print_err("Missing $$ inserted");
-//! @.Missing {\$\$} inserted@>
-//! help2("Displays can use special alignments (like \eqalignno)")@/
-//!   ("only if nothing but the alignment itself is between $$'s.");
-//! back_error;
-//! end
-//!
+//! ` `
+
+// @<Pontificate...@>=
+pub(crate) macro Pontificate_about_improper_alignment_in_display($globals:expr) {{
+    // begin print_err("Missing $$ inserted");
+    print_err!($globals, crate::strpool_str!("Missing $$ inserted"));
+    // @.Missing {\$\$} inserted@>
+    // help2("Displays can use special alignments (like \eqalignno)")@/
+    //   ("only if nothing but the alignment itself is between $$'s.");
+    help2!(
+        $globals,
+        crate::strpool_str!("Displays can use special alignments (like \\eqalignno)"),
+        crate::strpool_str!("only if nothing but the alignment itself is between $$'s.")
+    );
+    // back_error;
+    back_error($globals)?;
+    // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0327::back_error;
+}}
@@ -16,25 +16,25 @@
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1208($globals:expr) {{
     let globals = &mut *$globals;
     // primitive("long",prefix,1);
-    primitive(globals, crate::strpool_str!("long"), prefix, 1);
+    primitive(globals, crate::strpool_str!("long"), prefix, 1)?;
     // @!@:long_}{\.{\\long} primitive@>
     // primitive("outer",prefix,2);
-    primitive(globals, crate::strpool_str!("outer"), prefix, 2);
+    primitive(globals, crate::strpool_str!("outer"), prefix, 2)?;
     // @!@:outer_}{\.{\\outer} primitive@>
     // primitive("global",prefix,4);
-    primitive(globals, crate::strpool_str!("global"), prefix, 4);
+    primitive(globals, crate::strpool_str!("global"), prefix, 4)?;
     // @!@:global_}{\.{\\global} primitive@>
     // primitive("def",def,0);
-    primitive(globals, crate::strpool_str!("def"), def, 0);
+    primitive(globals, crate::strpool_str!("def"), def, 0)?;
     // @!@:def_}{\.{\\def} primitive@>
     // primitive("gdef",def,1);
-    primitive(globals, crate::strpool_str!("gdef"), def, 1);
+    primitive(globals, crate::strpool_str!("gdef"), def, 1)?;
     // @!@:gdef_}{\.{\\gdef} primitive@>
     // primitive("edef",def,2);
-    primitive(globals, crate::strpool_str!("edef"), def, 2);
+    primitive(globals, crate::strpool_str!("edef"), def, 2)?;
     // @!@:edef_}{\.{\\edef} primitive@>
     // primitive("xdef",def,3);
-    primitive(globals, crate::strpool_str!("xdef"), def, 3);
+    primitive(globals, crate::strpool_str!("xdef"), def, 3)?;
     // @!@:xdef_}{\.{\\xdef} primitive@>
 }}
 
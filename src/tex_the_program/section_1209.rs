@@ -13,6 +13,14 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_1209
         }
         // else print_esc("global");
         else {
+            // TeX-rs: `\protected` of `latex_support` shares this command code
+            #[cfg(feature = "latex_support")]
+            if $chr_code.get() == crate::latex_support::protected_code {
+                print_esc($globals, crate::strpool_str!("protected"));
+            } else {
+                print_esc($globals, crate::strpool_str!("global"));
+            }
+            #[cfg(not(feature = "latex_support"))]
             print_esc($globals, crate::strpool_str!("global"));
         }
         true
@@ -1,15 +1,15 @@
 //! @ If the user says, e.g., `\.{\\global\\global}', the redundancy is
 //! silently accepted.
 
-pub(crate) macro Assignments($globals:expr, $cur_cmd:expr, $a:expr, $lbl_done:lifetime) {{
+pub(crate) macro Assignments($globals:expr, $cur_cmd:expr, $a:expr, $j:expr, $lbl_done:lifetime) {{
     crate::trace_span_verbose!("Assignments");
     let processed = false
         || crate::section_1217::Assignments_1217!($globals, $cur_cmd, $a)
-        || crate::section_1218::Assignments_1218!($globals, $cur_cmd, $a)
+        || crate::section_1218::Assignments_1218!($globals, $cur_cmd, $a, $j)
         || crate::section_1221::Assignments_1221!($globals, $cur_cmd, $a)
         || crate::section_1224::Assignments_1224!($globals, $cur_cmd, $a)
         || crate::section_1225::Assignments_1225!($globals, $cur_cmd, $a)
-        || crate::section_1226::Assignments_1226!($globals, $cur_cmd, $a)
+        || crate::section_1226::Assignments_1226!($globals, $cur_cmd, $a, $lbl_done)
         || crate::section_1228::Assignments_1228!($globals, $cur_cmd, $a)
         || crate::section_1232::Assignments_1232!($globals, $cur_cmd, $a)
         || crate::section_1234::Assignments_1234!($globals, $cur_cmd, $a)
@@ -43,6 +43,8 @@ pub(crate) fn prefixed_command(globals: &mut TeXGlobals) -> TeXResult<()> {
     // @!e:boolean; {should a definition be expanded? or was \.{\\let} not done?}
     // begin a:=0;
     let mut a = 0;
+    // TeX-rs: the `\protected` prefix of `latex_support` is split off into `j`
+    let j: cur_tok_repr;
     // while cur_cmd=prefix do
     while globals.cur_cmd == prefix {
         //   begin if not odd(a div cur_chr) then a:=a+cur_chr;
@@ -61,7 +63,7 @@ pub(crate) fn prefixed_command(globals: &mut TeXGlobals) -> TeXResult<()> {
     }
     crate::trace_expr_verbose!("cur_cmd={}", globals.cur_cmd);
     // @<Discard the prefixes \.{\\long} and \.{\\outer} if they are irrelevant@>;
-    crate::section_1213::Discard_the_prefixes_long_and_outer_if_they_are_irrelevant!(globals, a);
+    crate::section_1213::Discard_the_prefixes_long_and_outer_if_they_are_irrelevant!(globals, a, j);
     crate::region_forward_label!(
     |'done|
     {
@@ -69,7 +71,7 @@ pub(crate) fn prefixed_command(globals: &mut TeXGlobals) -> TeXResult<()> {
     crate::section_1214::Adjust_f_for_the_setting_of_globaldefs!(globals, a);
     // case cur_cmd of
     // @t\4@>@<Assignments@>@;
-    if Assignments!(globals, globals.cur_cmd, a, 'done) {
+    if Assignments!(globals, globals.cur_cmd, a, j, 'done) {
         /// already processed
         do_nothing!();
     }
@@ -97,5 +99,6 @@ use crate::section_0095::confusion;
 use crate::section_0208::max_non_prefixed_command;
 use crate::section_0209::*;
 use crate::section_0210::*;
+use crate::section_0297::cur_tok_repr;
 use crate::section_0405::scan_optional_equals;
 use crate::section_0440::scan_int;
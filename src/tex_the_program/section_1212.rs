@@ -19,6 +19,16 @@ pub(crate) macro Discard_erroneous_prefixes_and_return($globals:expr) {{
         $globals,
         crate::strpool_str!("I'll pretend you didn't say \\long or \\outer or \\global.")
     );
+    // TeX-rs: `\protected` of `latex_support` is a prefix as well
+    #[cfg(feature = "latex_support")]
+    if $globals.latex_support_enabled {
+        help1!(
+            $globals,
+            crate::strpool_str!(
+                "I'll pretend you didn't say \\long or \\outer or \\global or \\protected."
+            )
+        );
+    }
     // back_error; return;
     back_error($globals)?;
     crate::return_nojump!();
@@ -1,17 +1,63 @@
 //! ` `
 
 // @<Discard the prefixes...@>=
-pub(crate) macro Discard_the_prefixes_long_and_outer_if_they_are_irrelevant($globals:expr, $a:expr) {{
+pub(crate) macro Discard_the_prefixes_long_and_outer_if_they_are_irrelevant($globals:expr, $a:expr, $j:expr) {{
+    // TeX-rs: the `\protected` prefix of `latex_support` is split off into `j`
+    #[cfg(feature = "latex_support")]
+    crate::latex_support::Split_off_the_protected_prefix!($a, $j);
+    #[cfg(not(feature = "latex_support"))]
+    {
+        $j = 0;
+    }
     // if (cur_cmd<>def)and(a mod 4<>0) then
-    if $globals.cur_cmd != def && $a % 4 != 0 {
-        todo!("discard the prefixes");
+    if $globals.cur_cmd != def && ($a % 4 != 0 || $j != 0) {
         // begin print_err("You can't use `"); print_esc("long"); print("' or `");
+        print_err!($globals, crate::strpool_str!("You can't use `"));
+        print_esc($globals, crate::strpool_str!("long"));
+        print($globals, crate::strpool_str!("' or `").get() as _);
         // print_esc("outer"); print("' with `");
+        print_esc($globals, crate::strpool_str!("outer"));
+        // TeX-rs: `\protected` of `latex_support` is a prefix as well
+        #[cfg(feature = "latex_support")]
+        if $globals.latex_support_enabled {
+            print($globals, crate::strpool_str!("' or `").get() as _);
+            print_esc($globals, crate::strpool_str!("protected"));
+        }
+        print($globals, crate::strpool_str!("' with `").get() as _);
         // @.You can't use \\long...@>
         // print_cmd_chr(cur_cmd,cur_chr); print_char("'");
+        print_cmd_chr($globals, $globals.cur_cmd, $globals.cur_chr);
+        print_char(
+            make_globals_io_string_log_view!($globals),
+            ASCII_code_literal!(b'\''),
+        );
         // help1("I'll pretend you didn't say \long or \outer here.");
+        help1!(
+            $globals,
+            crate::strpool_str!("I'll pretend you didn't say \\long or \\outer here.")
+        );
+        #[cfg(feature = "latex_support")]
+        if $globals.latex_support_enabled {
+            help1!(
+                $globals,
+                crate::strpool_str!(
+                    "I'll pretend you didn't say \\long or \\outer or \\protected here."
+                )
+            );
+        }
         // error;
+        error($globals)?;
         // end
     }
+    use crate::section_0004::make_globals_io_string_log_view;
+    use crate::section_0004::TeXGlobalsIoStringLogView;
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0058::print_char;
+    use crate::section_0059::print;
+    use crate::section_0063::print_esc;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help1;
+    use crate::section_0082::error;
     use crate::section_0209::def;
+    use crate::section_0298::print_cmd_chr;
 }}
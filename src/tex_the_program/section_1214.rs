@@ -20,10 +20,10 @@ pub(crate) macro define($globals:expr, $a:expr, $p:expr, $t:expr, $v:expr) {
 pub(crate) macro word_define($globals:expr, $a:expr, $p:expr, $v:expr) {
     if global!($a) {
         use crate::section_0279::geq_word_define;
-        geq_word_define($globals, $p, $v)
+        geq_word_define($globals, $p, $v);
     } else {
         use crate::section_0278::eq_word_define;
-        eq_word_define($globals, $p, $v)
+        eq_word_define($globals, $p, $v)?;
     }
 }
 
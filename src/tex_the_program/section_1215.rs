@@ -4,7 +4,6 @@
 //
 // @<Declare subprocedures for |prefixed_command|@>=
 // procedure get_r_token;
-#[allow(unused_variables)]
 #[cfg_attr(
     feature = "trace_verbose",
     tracing::instrument(level = "trace", skip(globals))
@@ -12,36 +11,65 @@
 pub(crate) fn get_r_token(globals: &mut TeXGlobals) -> TeXResult<()> {
     // label restart;
     crate::trace_expr_verbose!("cur_cs = {}", globals.cur_cs);
-    // begin restart: repeat get_token;
-    loop {
-        get_token(globals)?;
-        // until cur_tok<>space_token;
-        if globals.cur_tok != space_token {
-            break;
+    crate::region_backward_label! {
+        'restart <-
+        {
+            // begin restart: repeat get_token;
+            loop {
+                get_token(globals)?;
+                // until cur_tok<>space_token;
+                if globals.cur_tok != space_token {
+                    break;
+                }
+            }
+            // if (cur_cs=0)or(cur_cs>frozen_control_sequence) then
+            if globals.cur_cs == 0 || globals.cur_cs > frozen_control_sequence as _ {
+                crate::trace_expr_verbose!("cur_cs = {}", globals.cur_cs);
+                //   begin print_err("Missing control sequence inserted");
+                print_err!(
+                    globals,
+                    crate::strpool_str!("Missing control sequence inserted")
+                );
+                // @.Missing control...@>
+                //   help5("Please don't say `\def cs{...}', say `\def\cs{...}'.")@/
+                //   ("I've inserted an inaccessible control sequence so that your")@/
+                //   ("definition will be completed without mixing me up too badly.")@/
+                //   ("You can recover graciously from this error, if you're")@/
+                //   ("careful; see exercise 27.2 in The TeXbook.");
+                help5!(
+                    globals,
+                    crate::strpool_str!("Please don't say `\\def cs{...}', say `\\def\\cs{...}'."),
+                    crate::strpool_str!("I've inserted an inaccessible control sequence so that your"),
+                    crate::strpool_str!("definition will be completed without mixing me up too badly."),
+                    crate::strpool_str!("You can recover graciously from this error, if you're"),
+                    crate::strpool_str!("careful; see exercise 27.2 in The TeXbook.")
+                );
+                // @:TeXbook}{\sl The \TeX book@>
+                //   if cur_cs=0 then back_input;
+                if globals.cur_cs == 0 {
+                    back_input(globals)?;
+                }
+                //   cur_tok:=cs_token_flag+frozen_protection; ins_error; goto restart;
+                globals.cur_tok = cur_tok_type::from_cs(frozen_protection as _);
+                ins_error(globals)?;
+                crate::goto_backward_label!('restart);
+                //   end;
+            }
         }
-    }
-    // if (cur_cs=0)or(cur_cs>frozen_control_sequence) then
-    if globals.cur_cs == 0 || globals.cur_cs > frozen_control_sequence as _ {
-        crate::trace_expr_verbose!("cur_cs = {}", globals.cur_cs);
-        todo!("not yet implemented in {}", file!());
-        //   begin print_err("Missing control sequence inserted");
-        // @.Missing control...@>
-        //   help5("Please don't say `\def cs{...}', say `\def\cs{...}'.")@/
-        //   ("I've inserted an inaccessible control sequence so that your")@/
-        //   ("definition will be completed without mixing me up too badly.")@/
-        //   ("You can recover graciously from this error, if you're")@/
-        //   ("careful; see exercise 27.2 in The TeXbook.");
-        // @:TeXbook}{\sl The \TeX book@>
-        //   if cur_cs=0 then back_input;
-        //   cur_tok:=cs_token_flag+frozen_protection; ins_error; goto restart;
-        //   end;
+        |'restart|
     }
     // end;
     crate::ok_nojump!()
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0073::print_err;
+use crate::section_0079::help5;
 use crate::section_0081::TeXResult;
 use crate::section_0222::frozen_control_sequence;
+use crate::section_0222::frozen_protection;
 use crate::section_0289::space_token;
+use crate::section_0297::cur_tok_type;
+use crate::section_0325::back_input;
+use crate::section_0327::ins_error;
 use crate::section_0365::get_token;
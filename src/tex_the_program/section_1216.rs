@@ -1,4 +1,12 @@
 //! @ @<Initialize table entries...@>=
-//! text(frozen_protection):="inaccessible";
-//! @.inaccessible@>
-//!
+// text(frozen_protection):="inaccessible";
+pub(crate) macro Initialize_table_entries_done_by_initex_only_1216($globals:expr) {{
+    let globals = &mut *$globals;
+    text!(globals, frozen_protection as pointer) = crate::strpool_str!("inaccessible").get() as _;
+}}
+// @.inaccessible@>
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0115::pointer;
+use crate::section_0222::frozen_protection;
+use crate::section_0256::text;
@@ -3,7 +3,7 @@
 //! |cur_chr>=2| if the definition is supposed to be expanded.
 
 // @<Assignments@>=
-pub(crate) macro Assignments_1218($globals:expr, $cur_cmd:expr, $a:expr) {{
+pub(crate) macro Assignments_1218($globals:expr, $cur_cmd:expr, $a:expr, $j:expr) {{
     // def: begin if odd(cur_chr)and not global and(global_defs>=0) then a:=a+4;
     let processed = if $cur_cmd == def {
         crate::trace_span!("Assignments(def)");
@@ -16,6 +16,11 @@ pub(crate) macro Assignments_1218($globals:expr, $cur_cmd:expr, $a:expr) {{
         let p = $globals.cur_cs;
         // q:=scan_toks(true,e); define(p,call+(a mod 4),def_ref);
         let q = scan_toks($globals, true, e);
+        // TeX-rs: mark the text of a `\protected` macro of `latex_support`
+        #[cfg(feature = "latex_support")]
+        if $j != 0 {
+            crate::latex_support::Mark_the_macro_as_protected!($globals, $j);
+        }
         define!(
             $globals,
             $a,
@@ -10,7 +10,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1219($globals
         crate::strpool_str!("let"),
         r#let,
         let_kind::normal as _,
-    );
+    )?;
     // @!@:let_}{\.{\\let} primitive@>
     // primitive("futurelet",let,normal+1);@/
     primitive(
@@ -18,7 +18,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1219($globals
         crate::strpool_str!("futurelet"),
         r#let,
         let_kind::futurelet as _,
-    );
+    )?;
     // @!@:future_let_}{\.{\\futurelet} primitive@>
 }}
 
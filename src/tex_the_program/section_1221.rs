@@ -41,19 +41,29 @@ pub(crate) macro Assignments_1221($globals:expr, $cur_cmd:expr, $a:expr) {{
             get_token($globals)?;
             q = $globals.cur_tok;
             get_token($globals)?;
-            back_input($globals);
+            back_input($globals)?;
             // cur_tok:=q; back_input; {look ahead, then back up}
             $globals.cur_tok = q;
-            back_input($globals);
+            back_input($globals)?;
             /// look ahead, then back up
             const _: () = ();
             // end; {note that |back_input| doesn't affect |cur_cmd|, |cur_chr|}
             /// note that `back_input` doesn't affect `cur_cmd`, `cur_chr`
             const _: () = ();
         }
+        // TeX-rs: a `register` or `toks_register` defined by `\countdef` and so on
+        // may refer to a sparse array element of `latex_support`, which counts its references
+        #[cfg(feature = "latex_support")]
+        let sa_element = ($globals.cur_cmd == register || $globals.cur_cmd == toks_register)
+            && crate::latex_support::is_sa_element($globals.cur_chr.get());
+        #[cfg(not(feature = "latex_support"))]
+        let sa_element = false;
         // if cur_cmd>=call then add_token_ref(cur_chr);
         if $globals.cur_cmd >= call {
             add_token_ref!($globals, $globals.cur_chr.get() as pointer);
+        } else if sa_element {
+            #[cfg(feature = "latex_support")]
+            crate::latex_support::add_sa_ref($globals, $globals.cur_chr.get() as pointer);
         }
         // define(p,cur_cmd,cur_chr);
         define!(
@@ -37,7 +37,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1222($globals
         crate::strpool_str!("chardef"),
         shorthand_def,
         char_def_code,
-    );
+    )?;
     // @!@:char_def_}{\.{\\chardef} primitive@>
     // primitive("mathchardef",shorthand_def,math_char_def_code);@/
     primitive(
@@ -45,7 +45,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1222($globals
         crate::strpool_str!("mathchardef"),
         shorthand_def,
         math_char_def_code,
-    );
+    )?;
     // @!@:math_char_def_}{\.{\\mathchardef} primitive@>
     // primitive("countdef",shorthand_def,count_def_code);@/
     primitive(
@@ -53,7 +53,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1222($globals
         crate::strpool_str!("countdef"),
         shorthand_def,
         count_def_code,
-    );
+    )?;
     // @!@:count_def_}{\.{\\countdef} primitive@>
     // primitive("dimendef",shorthand_def,dimen_def_code);@/
     primitive(
@@ -61,7 +61,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1222($globals
         crate::strpool_str!("dimendef"),
         shorthand_def,
         dimen_def_code,
-    );
+    )?;
     // @!@:dimen_def_}{\.{\\dimendef} primitive@>
     // primitive("skipdef",shorthand_def,skip_def_code);@/
     primitive(
@@ -69,7 +69,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1222($globals
         crate::strpool_str!("skipdef"),
         shorthand_def,
         skip_def_code,
-    );
+    )?;
     // @!@:skip_def_}{\.{\\skipdef} primitive@>
     // primitive("muskipdef",shorthand_def,mu_skip_def_code);@/
     primitive(
@@ -77,7 +77,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1222($globals
         crate::strpool_str!("muskipdef"),
         shorthand_def,
         mu_skip_def_code,
-    );
+    )?;
     // @!@:mu_skip_def_}{\.{\\muskipdef} primitive@>
     // primitive("toksdef",shorthand_def,toks_def_code);@/
     primitive(
@@ -85,7 +85,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1222($globals
         crate::strpool_str!("toksdef"),
         shorthand_def,
         toks_def_code,
-    );
+    )?;
     // @!@:toks_def_}{\.{\\toksdef} primitive@>
 }}
 
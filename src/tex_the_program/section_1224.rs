@@ -28,10 +28,24 @@ pub(crate) macro Assignments_1224($globals:expr, $cur_cmd:expr, $a:expr) {{
         }
         // othercases begin scan_eight_bit_int;
         else {
-            scan_eight_bit_int($globals)?;
+            // TeX-rs: `latex_support` has registers up to 32767; those above 255
+            // are elements of sparse arrays
+            #[cfg(feature = "latex_support")]
+            let processed = {
+                crate::latex_support::scan_register_num($globals)?;
+                crate::latex_support::define_sa_register($globals, $a, p as _, n)?
+            };
+            #[cfg(not(feature = "latex_support"))]
+            let processed = {
+                scan_eight_bit_int($globals)?;
+                false
+            };
+            if processed {
+                // already defined
+            }
             //   case n of
             //   count_def_code: define(p,assign_int,count_base+cur_val);
-            if n == count_def_code as chr_code_repr {
+            else if n == count_def_code as chr_code_repr {
                 define!(
                     $globals,
                     $a,
@@ -100,6 +114,7 @@ pub(crate) macro Assignments_1224($globals:expr, $cur_cmd:expr, $a:expr) {{
         use crate::section_0247::scaled_base;
         use crate::section_0297::chr_code_repr;
         use crate::section_0405::scan_optional_equals;
+        #[cfg(not(feature = "latex_support"))]
         use crate::section_0433::scan_eight_bit_int;
         use crate::section_0434::scan_char_num;
         use crate::section_0436::scan_fifteen_bit_int;
@@ -8,24 +8,32 @@ pub(crate) macro Assignments_1225($globals:expr, $cur_cmd:expr, $a:expr) {{
         let p: pointer;
         /// for temporary short-term use
         let n: integer;
+        // TeX-rs: `j` tells `\readline` of `latex_support` from `\read`
+        let j = $globals.cur_chr.get();
 
         scan_int($globals)?;
         n = $globals.cur_val;
         // if not scan_keyword("to") then
         if !scan_keyword($globals, crate::strpool_str!("to"))? {
-            todo!("error");
             // @.to@>
             //   begin print_err("Missing `to' inserted");
+            print_err!($globals, crate::strpool_str!("Missing `to' inserted"));
             // @.Missing `to'...@>
             //   help2("You should have said `\read<number> to \cs'.")@/
             //   ("I'm going to look for the \cs now."); error;
+            help2!(
+                $globals,
+                crate::strpool_str!("You should have said `\\read<number> to \\cs'."),
+                crate::strpool_str!("I'm going to look for the \\cs now.")
+            );
+            error($globals)?;
             //   end;
         }
         // get_r_token;
         get_r_token($globals)?;
         // p:=cur_cs; read_toks(n,p); define(p,call,cur_val);
         p = $globals.cur_cs;
-        read_toks($globals, n, p)?;
+        read_toks($globals, n, p, j)?;
         define!($globals, $a, p, call, $globals.cur_val as _);
         // end;
         true
@@ -33,6 +41,9 @@ pub(crate) macro Assignments_1225($globals:expr, $cur_cmd:expr, $a:expr) {{
         false
     };
     use crate::pascal::integer;
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
     use crate::section_0115::pointer;
     use crate::section_0209::*;
     use crate::section_0210::*;
@@ -3,24 +3,55 @@
 //! enclosing pair of braces around an \.{\\output} list.)
 //
 // @<Assignments@>=
-pub(crate) macro Assignments_1226($globals:expr, $cur_cmd:expr, $a:expr) {{
+pub(crate) macro Assignments_1226($globals:expr, $cur_cmd:expr, $a:expr, $lbl_done:lifetime) {{
     // toks_register,assign_toks: begin q:=cur_cs;
     let processed = if $cur_cmd == toks_register || $cur_cmd == assign_toks {
         /// for temporary short-term use
         let (p, mut q): (pointer, pointer);
+        /// TeX-rs: does `p` refer to a sparse array element of `latex_support`?
+        let e: boolean;
 
         q = $globals.cur_cs;
         // if cur_cmd=toks_register then
         if $cur_cmd == toks_register {
-            // begin scan_eight_bit_int; p:=toks_base+cur_val;
-            todo!("toks_register");
-            // end
+            // TeX-rs: `latex_support` has `\toks` registers up to 32767
+            #[cfg(feature = "latex_support")]
+            {
+                if crate::latex_support::is_sa_element($globals.cur_chr.get()) {
+                    p = $globals.cur_chr.get() as pointer;
+                    e = true;
+                } else {
+                    crate::latex_support::scan_register_num($globals)?;
+                    if $globals.cur_val > 255 {
+                        crate::latex_support::find_sa_element(
+                            $globals,
+                            cur_val_level_kind::tok_val as _,
+                            $globals.cur_val,
+                            true,
+                        )?;
+                        p = $globals.cur_ptr;
+                        e = true;
+                    } else {
+                        p = toks_base as pointer + $globals.cur_val as pointer;
+                        e = false;
+                    }
+                }
+            }
+            #[cfg(not(feature = "latex_support"))]
+            {
+                // begin scan_eight_bit_int; p:=toks_base+cur_val;
+                scan_eight_bit_int($globals)?;
+                p = toks_base as pointer + $globals.cur_val as pointer;
+                e = false;
+                // end
+            }
         }
         // else p:=cur_chr; {|p=every_par_loc| or |output_routine_loc| or \dots}
         else {
             /// `p = every_par_loc` or `output_routine_loc` or `...`
             const _: () = ();
             p = $globals.cur_chr.get() as pointer;
+            e = false;
         }
         // scan_optional_equals;
         scan_optional_equals($globals)?;
@@ -28,11 +59,13 @@ pub(crate) macro Assignments_1226($globals:expr, $cur_cmd:expr, $a:expr) {{
         crate::section_0404::Get_the_next_non_blank_non_relax_non_call_token!($globals);
         // if cur_cmd<>left_brace then @<If the right-hand side is a token parameter
         //     or token register, finish the assignment and |goto done|@>;
-        if $cur_cmd != left_brace {
-            todo!("if the right-hand side");
+        if $globals.cur_cmd != left_brace {
+            crate::section_1227::If_the_right_hand_side_is_a_token_parameter_or_token_register__finish_the_assignment_and_goto_done!(
+                $globals, $a, p, e, $lbl_done
+            );
         }
         // back_input; cur_cs:=q; q:=scan_toks(false,false);
-        back_input($globals);
+        back_input($globals)?;
         $globals.cur_cs = q;
         q = scan_toks($globals, false, false)?;
         // if link(def_ref)=null then {empty list: revert to the default}
@@ -40,17 +73,21 @@ pub(crate) macro Assignments_1226($globals:expr, $cur_cmd:expr, $a:expr) {{
             /// empty list: revert to the default
             const _: () = ();
             // begin define(p,undefined_cs,null); free_avail(def_ref);
+            #[cfg(feature = "latex_support")]
+            crate::latex_support::sa_define!($globals, $a, e, p, undefined_cs, null);
+            #[cfg(not(feature = "latex_support"))]
             define!($globals, $a, p, undefined_cs, null);
             free_avail!($globals, $globals.def_ref);
             // end
         }
         // else  begin if p=output_routine_loc then {enclose in curlies}
         else {
-            if p == output_routine_loc {
+            // TeX-rs: a sparse array element `p` is never `output_routine_loc`
+            if p == output_routine_loc && !e {
                 /// enclose in curlies
                 const _: () = ();
                 // begin link(q):=get_avail; q:=link(q);
-                link!($globals, q) = get_avail($globals);
+                link!($globals, q) = get_avail($globals)?;
                 q = link!($globals, q);
                 // info(q):=right_brace_token+"}";
                 info_tok_assign!(
@@ -59,7 +96,7 @@ pub(crate) macro Assignments_1226($globals:expr, $cur_cmd:expr, $a:expr) {{
                     cur_tok_type::new(right_brace_token + b'}' as cur_tok_repr)
                 );
                 // q:=get_avail; info(q):=left_brace_token+"{";
-                q = get_avail($globals);
+                q = get_avail($globals)?;
                 info_tok_assign!(
                     $globals,
                     q,
@@ -71,6 +108,9 @@ pub(crate) macro Assignments_1226($globals:expr, $cur_cmd:expr, $a:expr) {{
                 // end;
             }
             // define(p,call,def_ref);
+            #[cfg(feature = "latex_support")]
+            crate::latex_support::sa_define!($globals, $a, e, p, call, $globals.def_ref);
+            #[cfg(not(feature = "latex_support"))]
             define!($globals, $a, p, call, $globals.def_ref);
             // end;
         }
@@ -79,6 +119,7 @@ pub(crate) macro Assignments_1226($globals:expr, $cur_cmd:expr, $a:expr) {{
         false
     };
     // end;
+    use crate::pascal::boolean;
     use crate::section_0115::null;
     use crate::section_0115::pointer;
     use crate::section_0118::info_tok_assign;
@@ -89,13 +130,19 @@ pub(crate) macro Assignments_1226($globals:expr, $cur_cmd:expr, $a:expr) {{
     use crate::section_0209::*;
     use crate::section_0210::*;
     use crate::section_0230::output_routine_loc;
+    use crate::section_0230::toks_base;
     use crate::section_0289::left_brace_token;
     use crate::section_0289::right_brace_token;
     use crate::section_0297::cur_tok_repr;
     use crate::section_0297::cur_tok_type;
     use crate::section_0325::back_input;
     use crate::section_0405::scan_optional_equals;
+    #[cfg(feature = "latex_support")]
+    use crate::section_0410::cur_val_level_kind;
+    #[cfg(not(feature = "latex_support"))]
+    use crate::section_0433::scan_eight_bit_int;
     use crate::section_0473::scan_toks;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_1214::define;
     processed
 }}
@@ -1,13 +1,67 @@
-//! @ @<If the right-hand side is a token parameter...@>=
-//! begin if cur_cmd=toks_register then
-//!   begin scan_eight_bit_int; cur_cmd:=assign_toks; cur_chr:=toks_base+cur_val;
-//!   end;
-//! if cur_cmd=assign_toks then
-//!   begin q:=equiv(cur_chr);
-//!   if q=null then define(p,undefined_cs,null)
-//!   else  begin add_token_ref(q); define(p,call,q);
-//!     end;
-//!   goto done;
-//!   end;
-//! end
-//!
+//! ` `
+
+// @<If the right-hand side is a token parameter...@>=
+pub(crate) macro If_the_right_hand_side_is_a_token_parameter_or_token_register__finish_the_assignment_and_goto_done($globals:expr, $a:expr, $p:expr, $e:expr, $lbl_done:lifetime) {{
+    /// the token list on the right-hand side
+    let q: pointer;
+    // begin if cur_cmd=toks_register then
+    // TeX-rs: both cases are handled together, so that `latex_support` can fetch
+    // a `\toks` register above 255 from its sparse array
+    if $globals.cur_cmd == toks_register || $globals.cur_cmd == assign_toks {
+        if $globals.cur_cmd == toks_register {
+            #[cfg(feature = "latex_support")]
+            {
+                q = crate::latex_support::fetch_toks_register($globals, $globals.cur_chr.get())?;
+            }
+            #[cfg(not(feature = "latex_support"))]
+            {
+                //   begin scan_eight_bit_int; cur_cmd:=assign_toks; cur_chr:=toks_base+cur_val;
+                scan_eight_bit_int($globals)?;
+                q = equiv!($globals, toks_base as pointer + $globals.cur_val as pointer);
+                //   end;
+            }
+        }
+        // if cur_cmd=assign_toks then
+        //   begin q:=equiv(cur_chr);
+        else {
+            q = equiv!($globals, $globals.cur_chr.get() as pointer);
+        }
+        //   if q=null then define(p,undefined_cs,null)
+        if q == null {
+            #[cfg(feature = "latex_support")]
+            crate::latex_support::sa_define!($globals, $a, $e, $p, undefined_cs, null);
+            #[cfg(not(feature = "latex_support"))]
+            define!($globals, $a, $p, undefined_cs, null);
+        }
+        //   else  begin add_token_ref(q); define(p,call,q);
+        //     end;
+        else {
+            add_token_ref!($globals, q);
+            #[cfg(feature = "latex_support")]
+            crate::latex_support::sa_define!($globals, $a, $e, $p, call, q);
+            #[cfg(not(feature = "latex_support"))]
+            define!($globals, $a, $p, call, q);
+        }
+        //   goto done;
+        crate::goto_forward_label!($lbl_done);
+        //   end;
+    }
+    // end
+    use crate::section_0115::null;
+    use crate::section_0115::pointer;
+    use crate::section_0203::add_token_ref;
+    use crate::section_0209::assign_toks;
+    use crate::section_0209::toks_register;
+    use crate::section_0210::call;
+    use crate::section_0210::undefined_cs;
+    use crate::section_0221::equiv;
+    #[cfg(not(feature = "latex_support"))]
+    use crate::section_0230::toks_base;
+    #[cfg(not(feature = "latex_support"))]
+    use crate::section_0433::scan_eight_bit_int;
+    #[cfg(not(feature = "latex_support"))]
+    use crate::section_1214::define;
+}}
+
+// @ If the user says, e.g., `\.{\\global\\global}', the redundancy is
+// silently accepted.
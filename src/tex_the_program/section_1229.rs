@@ -14,7 +14,7 @@ pub(crate) fn trap_zero_glue(globals: &mut TeXGlobals) {
         add_glue_ref!(globals, zero_glue);
         // delete_glue_ref(cur_val); cur_val:=zero_glue;
         delete_glue_ref(globals, globals.cur_val as pointer);
-        globals.cur_val = zero_glue.into();
+        globals.cur_val = zero_glue as _;
         // end;
     }
     // end;
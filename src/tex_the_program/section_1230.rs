@@ -11,7 +11,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("catcode"),
         def_code,
         cat_code_base as _,
-    );
+    )?;
     // @!@:cat_code_}{\.{\\catcode} primitive@>
     // primitive("mathcode",def_code,math_code_base);
     primitive(
@@ -19,7 +19,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("mathcode"),
         def_code,
         math_code_base as _,
-    );
+    )?;
     // @!@:math_code_}{\.{\\mathcode} primitive@>
     // primitive("lccode",def_code,lc_code_base);
     primitive(
@@ -27,7 +27,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("lccode"),
         def_code,
         lc_code_base as _,
-    );
+    )?;
     // @!@:lc_code_}{\.{\\lccode} primitive@>
     // primitive("uccode",def_code,uc_code_base);
     primitive(
@@ -35,7 +35,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("uccode"),
         def_code,
         uc_code_base as _,
-    );
+    )?;
     // @!@:uc_code_}{\.{\\uccode} primitive@>
     // primitive("sfcode",def_code,sf_code_base);
     primitive(
@@ -43,7 +43,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("sfcode"),
         def_code,
         sf_code_base as _,
-    );
+    )?;
     // @!@:sf_code_}{\.{\\sfcode} primitive@>
     // primitive("delcode",def_code,del_code_base);
     primitive(
@@ -51,7 +51,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("delcode"),
         def_code,
         del_code_base as _,
-    );
+    )?;
     // @!@:del_code_}{\.{\\delcode} primitive@>
     // primitive("textfont",def_family,math_font_base);
     primitive(
@@ -59,7 +59,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("textfont"),
         def_family,
         math_font_base as _,
-    );
+    )?;
     // @!@:text_font_}{\.{\\textfont} primitive@>
     // primitive("scriptfont",def_family,math_font_base+script_size);
     primitive(
@@ -67,7 +67,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("scriptfont"),
         def_family,
         (math_font_base + script_size as word) as _,
-    );
+    )?;
     // @!@:script_font_}{\.{\\scriptfont} primitive@>
     // primitive("scriptscriptfont",def_family,math_font_base+script_script_size);
     primitive(
@@ -75,7 +75,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1230($globals
         crate::strpool_str!("scriptscriptfont"),
         def_family,
         (math_font_base + script_script_size as word) as _,
-    );
+    )?;
     // @!@:script_script_font_}{\.{\\scriptscriptfont} primitive@>
 }}
 
@@ -15,6 +15,9 @@ pub(crate) macro Assignments_1232($globals:expr, $cur_cmd:expr, $a:expr) {{
         );
         //   p:=cur_chr; scan_char_num; p:=p+cur_val; scan_optional_equals;
         p = $globals.cur_chr.get() as _;
+        // TeX-rs: the table base, since `cur_chr` changes while scanning
+        #[cfg(feature = "unicode_support")]
+        let base = p;
         scan_char_num($globals, $globals.allow_big_char_code)?;
         p += $globals.cur_val;
         crate::trace_expr_verbose!("cur_val = {}", $globals.cur_val);
@@ -33,8 +36,22 @@ pub(crate) macro Assignments_1232($globals:expr, $cur_cmd:expr, $a:expr) {{
             //     error; cur_val:=0;
             //     end;
         }
+        #[cfg(feature = "unicode_support")]
+        let big_char = p - base >= 256;
+        #[cfg(not(feature = "unicode_support"))]
+        let big_char = false;
+        if big_char {
+            #[cfg(feature = "unicode_support")]
+            crate::unicode_support::unicode_code_assign(
+                $globals,
+                global!($a),
+                base as word,
+                (p - base) as _,
+                $globals.cur_val,
+            )?;
+        }
         // if p<math_code_base then define(p,data,cur_val)
-        if p < math_code_base as _ {
+        else if p < math_code_base as _ {
             define!($globals, $a, p as _, data, $globals.cur_val as _);
         }
         // else if p<del_code_base then define(p,data,hi(cur_val))
@@ -57,6 +74,7 @@ pub(crate) macro Assignments_1232($globals:expr, $cur_cmd:expr, $a:expr) {{
         false
     };
     use crate::pascal::integer;
+    use crate::pascal::word;
     use crate::section_0112::hi;
     use crate::section_0113::halfword;
     use crate::section_0209::*;
@@ -67,6 +85,7 @@ pub(crate) macro Assignments_1232($globals:expr, $cur_cmd:expr, $a:expr) {{
     use crate::section_0434::scan_char_num;
     use crate::section_0440::scan_int;
     use crate::section_1214::define;
+    use crate::section_1214::global;
     use crate::section_1214::word_define;
     processed
 }}
@@ -21,7 +21,19 @@ pub(crate) macro Let_n_be_the_largest_legal_code_value__based_on_cur_chr($global
     }
     // else n:=255
     else {
-        $n = 255;
+        #[cfg(not(feature = "unicode_support"))]
+        {
+            $n = 255;
+        }
+        #[cfg(feature = "unicode_support")]
+        {
+            $n = if $globals.allow_big_char_code {
+                crate::section_0018::ASCII_code::max_allowed_repr($globals)
+                    .min(crate::section_0110::max_halfword as _) as _
+            } else {
+                255
+            };
+        }
     }
     use crate::section_0230::cat_code_base;
     use crate::section_0230::math_code_base;
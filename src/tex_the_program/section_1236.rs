@@ -12,12 +12,31 @@ pub(crate) fn do_register_command(globals: &mut TeXGlobals, a: small_number) ->
     // @!p:int_val..mu_val; {type of register involved}
     /// type of register involved
     let p: cur_val_level_kind;
+    /// TeX-rs: does `l` refer to a sparse array element of `latex_support`?
+    let e: boolean;
+    /// TeX-rs: integer or dimen value of `l`
+    let w: integer;
+    /// TeX-rs: glue value of `l`
+    let s: pointer;
     // begin q:=cur_cmd;
     q = globals.cur_cmd as _;
     // @<Compute the register location |l| and its type |p|; but |return| if invalid@>;
     crate::section_1237::Compute_the_register_location_l_and_its_type_p_but_return_if_invalid!(
-        globals, p, q, l
+        globals, p, q, l, e
     );
+    // TeX-rs: as in e-TeX, the old value is fetched here, since `l` may be a
+    // sparse array element instead of a location in `eqtb`
+    #[cfg(feature = "latex_support")]
+    {
+        let (w_l, s_l) = crate::latex_support::fetch_register_location(globals, e, l, p);
+        w = w_l;
+        s = s_l;
+    }
+    #[cfg(not(feature = "latex_support"))]
+    {
+        w = globals.eqtb[l][MEMORY_WORD_INT];
+        s = equiv!(globals, l);
+    }
     // if q=register then scan_optional_equals
     if q == register as pointer {
         scan_optional_equals(globals)?;
@@ -34,33 +53,49 @@ pub(crate) fn do_register_command(globals: &mut TeXGlobals, a: small_number) ->
     //     |advance|, put it in |cur_val|@>
     if q < multiply as _ {
         crate::section_1238::Compute_result_of_register_or_advance_put_it_in_cur_val!(
-            globals, l, p, q
+            globals, w, s, p, q
         );
     }
     // else @<Compute result of |multiply| or |divide|, put it in |cur_val|@>;
     else {
         crate::section_1240::Compute_result_of_multiply_or_divide__put_it_in_cur_val!(
-            globals, l, p, q
+            globals, w, s, p, q
         );
     }
     // if arith_error then
     if globals.arith_error {
-        todo!("error arith");
         //   begin print_err("Arithmetic overflow");
+        print_err!(globals, crate::strpool_str!("Arithmetic overflow"));
         // @.Arithmetic overflow@>
         //   help2("I can't carry out that multiplication or division,")@/
         //     ("since the result is out of range.");
+        help2!(
+            globals,
+            crate::strpool_str!("I can't carry out that multiplication or division,"),
+            crate::strpool_str!("since the result is out of range.")
+        );
         //   if p>=glue_val then delete_glue_ref(cur_val);
+        if p >= glue_val {
+            delete_glue_ref(globals, globals.cur_val as _);
+        }
         //   error; return;
+        error(globals)?;
+        crate::return_nojump!();
         //   end;
     }
     // if p<glue_val then word_define(l,cur_val)
     if p < glue_val {
+        #[cfg(feature = "latex_support")]
+        crate::latex_support::sa_word_define!(globals, a, e, l, globals.cur_val);
+        #[cfg(not(feature = "latex_support"))]
         word_define!(globals, a, l, globals.cur_val);
     }
     // else  begin trap_zero_glue; define(l,glue_ref,cur_val);
     else {
         trap_zero_glue(globals);
+        #[cfg(feature = "latex_support")]
+        crate::latex_support::sa_define!(globals, a, e, l, glue_ref, globals.cur_val as _);
+        #[cfg(not(feature = "latex_support"))]
         define!(globals, a, l, glue_ref, globals.cur_val as _);
         // end;
     }
@@ -71,19 +106,31 @@ pub(crate) fn do_register_command(globals: &mut TeXGlobals, a: small_number) ->
     crate::ok_nojump!()
 }
 
+use crate::pascal::boolean;
+use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::do_nothing;
+use crate::section_0073::print_err;
+use crate::section_0079::help2;
 use crate::section_0081::TeXResult;
+use crate::section_0082::error;
 use crate::section_0101::small_number;
+#[cfg(not(feature = "latex_support"))]
+use crate::section_0113::MEMORY_WORD_INT;
 use crate::section_0115::pointer;
+use crate::section_0201::delete_glue_ref;
 use crate::section_0209::advance;
 use crate::section_0209::multiply;
 use crate::section_0209::register;
 use crate::section_0210::glue_ref;
+#[cfg(not(feature = "latex_support"))]
+use crate::section_0221::equiv;
 use crate::section_0405::scan_optional_equals;
 use crate::section_0407::scan_keyword;
 use crate::section_0410::cur_val_level_kind;
 use crate::section_0440::scan_int;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_1214::define;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_1214::word_define;
 use crate::section_1229::trap_zero_glue;
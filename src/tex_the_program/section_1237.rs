@@ -3,7 +3,7 @@
 //
 // @<Compute the register location |l| and its type |p|...@>=
 pub(crate) macro Compute_the_register_location_l_and_its_type_p_but_return_if_invalid
-    ($globals:expr, $p:expr, $q:expr, $l:expr) {{
+    ($globals:expr, $p:expr, $q:expr, $l:expr, $e:expr) {{
         crate::region_forward_label!(
         |'found|
         {
@@ -16,22 +16,56 @@ pub(crate) macro Compute_the_register_location_l_and_its_type_p_but_return_if_in
                 // begin l:=cur_chr; p:=cur_cmd-assign_int; goto found;
                 $l = $globals.cur_chr.get() as pointer;
                 $p = ($globals.cur_cmd - assign_int).into();
+                $e = false;
                 crate::goto_forward_label!('found);
-                todo!("update l and p");
                 // end;
             }
             // if cur_cmd<>register then
-            //   begin print_err("You can't use `"); print_cmd_chr(cur_cmd,cur_chr);
-            // @.You can't use x after ...@>
-            //   print("' after "); print_cmd_chr(q,0);
-            //   help1("I'm forgetting what you said and not changing anything.");
-            //   error; return;
-            //   end;
+            if $globals.cur_cmd != register {
+                // begin print_err("You can't use `"); print_cmd_chr(cur_cmd,cur_chr);
+                print_err!($globals, crate::strpool_str!("You can't use `"));
+                print_cmd_chr($globals, $globals.cur_cmd, $globals.cur_chr);
+                // @.You can't use x after ...@>
+                // print("' after "); print_cmd_chr(q,0);
+                print($globals, crate::strpool_str!("' after ").get() as _);
+                print_cmd_chr($globals, $q as _, chr_code_type::new(0));
+                // help1("I'm forgetting what you said and not changing anything.");
+                help1!(
+                    $globals,
+                    crate::strpool_str!("I'm forgetting what you said and not changing anything.")
+                );
+                // error; return;
+                error($globals)?;
+                crate::return_nojump!();
+                // end;
+            }
             // end;
         }
+        // TeX-rs: `latex_support` has registers up to 32767; those above 255
+        // are elements of sparse arrays, and `\countdef` and so on may point
+        // to them directly
+        #[cfg(feature = "latex_support")]
+        if crate::latex_support::is_sa_element($globals.cur_chr.get()) {
+            $l = $globals.cur_chr.get() as pointer;
+            $p = crate::latex_support::sa_type($globals, $l).into();
+            $e = true;
+            crate::goto_forward_label!('found);
+        }
         // p:=cur_chr; scan_eight_bit_int;
         $p = ($globals.cur_chr.get() as u8).into();
+        #[cfg(feature = "latex_support")]
+        {
+            crate::latex_support::scan_register_num($globals)?;
+            if $globals.cur_val > 255 {
+                crate::latex_support::find_sa_element($globals, $p as _, $globals.cur_val, true)?;
+                $l = $globals.cur_ptr;
+                $e = true;
+                crate::goto_forward_label!('found);
+            }
+        }
+        #[cfg(not(feature = "latex_support"))]
         scan_eight_bit_int($globals)?;
+        $e = false;
         // case p of
         match $p {
             // int_val: l:=cur_val+count_base;
@@ -68,7 +102,14 @@ pub(crate) macro Compute_the_register_location_l_and_its_type_p_but_return_if_in
         use crate::section_0224::mu_skip_base;
         use crate::section_0236::count_base;
         use crate::section_0247::scaled_base;
+        use crate::section_0059::print;
+        use crate::section_0073::print_err;
+        use crate::section_0079::help1;
+        use crate::section_0082::error;
+        use crate::section_0297::chr_code_type;
+        use crate::section_0298::print_cmd_chr;
         use crate::section_0380::get_x_token;
         use crate::section_0410::cur_val_level_kind;
+        #[cfg(not(feature = "latex_support"))]
         use crate::section_0433::scan_eight_bit_int;
     }}
@@ -1,11 +1,11 @@
 //! ` `
 // @<Compute the sum of two glue specs@>=
-pub(crate) macro Compute_the_sum_of_two_glue_specs($globals:expr, $l:expr) {{
+pub(crate) macro Compute_the_sum_of_two_glue_specs($globals:expr, $s:expr) {{
     /// for list manipulation
     let (q, r): (pointer, pointer);
     // begin q:=new_spec(cur_val); r:=equiv(l);
     q = new_spec($globals, $globals.cur_val as _)?;
-    r = equiv!($globals, $l);
+    r = $s;
     // delete_glue_ref(cur_val);
     delete_glue_ref($globals, $globals.cur_val as _);
     // width(q):=width(q)+width(r);
@@ -57,5 +57,4 @@ pub(crate) macro Compute_the_sum_of_two_glue_specs($globals:expr, $l:expr) {{
     use crate::section_0150::stretch_order;
     use crate::section_0151::new_spec;
     use crate::section_0201::delete_glue_ref;
-    use crate::section_0221::equiv;
 }}
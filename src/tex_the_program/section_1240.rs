@@ -1,7 +1,7 @@
 //! ` `
 
 // @<Compute result of |multiply| or |divide|...@>=
-pub(crate) macro Compute_result_of_multiply_or_divide__put_it_in_cur_val($globals:expr, $l:expr, $p:expr, $q:expr) {{
+pub(crate) macro Compute_result_of_multiply_or_divide__put_it_in_cur_val($globals:expr, $w:expr, $s:expr, $p:expr, $q:expr) {{
     // begin scan_int;
     scan_int($globals)?;
     // if p<glue_val then
@@ -10,17 +10,13 @@ pub(crate) macro Compute_result_of_multiply_or_divide__put_it_in_cur_val($global
         if $q == multiply as pointer {
             // if p=int_val then cur_val:=mult_integers(eqtb[l].int,cur_val)
             if $p == int_val {
-                $globals.cur_val = mult_integers!(
-                    $globals,
-                    $globals.eqtb[$l][MEMORY_WORD_INT],
-                    $globals.cur_val
-                );
+                $globals.cur_val = mult_integers!($globals, $w, $globals.cur_val);
             }
             // else cur_val:=nx_plus_y(eqtb[l].int,cur_val,0)
             else {
                 $globals.cur_val = nx_plus_y!(
                     $globals,
-                    $globals.eqtb[$l][MEMORY_WORD_INT],
+                    $w,
                     scaled::new_from_inner($globals.cur_val),
                     scaled::zero()
                 )
@@ -29,19 +25,15 @@ pub(crate) macro Compute_result_of_multiply_or_divide__put_it_in_cur_val($global
         }
         // else cur_val:=x_over_n(eqtb[l].int,cur_val)
         else {
-            $globals.cur_val = x_over_n(
-                $globals,
-                scaled::new_from_inner($globals.eqtb[$l][MEMORY_WORD_INT]),
-                $globals.cur_val,
-            )
-            .inner() as _;
+            $globals.cur_val =
+                x_over_n($globals, scaled::new_from_inner($w), $globals.cur_val).inner() as _;
         }
     }
     // else  begin s:=equiv(l); r:=new_spec(s);
     else {
         /// for list manipulation
         let (r, s): (pointer, pointer);
-        s = equiv!($globals, $l);
+        s = $s;
         r = new_spec($globals, s)?;
         // if q=multiply then
         if $q == multiply as pointer {
@@ -86,14 +78,12 @@ pub(crate) macro Compute_result_of_multiply_or_divide__put_it_in_cur_val($global
     use crate::section_0105::mult_integers;
     use crate::section_0105::nx_plus_y;
     use crate::section_0106::x_over_n;
-    use crate::section_0113::MEMORY_WORD_INT;
     use crate::section_0115::pointer;
     use crate::section_0135::width;
     use crate::section_0150::shrink;
     use crate::section_0150::stretch;
     use crate::section_0151::new_spec;
     use crate::section_0209::*;
-    use crate::section_0221::equiv;
     use crate::section_0410::cur_val_level_kind::*;
     use crate::section_0440::scan_int;
 }}
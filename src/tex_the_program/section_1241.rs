@@ -9,12 +9,26 @@ pub(crate) macro Assignments_1241($globals:expr, $cur_cmd:expr, $a:expr) {{
         /// for temporary short-term use
         let n: integer;
 
-        scan_eight_bit_int($globals)?;
-        // if global then n:=256+cur_val@+else n:=cur_val;
-        if global!($a) {
-            n = 256 + $globals.cur_val;
-        } else {
-            n = $globals.cur_val;
+        // TeX-rs: `latex_support` has box registers up to 32767, so `\global`
+        // is marked by `global_box_flag` instead of 256
+        #[cfg(feature = "latex_support")]
+        {
+            crate::latex_support::scan_register_num($globals)?;
+            if global!($a) {
+                n = crate::latex_support::global_box_flag - box_flag + $globals.cur_val;
+            } else {
+                n = $globals.cur_val;
+            }
+        }
+        #[cfg(not(feature = "latex_support"))]
+        {
+            scan_eight_bit_int($globals)?;
+            // if global then n:=256+cur_val@+else n:=cur_val;
+            if global!($a) {
+                n = 256 + $globals.cur_val;
+            } else {
+                n = $globals.cur_val;
+            }
         }
         // scan_optional_equals;
         scan_optional_equals($globals)?;
@@ -29,10 +43,15 @@ pub(crate) macro Assignments_1241($globals:expr, $cur_cmd:expr, $a:expr) {{
             // @.Improper \\setbox@>
             //   help2("Sorry, \setbox is not allowed after \halign in a display,")@/
             //   ("or between \accent and an accented character."); error;
+            help2!(
+                $globals,
+                crate::strpool_str!("Sorry, \\setbox is not allowed after \\halign in a display,"),
+                crate::strpool_str!("or between \\accent and an accented character.")
+            );
+            error($globals)?;
             //   end;
-            // end;
-            todo!("not yet implemented in {}", file!());
         }
+        // end;
         true
     } else {
         false
@@ -40,8 +59,11 @@ pub(crate) macro Assignments_1241($globals:expr, $cur_cmd:expr, $a:expr) {{
     use crate::pascal::integer;
     use crate::section_0063::print_esc;
     use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
     use crate::section_0209::*;
     use crate::section_0405::scan_optional_equals;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0433::scan_eight_bit_int;
     use crate::section_1071::box_flag;
     use crate::section_1084::scan_box;
@@ -26,11 +26,16 @@ pub(crate) fn alter_aux(globals: &mut TeXGlobals) -> TeXResult<()> {
             // if (cur_val<=0)or(cur_val>32767) then
             if globals.cur_val <= 0 || globals.cur_val > 32767 {
                 // begin print_err("Bad space factor");
+                print_err!(globals, crate::strpool_str!("Bad space factor"));
                 // @.Bad space factor@>
                 // help1("I allow only values in the range 1..32767 here.");
+                help1!(
+                    globals,
+                    crate::strpool_str!("I allow only values in the range 1..32767 here.")
+                );
                 // int_error(cur_val);
+                int_error(globals, globals.cur_val)?;
                 // end
-                todo!("range error");
             }
             // else space_factor:=cur_val;
             else {
@@ -46,7 +51,10 @@ pub(crate) fn alter_aux(globals: &mut TeXGlobals) -> TeXResult<()> {
 
 use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
+use crate::section_0073::print_err;
+use crate::section_0079::help1;
 use crate::section_0081::TeXResult;
+use crate::section_0091::int_error;
 use crate::section_0101::scaled;
 use crate::section_0149::leader_ptr;
 use crate::section_0211::vmode;
@@ -18,11 +18,17 @@ pub(crate) fn alter_prev_graf(globals: &mut TeXGlobals) -> TeXResult<()> {
     // if cur_val<0 then
     if globals.cur_val < 0 {
         // begin print_err("Bad "); print_esc("prevgraf");
+        print_err!(globals, crate::strpool_str!("Bad "));
+        print_esc(globals, crate::strpool_str!("prevgraf"));
         // @.Bad \\prevgraf@>
         // help1("I allow only nonnegative values here.");
+        help1!(
+            globals,
+            crate::strpool_str!("I allow only nonnegative values here.")
+        );
         // int_error(cur_val);
+        int_error(globals, globals.cur_val)?;
         // end
-        todo!("bad prevgraf");
     }
     // else  begin nest[p].pg_field:=cur_val; cur_list:=nest[nest_ptr];
     else {
@@ -36,7 +42,11 @@ pub(crate) fn alter_prev_graf(globals: &mut TeXGlobals) -> TeXResult<()> {
 
 use crate::section_0004::TeXGlobals;
 use crate::section_0016::decr;
+use crate::section_0063::print_esc;
+use crate::section_0073::print_err;
+use crate::section_0079::help1;
 use crate::section_0081::TeXResult;
+use crate::section_0091::int_error;
 use crate::section_0211::vmode;
 use crate::section_0405::scan_optional_equals;
 use crate::section_0440::scan_int;
@@ -7,24 +7,46 @@ pub(crate) fn alter_box_dimen(globals: &mut TeXGlobals) -> TeXResult<()> {
     let c: small_number;
     // @!b:eight_bits; {box number}
     /// box number
+    #[cfg(not(feature = "latex_support"))]
     let b: eight_bits;
+    /// box number
+    ///
+    /// TeX-rs: `latex_support` has box registers up to 32767
+    #[cfg(feature = "latex_support")]
+    let b: integer;
+    /// the box
+    let p: pointer;
     // begin c:=cur_chr; scan_eight_bit_int; b:=cur_val; scan_optional_equals;
     c = small_number::new(globals.cur_chr.get() as _);
+    #[cfg(feature = "latex_support")]
+    crate::latex_support::scan_register_num(globals)?;
+    #[cfg(not(feature = "latex_support"))]
     scan_eight_bit_int(globals)?;
     b = globals.cur_val as _;
     scan_optional_equals(globals)?;
     // scan_normal_dimen;
     scan_normal_dimen!(globals)?;
     // if box(b)<>null then mem[box(b)+c].sc:=cur_val;
-    if r#box!(globals, b) != null {
-        globals.mem[r#box!(globals, b) as pointer + c.get() as pointer][MEMORY_WORD_SC] =
+    #[cfg(feature = "latex_support")]
+    {
+        p = crate::latex_support::fetch_box(globals, b)?;
+    }
+    #[cfg(not(feature = "latex_support"))]
+    {
+        p = r#box!(globals, b);
+    }
+    if p != null {
+        globals.mem[p + c.get() as pointer][MEMORY_WORD_SC] =
             scaled::new_from_inner(globals.cur_val);
     }
     // end;
     crate::ok_nojump!()
 }
 
+#[cfg(feature = "latex_support")]
+use crate::pascal::integer;
 use crate::section_0004::TeXGlobals;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0025::eight_bits;
 use crate::section_0081::TeXResult;
 use crate::section_0101::scaled;
@@ -32,7 +54,9 @@ use crate::section_0101::small_number;
 use crate::section_0101::MEMORY_WORD_SC;
 use crate::section_0115::null;
 use crate::section_0115::pointer;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0230::r#box;
 use crate::section_0405::scan_optional_equals;
+#[cfg(not(feature = "latex_support"))]
 use crate::section_0433::scan_eight_bit_int;
 use crate::section_0448::scan_normal_dimen;
@@ -5,10 +5,10 @@
 pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1250($globals:expr) {{
     let globals = &mut *$globals;
     // primitive("hyphenation",hyph_data,0);
-    primitive(globals, crate::strpool_str!("hyphenation"), hyph_data, 0);
+    primitive(globals, crate::strpool_str!("hyphenation"), hyph_data, 0)?;
     // @!@:hyphenation_}{\.{\\hyphenation} primitive@>
     // primitive("patterns",hyph_data,1);
-    primitive(globals, crate::strpool_str!("patterns"), hyph_data, 1);
+    primitive(globals, crate::strpool_str!("patterns"), hyph_data, 1)?;
     // @!@:patterns_}{\.{\\patterns} primitive@>
 }}
 
@@ -7,15 +7,32 @@ pub(crate) macro Assignments_1252($globals:expr, $cur_cmd:expr, $a:expr, $lbl_do
         if $globals.cur_chr.get() == 1 {
             // begin @!init new_patterns; goto done;@;@+tini@/
             crate::region_initex! {
-                new_patterns($globals)?;
-                crate::goto_forward_label!($lbl_done);
+                // TeX-rs: only an `ini_version` run can load patterns.
+                if $globals.ini_version {
+                    new_patterns($globals)?;
+                    crate::goto_forward_label!($lbl_done);
+                }
             }
-            todo!("hyph data error");
             // print_err("Patterns can be loaded only by INITEX");
+            print_err!(
+                $globals,
+                crate::strpool_str!("Patterns can be loaded only by INITEX")
+            );
             // @.Patterns can be...@>
             // help0; error;
+            help0!($globals);
+            error($globals)?;
             // repeat get_token; until cur_cmd=right_brace; {flush the patterns}
+            loop {
+                get_token($globals)?;
+                if $globals.cur_cmd == right_brace {
+                    break;
+                }
+            }
+            /// flush the patterns
+            const _: () = ();
             // return;
+            crate::return_nojump!();
             // end
         }
         // else  begin new_hyph_exceptions; goto done;
@@ -24,6 +41,11 @@ pub(crate) macro Assignments_1252($globals:expr, $cur_cmd:expr, $a:expr, $lbl_do
             crate::goto_forward_label!($lbl_done);
             // end;
         }
+        use crate::section_0073::print_err;
+        use crate::section_0079::help0;
+        use crate::section_0082::error;
+        use crate::section_0207::right_brace;
+        use crate::section_0365::get_token;
         use crate::section_0934::new_hyph_exceptions;
         use crate::section_0960::new_patterns;
         true
@@ -10,10 +10,10 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1254($globals
         crate::strpool_str!("hyphenchar"),
         assign_font_int,
         0,
-    );
+    )?;
     // @!@:hyphen_char_}{\.{\\hyphenchar} primitive@>
     // primitive("skewchar",assign_font_int,1);
-    primitive(globals, crate::strpool_str!("skewchar"), assign_font_int, 1);
+    primitive(globals, crate::strpool_str!("skewchar"), assign_font_int, 1)?;
     // @!@:skew_char_}{\.{\\skewchar} primitive@>
 }}
 
@@ -25,7 +25,7 @@ pub(crate) fn new_font(globals: &mut TeXGlobals, a: small_number) -> TeXResult<(
     //   {avoid confusing \.{texput} with the font name}
     /// avoid confusing `texput` with the font name
     if globals.job_name == 0 {
-        open_log_file(globals);
+        open_log_file(globals)?;
     }
     // @.texput@>
     // get_r_token; u:=cur_cs;
@@ -54,7 +54,7 @@ pub(crate) fn new_font(globals: &mut TeXGlobals, a: small_number) -> TeXResult<(
         globals.selector = old_setting;
         // @.FONTx@>
         // str_room(1); t:=make_string;
-        str_room(globals, 1 * character_max_room);
+        str_room(globals, 1 * character_max_room)?;
         t = make_string(make_globals_string_view!(globals));
         // end;
     }
@@ -77,7 +77,7 @@ pub(crate) fn new_font(globals: &mut TeXGlobals, a: small_number) -> TeXResult<(
     }
     'common_ending <-
     );
-    equiv!(globals, u) = f.get();
+    equiv!(globals, u) = f.get() as _;
     globals.eqtb[(font_id_base + f.get() as word) as pointer] = globals.eqtb[u];
     font_id_text!(globals, f.get() as word) = t.get() as _;
     // end;
@@ -8,7 +8,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1262($globals
         crate::strpool_str!("batchmode"),
         set_interaction,
         batch_mode as _,
-    );
+    )?;
     // @!@:batch_mode_}{\.{\\batchmode} primitive@>
     // primitive("nonstopmode",set_interaction,nonstop_mode);
     primitive(
@@ -16,7 +16,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1262($globals
         crate::strpool_str!("nonstopmode"),
         set_interaction,
         nonstop_mode as _,
-    );
+    )?;
     // @!@:nonstop_mode_}{\.{\\nonstopmode} primitive@>
     // primitive("scrollmode",set_interaction,scroll_mode);
     primitive(
@@ -24,7 +24,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1262($globals
         crate::strpool_str!("scrollmode"),
         set_interaction,
         scroll_mode as _,
-    );
+    )?;
     // @!@:scroll_mode_}{\.{\\scrollmode} primitive@>
     // primitive("errorstopmode",set_interaction,error_stop_mode);
     primitive(
@@ -32,7 +32,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1262($globals
         crate::strpool_str!("errorstopmode"),
         set_interaction,
         error_stop_mode as _,
-    );
+    )?;
     // @!@:error_stop_mode_}{\.{\\errorstopmode} primitive@>
 }}
 
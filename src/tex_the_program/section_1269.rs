@@ -6,7 +6,7 @@ pub(crate) macro Insert_a_token_saved_by_afterassignment__if_any($globals:expr)
     if $globals.after_token != 0 {
         // begin cur_tok:=after_token; back_input; after_token:=0;
         $globals.cur_tok = $globals.after_token;
-        back_input($globals);
+        back_input($globals)?;
         $globals.after_token = cur_tok_type::default();
         // end
     }
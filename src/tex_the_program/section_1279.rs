@@ -25,7 +25,7 @@ pub(crate) fn issue_message(globals: &mut TeXGlobals) -> TeXResult<()> {
     // flush_list(def_ref);
     // str_room(1); s:=make_string;
     flush_list(globals, globals.def_ref);
-    str_room(globals, 1 * character_max_room);
+    str_room(globals, 1 * character_max_room)?;
     s = make_string(make_globals_string_view!(globals));
     // if c=0 then @<Print string |s| on the terminal@>
     if c == 0 {
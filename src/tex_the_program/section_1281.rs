@@ -1,7 +1,15 @@
 //! @ If \.{\\errmessage} occurs often in |scroll_mode|, without user-defined
 //! \.{\\errhelp}, we don't want to give a long help message each time. So we
 //! give a verbose explanation only once.
-//!
-//! @<Glob...@>=
-//! @!long_help_seen:boolean; {has the long \.{\\errmessage} help been used?}
-//!
+//
+// @<Glob...@>=
+// @!long_help_seen:boolean; {has the long \.{\\errmessage} help been used?}
+/// has the long `\errmessage` help been used?
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static long_help_seen: boolean = false;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::pascal::boolean;
+
+use crate::section_0004::TeXGlobals;
+use globals_struct::{globals_struct_field, globals_struct_use};
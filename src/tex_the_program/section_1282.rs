@@ -1,2 +1,7 @@
-//! @ @<Set init...@>=long_help_seen:=false;
-//!
+//! ` `
+// @<Set init...@>=
+pub(crate) macro Set_initial_values_of_key_variables_1282($globals:expr) {{
+    let globals = &mut *$globals;
+    // long_help_seen:=false;
+    globals.long_help_seen = false;
+}}
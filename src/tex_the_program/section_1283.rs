@@ -6,16 +6,45 @@ pub(crate) macro Print_string_s_as_an_error_message($globals:expr, $s:expr) {{
     print_err!($globals, crate::strpool_str!(""));
     slow_print($globals, $s);
     // if err_help<>null then use_err_help:=true
+    if err_help!($globals) != null {
+        $globals.use_err_help = true;
+    }
     // else if long_help_seen then help1("(That was another \errmessage.)")
+    else if $globals.long_help_seen {
+        help1!(
+            $globals,
+            crate::strpool_str!("(That was another \\errmessage.)")
+        );
+    }
     // else  begin if interaction<error_stop_mode then long_help_seen:=true;
-    //   help4("This error message was generated by an \errmessage")@/
-    //   ("command, so I can't give any explicit help.")@/
-    //   ("Pretend that you're Hercule Poirot: Examine all clues,")@/
-    // @^Poirot, Hercule@>
-    //   ("and deduce the truth by order and method.");
-    //   end;
+    else {
+        if $globals.interaction < error_stop_mode {
+            $globals.long_help_seen = true;
+        }
+        // help4("This error message was generated by an \errmessage")@/
+        // ("command, so I can't give any explicit help.")@/
+        // ("Pretend that you're Hercule Poirot: Examine all clues,")@/
+        // @^Poirot, Hercule@>
+        // ("and deduce the truth by order and method.");
+        help4!(
+            $globals,
+            crate::strpool_str!("This error message was generated by an \\errmessage"),
+            crate::strpool_str!("command, so I can't give any explicit help."),
+            crate::strpool_str!("Pretend that you're Hercule Poirot: Examine all clues,"),
+            crate::strpool_str!("and deduce the truth by order and method.")
+        );
+        // end;
+    }
     // error; use_err_help:=false;
+    error($globals)?;
+    $globals.use_err_help = false;
     // end
     use crate::section_0060::slow_print;
+    use crate::section_0073::error_stop_mode;
     use crate::section_0073::print_err;
+    use crate::section_0079::help1;
+    use crate::section_0079::help4;
+    use crate::section_0082::error;
+    use crate::section_0115::null;
+    use crate::section_0230::err_help;
 }}
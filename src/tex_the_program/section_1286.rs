@@ -10,7 +10,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1286($globals
         crate::strpool_str!("lowercase"),
         case_shift,
         lc_code_base as _,
-    );
+    )?;
     // @!@:lowercase_}{\.{\\lowercase} primitive@>
     // primitive("uppercase",case_shift,uc_code_base);
     primitive(
@@ -18,7 +18,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1286($globals
         crate::strpool_str!("uppercase"),
         case_shift,
         uc_code_base as _,
-    );
+    )?;
     // @!@:uppercase_}{\.{\\uppercase} primitive@>
 }}
 
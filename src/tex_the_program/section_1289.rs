@@ -9,7 +9,7 @@ pub(crate) macro Change_the_case_of_the_token_in_p_if_a_change_is_appropriate($g
     let t = info_tok!($globals, $p);
     #[cfg(not(feature = "unicode_support"))]
     {
-        todo!("not yet implemented in {}", file!());
+        crate::unported::unported!($globals, 1289);
         // if t<cs_token_flag+single_base then
         //   begin c:=t mod 256;
         //   if equiv(b+c)<>0 then info(p):=t-c+equiv(b+c);
@@ -20,33 +20,33 @@ pub(crate) macro Change_the_case_of_the_token_in_p_if_a_change_is_appropriate($g
         let c;
         if let Some(t_cs) = t.get_cs() {
             if t_cs < single_base as pointer {
-                c = Some((t_cs % 256) as u8);
+                c = Some((t_cs - active_base as pointer) as ASCII_code_repr);
             } else {
                 c = None;
             }
         } else if let Some((_, chr)) = t.get_cmd_and_chr() {
-            if chr.get() < 256 {
-                c = Some((chr.get() % 256) as u8)
-            } else {
-                todo!("not yet implemented in {}", file!());
-            }
+            c = Some(chr.get() as ASCII_code_repr)
         } else {
             c = None;
         }
         if let Some(c) = c {
-            if equiv!($globals, $b + c as pointer) != 0 {
+            let new_c = equiv_field!(code_table_word($globals, $b as word, ASCII_code(c)));
+            if new_c != 0 {
                 info_tok_assign!(
                     $globals,
                     $p,
-                    cur_tok_type::new(
-                        t.get() - c as cur_tok_repr
-                            + equiv!($globals, $b + c as pointer) as cur_tok_repr
-                    )
+                    cur_tok_type::new(t.get() - c as cur_tok_repr + new_c as cur_tok_repr)
                 );
             }
         }
 
+        use crate::pascal::word;
+        use crate::section_0018::ASCII_code;
+        use crate::section_0018::ASCII_code_repr;
+        use crate::section_0221::equiv_field;
+        use crate::section_0222::active_base;
         use crate::section_0222::single_base;
+        use crate::section_0230::code_table_word;
         use crate::section_0297::cur_tok_repr;
         use crate::section_0297::cur_tok_type;
     }
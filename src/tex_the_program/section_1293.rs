@@ -47,6 +47,12 @@ pub(crate) fn show_whatever(globals: &mut TeXGlobals) -> TeXResult<()> {
         // help3("This isn't an error message; I'm just \showing something.")@/
         // ("Type `I\show...' to show more (e.g., \show\cs,")@/
         // ("\showthe\count10, \showbox255, \showlists).");
+        help3!(
+            globals,
+            crate::strpool_str!("This isn't an error message; I'm just \\showing something."),
+            crate::strpool_str!("Type `I\\show...' to show more (e.g., \\show\\cs,"),
+            crate::strpool_str!("\\showthe\\count10, \\showbox255, \\showlists).")
+        );
         // end
     }
     // else  begin@t@>@;@/
@@ -56,6 +62,14 @@ pub(crate) fn show_whatever(globals: &mut TeXGlobals) -> TeXResult<()> {
         // ("\showthe\count10, \showbox255, \showlists).")@/
         // ("And type `I\tracingonline=1\show...' to show boxes and")@/
         // ("lists on your terminal as well as in the transcript file.");
+        help5!(
+            globals,
+            crate::strpool_str!("This isn't an error message; I'm just \\showing something."),
+            crate::strpool_str!("Type `I\\show...' to show more (e.g., \\show\\cs,"),
+            crate::strpool_str!("\\showthe\\count10, \\showbox255, \\showlists)."),
+            crate::strpool_str!("And type `I\\tracingonline=1\\show...' to show boxes and"),
+            crate::strpool_str!("lists on your terminal as well as in the transcript file.")
+        );
         // end;
     }
     // error;
@@ -68,6 +82,8 @@ use crate::section_0004::TeXGlobals;
 use crate::section_0016::decr;
 use crate::section_0073::error_stop_mode;
 use crate::section_0079::help0;
+use crate::section_0079::help3;
+use crate::section_0079::help5;
 use crate::section_0081::TeXResult;
 use crate::section_0082::error;
 use crate::section_0218::show_activities;
@@ -3,6 +3,11 @@
 // @<Cases of |print_cmd_chr|...@>=
 pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_1295($globals:expr, $cmd:expr, $chr_code:expr) {{
     // undefined_cs: print("undefined");
+    // TeX-rs: the `\protected` prefix of `latex_support` precedes the other prefixes
+    #[cfg(feature = "latex_support")]
+    if $cmd >= call && $cmd <= long_outer_call {
+        crate::latex_support::print_protected_prefix($globals, $cmd, $chr_code.get() as _);
+    }
     let processed = if $cmd == undefined_cs {
         print($globals, crate::strpool_str!("undefined").get() as _);
         true
@@ -1,8 +1,20 @@
 //! ` `
 // @<Show the current contents of a box@>=
 pub(crate) macro Show_the_current_contents_of_a_box($globals:expr) {{
+    /// the box register
+    let p: pointer;
     // begin scan_eight_bit_int; begin_diagnostic;
-    scan_eight_bit_int($globals)?;
+    // TeX-rs: `latex_support` has box registers up to 32767
+    #[cfg(feature = "latex_support")]
+    {
+        crate::latex_support::scan_register_num($globals)?;
+        p = crate::latex_support::fetch_box($globals, $globals.cur_val)?;
+    }
+    #[cfg(not(feature = "latex_support"))]
+    {
+        scan_eight_bit_int($globals)?;
+        p = r#box!($globals, $globals.cur_val);
+    }
     begin_diagnostic($globals);
     // print_nl("> \box"); print_int(cur_val); print_char("=");
     print_nl($globals, crate::strpool_str!("> \\box"));
@@ -12,12 +24,12 @@ pub(crate) macro Show_the_current_contents_of_a_box($globals:expr) {{
         ASCII_code_literal!(b'='),
     );
     // if box(cur_val)=null then print("void")
-    if r#box!($globals, $globals.cur_val) == null {
+    if p == null {
         print($globals, crate::strpool_str!("void").get() as _);
     }
     // else show_box(box(cur_val));
     else {
-        show_box($globals, r#box!($globals, $globals.cur_val));
+        show_box($globals, p);
     }
     // end
     use crate::section_0004::make_globals_io_string_log_view;
@@ -28,8 +40,11 @@ pub(crate) macro Show_the_current_contents_of_a_box($globals:expr) {{
     use crate::section_0062::print_nl;
     use crate::section_0065::print_int;
     use crate::section_0115::null;
+    use crate::section_0115::pointer;
     use crate::section_0198::show_box;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0230::r#box;
     use crate::section_0245::begin_diagnostic;
+    #[cfg(not(feature = "latex_support"))]
     use crate::section_0433::scan_eight_bit_int;
 }}
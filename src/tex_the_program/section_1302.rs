@@ -4,6 +4,10 @@ pub(crate) macro Dump_the_unicode_support_data($globals:expr) {{
     crate::unicode_support::dump_the_unicode_support_data($globals);
 }}
 
+pub(crate) macro Dump_the_latex_support_data($globals:expr) {{
+    crate::latex_support::dump_the_latex_support_data($globals);
+}}
+
 // @<Declare act...@>=
 // @!init procedure store_fmt_file;
 #[cfg(feature = "initex")]
@@ -35,6 +39,8 @@ pub(crate) fn store_fmt_file(globals: &mut TeXGlobals) -> TeXResult<()> {
     crate::section_1324::Dump_the_hyphenation_tables!(globals);
     #[cfg(feature = "unicode_support")]
     Dump_the_unicode_support_data!(globals);
+    #[cfg(feature = "latex_support")]
+    Dump_the_latex_support_data!(globals);
     // @<Dump a couple more things and the closing check word@>;
     crate::section_1326::Dump_a_couple_more_things_and_the_closing_check_word!(globals);
     // @<Close the format file@>;
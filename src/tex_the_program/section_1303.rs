@@ -27,6 +27,12 @@ pub(crate) macro Undump_the_unicode_support_data($globals:expr, $lbl_bad_fmt:lif
     }
 }}
 
+pub(crate) macro Undump_the_latex_support_data($globals:expr, $lbl_bad_fmt:lifetime) {{
+    if !crate::latex_support::undump_the_latex_support_data($globals) {
+        crate::goto_forward_label!($lbl_bad_fmt);
+    }
+}}
+
 // @p @t\4@>@<Declare the function called |open_fmt_file|@>@;
 // function load_fmt_file:boolean;
 #[allow(unused_variables)]
@@ -53,6 +59,8 @@ pub(crate) fn load_fmt_file(globals: &mut TeXGlobals) -> boolean {
         crate::section_1325::Undump_the_hyphenation_tables!(globals, 'bad_fmt);
         #[cfg(feature = "unicode_support")]
         Undump_the_unicode_support_data!(globals, 'bad_fmt);
+        #[cfg(feature = "latex_support")]
+        Undump_the_latex_support_data!(globals, 'bad_fmt);
         // @<Undump a couple more things and the closing check word@>;
         crate::section_1327::Undump_a_couple_more_things_and_the_closing_check_word!(globals, 'bad_fmt);
         // load_fmt_file:=true; return; {it worked!}
@@ -15,10 +15,16 @@ pub(crate) macro Dump_constants_for_consistency_check($globals:expr) {{
     dump_int!($globals, hash_prime as _);
     // dump_int(hyph_size)
     dump_int!($globals, hyph_size as _);
+    // TeX-rs: the width of a pointer, which depends on the build
+    dump_int!($globals, halfword::BITS as _);
+    // TeX-rs: the capacities configured at run time are not dumped. A format
+    // only has to fit in the run that loads it, and |undump_size| checks that
+    // for each table as it is read back.
     use crate::section_0012::hash_prime;
     use crate::section_0012::hyph_size;
     use crate::section_0012::mem_bot;
     use crate::section_0012::mem_top;
+    use crate::section_0113::halfword;
     use crate::section_0247::eqtb_size;
     use crate::section_1305::dump_int;
     use crate::string_pool::string_pool_checksum;
@@ -45,12 +45,18 @@ pub(crate) macro Undump_constants_for_consistency_check($globals:expr, $lbl_bad_
     if x != hyph_size {
         crate::goto_forward_label!($lbl_bad_fmt);
     }
+    // TeX-rs: a format written with pointers of another width is unusable
+    undump_int!($globals, x);
+    if x != halfword::BITS as integer {
+        crate::goto_forward_label!($lbl_bad_fmt);
+    }
     use crate::io_support::buffer_variable;
     use crate::pascal::integer;
     use crate::section_0012::hash_prime;
     use crate::section_0012::hyph_size;
     use crate::section_0012::mem_bot;
     use crate::section_0012::mem_top;
+    use crate::section_0113::halfword;
     use crate::section_0113::MEMORY_WORD_INT;
     use crate::section_0247::eqtb_size;
     use crate::section_1306::undump_int;
@@ -25,7 +25,7 @@ pub(crate) macro Undump_the_string_pool($globals:expr, $lbl_bad_fmt:lifetime) {{
     undump_size!(
         $globals,
         0,
-        pool_size,
+        $globals.pool_size,
         "string pool size",
         $globals.pool_ptr,
         pool_pointer::new,
@@ -70,7 +70,6 @@ pub(crate) macro Undump_the_string_pool($globals:expr, $lbl_bad_fmt:lifetime) {{
     $globals.init_str_ptr = $globals.str_ptr;
     $globals.init_pool_ptr = $globals.pool_ptr;
     use crate::section_0011::max_strings;
-    use crate::section_0011::pool_size;
     use crate::section_0038::pool_pointer;
     use crate::section_0038::str_number;
     use crate::section_1306::undump;
@@ -12,6 +12,7 @@ pub(crate) macro Undump_the_dynamic_memory($globals:expr, $lbl_bad_fmt:lifetime)
         core::convert::identity,
         $lbl_bad_fmt
     );
+    make_room_in_mem($globals, $globals.lo_mem_max, hi_mem_stat_min);
     // undump(lo_mem_stat_max+1)(lo_mem_max)(rover);
     undump!(
         $globals,
@@ -90,6 +91,7 @@ pub(crate) macro Undump_the_dynamic_memory($globals:expr, $lbl_bad_fmt:lifetime)
         $lbl_bad_fmt
     );
     $globals.mem_end = mem_top;
+    make_room_in_mem($globals, $globals.lo_mem_max, $globals.hi_mem_min);
     // for k:=hi_mem_min to mem_end do undump_wd(mem[k]);
     for k in $globals.hi_mem_min..=$globals.mem_end {
         undump_wd!($globals, $globals.mem[k]);
@@ -103,6 +105,7 @@ pub(crate) macro Undump_the_dynamic_memory($globals:expr, $lbl_bad_fmt:lifetime)
     use crate::section_0012::mem_top;
     use crate::section_0115::null;
     use crate::section_0115::pointer;
+    use crate::section_0116::make_room_in_mem;
     use crate::section_0118::info_inner;
     use crate::section_0118::link;
     use crate::section_0124::empty_flag;
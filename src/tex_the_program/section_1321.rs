@@ -5,7 +5,7 @@ pub(crate) macro Undump_the_font_information($globals:expr, $lbl_bad_fmt:lifetim
     undump_size!(
         $globals,
         7,
-        font_mem_size,
+        $globals.font_mem_size,
         "font mem size",
         $globals.fmem_ptr,
         font_index::new,
@@ -19,7 +19,7 @@ pub(crate) macro Undump_the_font_information($globals:expr, $lbl_bad_fmt:lifetim
     undump_size!(
         $globals,
         font_base,
-        font_max,
+        $globals.font_max,
         "font max",
         $globals.font_ptr,
         internal_font_number::new,
@@ -34,8 +34,6 @@ pub(crate) macro Undump_the_font_information($globals:expr, $lbl_bad_fmt:lifetim
             $lbl_bad_fmt
         );
     }
-    use crate::section_0011::font_max;
-    use crate::section_0011::font_mem_size;
     use crate::section_0012::font_base;
     use crate::section_0232::null_font;
     use crate::section_0548::font_index;
@@ -41,7 +41,7 @@ pub(crate) macro Undump_the_hyphenation_tables($globals:expr, $lbl_bad_fmt:lifet
     undump_size!(
         $globals,
         0,
-        trie_size,
+        $globals.trie_size,
         "trie size",
         j,
         trie_pointer::new,
@@ -136,7 +136,6 @@ pub(crate) macro Undump_the_hyphenation_tables($globals:expr, $lbl_bad_fmt:lifet
     }
     use crate::pascal::u16_from_0_to_n;
     use crate::section_0011::trie_op_size;
-    use crate::section_0011::trie_size;
     use crate::section_0012::hyph_size;
     use crate::section_0038::str_number;
     use crate::section_0101::small_number;
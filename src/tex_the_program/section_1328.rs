@@ -39,7 +39,7 @@ pub(crate) macro Create_the_format_ident__open_the_format_file__and_inform_the_u
         $globals.selector = term_and_log.into();
     }
     // str_room(1);
-    str_room($globals, 1);
+    str_room($globals, 1)?;
     // format_ident:=make_string;
     $globals.format_ident = make_string(make_globals_string_view!($globals));
     // pack_job_name(format_extension);
@@ -8,6 +8,8 @@
 pub(crate) static CHECK_THE_CONSTANT_VALUES_FOR_CONSISTENCY: [fn(&mut TeXGlobals)] = [..];
 
 pub(crate) macro Check_the_constant_values_for_consistency($globals:expr) {
+    crate::section_0014::Check_the_constant_values_for_consistency_0014!($globals);
+    crate::section_0111::Check_the_constant_values_for_consistency_0111!($globals);
     for f in CHECK_THE_CONSTANT_VALUES_FOR_CONSISTENCY {
         f($globals);
     }
@@ -21,9 +23,15 @@ pub(crate) macro Initialize_the_output_routines($globals:expr) {
 }
 
 /// Main entry to TeX
-#[allow(unused_mut, unused_variables)]
 #[cfg_attr(feature = "trace", tracing::instrument(level = "trace", skip(globals)))]
 pub fn entry(globals: &mut TeXGlobals) {
+    crate::limits::start_run_clock(globals);
+    // TeX-rs: whatever happens, the host gets control back.
+    catch_internal_errors_at_top_level(globals, start_here);
+}
+
+#[allow(unused_mut, unused_variables)]
+fn start_here(globals: &mut TeXGlobals) {
     // @p begin @!{|start_here|}
 
     /// start_here
@@ -59,22 +67,27 @@ pub fn entry(globals: &mut TeXGlobals) {
         crate::goto_forward_label!('final_end);
         // end;
     }
+    // TeX-rs: the tables whose size is configured at run time get their room now.
+    allocate_the_configured_tables(globals);
     // initialize; {set global variables to their starting values}
     /// set global variables to their starting values
     initialize(globals);
     // @!init if not get_strings_started then goto final_end;
     crate::region_initex! {
-        if !get_strings_started(globals) {
-            crate::goto_forward_label!('final_end);
+        // TeX-rs: a run that isn't `ini_version` gets its strings from a format.
+        if globals.ini_version {
+            if !get_strings_started(globals) {
+                crate::goto_forward_label!('final_end);
+            }
+            // init_prim; {call |primitive| for each primitive}
+            /// call `primitive` for each primitive
+            try_or_jump!(init_prim(globals), 'end_of_TEX);
+            // init_str_ptr:=str_ptr; init_pool_ptr:=pool_ptr; fix_date_and_time;
+            globals.init_str_ptr = globals.str_ptr;
+            globals.init_pool_ptr = globals.pool_ptr;
+            fix_date_and_time(globals);
+            // tini@/
         }
-        // init_prim; {call |primitive| for each primitive}
-        /// call `primitive` for each primitive
-        init_prim(globals);
-        // init_str_ptr:=str_ptr; init_pool_ptr:=pool_ptr; fix_date_and_time;
-        globals.init_str_ptr = globals.str_ptr;
-        globals.init_pool_ptr = globals.pool_ptr;
-        fix_date_and_time(globals);
-        // tini@/
     };
     /// ready_already:=314159;
     {
@@ -96,10 +109,10 @@ pub fn entry(globals: &mut TeXGlobals) {
 
     // main_control; {come to life}
     /// come to life
-    try_or_jump!(main_control(globals), 'end_of_TEX);
+    try_or_jump!(catch_internal_errors(globals, main_control), 'end_of_TEX);
     // final_cleanup; {prepare for death}
     /// prepare for death
-    try_or_jump!(final_cleanup(globals), 'end_of_TEX);
+    try_or_jump!(catch_internal_errors(globals, final_cleanup), 'end_of_TEX);
     }
     // end_of_TEX: close_files_and_terminate;
     'end_of_TEX <-
@@ -118,6 +131,7 @@ use crate::section_0004::make_globals_io_view;
 use crate::section_0004::TeXGlobals;
 use crate::section_0004::TeXGlobalsIoStringLogView;
 use crate::section_0004::TeXGlobalsIoView;
+use crate::section_0011::allocate_the_configured_tables;
 use crate::section_0033::t_open_out;
 use crate::section_0047::get_strings_started;
 use crate::section_0056::wterm_ln;
@@ -128,4 +142,5 @@ use crate::section_1030::main_control;
 use crate::section_1333::close_files_and_terminate;
 use crate::section_1335::final_cleanup;
 use crate::section_1336::init_prim;
+use crate::unported::{catch_internal_errors, catch_internal_errors_at_top_level};
 use linkme::distributed_slice;
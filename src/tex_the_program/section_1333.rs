@@ -29,7 +29,14 @@ pub(crate) fn close_files_and_terminate(globals: &mut TeXGlobals) {
     }
     // wake_up_terminal; @<Finish the \.{DVI} file@>;
     wake_up_terminal(globals);
-    crate::section_0642::Finish_the_DVI_file!(globals);
+    // TeX-rs: nothing here may produce error messages, so the output budget no longer
+    // applies, and finishing the DVI file cannot fail
+    globals.limits.output_byte_budget = None;
+    let finished: TeXResult<()> = (|| {
+        crate::section_0642::Finish_the_DVI_file!(globals);
+        crate::ok_nojump!()
+    })();
+    debug_assert!(finished.is_ok());
     // if log_opened then
     if globals.log_opened {
         // begin wlog_cr; a_close(log_file); selector:=selector-2;
@@ -67,4 +74,5 @@ use crate::section_0056::wlog_cr;
 use crate::section_0058::print_char;
 use crate::section_0060::slow_print;
 use crate::section_0062::print_nl;
+use crate::section_0081::TeXResult;
 use crate::section_0236::new_line_char;
@@ -42,7 +42,7 @@ pub(crate) macro Output_statistics_about_this_job($globals:expr) {{
             format!(
                 " {} string characters out of {}",
                 $globals.pool_ptr - $globals.init_pool_ptr,
-                pool_size as integer - $globals.init_pool_ptr.get() as integer
+                $globals.pool_size as integer - $globals.init_pool_ptr.get() as integer
             ),
         );
         // wlog_ln(' ',lo_mem_max-mem_min+mem_end-hi_mem_min+2:1,@|
@@ -83,7 +83,11 @@ pub(crate) macro Output_statistics_about_this_job($globals:expr) {{
         // wlog_ln(', out of ',font_mem_size:1,' for ',font_max-font_base:1);@/
         wlog_ln(
             make_globals_log_view!($globals),
-            format!(", out of {} for {}", font_mem_size, font_max - font_base),
+            format!(
+                ", out of {} for {}",
+                $globals.font_mem_size,
+                $globals.font_max - font_base
+            ),
         );
         // wlog(' ',hyph_count:1,' hyphenation exception');
         wlog(
@@ -116,28 +120,22 @@ pub(crate) macro Output_statistics_about_this_job($globals:expr) {{
                 $globals.max_nest_stack.get(),
                 $globals.max_param_stack,
                 $globals.max_buf_stack.get() + 1,
-                $globals.max_save_stack.get(),
-                stack_size,
+                $globals.max_save_stack.get() + 6,
+                $globals.stack_size,
                 nest_size,
                 param_size,
-                buf_size,
-                save_size
+                $globals.buf_size,
+                $globals.save_size
             ),
         );
         // end
     }
     use crate::pascal::integer;
     use crate::section_0004::make_globals_log_view;
-    use crate::section_0011::buf_size;
-    use crate::section_0011::font_max;
-    use crate::section_0011::font_mem_size;
     use crate::section_0011::max_strings;
     use crate::section_0011::mem_min;
     use crate::section_0011::nest_size;
     use crate::section_0011::param_size;
-    use crate::section_0011::pool_size;
-    use crate::section_0011::save_size;
-    use crate::section_0011::stack_size;
     use crate::section_0012::font_base;
     use crate::section_0012::hash_size;
     use crate::section_0012::hyph_size;
@@ -16,13 +16,13 @@ pub(crate) fn final_cleanup(globals: &mut TeXGlobals) -> TeXResult<()> {
     }
     // if job_name=0 then open_log_file;
     if globals.job_name == 0 {
-        open_log_file(globals);
+        open_log_file(globals)?;
     }
     // while input_ptr>0 do
     while globals.input_ptr > 0 {
         // if state=token_list then end_token_list@+else end_file_reading;
         if state!(globals) == token_list {
-            end_token_list(globals);
+            end_token_list(globals)?;
         } else {
             end_file_reading(globals);
         }
@@ -109,19 +109,22 @@ pub(crate) fn final_cleanup(globals: &mut TeXGlobals) -> TeXResult<()> {
     if c == 1 {
         // begin @!init for c:=top_mark_code to split_bot_mark_code do
         crate::region_initex! {
-            for c in mark_code_kind::top_mark_code.get()..=mark_code_kind::split_bot_mark_code.get() {
-                // if cur_mark[c]<>null then delete_token_ref(cur_mark[c]);
-                if globals.cur_mark[c] != null {
-                    delete_token_ref(globals, globals.cur_mark[c]);
+            // TeX-rs: only an `ini_version` run can `\dump`.
+            if globals.ini_version {
+                for c in mark_code_kind::top_mark_code.get()..=mark_code_kind::split_bot_mark_code.get() {
+                    // if cur_mark[c]<>null then delete_token_ref(cur_mark[c]);
+                    if globals.cur_mark[c] != null {
+                        delete_token_ref(globals, globals.cur_mark[c]);
+                    }
                 }
+                // if last_glue<>max_halfword then delete_glue_ref(last_glue);
+                if globals.last_glue != max_halfword {
+                    delete_glue_ref(globals, globals.last_glue);
+                }
+                // store_fmt_file; return;@+tini@/
+                store_fmt_file(globals)?;
+                crate::return_nojump!();
             }
-            // if last_glue<>max_halfword then delete_glue_ref(last_glue);
-            if globals.last_glue != max_halfword {
-                delete_glue_ref(globals, globals.last_glue);
-            }
-            // store_fmt_file; return;@+tini@/
-            store_fmt_file(globals)?;
-            crate::return_nojump!();
         }
         // print_nl("(\dump is performed only by INITEX)"); return;
         print_nl(
@@ -52,16 +52,18 @@ pub(crate) macro Put_each_of_TeX_s_primitivies_into_the_hash_table($globals:expr
 // @!init procedure init_prim; {initialize all the primitives}
 /// initialize all the primitives
 #[cfg(feature = "initex")]
-pub(crate) fn init_prim(globals: &mut TeXGlobals) {
+pub(crate) fn init_prim(globals: &mut TeXGlobals) -> TeXResult<()> {
     // begin no_new_control_sequence:=false;
     globals.no_new_control_sequence = false;
     // @<Put each...@>;
     Put_each_of_TeX_s_primitivies_into_the_hash_table!(globals);
     // no_new_control_sequence:=true;
     globals.no_new_control_sequence = true;
+    crate::ok_nojump!()
     // end;
     // tini
 }
 
 use crate::section_0004::TeXGlobals;
+use crate::section_0081::TeXResult;
 use linkme::distributed_slice;
@@ -8,7 +8,7 @@
 // @<Get the first line...@>=
 pub(crate) macro Get_the_first_line_of_input_and_prepare_to_start($globals:expr, $lbl_end_of_TEX:lifetime, $lbl_final_end:lifetime) {
     // begin @<Initialize the input routines@>;
-    crate::section_0331::Initialize_the_input_routines!($globals, $lbl_final_end);
+    crate::section_0331::Initialize_the_input_routines!($globals, $lbl_end_of_TEX, $lbl_final_end);
     // if (format_ident=0)or(buffer[loc]="&") then
     if $globals.format_ident.is_zero()
         || $globals.buffer[loc!($globals)] == ASCII_code_literal!(b'&')
@@ -23,7 +23,8 @@ pub(crate) macro Get_the_first_line_of_input_and_prepare_to_start($globals:expr,
             crate::goto_forward_label!($lbl_final_end);
         }
         // if not load_fmt_file then
-        if !load_fmt_file($globals) {
+        // TeX-rs: a format that needs an unported section fails to load like a bad one.
+        if !catch_internal_errors_while_loading($globals, load_fmt_file) {
             // begin w_close(fmt_file); goto final_end;
             w_close(&mut $globals.fmt_file);
             crate::goto_forward_label!($lbl_final_end);
@@ -39,6 +40,10 @@ pub(crate) macro Get_the_first_line_of_input_and_prepare_to_start($globals:expr,
         }
         // end;
     }
+    // TeX-rs: an interaction level given along with the job overrides the format's.
+    if let Some(interaction) = $globals.interaction_option {
+        $globals.interaction = interaction.into();
+    }
     // if end_line_char_inactive then decr(limit)
     if end_line_char_inactive!($globals) {
         decr!(limit!($globals));
@@ -55,7 +60,10 @@ pub(crate) macro Get_the_first_line_of_input_and_prepare_to_start($globals:expr,
     if loc!($globals) < limit!($globals)
         && cat_code!($globals, $globals.buffer[loc!($globals)]) != escape as halfword
     {
-        try_or_jump!(start_input($globals), $lbl_end_of_TEX);
+        try_or_jump!(
+            catch_internal_errors($globals, start_input),
+            $lbl_end_of_TEX
+        );
         // {\.{\\input} assumed}
         /// `\input` assumed.
         const _: () = ();
@@ -80,4 +88,5 @@ pub(crate) macro Get_the_first_line_of_input_and_prepare_to_start($globals:expr,
     use crate::section_0524::open_fmt_file;
     use crate::section_0537::start_input;
     use crate::section_1303::load_fmt_file;
+    use crate::unported::{catch_internal_errors, catch_internal_errors_while_loading};
 }
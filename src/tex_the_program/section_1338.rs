@@ -26,7 +26,7 @@
 /// routine to display various things
 #[cfg(feature = "debugging")]
 #[allow(unused_variables)]
-pub(crate) fn debug_help(globals: &mut TeXGlobals) {
+pub(crate) fn debug_help(globals: &mut TeXGlobals) -> TeXResult<()> {
     // label breakpoint,exit;
     // var k,@!l,@!m,@!n:integer;
     // begin clear_terminal;
@@ -38,7 +38,7 @@ pub(crate) fn debug_help(globals: &mut TeXGlobals) {
         print_nl(globals, crate::strpool_str!("debug # (-1 to exit):"));
         update_terminal(globals);
         // @.debug \#@>
-        todo!("not yet implemented in {}", file!());
+        crate::unported::unported!(globals, 1338);
         // read(term_in,m);
         // if m<0 then return
         // else if m=0 then
@@ -62,3 +62,4 @@ use crate::section_0034::clear_terminal;
 use crate::section_0034::update_terminal;
 use crate::section_0034::wake_up_terminal;
 use crate::section_0062::print_nl;
+use crate::section_0081::TeXResult;
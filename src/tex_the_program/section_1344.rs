@@ -19,7 +19,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1344($globals
         crate::strpool_str!("openout"),
         extension,
         open_node as _,
-    );
+    )?;
     // @!@:open_out_}{\.{\\openout} primitive@>
     // primitive("write",extension,write_node); write_loc:=cur_val;@/
     primitive(
@@ -27,7 +27,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1344($globals
         crate::strpool_str!("write"),
         extension,
         write_node as _,
-    );
+    )?;
     globals.write_loc = globals.cur_val as pointer;
     // @!@:write_}{\.{\\write} primitive@>
     // primitive("closeout",extension,close_node);@/
@@ -36,7 +36,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1344($globals
         crate::strpool_str!("closeout"),
         extension,
         close_node as _,
-    );
+    )?;
     // @!@:close_out_}{\.{\\closeout} primitive@>
     // primitive("special",extension,special_node);@/
     primitive(
@@ -44,7 +44,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1344($globals
         crate::strpool_str!("special"),
         extension,
         special_node as _,
-    );
+    )?;
     // @!@:special_}{\.{\\special} primitive@>
     // primitive("immediate",extension,immediate_code);@/
     primitive(
@@ -52,7 +52,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1344($globals
         crate::strpool_str!("immediate"),
         extension,
         immediate_code as _,
-    );
+    )?;
     // @!@:immediate_}{\.{\\immediate} primitive@>
     // primitive("setlanguage",extension,set_language_code);@/
     primitive(
@@ -60,7 +60,7 @@ pub(crate) macro Put_each_of_tex_s_primitivies_into_the_hash_table_1344($globals
         crate::strpool_str!("setlanguage"),
         extension,
         set_language_code as _,
-    );
+    )?;
     // @!@:set_language_}{\.{\\setlanguage} primitive@>
 }}
 
@@ -8,12 +8,21 @@ pub(crate) macro Cases_of_print_cmd_chr_for_symbolic_printing_of_primitives_1346
             unreachable!();
         }
         // open_node:print_esc("openout");
+        else if $chr_code.get() == open_node as chr_code_repr {
+            print_esc($globals, crate::strpool_str!("openout"));
+        }
         // write_node:print_esc("write");
         else if $chr_code.get() == write_node as chr_code_repr {
             print_esc($globals, crate::strpool_str!("write"));
         }
         // close_node:print_esc("closeout");
+        else if $chr_code.get() == close_node as chr_code_repr {
+            print_esc($globals, crate::strpool_str!("closeout"));
+        }
         // special_node:print_esc("special");
+        else if $chr_code.get() == special_node as chr_code_repr {
+            print_esc($globals, crate::strpool_str!("special"));
+        }
         // immediate_code:print_esc("immediate");
         else if $chr_code.get() == immediate_code as chr_code_repr {
             print_esc($globals, crate::strpool_str!("immediate"));
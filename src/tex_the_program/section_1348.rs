@@ -22,6 +22,9 @@ pub(crate) fn do_extension(globals: &mut TeXGlobals) -> TeXResult<()> {
         crate::section_1353::Implement_closeout!(globals);
     }
     // special_node:@<Implement \.{\\special}@>;
+    else if globals.cur_chr.get() == special_node as chr_code_repr {
+        crate::section_1354::Implement_special!(globals);
+    }
     // immediate_code:@<Implement \.{\\immediate}@>;
     else if globals.cur_chr.get() == immediate_code as chr_code_repr {
         crate::section_1375::Implement_immediate!(globals);
@@ -46,6 +49,7 @@ use crate::section_0095::confusion;
 use crate::section_0297::chr_code_repr;
 use crate::section_1341::close_node;
 use crate::section_1341::open_node;
+use crate::section_1341::special_node;
 use crate::section_1341::write_node;
 use crate::section_1344::immediate_code;
 use crate::section_1344::set_language_code;
@@ -1,8 +1,23 @@
 //! @ When `\.{\\special\{...\}}' appears, we expand the macros in the token
 //! list as in \.{\\xdef} and \.{\\mark}.
-//!
-//! @<Implement \.{\\special}@>=
-//! begin new_whatsit(special_node,write_node_size); write_stream(tail):=null;
-//! p:=scan_toks(false,true); write_tokens(tail):=def_ref;
-//! end
-//!
+//
+// @<Implement \.{\\special}@>=
+pub(crate) macro Implement_special($globals:expr) {{
+    let _p: pointer;
+    // begin new_whatsit(special_node,write_node_size); write_stream(tail):=null;
+    new_whatsit($globals, special_node.into(), write_node_size.into())?;
+    write_stream!($globals, tail!($globals)) = null as _;
+    // p:=scan_toks(false,true); write_tokens(tail):=def_ref;
+    _p = scan_toks($globals, false, true)?;
+    write_tokens!($globals, tail!($globals)) = $globals.def_ref;
+    // end
+    use crate::section_0115::null;
+    use crate::section_0115::pointer;
+    use crate::section_0213::tail;
+    use crate::section_0473::scan_toks;
+    use crate::section_1341::special_node;
+    use crate::section_1341::write_node_size;
+    use crate::section_1341::write_stream;
+    use crate::section_1341::write_tokens;
+    use crate::section_1349::new_whatsit;
+}}
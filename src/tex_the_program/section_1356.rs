@@ -5,8 +5,18 @@ pub(crate) macro Display_the_whatsit_node_p($globals:expr, $p:expr) {{
     let subtype_p = subtype!($globals, $p as pointer);
     // open_node:begin print_write_whatsit("openout",p);
     if subtype_p == open_node {
-        todo!("open_node");
+        print_write_whatsit($globals, crate::strpool_str!("openout"), $p as pointer);
         // print_char("="); print_file_name(open_name(p),open_area(p),open_ext(p));
+        print_char(
+            make_globals_io_string_log_view!($globals),
+            ASCII_code_literal!(b'='),
+        );
+        print_file_name(
+            $globals,
+            open_name!($globals, $p as pointer) as _,
+            open_area!($globals, $p as pointer) as _,
+            open_ext!($globals, $p as pointer) as _,
+        );
         // end;
     }
     // write_node:begin print_write_whatsit("write",p);
@@ -18,12 +28,13 @@ pub(crate) macro Display_the_whatsit_node_p($globals:expr, $p:expr) {{
     }
     // close_node:print_write_whatsit("closeout",p);
     else if subtype_p == close_node {
-        todo!("close_node");
+        print_write_whatsit($globals, crate::strpool_str!("closeout"), $p as pointer);
     }
     // special_node:begin print_esc("special");
     else if subtype_p == special_node {
-        todo!("special_node");
+        print_esc($globals, crate::strpool_str!("special"));
         // print_mark(write_tokens(p));
+        print_mark($globals, write_tokens!($globals, $p as pointer) as _);
         // end;
     }
     // language_node:begin print_esc("setlanguage");
@@ -60,8 +71,12 @@ pub(crate) macro Display_the_whatsit_node_p($globals:expr, $p:expr) {{
     use crate::section_0115::pointer;
     use crate::section_0133::subtype;
     use crate::section_0176::print_mark;
+    use crate::section_0518::print_file_name;
     use crate::section_1341::close_node;
     use crate::section_1341::language_node;
+    use crate::section_1341::open_area;
+    use crate::section_1341::open_ext;
+    use crate::section_1341::open_name;
     use crate::section_1341::open_node;
     use crate::section_1341::special_node;
     use crate::section_1341::what_lang;
@@ -1,15 +1,47 @@
 //! ` `
 // @<Make a partial copy of the whatsit...@>=
-// case subtype(p) of
-// open_node: begin r:=get_node(open_node_size); words:=open_node_size;
-//   end;
-// write_node,special_node: begin r:=get_node(write_node_size);
-//   add_token_ref(write_tokens(p)); words:=write_node_size;
-//   end;
-// close_node,language_node: begin r:=get_node(small_node_size);
-//   words:=small_node_size;
-//   end;
-// othercases confusion("ext2")
-// @:this can't happen ext2}{\quad ext2@>
-// endcases
-//
+pub(crate) macro Make_a_partial_copy_of_the_whatsit_node_p_and_make_r_point_to_it__set_words_to_the_number_of_initial_words_not_yet_copied($globals:expr, $p:expr, $r:expr, $words:expr) {{
+    // case subtype(p) of
+    let subtype_p = subtype!($globals, $p);
+    // open_node: begin r:=get_node(open_node_size); words:=open_node_size;
+    //   end;
+    if subtype_p == open_node {
+        $r = get_node($globals, open_node_size as _)?;
+        $words = open_node_size as _;
+    }
+    // write_node,special_node: begin r:=get_node(write_node_size);
+    //   add_token_ref(write_tokens(p)); words:=write_node_size;
+    //   end;
+    else if subtype_p == write_node || subtype_p == special_node {
+        $r = get_node($globals, write_node_size as _)?;
+        let write_tokens_p = write_tokens!($globals, $p);
+        add_token_ref!($globals, write_tokens_p);
+        $words = write_node_size as _;
+    }
+    // close_node,language_node: begin r:=get_node(small_node_size);
+    //   words:=small_node_size;
+    //   end;
+    else if subtype_p == close_node || subtype_p == language_node {
+        $r = get_node($globals, small_node_size as _)?;
+        $words = small_node_size as _;
+    }
+    // othercases confusion("ext2")
+    else {
+        confusion($globals, crate::strpool_str!("ext2"))?;
+    }
+    // @:this can't happen ext2}{\quad ext2@>
+    // endcases
+    use crate::section_0095::confusion;
+    use crate::section_0125::get_node;
+    use crate::section_0133::subtype;
+    use crate::section_0141::small_node_size;
+    use crate::section_0203::add_token_ref;
+    use crate::section_1341::close_node;
+    use crate::section_1341::language_node;
+    use crate::section_1341::open_node;
+    use crate::section_1341::open_node_size;
+    use crate::section_1341::special_node;
+    use crate::section_1341::write_node;
+    use crate::section_1341::write_node_size;
+    use crate::section_1341::write_tokens;
+}}
@@ -1,3 +1,7 @@
-//! @ @<Process whatsit |p| in |vert_break| loop, |goto not_found|@>=
-//! goto not_found
-//!
+//! ` `
+
+// @<Process whatsit |p| in |vert_break| loop, |goto not_found|@>=
+pub(crate) macro Process_whatsit_p_in_vert_break_loop__goto_not_found($globals:expr, $p:expr, $lbl_not_found:lifetime) {{
+    // goto not_found
+    crate::goto_forward_label!($lbl_not_found);
+}}
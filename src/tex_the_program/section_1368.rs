@@ -0,0 +1,97 @@
+//! @ After all this preliminary shuffling, we come finally to the routines
+//! that actually send out the requested data. Let's do \.{\\special} first
+//! (it's easier).
+//
+// @<Declare procedures needed in |hlist_out|, |vlist_out|@>=
+// procedure special_out(@!p:pointer);
+pub(crate) fn special_out(globals: &mut TeXGlobals, p: pointer) -> TeXResult<()> {
+    // var old_setting:0..max_selector; {holds print |selector|}
+    /// holds print `selector`
+    let old_setting;
+    // @!k:pool_pointer; {index into |str_pool|}
+    // begin synch_h; synch_v;@/
+    synch_h!(globals);
+    synch_v!(globals);
+    // old_setting:=selector; selector:=new_string;
+    old_setting = globals.selector;
+    globals.selector = new_string.into();
+    // show_token_list(link(write_tokens(p)),null,pool_size-pool_ptr);
+    show_token_list(
+        globals,
+        link!(globals, write_tokens!(globals, p)) as _,
+        null as _,
+        globals.pool_size as integer - globals.pool_ptr.get() as integer,
+    );
+    // selector:=old_setting;
+    globals.selector = old_setting;
+    // str_room(1);
+    str_room(globals, 1)?;
+    // TeX-rs: with `unicode_support` the pool keeps characters in a multibyte
+    // encoding; each one goes out as the single byte of its code, as in TeX,
+    // unless that code is beyond 255.
+    #[cfg(feature = "unicode_support")]
+    let special = {
+        let mut special = Vec::new();
+        for ch in globals
+            .str_pool
+            .slice_ascii_codes(globals.str_start[globals.str_ptr]..globals.pool_ptr)
+        {
+            let c = xord(ch).numeric_value();
+            if c < 256 {
+                special.push(c as u8);
+            } else {
+                special.extend(FssUtfEncodedIP32::new(c as _));
+            }
+        }
+        special
+    };
+    #[cfg(not(feature = "unicode_support"))]
+    let special = (globals.str_start[globals.str_ptr].get()..globals.pool_ptr.get())
+        .map(|k| globals.str_pool[k].numeric_value())
+        .collect::<Vec<u8>>();
+    // if cur_length<256 then
+    if special.len() < 256 {
+        // begin dvi_out(xxx1); dvi_out(cur_length);
+        dvi_out!(globals, xxx1.byte());
+        dvi_out!(globals, special.len() as u8);
+        // end
+    }
+    // else  begin dvi_out(xxx4); dvi_four(cur_length);
+    else {
+        dvi_out!(globals, xxx4.byte());
+        dvi_four(globals, special.len() as integer)?;
+        // end;
+    }
+    // for k:=str_start[str_ptr] to pool_ptr-1 do dvi_out(so(str_pool[k]));
+    for b in special {
+        dvi_out!(globals, b);
+    }
+    // pool_ptr:=str_start[str_ptr]; {erase the string}
+    /// erase the string
+    {
+        globals.pool_ptr = globals.str_start[globals.str_ptr];
+    }
+    crate::ok_nojump!()
+    // end;
+}
+
+use crate::pascal::integer;
+use crate::section_0004::TeXGlobals;
+#[cfg(feature = "unicode_support")]
+use crate::section_0020::xord;
+use crate::section_0042::str_room;
+use crate::section_0054::new_string;
+use crate::section_0081::TeXResult;
+use crate::section_0115::null;
+use crate::section_0115::pointer;
+use crate::section_0118::link;
+use crate::section_0292::show_token_list;
+use crate::section_0586::xxx1;
+use crate::section_0586::xxx4;
+use crate::section_0598::dvi_out;
+use crate::section_0600::dvi_four;
+use crate::section_0616::synch_h;
+use crate::section_0616::synch_v;
+use crate::section_1341::write_tokens;
+#[cfg(feature = "unicode_support")]
+use crate::unicode_support::FssUtfEncodedIP32;
@@ -0,0 +1,30 @@
+//! @ To write a token list, we must run it through \TeX's scanner, expanding
+//! macros and \.{\\the} and \.{\\number}, etc. This might cause runaways,
+//! if a delimited macro parameter isn't matched, and runaways would be
+//! extremely confusing since we are calling on \TeX's scanner in the middle
+//! of a \.{\\shipout} command. Therefore we will put a dummy control sequence as
+//! a ``stopper,'' right after the token list. This control sequence is
+//! artificially defined to be \.{\\outer}.
+//! @:end_write_}{\.{\\endwrite}@>
+//
+// @<Initialize table...@>=
+pub(crate) macro Initialize_table_entries_done_by_initex_only_1369($globals:expr) {{
+    let globals = &mut *$globals;
+    // text(end_write):="endwrite"; eq_level(end_write):=level_one;
+    text!(globals, end_write as pointer) = crate::strpool_str!("endwrite").get() as _;
+    eq_level!(globals, end_write) = level_one;
+    // eq_type(end_write):=outer_call; equiv(end_write):=null;
+    eq_type!(globals, end_write) = outer_call;
+    equiv!(globals, end_write) = null;
+}}
+
+use crate::section_0004::TeXGlobals;
+use crate::section_0115::null;
+use crate::section_0115::pointer;
+use crate::section_0210::outer_call;
+use crate::section_0221::eq_level;
+use crate::section_0221::eq_type;
+use crate::section_0221::equiv;
+use crate::section_0221::level_one;
+use crate::section_0222::end_write;
+use crate::section_0256::text;
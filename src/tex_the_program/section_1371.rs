@@ -18,21 +18,21 @@ pub(crate) macro Expand_macros_in_the_token_list_and_make_link_def_ref_point_to_
     /// temporary variables for list manipulation
     let (mut q, r): (pointer, pointer);
     // q:=get_avail; info(q):=right_brace_token+"}";@/
-    q = get_avail(globals);
+    q = get_avail(globals)?;
     info_tok_assign!(
         globals,
         q,
         cur_tok_type::new(right_brace_token + b'}' as cur_tok_repr)
     );
     // r:=get_avail; link(q):=r; info(r):=end_write_token; ins_list(q);@/
-    r = get_avail(globals);
+    r = get_avail(globals)?;
     link!(globals, q) = r;
     info_tok_assign!(globals, r, end_write_token!());
     ins_list!(globals, q);
     // begin_token_list(write_tokens(p),write_text);@/
     begin_token_list(globals, write_tokens!(globals, p), write_text);
     // q:=get_avail; info(q):=left_brace_token+"{"; ins_list(q);
-    q = get_avail(globals);
+    q = get_avail(globals)?;
     info_tok_assign!(
         globals,
         q,
@@ -64,13 +64,13 @@ pub(crate) macro Expand_macros_in_the_token_list_and_make_link_def_ref_point_to_
     //   @<Recover from an unbalanced write command@>;
     get_token(globals)?;
     if globals.cur_tok != end_write_token!() {
-        todo!("recover");
+        crate::section_1372::Recover_from_an_unbalanced_write_command!(globals);
     }
     // mode:=old_mode;
     mode!(globals) = old_mode;
     // end_token_list {conserve stack space}
     /// conserve stack space
-    end_token_list(globals);
+    end_token_list(globals)?;
     use crate::section_0115::pointer;
     use crate::section_0118::info_tok_assign;
     use crate::section_0118::link;
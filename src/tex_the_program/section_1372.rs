@@ -1,9 +1,29 @@
-//! @ @<Recover from an unbalanced write command@>=
-//! begin print_err("Unbalanced write command");
-//! @.Unbalanced write...@>
-//! help2("On this page there's a \write with fewer real {'s than }'s.")@/
-//! ("I can't handle that very well; good luck."); error;
-//! repeat get_token;
-//! until cur_tok=end_write_token;
-//! end
-//!
+//! ` `
+// @<Recover from an unbalanced write command@>=
+pub(crate) macro Recover_from_an_unbalanced_write_command($globals:expr) {{
+    // begin print_err("Unbalanced write command");
+    print_err!($globals, crate::strpool_str!("Unbalanced write command"));
+    // @.Unbalanced write...@>
+    // help2("On this page there's a \write with fewer real {'s than }'s.")@/
+    // ("I can't handle that very well; good luck."); error;
+    help2!(
+        $globals,
+        crate::strpool_str!("On this page there's a \\write with fewer real {'s than }'s."),
+        crate::strpool_str!("I can't handle that very well; good luck.")
+    );
+    error($globals)?;
+    // repeat get_token;
+    // until cur_tok=end_write_token;
+    loop {
+        get_token($globals)?;
+        if $globals.cur_tok == end_write_token!() {
+            break;
+        }
+    }
+    // end
+    use crate::section_0073::print_err;
+    use crate::section_0079::help2;
+    use crate::section_0082::error;
+    use crate::section_0365::get_token;
+    use crate::section_1371::end_write_token;
+}}
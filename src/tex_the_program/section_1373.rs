@@ -15,7 +15,7 @@ pub(crate) fn out_what(globals: &mut TeXGlobals, p: pointer) -> TeXResult<()> {
     }
     // special_node:special_out(p);
     else if subtype_p == special_node {
-        todo!("special_out");
+        special_out(globals, p)?;
     }
     // language_node:do_nothing;
     else if subtype_p == language_node {
@@ -38,3 +38,4 @@ use crate::section_0095::confusion;
 use crate::section_0115::pointer;
 use crate::section_0133::subtype;
 use crate::section_1341::*;
+use crate::section_1368::special_out;
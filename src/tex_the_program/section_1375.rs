@@ -26,7 +26,7 @@ pub(crate) macro Implement_immediate($globals:expr) {{
     }
     // else back_input;
     else {
-        back_input($globals);
+        back_input($globals)?;
     }
     // end
     use crate::section_0115::null;
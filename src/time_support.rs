@@ -0,0 +1,58 @@
+//! The date and time a job is considered to start at. `fix_date_and_time`
+//! asks for it once, just before `TeX` initializes its input and output.
+
+/// where the date and time of the job come from
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static clock: Clock = Clock::Local;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::configure::Clock;
+
+/// The environment variable that pins the current instant for reproducible builds,
+/// in seconds since 1970-01-01 00:00:00 UTC.
+const source_date_epoch_var: &str = "SOURCE_DATE_EPOCH";
+
+fn source_date_epoch() -> Option<DateTime<Utc>> {
+    let seconds = std::env::var(source_date_epoch_var)
+        .ok()?
+        .trim()
+        .parse::<i64>()
+        .ok()?;
+    Utc.timestamp_opt(seconds, 0).single()
+}
+
+fn date_time_from_chrono<Tz: TimeZone>(date_time: DateTime<Tz>) -> FixedDateTime {
+    FixedDateTime {
+        year: date_time.year(),
+        month: date_time.month() as u8,
+        day: date_time.day() as u8,
+        hour: date_time.hour() as u8,
+        minute: date_time.minute() as u8,
+    }
+}
+
+/// Whether `date_time` names a minute that exists in the proleptic Gregorian calendar.
+pub(crate) fn is_valid_date_and_time(date_time: FixedDateTime) -> bool {
+    NaiveDate::from_ymd_opt(date_time.year, date_time.month as u32, date_time.day as u32).is_some()
+        && date_time.hour < 24
+        && date_time.minute < 60
+}
+
+/// The date and time reported by `clock`; the instant is taken from
+/// `SOURCE_DATE_EPOCH` when it is set, and from the system clock otherwise.
+/// Like TeX Live, an instant from `SOURCE_DATE_EPOCH` is reported in UTC
+/// even by the local clock, so that a build doesn't depend on the time zone.
+pub(crate) fn current_date_and_time(clock: Clock) -> FixedDateTime {
+    match (clock, source_date_epoch()) {
+        (Clock::Fixed(date_time), _) => date_time,
+        (Clock::Local | Clock::Utc, Some(epoch)) => date_time_from_chrono(epoch),
+        (Clock::Local, None) => date_time_from_chrono(Local::now()),
+        (Clock::Utc, None) => date_time_from_chrono(Utc::now()),
+    }
+}
+
+use crate::configure::Clock;
+use crate::configure::FixedDateTime;
+use crate::section_0004::TeXGlobals;
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Timelike, Utc};
+use globals_struct::{globals_struct_field, globals_struct_use};
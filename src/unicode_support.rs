@@ -1,6 +1,14 @@
 #[globals_struct_field(TeXGlobals)]
 pub(crate) static max_allowed_ASCII_code_repr: integer = 255;
 
+#[globals_struct_field(TeXGlobals)]
+#[globals_struct_field_view(TeXGlobalsIoView)]
+pub(crate) static grapheme_cluster_handling: GraphemeClusterHandling =
+    GraphemeClusterHandling::Intern;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::configure::GraphemeClusterHandling;
+
 thread_local! {
     static GRAPHE_REGISTRY: GraphemeRegistry = GraphemeRegistry::new();
 }
@@ -28,6 +36,10 @@ impl GraphemeRegistry {
             return *v;
         }
         let cur_value = self.next_value.get();
+        if cur_value > crate::pascal::char::MAX.0 {
+            // no generalized character left for a new cluster
+            return core::char::REPLACEMENT_CHARACTER as u32;
+        }
         let s = Box::leak(s.into_boxed_str());
         self.normalized_strings_after_single_scalar_value
             .borrow_mut()
@@ -35,7 +47,7 @@ impl GraphemeRegistry {
         self.normalized_string_lookup_map
             .borrow_mut()
             .insert(s, cur_value);
-        self.next_value.set(cur_value.checked_add(1).unwrap());
+        self.next_value.set(cur_value + 1);
         cur_value
     }
 }
@@ -91,10 +103,37 @@ pub(crate) fn generalized_char_from_str(s: &str) -> generalized_char {
         return generalized_char::new(ch as u32);
     }
     let str = s.nfc().collect::<String>();
+    let (pos, ch) = str.char_indices().rev().next().unwrap();
+    if pos == 0 {
+        // composes into a single scalar value
+        return generalized_char::new(ch as u32);
+    }
     let result = GRAPHE_REGISTRY.with(|reg| reg.intern_normalized_multi_scalar_value(str));
     generalized_char::new(result)
 }
 
+/// Units to store in the buffer for a unit read from an input line; with
+/// [`GraphemeClusterHandling::NormalizeNfc`], a cluster interned when the line
+/// was read is split back into its NFC scalar values.
+pub(crate) fn buffer_units_from_input_unit(
+    handling: GraphemeClusterHandling,
+    val: generalized_char,
+) -> impl Iterator<Item = generalized_char> {
+    let split = handling == GraphemeClusterHandling::NormalizeNfc
+        && val.0 >= GRAPHEME_REGISTRY_INITIAL_VALUE;
+    let (unit, scalar_values) = if split {
+        (None, Some(chars_from_generalized_char(val)))
+    } else {
+        (Some(val), None)
+    };
+    unit.into_iter().chain(
+        scalar_values
+            .into_iter()
+            .flatten()
+            .map(|ch| generalized_char::new(ch as u32)),
+    )
+}
+
 pub(crate) fn chars_from_generalized_char(val: generalized_char) -> impl Iterator<Item = char> {
     use core::convert::TryFrom;
     let val = val.0;
@@ -406,6 +445,289 @@ pub(crate) fn triecharop_value(globals: &mut TeXGlobals, idx: halfword) -> trie_
     globals.triecharop_val_gallery[idx as usize]
 }
 
+/// Entries of the character code tables (`\catcode`, `\lccode`, `\uccode`, `\sfcode`,
+/// `\mathcode` and `\delcode`) for characters beyond 255, keyed with the base of the
+/// table in `eqtb` and the character code. Missing entries hold their default value.
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static unicode_code_table: BTreeMap<(word, ASCII_code_repr), memory_word> =
+    BTreeMap::new();
+
+/// Levels of the `\delcode` entries beyond 255, in the same way as `xeq_level`.
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static unicode_code_xeq_level: BTreeMap<ASCII_code_repr, quarterword> = BTreeMap::new();
+
+#[globals_struct_use(TeXGlobals)]
+use crate::section_0018::ASCII_code_repr;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::pascal::word;
+
+#[globals_struct_use(TeXGlobals)]
+use std::collections::BTreeMap;
+
+/// `save_type` when an entry of `unicode_code_table` should be restored later
+pub(crate) const restore_unicode_code: quarterword = level_boundary + 1;
+
+fn unicode_code_default(base: word) -> memory_word {
+    let mut w = memory_word::default();
+    if base == del_code_base {
+        w[MEMORY_WORD_INT] = -1;
+    } else {
+        eq_level_field!(w) = level_one;
+        eq_type_field!(w) = data;
+        equiv_field!(w) = if base == cat_code_base {
+            other_char as halfword
+        } else if base == sf_code_base {
+            1000
+        } else {
+            hi!(0)
+        };
+    }
+    w
+}
+
+fn unicode_code_is_default(base: word, w: &memory_word) -> bool {
+    let default = unicode_code_default(base);
+    if base == del_code_base {
+        w[MEMORY_WORD_INT] == default[MEMORY_WORD_INT]
+    } else {
+        eq_level_field!(*w) == eq_level_field!(default)
+            && eq_type_field!(*w) == eq_type_field!(default)
+            && equiv_field!(*w) == equiv_field!(default)
+    }
+}
+
+pub(crate) fn unicode_code_word(
+    globals: &TeXGlobals,
+    base: word,
+    c: ASCII_code_repr,
+) -> memory_word {
+    debug_assert!(c >= 256);
+    globals
+        .unicode_code_table
+        .get(&(base, c))
+        .copied()
+        .unwrap_or_else(|| unicode_code_default(base))
+}
+
+/// Store `w` as the entry for `c` in the table starting at `base`; entries
+/// equal to the default are dropped so that `unicode_code_table` stays sparse.
+fn unicode_code_set(globals: &mut TeXGlobals, base: word, c: ASCII_code_repr, w: memory_word) {
+    debug_assert!(c >= 256);
+    if unicode_code_is_default(base, &w) {
+        globals.unicode_code_table.remove(&(base, c));
+    } else {
+        globals.unicode_code_table.insert((base, c), w);
+    }
+}
+
+fn unicode_code_xeq_level(globals: &TeXGlobals, c: ASCII_code_repr) -> quarterword {
+    globals
+        .unicode_code_xeq_level
+        .get(&c)
+        .copied()
+        .unwrap_or(level_one)
+}
+
+fn unicode_code_save(
+    globals: &mut TeXGlobals,
+    base: word,
+    c: ASCII_code_repr,
+    l: quarterword,
+) -> TeXResult<()> {
+    check_full_save_stack!(globals);
+    globals.save_stack[globals.save_ptr] = unicode_code_word(globals, base, c);
+    incr!(globals.save_ptr);
+    globals.save_stack[globals.save_ptr][MEMORY_WORD_INT] = c as integer;
+    incr!(globals.save_ptr);
+    save_type!(globals, globals.save_ptr) = restore_unicode_code;
+    save_level!(globals, globals.save_ptr) = l;
+    save_index!(globals, globals.save_ptr) = base as halfword;
+    incr!(globals.save_ptr);
+    crate::ok_nojump!()
+}
+
+/// `eq_define` and `geq_define` for the halfword code tables beyond 255.
+fn unicode_code_define(
+    globals: &mut TeXGlobals,
+    global: boolean,
+    base: word,
+    c: ASCII_code_repr,
+    e: halfword,
+) -> TeXResult<()> {
+    let l = if global {
+        level_one
+    } else {
+        let old_level = eq_level_field!(unicode_code_word(globals, base, c));
+        if old_level != globals.cur_level && globals.cur_level > level_one {
+            unicode_code_save(globals, base, c, old_level)?;
+        }
+        globals.cur_level
+    };
+    let mut w = unicode_code_word(globals, base, c);
+    eq_level_field!(w) = l;
+    eq_type_field!(w) = data;
+    equiv_field!(w) = e;
+    unicode_code_set(globals, base, c, w);
+    crate::ok_nojump!()
+}
+
+/// `eq_word_define` and `geq_word_define` for the `\delcode` table beyond 255.
+fn unicode_code_word_define(
+    globals: &mut TeXGlobals,
+    global: boolean,
+    c: ASCII_code_repr,
+    w: integer,
+) -> TeXResult<()> {
+    let l = if global {
+        level_one
+    } else {
+        let old_level = unicode_code_xeq_level(globals, c);
+        if old_level != globals.cur_level {
+            unicode_code_save(globals, del_code_base, c, old_level)?;
+        }
+        globals.cur_level
+    };
+    if l == level_one {
+        globals.unicode_code_xeq_level.remove(&c);
+    } else {
+        globals.unicode_code_xeq_level.insert(c, l);
+    }
+    let mut code = unicode_code_word(globals, del_code_base, c);
+    code[MEMORY_WORD_INT] = w;
+    unicode_code_set(globals, del_code_base, c, code);
+    crate::ok_nojump!()
+}
+
+/// Assign a code beyond 255 in the table starting at `base`, as `def_code` does.
+pub(crate) fn unicode_code_assign(
+    globals: &mut TeXGlobals,
+    global: boolean,
+    base: word,
+    c: ASCII_code_repr,
+    val: integer,
+) -> TeXResult<()> {
+    if base < math_code_base {
+        unicode_code_define(globals, global, base, c, val as halfword)
+    } else if base < del_code_base {
+        unicode_code_define(globals, global, base, c, hi!(val as halfword))
+    } else {
+        unicode_code_word_define(globals, global, c, val)
+    }
+}
+
+/// The `restore_unicode_code` counterpart of
+/// `Store_s_save_stack_save_ptr_in_eqtb_p__unless_eqtb_p_holds_a_global_value`.
+pub(crate) fn unicode_code_restore(globals: &mut TeXGlobals) {
+    let l = save_level!(globals, globals.save_ptr);
+    let base = save_index!(globals, globals.save_ptr) as word;
+    decr!(globals.save_ptr);
+    let c = globals.save_stack[globals.save_ptr][MEMORY_WORD_INT] as ASCII_code_repr;
+    decr!(globals.save_ptr);
+    let saved = globals.save_stack[globals.save_ptr];
+    let restoring;
+    if base < int_base {
+        restoring = eq_level_field!(unicode_code_word(globals, base, c)) != level_one;
+        if restoring {
+            unicode_code_set(globals, base, c, saved);
+        }
+    } else {
+        restoring = unicode_code_xeq_level(globals, c) != level_one;
+        if restoring {
+            unicode_code_set(globals, base, c, saved);
+            if l == level_one {
+                globals.unicode_code_xeq_level.remove(&c);
+            } else {
+                globals.unicode_code_xeq_level.insert(c, l);
+            }
+        }
+    }
+    crate::region_stat! {
+        if tracing_restores!(globals) > 0 {
+            let s = if restoring {
+                crate::strpool_str!("restoring")
+            } else {
+                crate::strpool_str!("retaining")
+            };
+            begin_diagnostic(globals);
+            print_char(
+                make_globals_io_string_log_view!(globals),
+                ASCII_code_literal!(b'{'),
+            );
+            print(globals, s.get() as _);
+            print_char(
+                make_globals_io_string_log_view!(globals),
+                ASCII_code_literal!(b' '),
+            );
+            show_unicode_code(globals, base, c);
+            print_char(
+                make_globals_io_string_log_view!(globals),
+                ASCII_code_literal!(b'}'),
+            );
+            end_diagnostic(globals, false);
+        }
+        use crate::section_0004::make_globals_io_string_log_view;
+        use crate::section_0018::ASCII_code_literal;
+        use crate::section_0058::print_char;
+        use crate::section_0059::print;
+        use crate::section_0236::tracing_restores;
+        use crate::section_0245::begin_diagnostic;
+        use crate::section_0245::end_diagnostic;
+    }
+    crate::region_non_stat! {
+        crate::submit_strpool_str!("restoring");
+        crate::submit_strpool_str!("retaining");
+    };
+}
+
+/// The `show_eqtb` counterpart for entries of `unicode_code_table`.
+#[cfg(feature = "statistics")]
+pub(crate) fn show_unicode_code(globals: &mut TeXGlobals, base: word, c: ASCII_code_repr) {
+    let w = unicode_code_word(globals, base, c);
+    let value = if base == cat_code_base {
+        print_esc(globals, crate::strpool_str!("catcode"));
+        equiv_field!(w) as integer
+    } else if base == lc_code_base {
+        print_esc(globals, crate::strpool_str!("lccode"));
+        equiv_field!(w) as integer
+    } else if base == uc_code_base {
+        print_esc(globals, crate::strpool_str!("uccode"));
+        equiv_field!(w) as integer
+    } else if base == sf_code_base {
+        print_esc(globals, crate::strpool_str!("sfcode"));
+        equiv_field!(w) as integer
+    } else if base == math_code_base {
+        print_esc(globals, crate::strpool_str!("mathcode"));
+        ho!(equiv_field!(w)) as integer
+    } else {
+        print_esc(globals, crate::strpool_str!("delcode"));
+        w[MEMORY_WORD_INT]
+    };
+    print_int(globals, c as integer);
+    print_char(
+        make_globals_io_string_log_view!(globals),
+        ASCII_code_literal!(b'='),
+    );
+    print_int(globals, value);
+    use crate::section_0004::make_globals_io_string_log_view;
+    use crate::section_0018::ASCII_code_literal;
+    use crate::section_0058::print_char;
+    use crate::section_0063::print_esc;
+    use crate::section_0065::print_int;
+    use crate::section_0112::ho;
+}
+
+/// Control sequences named by a single character beyond 255 have no
+/// `single_base` slot and are kept in the hash table instead; this returns
+/// that character when string `s` is such a name.
+pub(crate) fn single_char_of_str(globals: &TeXGlobals, s: str_number) -> Option<ASCII_code> {
+    let mut chars = globals.str_pool.str_ascii_codes(&globals.str_start, s);
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Some(ASCII_code(ch.0)),
+        _ => None,
+    }
+}
+
 pub(crate) macro dump_four_bytes($globals:expr, $bytes:expr, $k:expr) {{
     let mut w = four_quarters::default();
     w[FOUR_QUARTERS_B0] = *$bytes.get($k as usize).unwrap_or(&0);
@@ -459,6 +781,19 @@ pub(crate) fn dump_the_unicode_support_data(globals: &mut TeXGlobals) {
         dump_int!(globals, triecharop_val.char.numeric_value() as _);
         dump_int!(globals, triecharop_val.op as _);
     }
+
+    let unicode_codes = globals
+        .unicode_code_table
+        .iter()
+        .filter(|((base, _), w)| !unicode_code_is_default(*base, w))
+        .map(|(k, w)| (*k, *w))
+        .collect::<Vec<_>>();
+    dump_int!(globals, unicode_codes.len() as _);
+    for ((base, c), w) in unicode_codes {
+        dump_int!(globals, base as _);
+        dump_int!(globals, c as _);
+        dump_wd!(globals, w);
+    }
 }
 
 pub(crate) macro undump_four_bytes($globals:expr, $bytes:expr, $k:expr) {{
@@ -500,7 +835,9 @@ pub(crate) fn undump_the_unicode_support_data(globals: &mut TeXGlobals) -> bool
         if !(GRAPHE_REGISTRY.with(|reg| -> bool {
             let count: integer;
             undump_int!(globals, count);
-            if GRAPHEME_REGISTRY_INITIAL_VALUE.checked_add(count as _).is_none() {
+            if count < 0
+                || count as u32 > crate::pascal::char::MAX.0 + 1 - GRAPHEME_REGISTRY_INITIAL_VALUE
+            {
                 return false;
             }
             reg.normalized_strings_after_single_scalar_value.borrow_mut().clear();
@@ -552,7 +889,7 @@ pub(crate) fn undump_the_unicode_support_data(globals: &mut TeXGlobals) -> bool
             undump!(
                 globals,
                 0,
-                graphme_registry_next_value,
+                graphme_registry_next_value - 1,
                 fontchar_val.character,
                 ASCII_code::from_integer,
                 'bad_fmt
@@ -568,7 +905,7 @@ pub(crate) fn undump_the_unicode_support_data(globals: &mut TeXGlobals) -> bool
             undump!(
                 globals,
                 0,
-                graphme_registry_next_value,
+                graphme_registry_next_value - 1,
                 triecharop_val.char,
                 ASCII_code::from_integer,
                 'bad_fmt
@@ -582,6 +919,42 @@ pub(crate) fn undump_the_unicode_support_data(globals: &mut TeXGlobals) -> bool
                 'bad_fmt
             );
         }
+
+        let unicode_code_count: integer;
+        undump_int!(globals, unicode_code_count);
+        if unicode_code_count < 0 {
+            crate::goto_forward_label!('bad_fmt);
+        }
+        globals.unicode_code_table.clear();
+        globals.unicode_code_xeq_level.clear();
+        for _ in 0..unicode_code_count {
+            let base: word;
+            undump_int!(globals, base, core::convert::identity);
+            if ![
+                cat_code_base,
+                lc_code_base,
+                uc_code_base,
+                sf_code_base,
+                math_code_base,
+                del_code_base,
+            ]
+            .contains(&base)
+            {
+                crate::goto_forward_label!('bad_fmt);
+            }
+            let c: ASCII_code_repr;
+            undump!(
+                globals,
+                256,
+                crate::pascal::char::MAX.0,
+                c,
+                core::convert::identity,
+                'bad_fmt
+            );
+            let w: memory_word;
+            undump_wd!(globals, w);
+            globals.unicode_code_table.insert((base, c), w);
+        }
         return true;
     }
     'bad_fmt <-
@@ -589,21 +962,53 @@ pub(crate) fn undump_the_unicode_support_data(globals: &mut TeXGlobals) -> bool
     false
 }
 
+use crate::configure::GraphemeClusterHandling;
+use crate::pascal::boolean;
 use crate::pascal::integer;
+use crate::pascal::word;
 use crate::section_0004::TeXGlobals;
 use crate::section_0012::font_base;
+use crate::section_0016::decr;
+use crate::section_0016::incr;
 use crate::section_0018::ASCII_code;
+use crate::section_0018::ASCII_code_repr;
 use crate::section_0038::packed_ASCII_code;
+use crate::section_0038::str_number;
+use crate::section_0081::TeXResult;
+use crate::section_0112::hi;
 use crate::section_0113::halfword;
+use crate::section_0113::memory_word;
+use crate::section_0113::quarterword;
+use crate::section_0113::MEMORY_WORD_INT;
 use crate::section_0134::font_and_character;
+use crate::section_0207::other_char;
+use crate::section_0210::data;
+use crate::section_0221::eq_level_field;
+use crate::section_0221::eq_type_field;
+use crate::section_0221::equiv_field;
+use crate::section_0221::level_one;
+use crate::section_0230::cat_code_base;
+use crate::section_0230::int_base;
+use crate::section_0230::lc_code_base;
+use crate::section_0230::math_code_base;
+use crate::section_0230::sf_code_base;
+use crate::section_0230::uc_code_base;
+use crate::section_0236::del_code_base;
+use crate::section_0268::level_boundary;
+use crate::section_0268::save_index;
+use crate::section_0268::save_level;
+use crate::section_0268::save_type;
+use crate::section_0273::check_full_save_stack;
 use crate::section_0297::cur_tok_repr;
 use crate::section_0548::internal_font_number;
 use crate::section_0921::trie_char_and_op;
 use crate::section_1305::dump_int;
+use crate::section_1305::dump_wd;
 use crate::section_1306::undump;
 use crate::section_1306::undump_int;
 use crate::section_1306::undump_qqqq;
 use crate::section_1306::undump_size;
+use crate::section_1306::undump_wd;
 use core::cell::{Cell, RefCell};
 use globals_struct::{globals_struct_field, globals_struct_use};
 use std::collections::BTreeMap;
@@ -0,0 +1,176 @@
+//! Parts of `TeX` that have not been ported yet.
+//!
+//! Reaching one of them is not the fault of the document, but the job cannot
+//! go on either. Instead of bringing down the host, the job ends the way
+//! `confusion` ends it: with a fatal error, a transcript that is closed
+//! properly, and `history=fatal_error_stop`. The section that was reached is
+//! kept, so that hosts can report it.
+//!
+//! A panic, which can only come from a bug in `TeX-rs`, ends the job the
+//! same way; the host gets its message instead of the panic.
+
+use std::any::Any;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// A section of `TeX` that a job needed, but that has not been ported yet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnportedSection {
+    /// the number of the section in `TeX`: The Program
+    pub section: u16,
+    /// what the section was about to do
+    pub detail: String,
+}
+
+impl fmt::Display for UnportedSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "section {} is not yet ported", self.section)?;
+        if !self.detail.is_empty() {
+            write!(f, " ({})", self.detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// Stands in for the code of section `$section` until it is ported; like
+/// `confusion`, it ends the job, so it can stand where any value is expected.
+pub(crate) macro unported {
+    ($globals:expr, $section:expr) => {
+        crate::unported::unported_section_reached($globals, $section, String::new())?
+    },
+    ($globals:expr, $section:expr, $($arg:tt)+) => {
+        crate::unported::unported_section_reached($globals, $section, format!($($arg)+))?
+    }
+}
+
+/// the unported section this job reached, if any
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static unported_section: Option<UnportedSection> = None;
+
+#[globals_struct_use(TeXGlobals)]
+use crate::unported::UnportedSection;
+
+/// the message of the panic that ended this job, if any
+#[globals_struct_field(TeXGlobals)]
+pub(crate) static internal_error: Option<String> = None;
+
+/// Run `f`; should it panic, end the job with a fatal error.
+pub(crate) fn catch_internal_errors(
+    globals: &mut TeXGlobals,
+    f: fn(&mut TeXGlobals) -> TeXResult<()>,
+) -> TeXResult<()> {
+    match catch_unwind(AssertUnwindSafe(|| f(globals))) {
+        Ok(result) => result,
+        Err(payload) => {
+            globals.internal_error = Some(panic_message(payload));
+            // the tables may be left half updated, so a second panic just ends the job
+            match catch_unwind(AssertUnwindSafe(|| -> TeXResult<()> {
+                confusion(globals, crate::strpool_str!("internal error"))?
+            })) {
+                Ok(result) => result,
+                Err(_) => {
+                    globals.history = history_kind::fatal_error_stop;
+                    crate::ok_nojump!()
+                }
+            }
+        }
+    }
+}
+
+/// Run `f`, which loads the format; should it panic, report the format file the
+/// way `load_fmt_file` reports a bad one, and fail.
+pub(crate) fn catch_internal_errors_while_loading(
+    globals: &mut TeXGlobals,
+    f: fn(&mut TeXGlobals) -> boolean,
+) -> boolean {
+    match catch_unwind(AssertUnwindSafe(|| f(globals))) {
+        Ok(loaded) => loaded,
+        Err(payload) => {
+            globals.internal_error = Some(panic_message(payload));
+            // the strings may be only partly loaded, so nothing is printed from the pool
+            wake_up_terminal(globals);
+            wterm_ln(
+                make_globals_io_view!(globals),
+                "(Fatal format file error; internal error)".to_string(),
+            );
+            false
+        }
+    }
+}
+
+/// Run `f`, which ends the job by itself; should it panic, just record why.
+pub(crate) fn catch_internal_errors_at_top_level(globals: &mut TeXGlobals, f: fn(&mut TeXGlobals)) {
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(|| f(globals))) {
+        globals.internal_error = Some(panic_message(payload));
+        globals.history = history_kind::fatal_error_stop;
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "panic".to_string(),
+        },
+    }
+}
+
+/// The counterpart of `confusion` for sections that are missing rather than broken.
+pub(crate) fn unported_section_reached(
+    globals: &mut TeXGlobals,
+    section: u16,
+    detail: String,
+) -> TeXResult<!> {
+    globals.unported_section = Some(UnportedSection { section, detail });
+    normalize_selector(globals)?;
+    if globals.history < history_kind::error_message_issued {
+        print_err!(
+            globals,
+            crate::strpool_str!("This can't happen (unported section ")
+        );
+        print_int(globals, section as integer);
+        print_char(
+            make_globals_io_string_log_view!(globals),
+            ASCII_code_literal!(b')'),
+        );
+        help1!(
+            globals,
+            crate::strpool_str!("This part of TeX has not been ported to TeX-rs yet.")
+        );
+    } else {
+        print_err!(
+            globals,
+            crate::strpool_str!("I can't go on meeting you like this")
+        );
+        help2!(
+            globals,
+            crate::strpool_str!("One of your faux pas seems to have wounded me deeply..."),
+            crate::strpool_str!("in fact, I'm barely conscious. Please fix it and try again.")
+        );
+    }
+    succumb(globals)?;
+    crate::never_nojump!()
+}
+
+use crate::pascal::boolean;
+use crate::pascal::integer;
+use crate::section_0004::make_globals_io_string_log_view;
+use crate::section_0004::make_globals_io_view;
+use crate::section_0004::TeXGlobals;
+use crate::section_0004::TeXGlobalsIoStringLogView;
+use crate::section_0004::TeXGlobalsIoView;
+use crate::section_0018::ASCII_code_literal;
+use crate::section_0034::wake_up_terminal;
+use crate::section_0056::wterm_ln;
+use crate::section_0058::print_char;
+use crate::section_0065::print_int;
+use crate::section_0073::print_err;
+use crate::section_0076::history_kind;
+use crate::section_0079::help1;
+use crate::section_0079::help2;
+use crate::section_0081::TeXResult;
+use crate::section_0092::normalize_selector;
+use crate::section_0093::succumb;
+use crate::section_0095::confusion;
+use globals_struct::{globals_struct_field, globals_struct_use};